@@ -1,154 +0,0 @@
-//! Authentication module with platform-specific implementations
-
-// Shared types
-
-
-#[derive(Debug)]
-pub struct AuthorizedClient;
-
-// Platform-specific implementations
-#[cfg(target_arch = "wasm32")]
-mod wasm_impl {
-    use dioxus::prelude::{ServerFnError, Readable};
-    use gloo::storage::Storage;
-    use supabase_js_rs::*;
-    use dioxus::signals::{GlobalSignal, Signal};
-    use tracing::debug;
-    use crate::api::env;
-
-    use crate::api::auth::{User, Credentials};
-
-    pub static CLIENT: GlobalSignal<SupabaseClient> = Signal::global(create_client);
-
-    fn create_client() -> SupabaseClient {
-        let config = env::get_env_config();
-        supabase_js_rs::create_client(&config.supabase_url, &config.supabase_anon_key)
-    }
-
-    pub async fn get_user() -> Option<User> {
-        // Get user from Supabase client
-        let _client = CLIENT.read();
-        
-        // For now, return None - implement actual user retrieval logic here
-        None
-    }
-
-    pub async fn signin_with_password(credentials: Credentials) -> Result<String, ServerFnError> {
-        debug!("Signing in with password for email: {}", credentials.email);
-        
-        // Placeholder implementation - implement actual Supabase auth here
-        Ok("WASM sign-in not fully implemented".to_string())
-    }
-
-    pub async fn signin_with_google() -> Result<String, ServerFnError> {
-        debug!("Signing in with Google");
-        
-        // Placeholder implementation - implement actual Google OAuth here
-        Ok("Google sign-in not fully implemented".to_string())
-    }
-
-    pub async fn signout() -> Result<String, ServerFnError> {
-        debug!("Signing out");
-        
-        // Placeholder implementation - implement actual sign-out logic here
-        Ok("Sign-out not fully implemented".to_string())
-    }
-
-    pub async fn set_session(_access_token: String, _refresh_token: String) -> Result<String, ServerFnError> {
-        debug!("Setting session with tokens");
-        
-        // Placeholder implementation - implement actual session management here
-        Ok("Session management not fully implemented".to_string())
-    }
-
-    // Server client for making API calls
-    pub struct ServerSupabaseClient {
-        pub url: String,
-        pub api_key: String,
-    }
-
-    impl ServerSupabaseClient {
-        pub fn new(url: &str, api_key: &str) -> Self {
-            Self {
-                url: url.to_string(),
-                api_key: api_key.to_string(),
-            }
-        }
-
-        pub fn table(&self, _table_name: &str) -> String {
-            // Placeholder implementation for WASM
-            "table access not implemented for WASM".to_string()
-        }
-    }
-
-    pub fn create_server_client() -> ServerSupabaseClient {
-        let config = env::get_env_config();
-        ServerSupabaseClient::new(
-            &config.supabase_url,
-            &config.supabase_anon_key,
-        )
-    }
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-mod native_impl {
-    use dioxus::prelude::ServerFnError;
-    use crate::api::env;
-
-    use crate::api::auth::{User, Credentials};
-
-    pub async fn get_user() -> Option<User> {
-        // For native builds, return None
-        None
-    }
-
-    pub async fn signin_with_password(_credentials: Credentials) -> Result<String, ServerFnError> {
-        Ok("Authentication not available in native builds".to_string())
-    }
-
-    pub async fn signin_with_google() -> Result<String, ServerFnError> {
-        Ok("Google sign-in not available in native builds".to_string())
-    }
-
-    pub async fn signout() -> Result<String, ServerFnError> {
-        Ok("Sign-out not available in native builds".to_string())
-    }
-
-    pub async fn set_session(_access_token: String, _refresh_token: String) -> Result<String, ServerFnError> {
-        Ok("Session management not available in native builds".to_string())
-    }
-
-    // Server client for making API calls
-    pub struct ServerSupabaseClient {
-        pub client: postgrest::Postgrest,
-    }
-
-    impl ServerSupabaseClient {
-        pub fn new(url: &str, api_key: &str) -> Self {
-            let client = postgrest::Postgrest::new(&format!("{}/rest/v1", url))
-                .insert_header("apikey", api_key)
-                .insert_header("Authorization", &format!("Bearer {}", api_key));
-            
-            Self { client }
-        }
-
-        pub fn table(&self, table_name: &str) -> postgrest::Builder {
-            self.client.from(table_name)
-        }
-    }
-
-    pub fn create_server_client() -> ServerSupabaseClient {
-        let config = env::get_env_config();
-        ServerSupabaseClient::new(
-            &config.supabase_url,
-            &config.supabase_anon_key,
-        )
-    }
-}
-
-// Re-export the platform-specific implementations
-#[cfg(target_arch = "wasm32")]
-pub use wasm_impl::*;
-
-#[cfg(not(target_arch = "wasm32"))]
-pub use native_impl::*;
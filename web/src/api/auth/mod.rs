@@ -1,8 +1,5 @@
 //! Authentication module with platform-specific implementations
 
-pub mod auth;
-
-
 // Shared types
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone)]
 pub struct Credentials {
@@ -10,7 +7,7 @@ pub struct Credentials {
     pub password: String,
 }
 
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, PartialEq)]
 pub struct User {
     pub id: String,
     pub email: String,
@@ -28,6 +25,46 @@ pub struct User {
     pub updated_at: String,
 }
 
+/// A signed-in Supabase session, as returned by the GoTrue `token` endpoint.
+/// `expires_at` is the access token's expiry in Unix seconds.
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone)]
+pub struct Session {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub expires_at: i64,
+    pub user: User,
+}
+
+/// Server function client that signs requests with the current session's access token,
+/// so the server can tell who is calling. Use it as `#[server(client = AuthClient)]`.
+pub struct AuthClient;
+
+impl<CustErr> dioxus::prelude::server_fn::client::Client<CustErr> for AuthClient {
+    type Request = dioxus::prelude::server_fn::request::browser::BrowserRequest;
+    type Response = dioxus::prelude::server_fn::response::browser::BrowserResponse;
+
+    fn send(
+        req: Self::Request,
+    ) -> impl std::future::Future<Output = Result<Self::Response, dioxus::prelude::ServerFnError<CustErr>>> + Send {
+        use dioxus::prelude::server_fn::client::{browser::BrowserClient, Client};
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            // Refreshing the session is a browser future, which is fine: wasm is single threaded
+            send_wrapper::SendWrapper::new(async move {
+                if let Some(session) = get_session().await {
+                    req.headers().set("Authorization", &format!("Bearer {}", session.access_token));
+                }
+                <BrowserClient as Client<CustErr>>::send(req).await
+            })
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            <BrowserClient as Client<CustErr>>::send(req)
+        }
+    }
+}
+
 // Platform-specific implementations
 #[cfg(target_arch = "wasm32")]
 mod wasm_impl;
@@ -3,7 +3,12 @@
 use dioxus::prelude::ServerFnError;
 use crate::api::env;
 
-use super::{User, Credentials};
+use super::{User, Credentials, Session};
+
+pub async fn get_session() -> Option<Session> {
+    // Sessions live in browser storage, so there is none outside the web client
+    None
+}
 
 pub async fn get_user() -> Option<User> {
     // For native builds, return None
@@ -33,16 +38,26 @@ pub struct ServerSupabaseClient {
 
 impl ServerSupabaseClient {
     pub fn new(url: &str, api_key: &str) -> Self {
+        Self::with_access_token(url, api_key, api_key)
+    }
+
+    /// A client whose requests run as the owner of `access_token`, so row-level security applies
+    pub fn with_access_token(url: &str, api_key: &str, access_token: &str) -> Self {
         let client = postgrest::Postgrest::new(&format!("{}/rest/v1", url))
             .insert_header("apikey", api_key)
-            .insert_header("Authorization", &format!("Bearer {}", api_key));
-        
+            .insert_header("Authorization", &format!("Bearer {}", access_token));
+
         Self { client }
     }
 
     pub fn table(&self, table_name: &str) -> postgrest::Builder {
         self.client.from(table_name)
     }
+
+    /// Call the database function `function` with `params`, a JSON object of its arguments
+    pub fn rpc(&self, function: &str, params: String) -> postgrest::Builder {
+        self.client.rpc(function, params)
+    }
 }
 
 pub fn create_server_client() -> ServerSupabaseClient {
@@ -52,3 +67,146 @@ pub fn create_server_client() -> ServerSupabaseClient {
         &config.supabase_anon_key,
     )
 }
+
+/// Client that runs as the service role, past row-level security. Only for rows the server
+/// makes itself and callers must not write, such as the results in the roll log.
+pub fn create_service_client() -> Result<ServerSupabaseClient, ServerFnError> {
+    let config = env::get_env_config();
+    let service_key = config
+        .supabase_service_role_key
+        .as_deref()
+        .ok_or_else(|| ServerFnError::new("SUPABASE_SERVICE_ROLE_KEY is not set".to_string()))?;
+    Ok(ServerSupabaseClient::with_access_token(&config.supabase_url, service_key, service_key))
+}
+
+/// Client for queries made on behalf of a signed-in user
+pub fn create_user_client(user: &AuthenticatedUser) -> ServerSupabaseClient {
+    let config = env::get_env_config();
+    ServerSupabaseClient::with_access_token(
+        &config.supabase_url,
+        &config.supabase_anon_key,
+        &user.access_token,
+    )
+}
+
+
+
+/// The caller of a server function, taken from the verified bearer token on the request.
+///
+/// Inside a server function: `let user: AuthenticatedUser = extract().await?;`
+#[derive(Debug, Clone)]
+pub struct AuthenticatedUser {
+    pub id: String,
+    pub email: Option<String>,
+    pub access_token: String,
+}
+
+/// The claims we rely on from a Supabase access token
+#[derive(Debug, serde::Deserialize)]
+struct Claims {
+    sub: String,
+    email: Option<String>,
+    role: String,
+    exp: i64,
+    /// A single audience or a list of them
+    #[serde(default)]
+    aud: serde_json::Value,
+}
+
+impl AuthenticatedUser {
+    /// Verify a Supabase access token against `SUPABASE_JWT_SECRET`
+    pub fn from_access_token(access_token: &str) -> Result<Self, ServerFnError> {
+        let secret = env::get_env_config()
+            .supabase_jwt_secret
+            .as_deref()
+            .ok_or_else(|| ServerFnError::new("SUPABASE_JWT_SECRET is not set".to_string()))?;
+        let claims = verify_hs256(access_token, secret).map_err(ServerFnError::new)?;
+
+        if claims.exp <= chrono::Utc::now().timestamp() {
+            return Err(ServerFnError::new("Session expired, please sign in again".to_string()));
+        }
+        if claims.role != "authenticated" {
+            return Err(ServerFnError::new("Not signed in".to_string()));
+        }
+
+        Ok(Self {
+            id: claims.sub,
+            email: claims.email,
+            access_token: access_token.to_string(),
+        })
+    }
+
+    /// The bearer token from an `Authorization` header value, if it is one
+    fn bearer_token(header: &str) -> Option<&str> {
+        header
+            .strip_prefix("Bearer ")
+            .or_else(|| header.strip_prefix("bearer "))
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+    }
+}
+
+#[cfg(feature = "server")]
+#[async_trait::async_trait]
+impl dioxus::prelude::FromServerContext for AuthenticatedUser {
+    type Rejection = ServerFnError;
+
+    async fn from_request(req: &dioxus::prelude::DioxusServerContext) -> Result<Self, Self::Rejection> {
+        let header = req
+            .request_parts()
+            .headers
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+            .ok_or_else(|| ServerFnError::new("Not signed in".to_string()))?;
+        let token = Self::bearer_token(&header)
+            .ok_or_else(|| ServerFnError::new("Malformed Authorization header".to_string()))?;
+        Self::from_access_token(token)
+    }
+}
+
+/// Check an HS256 JWT's signature and audience and return its claims. Expiry is left to the caller.
+fn verify_hs256(token: &str, secret: &str) -> Result<Claims, String> {
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use base64::Engine;
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    let mut parts = token.split('.');
+    let (Some(header_b64), Some(payload_b64), Some(signature_b64), None) = (parts.next(), parts.next(), parts.next(), parts.next()) else {
+        return Err("Malformed access token".to_string());
+    };
+
+    let header: serde_json::Value = URL_SAFE_NO_PAD
+        .decode(header_b64)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .ok_or_else(|| "Malformed access token header".to_string())?;
+    if header.get("alg").and_then(|alg| alg.as_str()) != Some("HS256") {
+        return Err("Unsupported access token algorithm".to_string());
+    }
+
+    let signature = URL_SAFE_NO_PAD
+        .decode(signature_b64)
+        .map_err(|_| "Malformed access token signature".to_string())?;
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).map_err(|e| e.to_string())?;
+    mac.update(format!("{}.{}", header_b64, payload_b64).as_bytes());
+    mac.verify_slice(&signature)
+        .map_err(|_| "Invalid access token signature".to_string())?;
+
+    let payload = URL_SAFE_NO_PAD
+        .decode(payload_b64)
+        .map_err(|_| "Malformed access token payload".to_string())?;
+    let claims = serde_json::from_slice::<Claims>(&payload).map_err(|e| format!("Invalid access token claims: {}", e))?;
+
+    // Supabase issues user sessions for the "authenticated" audience; anything else isn't one
+    let for_users = match &claims.aud {
+        serde_json::Value::String(aud) => aud == "authenticated",
+        serde_json::Value::Array(auds) => auds.iter().any(|aud| aud.as_str() == Some("authenticated")),
+        _ => false,
+    };
+    if !for_users {
+        return Err("Access token is not for this audience".to_string());
+    }
+    Ok(claims)
+}
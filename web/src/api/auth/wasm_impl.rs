@@ -1,55 +1,237 @@
 //! WASM-specific authentication implementation
+//!
+//! Talks to the Supabase GoTrue REST endpoints (`/auth/v1/...`) directly and keeps the
+//! resulting session in local storage. Access tokens are short lived, so every read of
+//! the session refreshes it first when it is about to expire; GoTrue rotates the refresh
+//! token on each refresh and the new one replaces the old in storage. Only one refresh runs
+//! at a time: a refresh token is spent by its first use, so callers that find the session
+//! expiring while a refresh is under way wait for that one instead of starting their own.
 
+use std::cell::RefCell;
 use dioxus::prelude::ServerFnError;
-use gloo::storage::Storage;
-use supabase_js_rs::*;
-use dioxus::signals::{GlobalSignal, Signal};
+use futures::future::{FutureExt, LocalBoxFuture, Shared};
+use gloo::storage::{LocalStorage, Storage};
 use tracing::debug;
 use crate::api::env;
-use dioxus::prelude::Readable;
-use super::{User, Credentials};
+use super::{User, Credentials, Session};
 
-pub static CLIENT: GlobalSignal<SupabaseClient> = Signal::global(create_client);
+/// Refresh this many seconds before the access token actually expires.
+const EXPIRY_MARGIN_SECS: i64 = 60;
 
-fn create_client() -> SupabaseClient {
+fn storage_key() -> String {
+    // Same key supabase-js uses, so sessions survive switching between the two clients
+    format!("sb-{}-auth-token", env::get_env_config().supabase_project_id)
+}
+
+fn auth_url(path: &str) -> String {
+    format!("{}/auth/v1/{}", env::get_env_config().supabase_url, path)
+}
+
+fn now_secs() -> i64 {
+    (js_sys::Date::now() / 1000.0) as i64
+}
+
+fn stored_session() -> Option<Session> {
+    LocalStorage::get::<Session>(storage_key()).ok()
+}
+
+fn store_session(session: &Session) -> Result<(), ServerFnError> {
+    LocalStorage::set(storage_key(), session)
+        .map_err(|e| ServerFnError::new(format!("Failed to store session: {}", e)))
+}
+
+fn clear_session() {
+    LocalStorage::delete(storage_key());
+}
+
+/// Pull a readable message out of a GoTrue error body, which uses a few different shapes.
+fn error_message(text: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(text)
+        .ok()
+        .and_then(|body| {
+            ["error_description", "msg", "message", "error"]
+                .iter()
+                .find_map(|key| body.get(*key).and_then(|v| v.as_str()).map(str::to_string))
+        })
+        .unwrap_or_else(|| text.to_string())
+}
+
+/// Why a GoTrue `token` grant failed
+#[derive(Clone, Debug)]
+enum GrantError {
+    /// GoTrue turned the grant down, e.g. a wrong password or a spent or revoked refresh token
+    Rejected(String),
+    /// GoTrue couldn't be reached or failed to answer
+    Failed(String),
+}
+
+impl std::fmt::Display for GrantError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GrantError::Rejected(message) | GrantError::Failed(message) => f.write_str(message),
+        }
+    }
+}
+
+/// POST to a GoTrue `token` grant and store the session it hands back.
+async fn grant(grant_type: &str, body: serde_json::Value) -> Result<Session, GrantError> {
     let config = env::get_env_config();
-    supabase_js_rs::create_client(&config.supabase_url, &config.supabase_anon_key)
+    let response = reqwest::Client::new()
+        .post(auth_url(&format!("token?grant_type={}", grant_type)))
+        .header("apikey", &config.supabase_anon_key)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| GrantError::Failed(format!("Request failed: {}", e)))?;
+
+    let status = response.status();
+    let text = response.text().await.map_err(|e| GrantError::Failed(e.to_string()))?;
+    if !status.is_success() {
+        debug!("GoTrue {} grant failed with status {}: {}", grant_type, status, text);
+        // GoTrue answers a bad grant, such as an invalid_grant refresh token, with a 400 or 401
+        return Err(match status.as_u16() {
+            400 | 401 => GrantError::Rejected(error_message(&text)),
+            _ => GrantError::Failed(error_message(&text)),
+        });
+    }
+
+    let session = serde_json::from_str::<Session>(&text)
+        .map_err(|e| GrantError::Failed(format!("Failed to parse session JSON: {}", e)))?;
+    store_session(&session).map_err(|e| GrantError::Failed(e.to_string()))?;
+    Ok(session)
+}
+
+async fn request_session(grant_type: &str, body: serde_json::Value) -> Result<Session, ServerFnError> {
+    grant(grant_type, body).await.map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+async fn refresh_session(refresh_token: String) -> Result<Session, GrantError> {
+    debug!("Refreshing session");
+    grant("refresh_token", serde_json::json!({ "refresh_token": refresh_token })).await
+}
+
+type Refresh = Shared<LocalBoxFuture<'static, Result<Session, GrantError>>>;
+
+thread_local! {
+    /// The refresh under way, shared by everyone waiting on it
+    static REFRESHING: RefCell<Option<Refresh>> = const { RefCell::new(None) };
+}
+
+/// The current session, refreshed first if the access token is (nearly) expired.
+/// A session whose refresh token GoTrue rejects is dropped, which signs the user out; one
+/// that only failed to refresh, say while offline, is kept and tried again next time.
+pub async fn get_session() -> Option<Session> {
+    let session = stored_session()?;
+    if session.expires_at - EXPIRY_MARGIN_SECS > now_secs() {
+        return Some(session);
+    }
+
+    let refresh = REFRESHING.with(|refreshing| {
+        refreshing
+            .borrow_mut()
+            .get_or_insert_with(|| refresh_stored(session.refresh_token.clone()).boxed_local().shared())
+            .clone()
+    });
+    match refresh.await {
+        Ok(refreshed) => Some(refreshed),
+        Err(GrantError::Rejected(_)) => None,
+        Err(GrantError::Failed(e)) => {
+            debug!("Session refresh failed, keeping the session: {}", e);
+            Some(session)
+        }
+    }
+}
+
+async fn refresh_stored(refresh_token: String) -> Result<Session, GrantError> {
+    let result = refresh_session(refresh_token.clone()).await;
+    REFRESHING.with(|refreshing| refreshing.borrow_mut().take());
+    match result {
+        Ok(refreshed) => Ok(refreshed),
+        Err(e) => match stored_session() {
+            // Another tab may have spent the token first and stored the session it got back
+            Some(current) if current.refresh_token != refresh_token => Ok(current),
+            _ => {
+                if let GrantError::Rejected(reason) = &e {
+                    debug!("Refresh token rejected, signing out: {}", reason);
+                    clear_session();
+                }
+                Err(e)
+            }
+        },
+    }
 }
 
 pub async fn get_user() -> Option<User> {
-    // Get user from Supabase client
-    let client = CLIENT.cloned();
-    
-    // For now, return None - implement actual user retrieval logic here
-    None
+    get_session().await.map(|session| session.user)
 }
 
 pub async fn signin_with_password(credentials: Credentials) -> Result<String, ServerFnError> {
     debug!("Signing in with password for email: {}", credentials.email);
-    
-    // Placeholder implementation - implement actual Supabase auth here
-    Ok("WASM sign-in not fully implemented".to_string())
+
+    let session = request_session(
+        "password",
+        serde_json::json!({ "email": credentials.email, "password": credentials.password }),
+    )
+    .await?;
+    Ok(format!("Signed in as {}", session.user.email))
 }
 
 pub async fn signin_with_google() -> Result<String, ServerFnError> {
     debug!("Signing in with Google");
-    
-    // Placeholder implementation - implement actual Google OAuth here
-    Ok("Google sign-in not fully implemented".to_string())
+
+    let window = web_sys::window().ok_or_else(|| ServerFnError::new("No window available".to_string()))?;
+    let origin = window
+        .location()
+        .origin()
+        .map_err(|_| ServerFnError::new("Could not read page origin".to_string()))?;
+    let query = serde_urlencoded::to_string([
+        ("provider", "google".to_string()),
+        ("redirect_to", format!("{}/callback", origin)),
+    ])
+    .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    // GoTrue sends the browser on to Google and back to /callback with the tokens in the hash
+    window
+        .location()
+        .set_href(&auth_url(&format!("authorize?{}", query)))
+        .map_err(|_| ServerFnError::new("Failed to redirect to Google".to_string()))?;
+    Ok("Redirecting to Google".to_string())
 }
 
 pub async fn signout() -> Result<String, ServerFnError> {
     debug!("Signing out");
-    
-    // Placeholder implementation - implement actual sign-out logic here
-    Ok("Sign-out not fully implemented".to_string())
+
+    let Some(session) = stored_session() else {
+        return Ok("Already signed out".to_string());
+    };
+    // Always forget the local session, even if revoking it on the server fails
+    clear_session();
+
+    let config = env::get_env_config();
+    let response = reqwest::Client::new()
+        .post(auth_url("logout"))
+        .header("apikey", &config.supabase_anon_key)
+        .bearer_auth(&session.access_token)
+        .send()
+        .await
+        .map_err(|e| ServerFnError::new(format!("Request failed: {}", e)))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let text = response.text().await.unwrap_or_default();
+        debug!("GoTrue logout failed with status {}: {}", status, text);
+        return Err(ServerFnError::new(error_message(&text)));
+    }
+    Ok("Signed out".to_string())
 }
 
-pub async fn set_session(access_token: String, refresh_token: String) -> Result<String, ServerFnError> {
+/// Adopt the tokens handed back by an OAuth redirect. The refresh token is exchanged
+/// straight away, which both validates it and gives us the expiry and user for the session.
+pub async fn set_session(_access_token: String, refresh_token: String) -> Result<String, ServerFnError> {
     debug!("Setting session with tokens");
-    
-    // Placeholder implementation - implement actual session management here
-    Ok("Session management not fully implemented".to_string())
+
+    let session = refresh_session(refresh_token).await?;
+    Ok(format!("Signed in as {}", session.user.email))
 }
 
 // Server client for making API calls
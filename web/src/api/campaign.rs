@@ -0,0 +1,1761 @@
+use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use chrono::NaiveDate;
+use dioxus::prelude::*;
+use dioxus::prelude::server_fn::codec::{StreamingText, TextStream};
+use crate::api::homebrew::HomebrewPack;
+use crate::rules::combat::Encounter;
+use crate::rules::dice::RollResult;
+use crate::rules::play::{Condition, DeathSaves};
+
+
+
+// Campaign structures shared by the campaign views.
+// Every row below the campaign itself carries the `campaign_id` it belongs to.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Campaign {
+    pub id: i32,
+    pub name: String,
+    pub slug: String,
+    pub description: String,
+    pub current_location: String,
+    pub next_session: Option<NaiveDate>,
+    /// Kept in `campaign_dm_notes`, which only the DM can read; empty for everyone else
+    #[serde(default)]
+    pub dm_notes: String,
+    pub owner: String,
+    #[serde(default)]
+    pub archived: bool,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Quest {
+    pub id: i32,
+    pub campaign_id: i32,
+    pub title: String,
+    pub description: String,
+    pub status: QuestStatus,
+    pub progress: i32, // 0-100%
+    pub npc_id: Option<i32>, // NPC who gave the quest
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum QuestStatus {
+    Available,
+    Active,
+    OnHold,
+    Completed,
+    Failed,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct CampaignEvent {
+    pub id: i32,
+    pub campaign_id: i32,
+    pub title: String,
+    pub date: NaiveDate,
+    pub description: String,
+    pub impact: EventImpact,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum EventImpact {
+    Positive,
+    Neutral,
+    Negative,
+    MajorPlotPoint,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PartyMember {
+    pub id: i32,
+    pub campaign_id: i32,
+    pub character_id: Option<i32>,
+    pub name: String,
+    pub player: String,
+    pub level: i32,
+    pub status: CharacterCondition,
+    pub notes: String,
+    /// Whether the owner of `character_id` accepted the DM's invite. Until they do, the link
+    /// gives neither side any access. Set by the database, never by a save.
+    #[serde(default)]
+    pub accepted: bool,
+}
+
+impl PartyMember {
+    /// The linked character, once its owner accepted the invite
+    pub fn joined_character(&self) -> Option<i32> {
+        self.character_id.filter(|_| self.accepted)
+    }
+}
+
+/// One of the caller's campaigns, with the part they play in it
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct CampaignListing {
+    pub campaign: Campaign,
+    pub role: CampaignRole,
+}
+
+/// A DM's invite for one of the caller's characters to join their campaign's party
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PartyInvite {
+    /// The party row the invite is for
+    pub id: i32,
+    pub campaign_id: i32,
+    pub campaign_name: String,
+    pub character_id: i32,
+    pub character_name: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum CharacterCondition {
+    Healthy,
+    Injured,
+    Cursed,
+    SpecialCondition(String),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Adventure {
+    pub id: i32,
+    pub campaign_id: i32,
+    pub title: String,
+    pub date_played: String,
+    pub character_used: String,
+    pub character_level: i32,
+    pub summary: String,
+    #[serde(default)]
+    pub rewards: Vec<String>,
+    #[serde(default)]
+    pub notable_events: Vec<String>,
+    /// Kept in `adventure_dm_notes`, which only the DM can read; `None` for everyone else
+    pub dm_notes: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct LoreCategory {
+    pub id: i32,
+    pub campaign_id: i32,
+    pub title: String,
+    pub description: String,
+    // Filled in by `get_lore`; not a column
+    #[serde(default, skip_serializing)]
+    pub entries: Vec<LoreEntry>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct LoreEntry {
+    pub id: i32,
+    pub campaign_id: i32,
+    pub category_id: i32,
+    pub title: String,
+    pub content: String,
+    #[serde(default)]
+    pub related_entries: Vec<i32>, // IDs of related entries
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct NPC {
+    pub id: i32,
+    pub campaign_id: i32,
+    pub name: String,
+    pub race: String,
+    pub role: String,
+    pub location: String,
+    pub affiliation: String,
+    pub description: String,
+    pub personality: String,
+    pub appearance: String,
+    pub relationship: String, // Party's relationship status
+    #[serde(default)]
+    pub important_notes: Vec<String>,
+    pub portrait: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct MapLocation {
+    pub id: i32,
+    pub campaign_id: i32,
+    pub name: String,
+    pub description: String,
+    pub x: f32, // Relative coordinates (0-100)
+    pub y: f32,
+    pub location_type: LocationType,
+    pub is_visited: bool,
+    #[serde(default)]
+    pub important_notes: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LocationType {
+    City,
+    Dungeon,
+    Landmark,
+    Settlement,
+    PointOfInterest,
+}
+
+/// A fight the DM has set up or is running, saved so it can be resumed.
+/// `state` is the whole [`Encounter`]: combatants, initiative, round and whose turn it is.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct EncounterRecord {
+    pub id: i32,
+    pub campaign_id: i32,
+    pub name: String,
+    #[serde(default)]
+    pub state: Encounter,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+/// Who besides the DM and the roller sees a roll in the campaign's roll log
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum RollVisibility {
+    #[default]
+    Public,
+    /// Only the DM and the roller
+    Dm,
+    /// The player of the character in `whisper_to`
+    Whisper,
+}
+
+impl RollVisibility {
+    pub const ALL: [RollVisibility; 3] = [RollVisibility::Public, RollVisibility::Dm, RollVisibility::Whisper];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            RollVisibility::Public => "Everyone",
+            RollVisibility::Dm => "DM only",
+            RollVisibility::Whisper => "Whisper",
+        }
+    }
+}
+
+/// One roll in a campaign's roll log, with every die it threw
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct RollEntry {
+    pub id: i32,
+    pub campaign_id: i32,
+    /// User id of whoever rolled
+    pub roller: String,
+    /// The character's name for rolls made as a character, otherwise "DM" or the player
+    pub roller_name: String,
+    pub character_id: Option<i32>,
+    /// What the roll was for, e.g. "Perception check"
+    pub purpose: String,
+    pub expression: String,
+    pub result: RollResult,
+    pub total: i32,
+    #[serde(default)]
+    pub visibility: RollVisibility,
+    /// The party character whose player a whisper goes to
+    pub whisper_to: Option<i32>,
+    pub created_at: Option<String>,
+}
+
+impl RollEntry {
+    /// Whether the user `user_id`, holding `role` and playing `characters`, sees this roll
+    pub fn visible_to(&self, user_id: &str, role: CampaignRole, characters: &HashSet<i32>) -> bool {
+        role == CampaignRole::Dm
+            || self.roller == user_id
+            || match self.visibility {
+                RollVisibility::Public => true,
+                RollVisibility::Dm => false,
+                RollVisibility::Whisper => self.whisper_to.is_some_and(|id| characters.contains(&id)),
+            }
+    }
+}
+
+/// A roll to add to a campaign's log; the server rolls `expression` and fills in who made it
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct NewRoll {
+    pub campaign_id: i32,
+    /// The caller's character the roll was made for, if any
+    pub character_id: Option<i32>,
+    pub purpose: String,
+    /// Dice notation, e.g. `1d20+5`
+    pub expression: String,
+    pub visibility: RollVisibility,
+    pub whisper_to: Option<i32>,
+}
+
+/// Rolls kept in a campaign's log as shown to players
+pub const ROLL_LOG_LENGTH: usize = 50;
+
+/// A signed-in user's part in a campaign. The campaign owner is its DM; anyone with one of
+/// their characters in the party is a member. Ordered so `role >= needed` checks access.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CampaignRole {
+    Member,
+    Dm,
+}
+
+
+
+// Shared Supabase plumbing for the campaign tables. Every server function below
+// goes through these so the error messages and logging stay the same per table.
+#[cfg(not(target_arch = "wasm32"))]
+mod rows {
+    use std::collections::HashSet;
+    use dioxus::prelude::*;
+    use serde::{de::DeserializeOwned, Deserialize, Serialize};
+    use tracing::info;
+    use crate::api::auth::{create_user_client, AuthenticatedUser, ServerSupabaseClient};
+    use super::{Campaign, CampaignRole};
+
+    #[derive(Deserialize)]
+    struct IdRow {
+        id: i32,
+    }
+
+    // Pass the body through on success, otherwise turn the status and body into an error
+    fn checked(success: bool, status: impl std::fmt::Display, text: String, action: &str, table: &str) -> Result<String, ServerFnError> {
+        if success {
+            info!("Raw Supabase response: {}", text);
+            Ok(text)
+        } else {
+            let error_msg = format!("Failed to {} {}. Status: {}, Response: {}", action, table, status, text);
+            info!("{}", error_msg);
+            Err(ServerFnError::new(error_msg))
+        }
+    }
+
+    fn parse<T: DeserializeOwned>(text: &str, table: &str) -> Result<T, ServerFnError> {
+        serde_json::from_str::<T>(text).map_err(|parse_error| {
+            info!("JSON parsing failed: {}", parse_error);
+            ServerFnError::new(format!("Failed to parse {} JSON: {}", table, parse_error))
+        })
+    }
+
+    // Serialize a row for insert/update. The id is never written; Supabase assigns it.
+    // `elsewhere` are fields kept in other tables.
+    fn body<T: Serialize>(row: &T, elsewhere: &[&str]) -> Result<String, ServerFnError> {
+        let mut value = serde_json::to_value(row).map_err(|e| ServerFnError::new(e.to_string()))?;
+        if let Some(object) = value.as_object_mut() {
+            object.remove("id");
+            object.remove("created_at");
+            for field in elsewhere {
+                object.remove(*field);
+            }
+        }
+        Ok(value.to_string())
+    }
+
+    // The campaign tables are behind row-level security, so every query runs as the caller
+    #[cfg(feature = "server")]
+    async fn caller() -> Result<ServerSupabaseClient, ServerFnError> {
+        let user: AuthenticatedUser = extract().await?;
+        Ok(create_user_client(&user))
+    }
+
+    /// All rows of `table` matching `column = value`, in `order`
+    #[cfg(feature = "server")]
+    pub async fn list<T: DeserializeOwned>(table: &str, column: &str, value: String, order: &str) -> Result<Vec<T>, ServerFnError> {
+        list_with(&caller().await?, table, column, value, order).await
+    }
+
+    #[cfg(feature = "server")]
+    async fn list_with<T: DeserializeOwned>(
+        client: &ServerSupabaseClient,
+        table: &str,
+        column: &str,
+        value: String,
+        order: &str,
+    ) -> Result<Vec<T>, ServerFnError> {
+        info!("Fetching {} where {} = {} from Supabase...", table, column, value);
+
+        let response = client
+            .table(table)
+            .select("*")
+            .eq(column, value)
+            .order(order)
+            .limit(1000)
+            .execute()
+            .await
+            .map_err(|e| ServerFnError::new(format!("Request failed: {}", e)))?;
+
+        let status = response.status();
+        let text = response.text().await.map_err(|e| ServerFnError::new(e.to_string()))?;
+        let text = checked(status.is_success(), status, text, "fetch", table)?;
+        parse(&text, table)
+    }
+
+    /// The single row of `table` matching `column = value`
+    #[cfg(feature = "server")]
+    pub async fn single<T: DeserializeOwned>(table: &str, column: &str, value: String) -> Result<T, ServerFnError> {
+        single_with(&caller().await?, table, column, value).await
+    }
+
+    #[cfg(feature = "server")]
+    async fn single_with<T: DeserializeOwned>(client: &ServerSupabaseClient, table: &str, column: &str, value: String) -> Result<T, ServerFnError> {
+        info!("Fetching {} where {} = {} from Supabase...", table, column, value);
+
+        let response = client
+            .table(table)
+            .select("*")
+            .eq(column, value.clone())
+            .single()
+            .execute()
+            .await
+            .map_err(|e| ServerFnError::new(format!("Request failed: {}", e)))?;
+
+        if response.status().as_u16() == 406 {
+            return Err(ServerFnError::new(format!("No {} row with {} '{}' found", table, column, value)));
+        }
+        let status = response.status();
+        let text = response.text().await.map_err(|e| ServerFnError::new(e.to_string()))?;
+        let text = checked(status.is_success(), status, text, "fetch", table)?;
+        parse(&text, table)
+    }
+
+    /// Insert `row` when `id` is 0, otherwise update the row with that id. Returns the stored row.
+    #[cfg(feature = "server")]
+    pub async fn save<T: Serialize + DeserializeOwned>(table: &str, id: i32, row: &T) -> Result<T, ServerFnError> {
+        save_except(table, id, row, &[]).await
+    }
+
+    /// Like [`save`], leaving out the fields in `elsewhere`, which aren't columns of `table`
+    #[cfg(feature = "server")]
+    pub async fn save_except<T: Serialize + DeserializeOwned>(table: &str, id: i32, row: &T, elsewhere: &[&str]) -> Result<T, ServerFnError> {
+        let client = caller().await?;
+        let body = body(row, elsewhere)?;
+
+        let request = if id == 0 {
+            info!("Inserting into {}...", table);
+            client.table(table).insert(body)
+        } else {
+            info!("Updating {} row {}...", table, id);
+            client.table(table).update(body).eq("id", id.to_string())
+        };
+
+        let response = request
+            .single()
+            .execute()
+            .await
+            .map_err(|e| ServerFnError::new(format!("Request failed: {}", e)))?;
+
+        if response.status().as_u16() == 406 {
+            return Err(ServerFnError::new(format!("No {} row with id {} found", table, id)));
+        }
+        let status = response.status();
+        let text = response.text().await.map_err(|e| ServerFnError::new(e.to_string()))?;
+        let text = checked(status.is_success(), status, text, "save", table)?;
+        parse(&text, table)
+    }
+
+    /// Insert `row` into `table` as the server rather than the caller, for rows whose contents
+    /// only the server may decide. The caller's access is the server's to check first.
+    #[cfg(feature = "server")]
+    pub async fn insert_as_server<T: Serialize + DeserializeOwned>(table: &str, row: &T) -> Result<T, ServerFnError> {
+        info!("Inserting into {} as the server...", table);
+        let response = crate::api::auth::create_service_client()?
+            .table(table)
+            .insert(body(row, &[])?)
+            .single()
+            .execute()
+            .await
+            .map_err(|e| ServerFnError::new(format!("Request failed: {}", e)))?;
+        let status = response.status();
+        let text = response.text().await.map_err(|e| ServerFnError::new(e.to_string()))?;
+        let text = checked(status.is_success(), status, text, "save", table)?;
+        parse(&text, table)
+    }
+
+    /// Ids of the caller's own characters
+    async fn own_character_ids(user: &AuthenticatedUser) -> Result<HashSet<i32>, ServerFnError> {
+        let response = create_user_client(user)
+            .table("characters")
+            .select("id")
+            .eq("owner", &user.id)
+            .execute()
+            .await
+            .map_err(|e| ServerFnError::new(format!("Request failed: {}", e)))?;
+        let status = response.status();
+        let text = response.text().await.map_err(|e| ServerFnError::new(e.to_string()))?;
+        let text = checked(status.is_success(), status, text, "fetch", "characters")?;
+        Ok(parse::<Vec<IdRow>>(&text, "characters")?.into_iter().map(|c| c.id).collect())
+    }
+
+    /// The campaigns the caller plays in through one of their own characters
+    async fn member_campaign_ids(user: &AuthenticatedUser) -> Result<HashSet<i32>, ServerFnError> {
+        #[derive(Deserialize)]
+        struct PartyRow {
+            campaign_id: i32,
+        }
+
+        let character_ids: Vec<String> = own_character_ids(user).await?.into_iter().map(|id| id.to_string()).collect();
+        if character_ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let response = create_user_client(user)
+            .table("party_members")
+            .select("campaign_id")
+            .in_("character_id", &character_ids)
+            .eq("accepted", "true")
+            .execute()
+            .await
+            .map_err(|e| ServerFnError::new(format!("Request failed: {}", e)))?;
+        let status = response.status();
+        let text = response.text().await.map_err(|e| ServerFnError::new(e.to_string()))?;
+        let text = checked(status.is_success(), status, text, "fetch", "party_members")?;
+        Ok(parse::<Vec<PartyRow>>(&text, "party_members")?.into_iter().map(|p| p.campaign_id).collect())
+    }
+
+    /// Every campaign the caller runs or plays in
+    pub async fn campaign_ids(user: &AuthenticatedUser) -> Result<HashSet<i32>, ServerFnError> {
+        // Row-level security leaves exactly those campaigns
+        let response = create_user_client(user)
+            .table("campaigns")
+            .select("id")
+            .execute()
+            .await
+            .map_err(|e| ServerFnError::new(format!("Request failed: {}", e)))?;
+        let status = response.status();
+        let text = response.text().await.map_err(|e| ServerFnError::new(e.to_string()))?;
+        let text = checked(status.is_success(), status, text, "fetch", "campaigns")?;
+        Ok(parse::<Vec<IdRow>>(&text, "campaigns")?.into_iter().map(|c| c.id).collect())
+    }
+
+    /// The caller's role in each of `campaigns`, or `None` where they have no part in it
+    pub async fn roles(user: &AuthenticatedUser, campaigns: &[Campaign]) -> Result<Vec<Option<CampaignRole>>, ServerFnError> {
+        let member_of = member_campaign_ids(user).await?;
+        Ok(campaigns
+            .iter()
+            .map(|campaign| {
+                if campaign.owner == user.id {
+                    Some(CampaignRole::Dm)
+                } else if member_of.contains(&campaign.id) {
+                    Some(CampaignRole::Member)
+                } else {
+                    None
+                }
+            })
+            .collect())
+    }
+
+    /// The caller's role in campaign `campaign_id`, provided it is at least `needed`
+    #[cfg(feature = "server")]
+    pub async fn require_role(campaign_id: i32, needed: CampaignRole) -> Result<CampaignRole, ServerFnError> {
+        let campaign: Campaign = single("campaigns", "id", campaign_id.to_string()).await?;
+        role_in(&campaign, needed).await
+    }
+
+    #[cfg(feature = "server")]
+    async fn role_in(campaign: &Campaign, needed: CampaignRole) -> Result<CampaignRole, ServerFnError> {
+        let user: AuthenticatedUser = extract().await?;
+        match roles(&user, std::slice::from_ref(campaign)).await?[0] {
+            Some(role) if role >= needed => Ok(role),
+            Some(_) => Err(ServerFnError::new("Only the campaign's DM can do that".to_string())),
+            None => Err(ServerFnError::new("You are not part of this campaign".to_string())),
+        }
+    }
+
+    /// `campaign` as the caller may see it, provided they hold at least `needed` in it.
+    /// DM notes are only filled in for the DM.
+    #[cfg(feature = "server")]
+    pub async fn for_caller(mut campaign: Campaign, needed: CampaignRole) -> Result<(Campaign, CampaignRole), ServerFnError> {
+        let role = role_in(&campaign, needed).await?;
+        fill_dm_notes(std::slice::from_mut(&mut campaign)).await?;
+        Ok((campaign, role))
+    }
+
+    #[cfg(feature = "server")]
+    #[derive(Deserialize)]
+    struct CampaignNotes {
+        campaign_id: i32,
+        notes: String,
+    }
+
+    /// Fill in the DM notes of `campaigns`. Row-level security only hands the notes of the
+    /// campaigns the caller runs, so the others keep theirs empty.
+    #[cfg(feature = "server")]
+    pub async fn fill_dm_notes(campaigns: &mut [Campaign]) -> Result<(), ServerFnError> {
+        use crate::api::character::fetch_rows;
+
+        if campaigns.is_empty() {
+            return Ok(());
+        }
+        let ids: Vec<String> = campaigns.iter().map(|campaign| campaign.id.to_string()).collect();
+        let client = caller().await?;
+        let notes: Vec<CampaignNotes> =
+            fetch_rows(client.table("campaign_dm_notes").select("*").in_("campaign_id", &ids), "campaign DM notes").await?;
+        for campaign in campaigns.iter_mut() {
+            campaign.dm_notes = notes
+                .iter()
+                .find(|note| note.campaign_id == campaign.id)
+                .map(|note| note.notes.clone())
+                .unwrap_or_default();
+        }
+        Ok(())
+    }
+
+    /// Store the DM notes of campaign `campaign_id`
+    #[cfg(feature = "server")]
+    pub async fn save_dm_notes(campaign_id: i32, notes: &str) -> Result<(), ServerFnError> {
+        use crate::api::character::fetch_rows;
+
+        let body = serde_json::json!({ "campaign_id": campaign_id, "notes": notes }).to_string();
+        let request = caller().await?.table("campaign_dm_notes").upsert(body).on_conflict("campaign_id");
+        fetch_rows::<serde_json::Value>(request, "campaign DM notes").await?;
+        Ok(())
+    }
+
+    #[cfg(feature = "server")]
+    #[derive(Deserialize)]
+    pub struct AdventureNotes {
+        pub adventure_id: i32,
+        pub notes: String,
+    }
+
+    /// The DM notes of the adventures in campaign `campaign_id`; none unless the caller is its DM
+    #[cfg(feature = "server")]
+    pub async fn adventure_dm_notes(campaign_id: i32) -> Result<Vec<AdventureNotes>, ServerFnError> {
+        list("adventure_dm_notes", "campaign_id", campaign_id.to_string(), "adventure_id.asc").await
+    }
+
+    /// Store or, with `None`, clear the DM notes of adventure `adventure_id`
+    #[cfg(feature = "server")]
+    pub async fn save_adventure_dm_notes(adventure_id: i32, campaign_id: i32, notes: Option<&str>) -> Result<(), ServerFnError> {
+        use crate::api::character::fetch_rows;
+
+        let table = caller().await?.table("adventure_dm_notes");
+        let request = match notes {
+            Some(notes) => {
+                let body = serde_json::json!({ "adventure_id": adventure_id, "campaign_id": campaign_id, "notes": notes });
+                table.upsert(body.to_string()).on_conflict("adventure_id")
+            }
+            None => table.delete().eq("adventure_id", adventure_id.to_string()),
+        };
+        fetch_rows::<serde_json::Value>(request, "adventure DM notes").await?;
+        Ok(())
+    }
+
+    /// Refuse to move row `id` of `table` out of the campaign it is stored in; `what` names
+    /// the rows in the error. New rows (id 0) may go into any campaign.
+    #[cfg(feature = "server")]
+    pub async fn keep_campaign(table: &str, id: i32, campaign_id: i32, what: &str) -> Result<(), ServerFnError> {
+        if id != 0 && campaign_of(table, id).await? != campaign_id {
+            return Err(ServerFnError::new(format!("{} can't move between campaigns", what)));
+        }
+        Ok(())
+    }
+
+    /// Someone taking part in a campaign, and which of its party's characters they play
+    #[cfg(feature = "server")]
+    pub struct Viewer {
+        pub user: AuthenticatedUser,
+        pub role: CampaignRole,
+        pub characters: HashSet<i32>,
+    }
+
+    #[cfg(feature = "server")]
+    impl Viewer {
+        pub fn can_see(&self, event: &crate::api::live::LiveEvent) -> bool {
+            use crate::api::live::LiveEvent;
+            match event {
+                LiveEvent::Roll(entry) => entry.visible_to(&self.user.id, self.role, &self.characters),
+                event => self.role == CampaignRole::Dm || !event.dm_only(),
+            }
+        }
+    }
+
+    /// The caller as a participant of campaign `campaign_id`
+    #[cfg(feature = "server")]
+    pub async fn viewer(campaign_id: i32) -> Result<Viewer, ServerFnError> {
+        let user: AuthenticatedUser = extract().await?;
+        viewer_as(user, campaign_id).await
+    }
+
+    /// `user` as a participant of campaign `campaign_id`, for looking again once the request
+    /// that brought them is over, as a `watch_campaign` stream does
+    #[cfg(feature = "server")]
+    pub async fn viewer_as(user: AuthenticatedUser, campaign_id: i32) -> Result<Viewer, ServerFnError> {
+        let client = create_user_client(&user);
+        let campaign: Campaign = single_with(&client, "campaigns", "id", campaign_id.to_string()).await?;
+        let role = roles(&user, &[campaign])
+            .await?[0]
+            .ok_or_else(|| ServerFnError::new("You are not part of this campaign".to_string()))?;
+        let party: Vec<super::PartyMember> = list_with(&client, "party_members", "campaign_id", campaign_id.to_string(), "name.asc").await?;
+        let own = own_character_ids(&user).await?;
+        let characters = party.iter().filter_map(super::PartyMember::joined_character).filter(|id| own.contains(id)).collect();
+        Ok(Viewer { user, role, characters })
+    }
+
+    /// The campaign that the row of `table` with `id` belongs to
+    #[cfg(feature = "server")]
+    pub async fn campaign_of(table: &str, id: i32) -> Result<i32, ServerFnError> {
+        #[derive(Deserialize)]
+        struct CampaignIdRow {
+            campaign_id: i32,
+        }
+        let row: CampaignIdRow = single(table, "id", id.to_string()).await?;
+        Ok(row.campaign_id)
+    }
+
+    /// Delete the row of `table` with `id`
+    #[cfg(feature = "server")]
+    pub async fn delete(table: &str, id: i32) -> Result<(), ServerFnError> {
+        info!("Deleting {} row {}...", table, id);
+
+        let response = caller()
+            .await?
+            .table(table)
+            .delete()
+            .eq("id", id.to_string())
+            .execute()
+            .await
+            .map_err(|e| ServerFnError::new(format!("Request failed: {}", e)))?;
+
+        let status = response.status();
+        let text = response.text().await.map_err(|e| ServerFnError::new(e.to_string()))?;
+        let text = checked(status.is_success(), status, text, "delete", table)?;
+        let deleted: Vec<serde_json::Value> = parse(&text, table)?;
+        if deleted.is_empty() {
+            Err(ServerFnError::new(format!("No {} row with id {} found", table, id)))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+
+
+#[server(name = GetCampaign, client = crate::api::auth::AuthClient)]
+pub async fn get_campaign(id: i32) -> Result<Campaign, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let campaign = rows::single("campaigns", "id", id.to_string()).await?;
+        Ok(rows::for_caller(campaign, CampaignRole::Member).await?.0)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = GetCampaignWithSlug, client = crate::api::auth::AuthClient)]
+pub async fn get_campaign_with_slug(slug: String) -> Result<Campaign, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let campaign = rows::single("campaigns", "slug", slug).await?;
+        Ok(rows::for_caller(campaign, CampaignRole::Member).await?.0)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// The caller's role in the campaign with `campaign_slug`, or `None` if they have no part in it
+#[server(name = GetCampaignRole, client = crate::api::auth::AuthClient)]
+pub async fn get_campaign_role(campaign_slug: String) -> Result<Option<CampaignRole>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::AuthenticatedUser;
+
+        let user: AuthenticatedUser = extract().await?;
+        // Row-level security hides the campaigns the caller has no part in
+        let campaigns: Vec<Campaign> = rows::list("campaigns", "slug", campaign_slug, "id.asc").await?;
+        if campaigns.is_empty() {
+            return Ok(None);
+        }
+        Ok(rows::roles(&user, &campaigns).await?[0])
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = GetCampaigns, client = crate::api::auth::AuthClient)]
+pub async fn get_campaigns() -> Result<Vec<CampaignListing>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::{create_user_client, AuthenticatedUser};
+        use tracing::info;
+
+        let user: AuthenticatedUser = extract().await?;
+
+        info!("Fetching campaigns from Supabase...");
+
+        // Row-level security leaves only the campaigns the caller runs or plays in
+        let response = create_user_client(&user)
+            .table("campaigns")
+            .select("*")
+            .order("updated_at.desc.nullslast")
+            .limit(1000)
+            .execute()
+            .await
+            .map_err(|e| ServerFnError::new(format!("Request failed: {}", e)))?;
+
+        let status = response.status();
+        let text = response.text().await.map_err(|e| ServerFnError::new(e.to_string()))?;
+        if !status.is_success() {
+            return Err(ServerFnError::new(format!("Failed to fetch campaigns. Status: {}, Response: {}", status, text)));
+        }
+        let mut campaigns = serde_json::from_str::<Vec<Campaign>>(&text)
+            .map_err(|e| ServerFnError::new(format!("Failed to parse campaigns JSON: {}", e)))?;
+        rows::fill_dm_notes(&mut campaigns).await?;
+        let roles = rows::roles(&user, &campaigns).await?;
+        Ok(campaigns
+            .into_iter()
+            .zip(roles)
+            .filter_map(|(campaign, role)| role.map(|role| CampaignListing { campaign, role }))
+            .collect())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = SaveCampaign, client = crate::api::auth::AuthClient)]
+pub async fn save_campaign(campaign: Campaign) -> Result<Campaign, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::{create_user_client, AuthenticatedUser};
+        use crate::api::character::{slug_matches_name, unique_slug};
+
+        if campaign.name.trim().is_empty() {
+            return Err(ServerFnError::new("Campaign name is required".to_string()));
+        }
+        let user: AuthenticatedUser = extract().await?;
+        let mut campaign = campaign;
+        // Whoever creates a campaign is its DM, and only the DM can change it afterwards
+        if campaign.id == 0 {
+            campaign.owner = user.id.clone();
+        } else {
+            rows::require_role(campaign.id, CampaignRole::Dm).await?;
+            let stored: Campaign = rows::single("campaigns", "id", campaign.id.to_string()).await?;
+            campaign.owner = stored.owner;
+        }
+        if !slug_matches_name(&campaign.slug, &campaign.name) {
+            let exclude_id = (campaign.id != 0).then_some(campaign.id);
+            campaign.slug = unique_slug(&create_user_client(&user), "campaigns", &campaign.name, exclude_id).await?;
+        }
+        campaign.updated_at = Some(chrono::Utc::now().to_rfc3339());
+        let mut saved: Campaign = rows::save_except("campaigns", campaign.id, &campaign, &["dm_notes"]).await?;
+        rows::save_dm_notes(saved.id, &campaign.dm_notes).await?;
+        saved.dm_notes = campaign.dm_notes;
+        Ok(saved)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = DeleteCampaign, client = crate::api::auth::AuthClient)]
+pub async fn delete_campaign(id: i32) -> Result<(), ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(id, CampaignRole::Dm).await?;
+        rows::delete("campaigns", id).await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+
+
+#[server(name = GetQuests, client = crate::api::auth::AuthClient)]
+pub async fn get_quests(campaign_id: i32) -> Result<Vec<Quest>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(campaign_id, CampaignRole::Member).await?;
+        rows::list("quests", "campaign_id", campaign_id.to_string(), "id.asc").await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = SaveQuest, client = crate::api::auth::AuthClient)]
+pub async fn save_quest(quest: Quest) -> Result<Quest, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::live::{self, LiveEvent};
+
+        rows::require_role(quest.campaign_id, CampaignRole::Dm).await?;
+        rows::keep_campaign("quests", quest.id, quest.campaign_id, "Quests").await?;
+        if quest.title.trim().is_empty() {
+            return Err(ServerFnError::new("Quest title is required".to_string()));
+        }
+        let mut quest = quest;
+        quest.progress = quest.progress.clamp(0, 100);
+        let saved: Quest = rows::save("quests", quest.id, &quest).await?;
+        live::publish(saved.campaign_id, LiveEvent::Quest(saved.clone()));
+        Ok(saved)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = DeleteQuest, client = crate::api::auth::AuthClient)]
+pub async fn delete_quest(id: i32) -> Result<(), ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::live::{self, LiveEvent};
+
+        let campaign_id = rows::campaign_of("quests", id).await?;
+        rows::require_role(campaign_id, CampaignRole::Dm).await?;
+        rows::delete("quests", id).await?;
+        live::publish(campaign_id, LiveEvent::QuestDeleted { id });
+        Ok(())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+
+
+#[server(name = GetCampaignEvents, client = crate::api::auth::AuthClient)]
+pub async fn get_campaign_events(campaign_id: i32) -> Result<Vec<CampaignEvent>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(campaign_id, CampaignRole::Member).await?;
+        rows::list("campaign_events", "campaign_id", campaign_id.to_string(), "date.desc").await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = SaveCampaignEvent, client = crate::api::auth::AuthClient)]
+pub async fn save_campaign_event(event: CampaignEvent) -> Result<CampaignEvent, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(event.campaign_id, CampaignRole::Dm).await?;
+        rows::keep_campaign("campaign_events", event.id, event.campaign_id, "Events").await?;
+        if event.title.trim().is_empty() {
+            return Err(ServerFnError::new("Event title is required".to_string()));
+        }
+        rows::save("campaign_events", event.id, &event).await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = DeleteCampaignEvent, client = crate::api::auth::AuthClient)]
+pub async fn delete_campaign_event(id: i32) -> Result<(), ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(rows::campaign_of("campaign_events", id).await?, CampaignRole::Dm).await?;
+        rows::delete("campaign_events", id).await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+
+
+#[server(name = GetPartyMembers, client = crate::api::auth::AuthClient)]
+pub async fn get_party_members(campaign_id: i32) -> Result<Vec<PartyMember>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(campaign_id, CampaignRole::Member).await?;
+        rows::list("party_members", "campaign_id", campaign_id.to_string(), "name.asc").await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// How a party member's character is doing in play, for the dashboard's party status
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PartyVitals {
+    pub character_id: i32,
+    pub slug: String,
+    pub name: String,
+    pub armor_class: i32,
+    pub dexterity: i32,
+    pub initiative: i32,
+    pub hit_points: i32,
+    pub max_hit_points: i32,
+    pub temporary_hit_points: i32,
+    pub conditions: Vec<Condition>,
+    pub exhaustion: i32,
+    pub death_saves: DeathSaves,
+    pub dead: bool,
+    /// Name of the spell being concentrated on
+    pub concentration: Option<String>,
+}
+
+#[server(name = GetPartyVitals, client = crate::api::auth::AuthClient)]
+pub async fn get_party_vitals(campaign_id: i32) -> Result<Vec<PartyVitals>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::{create_user_client, AuthenticatedUser};
+        use crate::api::character::{fetch_rows, CharacterDetail, SupabaseCharacterDetail};
+        use crate::rules::{play, DerivedStats};
+
+        let user: AuthenticatedUser = extract().await?;
+        rows::require_role(campaign_id, CampaignRole::Member).await?;
+        let members: Vec<PartyMember> = rows::list("party_members", "campaign_id", campaign_id.to_string(), "name.asc").await?;
+        let character_ids: Vec<String> = members.iter().filter_map(PartyMember::joined_character).map(|id| id.to_string()).collect();
+        if character_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let characters: Vec<SupabaseCharacterDetail> = fetch_rows(
+            create_user_client(&user).table("characters").select("*").in_("id", &character_ids),
+            "party characters",
+        )
+        .await?;
+        Ok(characters
+            .into_iter()
+            .map(CharacterDetail::from)
+            .map(|character| {
+                let max_hit_points = play::max_hit_points(&character);
+                let state = &character.play_state;
+                PartyVitals {
+                    character_id: character.id,
+                    slug: character.slug.clone(),
+                    name: character.name.clone(),
+                    armor_class: character.armor_class,
+                    dexterity: character.dexterity,
+                    initiative: DerivedStats::for_character(&character).initiative,
+                    hit_points: state.current_hit_points(max_hit_points),
+                    max_hit_points,
+                    temporary_hit_points: state.temporary_hit_points,
+                    conditions: state.conditions.clone(),
+                    exhaustion: state.exhaustion,
+                    death_saves: state.death_saves,
+                    dead: state.is_dead(),
+                    concentration: character.spellbook.concentrating_on().map(|entry| entry.name.clone()),
+                }
+            })
+            .collect())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// Add or change a party member. Linking someone else's character only invites it: the link
+/// counts once the character's owner accepts with [`respond_to_party_invite`].
+#[server(name = SavePartyMember, client = crate::api::auth::AuthClient)]
+pub async fn save_party_member(member: PartyMember) -> Result<PartyMember, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(member.campaign_id, CampaignRole::Dm).await?;
+        rows::keep_campaign("party_members", member.id, member.campaign_id, "Party members").await?;
+        if member.name.trim().is_empty() {
+            return Err(ServerFnError::new("Party member name is required".to_string()));
+        }
+        rows::save("party_members", member.id, &member).await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = DeletePartyMember, client = crate::api::auth::AuthClient)]
+pub async fn delete_party_member(id: i32) -> Result<(), ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(rows::campaign_of("party_members", id).await?, CampaignRole::Dm).await?;
+        rows::delete("party_members", id).await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// The invites for the caller's characters that they haven't answered yet
+#[server(name = GetPartyInvites, client = crate::api::auth::AuthClient)]
+pub async fn get_party_invites() -> Result<Vec<PartyInvite>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::{create_user_client, AuthenticatedUser};
+        use crate::api::character::fetch_rows;
+
+        let user: AuthenticatedUser = extract().await?;
+        fetch_rows(create_user_client(&user).rpc("pending_party_invites", "{}".to_string()), "party invites").await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// Accept the invite of party row `id` for one of the caller's characters, or decline it.
+/// Declining an accepted invite takes the character out of the party.
+#[server(name = RespondToPartyInvite, client = crate::api::auth::AuthClient)]
+pub async fn respond_to_party_invite(id: i32, accept: bool) -> Result<(), ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::{create_user_client, AuthenticatedUser};
+        use tracing::info;
+
+        let user: AuthenticatedUser = extract().await?;
+        info!("{} party invite {}", if accept { "Accepting" } else { "Declining" }, id);
+        let params = serde_json::json!({ "p_party_member_id": id, "p_accept": accept });
+        let response = create_user_client(&user)
+            .rpc("respond_to_party_invite", params.to_string())
+            .execute()
+            .await
+            .map_err(|e| ServerFnError::new(format!("Request failed: {}", e)))?;
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            return Err(ServerFnError::new(format!("Failed to answer the invite. Status: {}, Response: {}", status, text)));
+        }
+        Ok(())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+
+
+#[server(name = GetAdventures, client = crate::api::auth::AuthClient)]
+pub async fn get_adventures(campaign_id: i32) -> Result<Vec<Adventure>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(campaign_id, CampaignRole::Member).await?;
+        let mut adventures: Vec<Adventure> =
+            rows::list("adventures", "campaign_id", campaign_id.to_string(), "date_played.desc").await?;
+        let notes = rows::adventure_dm_notes(campaign_id).await?;
+        for adventure in adventures.iter_mut() {
+            adventure.dm_notes = notes.iter().find(|note| note.adventure_id == adventure.id).map(|note| note.notes.clone());
+        }
+        Ok(adventures)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = SaveAdventure, client = crate::api::auth::AuthClient)]
+pub async fn save_adventure(adventure: Adventure) -> Result<Adventure, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let role = rows::require_role(adventure.campaign_id, CampaignRole::Member).await?;
+        rows::keep_campaign("adventures", adventure.id, adventure.campaign_id, "Adventures").await?;
+        if adventure.title.trim().is_empty() {
+            return Err(ServerFnError::new("Adventure title is required".to_string()));
+        }
+        let mut saved: Adventure = rows::save_except("adventures", adventure.id, &adventure, &["dm_notes"]).await?;
+        // Players never see DM notes, so whatever the DM wrote stays when a player saves
+        if role == CampaignRole::Dm {
+            let notes = adventure.dm_notes.as_deref().filter(|notes| !notes.trim().is_empty());
+            rows::save_adventure_dm_notes(saved.id, saved.campaign_id, notes).await?;
+            saved.dm_notes = notes.map(str::to_string);
+        }
+        Ok(saved)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = DeleteAdventure, client = crate::api::auth::AuthClient)]
+pub async fn delete_adventure(id: i32) -> Result<(), ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(rows::campaign_of("adventures", id).await?, CampaignRole::Dm).await?;
+        rows::delete("adventures", id).await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+
+
+/// Lore categories of a campaign with their entries filled in
+#[server(name = GetLore, client = crate::api::auth::AuthClient)]
+pub async fn get_lore(campaign_id: i32) -> Result<Vec<LoreCategory>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(campaign_id, CampaignRole::Member).await?;
+        let mut categories: Vec<LoreCategory> =
+            rows::list("lore_categories", "campaign_id", campaign_id.to_string(), "id.asc").await?;
+        let entries: Vec<LoreEntry> =
+            rows::list("lore_entries", "campaign_id", campaign_id.to_string(), "title.asc").await?;
+
+        for category in categories.iter_mut() {
+            category.entries = entries
+                .iter()
+                .filter(|entry| entry.category_id == category.id)
+                .cloned()
+                .collect();
+        }
+        Ok(categories)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = SaveLoreCategory, client = crate::api::auth::AuthClient)]
+pub async fn save_lore_category(category: LoreCategory) -> Result<LoreCategory, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(category.campaign_id, CampaignRole::Dm).await?;
+        rows::keep_campaign("lore_categories", category.id, category.campaign_id, "Lore categories").await?;
+        if category.title.trim().is_empty() {
+            return Err(ServerFnError::new("Category title is required".to_string()));
+        }
+        rows::save("lore_categories", category.id, &category).await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = DeleteLoreCategory, client = crate::api::auth::AuthClient)]
+pub async fn delete_lore_category(id: i32) -> Result<(), ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(rows::campaign_of("lore_categories", id).await?, CampaignRole::Dm).await?;
+        rows::delete("lore_categories", id).await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = SaveLoreEntry, client = crate::api::auth::AuthClient)]
+pub async fn save_lore_entry(entry: LoreEntry) -> Result<LoreEntry, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(entry.campaign_id, CampaignRole::Dm).await?;
+        rows::keep_campaign("lore_entries", entry.id, entry.campaign_id, "Lore entries").await?;
+        if entry.title.trim().is_empty() {
+            return Err(ServerFnError::new("Lore entry title is required".to_string()));
+        }
+        rows::save("lore_entries", entry.id, &entry).await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = DeleteLoreEntry, client = crate::api::auth::AuthClient)]
+pub async fn delete_lore_entry(id: i32) -> Result<(), ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(rows::campaign_of("lore_entries", id).await?, CampaignRole::Dm).await?;
+        rows::delete("lore_entries", id).await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+
+
+#[server(name = GetNpcs, client = crate::api::auth::AuthClient)]
+pub async fn get_npcs(campaign_id: i32) -> Result<Vec<NPC>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(campaign_id, CampaignRole::Member).await?;
+        rows::list("npcs", "campaign_id", campaign_id.to_string(), "name.asc").await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = SaveNpc, client = crate::api::auth::AuthClient)]
+pub async fn save_npc(npc: NPC) -> Result<NPC, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::live::{self, LiveEvent};
+
+        rows::require_role(npc.campaign_id, CampaignRole::Dm).await?;
+        rows::keep_campaign("npcs", npc.id, npc.campaign_id, "NPCs").await?;
+        if npc.name.trim().is_empty() {
+            return Err(ServerFnError::new("NPC name is required".to_string()));
+        }
+        let saved: NPC = rows::save("npcs", npc.id, &npc).await?;
+        live::publish(saved.campaign_id, LiveEvent::Npc(saved.clone()));
+        Ok(saved)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = DeleteNpc, client = crate::api::auth::AuthClient)]
+pub async fn delete_npc(id: i32) -> Result<(), ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::live::{self, LiveEvent};
+
+        let campaign_id = rows::campaign_of("npcs", id).await?;
+        rows::require_role(campaign_id, CampaignRole::Dm).await?;
+        rows::delete("npcs", id).await?;
+        live::publish(campaign_id, LiveEvent::NpcDeleted { id });
+        Ok(())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+
+
+#[server(name = GetMapLocations, client = crate::api::auth::AuthClient)]
+pub async fn get_map_locations(campaign_id: i32) -> Result<Vec<MapLocation>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(campaign_id, CampaignRole::Member).await?;
+        rows::list("map_locations", "campaign_id", campaign_id.to_string(), "name.asc").await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = SaveMapLocation, client = crate::api::auth::AuthClient)]
+pub async fn save_map_location(location: MapLocation) -> Result<MapLocation, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(location.campaign_id, CampaignRole::Dm).await?;
+        rows::keep_campaign("map_locations", location.id, location.campaign_id, "Locations").await?;
+        if location.name.trim().is_empty() {
+            return Err(ServerFnError::new("Location name is required".to_string()));
+        }
+        let mut location = location;
+        location.x = location.x.clamp(0.0, 100.0);
+        location.y = location.y.clamp(0.0, 100.0);
+        rows::save("map_locations", location.id, &location).await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = DeleteMapLocation, client = crate::api::auth::AuthClient)]
+pub async fn delete_map_location(id: i32) -> Result<(), ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(rows::campaign_of("map_locations", id).await?, CampaignRole::Dm).await?;
+        rows::delete("map_locations", id).await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+
+
+/// The campaign's saved encounters, most recently touched first. DM only.
+#[server(name = GetEncounters, client = crate::api::auth::AuthClient)]
+pub async fn get_encounters(campaign_id: i32) -> Result<Vec<EncounterRecord>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        rows::require_role(campaign_id, CampaignRole::Dm).await?;
+        rows::list("encounters", "campaign_id", campaign_id.to_string(), "updated_at.desc.nullslast").await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = GetEncounter, client = crate::api::auth::AuthClient)]
+pub async fn get_encounter(id: i32) -> Result<EncounterRecord, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let encounter: EncounterRecord = rows::single("encounters", "id", id.to_string()).await?;
+        rows::require_role(encounter.campaign_id, CampaignRole::Dm).await?;
+        Ok(encounter)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = SaveEncounter, client = crate::api::auth::AuthClient)]
+pub async fn save_encounter(encounter: EncounterRecord) -> Result<EncounterRecord, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::live::{self, LiveEvent};
+
+        rows::require_role(encounter.campaign_id, CampaignRole::Dm).await?;
+        rows::keep_campaign("encounters", encounter.id, encounter.campaign_id, "Encounters").await?;
+        if encounter.name.trim().is_empty() {
+            return Err(ServerFnError::new("Encounter name is required".to_string()));
+        }
+        let errors = encounter.state.validate();
+        if !errors.is_empty() {
+            return Err(ServerFnError::new(errors.join("\n")));
+        }
+        let encounter = EncounterRecord { updated_at: Some(chrono::Utc::now().to_rfc3339()), ..encounter };
+        let saved: EncounterRecord = rows::save("encounters", encounter.id, &encounter).await?;
+        live::publish(saved.campaign_id, LiveEvent::Encounter(saved.clone()));
+        Ok(saved)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = DeleteEncounter, client = crate::api::auth::AuthClient)]
+pub async fn delete_encounter(id: i32) -> Result<(), ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::live::{self, LiveEvent};
+
+        let campaign_id = rows::campaign_of("encounters", id).await?;
+        rows::require_role(campaign_id, CampaignRole::Dm).await?;
+        rows::delete("encounters", id).await?;
+        live::publish(campaign_id, LiveEvent::EncounterDeleted { id });
+        Ok(())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+
+
+/// The campaigns `character_id` plays in, among those the caller is part of
+#[server(name = GetCharacterCampaigns, client = crate::api::auth::AuthClient)]
+pub async fn get_character_campaigns(character_id: i32) -> Result<Vec<Campaign>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::AuthenticatedUser;
+
+        let user: AuthenticatedUser = extract().await?;
+        let memberships: Vec<PartyMember> = rows::list("party_members", "character_id", character_id.to_string(), "campaign_id.asc").await?;
+        let mut campaigns: Vec<Campaign> = vec![];
+        for member in memberships.into_iter().filter(|member| member.accepted) {
+            campaigns.push(rows::single("campaigns", "id", member.campaign_id.to_string()).await?);
+        }
+        let roles = rows::roles(&user, &campaigns).await?;
+        let mut campaigns: Vec<Campaign> = campaigns
+            .into_iter()
+            .zip(roles)
+            .filter_map(|(campaign, role)| role.map(|_| campaign))
+            .collect();
+        rows::fill_dm_notes(&mut campaigns).await?;
+        Ok(campaigns)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// A homebrew pack a campaign's DM may enable: one of their own, one someone shared, or
+/// one that is already enabled
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct CampaignPack {
+    pub pack: HomebrewPack,
+    pub enabled: bool,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Deserialize)]
+struct PackIdRow {
+    pack_id: i32,
+}
+
+// The packs enabled in the campaigns with `campaign_ids`
+#[cfg(not(target_arch = "wasm32"))]
+async fn campaign_pack_ids(
+    client: &crate::api::auth::ServerSupabaseClient,
+    campaign_ids: &[String],
+) -> Result<HashSet<i32>, ServerFnError> {
+    use crate::api::character::fetch_rows;
+
+    if campaign_ids.is_empty() {
+        return Ok(HashSet::new());
+    }
+    let rows: Vec<PackIdRow> = fetch_rows(
+        client.table("campaign_homebrew_packs").select("pack_id").in_("campaign_id", campaign_ids),
+        "campaign homebrew packs",
+    )
+    .await?;
+    Ok(rows.into_iter().map(|row| row.pack_id).collect())
+}
+
+/// Every campaign `user` runs or plays in
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn caller_campaign_ids(user: &crate::api::auth::AuthenticatedUser) -> Result<HashSet<i32>, ServerFnError> {
+    rows::campaign_ids(user).await
+}
+
+/// Homebrew packs enabled in any campaign `user` runs or plays in
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn enabled_pack_ids(user: &crate::api::auth::AuthenticatedUser) -> Result<HashSet<i32>, ServerFnError> {
+    let campaign_ids: Vec<String> = rows::campaign_ids(user).await?.into_iter().map(|id| id.to_string()).collect();
+    campaign_pack_ids(&crate::api::auth::create_user_client(user), &campaign_ids).await
+}
+
+/// The homebrew packs the DM could enable in the campaign, and which of them are. DM only.
+#[server(name = GetCampaignPacks, client = crate::api::auth::AuthClient)]
+pub async fn get_campaign_packs(campaign_id: i32) -> Result<Vec<CampaignPack>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::{create_user_client, AuthenticatedUser};
+        use crate::api::character::fetch_rows;
+
+        rows::require_role(campaign_id, CampaignRole::Dm).await?;
+        let user: AuthenticatedUser = extract().await?;
+        let client = create_user_client(&user);
+        let enabled = campaign_pack_ids(&client, &[campaign_id.to_string()]).await?;
+
+        let mut available = format!("owner.eq.{},shared.is.true", user.id);
+        if !enabled.is_empty() {
+            let ids: Vec<String> = enabled.iter().map(i32::to_string).collect();
+            available.push_str(&format!(",id.in.({})", ids.join(",")));
+        }
+        let packs: Vec<HomebrewPack> =
+            fetch_rows(client.table("homebrew_packs").select("*").or(available).order("name.asc"), "homebrew packs").await?;
+        Ok(packs
+            .into_iter()
+            .map(|pack| CampaignPack { enabled: enabled.contains(&pack.id), pack })
+            .collect())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// Enable or disable homebrew pack `pack_id` in the campaign. Only the DM can, and only
+/// with their own packs or shared ones.
+#[server(name = SetCampaignPack, client = crate::api::auth::AuthClient)]
+pub async fn set_campaign_pack(campaign_id: i32, pack_id: i32, enabled: bool) -> Result<(), ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::{create_user_client, AuthenticatedUser};
+        use crate::api::character::fetch_rows;
+        use tracing::info;
+
+        rows::require_role(campaign_id, CampaignRole::Dm).await?;
+        let user: AuthenticatedUser = extract().await?;
+        let client = create_user_client(&user);
+        if !enabled {
+            info!("Disabling homebrew pack {} in campaign {}", pack_id, campaign_id);
+            let request = client
+                .table("campaign_homebrew_packs")
+                .delete()
+                .eq("campaign_id", campaign_id.to_string())
+                .eq("pack_id", pack_id.to_string());
+            fetch_rows::<serde_json::Value>(request, "campaign homebrew packs").await?;
+            return Ok(());
+        }
+
+        let packs: Vec<HomebrewPack> =
+            fetch_rows(client.table("homebrew_packs").select("*").eq("id", pack_id.to_string()), "homebrew pack").await?;
+        match packs.first() {
+            Some(pack) if pack.shared || pack.owner == user.id => {}
+            Some(pack) => return Err(ServerFnError::new(format!("{} isn't shared, so only its owner can enable it", pack.name))),
+            None => return Err(ServerFnError::new(format!("No homebrew pack with id {} found", pack_id))),
+        }
+
+        info!("Enabling homebrew pack {} in campaign {}", pack_id, campaign_id);
+        let body = serde_json::json!({ "campaign_id": campaign_id, "pack_id": pack_id }).to_string();
+        let request = client.table("campaign_homebrew_packs").upsert(body).on_conflict("campaign_id,pack_id");
+        fetch_rows::<serde_json::Value>(request, "campaign homebrew packs").await?;
+        Ok(())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// The latest rolls in the campaign's log that the caller may see, newest first
+#[server(name = GetRollLog, client = crate::api::auth::AuthClient)]
+pub async fn get_roll_log(campaign_id: i32) -> Result<Vec<RollEntry>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let viewer = rows::viewer(campaign_id).await?;
+        let rolls: Vec<RollEntry> = rows::list("campaign_rolls", "campaign_id", campaign_id.to_string(), "created_at.desc").await?;
+        Ok(rolls
+            .into_iter()
+            .filter(|roll| roll.visible_to(&viewer.user.id, viewer.role, &viewer.characters))
+            .take(ROLL_LOG_LENGTH)
+            .collect())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// Add a roll to the campaign's log and push it to everyone watching who may see it
+#[server(name = RecordRoll, client = crate::api::auth::AuthClient)]
+pub async fn record_roll(roll: NewRoll) -> Result<RollEntry, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::live::{self, LiveEvent};
+        use crate::rules::dice;
+        use tracing::info;
+
+        let viewer = rows::viewer(roll.campaign_id).await?;
+        let purpose = roll.purpose.trim().to_string();
+        if purpose.chars().count() > 100 {
+            return Err(ServerFnError::new("Roll purpose can be at most 100 characters".to_string()));
+        }
+
+        let party: Vec<PartyMember> = rows::list("party_members", "campaign_id", roll.campaign_id.to_string(), "name.asc").await?;
+        let party_name = |id: i32| party.iter().find(|m| m.joined_character() == Some(id)).map(|m| m.name.clone());
+        let whisper_to = match roll.visibility {
+            RollVisibility::Whisper => match roll.whisper_to {
+                Some(id) if party_name(id).is_some() => Some(id),
+                _ => return Err(ServerFnError::new("Whispers go to a character in the party".to_string())),
+            },
+            _ => None,
+        };
+        // Players with a single character in the party roll as that character by default
+        let character_id = match roll.character_id {
+            None if viewer.role != CampaignRole::Dm && viewer.characters.len() == 1 => viewer.characters.iter().next().copied(),
+            character_id => character_id,
+        };
+        let roller_name = match character_id {
+            Some(id) if viewer.characters.contains(&id) || viewer.role == CampaignRole::Dm => {
+                party_name(id).ok_or_else(|| ServerFnError::new("That character isn't in this campaign's party".to_string()))?
+            }
+            Some(_) => return Err(ServerFnError::new("You can only roll for your own characters".to_string())),
+            None if viewer.role == CampaignRole::Dm => "DM".to_string(),
+            None => viewer.user.email.as_deref().and_then(|email| email.split('@').next()).unwrap_or("Player").to_string(),
+        };
+
+        // The server throws the dice, like it does for hit points when levelling up, so the
+        // log only ever holds rolls nobody picked
+        let result = dice::roll(&roll.expression).map_err(ServerFnError::new)?;
+        info!("{} rolled {} in campaign {}", roller_name, result, roll.campaign_id);
+
+        let entry = RollEntry {
+            id: 0,
+            campaign_id: roll.campaign_id,
+            roller: viewer.user.id.clone(),
+            roller_name,
+            character_id,
+            purpose,
+            expression: result.expression.clone(),
+            total: result.total,
+            result,
+            visibility: roll.visibility,
+            whisper_to,
+            created_at: None,
+        };
+        // Callers can't insert into the roll log themselves, or they could log any total they liked
+        let saved: RollEntry = rows::insert_as_server("campaign_rolls", &entry).await?;
+        live::publish(saved.campaign_id, LiveEvent::Roll(saved.clone()));
+        Ok(saved)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// Everything happening in the campaign that the caller may see, as it happens: one JSON
+/// [`crate::api::live::LiveEvent`] per line. Read it with [`crate::api::live::events`].
+#[server(name = WatchCampaign, client = crate::api::auth::AuthClient, output = StreamingText)]
+pub async fn watch_campaign(campaign_id: i32) -> Result<TextStream, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use futures::StreamExt;
+        use tokio::sync::broadcast::error::RecvError;
+        use tracing::info;
+        use crate::api::live;
+
+        let viewer = rows::viewer(campaign_id).await?;
+        let receiver = live::subscribe(campaign_id);
+
+        // Answering straight away gets the response to the browser before anything has happened
+        let opening = futures::stream::once(async {
+            serde_json::to_string(&live::LiveEvent::Connected)
+                .map(|json| json + "\n")
+                .map_err(|e| ServerFnError::new(e.to_string()))
+        });
+        let events = futures::stream::unfold((receiver, viewer), move |(mut receiver, viewer)| async move {
+            loop {
+                let event = match receiver.recv().await {
+                    Ok(event) => event,
+                    // Missed events are only lost from this watcher's live view, not the log
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => return None,
+                };
+                // The watcher may have left the party, or had their character removed from it,
+                // since the stream opened, so look again at what they may see. Ending the stream
+                // when they can't be looked up makes the browser reconnect, which checks afresh.
+                let viewer = match rows::viewer_as(viewer.user.clone(), campaign_id).await {
+                    Ok(viewer) => viewer,
+                    Err(e) => {
+                        info!("Closing campaign {} stream for {}: {}", campaign_id, viewer.user.id, e);
+                        return None;
+                    }
+                };
+                if viewer.can_see(&event) {
+                    let line = serde_json::to_string(&event)
+                        .map(|json| json + "\n")
+                        .map_err(|e| ServerFnError::new(e.to_string()));
+                    return Some((line, (receiver, viewer)));
+                }
+            }
+        });
+        Ok(TextStream::new(opening.chain(events)))
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// Tell everyone watching the campaigns `character_id` plays in that it changed. The change
+/// is already saved, so failing to find those campaigns is only logged.
+#[cfg(feature = "server")]
+pub(crate) async fn announce_character_change(character_id: i32) {
+    use crate::api::live::{self, LiveEvent};
+
+    match rows::list::<PartyMember>("party_members", "character_id", character_id.to_string(), "campaign_id.asc").await {
+        Ok(memberships) => {
+            for member in memberships {
+                live::publish(member.campaign_id, LiveEvent::Character { character_id });
+            }
+        }
+        Err(e) => tracing::warn!("Couldn't announce the change to character {}: {}", character_id, e),
+    }
+}
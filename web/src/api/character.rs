@@ -1,40 +1,88 @@
 use serde::{Deserialize, Serialize};
 use dioxus::prelude::*;
+use crate::rules::creation::AbilityRoll;
+use crate::rules::features::{self, Feature};
+use crate::rules::inventory::Inventory;
+use crate::rules::play::{PlayAction, PlayState};
+use crate::rules::spellbook::Spellbook;
+use crate::rules::leveling::{HitPointMethod, Improvement, LevelUpChoice};
+use crate::rules::{multiclass, Ability, Alignment, Background, Class, ClassLevel, Race, Subclass};
 
 
 
 // Define D&D character structure
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct CharacterDetail {
     pub id: i32,
     pub name: String,
     pub slug: String,
     pub description: String,
-    pub race: String,
-    pub class: String,
-    pub level: i32,
-    pub background: String,
-    pub alignment: String,
+    pub race: Option<Race>,
+    /// Levels in each class, starting class first. The character's level is their sum.
+    #[serde(default)]
+    pub classes: Vec<ClassLevel>,
+    pub background: Option<Background>,
+    pub alignment: Option<Alignment>,
     pub strength: i32,
     pub dexterity: i32,
     pub constitution: i32,
     pub intelligence: i32,
     pub wisdom: i32,
     pub charisma: i32,
+    /// Every 4d6-drop-lowest roll made while creating the character, kept so the table can see them
+    #[serde(default)]
+    pub ability_rolls: Vec<AbilityRoll>,
     pub hit_points: i32,
     pub armor_class: i32,
     pub speed: i32,
     pub proficiency_bonus: i32,
     pub skills: Vec<String>,
     pub languages: Vec<String>,
-    pub equipment: Vec<String>,
-    pub spells: Vec<String>,
-    pub features: Vec<String>,
+    /// Items, containers and the coin purse
+    #[serde(default)]
+    pub inventory: Inventory,
+    /// Known and prepared spells, spent slots and concentration
+    #[serde(default)]
+    pub spellbook: Spellbook,
+    /// Current hit points, conditions and the rest of the state that changes during a session
+    #[serde(default)]
+    pub play_state: PlayState,
+    /// Class, race and feat features, with uses for the limited-use ones
+    pub features: Vec<Feature>,
     pub backstory: String,
     pub portrait_image: Option<String>,
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
     pub owner: String,
+    // What the signed-in caller may do with this character; filled in by the server functions
+    #[serde(default)]
+    pub access: CharacterAccess,
+}
+
+/// How the signed-in user relates to a character, from most to least access.
+/// Owners can do anything, the DM of a campaign the character plays in can view and edit it,
+/// and other players in that campaign's party can view it read-only.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CharacterAccess {
+    #[default]
+    NoAccess,
+    PartyMember,
+    CampaignDm,
+    Owner,
+}
+
+impl CharacterAccess {
+    pub fn can_view(self) -> bool {
+        self != CharacterAccess::NoAccess
+    }
+
+    pub fn can_edit(self) -> bool {
+        self >= CharacterAccess::CampaignDm
+    }
+
+    pub fn can_delete(self) -> bool {
+        self == CharacterAccess::Owner
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -43,9 +91,14 @@ pub struct SupabaseCharacterDetail {
     pub name: String,
     pub slug: String,
     pub description: String,
+    // Free text in rows saved before the catalogs existed; parsed leniently on the way in
     pub race: String,
     pub class: String,
+    #[serde(default)]
+    pub subclass: Option<String>,
     pub level: i32,
+    #[serde(default)]
+    pub classes: Option<serde_json::Value>, // JSON array of class levels from Supabase
     pub background: String,
     pub alignment: String,
     pub strength: i32,
@@ -54,6 +107,8 @@ pub struct SupabaseCharacterDetail {
     pub intelligence: i32,
     pub wisdom: i32,
     pub charisma: i32,
+    #[serde(default)]
+    pub ability_rolls: Option<serde_json::Value>, // JSON array from Supabase
     pub hit_points: i32,
     pub armor_class: i32,
     pub speed: i32,
@@ -61,7 +116,13 @@ pub struct SupabaseCharacterDetail {
     pub skills: Option<serde_json::Value>, // JSON array from Supabase
     pub languages: Option<serde_json::Value>, // JSON array from Supabase
     pub equipment: Option<serde_json::Value>, // JSON array from Supabase
+    #[serde(default)]
+    pub inventory: Option<serde_json::Value>, // JSON object from Supabase
     pub spells: Option<serde_json::Value>, // JSON array from Supabase
+    #[serde(default)]
+    pub spellbook: Option<serde_json::Value>, // JSON object from Supabase
+    #[serde(default)]
+    pub play_state: Option<serde_json::Value>, // JSON object from Supabase
     pub features: Option<serde_json::Value>, // JSON array from Supabase
     pub backstory: String,
     pub portrait_image: Option<String>,
@@ -70,6 +131,527 @@ pub struct SupabaseCharacterDetail {
     pub owner: String,
 }
 
+// Columns written to the `characters` table on create/update
+#[derive(Clone, Debug, Serialize)]
+pub struct NewCharacter {
+    pub name: String,
+    pub slug: String,
+    pub description: String,
+    pub race: Option<Race>,
+    // `class`, `subclass` and `level` summarise `classes` (starting class, total level)
+    // so the columns stay useful for listing and sorting
+    pub class: Option<Class>,
+    pub subclass: Option<Subclass>,
+    pub level: i32,
+    pub classes: Vec<ClassLevel>,
+    pub background: Option<Background>,
+    pub alignment: Option<Alignment>,
+    pub strength: i32,
+    pub dexterity: i32,
+    pub constitution: i32,
+    pub intelligence: i32,
+    pub wisdom: i32,
+    pub charisma: i32,
+    pub ability_rolls: Vec<AbilityRoll>,
+    pub hit_points: i32,
+    pub armor_class: i32,
+    pub speed: i32,
+    pub proficiency_bonus: i32,
+    pub skills: Vec<String>,
+    pub languages: Vec<String>,
+    // Item names, kept alongside `inventory` for anything still reading the old list
+    pub equipment: Vec<String>,
+    pub inventory: Inventory,
+    // Spell names, kept alongside `spellbook` for the same reason
+    pub spells: Vec<String>,
+    pub spellbook: Spellbook,
+    pub play_state: PlayState,
+    pub features: Vec<Feature>,
+    pub backstory: String,
+    pub portrait_image: Option<String>,
+    pub owner: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl From<CharacterDetail> for NewCharacter {
+    fn from(character: CharacterDetail) -> Self {
+        let level = character.level();
+        let starting = character.classes.first().copied();
+        Self {
+            name: character.name.trim().to_string(),
+            slug: character.slug,
+            description: character.description,
+            race: character.race,
+            class: starting.map(|class_level| class_level.class),
+            subclass: starting.and_then(|class_level| class_level.subclass),
+            level,
+            classes: character.classes,
+            background: character.background,
+            alignment: character.alignment,
+            strength: character.strength,
+            dexterity: character.dexterity,
+            constitution: character.constitution,
+            intelligence: character.intelligence,
+            wisdom: character.wisdom,
+            charisma: character.charisma,
+            ability_rolls: character.ability_rolls,
+            hit_points: character.hit_points,
+            armor_class: character.armor_class,
+            speed: character.speed,
+            // Never trust a hand-typed bonus; it always follows from level
+            proficiency_bonus: crate::rules::proficiency_bonus(level),
+            skills: character.skills,
+            languages: character.languages,
+            equipment: character.inventory.equipment_names(),
+            inventory: character.inventory,
+            spells: character.spellbook.names(),
+            spellbook: character.spellbook,
+            play_state: character.play_state,
+            features: character.features,
+            backstory: character.backstory,
+            portrait_image: character.portrait_image,
+            owner: character.owner,
+            updated_at: None,
+        }
+    }
+}
+
+impl CharacterDetail {
+    /// Character level, the total of every class's levels
+    pub fn level(&self) -> i32 {
+        multiclass::total_level(&self.classes)
+    }
+
+    /// e.g. "Fighter 3 (Champion) / Wizard 2"
+    pub fn class_summary(&self) -> String {
+        let classes: Vec<String> = self.classes.iter().map(ClassLevel::to_string).collect();
+        classes.join(" / ")
+    }
+
+    /// e.g. "Half-Elf Bard 5 (College of Lore)", leaving out whatever hasn't been chosen yet
+    pub fn race_and_class(&self) -> String {
+        let mut parts: Vec<String> = vec![];
+        parts.extend(self.race.map(|race| race.to_string()));
+        if !self.classes.is_empty() {
+            parts.push(self.class_summary());
+        }
+        parts.join(" ")
+    }
+
+    /// For a character built from rolled scores: its scores, less racial bonuses, must be the
+    /// totals of the latest set it rolled, one to each ability
+    pub fn validate_rolled_scores(&self) -> Vec<String> {
+        let Some(latest) = self.ability_rolls.iter().map(|roll| roll.set).max() else {
+            return vec![];
+        };
+        let mut rolled: Vec<i32> = self.ability_rolls.iter().filter(|roll| roll.set == latest).map(AbilityRoll::total).collect();
+        let mut base: Vec<i32> = Ability::ALL
+            .iter()
+            .map(|ability| ability.score(self) - self.race.map_or(0, |race| race.ability_bonus(*ability)))
+            .collect();
+        rolled.sort_unstable();
+        base.sort_unstable();
+        if rolled == base {
+            vec![]
+        } else {
+            vec!["Ability scores must be the latest set of rolls, one to each ability".to_string()]
+        }
+    }
+
+    /// Check the fields a player fills in on the edit form.
+    /// Returns one message per problem; an empty list means the character can be saved.
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = vec![];
+
+        if self.name.trim().is_empty() {
+            errors.push("Character name is required".to_string());
+        } else if self.name.trim().chars().count() > 100 {
+            errors.push("Character name must be 100 characters or fewer".to_string());
+        } else if slugify(&self.name).is_empty() {
+            errors.push("Character name must contain at least one letter or number".to_string());
+        }
+        if self.race.is_none() {
+            errors.push("Race is required".to_string());
+        }
+        if self.alignment.is_none() {
+            errors.push("Alignment is required".to_string());
+        }
+        errors.extend(self.validate_classes());
+
+        let abilities = [
+            ("Strength", self.strength),
+            ("Dexterity", self.dexterity),
+            ("Constitution", self.constitution),
+            ("Intelligence", self.intelligence),
+            ("Wisdom", self.wisdom),
+            ("Charisma", self.charisma),
+        ];
+        for (ability, score) in abilities {
+            if !(1..=30).contains(&score) {
+                errors.push(format!("{} must be between 1 and 30", ability));
+            }
+        }
+
+        if self.hit_points < 1 {
+            errors.push("Hit points must be at least 1".to_string());
+        }
+        if self.armor_class < 1 {
+            errors.push("Armor class must be at least 1".to_string());
+        }
+        if self.speed < 0 {
+            errors.push("Speed cannot be negative".to_string());
+        }
+        if self.proficiency_bonus < 1 {
+            errors.push("Proficiency bonus must be at least 1".to_string());
+        }
+        errors.extend(self.inventory.validate());
+        errors.extend(self.spellbook.validate(&self.classes, |ability| ability.score(self)));
+        errors.extend(self.play_state.validate(self));
+        errors.extend(features::validate(&self.features));
+
+        errors
+    }
+
+    fn validate_classes(&self) -> Vec<String> {
+        let mut errors = vec![];
+
+        if self.classes.is_empty() {
+            errors.push("Class is required".to_string());
+            return errors;
+        }
+        if !(1..=20).contains(&self.level()) {
+            errors.push("Level must be between 1 and 20".to_string());
+        }
+        for (index, class_level) in self.classes.iter().enumerate() {
+            let class = class_level.class;
+            if self.classes[..index].iter().any(|earlier| earlier.class == class) {
+                errors.push(format!("{} is listed more than once", class));
+            }
+            if class_level.level < 1 {
+                errors.push(format!("{} level must be at least 1", class));
+            }
+            if let Some(subclass) = class_level.subclass {
+                if subclass.class() != class {
+                    errors.push(format!("{} is not a {} subclass", subclass, class));
+                } else if class_level.level < class.subclass_level() {
+                    errors.push(format!("{}s choose a subclass at level {}", class, class.subclass_level()));
+                }
+            }
+        }
+
+        // Multiclassing needs the prerequisites of every class, including the starting one
+        if self.classes.len() > 1 {
+            let score = |ability: crate::rules::Ability| ability.score(self);
+            for class_level in &self.classes {
+                let class = class_level.class;
+                if !multiclass::meets_prerequisites(class, score) {
+                    let abilities: Vec<&str> = multiclass::prerequisites(class).iter().map(|a| a.name()).collect();
+                    let joiner = if class == Class::Fighter { " or " } else { " and " };
+                    errors.push(format!("Multiclassing with {} needs {} of 13 or higher", class, abilities.join(joiner)));
+                }
+            }
+        }
+
+        errors
+    }
+}
+
+/// Turn a character name into a URL slug, e.g. "Aragorn, Son of Arathorn" -> "aragorn-son-of-arathorn"
+pub fn slugify(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.trim().to_lowercase().chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+// Pick `base`, or the first of `base-2`, `base-3`, ... that is not already taken.
+// "new" is reserved because `/characters/new/edit` is the create form.
+fn next_free_slug(base: &str, taken: &[String]) -> String {
+    let is_free = |candidate: &str| candidate != "new" && !taken.iter().any(|t| t == candidate);
+    if is_free(base) {
+        return base.to_string();
+    }
+    (2..)
+        .map(|n| format!("{}-{}", base, n))
+        .find(|candidate| is_free(candidate))
+        .unwrap()
+}
+
+// True when `slug` was generated from `name`, with or without a `-N` suffix
+pub(crate) fn slug_matches_name(slug: &str, name: &str) -> bool {
+    let base = slugify(name);
+    slug == base
+        || slug
+            .strip_prefix(&format!("{}-", base))
+            .is_some_and(|n| n.parse::<u32>().is_ok())
+}
+
+// Find a slug for `name` that no other row in `table` uses, including rows the caller
+// can't read. `exclude_id` is the row being updated, so it can keep its own slug.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn unique_slug(
+    client: &crate::api::auth::ServerSupabaseClient,
+    table: &str,
+    name: &str,
+    exclude_id: Option<i32>,
+) -> Result<String, ServerFnError> {
+    #[derive(Deserialize)]
+    struct SlugRow {
+        id: i32,
+        slug: String,
+    }
+
+    let base = slugify(name);
+    let params = serde_json::json!({ "p_table": table, "p_base": base });
+    let response = client
+        .rpc("taken_slugs", params.to_string())
+        .execute()
+        .await
+        .map_err(|e| ServerFnError::new(format!("Request failed: {}", e)))?;
+
+    let status = response.status();
+    let text = response.text().await.map_err(|e| ServerFnError::new(e.to_string()))?;
+    if !status.is_success() {
+        return Err(ServerFnError::new(format!("Failed to check {} slugs. Status: {}, Response: {}", table, status, text)));
+    }
+
+    let taken: Vec<String> = serde_json::from_str::<Vec<SlugRow>>(&text)
+        .map_err(|e| ServerFnError::new(format!("Failed to parse {} slugs JSON: {}", table, e)))?
+        .into_iter()
+        .filter(|row| Some(row.id) != exclude_id)
+        .map(|row| row.slug)
+        .collect();
+
+    Ok(next_free_slug(&base, &taken))
+}
+
+// Run a query and parse the rows it returns
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn fetch_rows<T: serde::de::DeserializeOwned>(query: postgrest::Builder, what: &str) -> Result<Vec<T>, ServerFnError> {
+    let response = query
+        .execute()
+        .await
+        .map_err(|e| ServerFnError::new(format!("Request failed: {}", e)))?;
+
+    let status = response.status();
+    let text = response.text().await.map_err(|e| ServerFnError::new(e.to_string()))?;
+    if !status.is_success() {
+        return Err(ServerFnError::new(format!("Failed to fetch {}. Status: {}, Response: {}", what, status, text)));
+    }
+    serde_json::from_str::<Vec<T>>(&text)
+        .map_err(|e| ServerFnError::new(format!("Failed to parse {} JSON: {}", what, e)))
+}
+
+/// Like [`fetch_rows`] for a query made with `exact_count`, also returning how many rows match
+/// the filters in all. Asking for a range past the last row gives no rows rather than an error.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn fetch_counted_rows<T: serde::de::DeserializeOwned>(query: postgrest::Builder, what: &str) -> Result<(Vec<T>, i32), ServerFnError> {
+    let response = query
+        .execute()
+        .await
+        .map_err(|e| ServerFnError::new(format!("Request failed: {}", e)))?;
+
+    let status = response.status();
+    // The count comes back in a header like "0-23/319", or "*/0" when nothing matches
+    let total = response
+        .headers()
+        .get("content-range")
+        .and_then(|range| range.to_str().ok())
+        .and_then(|range| range.rsplit('/').next())
+        .and_then(|total| total.parse::<i32>().ok());
+    let text = response.text().await.map_err(|e| ServerFnError::new(e.to_string()))?;
+    if status.as_u16() == 416 {
+        return Ok((vec![], total.unwrap_or(0)));
+    }
+    if !status.is_success() {
+        return Err(ServerFnError::new(format!("Failed to fetch {}. Status: {}, Response: {}", what, status, text)));
+    }
+    let rows = serde_json::from_str::<Vec<T>>(&text)
+        .map_err(|e| ServerFnError::new(format!("Failed to parse {} JSON: {}", what, e)))?;
+    let total = total.unwrap_or(rows.len() as i32);
+    Ok((rows, total))
+}
+
+/// Narrow a catalog query to rows whose name contains `query`, ignoring case
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn filter_name(request: postgrest::Builder, query: &str) -> postgrest::Builder {
+    // `*` is PostgREST's wildcard; strip the characters that would break the filter syntax
+    let query: String = query.chars().filter(|c| !matches!(c, '*' | '%' | ',' | '(' | ')')).collect();
+    if query.trim().is_empty() {
+        request
+    } else {
+        request.ilike("name", format!("*{}*", query.trim()))
+    }
+}
+
+// Work out what `user` may do with each of `characters`, by way of the campaigns they play in:
+// the campaign owner is its DM, and anyone with their own character in the party is a member.
+// Only party links whose character's owner accepted the invite count.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn character_access(
+    client: &crate::api::auth::ServerSupabaseClient,
+    user: &crate::api::auth::AuthenticatedUser,
+    characters: &mut [CharacterDetail],
+) -> Result<(), ServerFnError> {
+    use std::collections::HashSet;
+
+    #[derive(Deserialize)]
+    struct PartyRow {
+        campaign_id: i32,
+        character_id: Option<i32>,
+    }
+    #[derive(Deserialize)]
+    struct CampaignRow {
+        id: i32,
+        owner: String,
+    }
+    #[derive(Deserialize)]
+    struct CharacterRow {
+        id: i32,
+    }
+
+    let others: Vec<String> = characters
+        .iter()
+        .filter(|c| c.owner != user.id)
+        .map(|c| c.id.to_string())
+        .collect();
+
+    // Which campaigns each of the other characters plays in
+    let memberships: Vec<PartyRow> = if others.is_empty() {
+        vec![]
+    } else {
+        fetch_rows(
+            client.table("party_members").select("campaign_id,character_id").in_("character_id", &others).eq("accepted", "true"),
+            "party members",
+        )
+        .await?
+    };
+    let campaign_ids: Vec<String> = memberships
+        .iter()
+        .map(|m| m.campaign_id.to_string())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+
+    let mut dm_campaigns = HashSet::new();
+    let mut member_campaigns = HashSet::new();
+    if !campaign_ids.is_empty() {
+        let campaigns: Vec<CampaignRow> =
+            fetch_rows(client.table("campaigns").select("id,owner").in_("id", &campaign_ids), "campaigns").await?;
+        dm_campaigns.extend(campaigns.into_iter().filter(|c| c.owner == user.id).map(|c| c.id));
+
+        // The user is a party member wherever one of their own characters shares the party
+        let parties: Vec<PartyRow> = fetch_rows(
+            client.table("party_members").select("campaign_id,character_id").in_("campaign_id", &campaign_ids).eq("accepted", "true"),
+            "party members",
+        )
+        .await?;
+        let party_character_ids: Vec<String> = parties.iter().filter_map(|p| p.character_id).map(|id| id.to_string()).collect();
+        if !party_character_ids.is_empty() {
+            let mine: HashSet<i32> = fetch_rows::<CharacterRow>(
+                client.table("characters").select("id").in_("id", &party_character_ids).eq("owner", &user.id),
+                "characters",
+            )
+            .await?
+            .into_iter()
+            .map(|c| c.id)
+            .collect();
+            member_campaigns.extend(
+                parties
+                    .iter()
+                    .filter(|p| p.character_id.is_some_and(|id| mine.contains(&id)))
+                    .map(|p| p.campaign_id),
+            );
+        }
+    }
+
+    for character in characters.iter_mut() {
+        let campaigns = memberships
+            .iter()
+            .filter(|m| m.character_id == Some(character.id))
+            .map(|m| m.campaign_id);
+        character.access = if character.owner == user.id {
+            CharacterAccess::Owner
+        } else if campaigns.clone().any(|id| dm_campaigns.contains(&id)) {
+            CharacterAccess::CampaignDm
+        } else if campaigns.clone().any(|id| member_campaigns.contains(&id)) {
+            CharacterAccess::PartyMember
+        } else {
+            CharacterAccess::NoAccess
+        };
+    }
+    Ok(())
+}
+
+// A set of six ability scores the server rolled for a player in the creation wizard
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Deserialize)]
+struct AbilityRollSet {
+    id: i32,
+    dice: Vec<[i32; 4]>,
+}
+
+// Make sure every roll a new character claims is one the server rolled for `user`
+#[cfg(not(target_arch = "wasm32"))]
+async fn check_ability_rolls(
+    client: &crate::api::auth::ServerSupabaseClient,
+    user: &crate::api::auth::AuthenticatedUser,
+    rolls: &[AbilityRoll],
+) -> Result<(), ServerFnError> {
+    let mut set_ids: Vec<i32> = rolls.iter().map(|roll| roll.set).collect();
+    set_ids.sort_unstable();
+    set_ids.dedup();
+    let ids: Vec<String> = set_ids.iter().map(|id| id.to_string()).collect();
+
+    let issued: Vec<AbilityRollSet> = fetch_rows(
+        client.table("ability_roll_sets").select("id,dice").eq("owner", &user.id).in_("id", ids),
+        "ability roll sets",
+    )
+    .await?;
+    let genuine = set_ids.iter().all(|id| {
+        let claimed: Vec<[i32; 4]> = rolls.iter().filter(|roll| roll.set == *id).map(|roll| roll.dice).collect();
+        issued.iter().any(|set| set.id == *id && set.dice == claimed)
+    });
+    if !genuine {
+        return Err(ServerFnError::new("Those ability scores weren't rolled here; roll them again".to_string()));
+    }
+    Ok(())
+}
+
+// Load one character by id with the caller's access filled in
+#[cfg(not(target_arch = "wasm32"))]
+async fn load_with_access(
+    client: &crate::api::auth::ServerSupabaseClient,
+    user: &crate::api::auth::AuthenticatedUser,
+    id: i32,
+) -> Result<CharacterDetail, ServerFnError> {
+    let rows: Vec<SupabaseCharacterDetail> =
+        fetch_rows(client.table("characters").select("*").eq("id", id.to_string()), "character").await?;
+    let mut characters: Vec<CharacterDetail> = rows.into_iter().map(CharacterDetail::from).collect();
+    if characters.is_empty() {
+        return Err(ServerFnError::new(format!("Character with id {} not found", id)));
+    }
+    character_access(client, user, &mut characters).await?;
+    Ok(characters.remove(0))
+}
+
+// Rows typed in before the catalogs existed may hold values we don't recognise. Those read
+// back as unset, so the edit form asks for them again instead of the sheet showing junk.
+fn parse_catalog<T: TryFrom<String, Error = String>>(value: String) -> Option<T> {
+    if value.trim().is_empty() {
+        return None;
+    }
+    T::try_from(value)
+        .map_err(|e| tracing::warn!("Ignoring stored character value: {}", e))
+        .ok()
+}
+
 impl From<SupabaseCharacterDetail> for CharacterDetail {
     fn from(supabase_character: SupabaseCharacterDetail) -> Self {
         let skills = if let Some(skills_json) = supabase_character.skills {
@@ -92,87 +674,130 @@ impl From<SupabaseCharacterDetail> for CharacterDetail {
             vec![]
         };
 
-        let equipment = if let Some(equipment_json) = supabase_character.equipment {
-            if let Ok(equipment_vec) = serde_json::from_value::<Vec<String>>(equipment_json) {
-                equipment_vec
-            } else {
-                vec![]
-            }
-        } else {
-            vec![]
-        };
+        // Rows saved before the structured inventory only have a list of item names
+        let inventory = supabase_character
+            .inventory
+            .and_then(|inventory_json| serde_json::from_value::<Inventory>(inventory_json).ok())
+            .filter(|inventory| *inventory != Inventory::default())
+            .unwrap_or_else(|| {
+                let names = supabase_character
+                    .equipment
+                    .and_then(|equipment_json| serde_json::from_value::<Vec<String>>(equipment_json).ok())
+                    .unwrap_or_default();
+                Inventory::from_names(&names)
+            });
 
-        let spells = if let Some(spells_json) = supabase_character.spells {
-            if let Ok(spells_vec) = serde_json::from_value::<Vec<String>>(spells_json) {
-                spells_vec
-            } else {
-                vec![]
-            }
-        } else {
-            vec![]
-        };
+        // Rows saved before the spellbook only have a list of spell names
+        let spellbook = supabase_character
+            .spellbook
+            .and_then(|spellbook_json| serde_json::from_value::<Spellbook>(spellbook_json).ok())
+            .filter(|spellbook| *spellbook != Spellbook::default())
+            .unwrap_or_else(|| {
+                let names = supabase_character
+                    .spells
+                    .and_then(|spells_json| serde_json::from_value::<Vec<String>>(spells_json).ok())
+                    .unwrap_or_default();
+                Spellbook::from_names(&names)
+            });
 
-        let features = if let Some(features_json) = supabase_character.features {
-            if let Ok(features_vec) = serde_json::from_value::<Vec<String>>(features_json) {
-                features_vec
-            } else {
-                vec![]
-            }
-        } else {
-            vec![]
-        };
+        let play_state = supabase_character
+            .play_state
+            .and_then(|play_json| serde_json::from_value::<PlayState>(play_json).ok())
+            .unwrap_or_default();
+
+        // Rows saved before multiclassing only have the single class and level columns
+        let classes = supabase_character
+            .classes
+            .and_then(|classes_json| serde_json::from_value::<Vec<ClassLevel>>(classes_json).ok())
+            .filter(|classes| !classes.is_empty())
+            .unwrap_or_else(|| {
+                parse_catalog::<Class>(supabase_character.class)
+                    .map(|class| ClassLevel {
+                        class,
+                        level: supabase_character.level,
+                        subclass: supabase_character.subclass.and_then(parse_catalog),
+                    })
+                    .into_iter()
+                    .collect()
+            });
+        let level = multiclass::total_level(&classes);
+
+        // Rows saved before limited-use tracking hold plain feature names
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StoredFeature {
+            Name(String),
+            Feature(Feature),
+        }
+        let features = supabase_character
+            .features
+            .and_then(|features_json| serde_json::from_value::<Vec<StoredFeature>>(features_json).ok())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|stored| match stored {
+                StoredFeature::Name(name) => Feature::named(&name),
+                StoredFeature::Feature(feature) => feature,
+            })
+            .collect();
 
         CharacterDetail {
             id: supabase_character.id,
             name: supabase_character.name,
             slug: supabase_character.slug,
             description: supabase_character.description,
-            race: supabase_character.race,
-            class: supabase_character.class,
-            level: supabase_character.level,
-            background: supabase_character.background,
-            alignment: supabase_character.alignment,
+            race: parse_catalog(supabase_character.race),
+            classes,
+            background: parse_catalog(supabase_character.background),
+            alignment: parse_catalog(supabase_character.alignment),
             strength: supabase_character.strength,
             dexterity: supabase_character.dexterity,
             constitution: supabase_character.constitution,
             intelligence: supabase_character.intelligence,
             wisdom: supabase_character.wisdom,
             charisma: supabase_character.charisma,
+            ability_rolls: supabase_character
+                .ability_rolls
+                .and_then(|rolls_json| serde_json::from_value::<Vec<AbilityRoll>>(rolls_json).ok())
+                .unwrap_or_default(),
             hit_points: supabase_character.hit_points,
             armor_class: supabase_character.armor_class,
             speed: supabase_character.speed,
-            proficiency_bonus: supabase_character.proficiency_bonus,
+            proficiency_bonus: crate::rules::proficiency_bonus(level),
             skills,
             languages,
-            equipment,
-            spells,
+            inventory,
+            spellbook,
+            play_state,
             features,
             backstory: supabase_character.backstory,
             portrait_image: supabase_character.portrait_image,
             created_at: supabase_character.created_at,
             updated_at: supabase_character.updated_at,
             owner: supabase_character.owner,
+            access: CharacterAccess::NoAccess,
         }
     }
 }
 
 
 
-#[server(name = GetCharacter)]
+#[server(name = GetCharacter, client = crate::api::auth::AuthClient)]
 pub async fn get_character() -> Result<Vec<CharacterDetail>, ServerFnError> {
     #[cfg(not(target_arch = "wasm32"))]
     {
-        use crate::api::auth::create_server_client;
+        use crate::api::auth::{create_user_client, AuthenticatedUser};
         use tracing::info;
 
+        let user: AuthenticatedUser = extract().await?;
+
         info!("Fetching character details from Supabase...");
 
-        let client = create_server_client();
+        let client = create_user_client(&user);
 
         // Query character details with specific fields and ordering - remove any potential limits
         let resp = client
             .table("characters")
-            .select("id,name,slug,description,race,class,level,background,alignment,strength,dexterity,constitution,intelligence,wisdom,charisma,hit_points,armor_class,speed,proficiency_bonus,skills,languages,equipment,spells,features,backstory,portrait_image,created_at,updated_at,owner")
+            .select("id,name,slug,description,race,class,level,classes,background,alignment,subclass,strength,dexterity,constitution,intelligence,wisdom,charisma,ability_rolls,hit_points,armor_class,speed,proficiency_bonus,skills,languages,equipment,inventory,spells,spellbook,play_state,features,backstory,portrait_image,created_at,updated_at,owner")
             .order("created_at.desc")
             .limit(1000) // Explicitly set a high limit to ensure we get all characters
             .execute()
@@ -187,10 +812,14 @@ pub async fn get_character() -> Result<Vec<CharacterDetail>, ServerFnError> {
                     info!("Raw Supabase response: {}", text);
                     info!("Response length: {} characters", text.len());
 
-                    // Try to parse the JSON response into our CharacterDetail struct
-                    match serde_json::from_str::<Vec<CharacterDetail>>(&text) {
-                        Ok(character_details) => {
+                    // Parse the raw rows first so old free-text catalog values and feature lists still load
+                    match serde_json::from_str::<Vec<SupabaseCharacterDetail>>(&text) {
+                        Ok(rows) => {
+                            let mut character_details: Vec<CharacterDetail> = rows.into_iter().map(CharacterDetail::from).collect();
                             info!("Successfully parsed {} character details", character_details.len());
+                            // Only list the characters this user is allowed to see
+                            character_access(&client, &user, &mut character_details).await?;
+                            character_details.retain(|c| c.access.can_view());
                             for (i, character_detail) in character_details.iter().enumerate() {
                                 info!("Character Detail {}: '{}' (owner: '{}')", i + 1, character_detail.name, character_detail.owner);
                             }
@@ -235,21 +864,23 @@ pub async fn get_character() -> Result<Vec<CharacterDetail>, ServerFnError> {
 
 
 
-#[server(name = GetCharacterWithSlug)]
+#[server(name = GetCharacterWithSlug, client = crate::api::auth::AuthClient)]
 pub async fn get_character_with_slug(slug: String) -> Result<CharacterDetail, ServerFnError> {
     #[cfg(not(target_arch = "wasm32"))]
     {
-        use crate::api::auth::create_server_client;
+        use crate::api::auth::{create_user_client, AuthenticatedUser};
         use tracing::info;
 
+        let user: AuthenticatedUser = extract().await?;
+
         info!("Fetching character with slug '{}' from Supabase...", slug);
         
-        let client = create_server_client();
+        let client = create_user_client(&user);
 
         // Query specific character detail by slug
         let resp = client
             .table("characters")
-            .select("id,name,slug,description,race,class,level,background,alignment,strength,dexterity,constitution,intelligence,wisdom,charisma,hit_points,armor_class,speed,proficiency_bonus,skills,languages,equipment,spells,features,backstory,portrait_image,created_at,updated_at,owner")
+            .select("id,name,slug,description,race,class,level,classes,background,alignment,subclass,strength,dexterity,constitution,intelligence,wisdom,charisma,ability_rolls,hit_points,armor_class,speed,proficiency_bonus,skills,languages,equipment,inventory,spells,spellbook,play_state,features,backstory,portrait_image,created_at,updated_at,owner")
             .eq("slug", &slug)
             .single() // Use single() to get one result instead of an array
             .execute()
@@ -267,7 +898,12 @@ pub async fn get_character_with_slug(slug: String) -> Result<CharacterDetail, Se
                     match serde_json::from_str::<SupabaseCharacterDetail>(&text) {
                         Ok(supabase_character) => {
                             info!("Successfully parsed character detail: '{}'", supabase_character.name);
-                            let character_detail: CharacterDetail = supabase_character.into();
+                            let mut characters = vec![CharacterDetail::from(supabase_character)];
+                            character_access(&client, &user, &mut characters).await?;
+                            let character_detail = characters.remove(0);
+                            if !character_detail.access.can_view() {
+                                return Err(ServerFnError::new("You don't have access to this character".to_string()));
+                            }
                             Ok(character_detail)
                         }
                         Err(parse_error) => {
@@ -306,4 +942,798 @@ pub async fn get_character_with_slug(slug: String) -> Result<CharacterDetail, Se
     {
         Err(ServerFnError::new("Server function called on client side".to_string()))
     }
-}
\ No newline at end of file
+}
+
+
+
+#[server(name = CreateCharacter, client = crate::api::auth::AuthClient)]
+pub async fn create_character(character: CharacterDetail) -> Result<CharacterDetail, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::{create_user_client, AuthenticatedUser};
+        use tracing::info;
+
+        let user: AuthenticatedUser = extract().await?;
+
+        let mut errors = character.validate();
+        errors.extend(character.validate_rolled_scores());
+        if !errors.is_empty() {
+            return Err(ServerFnError::new(errors.join("\n")));
+        }
+
+        info!("Creating character '{}' in Supabase...", character.name);
+
+        let client = create_user_client(&user);
+
+        if !character.ability_rolls.is_empty() {
+            check_ability_rolls(&client, &user, &character.ability_rolls).await?;
+        }
+
+        let mut new_character = NewCharacter::from(character);
+        new_character.owner = user.id.clone();
+        new_character.slug = unique_slug(&client, "characters", &new_character.name, None).await?;
+
+        let body = serde_json::to_string(&new_character).map_err(|e| ServerFnError::new(e.to_string()))?;
+        let resp = client
+            .table("characters")
+            .insert(body)
+            .single()
+            .execute()
+            .await;
+
+        match resp {
+            Ok(response) => {
+                let status = response.status();
+                let text = response.text().await.map_err(|e| ServerFnError::new(e.to_string()))?;
+
+                if status.is_success() {
+                    info!("Raw Supabase response: {}", text);
+                    match serde_json::from_str::<SupabaseCharacterDetail>(&text) {
+                        Ok(supabase_character) => {
+                            info!("Created character '{}' with slug '{}'", supabase_character.name, supabase_character.slug);
+                            Ok(CharacterDetail { access: CharacterAccess::Owner, ..supabase_character.into() })
+                        }
+                        Err(parse_error) => {
+                            info!("JSON parsing failed: {}", parse_error);
+                            Err(ServerFnError::new(format!("Failed to parse created character JSON: {}", parse_error)))
+                        }
+                    }
+                } else {
+                    let error_msg = format!("Failed to create character. Status: {}, Response: {}", status, text);
+                    info!("{}", error_msg);
+                    Err(ServerFnError::new(error_msg))
+                }
+            }
+            Err(e) => {
+                let error_msg = format!("Request failed: {}", e);
+                info!("{}", error_msg);
+                Err(ServerFnError::new(error_msg))
+            }
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+
+
+/// Roll a set of six ability scores, 4d6 dropping the lowest, for the creation wizard. The
+/// server throws the dice and keeps the set, so a new character can only claim rolls it got.
+#[server(name = RollAbilityScores, client = crate::api::auth::AuthClient)]
+pub async fn roll_ability_scores() -> Result<Vec<AbilityRoll>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::{create_service_client, AuthenticatedUser};
+        use crate::rules::creation;
+        use tracing::info;
+
+        #[derive(Deserialize)]
+        struct SavedSet {
+            id: i32,
+        }
+
+        let user: AuthenticatedUser = extract().await?;
+
+        let mut rolls = creation::roll_ability_scores(0, &mut rand::thread_rng());
+        let dice: Vec<[i32; 4]> = rolls.iter().map(|roll| roll.dice).collect();
+        let body = serde_json::json!({ "owner": user.id, "dice": dice }).to_string();
+
+        // Players can't write these themselves, or they could keep any set they liked
+        let saved: Vec<SavedSet> =
+            fetch_rows(create_service_client()?.table("ability_roll_sets").insert(body), "ability roll set").await?;
+        let Some(set) = saved.into_iter().next() else {
+            return Err(ServerFnError::new("Failed to save the ability rolls".to_string()));
+        };
+        for roll in rolls.iter_mut() {
+            roll.set = set.id;
+        }
+
+        info!("Rolled ability score set {} for {}", set.id, user.id);
+        Ok(rolls)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = UpdateCharacter, client = crate::api::auth::AuthClient)]
+pub async fn update_character(character: CharacterDetail) -> Result<CharacterDetail, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::{create_user_client, AuthenticatedUser};
+        use tracing::info;
+
+        let user: AuthenticatedUser = extract().await?;
+
+        let errors = character.validate();
+        if !errors.is_empty() {
+            return Err(ServerFnError::new(errors.join("\n")));
+        }
+
+        info!("Updating character {} ('{}') in Supabase...", character.id, character.name);
+
+        let client = create_user_client(&user);
+
+        let id = character.id;
+        let stored = load_with_access(&client, &user, id).await?;
+        if !stored.access.can_edit() {
+            return Err(ServerFnError::new("You don't have permission to edit this character".to_string()));
+        }
+
+        // The form overwrites the play state, spellbook, features and inventory too, so an
+        // editor opened before someone played the character must not undo their changes
+        let loaded_at = character.updated_at.clone();
+        if loaded_at != stored.updated_at {
+            return Err(ServerFnError::new(
+                "The character was changed since you opened it; reload to see the changes".to_string(),
+            ));
+        }
+
+        let mut updated_character = NewCharacter::from(character);
+        // Ownership never changes through an edit, and neither do the rolls made at creation
+        updated_character.owner = stored.owner;
+        updated_character.ability_rolls = stored.ability_rolls;
+        // Renaming a character moves it to a new slug; keeping the name keeps the stored slug
+        updated_character.slug = if slug_matches_name(&stored.slug, &updated_character.name) {
+            stored.slug
+        } else {
+            unique_slug(&client, "characters", &updated_character.name, Some(id)).await?
+        };
+        updated_character.updated_at = Some(chrono::Utc::now().to_rfc3339());
+
+        let body = serde_json::to_string(&updated_character).map_err(|e| ServerFnError::new(e.to_string()))?;
+        let query = client.table("characters").update(body).eq("id", id.to_string());
+        let query = match &loaded_at {
+            Some(updated_at) => query.eq("updated_at", updated_at),
+            None => query.is("updated_at", "null"),
+        };
+        let rows: Vec<SupabaseCharacterDetail> = fetch_rows(query, "character").await?;
+        match rows.into_iter().next() {
+            Some(supabase_character) => {
+                info!("Updated character '{}'", supabase_character.name);
+                crate::api::campaign::announce_character_change(id).await;
+                Ok(CharacterDetail { access: stored.access, ..supabase_character.into() })
+            }
+            None => Err(ServerFnError::new(
+                "The character was changed since you opened it; reload to see the changes".to_string(),
+            )),
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+
+
+#[server(name = DeleteCharacter, client = crate::api::auth::AuthClient)]
+pub async fn delete_character(id: i32) -> Result<(), ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::{create_user_client, AuthenticatedUser};
+        use tracing::info;
+
+        let user: AuthenticatedUser = extract().await?;
+
+        info!("Deleting character {} from Supabase...", id);
+
+        let client = create_user_client(&user);
+
+        if !load_with_access(&client, &user, id).await?.access.can_delete() {
+            return Err(ServerFnError::new("Only the character's owner can delete it".to_string()));
+        }
+
+        let resp = client
+            .table("characters")
+            .delete()
+            .eq("id", id.to_string())
+            .execute()
+            .await;
+
+        match resp {
+            Ok(response) => {
+                let status = response.status();
+                let text = response.text().await.map_err(|e| ServerFnError::new(e.to_string()))?;
+
+                if status.is_success() {
+                    info!("Raw Supabase response: {}", text);
+                    let deleted = serde_json::from_str::<Vec<serde_json::Value>>(&text).map(|rows| rows.len()).unwrap_or(0);
+                    if deleted == 0 {
+                        Err(ServerFnError::new(format!("Character with id {} not found", id)))
+                    } else {
+                        Ok(())
+                    }
+                } else {
+                    let error_msg = format!("Failed to delete character. Status: {}, Response: {}", status, text);
+                    info!("{}", error_msg);
+                    Err(ServerFnError::new(error_msg))
+                }
+            }
+            Err(e) => {
+                let error_msg = format!("Request failed: {}", e);
+                info!("{}", error_msg);
+                Err(ServerFnError::new(error_msg))
+            }
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+
+
+/// One row of a character's level-up history, kept so the DM can audit how it got where it is
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct LevelUpRecord {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i32>,
+    pub character_id: i32,
+    /// Character level reached
+    pub level: i32,
+    pub class: Class,
+    /// Level reached in `class`
+    pub class_level: i32,
+    pub subclass: Option<Subclass>,
+    pub hit_point_method: HitPointMethod,
+    pub hit_die: i32,
+    /// The server's roll, when the player rolled for hit points
+    pub hit_die_roll: Option<i32>,
+    pub hit_points_gained: i32,
+    pub improvement: Option<Improvement>,
+    pub features: Vec<String>,
+    pub spells: Vec<String>,
+    pub created_by: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+}
+
+#[server(name = LevelUpCharacter, client = crate::api::auth::AuthClient)]
+pub async fn level_up_character(id: i32, choice: LevelUpChoice) -> Result<CharacterDetail, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::{create_user_client, AuthenticatedUser};
+        use crate::rules::{dice, leveling};
+        use tracing::info;
+
+        let user: AuthenticatedUser = extract().await?;
+
+        info!("Levelling up character {} in {}...", id, choice.class);
+
+        let client = create_user_client(&user);
+
+        // The catalog's copies of the chosen spells, so the character learns those and not
+        // whatever the client sent
+        let catalog: Vec<crate::api::spells::Spell> = if choice.new_spells.is_empty() {
+            vec![]
+        } else {
+            use crate::api::homebrew::CatalogReader;
+            use crate::api::spells::SPELL_COLUMNS;
+
+            let slugs: Vec<&str> = choice.new_spells.iter().map(|entry| entry.spell.as_str()).collect();
+            let reader = CatalogReader::for_caller().await?;
+            let request = reader.client.table("spells").select(SPELL_COLUMNS).in_("slug", slugs);
+            fetch_rows(reader.narrow(request, None), "spells").await?
+        };
+
+        // Rolled once, so starting over below doesn't give the player another go at it
+        let hit_die = choice.class.hit_die();
+        let roll = (choice.hit_points == HitPointMethod::Roll).then(|| dice::die(hit_die, &mut rand::thread_rng()));
+
+        // The sheet may be in play while the character levels up. Each attempt only writes if the
+        // row is unchanged since it was read, and otherwise starts over from the new row, as
+        // long as nobody else has levelled the character in the meantime.
+        let mut from_level = None;
+        for _ in 0..3 {
+            let stored = load_with_access(&client, &user, id).await?;
+            if !stored.access.can_edit() {
+                return Err(ServerFnError::new("You don't have permission to level up this character".to_string()));
+            }
+            if *from_level.get_or_insert(stored.level()) != stored.level() {
+                return Err(ServerFnError::new(format!(
+                    "{} was levelled up while you were levelling up; reload the sheet and try again",
+                    stored.name
+                )));
+            }
+
+            let mut choice = choice.clone();
+            if !choice.new_spells.is_empty() {
+                let class_level = stored.classes.iter().find(|c| c.class == choice.class).map_or(1, |c| c.level + 1);
+                choice.new_spells = leveling::catalog_spells(choice.class, class_level, &choice.new_spells, &catalog)
+                    .map_err(|errors| ServerFnError::new(errors.join("\n")))?;
+            }
+            let level_up = leveling::level_up(&stored, &choice, roll).map_err(|errors| ServerFnError::new(errors.join("\n")))?;
+
+            let mut updated_character = NewCharacter::from(level_up.character.clone());
+            updated_character.owner = stored.owner.clone();
+            updated_character.updated_at = Some(chrono::Utc::now().to_rfc3339());
+
+            let body = serde_json::to_string(&updated_character).map_err(|e| ServerFnError::new(e.to_string()))?;
+            let query = client.table("characters").update(body).eq("id", id.to_string());
+            let query = match &stored.updated_at {
+                Some(updated_at) => query.eq("updated_at", updated_at),
+                None => query.is("updated_at", "null"),
+            };
+            let rows: Vec<SupabaseCharacterDetail> = fetch_rows(query, "levelled character").await?;
+            let Some(supabase_character) = rows.into_iter().next() else {
+                info!("Character {} changed while levelling up; retrying", id);
+                continue;
+            };
+
+            let record = LevelUpRecord {
+                id: None,
+                character_id: id,
+                level: level_up.character.level(),
+                class: choice.class,
+                class_level: level_up.class_level,
+                subclass: level_up.character.classes.iter().find(|c| c.class == choice.class).and_then(|c| c.subclass),
+                hit_point_method: choice.hit_points,
+                hit_die,
+                hit_die_roll: roll,
+                hit_points_gained: level_up.hit_points_gained,
+                improvement: choice.improvement.filter(|_| leveling::grants_ability_score_improvement(choice.class, level_up.class_level)),
+                features: level_up.features,
+                spells: choice.new_spells.iter().map(|entry| entry.name.clone()).collect(),
+                created_by: user.id.clone(),
+                created_at: None,
+            };
+            let body = serde_json::to_string(&record).map_err(|e| ServerFnError::new(e.to_string()))?;
+            let recorded: Result<Vec<LevelUpRecord>, ServerFnError> =
+                fetch_rows(client.table("character_level_ups").insert(body), "level-up history").await;
+            if let Err(e) = recorded {
+                // The character has already been levelled, so log it rather than failing the request
+                tracing::warn!("Levelled character {} but could not record the history: {}", id, e);
+            }
+
+            info!("Levelled '{}' to {} {}", supabase_character.name, choice.class, level_up.class_level);
+            crate::api::campaign::announce_character_change(id).await;
+            return Ok(CharacterDetail { access: stored.access, ..supabase_character.into() });
+        }
+        Err(ServerFnError::new("The character is being changed by someone else; try again".to_string()))
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+
+
+#[server(name = GetLevelHistory, client = crate::api::auth::AuthClient)]
+pub async fn get_level_history(character_id: i32) -> Result<Vec<LevelUpRecord>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::{create_user_client, AuthenticatedUser};
+        use tracing::info;
+
+        let user: AuthenticatedUser = extract().await?;
+
+        info!("Fetching level-up history for character {}...", character_id);
+
+        let client = create_user_client(&user);
+
+        // The history is for the owner and the DM; party members only see the sheet
+        if !load_with_access(&client, &user, character_id).await?.access.can_edit() {
+            return Err(ServerFnError::new("You don't have access to this character's level history".to_string()));
+        }
+
+        fetch_rows(
+            client
+                .table("character_level_ups")
+                .select("*")
+                .eq("character_id", character_id.to_string())
+                .order("created_at.asc"),
+            "level-up history",
+        )
+        .await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+
+
+#[server(name = UpdateInventory, client = crate::api::auth::AuthClient)]
+pub async fn update_inventory(id: i32, inventory: Inventory, loaded_at: Option<String>) -> Result<CharacterDetail, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::{create_user_client, AuthenticatedUser};
+        use tracing::info;
+
+        // Only the inventory columns, so managing loot never overwrites the rest of the sheet
+        #[derive(Serialize)]
+        struct InventoryUpdate {
+            inventory: Inventory,
+            equipment: Vec<String>,
+            updated_at: String,
+        }
+
+        let user: AuthenticatedUser = extract().await?;
+
+        let errors = inventory.validate();
+        if !errors.is_empty() {
+            return Err(ServerFnError::new(errors.join("\n")));
+        }
+
+        info!("Updating inventory of character {} ({} items)...", id, inventory.items.len());
+
+        let client = create_user_client(&user);
+
+        let stored = load_with_access(&client, &user, id).await?;
+        if !stored.access.can_edit() {
+            return Err(ServerFnError::new("You don't have permission to edit this character".to_string()));
+        }
+        // The whole inventory is replaced, so a copy loaded before someone else changed the
+        // character must not undo their changes
+        if loaded_at != stored.updated_at {
+            return Err(ServerFnError::new(
+                "The character was changed since you opened it; reload to see the changes".to_string(),
+            ));
+        }
+
+        let update = InventoryUpdate {
+            equipment: inventory.equipment_names(),
+            inventory,
+            updated_at: chrono::Utc::now().to_rfc3339(),
+        };
+        let body = serde_json::to_string(&update).map_err(|e| ServerFnError::new(e.to_string()))?;
+        let query = client.table("characters").update(body).eq("id", id.to_string());
+        let query = match &loaded_at {
+            Some(updated_at) => query.eq("updated_at", updated_at),
+            None => query.is("updated_at", "null"),
+        };
+        let rows: Vec<SupabaseCharacterDetail> = fetch_rows(query, "character inventory").await?;
+        let Some(supabase_character) = rows.into_iter().next() else {
+            return Err(ServerFnError::new(
+                "The character was changed since you opened it; reload to see the changes".to_string(),
+            ));
+        };
+
+        info!("Updated inventory of '{}'", supabase_character.name);
+        crate::api::campaign::announce_character_change(id).await;
+        Ok(CharacterDetail { access: stored.access, ..supabase_character.into() })
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+
+
+#[server(name = UpdateSpellbook, client = crate::api::auth::AuthClient)]
+pub async fn update_spellbook(id: i32, spellbook: Spellbook, loaded_at: Option<String>) -> Result<CharacterDetail, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::{create_user_client, AuthenticatedUser};
+        use tracing::info;
+
+        // Only the spell columns, so casting and preparing never overwrite the rest of the sheet
+        #[derive(Serialize)]
+        struct SpellbookUpdate {
+            spellbook: Spellbook,
+            spells: Vec<String>,
+            updated_at: String,
+        }
+
+        let user: AuthenticatedUser = extract().await?;
+
+        info!("Updating spellbook of character {} ({} spells)...", id, spellbook.entries.len());
+
+        let client = create_user_client(&user);
+
+        let stored = load_with_access(&client, &user, id).await?;
+        if !stored.access.can_edit() {
+            return Err(ServerFnError::new("You don't have permission to edit this character".to_string()));
+        }
+        // The whole spellbook is replaced, so a copy loaded before someone else changed the
+        // character must not undo their changes
+        if loaded_at != stored.updated_at {
+            return Err(ServerFnError::new(
+                "The character was changed since you opened it; reload to see the changes".to_string(),
+            ));
+        }
+
+        // Preparation limits and slot counts depend on the stored classes and scores
+        let errors = spellbook.validate(&stored.classes, |ability| ability.score(&stored));
+        if !errors.is_empty() {
+            return Err(ServerFnError::new(errors.join("\n")));
+        }
+
+        let update = SpellbookUpdate {
+            spells: spellbook.names(),
+            spellbook,
+            updated_at: chrono::Utc::now().to_rfc3339(),
+        };
+        let body = serde_json::to_string(&update).map_err(|e| ServerFnError::new(e.to_string()))?;
+        let query = client.table("characters").update(body).eq("id", id.to_string());
+        let query = match &loaded_at {
+            Some(updated_at) => query.eq("updated_at", updated_at),
+            None => query.is("updated_at", "null"),
+        };
+        let rows: Vec<SupabaseCharacterDetail> = fetch_rows(query, "character spellbook").await?;
+        let Some(supabase_character) = rows.into_iter().next() else {
+            return Err(ServerFnError::new(
+                "The character was changed since you opened it; reload to see the changes".to_string(),
+            ));
+        };
+
+        info!("Updated spellbook of '{}'", supabase_character.name);
+        crate::api::campaign::announce_character_change(id).await;
+        Ok(CharacterDetail { access: stored.access, ..supabase_character.into() })
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = UpdateFeatures, client = crate::api::auth::AuthClient)]
+pub async fn update_features(id: i32, features: Vec<Feature>, loaded_at: Option<String>) -> Result<CharacterDetail, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::{create_user_client, AuthenticatedUser};
+        use tracing::info;
+
+        #[derive(Serialize)]
+        struct FeaturesUpdate {
+            features: Vec<Feature>,
+            updated_at: String,
+        }
+
+        let user: AuthenticatedUser = extract().await?;
+
+        info!("Updating features of character {} ({} features)...", id, features.len());
+
+        let client = create_user_client(&user);
+
+        let stored = load_with_access(&client, &user, id).await?;
+        if !stored.access.can_edit() {
+            return Err(ServerFnError::new("You don't have permission to edit this character".to_string()));
+        }
+        // The whole feature list is replaced, so a copy loaded before someone else changed the
+        // character must not undo their changes
+        if loaded_at != stored.updated_at {
+            return Err(ServerFnError::new(
+                "The character was changed since you opened it; reload to see the changes".to_string(),
+            ));
+        }
+
+        let errors = features::validate(&features);
+        if !errors.is_empty() {
+            return Err(ServerFnError::new(errors.join("\n")));
+        }
+
+        let update = FeaturesUpdate { features, updated_at: chrono::Utc::now().to_rfc3339() };
+        let body = serde_json::to_string(&update).map_err(|e| ServerFnError::new(e.to_string()))?;
+        let query = client.table("characters").update(body).eq("id", id.to_string());
+        let query = match &loaded_at {
+            Some(updated_at) => query.eq("updated_at", updated_at),
+            None => query.is("updated_at", "null"),
+        };
+        let rows: Vec<SupabaseCharacterDetail> = fetch_rows(query, "character features").await?;
+        let Some(supabase_character) = rows.into_iter().next() else {
+            return Err(ServerFnError::new(
+                "The character was changed since you opened it; reload to see the changes".to_string(),
+            ));
+        };
+
+        info!("Updated features of '{}'", supabase_character.name);
+        crate::api::campaign::announce_character_change(id).await;
+        Ok(CharacterDetail { access: stored.access, ..supabase_character.into() })
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+
+
+/// A character after a play action, and a line describing what happened
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PlayUpdate {
+    pub character: CharacterDetail,
+    pub message: String,
+}
+
+#[server(name = ApplyPlayAction, client = crate::api::auth::AuthClient)]
+pub async fn apply_play_action(id: i32, action: PlayAction) -> Result<PlayUpdate, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::{create_user_client, AuthenticatedUser};
+        use crate::rules::{dice, play};
+        use tracing::info;
+
+        // Rests also bring back spell slots and feature uses, so those are written alongside the play state
+        #[derive(Serialize)]
+        struct PlayStateUpdate {
+            play_state: PlayState,
+            spellbook: Spellbook,
+            features: Vec<Feature>,
+            updated_at: String,
+        }
+
+        let user: AuthenticatedUser = extract().await?;
+
+        info!("Applying {:?} to character {}...", action, id);
+
+        let client = create_user_client(&user);
+
+        // The player and the DM may both be changing the character mid-session. Each attempt
+        // only writes if the row is unchanged since it was read, and otherwise starts over.
+        for _ in 0..3 {
+            let stored = load_with_access(&client, &user, id).await?;
+            if !stored.access.can_edit() {
+                return Err(ServerFnError::new("You don't have permission to play this character".to_string()));
+            }
+
+            let mut character = stored.clone();
+            let message = {
+                let mut rng = rand::thread_rng();
+                play::apply(&mut character, &action, &mut |sides| dice::die(sides, &mut rng)).map_err(ServerFnError::new)?
+            };
+
+            let update = PlayStateUpdate {
+                play_state: character.play_state,
+                spellbook: character.spellbook,
+                features: character.features,
+                updated_at: chrono::Utc::now().to_rfc3339(),
+            };
+            let body = serde_json::to_string(&update).map_err(|e| ServerFnError::new(e.to_string()))?;
+            let query = client.table("characters").update(body).eq("id", id.to_string());
+            let query = match &stored.updated_at {
+                Some(updated_at) => query.eq("updated_at", updated_at),
+                None => query.is("updated_at", "null"),
+            };
+            let rows: Vec<SupabaseCharacterDetail> = fetch_rows(query, "character play state").await?;
+            if let Some(supabase_character) = rows.into_iter().next() {
+                info!("{}", message);
+                crate::api::campaign::announce_character_change(id).await;
+                return Ok(PlayUpdate {
+                    character: CharacterDetail { access: stored.access, ..supabase_character.into() },
+                    message,
+                });
+            }
+            info!("Character {} changed while applying {:?}; retrying", id, action);
+        }
+        Err(ServerFnError::new("The character is being changed by someone else; try again".to_string()))
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A row as it was stored before the catalogs, multiclassing, the inventory, the spellbook
+    // and limited-use features
+    fn legacy_row() -> serde_json::Value {
+        serde_json::json!({
+            "id": 7,
+            "name": "Grog",
+            "slug": "grog",
+            "description": "",
+            "race": "Half-Giant",
+            "class": "barbarian",
+            "subclass": null,
+            "level": 3,
+            "background": "Outlander",
+            "alignment": "Chaotic-ish",
+            "strength": 18,
+            "dexterity": 12,
+            "constitution": 16,
+            "intelligence": 6,
+            "wisdom": 10,
+            "charisma": 8,
+            "hit_points": 35,
+            "armor_class": 14,
+            "speed": 30,
+            "proficiency_bonus": 2,
+            "skills": ["Athletics"],
+            "languages": ["Common"],
+            "equipment": ["Greataxe", "Javelin"],
+            "spells": null,
+            "features": [
+                "Rage",
+                "Unarmored Defense",
+                { "name": "Goliath Grip", "description": "Homebrew", "uses": null }
+            ],
+            "backstory": "",
+            "portrait_image": null,
+            "created_at": null,
+            "updated_at": null,
+            "owner": "owner"
+        })
+    }
+
+    #[test]
+    fn legacy_rows_convert_leniently() {
+        let row: SupabaseCharacterDetail = serde_json::from_value(legacy_row()).unwrap();
+        let character = CharacterDetail::from(row);
+
+        assert_eq!(character.race, None);
+        assert_eq!(character.alignment, None);
+        assert_eq!(character.background, Some(Background::Outlander));
+        assert_eq!(character.classes, vec![ClassLevel::new(Class::Barbarian, 3)]);
+        assert_eq!(character.inventory.equipment_names(), vec!["Greataxe".to_string(), "Javelin".to_string()]);
+        assert!(character.spellbook.entries.is_empty());
+    }
+
+    #[test]
+    fn plain_feature_names_become_features() {
+        let row: SupabaseCharacterDetail = serde_json::from_value(legacy_row()).unwrap();
+        let features = CharacterDetail::from(row).features;
+
+        let names: Vec<&str> = features.iter().map(|feature| feature.name.as_str()).collect();
+        assert_eq!(names, vec!["Rage", "Unarmored Defense", "Goliath Grip"]);
+        // Known class resources pick up their uses; everything else is kept as it was stored
+        assert!(features[0].uses.is_some());
+        assert!(features[1].uses.is_none());
+        assert_eq!(features[2].description, "Homebrew");
+    }
+
+    #[test]
+    fn rolled_scores_come_from_the_latest_set() {
+        let roll = |set, dice| AbilityRoll { set, dice };
+        let mut character = crate::rules::tests::character(vec![ClassLevel::new(Class::Fighter, 1)]);
+        character.ability_rolls = vec![
+            roll(3, [6, 6, 6, 6]),
+            roll(3, [6, 6, 6, 6]),
+            roll(3, [6, 6, 6, 6]),
+            roll(3, [6, 6, 6, 6]),
+            roll(3, [6, 6, 6, 6]),
+            roll(3, [6, 6, 6, 6]),
+            roll(8, [1, 2, 3, 4]),
+            roll(8, [2, 2, 2, 2]),
+            roll(8, [5, 5, 5, 1]),
+            roll(8, [3, 3, 3, 3]),
+            roll(8, [6, 5, 4, 3]),
+            roll(8, [4, 4, 4, 4]),
+        ];
+        // The latest set's 9, 6, 15, 9, 15 and 12 in another order, plus one each for being Human
+        [character.strength, character.dexterity, character.constitution] = [16, 10, 7];
+        [character.intelligence, character.wisdom, character.charisma] = [16, 13, 10];
+        assert!(character.validate_rolled_scores().is_empty());
+
+        // Scores from an earlier set, or one nudged up, don't count
+        character.strength = 19;
+        assert_eq!(character.validate_rolled_scores().len(), 1);
+        character.strength = 17;
+        assert_eq!(character.validate_rolled_scores().len(), 1);
+
+        // Without rolls there is nothing to check against
+        character.ability_rolls.clear();
+        assert!(character.validate_rolled_scores().is_empty());
+    }
+}
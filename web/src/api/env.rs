@@ -6,6 +6,12 @@ pub struct EnvConfig {
     pub supabase_project_id: String,
     pub supabase_url: String,
     pub supabase_anon_key: String,
+    /// Verifies callers' access tokens on the server. Never built into the web client.
+    #[serde(default)]
+    pub supabase_jwt_secret: Option<String>,
+    /// Writes the rows callers may not, like the dice in the roll log. Never built into the web client.
+    #[serde(default)]
+    pub supabase_service_role_key: Option<String>,
 }
 
 impl EnvConfig {
@@ -24,6 +30,8 @@ impl EnvConfig {
                 supabase_project_id: env!("APP_PUBLIC_ID").to_string(),
                 supabase_url: env!("APP_PUBLIC_SUPABASE_URL").to_string(),
                 supabase_anon_key: env!("APP_PUBLIC_SUPABASE_ANON_KEY").to_string(),
+                supabase_jwt_secret: None,
+                supabase_service_role_key: None,
             }
         }
     }
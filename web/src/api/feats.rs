@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use dioxus::prelude::*;
+use crate::api::homebrew::{first_version, srd_source};
+
+// The shared feat catalog. The SRD has a single feat; the rest come from homebrew packs.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Feat {
+    pub id: i32,
+    pub slug: String,
+    pub name: String,
+    /// e.g. "Strength 13 or higher"; empty when anyone can take it
+    #[serde(default)]
+    pub prerequisite: String,
+    #[serde(default)]
+    pub description: String,
+    /// The homebrew pack this feat belongs to; `None` for SRD feats
+    #[serde(default)]
+    pub pack_id: Option<i32>,
+    /// "SRD 5.1", or the name of its homebrew pack
+    #[serde(default = "srd_source")]
+    pub source: String,
+    #[serde(default = "first_version")]
+    pub version: i32,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const FEAT_COLUMNS: &str = "id,slug,name,prerequisite,description,pack_id,source,version";
+
+/// Feats whose name contains `query`, by name. `homebrew` keeps only homebrew or only SRD feats.
+#[server(name = ListFeats, client = crate::api::auth::AuthClient)]
+pub async fn list_feats(query: String, homebrew: Option<bool>) -> Result<Vec<Feat>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::character::{fetch_rows, filter_name};
+        use crate::api::homebrew::CatalogReader;
+        use tracing::info;
+
+        info!("Listing feats for '{}' (homebrew: {:?})", query, homebrew);
+
+        let reader = CatalogReader::for_caller().await?;
+        let request = reader.client.table("feats").select(FEAT_COLUMNS).order("name.asc").limit(200);
+        let feats: Vec<Feat> = fetch_rows(reader.narrow(filter_name(request, &query), homebrew), "feats").await?;
+        info!("Found {} feats", feats.len());
+        Ok(feats)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
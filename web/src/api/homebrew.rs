@@ -0,0 +1,768 @@
+use serde::{Deserialize, Serialize};
+use dioxus::prelude::*;
+use crate::api::feats::Feat;
+use crate::api::items::CatalogItem;
+use crate::api::monsters::{Monster, Speed};
+use crate::api::spells::{Components, Spell};
+use crate::rules::{CreatureType, ItemCategory, MagicSchool, Rarity, Size};
+
+#[cfg(feature = "server")]
+use crate::api::auth::{AuthenticatedUser, ServerSupabaseClient};
+
+/// The `source` of catalog entries from the System Reference Document
+pub const SRD_SOURCE: &str = "SRD 5.1";
+
+pub(crate) fn srd_source() -> String {
+    SRD_SOURCE.to_string()
+}
+
+pub(crate) fn first_version() -> i32 {
+    1
+}
+
+// A user's collection of homebrew entries. Entries are rows of the catalog tables marked
+// with the pack's id, and take the pack's name as their `source`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct HomebrewPack {
+    pub id: i32,
+    pub slug: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub owner: String,
+    /// Anyone may browse a shared pack, and DMs may enable it in their campaigns
+    #[serde(default)]
+    pub shared: bool,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+impl HomebrewPack {
+    pub fn new(name: String, description: String) -> Self {
+        Self {
+            id: 0,
+            slug: String::new(),
+            name,
+            description,
+            owner: String::new(),
+            shared: false,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+/// The catalogs homebrew can be written for
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum HomebrewKind {
+    Spell,
+    Monster,
+    Item,
+    Feat,
+}
+
+impl HomebrewKind {
+    pub const ALL: &'static [HomebrewKind] = &[HomebrewKind::Spell, HomebrewKind::Monster, HomebrewKind::Item, HomebrewKind::Feat];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            HomebrewKind::Spell => "Spell",
+            HomebrewKind::Monster => "Monster",
+            HomebrewKind::Item => "Item",
+            HomebrewKind::Feat => "Feat",
+        }
+    }
+
+    pub fn plural(self) -> &'static str {
+        match self {
+            HomebrewKind::Spell => "Spells",
+            HomebrewKind::Monster => "Monsters",
+            HomebrewKind::Item => "Items",
+            HomebrewKind::Feat => "Feats",
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn table(self) -> &'static str {
+        match self {
+            HomebrewKind::Spell => "spells",
+            HomebrewKind::Monster => "monsters",
+            HomebrewKind::Item => "items",
+            HomebrewKind::Feat => "feats",
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn columns(self) -> &'static str {
+        match self {
+            HomebrewKind::Spell => crate::api::spells::SPELL_COLUMNS,
+            HomebrewKind::Monster => crate::api::monsters::MONSTER_COLUMNS,
+            HomebrewKind::Item => crate::api::items::ITEM_COLUMNS,
+            HomebrewKind::Feat => crate::api::feats::FEAT_COLUMNS,
+        }
+    }
+}
+
+/// One homebrew spell, monster, item or feat, as the catalog row it is stored as
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum HomebrewEntry {
+    Spell(Spell),
+    Monster(Box<Monster>),
+    Item(CatalogItem),
+    Feat(Feat),
+}
+
+// Run `$body` on whichever catalog row `$entry` holds; they share the homebrew fields
+macro_rules! on_row {
+    ($entry:expr, $row:ident => $body:expr) => {
+        match $entry {
+            HomebrewEntry::Spell($row) => $body,
+            HomebrewEntry::Monster($row) => $body,
+            HomebrewEntry::Item($row) => $body,
+            HomebrewEntry::Feat($row) => $body,
+        }
+    };
+}
+
+impl HomebrewEntry {
+    /// A new, empty entry of `kind` to fill in
+    pub fn blank(kind: HomebrewKind) -> Self {
+        match kind {
+            HomebrewKind::Spell => HomebrewEntry::Spell(Spell {
+                id: 0,
+                slug: String::new(),
+                name: String::new(),
+                level: 1,
+                school: MagicSchool::Evocation,
+                ritual: false,
+                concentration: false,
+                classes: vec![],
+                casting_time: "1 action".to_string(),
+                range: String::new(),
+                components: Components { verbal: true, somatic: true, material: None },
+                duration: "Instantaneous".to_string(),
+                description: String::new(),
+                higher_levels: String::new(),
+                pack_id: None,
+                source: srd_source(),
+                version: first_version(),
+            }),
+            HomebrewKind::Monster => HomebrewEntry::Monster(Box::new(Monster {
+                id: 0,
+                slug: String::new(),
+                name: String::new(),
+                size: Size::Medium,
+                monster_type: CreatureType::Humanoid,
+                subtype: String::new(),
+                alignment: "unaligned".to_string(),
+                armor_class: 10,
+                armor_desc: String::new(),
+                hit_points: 4,
+                hit_dice: "1d8".to_string(),
+                speed: Speed { walk: 30, ..Speed::default() },
+                strength: 10,
+                dexterity: 10,
+                constitution: 10,
+                intelligence: 10,
+                wisdom: 10,
+                charisma: 10,
+                saving_throws: vec![],
+                skills: vec![],
+                damage_vulnerabilities: String::new(),
+                damage_resistances: String::new(),
+                damage_immunities: String::new(),
+                condition_immunities: String::new(),
+                senses: String::new(),
+                languages: String::new(),
+                challenge_rating: 0.0,
+                environments: vec![],
+                traits: vec![],
+                actions: vec![],
+                reactions: vec![],
+                legendary_actions_per_round: 0,
+                legendary_actions: vec![],
+                pack_id: None,
+                source: srd_source(),
+                version: first_version(),
+            })),
+            HomebrewKind::Item => HomebrewEntry::Item(CatalogItem {
+                id: 0,
+                slug: String::new(),
+                name: String::new(),
+                category: ItemCategory::WondrousItem,
+                rarity: Rarity::Common,
+                requires_attunement: false,
+                weight: 0.0,
+                value: 0,
+                description: String::new(),
+                pack_id: None,
+                source: srd_source(),
+                version: first_version(),
+            }),
+            HomebrewKind::Feat => HomebrewEntry::Feat(Feat {
+                id: 0,
+                slug: String::new(),
+                name: String::new(),
+                prerequisite: String::new(),
+                description: String::new(),
+                pack_id: None,
+                source: srd_source(),
+                version: first_version(),
+            }),
+        }
+    }
+
+    pub fn kind(&self) -> HomebrewKind {
+        match self {
+            HomebrewEntry::Spell(_) => HomebrewKind::Spell,
+            HomebrewEntry::Monster(_) => HomebrewKind::Monster,
+            HomebrewEntry::Item(_) => HomebrewKind::Item,
+            HomebrewEntry::Feat(_) => HomebrewKind::Feat,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        on_row!(self, row => &row.name)
+    }
+
+    /// Empty until the entry is first saved
+    pub fn slug(&self) -> &str {
+        on_row!(self, row => &row.slug)
+    }
+
+    pub fn version(&self) -> i32 {
+        on_row!(self, row => row.version)
+    }
+
+    /// One line on what the entry is: "3rd level evocation", "Wondrous item, rare"
+    pub fn summary(&self) -> String {
+        match self {
+            HomebrewEntry::Spell(spell) => format!("{} {}", spell.level_label(), spell.school.name().to_lowercase()),
+            HomebrewEntry::Monster(monster) => format!("{}, CR {}", monster.kind_line(), monster.challenge_label()),
+            HomebrewEntry::Item(item) => item.kind_line(),
+            HomebrewEntry::Feat(feat) if feat.prerequisite.is_empty() => "Feat".to_string(),
+            HomebrewEntry::Feat(feat) => format!("Feat, requires {}", feat.prerequisite),
+        }
+    }
+
+    /// The entry's rules text; monsters, which have stat blocks instead, get their defenses
+    pub fn description(&self) -> String {
+        match self {
+            HomebrewEntry::Spell(spell) => spell.description.clone(),
+            HomebrewEntry::Monster(monster) => {
+                format!("Armor Class {}, Hit Points {} ({})", monster.armor_class, monster.hit_points, monster.hit_dice)
+            }
+            HomebrewEntry::Item(item) => item.description.clone(),
+            HomebrewEntry::Feat(feat) => feat.description.clone(),
+        }
+    }
+
+    /// Whether this is homebrew rather than an SRD entry
+    pub fn is_homebrew(&self) -> bool {
+        on_row!(self, row => row.pack_id.is_some())
+    }
+
+    pub fn source(&self) -> &str {
+        on_row!(self, row => &row.source)
+    }
+
+    /// What's wrong with the entry, if anything, before it can be saved
+    pub fn problem(&self) -> Option<String> {
+        if self.name().trim().is_empty() {
+            return Some("Name is required".to_string());
+        }
+        match self {
+            HomebrewEntry::Spell(spell) if !(0..=9).contains(&spell.level) => Some("Spell level must be between 0 and 9".to_string()),
+            HomebrewEntry::Monster(monster) if monster.hit_points <= 0 => Some("Hit points must be above 0".to_string()),
+            HomebrewEntry::Monster(monster) => crate::rules::dice::parse(&monster.hit_dice)
+                .err()
+                .map(|e| format!("Hit dice: {}", e)),
+            _ => None,
+        }
+    }
+
+    // Stamp the entry as part of `pack`, under `slug` at `version`
+    #[cfg(not(target_arch = "wasm32"))]
+    fn mark(&mut self, pack: &HomebrewPack, slug: String, version: i32) {
+        on_row!(self, row => {
+            row.slug = slug;
+            row.pack_id = Some(pack.id);
+            row.source = pack.name.clone();
+            row.version = version;
+        })
+    }
+
+    // The catalog row to write. The id is never written; Supabase assigns it.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn row(&self) -> Result<serde_json::Value, ServerFnError> {
+        let mut value = on_row!(self, row => serde_json::to_value(row)).map_err(|e| ServerFnError::new(e.to_string()))?;
+        if let Some(object) = value.as_object_mut() {
+            object.remove("id");
+        }
+        Ok(value)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn from_row(kind: HomebrewKind, row: serde_json::Value) -> Result<Self, ServerFnError> {
+        let parsed = match kind {
+            HomebrewKind::Spell => serde_json::from_value(row).map(HomebrewEntry::Spell),
+            HomebrewKind::Monster => serde_json::from_value(row).map(HomebrewEntry::Monster),
+            HomebrewKind::Item => serde_json::from_value(row).map(HomebrewEntry::Item),
+            HomebrewKind::Feat => serde_json::from_value(row).map(HomebrewEntry::Feat),
+        };
+        parsed.map_err(|e| ServerFnError::new(format!("Failed to parse {} JSON: {}", kind.table(), e)))
+    }
+}
+
+/// A saved version of a homebrew entry, kept so it can be looked at and restored
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct HomebrewVersion {
+    pub id: i32,
+    pub pack_id: i32,
+    pub kind: HomebrewKind,
+    pub slug: String,
+    pub version: i32,
+    pub name: String,
+    pub data: HomebrewEntry,
+    pub author: String,
+    pub created_at: Option<String>,
+}
+
+/// The caller's own packs and the ones others have shared
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct HomebrewLibrary {
+    pub own: Vec<HomebrewPack>,
+    pub shared: Vec<HomebrewPack>,
+}
+
+/// A pack with its entries, by kind and then name
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PackContents {
+    pub pack: HomebrewPack,
+    /// The caller owns the pack and may change it
+    pub can_edit: bool,
+    pub entries: Vec<HomebrewEntry>,
+}
+
+/// Who is reading a catalog: the client to read it with, and the homebrew packs listed
+/// besides the SRD, which are the reader's own and those enabled in their campaigns.
+/// Visitors who aren't signed in read the SRD only.
+#[cfg(feature = "server")]
+pub(crate) struct CatalogReader {
+    pub client: ServerSupabaseClient,
+    pub packs: Vec<i32>,
+}
+
+#[cfg(feature = "server")]
+impl CatalogReader {
+    pub async fn for_caller() -> Result<Self, ServerFnError> {
+        use crate::api::auth::{create_server_client, create_user_client};
+        use crate::api::campaign::enabled_pack_ids;
+        use crate::api::character::fetch_rows;
+
+        #[derive(Deserialize)]
+        struct IdRow {
+            id: i32,
+        }
+
+        let user: Result<AuthenticatedUser, _> = extract().await;
+        let Ok(user) = user else {
+            return Ok(Self { client: create_server_client(), packs: vec![] });
+        };
+        let client = create_user_client(&user);
+        let own: Vec<IdRow> = fetch_rows(client.table("homebrew_packs").select("id").eq("owner", &user.id), "homebrew packs").await?;
+        let mut packs = enabled_pack_ids(&user).await?;
+        packs.extend(own.into_iter().map(|pack| pack.id));
+        let mut packs: Vec<i32> = packs.into_iter().collect();
+        packs.sort();
+        Ok(Self { client, packs })
+    }
+
+    /// Narrow `request` to SRD entries and the reader's homebrew, or only one of the two
+    pub fn narrow(&self, request: postgrest::Builder, homebrew: Option<bool>) -> postgrest::Builder {
+        let packs: Vec<String> = self.packs.iter().map(i32::to_string).collect();
+        match homebrew {
+            Some(false) => request.is("pack_id", "null"),
+            Some(true) => request.in_("pack_id", &packs),
+            None if packs.is_empty() => request.is("pack_id", "null"),
+            None => request.or(format!("pack_id.is.null,pack_id.in.({})", packs.join(","))),
+        }
+    }
+}
+
+// Pack `id`, provided the caller owns it
+#[cfg(feature = "server")]
+async fn own_pack(client: &ServerSupabaseClient, user: &AuthenticatedUser, id: i32) -> Result<HomebrewPack, ServerFnError> {
+    use crate::api::character::fetch_rows;
+
+    let packs: Vec<HomebrewPack> = fetch_rows(client.table("homebrew_packs").select("*").eq("id", id.to_string()), "homebrew pack").await?;
+    match packs.into_iter().next() {
+        Some(pack) if pack.owner == user.id => Ok(pack),
+        Some(pack) => Err(ServerFnError::new(format!("Only the owner of {} can change it", pack.name))),
+        None => Err(ServerFnError::new(format!("No homebrew pack with id {} found", id))),
+    }
+}
+
+// Save `entry` into `pack` as its next version and record that version in the history.
+// Entries are found by slug, so restoring a deleted entry brings it back under its old slug.
+#[cfg(feature = "server")]
+async fn store_entry(
+    client: &ServerSupabaseClient,
+    user: &AuthenticatedUser,
+    pack: &HomebrewPack,
+    mut entry: HomebrewEntry,
+) -> Result<HomebrewEntry, ServerFnError> {
+    use crate::api::character::{fetch_rows, unique_slug};
+    use tracing::info;
+
+    #[derive(Deserialize)]
+    struct StoredRow {
+        id: i32,
+        pack_id: Option<i32>,
+    }
+    #[derive(Deserialize)]
+    struct VersionRow {
+        version: i32,
+    }
+
+    if let Some(problem) = entry.problem() {
+        return Err(ServerFnError::new(problem));
+    }
+    let kind = entry.kind();
+    let table = kind.table();
+
+    let stored: Option<StoredRow> = if entry.slug().is_empty() {
+        None
+    } else {
+        fetch_rows(client.table(table).select("id,pack_id").eq("slug", entry.slug()), table).await?.into_iter().next()
+    };
+    if stored.as_ref().is_some_and(|row| row.pack_id != Some(pack.id)) {
+        return Err(ServerFnError::new(format!("'{}' is not part of {}", entry.name(), pack.name)));
+    }
+    // Spellbooks and combatants point at entries by slug, so it never changes once taken
+    let slug = if entry.slug().is_empty() {
+        unique_slug(client, table, &format!("{} {}", pack.slug, entry.name()), None).await?
+    } else {
+        entry.slug().to_string()
+    };
+
+    // Two saves of the same entry can both read the same latest version. The history keeps each
+    // version once, so the version is claimed there first: the save whose snapshot is turned
+    // away rereads the history and goes again, and the entry row only ever carries a version
+    // the history has.
+    let mut claimed = None;
+    for _ in 0..3 {
+        let latest: Vec<VersionRow> = fetch_rows(
+            client
+                .table("homebrew_versions")
+                .select("version")
+                .eq("kind", kind.name())
+                .eq("slug", &slug)
+                .order("version.desc")
+                .limit(1),
+            "homebrew history",
+        )
+        .await?;
+        let version = latest.first().map_or(1, |row| row.version + 1);
+        entry.mark(pack, slug.clone(), version);
+
+        let history = serde_json::json!({
+            "pack_id": pack.id,
+            "kind": kind,
+            "slug": slug,
+            "version": version,
+            "name": entry.name(),
+            "data": entry,
+            "author": user.id,
+        });
+        let response = client
+            .table("homebrew_versions")
+            .insert(history.to_string())
+            .execute()
+            .await
+            .map_err(|e| ServerFnError::new(format!("Request failed: {}", e)))?;
+        let status = response.status();
+        if status.is_success() {
+            claimed = Some(version);
+            break;
+        }
+        let text = response.text().await.map_err(|e| ServerFnError::new(e.to_string()))?;
+        if !is_version_taken(status.as_u16(), &text) {
+            return Err(ServerFnError::new(format!("Failed to save homebrew history. Status: {}, Response: {}", status, text)));
+        }
+        info!("{} '{}' version {} was saved by someone else first; retrying", kind.name(), slug, version);
+    }
+    let Some(version) = claimed else {
+        return Err(ServerFnError::new(format!("'{}' is being changed by someone else; try again", entry.name())));
+    };
+
+    info!("Saving {} '{}' version {} in pack {}", kind.name(), slug, version, pack.id);
+    let body = entry.row()?.to_string();
+    let request = match &stored {
+        // A save that claimed a later version may have got to the row first; it keeps it
+        Some(row) => client.table(table).update(body).eq("id", row.id.to_string()).lt("version", version.to_string()),
+        None => client.table(table).insert(body),
+    };
+    let saved: Vec<serde_json::Value> = fetch_rows(request, table).await?;
+    let Some(saved) = saved.into_iter().next() else {
+        return Err(ServerFnError::new(format!(
+            "Someone else saved '{}' at the same time; yours is kept as version {} in its history",
+            entry.name(),
+            version
+        )));
+    };
+    HomebrewEntry::from_row(kind, saved)
+}
+
+// Whether a failed history insert was turned away because that version of the entry is
+// already there, from PostgREST's 409 for the `(kind, slug, version)` unique constraint
+#[cfg(feature = "server")]
+fn is_version_taken(status: u16, body: &str) -> bool {
+    #[derive(Deserialize)]
+    struct PostgrestError {
+        code: String,
+        message: String,
+    }
+
+    status == 409
+        && serde_json::from_str::<PostgrestError>(body)
+            .is_ok_and(|error| error.code == "23505" && error.message.contains("homebrew_versions_kind_slug_version_key"))
+}
+
+#[server(name = GetHomebrewLibrary, client = crate::api::auth::AuthClient)]
+pub async fn get_homebrew_library() -> Result<HomebrewLibrary, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::create_user_client;
+        use crate::api::character::fetch_rows;
+
+        let user: AuthenticatedUser = extract().await?;
+        let client = create_user_client(&user);
+        let own = fetch_rows(client.table("homebrew_packs").select("*").eq("owner", &user.id).order("name.asc"), "homebrew packs").await?;
+        let shared = fetch_rows(
+            client.table("homebrew_packs").select("*").eq("shared", "true").neq("owner", &user.id).order("name.asc"),
+            "homebrew packs",
+        )
+        .await?;
+        Ok(HomebrewLibrary { own, shared })
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// Create a pack for the caller when `pack.id` is 0, otherwise update one of theirs.
+/// Renaming a pack renames the source shown on its entries.
+#[server(name = SaveHomebrewPack, client = crate::api::auth::AuthClient)]
+pub async fn save_homebrew_pack(pack: HomebrewPack) -> Result<HomebrewPack, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::create_user_client;
+        use crate::api::character::{fetch_rows, slug_matches_name, unique_slug};
+        use tracing::info;
+
+        if pack.name.trim().is_empty() {
+            return Err(ServerFnError::new("Pack name is required".to_string()));
+        }
+        let user: AuthenticatedUser = extract().await?;
+        let client = create_user_client(&user);
+        let mut pack = HomebrewPack { name: pack.name.trim().to_string(), ..pack };
+        let renamed_from = if pack.id == 0 {
+            pack.owner = user.id.clone();
+            None
+        } else {
+            let stored = own_pack(&client, &user, pack.id).await?;
+            pack.owner = stored.owner;
+            pack.created_at = stored.created_at;
+            (stored.name != pack.name).then_some(stored.name)
+        };
+        if !slug_matches_name(&pack.slug, &pack.name) {
+            let exclude_id = (pack.id != 0).then_some(pack.id);
+            pack.slug = unique_slug(&client, "homebrew_packs", &pack.name, exclude_id).await?;
+        }
+        pack.updated_at = Some(chrono::Utc::now().to_rfc3339());
+
+        let mut body = serde_json::to_value(&pack).map_err(|e| ServerFnError::new(e.to_string()))?;
+        if let Some(object) = body.as_object_mut() {
+            object.remove("id");
+            object.remove("created_at");
+        }
+        let request = if pack.id == 0 {
+            info!("Creating homebrew pack '{}'", pack.name);
+            client.table("homebrew_packs").insert(body.to_string())
+        } else {
+            info!("Updating homebrew pack {}", pack.id);
+            client.table("homebrew_packs").update(body.to_string()).eq("id", pack.id.to_string())
+        };
+        let saved: HomebrewPack = fetch_rows(request, "homebrew pack")
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| ServerFnError::new("Saving the homebrew pack returned no row".to_string()))?;
+
+        if let Some(old_name) = renamed_from {
+            info!("Renaming source '{}' to '{}'", old_name, saved.name);
+            let source = serde_json::json!({ "source": saved.name }).to_string();
+            for kind in HomebrewKind::ALL {
+                let request = client.table(kind.table()).update(source.clone()).eq("pack_id", saved.id.to_string());
+                fetch_rows::<serde_json::Value>(request, kind.table()).await?;
+            }
+        }
+        Ok(saved)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// Delete one of the caller's packs with all its entries and their history
+#[server(name = DeleteHomebrewPack, client = crate::api::auth::AuthClient)]
+pub async fn delete_homebrew_pack(id: i32) -> Result<(), ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::create_user_client;
+        use crate::api::character::fetch_rows;
+        use tracing::info;
+
+        let user: AuthenticatedUser = extract().await?;
+        let client = create_user_client(&user);
+        own_pack(&client, &user, id).await?;
+        info!("Deleting homebrew pack {}", id);
+        fetch_rows::<serde_json::Value>(client.table("homebrew_packs").delete().eq("id", id.to_string()), "homebrew pack").await?;
+        Ok(())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// The pack with `slug` and its entries, if the caller may see it
+#[server(name = GetHomebrewPack, client = crate::api::auth::AuthClient)]
+pub async fn get_homebrew_pack(slug: String) -> Result<PackContents, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::create_user_client;
+        use crate::api::character::fetch_rows;
+
+        let user: AuthenticatedUser = extract().await?;
+        let client = create_user_client(&user);
+        // Row-level security hides packs that are neither the caller's, shared, nor in their campaigns
+        let pack: HomebrewPack = fetch_rows(client.table("homebrew_packs").select("*").eq("slug", &slug), "homebrew pack")
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| ServerFnError::new(format!("No homebrew pack found with slug '{}'", slug)))?;
+
+        let mut entries = vec![];
+        for kind in HomebrewKind::ALL {
+            let request = client.table(kind.table()).select(kind.columns()).eq("pack_id", pack.id.to_string()).order("name.asc");
+            for row in fetch_rows::<serde_json::Value>(request, kind.table()).await? {
+                entries.push(HomebrewEntry::from_row(*kind, row)?);
+            }
+        }
+        Ok(PackContents { can_edit: pack.owner == user.id, pack, entries })
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// Save `entry` into pack `pack_id` as a new version. Entries with an empty slug are created.
+#[server(name = SaveHomebrewEntry, client = crate::api::auth::AuthClient)]
+pub async fn save_homebrew_entry(pack_id: i32, entry: HomebrewEntry) -> Result<HomebrewEntry, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::create_user_client;
+
+        let user: AuthenticatedUser = extract().await?;
+        let client = create_user_client(&user);
+        let pack = own_pack(&client, &user, pack_id).await?;
+        store_entry(&client, &user, &pack, entry).await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// Remove an entry from its pack. Its history is kept, so it can be restored.
+#[server(name = DeleteHomebrewEntry, client = crate::api::auth::AuthClient)]
+pub async fn delete_homebrew_entry(kind: HomebrewKind, slug: String) -> Result<(), ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::create_user_client;
+        use crate::api::character::fetch_rows;
+        use tracing::info;
+
+        let user: AuthenticatedUser = extract().await?;
+        let client = create_user_client(&user);
+        info!("Deleting {} '{}'", kind.name(), slug);
+        // Only pack owners may delete catalog rows, and SRD rows have no pack
+        let request = client.table(kind.table()).delete().eq("slug", &slug).not("is", "pack_id", "null");
+        let deleted: Vec<serde_json::Value> = fetch_rows(request, kind.table()).await?;
+        if deleted.is_empty() {
+            return Err(ServerFnError::new(format!("No homebrew {} of yours with slug '{}' found", kind.name().to_lowercase(), slug)));
+        }
+        Ok(())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// Every saved version of an entry, newest first
+#[server(name = GetHomebrewHistory, client = crate::api::auth::AuthClient)]
+pub async fn get_homebrew_history(kind: HomebrewKind, slug: String) -> Result<Vec<HomebrewVersion>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::create_user_client;
+        use crate::api::character::fetch_rows;
+
+        let user: AuthenticatedUser = extract().await?;
+        let client = create_user_client(&user);
+        fetch_rows(
+            client
+                .table("homebrew_versions")
+                .select("*")
+                .eq("kind", kind.name())
+                .eq("slug", &slug)
+                .order("version.desc"),
+            "homebrew history",
+        )
+        .await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// Save an earlier version of an entry again, as its newest version
+#[server(name = RestoreHomebrewVersion, client = crate::api::auth::AuthClient)]
+pub async fn restore_homebrew_version(id: i32) -> Result<HomebrewEntry, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::create_user_client;
+        use crate::api::character::fetch_rows;
+
+        let user: AuthenticatedUser = extract().await?;
+        let client = create_user_client(&user);
+        let version: HomebrewVersion = fetch_rows(client.table("homebrew_versions").select("*").eq("id", id.to_string()), "homebrew history")
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| ServerFnError::new(format!("No homebrew version with id {} found", id)))?;
+        let pack = own_pack(&client, &user, version.pack_id).await?;
+        store_entry(&client, &user, &pack, version.data).await
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+use dioxus::prelude::*;
+use crate::api::homebrew::{first_version, srd_source};
+use crate::rules::{ItemCategory, Rarity};
+
+// The shared item catalog: magic items from the SRD plus homebrew. These are definitions;
+// what a character carries lives in their inventory.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct CatalogItem {
+    pub id: i32,
+    pub slug: String,
+    pub name: String,
+    pub category: ItemCategory,
+    pub rarity: Rarity,
+    #[serde(default)]
+    pub requires_attunement: bool,
+    /// Pounds
+    #[serde(default)]
+    pub weight: f64,
+    /// Copper pieces; 0 for magic items, which have no set price
+    #[serde(default)]
+    pub value: i64,
+    #[serde(default)]
+    pub description: String,
+    /// The homebrew pack this item belongs to; `None` for SRD items
+    #[serde(default)]
+    pub pack_id: Option<i32>,
+    /// "SRD 5.1", or the name of its homebrew pack
+    #[serde(default = "srd_source")]
+    pub source: String,
+    #[serde(default = "first_version")]
+    pub version: i32,
+}
+
+impl CatalogItem {
+    /// "Wondrous Item, uncommon (requires attunement)"
+    pub fn kind_line(&self) -> String {
+        let mut line = format!("{}, {}", self.category, self.rarity.name().to_lowercase());
+        if self.requires_attunement {
+            line.push_str(" (requires attunement)");
+        }
+        line
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const ITEM_COLUMNS: &str =
+    "id,slug,name,category,rarity,requires_attunement,weight,value,description,pack_id,source,version";
+
+/// What the item catalog is narrowed down to. Unset fields don't filter.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct ItemFilter {
+    pub query: String,
+    pub category: Option<ItemCategory>,
+    pub rarity: Option<Rarity>,
+    /// Only homebrew items, or only SRD ones
+    pub homebrew: Option<bool>,
+}
+
+/// Items narrowed by `filter`, by name
+#[server(name = ListItems, client = crate::api::auth::AuthClient)]
+pub async fn list_items(filter: ItemFilter) -> Result<Vec<CatalogItem>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::character::{fetch_rows, filter_name};
+        use crate::api::homebrew::CatalogReader;
+        use tracing::info;
+
+        info!("Listing items of {:?}", filter);
+
+        let reader = CatalogReader::for_caller().await?;
+        let mut request = reader.client.table("items").select(ITEM_COLUMNS).order("name.asc").limit(200);
+        request = reader.narrow(filter_name(request, &filter.query), filter.homebrew);
+        if let Some(category) = filter.category {
+            request = request.eq("category", category.name());
+        }
+        if let Some(rarity) = filter.rarity {
+            request = request.eq("rarity", rarity.name());
+        }
+
+        let items: Vec<CatalogItem> = fetch_rows(request, "items").await?;
+        info!("Found {} items", items.len());
+        Ok(items)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
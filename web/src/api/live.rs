@@ -0,0 +1,92 @@
+//! Changes pushed to everyone watching a campaign, so the table sees rolls, character sheets,
+//! quests, NPCs and the combat tracker change as they happen. The server keeps one broadcast
+//! channel per watched campaign; `watch_campaign` in [`crate::api::campaign`] streams it to
+//! each browser as one JSON [`LiveEvent`] per line.
+
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use dioxus::prelude::server_fn::codec::TextStream;
+use crate::api::campaign::{EncounterRecord, Quest, RollEntry, NPC};
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind")]
+pub enum LiveEvent {
+    /// First on every connection. Anything that changed while disconnected is only in the
+    /// database, so watchers reload what they show.
+    Connected,
+    Roll(RollEntry),
+    /// A party character changed. Who may do what with it depends on the viewer, so this
+    /// only says which one; fetch it again to see the change.
+    Character { character_id: i32 },
+    Quest(Quest),
+    QuestDeleted { id: i32 },
+    Npc(NPC),
+    NpcDeleted { id: i32 },
+    /// The DM's combat tracker, only sent to the DM
+    Encounter(EncounterRecord),
+    EncounterDeleted { id: i32 },
+}
+
+impl LiveEvent {
+    /// Whether the event needs the DM's eyes only
+    pub fn dm_only(&self) -> bool {
+        matches!(self, LiveEvent::Encounter(_) | LiveEvent::EncounterDeleted { .. })
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod hub {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use tokio::sync::broadcast;
+    use super::LiveEvent;
+
+    // Events a slow watcher hasn't read yet before it starts missing them
+    const BACKLOG: usize = 64;
+
+    lazy_static::lazy_static! {
+        static ref CHANNELS: Mutex<HashMap<i32, broadcast::Sender<LiveEvent>>> = Mutex::new(HashMap::new());
+    }
+
+    /// Send `event` to everyone currently watching `campaign_id`
+    pub fn publish(campaign_id: i32, event: LiveEvent) {
+        let mut channels = CHANNELS.lock().unwrap();
+        if let Some(sender) = channels.get(&campaign_id) {
+            // Nobody is listening any more, so stop keeping the channel around
+            if sender.send(event).is_err() {
+                channels.remove(&campaign_id);
+            }
+        }
+    }
+
+    pub fn subscribe(campaign_id: i32) -> broadcast::Receiver<LiveEvent> {
+        CHANNELS
+            .lock()
+            .unwrap()
+            .entry(campaign_id)
+            .or_insert_with(|| broadcast::channel(BACKLOG).0)
+            .subscribe()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use hub::{publish, subscribe};
+
+/// Decode the lines of a `watch_campaign` stream. Chunks can end mid-line, so text is held
+/// back until its newline arrives; lines that don't parse are skipped.
+pub fn events(stream: TextStream) -> impl Stream<Item = LiveEvent> {
+    let mut buffer = String::new();
+    stream.into_inner().flat_map(move |chunk| {
+        let mut events = vec![];
+        if let Ok(chunk) = chunk {
+            buffer.push_str(&chunk);
+            while let Some(end) = buffer.find('\n') {
+                let line: String = buffer.drain(..=end).collect();
+                if let Ok(event) = serde_json::from_str(line.trim()) {
+                    events.push(event);
+                }
+            }
+        }
+        futures::stream::iter(events)
+    })
+}
@@ -14,4 +14,13 @@ mod env;
 
 
 pub mod character;
+pub mod campaign;
+pub mod live;
+pub mod spells;
+pub mod monsters;
+pub mod items;
+pub mod feats;
+pub mod homebrew;
+pub mod reference;
+pub mod search;
 pub use character::{get_character, get_character_with_slug, CharacterDetail, GetCharacterWithSlug};
\ No newline at end of file
@@ -0,0 +1,318 @@
+use serde::{Deserialize, Serialize};
+use dioxus::prelude::*;
+use crate::api::homebrew::{first_version, srd_source};
+use crate::rules::{ability_modifier, Ability, CreatureType, Environment, Size};
+use crate::rules::combat::{Combatant, Side};
+
+// The shared bestiary. Combatants added from it keep the slug, so renaming one loses the
+// tracker's link back to its stat block.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Monster {
+    pub id: i32,
+    pub slug: String,
+    pub name: String,
+    pub size: Size,
+    pub monster_type: CreatureType,
+    /// "goblinoid", "shapechanger", "any race"; empty for most
+    #[serde(default)]
+    pub subtype: String,
+    #[serde(default)]
+    pub alignment: String,
+    pub armor_class: i32,
+    /// What the armor class comes from, e.g. "natural armor"
+    #[serde(default)]
+    pub armor_desc: String,
+    pub hit_points: i32,
+    /// The formula `hit_points` averages, e.g. "2d6+2"
+    pub hit_dice: String,
+    #[serde(default)]
+    pub speed: Speed,
+    pub strength: i32,
+    pub dexterity: i32,
+    pub constitution: i32,
+    pub intelligence: i32,
+    pub wisdom: i32,
+    pub charisma: i32,
+    #[serde(default)]
+    pub saving_throws: Vec<Bonus>,
+    #[serde(default)]
+    pub skills: Vec<Bonus>,
+    #[serde(default)]
+    pub damage_vulnerabilities: String,
+    #[serde(default)]
+    pub damage_resistances: String,
+    #[serde(default)]
+    pub damage_immunities: String,
+    #[serde(default)]
+    pub condition_immunities: String,
+    #[serde(default)]
+    pub senses: String,
+    #[serde(default)]
+    pub languages: String,
+    /// 1/8, 1/4 and 1/2 are stored as fractions
+    pub challenge_rating: f64,
+    #[serde(default)]
+    pub environments: Vec<Environment>,
+    #[serde(default)]
+    pub traits: Vec<MonsterAction>,
+    #[serde(default)]
+    pub actions: Vec<MonsterAction>,
+    #[serde(default)]
+    pub reactions: Vec<MonsterAction>,
+    #[serde(default)]
+    pub legendary_actions_per_round: i32,
+    #[serde(default)]
+    pub legendary_actions: Vec<MonsterAction>,
+    /// The homebrew pack this monster belongs to; `None` for SRD monsters
+    #[serde(default)]
+    pub pack_id: Option<i32>,
+    /// "SRD 5.1", or the name of its homebrew pack
+    #[serde(default = "srd_source")]
+    pub source: String,
+    #[serde(default = "first_version")]
+    pub version: i32,
+}
+
+/// Movement speeds in feet; 0 means the creature can't move that way
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct Speed {
+    pub walk: i32,
+    pub fly: i32,
+    pub swim: i32,
+    pub climb: i32,
+    pub burrow: i32,
+    pub hover: bool,
+}
+
+impl std::fmt::Display for Speed {
+    /// "30 ft., fly 60 ft. (hover)"
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = vec![format!("{} ft.", self.walk)];
+        for (mode, feet) in [("burrow", self.burrow), ("climb", self.climb), ("fly", self.fly), ("swim", self.swim)] {
+            if feet > 0 {
+                let hover = if mode == "fly" && self.hover { " (hover)" } else { "" };
+                parts.push(format!("{} {} ft.{}", mode, feet, hover));
+            }
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+/// A saving throw or skill the monster is proficient in, with its total bonus
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Bonus {
+    pub name: String,
+    pub bonus: i32,
+}
+
+/// A trait, action, reaction or legendary action. Attacks carry their to-hit bonus and
+/// damage dice so they can be rolled; saves and other effects may carry damage only.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MonsterAction {
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub attack_bonus: Option<i32>,
+    #[serde(default)]
+    pub damage: Option<String>,
+}
+
+impl Monster {
+    pub fn score(&self, ability: Ability) -> i32 {
+        match ability {
+            Ability::Strength => self.strength,
+            Ability::Dexterity => self.dexterity,
+            Ability::Constitution => self.constitution,
+            Ability::Intelligence => self.intelligence,
+            Ability::Wisdom => self.wisdom,
+            Ability::Charisma => self.charisma,
+        }
+    }
+
+    pub fn set_score(&mut self, ability: Ability, score: i32) {
+        match ability {
+            Ability::Strength => self.strength = score,
+            Ability::Dexterity => self.dexterity = score,
+            Ability::Constitution => self.constitution = score,
+            Ability::Intelligence => self.intelligence = score,
+            Ability::Wisdom => self.wisdom = score,
+            Ability::Charisma => self.charisma = score,
+        }
+    }
+
+    /// "Medium humanoid (goblinoid), neutral evil"
+    pub fn kind_line(&self) -> String {
+        let mut line = format!("{} {}", self.size, self.monster_type.name().to_lowercase());
+        if !self.subtype.is_empty() {
+            line.push_str(&format!(" ({})", self.subtype));
+        }
+        if !self.alignment.is_empty() {
+            line.push_str(&format!(", {}", self.alignment));
+        }
+        line
+    }
+
+    pub fn challenge_label(&self) -> String {
+        challenge_label(self.challenge_rating)
+    }
+
+    pub fn experience(&self) -> i32 {
+        challenge_experience(self.challenge_rating)
+    }
+
+    /// A combatant for the tracker with this monster's numbers, linked back to its stat block
+    pub fn to_combatant(&self) -> Combatant {
+        let mut combatant = Combatant::monster(&self.name, self.armor_class, self.hit_points, self.dexterity);
+        combatant.side = Side::Monster { monster: Some(self.slug.clone()) };
+        combatant.legendary_actions = self.legendary_actions_per_round;
+        combatant
+    }
+}
+
+/// "1/4" for 0.25, "5" for 5.0
+pub fn challenge_label(challenge: f64) -> String {
+    match challenge {
+        c if c > 0.0 && c < 1.0 => format!("1/{}", (1.0 / c).round()),
+        c => format!("{}", c.round() as i32),
+    }
+}
+
+/// Experience points for defeating a monster of `challenge`
+pub fn challenge_experience(challenge: f64) -> i32 {
+    const BY_RATING: [i32; 31] = [
+        10, 200, 450, 700, 1_100, 1_800, 2_300, 2_900, 3_900, 5_000, 5_900, 7_200, 8_400, 10_000, 11_500, 13_000,
+        15_000, 18_000, 20_000, 22_000, 25_000, 33_000, 41_000, 50_000, 62_000, 75_000, 90_000, 105_000, 120_000,
+        135_000, 155_000,
+    ];
+    match challenge {
+        c if c <= 0.0 => 10,
+        c if c <= 0.125 => 25,
+        c if c <= 0.25 => 50,
+        c if c <= 0.5 => 100,
+        c => BY_RATING[(c.round() as usize).min(BY_RATING.len() - 1)],
+    }
+}
+
+/// Challenge ratings to filter by, as `(value, label)` pairs for a `SelectInput`
+pub fn challenge_options() -> Vec<(String, String)> {
+    [0.0, 0.125, 0.25, 0.5]
+        .into_iter()
+        .chain((1..=30).map(f64::from))
+        .map(|challenge| (challenge.to_string(), challenge_label(challenge)))
+        .collect()
+}
+
+/// The modifier shown next to an ability score, e.g. "14 (+2)"
+pub fn score_label(score: i32) -> String {
+    let modifier = ability_modifier(score);
+    if modifier >= 0 {
+        format!("{} (+{})", score, modifier)
+    } else {
+        format!("{} ({})", score, modifier)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const MONSTER_COLUMNS: &str = "id,slug,name,size,monster_type,subtype,alignment,armor_class,armor_desc,hit_points,hit_dice,speed,\
+    strength,dexterity,constitution,intelligence,wisdom,charisma,saving_throws,skills,damage_vulnerabilities,damage_resistances,\
+    damage_immunities,condition_immunities,senses,languages,challenge_rating,environments,traits,actions,reactions,\
+    legendary_actions_per_round,legendary_actions,pack_id,source,version";
+
+/// Monsters shown on one page of the bestiary
+pub const MONSTERS_PER_PAGE: i32 = 20;
+
+/// What the bestiary is narrowed down to. Unset fields don't filter.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct MonsterFilter {
+    pub query: String,
+    pub monster_type: Option<CreatureType>,
+    pub environment: Option<Environment>,
+    pub min_challenge: Option<f64>,
+    pub max_challenge: Option<f64>,
+    /// Only homebrew monsters, or only SRD ones
+    pub homebrew: Option<bool>,
+}
+
+/// One page of the filtered bestiary, and how many monsters match in all
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct MonsterPage {
+    pub monsters: Vec<Monster>,
+    pub total: i32,
+    pub page: i32,
+}
+
+impl MonsterPage {
+    pub fn page_count(&self) -> i32 {
+        ((self.total + MONSTERS_PER_PAGE - 1) / MONSTERS_PER_PAGE).max(1)
+    }
+}
+
+/// Page `page` (from 0) of the bestiary as narrowed by `filter`, by challenge rating and then name
+#[server(name = ListMonsters, client = crate::api::auth::AuthClient)]
+pub async fn list_monsters(filter: MonsterFilter, page: i32) -> Result<MonsterPage, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::character::{fetch_counted_rows, filter_name};
+        use crate::api::homebrew::CatalogReader;
+        use tracing::info;
+
+        info!("Listing monsters, page {} of {:?}", page, filter);
+
+        let page = page.max(0);
+        let first = (page * MONSTERS_PER_PAGE) as usize;
+        let reader = CatalogReader::for_caller().await?;
+        let mut request = reader
+            .client
+            .table("monsters")
+            .select(MONSTER_COLUMNS)
+            .order("challenge_rating.asc,name.asc")
+            .range(first, first + MONSTERS_PER_PAGE as usize - 1)
+            .exact_count();
+
+        request = reader.narrow(filter_name(request, &filter.query), filter.homebrew);
+        if let Some(monster_type) = filter.monster_type {
+            request = request.eq("monster_type", monster_type.name());
+        }
+        if let Some(environment) = filter.environment {
+            request = request.cs("environments", format!("[\"{}\"]", environment.name()));
+        }
+        if let Some(min) = filter.min_challenge {
+            request = request.gte("challenge_rating", min.to_string());
+        }
+        if let Some(max) = filter.max_challenge {
+            request = request.lte("challenge_rating", max.to_string());
+        }
+
+        let (monsters, total): (Vec<Monster>, i32) = fetch_counted_rows(request, "monsters").await?;
+        info!("Found {} of {} monsters", monsters.len(), total);
+        Ok(MonsterPage { monsters, total, page })
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = GetMonster, client = crate::api::auth::AuthClient)]
+pub async fn get_monster(slug: String) -> Result<Monster, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::homebrew::CatalogReader;
+        use crate::api::character::fetch_rows;
+        use tracing::info;
+
+        info!("Fetching monster '{}'", slug);
+
+        let reader = CatalogReader::for_caller().await?;
+        let monsters: Vec<Monster> = fetch_rows(reader.client.table("monsters").select(MONSTER_COLUMNS).eq("slug", &slug), "monster").await?;
+        monsters
+            .into_iter()
+            .next()
+            .ok_or_else(|| ServerFnError::new(format!("No monster found with slug '{}'", slug)))
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
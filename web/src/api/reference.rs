@@ -0,0 +1,146 @@
+use serde::{Deserialize, Serialize};
+use dioxus::prelude::*;
+use crate::api::homebrew::{HomebrewEntry, HomebrewKind};
+
+#[cfg(feature = "server")]
+use crate::api::auth::AuthenticatedUser;
+
+/// A spell, monster, item or feat as Quick Reference lists it
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ReferenceEntry {
+    pub kind: HomebrewKind,
+    pub slug: String,
+    pub name: String,
+    /// "3rd level evocation", "Wondrous item, rare"
+    pub summary: String,
+    pub description: String,
+    pub source: String,
+    pub homebrew: bool,
+    /// The spell's level; `None` for everything that isn't a spell
+    pub level: Option<i32>,
+}
+
+impl ReferenceEntry {
+    pub fn new(entry: &HomebrewEntry) -> Self {
+        Self {
+            kind: entry.kind(),
+            slug: entry.slug().to_string(),
+            name: entry.name().to_string(),
+            summary: entry.summary(),
+            description: entry.description(),
+            source: entry.source().to_string(),
+            homebrew: entry.is_homebrew(),
+            level: match entry {
+                HomebrewEntry::Spell(spell) => Some(spell.level),
+                _ => None,
+            },
+        }
+    }
+}
+
+// One pin in reference_favorites
+#[cfg(feature = "server")]
+#[derive(Deserialize)]
+struct FavoriteRow {
+    kind: HomebrewKind,
+    slug: String,
+}
+
+/// Spells, monsters, items and feats whose name matches `query`, from one catalog or all four.
+/// Catalogs cover the SRD and the reader's homebrew, as on the reference pages.
+#[server(name = SearchReference, client = crate::api::auth::AuthClient)]
+pub async fn search_reference(query: String, kind: Option<HomebrewKind>) -> Result<Vec<ReferenceEntry>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::character::{fetch_rows, filter_name};
+        use crate::api::homebrew::CatalogReader;
+
+        let reader = CatalogReader::for_caller().await?;
+
+        let kinds = match kind {
+            Some(kind) => vec![kind],
+            None => HomebrewKind::ALL.to_vec(),
+        };
+        let mut found = vec![];
+        for kind in kinds {
+            // Enough of each catalog to browse; narrowing the search finds the rest
+            let request = filter_name(reader.client.table(kind.table()).select(kind.columns()), &query)
+                .order("name.asc")
+                .limit(50);
+            for row in fetch_rows::<serde_json::Value>(reader.narrow(request, None), kind.table()).await? {
+                found.push(ReferenceEntry::new(&HomebrewEntry::from_row(kind, row)?));
+            }
+        }
+        found.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(found)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// The entries the caller pinned, in the order they were pinned. Pins of entries that were
+/// deleted, or whose homebrew pack the caller can no longer read, are left out.
+#[server(name = GetReferenceFavorites, client = crate::api::auth::AuthClient)]
+pub async fn get_reference_favorites() -> Result<Vec<ReferenceEntry>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::create_user_client;
+        use crate::api::character::fetch_rows;
+
+        let user: AuthenticatedUser = extract().await?;
+        let client = create_user_client(&user);
+        let favorites: Vec<FavoriteRow> = fetch_rows(
+            client.table("reference_favorites").select("kind,slug").eq("owner", &user.id).order("created_at.asc"),
+            "reference favorites",
+        )
+        .await?;
+
+        let mut entries = vec![];
+        for kind in HomebrewKind::ALL.iter().copied() {
+            let slugs: Vec<&str> = favorites.iter().filter(|pin| pin.kind == kind).map(|pin| pin.slug.as_str()).collect();
+            if slugs.is_empty() {
+                continue;
+            }
+            let request = client.table(kind.table()).select(kind.columns()).in_("slug", slugs);
+            for row in fetch_rows::<serde_json::Value>(request, kind.table()).await? {
+                entries.push(HomebrewEntry::from_row(kind, row)?);
+            }
+        }
+        Ok(favorites
+            .iter()
+            .filter_map(|pin| entries.iter().find(|entry| entry.kind() == pin.kind && entry.slug() == pin.slug))
+            .map(ReferenceEntry::new)
+            .collect())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// Pin or unpin an entry for the caller
+#[server(name = SetReferenceFavorite, client = crate::api::auth::AuthClient)]
+pub async fn set_reference_favorite(kind: HomebrewKind, slug: String, favorite: bool) -> Result<(), ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::auth::create_user_client;
+        use crate::api::character::fetch_rows;
+
+        let user: AuthenticatedUser = extract().await?;
+        let client = create_user_client(&user);
+        let request = if favorite {
+            let body = serde_json::json!({ "owner": user.id, "kind": kind, "slug": slug }).to_string();
+            client.table("reference_favorites").upsert(body).on_conflict("owner,kind,slug")
+        } else {
+            client.table("reference_favorites").delete().eq("owner", &user.id).eq("kind", kind.name()).eq("slug", &slug)
+        };
+        fetch_rows::<serde_json::Value>(request, "reference favorites").await?;
+        Ok(())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use crate::api::auth::AuthenticatedUser;
+
+/// Where `ts_headline` opens and closes a matched word in a snippet
+pub const MATCH_START: char = '\u{2}';
+pub const MATCH_END: char = '\u{3}';
+
+/// What a search hit is, which decides where it links to
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum SearchKind {
+    Character,
+    Npc,
+    Lore,
+    Location,
+    Adventure,
+    Spell,
+    Monster,
+}
+
+impl SearchKind {
+    pub fn plural(self) -> &'static str {
+        match self {
+            SearchKind::Character => "Characters",
+            SearchKind::Npc => "NPCs",
+            SearchKind::Lore => "Lore",
+            SearchKind::Location => "Locations",
+            SearchKind::Adventure => "Adventures",
+            SearchKind::Spell => "Spells",
+            SearchKind::Monster => "Monsters",
+        }
+    }
+}
+
+/// One match of a global search
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SearchHit {
+    pub kind: SearchKind,
+    /// The slug of the character, spell or monster; `None` for campaign content
+    pub slug: Option<String>,
+    /// The campaign an NPC, lore entry, location or adventure belongs to
+    pub campaign_slug: Option<String>,
+    pub title: String,
+    /// A few words around the matches, with each match between `MATCH_START` and `MATCH_END`
+    pub snippet: String,
+    /// How well it matched, from 0 to 1
+    pub rank: f32,
+}
+
+impl SearchHit {
+    /// The snippet as runs of text, each marked with whether it is a match
+    pub fn snippet_parts(&self) -> Vec<(String, bool)> {
+        let mut parts = vec![];
+        let mut matched = false;
+        let mut run = String::new();
+        for c in self.snippet.chars() {
+            if c == MATCH_START || c == MATCH_END {
+                if !run.is_empty() {
+                    parts.push((std::mem::take(&mut run), matched));
+                }
+                matched = c == MATCH_START;
+            } else {
+                run.push(c);
+            }
+        }
+        if !run.is_empty() {
+            parts.push((run, matched));
+        }
+        parts
+    }
+}
+
+/// The hits of one kind, best first
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SearchGroup {
+    pub kind: SearchKind,
+    pub hits: Vec<SearchHit>,
+}
+
+/// Search characters, NPCs, lore, locations, adventures, spells and monsters at once.
+/// Campaign content comes from the campaigns the caller runs or plays in, and the catalogs
+/// cover the SRD and the caller's homebrew. Groups come best match first.
+#[server(name = SearchEverything, client = crate::api::auth::AuthClient)]
+pub async fn search_everything(query: String) -> Result<Vec<SearchGroup>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::campaign::caller_campaign_ids;
+        use crate::api::character::fetch_rows;
+        use crate::api::homebrew::CatalogReader;
+        use tracing::info;
+
+        let query = query.trim();
+        if query.is_empty() {
+            return Ok(vec![]);
+        }
+        let user: AuthenticatedUser = extract().await?;
+        let reader = CatalogReader::for_caller().await?;
+        let campaign_ids: Vec<i32> = caller_campaign_ids(&user).await?.into_iter().collect();
+
+        info!("Searching everything for '{}'", query);
+        let params = serde_json::json!({
+            "p_query": query,
+            "p_campaign_ids": campaign_ids,
+            "p_pack_ids": reader.packs,
+            "p_limit": 8,
+        });
+        // Row-level security still applies to characters, spells and monsters
+        let hits: Vec<SearchHit> = fetch_rows(reader.client.rpc("search_everything", params.to_string()), "search results").await?;
+
+        let mut groups: Vec<SearchGroup> = vec![];
+        for hit in hits {
+            match groups.iter_mut().find(|group| group.kind == hit.kind) {
+                Some(group) => group.hits.push(hit),
+                None => groups.push(SearchGroup { kind: hit.kind, hits: vec![hit] }),
+            }
+        }
+        Ok(groups)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
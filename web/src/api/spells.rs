@@ -0,0 +1,247 @@
+use serde::{Deserialize, Serialize};
+use dioxus::prelude::*;
+use crate::api::homebrew::{first_version, srd_source};
+use crate::rules::{Class, MagicSchool};
+use crate::rules::spellbook::SpellbookEntry;
+
+// The shared spell catalog. Spellbooks point at these rows by slug, so renaming a slug
+// orphans every spellbook entry that uses it.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Spell {
+    pub id: i32,
+    pub slug: String,
+    pub name: String,
+    pub level: i32,
+    pub school: MagicSchool,
+    #[serde(default)]
+    pub ritual: bool,
+    #[serde(default)]
+    pub concentration: bool,
+    #[serde(default)]
+    pub classes: Vec<Class>,
+    #[serde(default)]
+    pub casting_time: String,
+    #[serde(default)]
+    pub range: String,
+    #[serde(default)]
+    pub components: Components,
+    #[serde(default)]
+    pub duration: String,
+    #[serde(default)]
+    pub description: String,
+    /// What casting it with a higher slot (or, for cantrips, at higher character levels) adds
+    #[serde(default)]
+    pub higher_levels: String,
+    /// The homebrew pack this spell belongs to; `None` for SRD spells
+    #[serde(default)]
+    pub pack_id: Option<i32>,
+    /// "SRD 5.1", or the name of its homebrew pack
+    #[serde(default = "srd_source")]
+    pub source: String,
+    #[serde(default = "first_version")]
+    pub version: i32,
+}
+
+/// Verbal, somatic and material components, with the material text when there is one
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Components {
+    #[serde(default)]
+    pub verbal: bool,
+    #[serde(default)]
+    pub somatic: bool,
+    #[serde(default)]
+    pub material: Option<String>,
+}
+
+impl std::fmt::Display for Components {
+    /// "V, S, M (a bit of fleece)"
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = vec![];
+        if self.verbal {
+            parts.push("V".to_string());
+        }
+        if self.somatic {
+            parts.push("S".to_string());
+        }
+        if let Some(material) = &self.material {
+            parts.push(format!("M ({})", material));
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+impl Spell {
+    /// "Cantrip" or "1st level", for headings and badges
+    pub fn level_label(&self) -> String {
+        spell_level_label(self.level)
+    }
+
+    /// A spellbook entry for this spell, learned through `class`
+    pub fn to_entry(&self, class: Option<Class>) -> SpellbookEntry {
+        SpellbookEntry {
+            spell: self.slug.clone(),
+            name: self.name.clone(),
+            level: self.level,
+            ritual: self.ritual,
+            concentration: self.concentration,
+            class,
+            prepared: false,
+            always_prepared: false,
+        }
+    }
+}
+
+pub fn spell_level_label(level: i32) -> String {
+    match level {
+        0 => "Cantrip".to_string(),
+        1 => "1st level".to_string(),
+        2 => "2nd level".to_string(),
+        3 => "3rd level".to_string(),
+        _ => format!("{}th level", level),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) const SPELL_COLUMNS: &str = "id,slug,name,level,school,ritual,concentration,classes,casting_time,range,components,\
+    duration,description,higher_levels,pack_id,source,version";
+
+/// Spells shown on one page of the catalog
+pub const SPELLS_PER_PAGE: i32 = 24;
+
+/// What the catalog is narrowed down to. Unset fields don't filter.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct SpellFilter {
+    pub query: String,
+    pub class: Option<Class>,
+    pub level: Option<i32>,
+    pub school: Option<MagicSchool>,
+    pub concentration: Option<bool>,
+    pub ritual: Option<bool>,
+    /// Only homebrew spells, or only SRD ones
+    pub homebrew: Option<bool>,
+}
+
+/// One page of the filtered catalog, and how many spells match in all
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SpellPage {
+    pub spells: Vec<Spell>,
+    pub total: i32,
+    pub page: i32,
+}
+
+impl SpellPage {
+    pub fn page_count(&self) -> i32 {
+        ((self.total + SPELLS_PER_PAGE - 1) / SPELLS_PER_PAGE).max(1)
+    }
+}
+
+// Narrow `request` to spells named like `query` and on `class`'s list
+#[cfg(not(target_arch = "wasm32"))]
+fn filter_name_and_class(request: postgrest::Builder, query: &str, class: Option<Class>) -> postgrest::Builder {
+    let mut request = crate::api::character::filter_name(request, query);
+    if let Some(class) = class {
+        request = request.cs("classes", format!("[\"{}\"]", class.name()));
+    }
+    request
+}
+
+/// Spells whose name contains `query`, optionally only those on `class`'s list and no higher than `max_level`
+#[server(name = SearchSpells, client = crate::api::auth::AuthClient)]
+pub async fn search_spells(query: String, class: Option<Class>, max_level: Option<i32>, limit: i32) -> Result<Vec<Spell>, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::homebrew::CatalogReader;
+        use crate::api::character::fetch_rows;
+        use tracing::info;
+
+        info!("Searching spells for '{}' (class: {:?}, max level: {:?})", query, class, max_level);
+
+        let reader = CatalogReader::for_caller().await?;
+        let mut request = reader
+            .client
+            .table("spells")
+            .select(SPELL_COLUMNS)
+            .order("level.asc,name.asc")
+            .limit(limit.clamp(1, 200) as usize);
+        request = reader.narrow(filter_name_and_class(request, &query, class), None);
+        if let Some(max_level) = max_level {
+            request = request.lte("level", max_level.to_string());
+        }
+
+        let spells: Vec<Spell> = fetch_rows(request, "spells").await?;
+        info!("Found {} spells", spells.len());
+        Ok(spells)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+/// Page `page` (from 0) of the catalog as narrowed by `filter`, by level and then name
+#[server(name = ListSpells, client = crate::api::auth::AuthClient)]
+pub async fn list_spells(filter: SpellFilter, page: i32) -> Result<SpellPage, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::homebrew::CatalogReader;
+        use crate::api::character::fetch_counted_rows;
+        use tracing::info;
+
+        info!("Listing spells, page {} of {:?}", page, filter);
+
+        let page = page.max(0);
+        let first = (page * SPELLS_PER_PAGE) as usize;
+        let reader = CatalogReader::for_caller().await?;
+        let mut request = reader
+            .client
+            .table("spells")
+            .select(SPELL_COLUMNS)
+            .order("level.asc,name.asc")
+            .range(first, first + SPELLS_PER_PAGE as usize - 1)
+            .exact_count();
+        request = reader.narrow(filter_name_and_class(request, &filter.query, filter.class), filter.homebrew);
+        if let Some(level) = filter.level {
+            request = request.eq("level", level.to_string());
+        }
+        if let Some(school) = filter.school {
+            request = request.eq("school", school.name());
+        }
+        if let Some(concentration) = filter.concentration {
+            request = request.eq("concentration", concentration.to_string());
+        }
+        if let Some(ritual) = filter.ritual {
+            request = request.eq("ritual", ritual.to_string());
+        }
+
+        let (spells, total): (Vec<Spell>, i32) = fetch_counted_rows(request, "spells").await?;
+        info!("Found {} of {} spells", spells.len(), total);
+        Ok(SpellPage { spells, total, page })
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
+
+#[server(name = GetSpell, client = crate::api::auth::AuthClient)]
+pub async fn get_spell(slug: String) -> Result<Spell, ServerFnError> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use crate::api::homebrew::CatalogReader;
+        use crate::api::character::fetch_rows;
+        use tracing::info;
+
+        info!("Fetching spell '{}'", slug);
+
+        let reader = CatalogReader::for_caller().await?;
+        let spells: Vec<Spell> = fetch_rows(reader.client.table("spells").select(SPELL_COLUMNS).eq("slug", &slug), "spell").await?;
+        spells
+            .into_iter()
+            .next()
+            .ok_or_else(|| ServerFnError::new(format!("No spell found with slug '{}'", slug)))
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        Err(ServerFnError::new("Server function called on client side".to_string()))
+    }
+}
@@ -1,57 +1,46 @@
 #![allow(non_snake_case)]
 
 use dioxus::prelude::*;
-use serde_json;
-#[cfg(target_arch = "wasm32")]
-use gloo::dialogs::alert;
 
-// Update the import path to match the actual location of signin_with_password and User.
-// For example, if auth.rs is in src/components and api/auth.rs is in src/api, use super::super::api::auth
 use crate::components::{
     button::Button,
     input::{PasswordInput, TextInput},
 };
-// Update this path as needed:
-// Update the path below if your api module is in a different location
-use crate::api::auth::{signin_with_password, User, Credentials};
+use crate::api::auth::{get_user, signin_with_password, Credentials, User};
 
 #[component]
 pub fn Auth(on_success: EventHandler<User>) -> Element {
     let mut email = use_signal(|| "".to_string());
     let mut password = use_signal(|| "".to_string());
     let mut remember_me = use_signal(|| false);
+    let mut error = use_signal::<Option<String>>(|| None);
+    let mut submitting = use_signal(|| false);
 
-    let login = move |_| {
+    let mut login = move || {
+        if submitting() {
+            return;
+        }
+        submitting.set(true);
+        error.set(None);
         spawn(async move {
             client! {
-              let res = signin_with_password(Credentials { email: email.to_string(), password: password.to_string() }).await;
-
-              if let Ok(_message) = res {
-                email.set("".into());
-                password.set("".into());
-                // Create a dummy user for now since auth is not fully implemented
-                let dummy_user = User {
-                    id: "temp".to_string(),
-                    aud: "".to_string(),
-                    role: "".to_string(),
-                    email: email.read().clone(),
-                    email_confirmed_at: None,
-                    phone: None,
-                    confirmation_sent_at: None,
-                    confirmed_at: None,
-                    last_sign_in_at: None,
-                    app_metadata: serde_json::Value::Null,
-                    user_metadata: serde_json::Value::Null,
-                    identities: vec![],
-                    created_at: "".to_string(),
-                    updated_at: "".to_string(),
-                };
-                on_success.call(dummy_user);
-              } else {
-                #[cfg(target_arch = "wasm32")]
-                alert("failed to login user");
-              }
+                let credentials = Credentials { email: email.to_string(), password: password.to_string() };
+                match signin_with_password(credentials).await {
+                    Ok(_message) => match get_user().await {
+                        Some(user) => {
+                            email.set("".into());
+                            password.set("".into());
+                            on_success.call(user);
+                        }
+                        None => error.set(Some("Signed in, but the session could not be loaded".to_string())),
+                    },
+                    Err(e) => error.set(Some(match e {
+                        ServerFnError::ServerError(message) => message,
+                        other => other.to_string(),
+                    })),
+                }
             }
+            submitting.set(false);
         });
     };
 
@@ -60,22 +49,13 @@ pub fn Auth(on_success: EventHandler<User>) -> Element {
             class: "space-y-6",
             onsubmit: move |event| {
                 event.prevent_default();
-                spawn(async move {
-                    client! {
-                        let res = signin_with_password(Credentials { email : email.to_string(),
-                        password : password.to_string() }). await; if let Ok(_message) = res {
-                        email.set("".into()); password.set("".into()); let dummy_user = User { id
-                        : "temp".to_string(), aud : "".to_string(), role : "".to_string(), email
-                        : email.read().clone(), email_confirmed_at : None, phone : None,
-                        confirmation_sent_at : None, confirmed_at : None, last_sign_in_at : None,
-                        app_metadata : serde_json::Value::Null, user_metadata :
-                        serde_json::Value::Null, identities : vec![], created_at : ""
-                        .to_string(), updated_at : "".to_string(), }; on_success
-                        .call(dummy_user); } else { #[cfg(target_arch = "wasm32")]
-                        alert("failed to login user"); }
-                    }
-                });
+                login();
             },
+            if let Some(err) = error.read().as_ref() {
+                div { class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4", role: "alert",
+                    p { "{err}" }
+                }
+            }
             div {
                 label {
                     class: "block text-sm font-medium text-gray-700 mb-1",
@@ -140,13 +120,15 @@ pub fn Auth(on_success: EventHandler<User>) -> Element {
             }
             div {
                 Button {
-                    text: "Sign in",
+                    text: if submitting() { "Signing in..." } else { "Sign in" },
+                    disabled: submitting(),
                     button_type: Some(crate::components::button::ButtonType::Submit),
                     class: Some(
                         "w-full flex justify-center py-2 px-4 border border-transparent rounded-md shadow-sm text-sm font-medium text-white bg-indigo-600 hover:bg-indigo-700 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-indigo-500 transition duration-150 ease-in-out"
                             .to_string(),
                     ),
-                    on_click: login,
+                    // The submit button posts the form, which signs in
+                    on_click: move |_| {},
                 }
             }
         }
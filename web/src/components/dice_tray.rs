@@ -0,0 +1,184 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use crate::rules::dice::{self, Advantage, RollResult};
+
+/// One finished roll and what it was for
+#[derive(Clone, Debug, PartialEq)]
+pub struct RolledLine {
+    pub label: String,
+    pub result: RollResult,
+}
+
+impl RolledLine {
+    pub fn new(label: impl Into<String>, result: RollResult) -> Self {
+        Self { label: label.into(), result }
+    }
+
+    /// Roll `expression`, e.g. a skill check as `1d20+5`
+    pub fn roll(label: impl Into<String>, expression: &str) -> Result<Self, String> {
+        Ok(Self::new(label, dice::roll(expression)?))
+    }
+}
+
+impl std::fmt::Display for RolledLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.label, self.result)
+    }
+}
+
+pub(crate) fn total_class(result: &RollResult) -> &'static str {
+    if result.is_critical() {
+        "text-2xl font-bold text-green-600"
+    } else if result.is_fumble() {
+        "text-2xl font-bold text-red-600"
+    } else {
+        "text-2xl font-bold text-gray-900 dark:text-white"
+    }
+}
+
+fn advantage_class(active: bool) -> &'static str {
+    if active {
+        "px-2 py-1 text-xs rounded bg-gray-800 text-white"
+    } else {
+        "px-2 py-1 text-xs rounded bg-gray-100 text-gray-700 hover:bg-gray-200"
+    }
+}
+
+/// Free-form dice and attack rolls. Finished rolls go to the front of `rolls`, which the parent
+/// owns so its own roll buttons can add to the same list, and are passed to `on_roll`.
+#[component]
+pub fn DiceTray(rolls: Signal<Vec<RolledLine>>, on_roll: Option<EventHandler<RolledLine>>, class: Option<String>) -> Element {
+    let mut expression = use_signal(|| "1d20".to_string());
+    let mut to_hit = use_signal(|| "5".to_string());
+    let mut damage = use_signal(|| "1d8+3".to_string());
+    let mut advantage = use_signal(Advantage::default);
+    let mut error = use_signal::<Option<String>>(|| None);
+    let custom_class = class.unwrap_or_else(|| "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg".to_string());
+
+    let mut record = move |lines: Vec<RolledLine>| {
+        error.set(None);
+        for line in lines {
+            if let Some(on_roll) = on_roll {
+                on_roll.call(line.clone());
+            }
+            let mut rolls = rolls.write();
+            rolls.insert(0, line);
+            rolls.truncate(10);
+        }
+    };
+
+    let mut roll_expression = move |text: String| match dice::roll(&text) {
+        Ok(result) => record(vec![RolledLine::new(text.trim(), result)]),
+        Err(message) => error.set(Some(message)),
+    };
+
+    let roll_attack = move |_| {
+        let Ok(bonus) = to_hit().trim().parse::<i32>() else {
+            error.set(Some("The attack bonus has to be a whole number".to_string()));
+            return;
+        };
+        match dice::attack(bonus, advantage(), Some(&damage()), &mut rand::thread_rng()) {
+            Ok(attack) => {
+                // Newest rolls go on top, so the damage goes in before the attack that caused it
+                let mut lines = vec![];
+                if let Some(damage) = attack.damage {
+                    let label = if attack.to_hit.is_critical() { "Damage (critical)" } else { "Damage" };
+                    lines.push(RolledLine::new(label, damage));
+                }
+                let label = if attack.to_hit.is_fumble() { "Attack (miss)" } else { "Attack" };
+                lines.push(RolledLine::new(label, attack.to_hit));
+                record(lines);
+            }
+            Err(message) => error.set(Some(message)),
+        }
+    };
+
+    rsx! {
+        div { class: "{custom_class}",
+            h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "Dice" }
+
+            div { class: "flex flex-wrap gap-2 mb-3",
+                for sides in [4, 6, 8, 10, 12, 20, 100] {
+                    button {
+                        r#type: "button",
+                        onclick: move |_| roll_expression(format!("1d{}", sides)),
+                        class: "px-3 py-1 text-sm bg-gray-100 text-gray-800 rounded hover:bg-gray-200",
+                        "d{sides}"
+                    }
+                }
+            }
+
+            form {
+                class: "flex gap-2 mb-4",
+                onsubmit: move |e| {
+                    e.prevent_default();
+                    roll_expression(expression());
+                },
+                input {
+                    class: "flex-1 px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg font-mono dark:bg-gray-700 dark:text-white",
+                    placeholder: "4d6kh3, 2d20kl1+5, 1d10!",
+                    value: "{expression}",
+                    oninput: move |e| expression.set(e.value()),
+                }
+                button {
+                    r#type: "submit",
+                    class: "px-4 py-2 bg-blue-600 text-white rounded-lg hover:bg-blue-700 transition-colors",
+                    "Roll"
+                }
+            }
+
+            div { class: "grid grid-cols-3 gap-2 mb-2",
+                label { class: "text-xs text-gray-500 dark:text-gray-400",
+                    "To hit"
+                    input {
+                        r#type: "number",
+                        class: "w-full px-2 py-1 border border-gray-300 dark:border-gray-600 rounded dark:bg-gray-700 dark:text-white",
+                        value: "{to_hit}",
+                        oninput: move |e| to_hit.set(e.value()),
+                    }
+                }
+                label { class: "col-span-2 text-xs text-gray-500 dark:text-gray-400",
+                    "Damage"
+                    input {
+                        class: "w-full px-2 py-1 border border-gray-300 dark:border-gray-600 rounded font-mono dark:bg-gray-700 dark:text-white",
+                        value: "{damage}",
+                        oninput: move |e| damage.set(e.value()),
+                    }
+                }
+            }
+            div { class: "flex items-center gap-2 mb-4",
+                for option in [Advantage::KeepLower, Advantage::Normal, Advantage::KeepHigher] {
+                    button {
+                        r#type: "button",
+                        onclick: move |_| advantage.set(option),
+                        class: advantage_class(advantage() == option),
+                        "{option}"
+                    }
+                }
+                button {
+                    r#type: "button",
+                    onclick: roll_attack,
+                    class: "ml-auto px-4 py-1 bg-red-600 text-white rounded-lg hover:bg-red-700 transition-colors",
+                    "Attack"
+                }
+            }
+
+            if let Some(message) = error.read().as_ref() {
+                p { class: "text-sm text-red-600 mb-2", role: "alert", "{message}" }
+            }
+
+            ul { class: "space-y-2",
+                for line in rolls.read().iter() {
+                    li { class: "flex items-center justify-between gap-3 border-t border-gray-100 dark:border-gray-700 pt-2",
+                        div { class: "min-w-0",
+                            div { class: "text-sm font-medium text-gray-700 dark:text-gray-300", "{line.label}" }
+                            div { class: "text-xs font-mono text-gray-500 dark:text-gray-400 break-all", "{line.result.breakdown}" }
+                        }
+                        span { class: total_class(&line.result), "{line.result.total}" }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -89,10 +89,11 @@ pub fn SelectInput(
     class: Option<String>,
     options: Vec<(String, String)>,
 ) -> Element {
+    let custom_class = class.unwrap_or_else(|| "input-select".to_string());
     rsx! {
         select {
             value: "{i_value}",
-            class: "input-select",
+            class: "{custom_class}",
             oninput: move |event| on_input.call(event),
             for (value , display) in options {
                 option { value, "{display}" }
@@ -19,4 +19,7 @@ pub mod input;
 pub use input::{TextInput, PasswordInput, NumberInput, DateInput, SelectInput};
 
 pub mod modal;
-pub use modal::Modal;
\ No newline at end of file
+pub use modal::Modal;
+
+pub mod dice_tray;
+pub use dice_tray::{DiceTray, RolledLine};
\ No newline at end of file
@@ -1,12 +1,15 @@
 // src/main.rs
 use dioxus::prelude::*;
-use views::{AppLayout, About, Contact, Home, Projects, Protected, Callback, Login};
-use views::characters::{Character, CharacterById, CharacterEdit};
-use views::campaigns::{Campaigns, CampaignDashboard, AdventureLog, Lore, MapLocations, NpcView}; 
-use views::references::{Classes, CoreRules, FeatsAbilities, Monsters, Spells, QuickReference};
+use views::{AppLayout, About, Contact, Home, Projects, Protected, Callback, Login, Search};
+use views::characters::{Character, CharacterById, CharacterCreate, CharacterEdit, CharacterSpellbook, PlayerLoot};
+use views::campaigns::{Campaigns, CampaignLayout, CampaignDashboard, AdventureLog, Lore, MapLocations, NpcView, CampaignRolls}; 
+use views::dm_tools::{CombatTracker, DMTools, Encounters};
+use views::references::{Classes, CoreRules, FeatsAbilities, Items, MonsterDetail, Monsters, SpellDetail, Spells, QuickReference};
+use views::homebrew::{Homebrew, HomebrewPackView};
 mod components;
 mod views;
 mod api;
+mod rules;
 
 
 
@@ -29,6 +32,8 @@ fn main() {
 
 #[component]
 fn App() -> Element {
+    use_context_provider(|| Signal::new(views::routes::GuardContext::default()));
+
     rsx! {
         document::Link { rel: "icon", href: FAVICON }
         document::Link { rel: "stylesheet", href: TAILWIND_CSS }
@@ -46,12 +51,21 @@ enum Route {
         #[route("/characters")]
         Character {},
 
+        #[route("/characters/new")]
+        CharacterCreate {},
+
         #[route("/characters/:slug")]
         CharacterById { slug: String },
 
         #[route("/characters/:slug/edit")]
         CharacterEdit { slug: String },
 
+        #[route("/characters/:slug/inventory")]
+        PlayerLoot { slug: String },
+
+        #[route("/characters/:slug/spells")]
+        CharacterSpellbook { slug: String },
+
 
         #[route("/about")]
         About {},
@@ -66,20 +80,36 @@ enum Route {
         #[route("/campaign")]
         Campaigns {},
 
-        #[route("/campaign/dashboard")]
-        CampaignDashboard {},
+        #[nest("/campaign/:campaign_slug")]
+        #[layout(CampaignLayout)]
+            #[route("/dashboard")]
+            CampaignDashboard { campaign_slug: String },
+
+            #[route("/adventure-log")]
+            AdventureLog { campaign_slug: String },
+
+            #[route("/lore")]
+            Lore { campaign_slug: String },
+
+            #[route("/map-locations")]
+            MapLocations { campaign_slug: String },
+
+            #[route("/npcs")]
+            NpcView { campaign_slug: String },
 
-        #[route("/campaign/adventure-log")]
-        AdventureLog {},
+            #[route("/rolls")]
+            CampaignRolls { campaign_slug: String },
 
-        #[route("/campaign/lore")]
-        Lore {},
+            #[route("/combat")]
+            Encounters { campaign_slug: String },
 
-        #[route("/campaign/map-locations")]
-        MapLocations {},
+            #[route("/combat/:encounter_id")]
+            CombatTracker { campaign_slug: String, encounter_id: i32 },
 
-        #[route("/campaign/npcs")]
-        NpcView {},
+            #[route("/dm-tools")]
+            DMTools { campaign_slug: String },
+        #[end_layout]
+        #[end_nest]
 
         #[route("/reference/quick-reference")]
         QuickReference {},
@@ -92,8 +122,22 @@ enum Route {
         FeatsAbilities {},
         #[route("/reference/monsters")]
         Monsters {},
+        #[route("/reference/monsters/:slug")]
+        MonsterDetail { slug: String },
         #[route("/reference/spells")]
         Spells {},
+        #[route("/reference/spells/:slug")]
+        SpellDetail { slug: String },
+        #[route("/reference/items")]
+        Items {},
+
+        #[route("/search?:q")]
+        Search { q: String },
+
+        #[route("/homebrew")]
+        Homebrew {},
+        #[route("/homebrew/:slug")]
+        HomebrewPackView { slug: String },
 
         #[route("/protected")]
         Protected {},
@@ -0,0 +1,421 @@
+//! The fixed lists a character is built from: races, classes and their subclasses, backgrounds,
+//! alignments and schools of magic, the sizes, types and habitats the bestiary sorts
+//! monsters by, and the categories and rarities of catalog items. Each entry is stored in
+//! Supabase under its display name, and older rows that were typed in by hand are read back
+//! through the same forgiving lookup (see [`Race::from_name`]).
+
+use serde::{Deserialize, Serialize};
+use super::{normalize, Ability, Skill};
+
+/// Declares a catalog enum that serializes as its display name.
+/// Aliases are older spellings found in hand-typed rows that map onto an entry.
+macro_rules! catalog {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $what:literal {
+            $($variant:ident => $label:literal $(| $alias:literal)*,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        #[serde(into = "String", try_from = "String")]
+        pub enum $name {
+            $($variant,)+
+        }
+
+        impl $name {
+            pub const ALL: &'static [$name] = &[$($name::$variant,)+];
+
+            pub fn name(self) -> &'static str {
+                match self {
+                    $($name::$variant => $label,)+
+                }
+            }
+
+            fn aliases(self) -> &'static [&'static str] {
+                match self {
+                    $($name::$variant => &[$($alias),*],)+
+                }
+            }
+
+            /// Look an entry up by name, ignoring case, spacing and punctuation
+            pub fn from_name(name: &str) -> Option<$name> {
+                let wanted = normalize(name);
+                Self::ALL.iter().copied().find(|entry| {
+                    normalize(entry.name()) == wanted || entry.aliases().iter().any(|alias| normalize(alias) == wanted)
+                })
+            }
+
+            /// `(value, label)` pairs for a `SelectInput`
+            pub fn options() -> Vec<(String, String)> {
+                Self::ALL.iter().map(|entry| (entry.name().to_string(), entry.name().to_string())).collect()
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.name())
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(entry: $name) -> String {
+                entry.name().to_string()
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = String;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                $name::from_name(&value).ok_or_else(|| format!("Unknown {} '{}'", $what, value))
+            }
+        }
+    };
+}
+
+catalog! {
+    Race, "race" {
+        Dragonborn => "Dragonborn",
+        Dwarf => "Dwarf" | "Hill Dwarf" | "Mountain Dwarf",
+        Elf => "Elf" | "High Elf" | "Wood Elf" | "Drow" | "Dark Elf",
+        Gnome => "Gnome" | "Forest Gnome" | "Rock Gnome",
+        HalfElf => "Half-Elf",
+        Halfling => "Halfling" | "Lightfoot Halfling" | "Stout Halfling",
+        HalfOrc => "Half-Orc",
+        Human => "Human",
+        Tiefling => "Tiefling",
+    }
+}
+
+impl Race {
+    /// Fixed ability score increases. Half-elves also raise two other abilities of the
+    /// player's choice by 1, which is left to the player.
+    pub fn ability_bonuses(self) -> &'static [(Ability, i32)] {
+        use Ability::*;
+        match self {
+            Race::Dragonborn => &[(Strength, 2), (Charisma, 1)],
+            Race::Dwarf => &[(Constitution, 2)],
+            Race::Elf => &[(Dexterity, 2)],
+            Race::Gnome => &[(Intelligence, 2)],
+            Race::HalfElf => &[(Charisma, 2)],
+            Race::Halfling => &[(Dexterity, 2)],
+            Race::HalfOrc => &[(Strength, 2), (Constitution, 1)],
+            Race::Human => &[(Strength, 1), (Dexterity, 1), (Constitution, 1), (Intelligence, 1), (Wisdom, 1), (Charisma, 1)],
+            Race::Tiefling => &[(Charisma, 2), (Intelligence, 1)],
+        }
+    }
+
+    pub fn ability_bonus(self, ability: Ability) -> i32 {
+        self.ability_bonuses().iter().filter(|(a, _)| *a == ability).map(|(_, bonus)| bonus).sum()
+    }
+
+    /// Base walking speed in feet
+    pub fn speed(self) -> i32 {
+        match self {
+            Race::Dwarf | Race::Gnome | Race::Halfling => 25,
+            _ => 30,
+        }
+    }
+}
+
+catalog! {
+    Class, "class" {
+        Barbarian => "Barbarian",
+        Bard => "Bard",
+        Cleric => "Cleric",
+        Druid => "Druid",
+        Fighter => "Fighter",
+        Monk => "Monk",
+        Paladin => "Paladin",
+        Ranger => "Ranger",
+        Rogue => "Rogue",
+        Sorcerer => "Sorcerer",
+        Warlock => "Warlock",
+        Wizard => "Wizard",
+    }
+}
+
+impl Class {
+    /// Hit die size, e.g. 10 for a Fighter's d10
+    pub fn hit_die(self) -> i32 {
+        match self {
+            Class::Barbarian => 12,
+            Class::Fighter | Class::Paladin | Class::Ranger => 10,
+            Class::Sorcerer | Class::Wizard => 6,
+            _ => 8,
+        }
+    }
+
+    /// The abilities this class is proficient in saving throws for
+    pub fn saving_throws(self) -> [Ability; 2] {
+        use Ability::*;
+        match self {
+            Class::Barbarian | Class::Fighter => [Strength, Constitution],
+            Class::Bard => [Dexterity, Charisma],
+            Class::Cleric | Class::Paladin | Class::Warlock => [Wisdom, Charisma],
+            Class::Druid | Class::Wizard => [Intelligence, Wisdom],
+            Class::Monk | Class::Ranger => [Strength, Dexterity],
+            Class::Rogue => [Dexterity, Intelligence],
+            Class::Sorcerer => [Constitution, Charisma],
+        }
+    }
+
+    /// The ability this class casts spells with, if it casts at all
+    pub fn spellcasting_ability(self) -> Option<Ability> {
+        match self {
+            Class::Bard | Class::Paladin | Class::Sorcerer | Class::Warlock => Some(Ability::Charisma),
+            Class::Cleric | Class::Druid | Class::Ranger => Some(Ability::Wisdom),
+            Class::Wizard => Some(Ability::Intelligence),
+            Class::Barbarian | Class::Fighter | Class::Monk | Class::Rogue => None,
+        }
+    }
+
+    /// The class level at which a subclass is chosen
+    pub fn subclass_level(self) -> i32 {
+        match self {
+            Class::Cleric | Class::Sorcerer | Class::Warlock => 1,
+            Class::Druid | Class::Wizard => 2,
+            _ => 3,
+        }
+    }
+
+    /// How many skills a new character of this class picks, and the skills they pick from
+    pub fn skill_choices(self) -> (usize, &'static [Skill]) {
+        use Skill::*;
+        match self {
+            Class::Barbarian => (2, &[AnimalHandling, Athletics, Intimidation, Nature, Perception, Survival]),
+            Class::Bard => (3, &Skill::ALL),
+            Class::Cleric => (2, &[History, Insight, Medicine, Persuasion, Religion]),
+            Class::Druid => (2, &[Arcana, AnimalHandling, Insight, Medicine, Nature, Perception, Religion, Survival]),
+            Class::Fighter => (2, &[Acrobatics, AnimalHandling, Athletics, History, Insight, Intimidation, Perception, Survival]),
+            Class::Monk => (2, &[Acrobatics, Athletics, History, Insight, Religion, Stealth]),
+            Class::Paladin => (2, &[Athletics, Insight, Intimidation, Medicine, Persuasion, Religion]),
+            Class::Ranger => (3, &[AnimalHandling, Athletics, Insight, Investigation, Nature, Perception, Stealth, Survival]),
+            Class::Rogue => (
+                4,
+                &[
+                    Acrobatics, Athletics, Deception, Insight, Intimidation, Investigation, Perception, Performance, Persuasion,
+                    SleightOfHand, Stealth,
+                ],
+            ),
+            Class::Sorcerer => (2, &[Arcana, Deception, Insight, Intimidation, Persuasion, Religion]),
+            Class::Warlock => (2, &[Arcana, Deception, History, Intimidation, Investigation, Nature, Religion]),
+            Class::Wizard => (2, &[Arcana, History, Insight, Investigation, Medicine, Religion]),
+        }
+    }
+
+    pub fn subclasses(self) -> Vec<Subclass> {
+        Subclass::ALL.iter().copied().filter(|subclass| subclass.class() == self).collect()
+    }
+}
+
+catalog! {
+    Subclass, "subclass" {
+        Berserker => "Path of the Berserker" | "Berserker",
+        TotemWarrior => "Path of the Totem Warrior" | "Totem Warrior",
+        Lore => "College of Lore",
+        Valor => "College of Valor",
+        Knowledge => "Knowledge Domain",
+        Life => "Life Domain",
+        Light => "Light Domain",
+        NatureDomain => "Nature Domain",
+        Tempest => "Tempest Domain",
+        Trickery => "Trickery Domain",
+        War => "War Domain",
+        Land => "Circle of the Land",
+        Moon => "Circle of the Moon",
+        Champion => "Champion",
+        BattleMaster => "Battle Master",
+        EldritchKnight => "Eldritch Knight",
+        OpenHand => "Way of the Open Hand" | "Open Hand",
+        Shadow => "Way of Shadow",
+        FourElements => "Way of the Four Elements",
+        Devotion => "Oath of Devotion",
+        Ancients => "Oath of the Ancients",
+        Vengeance => "Oath of Vengeance",
+        Hunter => "Hunter",
+        BeastMaster => "Beast Master",
+        Thief => "Thief",
+        Assassin => "Assassin",
+        ArcaneTrickster => "Arcane Trickster",
+        Draconic => "Draconic Bloodline",
+        WildMagic => "Wild Magic",
+        Archfey => "The Archfey" | "Archfey",
+        Fiend => "The Fiend" | "Fiend",
+        GreatOldOne => "The Great Old One" | "Great Old One",
+        Abjuration => "School of Abjuration" | "Abjuration",
+        Conjuration => "School of Conjuration" | "Conjuration",
+        Divination => "School of Divination" | "Divination",
+        Enchantment => "School of Enchantment" | "Enchantment",
+        Evocation => "School of Evocation" | "Evocation",
+        Illusion => "School of Illusion" | "Illusion",
+        Necromancy => "School of Necromancy" | "Necromancy",
+        Transmutation => "School of Transmutation" | "Transmutation",
+    }
+}
+
+impl Subclass {
+    pub fn class(self) -> Class {
+        use Subclass::*;
+        match self {
+            Berserker | TotemWarrior => Class::Barbarian,
+            Lore | Valor => Class::Bard,
+            Knowledge | Life | Light | NatureDomain | Tempest | Trickery | War => Class::Cleric,
+            Land | Moon => Class::Druid,
+            Champion | BattleMaster | EldritchKnight => Class::Fighter,
+            OpenHand | Shadow | FourElements => Class::Monk,
+            Devotion | Ancients | Vengeance => Class::Paladin,
+            Hunter | BeastMaster => Class::Ranger,
+            Thief | Assassin | ArcaneTrickster => Class::Rogue,
+            Draconic | WildMagic => Class::Sorcerer,
+            Archfey | Fiend | GreatOldOne => Class::Warlock,
+            Abjuration | Conjuration | Divination | Enchantment | Evocation | Illusion | Necromancy | Transmutation => {
+                Class::Wizard
+            }
+        }
+    }
+}
+
+catalog! {
+    Background, "background" {
+        Acolyte => "Acolyte",
+        Charlatan => "Charlatan",
+        Criminal => "Criminal" | "Spy",
+        Entertainer => "Entertainer" | "Gladiator",
+        FolkHero => "Folk Hero",
+        GuildArtisan => "Guild Artisan" | "Guild Merchant",
+        Hermit => "Hermit",
+        Noble => "Noble" | "Knight",
+        Outlander => "Outlander",
+        Sage => "Sage",
+        Sailor => "Sailor" | "Pirate",
+        Soldier => "Soldier",
+        Urchin => "Urchin",
+    }
+}
+
+impl Background {
+    /// The two skills this background makes the character proficient in
+    pub fn skill_proficiencies(self) -> [Skill; 2] {
+        use Skill::*;
+        match self {
+            Background::Acolyte => [Insight, Religion],
+            Background::Charlatan => [Deception, SleightOfHand],
+            Background::Criminal => [Deception, Stealth],
+            Background::Entertainer => [Acrobatics, Performance],
+            Background::FolkHero => [AnimalHandling, Survival],
+            Background::GuildArtisan => [Insight, Persuasion],
+            Background::Hermit => [Medicine, Religion],
+            Background::Noble => [History, Persuasion],
+            Background::Outlander => [Athletics, Survival],
+            Background::Sage => [Arcana, History],
+            Background::Sailor => [Athletics, Perception],
+            Background::Soldier => [Athletics, Intimidation],
+            Background::Urchin => [SleightOfHand, Stealth],
+        }
+    }
+}
+
+catalog! {
+    Alignment, "alignment" {
+        LawfulGood => "Lawful Good" | "LG",
+        NeutralGood => "Neutral Good" | "NG",
+        ChaoticGood => "Chaotic Good" | "CG",
+        LawfulNeutral => "Lawful Neutral" | "LN",
+        Neutral => "Neutral" | "True Neutral" | "N" | "TN",
+        ChaoticNeutral => "Chaotic Neutral" | "CN",
+        LawfulEvil => "Lawful Evil" | "LE",
+        NeutralEvil => "Neutral Evil" | "NE",
+        ChaoticEvil => "Chaotic Evil" | "CE",
+    }
+}
+
+catalog! {
+    /// The eight schools of magic every spell belongs to
+    MagicSchool, "school of magic" {
+        Abjuration => "Abjuration",
+        Conjuration => "Conjuration",
+        Divination => "Divination",
+        Enchantment => "Enchantment",
+        Evocation => "Evocation",
+        Illusion => "Illusion",
+        Necromancy => "Necromancy",
+        Transmutation => "Transmutation",
+    }
+}
+
+catalog! {
+    /// How much space a creature takes up on the battlefield
+    Size, "size" {
+        Tiny => "Tiny",
+        Small => "Small",
+        Medium => "Medium",
+        Large => "Large",
+        Huge => "Huge",
+        Gargantuan => "Gargantuan",
+    }
+}
+
+catalog! {
+    CreatureType, "creature type" {
+        Aberration => "Aberration",
+        Beast => "Beast",
+        Celestial => "Celestial",
+        Construct => "Construct",
+        Dragon => "Dragon",
+        Elemental => "Elemental",
+        Fey => "Fey",
+        Fiend => "Fiend",
+        Giant => "Giant",
+        Humanoid => "Humanoid",
+        Monstrosity => "Monstrosity",
+        Ooze => "Ooze",
+        Plant => "Plant",
+        Undead => "Undead",
+    }
+}
+
+catalog! {
+    /// Where a monster is usually met, for building encounters that fit the map
+    Environment, "environment" {
+        Arctic => "Arctic",
+        Coastal => "Coastal",
+        Desert => "Desert",
+        Forest => "Forest",
+        Grassland => "Grassland",
+        Hill => "Hill" | "Hills",
+        Mountain => "Mountain" | "Mountains",
+        Swamp => "Swamp",
+        Underdark => "Underdark",
+        Underwater => "Underwater",
+        Urban => "Urban" | "City",
+    }
+}
+
+catalog! {
+    /// What kind of thing a catalog item is, as magic items are grouped in the SRD
+    ItemCategory, "item category" {
+        AdventuringGear => "Adventuring Gear" | "Gear",
+        Armor => "Armor",
+        Potion => "Potion",
+        Ring => "Ring",
+        Rod => "Rod",
+        Scroll => "Scroll",
+        Staff => "Staff",
+        Wand => "Wand",
+        Weapon => "Weapon",
+        WondrousItem => "Wondrous Item",
+    }
+}
+
+catalog! {
+    Rarity, "rarity" {
+        Common => "Common",
+        Uncommon => "Uncommon",
+        Rare => "Rare",
+        VeryRare => "Very Rare",
+        Legendary => "Legendary",
+        Artifact => "Artifact",
+    }
+}
@@ -0,0 +1,527 @@
+//! Running an encounter: initiative order, turns and rounds, and each combatant's hit points,
+//! conditions, concentration and legendary actions. The DM's combat tracker keeps one
+//! [`Encounter`] and saves it whole, so a fight can be picked up again later.
+
+use serde::{Deserialize, Serialize};
+use super::ability_modifier;
+use super::play::Condition;
+
+/// A condition that wears off by itself after a number of rounds
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimedCondition {
+    pub condition: Condition,
+    /// Rounds left, counted down at the end of the affected creature's turn. `None` lasts until removed.
+    pub rounds: Option<i32>,
+}
+
+/// Which side a combatant fights on, and where its numbers came from
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum Side {
+    /// A party member's character. Their hit points live on the sheet and are copied in.
+    Party { character_id: i32, slug: String },
+    /// A monster or NPC, from the bestiary when `monster` holds its slug
+    Monster { monster: Option<String> },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Combatant {
+    pub id: u32,
+    pub name: String,
+    pub side: Side,
+    pub initiative: Option<i32>,
+    pub initiative_bonus: i32,
+    /// DEX score, which breaks initiative ties
+    pub dexterity: i32,
+    pub armor_class: i32,
+    pub hit_points: i32,
+    pub max_hit_points: i32,
+    #[serde(default)]
+    pub temporary_hit_points: i32,
+    #[serde(default)]
+    pub conditions: Vec<TimedCondition>,
+    /// Name of the spell being concentrated on
+    #[serde(default)]
+    pub concentration: Option<String>,
+    /// Legendary actions per round, 0 for ordinary creatures
+    #[serde(default)]
+    pub legendary_actions: i32,
+    #[serde(default)]
+    pub legendary_used: i32,
+}
+
+impl Combatant {
+    pub fn monster(name: &str, armor_class: i32, hit_points: i32, dexterity: i32) -> Self {
+        Self {
+            id: 0,
+            name: name.to_string(),
+            side: Side::Monster { monster: None },
+            initiative: None,
+            initiative_bonus: ability_modifier(dexterity),
+            dexterity,
+            armor_class,
+            hit_points,
+            max_hit_points: hit_points,
+            temporary_hit_points: 0,
+            conditions: vec![],
+            concentration: None,
+            legendary_actions: 0,
+            legendary_used: 0,
+        }
+    }
+
+    pub fn is_party(&self) -> bool {
+        matches!(self.side, Side::Party { .. })
+    }
+
+    /// Monsters at 0 hit points are out of the fight; characters still roll death saves
+    pub fn is_defeated(&self) -> bool {
+        !self.is_party() && self.hit_points == 0
+    }
+
+    pub fn has(&self, condition: Condition) -> bool {
+        self.conditions.iter().any(|timed| timed.condition == condition)
+    }
+
+    pub fn legendary_remaining(&self) -> i32 {
+        (self.legendary_actions - self.legendary_used).max(0)
+    }
+}
+
+/// One fight. `round` is 0 until initiative is settled and the fight starts.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Encounter {
+    pub combatants: Vec<Combatant>,
+    pub round: i32,
+    /// Id of the combatant whose turn it is
+    pub turn: Option<u32>,
+    pub next_id: u32,
+}
+
+impl Encounter {
+    pub fn is_started(&self) -> bool {
+        self.round > 0
+    }
+
+    pub fn combatant(&self, id: u32) -> Option<&Combatant> {
+        self.combatants.iter().find(|c| c.id == id)
+    }
+
+    fn combatant_mut(&mut self, id: u32) -> Result<&mut Combatant, String> {
+        self.combatants.iter_mut().find(|c| c.id == id).ok_or_else(|| "That combatant has left the fight".to_string())
+    }
+
+    /// Add a combatant and return the id it was given
+    pub fn add(&mut self, mut combatant: Combatant) -> u32 {
+        self.next_id = self.next_id.max(self.combatants.iter().map(|c| c.id).max().unwrap_or(0)) + 1;
+        combatant.id = self.next_id;
+        self.combatants.push(combatant);
+        self.next_id
+    }
+
+    /// Take a combatant out, passing the turn on if it was theirs
+    pub fn remove(&mut self, id: u32) {
+        if self.turn == Some(id) {
+            let order = self.order_ids();
+            let position = order.iter().position(|other| *other == id).unwrap_or(0);
+            self.turn = order.iter().cycle().skip(position + 1).take(order.len() - 1).copied().next();
+        }
+        self.combatants.retain(|c| c.id != id);
+        if self.combatants.is_empty() {
+            self.end();
+        }
+    }
+
+    /// Turn order: highest initiative first, then the higher DEX score, then whoever was added first.
+    /// Combatants without initiative go last.
+    pub fn order(&self) -> Vec<&Combatant> {
+        let mut order: Vec<&Combatant> = self.combatants.iter().collect();
+        order.sort_by(|a, b| {
+            b.initiative
+                .cmp(&a.initiative)
+                .then(b.dexterity.cmp(&a.dexterity))
+                .then(b.initiative_bonus.cmp(&a.initiative_bonus))
+                .then(a.id.cmp(&b.id))
+        });
+        order
+    }
+
+    fn order_ids(&self) -> Vec<u32> {
+        self.order().into_iter().map(|c| c.id).collect()
+    }
+
+    pub fn set_initiative(&mut self, id: u32, initiative: Option<i32>) -> Result<(), String> {
+        self.combatant_mut(id)?.initiative = initiative;
+        Ok(())
+    }
+
+    /// Roll d20 + bonus for everyone still without an initiative
+    pub fn roll_initiative(&mut self, roll: &mut dyn FnMut(i32) -> i32) -> Vec<String> {
+        self.combatants
+            .iter_mut()
+            .filter(|c| c.initiative.is_none())
+            .map(|c| {
+                let die = roll(20);
+                let total = die + c.initiative_bonus;
+                c.initiative = Some(total);
+                format!("{} rolls {} for initiative ({} {:+})", c.name, total, die, c.initiative_bonus)
+            })
+            .collect()
+    }
+
+    pub fn start(&mut self) -> Result<String, String> {
+        if self.combatants.is_empty() {
+            return Err("Add someone to fight first".to_string());
+        }
+        let waiting: Vec<&str> = self.combatants.iter().filter(|c| c.initiative.is_none()).map(|c| c.name.as_str()).collect();
+        if !waiting.is_empty() {
+            return Err(format!("Still waiting on initiative from {}", waiting.join(", ")));
+        }
+        self.round = 1;
+        self.turn = None;
+        let first = self.next_in_order(None);
+        self.begin_turn(first);
+        Ok(format!("Round 1: {} goes first", self.turn_name()))
+    }
+
+    pub fn end(&mut self) {
+        self.round = 0;
+        self.turn = None;
+    }
+
+    fn turn_name(&self) -> String {
+        self.turn.and_then(|id| self.combatant(id)).map_or_else(String::new, |c| c.name.clone())
+    }
+
+    // The next combatant after `after` who can still act, and whether the order wrapped around
+    fn next_in_order(&self, after: Option<u32>) -> (Option<u32>, bool) {
+        let order: Vec<&Combatant> = self.order();
+        let start = after.and_then(|id| order.iter().position(|c| c.id == id)).map_or(0, |position| position + 1);
+        (0..order.len())
+            .map(|offset| (start + offset) % order.len())
+            .find(|index| !order[*index].is_defeated())
+            .map(|index| (Some(order[index].id), after.is_some() && index < start))
+            .unwrap_or((None, false))
+    }
+
+    fn begin_turn(&mut self, (next, wrapped): (Option<u32>, bool)) {
+        if wrapped {
+            self.round += 1;
+        }
+        self.turn = next;
+        // Legendary actions come back at the start of the creature's own turn
+        if let Some(combatant) = next.and_then(|id| self.combatants.iter_mut().find(|c| c.id == id)) {
+            combatant.legendary_used = 0;
+        }
+    }
+
+    /// End the current turn, counting down its conditions, and start the next one
+    pub fn next_turn(&mut self) -> Vec<String> {
+        if !self.is_started() {
+            return vec![];
+        }
+        let mut messages = vec![];
+        if let Some(current) = self.turn.and_then(|id| self.combatants.iter_mut().find(|c| c.id == id)) {
+            for timed in current.conditions.iter_mut() {
+                if let Some(rounds) = timed.rounds.as_mut() {
+                    *rounds -= 1;
+                }
+            }
+            let (expired, lasting): (Vec<TimedCondition>, Vec<TimedCondition>) =
+                current.conditions.iter().partition(|timed| timed.rounds.is_some_and(|rounds| rounds <= 0));
+            for timed in expired {
+                messages.push(format!("{} is no longer {}", current.name, timed.condition.name().to_lowercase()));
+            }
+            current.conditions = lasting;
+        }
+
+        let round = self.round;
+        let next = self.next_in_order(self.turn);
+        self.begin_turn(next);
+        if self.round != round {
+            messages.push(format!("Round {}", self.round));
+        }
+        messages.push(format!("{}'s turn", self.turn_name()));
+        messages
+    }
+
+    /// Step back to the previous turn, e.g. after clicking Next too soon. Conditions aren't restored.
+    pub fn previous_turn(&mut self) {
+        let order = self.order_ids();
+        let Some(position) = self.turn.and_then(|id| order.iter().position(|other| *other == id)) else {
+            return;
+        };
+        if position == 0 {
+            if self.round > 1 {
+                self.round -= 1;
+                self.turn = order.last().copied();
+            }
+        } else {
+            self.turn = Some(order[position - 1]);
+        }
+    }
+
+    /// Damage a combatant, temporary hit points first. Notes the concentration save it calls for.
+    pub fn damage(&mut self, id: u32, amount: i32) -> Result<String, String> {
+        if amount < 1 {
+            return Err("Damage must be at least 1".to_string());
+        }
+        let combatant = self.combatant_mut(id)?;
+        let absorbed = amount.min(combatant.temporary_hit_points);
+        combatant.temporary_hit_points -= absorbed;
+        let remaining = amount - absorbed;
+        combatant.hit_points = (combatant.hit_points - remaining).max(0);
+
+        let mut message = format!("{} takes {} damage", combatant.name, amount);
+        if combatant.hit_points == 0 {
+            if let Some(spell) = combatant.concentration.take() {
+                message.push_str(&format!(", loses concentration on {}", spell));
+            }
+            if combatant.is_party() {
+                message.push_str(" and falls unconscious");
+                if !combatant.has(Condition::Unconscious) {
+                    combatant.conditions.push(TimedCondition { condition: Condition::Unconscious, rounds: None });
+                }
+            } else {
+                message.push_str(" and is defeated");
+            }
+        } else if remaining > 0 {
+            if let Some(spell) = &combatant.concentration {
+                message.push_str(&format!("; DC {} Constitution save to keep concentrating on {}", (amount / 2).max(10), spell));
+            }
+        }
+
+        // Defeated monsters give up their turn
+        if self.turn == Some(id) && self.combatant(id).is_some_and(Combatant::is_defeated) {
+            let next = self.next_in_order(Some(id));
+            self.begin_turn(next);
+        }
+        Ok(message)
+    }
+
+    pub fn heal(&mut self, id: u32, amount: i32) -> Result<String, String> {
+        if amount < 1 {
+            return Err("Healing must be at least 1".to_string());
+        }
+        let combatant = self.combatant_mut(id)?;
+        let before = combatant.hit_points;
+        combatant.hit_points = (combatant.hit_points + amount).min(combatant.max_hit_points);
+        if before == 0 && combatant.hit_points > 0 {
+            combatant.conditions.retain(|timed| timed.condition != Condition::Unconscious);
+        }
+        Ok(format!("{} regains {} hit points", combatant.name, combatant.hit_points - before))
+    }
+
+    pub fn set_temporary_hit_points(&mut self, id: u32, amount: i32) -> Result<(), String> {
+        if amount < 0 {
+            return Err("Temporary hit points can't be negative".to_string());
+        }
+        // Temporary hit points don't stack; keep the larger amount
+        let combatant = self.combatant_mut(id)?;
+        combatant.temporary_hit_points = combatant.temporary_hit_points.max(amount);
+        Ok(())
+    }
+
+    /// Apply a condition, replacing the duration of one already there
+    pub fn add_condition(&mut self, id: u32, condition: Condition, rounds: Option<i32>) -> Result<(), String> {
+        if rounds.is_some_and(|rounds| rounds < 1) {
+            return Err("A condition has to last at least one round".to_string());
+        }
+        let combatant = self.combatant_mut(id)?;
+        combatant.conditions.retain(|timed| timed.condition != condition);
+        combatant.conditions.push(TimedCondition { condition, rounds });
+        Ok(())
+    }
+
+    pub fn remove_condition(&mut self, id: u32, condition: Condition) -> Result<(), String> {
+        self.combatant_mut(id)?.conditions.retain(|timed| timed.condition != condition);
+        Ok(())
+    }
+
+    pub fn set_concentration(&mut self, id: u32, spell: Option<String>) -> Result<(), String> {
+        self.combatant_mut(id)?.concentration = spell.filter(|spell| !spell.trim().is_empty());
+        Ok(())
+    }
+
+    /// Spend one legendary action, which happens at the end of another creature's turn
+    pub fn use_legendary_action(&mut self, id: u32) -> Result<String, String> {
+        let turn = self.turn;
+        let combatant = self.combatant_mut(id)?;
+        if turn == Some(id) {
+            return Err(format!("{} can only take legendary actions on other creatures' turns", combatant.name));
+        }
+        if combatant.legendary_remaining() == 0 {
+            return Err(format!("{} has no legendary actions left this round", combatant.name));
+        }
+        combatant.legendary_used += 1;
+        Ok(format!("{} takes a legendary action ({} left)", combatant.name, combatant.legendary_remaining()))
+    }
+
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = vec![];
+        for combatant in &self.combatants {
+            if combatant.name.trim().is_empty() {
+                errors.push("Every combatant needs a name".to_string());
+            }
+            if combatant.max_hit_points < 1 {
+                errors.push(format!("{} needs at least 1 maximum hit point", combatant.name));
+            }
+            if combatant.hit_points < 0 || combatant.hit_points > combatant.max_hit_points {
+                errors.push(format!("{} has hit points outside 0 to {}", combatant.name, combatant.max_hit_points));
+            }
+            if combatant.legendary_actions < 0 || combatant.legendary_used < 0 {
+                errors.push(format!("{} can't have negative legendary actions", combatant.name));
+            }
+        }
+        if self.turn.is_some_and(|id| self.combatant(id).is_none()) {
+            errors.push("It's the turn of a combatant who isn't in the fight".to_string());
+        }
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn party(name: &str, dexterity: i32) -> Combatant {
+        Combatant {
+            side: Side::Party { character_id: 1, slug: name.to_lowercase() },
+            ..Combatant::monster(name, 16, 30, dexterity)
+        }
+    }
+
+    // Goblin (1) and Dragon (2) tie on 15 but the goblin is quicker; Fighter (3) goes last
+    fn skirmish() -> Encounter {
+        let mut encounter = Encounter::default();
+        for (combatant, initiative) in [
+            (Combatant::monster("Goblin", 15, 7, 14), 15),
+            (Combatant { legendary_actions: 3, ..Combatant::monster("Dragon", 18, 200, 10) }, 15),
+            (party("Fighter", 12), 10),
+        ] {
+            let id = encounter.add(combatant);
+            encounter.set_initiative(id, Some(initiative)).unwrap();
+        }
+        encounter
+    }
+
+    fn names(encounter: &Encounter) -> Vec<&str> {
+        encounter.order().into_iter().map(|c| c.name.as_str()).collect()
+    }
+
+    #[test]
+    fn initiative_order_breaks_ties() {
+        let mut encounter = skirmish();
+        assert_eq!(names(&encounter), vec!["Goblin", "Dragon", "Fighter"]);
+
+        // Same initiative and DEX: the one added first
+        let id = encounter.add(Combatant::monster("Twin", 15, 7, 14));
+        encounter.set_initiative(id, Some(15)).unwrap();
+        let late = encounter.add(Combatant::monster("Latecomer", 12, 10, 10));
+        assert_eq!(names(&encounter), vec!["Goblin", "Twin", "Dragon", "Fighter", "Latecomer"]);
+        assert_eq!(encounter.start(), Err("Still waiting on initiative from Latecomer".to_string()));
+
+        let rolls = encounter.roll_initiative(&mut |_| 8);
+        assert_eq!(rolls, vec!["Latecomer rolls 8 for initiative (8 +0)".to_string()]);
+        assert_eq!(encounter.combatant(late).and_then(|c| c.initiative), Some(8));
+        assert_eq!(Encounter::default().start(), Err("Add someone to fight first".to_string()));
+    }
+
+    #[test]
+    fn turns_and_rounds() {
+        let mut encounter = skirmish();
+        assert_eq!(encounter.start(), Ok("Round 1: Goblin goes first".to_string()));
+        assert_eq!(encounter.next_turn(), vec!["Dragon's turn".to_string()]);
+        assert_eq!(encounter.next_turn(), vec!["Fighter's turn".to_string()]);
+        assert_eq!(encounter.next_turn(), vec!["Round 2".to_string(), "Goblin's turn".to_string()]);
+
+        encounter.previous_turn();
+        assert_eq!((encounter.round, encounter.turn), (1, Some(3)));
+        encounter.previous_turn();
+        encounter.previous_turn();
+        encounter.previous_turn();
+        assert_eq!((encounter.round, encounter.turn), (1, Some(1)));
+    }
+
+    #[test]
+    fn timed_conditions_wear_off_at_the_end_of_the_turn() {
+        let mut encounter = skirmish();
+        encounter.start().unwrap();
+        encounter.add_condition(1, Condition::Frightened, Some(1)).unwrap();
+        encounter.add_condition(1, Condition::Prone, None).unwrap();
+        assert!(encounter.add_condition(1, Condition::Poisoned, Some(0)).is_err());
+
+        assert_eq!(encounter.next_turn(), vec!["Goblin is no longer frightened".to_string(), "Dragon's turn".to_string()]);
+        let goblin = encounter.combatant(1).unwrap();
+        assert!(goblin.has(Condition::Prone) && !goblin.has(Condition::Frightened));
+    }
+
+    #[test]
+    fn damage_and_healing() {
+        let mut encounter = skirmish();
+        encounter.set_temporary_hit_points(3, 5).unwrap();
+        encounter.set_temporary_hit_points(3, 2).unwrap();
+        encounter.set_concentration(3, Some("Bless".to_string())).unwrap();
+
+        let message = encounter.damage(3, 25).unwrap();
+        assert_eq!(message, "Fighter takes 25 damage; DC 12 Constitution save to keep concentrating on Bless");
+        assert_eq!(encounter.combatant(3).unwrap().hit_points, 10);
+        let message = encounter.damage(3, 15).unwrap();
+        assert_eq!(message, "Fighter takes 15 damage, loses concentration on Bless and falls unconscious");
+        assert!(encounter.combatant(3).unwrap().has(Condition::Unconscious));
+        assert!(!encounter.combatant(3).unwrap().is_defeated());
+
+        assert_eq!(encounter.heal(3, 50).unwrap(), "Fighter regains 30 hit points");
+        assert!(!encounter.combatant(3).unwrap().has(Condition::Unconscious));
+        assert!(encounter.damage(3, 0).is_err());
+        assert!(encounter.heal(9, 1).is_err());
+    }
+
+    #[test]
+    fn defeated_monsters_lose_their_turns() {
+        let mut encounter = skirmish();
+        encounter.start().unwrap();
+        assert_eq!(encounter.damage(1, 10).unwrap(), "Goblin takes 10 damage and is defeated");
+        assert_eq!(encounter.turn, Some(2));
+        encounter.next_turn();
+        assert_eq!(encounter.next_turn(), vec!["Round 2".to_string(), "Dragon's turn".to_string()]);
+
+        encounter.remove(2);
+        assert_eq!(encounter.turn, Some(3));
+        encounter.remove(1);
+        encounter.remove(3);
+        assert!(!encounter.is_started());
+    }
+
+    #[test]
+    fn legendary_actions_come_back_on_their_own_turn() {
+        let mut encounter = skirmish();
+        encounter.start().unwrap();
+        assert_eq!(encounter.use_legendary_action(2), Ok("Dragon takes a legendary action (2 left)".to_string()));
+        encounter.use_legendary_action(2).unwrap();
+        encounter.use_legendary_action(2).unwrap();
+        assert_eq!(encounter.use_legendary_action(2), Err("Dragon has no legendary actions left this round".to_string()));
+
+        encounter.next_turn();
+        assert_eq!(encounter.combatant(2).unwrap().legendary_remaining(), 3);
+        assert!(encounter.use_legendary_action(2).is_err());
+    }
+
+    #[test]
+    fn validation_catches_impossible_numbers() {
+        let mut encounter = skirmish();
+        assert!(encounter.validate().is_empty());
+        encounter.combatants[0].hit_points = 8;
+        encounter.combatants[1].name = " ".to_string();
+        encounter.turn = Some(9);
+        assert_eq!(
+            encounter.validate(),
+            vec![
+                "Goblin has hit points outside 0 to 7".to_string(),
+                "Every combatant needs a name".to_string(),
+                "It's the turn of a combatant who isn't in the fight".to_string(),
+            ]
+        );
+    }
+}
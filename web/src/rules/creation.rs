@@ -0,0 +1,196 @@
+//! Rules that only apply while building a new 1st-level character: the three ways of generating
+//! ability scores and the starting equipment each class can pick from.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use super::{ability_modifier, dice, Ability, Class};
+
+/// Points a player may spend on ability scores with point buy
+pub const POINT_BUY_BUDGET: i32 = 27;
+pub const POINT_BUY_MIN: i32 = 8;
+pub const POINT_BUY_MAX: i32 = 15;
+
+/// The six scores a player hands out when using the standard array
+pub const STANDARD_ARRAY: [i32; 6] = [15, 14, 13, 12, 10, 8];
+
+/// What a base score costs under point buy, or `None` if it can't be bought
+pub fn point_buy_cost(score: i32) -> Option<i32> {
+    match score {
+        8..=13 => Some(score - 8),
+        14 => Some(7),
+        15 => Some(9),
+        _ => None,
+    }
+}
+
+/// Points spent on a set of base scores; `None` if any score is outside the 8-15 range
+pub fn point_buy_spent(scores: &[i32]) -> Option<i32> {
+    scores.iter().map(|score| point_buy_cost(*score)).sum()
+}
+
+/// One ability score rolled as 4d6, dropping the lowest die
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbilityRoll {
+    /// The set of six this belongs to, numbered by the server that rolled it; a player who rerolls
+    /// keeps their earlier sets in the log
+    pub set: i32,
+    pub dice: [i32; 4],
+}
+
+impl AbilityRoll {
+    pub fn dropped(&self) -> i32 {
+        self.dice.iter().copied().min().unwrap_or(0)
+    }
+
+    pub fn total(&self) -> i32 {
+        self.dice.iter().sum::<i32>() - self.dropped()
+    }
+}
+
+/// Roll a full set of six ability scores
+pub fn roll_ability_scores(set: i32, rng: &mut impl Rng) -> Vec<AbilityRoll> {
+    (0..6)
+        .map(|_| AbilityRoll {
+            set,
+            dice: [dice::die(6, rng), dice::die(6, rng), dice::die(6, rng), dice::die(6, rng)],
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StartingArmor {
+    None,
+    /// Leather armor: 11 + Dexterity
+    Leather,
+    /// Scale mail: 14 + Dexterity (max 2)
+    ScaleMail,
+    /// Chain mail: 16, no Dexterity
+    ChainMail,
+}
+
+/// One of the starting equipment choices a class offers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EquipmentPackage {
+    pub name: &'static str,
+    pub items: &'static [&'static str],
+    pub armor: StartingArmor,
+    pub shield: bool,
+}
+
+impl EquipmentPackage {
+    /// Armor class wearing this package. Unarmored barbarians and monks add Constitution or Wisdom.
+    pub fn armor_class(&self, class: Class, score: impl Fn(Ability) -> i32) -> i32 {
+        let dex = ability_modifier(score(Ability::Dexterity));
+        let armor = match (self.armor, class) {
+            (StartingArmor::None, Class::Barbarian) => 10 + dex + ability_modifier(score(Ability::Constitution)),
+            (StartingArmor::None, Class::Monk) => 10 + dex + ability_modifier(score(Ability::Wisdom)),
+            (StartingArmor::None, _) => 10 + dex,
+            (StartingArmor::Leather, _) => 11 + dex,
+            (StartingArmor::ScaleMail, _) => 14 + dex.min(2),
+            (StartingArmor::ChainMail, _) => 16,
+        };
+        armor + if self.shield { 2 } else { 0 }
+    }
+}
+
+// A macro rather than a `const fn` so the package tables below are promoted to statics
+macro_rules! package {
+    ($name:expr, $items:expr, $armor:expr, $shield:expr $(,)?) => {
+        EquipmentPackage { name: $name, items: $items, armor: $armor, shield: $shield }
+    };
+}
+
+/// The starting equipment a class can choose between
+pub fn equipment_packages(class: Class) -> &'static [EquipmentPackage] {
+    use StartingArmor::*;
+    match class {
+        Class::Barbarian => &[
+            package!("Greataxe", &["Greataxe", "Two handaxes", "Four javelins", "Explorer's pack"], None, false),
+            package!("Battleaxe", &["Battleaxe", "Two handaxes", "Four javelins", "Explorer's pack"], None, false),
+        ],
+        Class::Bard => &[
+            package!("Rapier and diplomat's pack", &["Rapier", "Leather armor", "Dagger", "Lute", "Diplomat's pack"], Leather, false),
+            package!("Longsword and entertainer's pack", &["Longsword", "Leather armor", "Dagger", "Lute", "Entertainer's pack"], Leather, false),
+        ],
+        Class::Cleric => &[
+            package!(
+                "Scale mail",
+                &["Mace", "Scale mail", "Shield", "Light crossbow and 20 bolts", "Holy symbol", "Priest's pack"],
+                ScaleMail,
+                true,
+            ),
+            package!(
+                "Leather armor",
+                &["Mace", "Leather armor", "Shield", "Light crossbow and 20 bolts", "Holy symbol", "Explorer's pack"],
+                Leather,
+                true,
+            ),
+        ],
+        Class::Druid => &[
+            package!("Scimitar", &["Scimitar", "Wooden shield", "Leather armor", "Druidic focus", "Explorer's pack"], Leather, true),
+            package!("Quarterstaff", &["Quarterstaff", "Wooden shield", "Leather armor", "Druidic focus", "Explorer's pack"], Leather, true),
+        ],
+        Class::Fighter => &[
+            package!(
+                "Chain mail and shield",
+                &["Chain mail", "Longsword", "Shield", "Light crossbow and 20 bolts", "Dungeoneer's pack"],
+                ChainMail,
+                true,
+            ),
+            package!("Archer", &["Leather armor", "Longbow and 20 arrows", "Two handaxes", "Explorer's pack"], Leather, false),
+        ],
+        Class::Monk => &[
+            package!("Dungeoneer", &["Shortsword", "10 darts", "Dungeoneer's pack"], None, false),
+            package!("Explorer", &["Shortsword", "10 darts", "Explorer's pack"], None, false),
+        ],
+        Class::Paladin => &[
+            package!(
+                "Sword and shield",
+                &["Chain mail", "Longsword", "Shield", "Five javelins", "Holy symbol", "Priest's pack"],
+                ChainMail,
+                true,
+            ),
+            package!("Greatsword", &["Chain mail", "Greatsword", "Five javelins", "Holy symbol", "Explorer's pack"], ChainMail, false),
+        ],
+        Class::Ranger => &[
+            package!("Scale mail", &["Scale mail", "Two shortswords", "Longbow and 20 arrows", "Dungeoneer's pack"], ScaleMail, false),
+            package!("Leather armor", &["Leather armor", "Two shortswords", "Longbow and 20 arrows", "Explorer's pack"], Leather, false),
+        ],
+        Class::Rogue => &[
+            package!(
+                "Rapier and burglar's pack",
+                &["Rapier", "Shortbow and 20 arrows", "Leather armor", "Two daggers", "Thieves' tools", "Burglar's pack"],
+                Leather,
+                false,
+            ),
+            package!(
+                "Shortsword and dungeoneer's pack",
+                &["Shortsword", "Shortbow and 20 arrows", "Leather armor", "Two daggers", "Thieves' tools", "Dungeoneer's pack"],
+                Leather,
+                false,
+            ),
+        ],
+        Class::Sorcerer => &[
+            package!("Crossbow", &["Light crossbow and 20 bolts", "Component pouch", "Two daggers", "Dungeoneer's pack"], None, false),
+            package!("Quarterstaff", &["Quarterstaff", "Arcane focus", "Two daggers", "Explorer's pack"], None, false),
+        ],
+        Class::Warlock => &[
+            package!(
+                "Crossbow",
+                &["Light crossbow and 20 bolts", "Component pouch", "Leather armor", "Two daggers", "Quarterstaff", "Scholar's pack"],
+                Leather,
+                false,
+            ),
+            package!(
+                "Arcane focus",
+                &["Quarterstaff", "Arcane focus", "Leather armor", "Two daggers", "Dungeoneer's pack"],
+                Leather,
+                false,
+            ),
+        ],
+        Class::Wizard => &[
+            package!("Scholar", &["Quarterstaff", "Component pouch", "Spellbook", "Scholar's pack"], None, false),
+            package!("Explorer", &["Dagger", "Arcane focus", "Spellbook", "Explorer's pack"], None, false),
+        ],
+    }
+}
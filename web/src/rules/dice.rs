@@ -0,0 +1,750 @@
+//! Dice notation and the roller behind every roll in the app.
+//!
+//! Expressions mix dice and numbers with `+ - * /` and parentheses, e.g. `1d8+3`, `2d6 + (1d4 * 2)`.
+//! Each dice term can take modifiers after the sides:
+//! - `kh3` / `kl1` keep the highest or lowest dice (`k3` is `kh3`), `dh1` / `dl1` drop them
+//! - `!` explodes: every die showing its maximum adds another die; `!>5` explodes on 5 or more
+//! - `r1` rerolls any die showing 1 or lower, once, keeping the new roll; `r2` for 2 or lower
+//!
+//! Rolls take any [`Rng`], so a seeded one from [`seeded`] gives the same dice every time.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// Most dice a single term may roll, before explosions
+pub const MAX_DICE: u32 = 100;
+pub const MAX_SIDES: u32 = 1000;
+/// Most extra dice explosions may add to one term
+const MAX_EXPLOSIONS: usize = 100;
+const MAX_LENGTH: usize = 200;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Keep {
+    Highest(u32),
+    Lowest(u32),
+}
+
+/// One dice term like `4d6kh3` or `1d10!`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiceTerm {
+    pub count: u32,
+    pub sides: u32,
+    pub keep: Option<Keep>,
+    /// Explode on this value or higher
+    pub explode: Option<u32>,
+    /// Reroll once on this value or lower
+    pub reroll: Option<u32>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Op {
+    fn symbol(self) -> char {
+        match self {
+            Op::Add => '+',
+            Op::Subtract => '-',
+            Op::Multiply => '*',
+            Op::Divide => '/',
+        }
+    }
+
+    fn precedence(self) -> u8 {
+        match self {
+            Op::Add | Op::Subtract => 1,
+            Op::Multiply | Op::Divide => 2,
+        }
+    }
+}
+
+/// A parsed dice expression, ready to roll any number of times
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expression {
+    Number(i32),
+    Dice(DiceTerm),
+    Negate(Box<Expression>),
+    Binary(Op, Box<Expression>, Box<Expression>),
+}
+
+/// A single die as it landed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Die {
+    pub value: i32,
+    /// False for dice dropped by `kh`/`kl`/`dh`/`dl`
+    pub kept: bool,
+    /// Rolled because the die before it exploded
+    #[serde(default)]
+    pub exploded: bool,
+    /// What the die showed before it was rerolled
+    #[serde(default)]
+    pub rerolled_from: Option<i32>,
+}
+
+/// Every die of one dice term
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiceRoll {
+    pub notation: String,
+    pub sides: u32,
+    pub dice: Vec<Die>,
+    pub total: i32,
+}
+
+impl DiceRoll {
+    /// The dice in brackets, dropped ones in parentheses, e.g. `[6, 5, 3, (1)]`
+    pub fn dice_label(&self) -> String {
+        let dice: Vec<String> = self
+            .dice
+            .iter()
+            .map(|die| {
+                let mut label = match die.rerolled_from {
+                    Some(first) => format!("{}→{}", first, die.value),
+                    None => die.value.to_string(),
+                };
+                if die.exploded {
+                    label.insert(0, '!');
+                }
+                if die.kept {
+                    label
+                } else {
+                    format!("({})", label)
+                }
+            })
+            .collect();
+        format!("[{}]", dice.join(", "))
+    }
+}
+
+/// The outcome of rolling an expression
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RollResult {
+    pub expression: String,
+    pub rolls: Vec<DiceRoll>,
+    /// The expression with each dice term replaced by its dice, e.g. `4d6kh3[6, 5, 3, (1)] + 2`
+    pub breakdown: String,
+    pub total: i32,
+}
+
+impl RollResult {
+    /// The kept d20 of a d20 roll, to spot natural 20s and 1s
+    pub fn natural(&self) -> Option<i32> {
+        let d20 = self.rolls.iter().find(|roll| roll.sides == 20)?;
+        d20.dice.iter().find(|die| die.kept).map(|die| die.value)
+    }
+
+    pub fn is_critical(&self) -> bool {
+        self.natural() == Some(20)
+    }
+
+    pub fn is_fumble(&self) -> bool {
+        self.natural() == Some(1)
+    }
+}
+
+impl std::fmt::Display for RollResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} = {}", self.breakdown, self.total)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Advantage {
+    #[default]
+    Normal,
+    /// Advantage: roll two d20s and keep the higher
+    KeepHigher,
+    /// Disadvantage: roll two d20s and keep the lower
+    KeepLower,
+}
+
+impl std::fmt::Display for Advantage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Advantage::Normal => "Normal",
+            Advantage::KeepHigher => "Advantage",
+            Advantage::KeepLower => "Disadvantage",
+        })
+    }
+}
+
+/// The notation for a d20 roll with a bonus, e.g. `2d20kh1+5` with advantage
+pub fn d20(bonus: i32, advantage: Advantage) -> String {
+    let die = match advantage {
+        Advantage::Normal => "1d20",
+        Advantage::KeepHigher => "2d20kh1",
+        Advantage::KeepLower => "2d20kl1",
+    };
+    match bonus {
+        0 => die.to_string(),
+        bonus => format!("{}{:+}", die, bonus),
+    }
+}
+
+/// An attack roll and, when it could hit, its damage. Natural 20s roll the damage dice twice.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttackRoll {
+    pub to_hit: RollResult,
+    pub damage: Option<RollResult>,
+}
+
+pub fn attack<R: Rng + ?Sized>(bonus: i32, advantage: Advantage, damage: Option<&str>, rng: &mut R) -> Result<AttackRoll, String> {
+    let damage = damage.filter(|damage| !damage.trim().is_empty()).map(parse).transpose()?;
+    let to_hit = parse(&d20(bonus, advantage))?.roll(rng)?;
+    let damage = match damage {
+        Some(_) if to_hit.is_fumble() => None,
+        Some(damage) if to_hit.is_critical() => Some(damage.critical().roll(rng)?),
+        Some(damage) => Some(damage.roll(rng)?),
+        None => None,
+    };
+    Ok(AttackRoll { to_hit, damage })
+}
+
+/// Roll `expression` with the thread's random numbers
+pub fn roll(expression: &str) -> Result<RollResult, String> {
+    roll_with(expression, &mut rand::thread_rng())
+}
+
+pub fn roll_with<R: Rng + ?Sized>(expression: &str, rng: &mut R) -> Result<RollResult, String> {
+    parse(expression)?.roll(rng)
+}
+
+/// One die with `sides` sides
+pub fn die<R: Rng + ?Sized>(sides: i32, rng: &mut R) -> i32 {
+    rng.gen_range(1..=sides.max(1))
+}
+
+pub fn parse(expression: &str) -> Result<Expression, String> {
+    if expression.len() > MAX_LENGTH {
+        return Err(format!("Dice expressions can be at most {} characters", MAX_LENGTH));
+    }
+    let tokens: Vec<char> = expression.chars().filter(|c| !c.is_whitespace()).map(|c| c.to_ascii_lowercase()).collect();
+    if tokens.is_empty() {
+        return Err("Enter something to roll, like 1d20+5".to_string());
+    }
+    let mut parser = Parser { tokens, position: 0 };
+    let parsed = parser.expression()?;
+    match parser.peek() {
+        None => Ok(parsed),
+        Some(unexpected) => Err(format!("Unexpected '{}' in {}", unexpected, expression.trim())),
+    }
+}
+
+struct Parser {
+    tokens: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.tokens.get(self.position).copied()
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn number(&mut self) -> Option<u32> {
+        let start = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.position += 1;
+        }
+        // Anything too long to fit is caught by the limits below
+        (self.position > start).then(|| self.tokens[start..self.position].iter().collect::<String>().parse().unwrap_or(u32::MAX))
+    }
+
+    fn expression(&mut self) -> Result<Expression, String> {
+        let mut left = self.term()?;
+        loop {
+            let op = match self.peek() {
+                Some('+') => Op::Add,
+                Some('-') => Op::Subtract,
+                _ => return Ok(left),
+            };
+            self.position += 1;
+            left = Expression::Binary(op, Box::new(left), Box::new(self.term()?));
+        }
+    }
+
+    fn term(&mut self) -> Result<Expression, String> {
+        let mut left = self.factor()?;
+        loop {
+            let op = match self.peek() {
+                Some('*') | Some('x') => Op::Multiply,
+                Some('/') => Op::Divide,
+                _ => return Ok(left),
+            };
+            self.position += 1;
+            left = Expression::Binary(op, Box::new(left), Box::new(self.factor()?));
+        }
+    }
+
+    fn factor(&mut self) -> Result<Expression, String> {
+        if self.eat('-') {
+            return Ok(Expression::Negate(Box::new(self.factor()?)));
+        }
+        if self.eat('+') {
+            return self.factor();
+        }
+        if self.eat('(') {
+            let inner = self.expression()?;
+            if !self.eat(')') {
+                return Err("Missing ')'".to_string());
+            }
+            return Ok(inner);
+        }
+
+        let count = self.number();
+        if !self.eat('d') {
+            return match count {
+                Some(number) if number <= i32::MAX as u32 => Ok(Expression::Number(number as i32)),
+                Some(_) => Err("That number is too big".to_string()),
+                None => Err(match self.peek() {
+                    Some(unexpected) => format!("Unexpected '{}'", unexpected),
+                    None => "The expression ends too soon".to_string(),
+                }),
+            };
+        }
+        self.dice(count.unwrap_or(1))
+    }
+
+    fn dice(&mut self, count: u32) -> Result<Expression, String> {
+        let sides = if self.eat('%') { 100 } else { self.number().ok_or("Dice need a number of sides, like d6")? };
+        if count == 0 || count > MAX_DICE {
+            return Err(format!("Roll between 1 and {} dice at a time", MAX_DICE));
+        }
+        if sides == 0 || sides > MAX_SIDES {
+            return Err(format!("Dice can have between 1 and {} sides", MAX_SIDES));
+        }
+
+        let mut term = DiceTerm { count, sides, keep: None, explode: None, reroll: None };
+        loop {
+            match self.peek() {
+                Some('k') | Some('d') => {
+                    let dropping = self.peek() == Some('d');
+                    self.position += 1;
+                    let highest = match self.peek() {
+                        Some('h') => {
+                            self.position += 1;
+                            true
+                        }
+                        Some('l') => {
+                            self.position += 1;
+                            false
+                        }
+                        _ if !dropping => true,
+                        _ => return Err("Use dh or dl to drop dice".to_string()),
+                    };
+                    let amount = self.number().unwrap_or(1);
+                    if term.keep.is_some() {
+                        return Err("Keep or drop dice only once per term".to_string());
+                    }
+                    if amount == 0 || amount > count {
+                        return Err(format!("Can't keep or drop {} of {} dice", amount, count));
+                    }
+                    if dropping && amount == count {
+                        return Err("Can't drop every die".to_string());
+                    }
+                    term.keep = Some(match (dropping, highest) {
+                        (false, true) => Keep::Highest(amount),
+                        (false, false) => Keep::Lowest(amount),
+                        (true, true) => Keep::Lowest(count - amount),
+                        (true, false) => Keep::Highest(count - amount),
+                    });
+                }
+                Some('!') => {
+                    self.position += 1;
+                    let threshold = if self.eat('>') { self.number().ok_or("Explode on which value? Try !>5")? } else { sides };
+                    if threshold < 2 || threshold > sides {
+                        return Err(format!("A d{} can only explode on 2 to {}", sides, sides));
+                    }
+                    term.explode = Some(threshold);
+                }
+                Some('r') => {
+                    self.position += 1;
+                    self.eat('<');
+                    let threshold = self.number().ok_or("Reroll which value? Try r1")?;
+                    if threshold == 0 || threshold >= sides {
+                        return Err(format!("A d{} can only reroll values 1 to {}", sides, sides.saturating_sub(1)));
+                    }
+                    term.reroll = Some(threshold);
+                }
+                _ => return Ok(Expression::Dice(term)),
+            }
+        }
+    }
+}
+
+impl DiceTerm {
+    fn roll<R: Rng + ?Sized>(&self, rng: &mut R) -> DiceRoll {
+        let sides = self.sides as i32;
+        let mut dice: Vec<Die> = vec![];
+        let mut explosions = 0;
+        for _ in 0..self.count {
+            let mut exploded = false;
+            loop {
+                let first = die(sides, rng);
+                let (value, rerolled_from) = match self.reroll {
+                    Some(threshold) if first <= threshold as i32 => (die(sides, rng), Some(first)),
+                    _ => (first, None),
+                };
+                dice.push(Die { value, kept: true, exploded, rerolled_from });
+                let explodes = self.explode.is_some_and(|threshold| value >= threshold as i32);
+                if !explodes || explosions >= MAX_EXPLOSIONS {
+                    break;
+                }
+                explosions += 1;
+                exploded = true;
+            }
+        }
+
+        if let Some(keep) = self.keep {
+            let mut ranked: Vec<usize> = (0..dice.len()).collect();
+            ranked.sort_by_key(|index| dice[*index].value);
+            let dropped: Vec<usize> = match keep {
+                // Exploded dice join the pool, so keep counts apply to everything rolled
+                Keep::Highest(keep) => ranked.iter().take(dice.len().saturating_sub(keep as usize)).copied().collect(),
+                Keep::Lowest(keep) => ranked.iter().skip(keep as usize).copied().collect(),
+            };
+            for index in dropped {
+                dice[index].kept = false;
+            }
+        }
+
+        let total = dice.iter().filter(|die| die.kept).map(|die| die.value).sum();
+        DiceRoll { notation: self.to_string(), sides: self.sides, dice, total }
+    }
+}
+
+impl std::fmt::Display for DiceTerm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}d{}", self.count, self.sides)?;
+        if let Some(threshold) = self.reroll {
+            write!(f, "r{}", threshold)?;
+        }
+        match self.explode {
+            Some(threshold) if threshold == self.sides => write!(f, "!")?,
+            Some(threshold) => write!(f, "!>{}", threshold)?,
+            None => {}
+        }
+        match self.keep {
+            Some(Keep::Highest(keep)) => write!(f, "kh{}", keep),
+            Some(Keep::Lowest(keep)) => write!(f, "kl{}", keep),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Expression {
+    pub fn roll<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<RollResult, String> {
+        let mut rolls = vec![];
+        let (total, breakdown) = self.evaluate(rng, &mut rolls)?;
+        Ok(RollResult { expression: self.to_string(), rolls, breakdown, total })
+    }
+
+    fn evaluate<R: Rng + ?Sized>(&self, rng: &mut R, rolls: &mut Vec<DiceRoll>) -> Result<(i32, String), String> {
+        Ok(match self {
+            Expression::Number(number) => (*number, number.to_string()),
+            Expression::Dice(term) => {
+                let roll = term.roll(rng);
+                let shown = format!("{}{}", roll.notation, roll.dice_label());
+                let total = roll.total;
+                rolls.push(roll);
+                (total, shown)
+            }
+            Expression::Negate(inner) => {
+                let (value, shown) = inner.evaluate(rng, rolls)?;
+                (value.checked_neg().ok_or("That total is too big")?, format!("-{}", negated(inner, shown)))
+            }
+            Expression::Binary(op, left, right) => {
+                let (a, left_shown) = left.evaluate(rng, rolls)?;
+                let (b, right_shown) = right.evaluate(rng, rolls)?;
+                let value = match op {
+                    Op::Add => a.checked_add(b),
+                    Op::Subtract => a.checked_sub(b),
+                    Op::Multiply => a.checked_mul(b),
+                    Op::Divide if b == 0 => return Err("Can't divide by zero".to_string()),
+                    // 5e rounds down
+                    Op::Divide => a.checked_div_euclid(b),
+                }
+                .ok_or("That total is too big")?;
+                let left_shown = self.wrap(left, left_shown, false);
+                let right_shown = self.wrap(right, right_shown, true);
+                (value, format!("{} {} {}", left_shown, op.symbol(), right_shown))
+            }
+        })
+    }
+
+    // Parenthesize a child that binds more loosely than this operator
+    fn wrap(&self, child: &Expression, shown: String, right: bool) -> String {
+        let (Expression::Binary(op, ..), Expression::Binary(child_op, ..)) = (self, child) else {
+            return shown;
+        };
+        let looser = child_op.precedence() < op.precedence();
+        let same_but_right = right && child_op.precedence() == op.precedence() && matches!(op, Op::Subtract | Op::Divide);
+        if looser || same_but_right {
+            format!("({})", shown)
+        } else {
+            shown
+        }
+    }
+
+    /// The same expression with every dice term rolling twice as many dice, for critical hits
+    pub fn critical(&self) -> Expression {
+        match self {
+            Expression::Number(number) => Expression::Number(*number),
+            Expression::Dice(term) => {
+                // Kept dice double too, but never past the dice there are after the cap
+                let count = (term.count * 2).min(MAX_DICE);
+                let doubled = |keep: u32| (keep * 2).min(count);
+                Expression::Dice(DiceTerm {
+                    count,
+                    keep: term.keep.map(|keep| match keep {
+                        Keep::Highest(keep) => Keep::Highest(doubled(keep)),
+                        Keep::Lowest(keep) => Keep::Lowest(doubled(keep)),
+                    }),
+                    ..term.clone()
+                })
+            }
+            Expression::Negate(inner) => Expression::Negate(Box::new(inner.critical())),
+            Expression::Binary(op, left, right) => Expression::Binary(*op, Box::new(left.critical()), Box::new(right.critical())),
+        }
+    }
+}
+
+fn negated(inner: &Expression, shown: String) -> String {
+    match inner {
+        Expression::Binary(..) => format!("({})", shown),
+        _ => shown,
+    }
+}
+
+impl std::fmt::Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expression::Number(number) => write!(f, "{}", number),
+            Expression::Dice(term) => write!(f, "{}", term),
+            Expression::Negate(inner) => write!(f, "-{}", negated(inner, inner.to_string())),
+            Expression::Binary(op, left, right) => {
+                let left_shown = self.wrap(left, left.to_string(), false);
+                let right_shown = self.wrap(right, right.to_string(), true);
+                write!(f, "{}{}{}", left_shown, op.symbol(), right_shown)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const SEEDS: std::ops::Range<u64> = 0..500;
+
+    /// A random number generator that gives the same rolls for the same seed
+    fn seeded(seed: u64) -> StdRng {
+        StdRng::seed_from_u64(seed)
+    }
+
+    fn roll_seeded(expression: &str, seed: u64) -> RollResult {
+        roll_with(expression, &mut seeded(seed)).unwrap()
+    }
+
+    fn values(roll: &DiceRoll, kept: bool) -> Vec<i32> {
+        roll.dice.iter().filter(|die| die.kept == kept).map(|die| die.value).collect()
+    }
+
+    #[test]
+    fn same_seed_same_dice() {
+        for seed in 0..20 {
+            assert_eq!(roll_seeded("4d6kh3 + 1d20! + 2d8r1", seed), roll_seeded("4d6kh3 + 1d20! + 2d8r1", seed));
+        }
+    }
+
+    #[test]
+    fn keep_highest() {
+        for seed in SEEDS {
+            let result = roll_seeded("4d6kh3", seed);
+            let roll = &result.rolls[0];
+            let (kept, dropped) = (values(roll, true), values(roll, false));
+            assert_eq!((kept.len(), dropped.len()), (3, 1));
+            assert!(kept.iter().all(|value| *value >= dropped[0]), "{}", result);
+            assert_eq!(result.total, kept.iter().sum::<i32>());
+            assert!((3..=18).contains(&result.total));
+        }
+    }
+
+    #[test]
+    fn keep_lowest() {
+        for seed in SEEDS {
+            let result = roll_seeded("2d20kl1", seed);
+            let dice: Vec<i32> = result.rolls[0].dice.iter().map(|die| die.value).collect();
+            assert_eq!(result.natural(), dice.iter().min().copied());
+            assert_eq!(Some(result.total), result.natural());
+        }
+        assert_eq!(parse("4d6dl1"), parse("4d6kh3"));
+        assert_eq!(parse("4d6dh1"), parse("4d6kl3"));
+        assert_eq!(parse("4d6k3"), parse("4d6kh3"));
+    }
+
+    #[test]
+    fn dice_plus_a_modifier() {
+        for seed in SEEDS {
+            let result = roll_seeded("1d8+3", seed);
+            let die = result.rolls[0].dice[0].value;
+            assert_eq!(result.total, die + 3);
+            assert_eq!(result.breakdown, format!("1d8[{}] + 3", die));
+            assert_eq!(result.to_string(), format!("1d8[{}] + 3 = {}", die, die + 3));
+        }
+    }
+
+    #[test]
+    fn exploding_dice() {
+        let mut explosions = 0;
+        for seed in SEEDS {
+            let result = roll_seeded("1d6!", seed);
+            let dice = &result.rolls[0].dice;
+            // Every die but the last showed a 6 and set off the next
+            for (index, die) in dice.iter().enumerate() {
+                assert_eq!(die.exploded, index > 0);
+                assert_eq!(die.value == 6, index + 1 < dice.len(), "{}", result);
+            }
+            assert_eq!(result.total, dice.iter().map(|die| die.value).sum::<i32>());
+            explosions += dice.len() - 1;
+        }
+        assert!(explosions > 0);
+
+        for seed in SEEDS {
+            let dice = &roll_seeded("2d10!>9", seed).rolls[0].dice;
+            assert!(dice.iter().filter(|die| !die.exploded).count() == 2);
+            assert!(dice.windows(2).all(|pair| !pair[1].exploded || pair[0].value >= 9));
+        }
+    }
+
+    #[test]
+    fn rerolling_ones() {
+        let mut rerolls = 0;
+        for seed in SEEDS {
+            let result = roll_seeded("2d6r1", seed);
+            for die in &result.rolls[0].dice {
+                if let Some(first) = die.rerolled_from {
+                    assert_eq!(first, 1);
+                    rerolls += 1;
+                }
+            }
+        }
+        assert!(rerolls > 0);
+        assert_eq!(parse("1d6r<2"), parse("1d6r2"));
+    }
+
+    #[test]
+    fn arithmetic_and_parentheses() {
+        let table = [
+            ("2+3*4", 14, "2+3*4"),
+            ("(2+3)*4", 20, "(2+3)*4"),
+            ("10-(2-1)", 9, "10-(2-1)"),
+            ("10-2-1", 7, "10-2-1"),
+            ("7/2", 3, "7/2"),
+            ("-7/2", -4, "-7/2"),
+            ("-(1+2)", -3, "-(1+2)"),
+            ("3x2", 6, "3*2"),
+            (" +4 ", 4, "4"),
+        ];
+        for (expression, total, shown) in table {
+            let result = roll_with(expression, &mut seeded(0)).unwrap();
+            assert_eq!(result.total, total, "{}", expression);
+            assert_eq!(result.expression, shown);
+        }
+
+        for seed in SEEDS {
+            let result = roll_seeded("(1d4 + 1) * 2", seed);
+            let die = result.rolls[0].dice[0].value;
+            assert_eq!(result.total, (die + 1) * 2);
+            assert_eq!(result.breakdown, format!("(1d4[{}] + 1) * 2", die));
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        let table = [
+            ("", "Enter something to roll, like 1d20+5"),
+            ("1d", "Dice need a number of sides, like d6"),
+            ("0d6", "Roll between 1 and 100 dice at a time"),
+            ("101d6", "Roll between 1 and 100 dice at a time"),
+            ("1d0", "Dice can have between 1 and 1000 sides"),
+            ("(1d6+2", "Missing ')'"),
+            ("1d6 +", "The expression ends too soon"),
+            ("1d6 + )", "Unexpected ')'"),
+            ("1d6)", "Unexpected ')' in 1d6)"),
+            ("99999999999", "That number is too big"),
+            ("4d6kh5", "Can't keep or drop 5 of 4 dice"),
+            ("2d6dh2", "Can't drop every die"),
+            ("2d6kh1kl1", "Keep or drop dice only once per term"),
+            ("1d6!>1", "A d6 can only explode on 2 to 6"),
+            ("1d6!>", "Explode on which value? Try !>5"),
+            ("1d6r6", "A d6 can only reroll values 1 to 5"),
+            ("1d6r", "Reroll which value? Try r1"),
+        ];
+        for (expression, error) in table {
+            assert_eq!(parse(expression), Err(error.to_string()), "{:?}", expression);
+        }
+        assert!(parse(&"1+".repeat(150)).is_err());
+    }
+
+    #[test]
+    fn totals_that_cant_be_computed() {
+        let table = [
+            ("1/0", "Can't divide by zero"),
+            ("2147483647+1", "That total is too big"),
+            ("-(0-2147483647-1)", "That total is too big"),
+            ("(-2147483647-1)/-1", "That total is too big"),
+        ];
+        for (expression, error) in table {
+            assert_eq!(roll_with(expression, &mut seeded(0)), Err(error.to_string()), "{}", expression);
+        }
+    }
+
+    #[test]
+    fn critical_hits_double_the_dice() {
+        let table = [
+            ("2d6+3", "4d6+3"),
+            ("1d8+1d6", "2d8+2d6"),
+            ("-1d4", "-2d4"),
+            ("4d6kh3", "8d6kh6"),
+            ("60d6", "100d6"),
+            // The count hits the cap, so the kept dice stop at it too
+            ("60d6kh55", "100d6kh100"),
+            ("60d6kl30", "100d6kl60"),
+        ];
+        for (expression, critical) in table {
+            let doubled = parse(expression).unwrap().critical();
+            assert_eq!(doubled.to_string(), critical);
+            assert_eq!(parse(&doubled.to_string()), Ok(doubled));
+        }
+    }
+
+    #[test]
+    fn attacks_roll_damage_unless_they_fumble() {
+        for seed in SEEDS {
+            let attack = attack(5, Advantage::Normal, Some("1d8+3"), &mut seeded(seed)).unwrap();
+            match (attack.to_hit.natural(), attack.damage) {
+                (Some(1), damage) => assert_eq!(damage, None),
+                (Some(20), Some(damage)) => assert_eq!(damage.rolls[0].dice.len(), 2),
+                (_, Some(damage)) => assert_eq!(damage.rolls[0].dice.len(), 1),
+                (natural, None) => panic!("no damage on a {:?}", natural),
+            }
+        }
+        assert_eq!(d20(0, Advantage::Normal), "1d20");
+        assert_eq!(d20(5, Advantage::KeepHigher), "2d20kh1+5");
+        assert_eq!(d20(-1, Advantage::KeepLower), "2d20kl1-1");
+    }
+}
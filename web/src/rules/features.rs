@@ -0,0 +1,204 @@
+//! Features a character has from their class, race, feats and items, and the limited-use ones
+//! among them: how many uses they get ([`MaxUses`]), when those come back ([`Recharge`]) and how
+//! many are spent. Common class resources like Rage and Ki come set up through [`preset`].
+
+use serde::{Deserialize, Serialize};
+use crate::api::character::CharacterDetail;
+use super::{ability_modifier, proficiency_bonus, Ability, Class};
+
+/// How many uses a limited-use feature has
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum MaxUses {
+    Fixed { uses: i32 },
+    /// `per_level` uses for every level in `class`, or in total when there's no class, e.g. Ki points
+    PerLevel { class: Option<Class>, per_level: i32 },
+    /// Uses that step up at certain levels, `(from level, uses)` in order, e.g. Rage
+    LevelTable { class: Option<Class>, steps: Vec<(i32, i32)> },
+    /// The modifier of an ability, but never fewer than `minimum`, e.g. Bardic Inspiration
+    AbilityModifier { ability: Ability, minimum: i32 },
+    /// One plus the modifier of an ability, e.g. Divine Sense
+    OnePlusAbilityModifier { ability: Ability },
+    ProficiencyBonus,
+}
+
+impl MaxUses {
+    pub fn evaluate(&self, character: &CharacterDetail) -> i32 {
+        let level_in = |class: Option<Class>| match class {
+            Some(class) => character.classes.iter().find(|c| c.class == class).map_or(0, |c| c.level),
+            None => character.level(),
+        };
+        let uses = match self {
+            MaxUses::Fixed { uses } => *uses,
+            MaxUses::PerLevel { class, per_level } => level_in(*class) * per_level,
+            MaxUses::LevelTable { class, steps } => {
+                let level = level_in(*class);
+                steps.iter().take_while(|(from, _)| *from <= level).last().map_or(0, |(_, uses)| *uses)
+            }
+            MaxUses::AbilityModifier { ability, minimum } => ability_modifier(ability.score(character)).max(*minimum),
+            MaxUses::OnePlusAbilityModifier { ability } => 1 + ability_modifier(ability.score(character)),
+            MaxUses::ProficiencyBonus => proficiency_bonus(character.level()),
+        };
+        uses.max(0)
+    }
+}
+
+impl std::fmt::Display for MaxUses {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let levels = |class: &Option<Class>| class.map_or("character level".to_string(), |class| format!("{} level", class));
+        match self {
+            MaxUses::Fixed { uses } => write!(f, "{}", uses),
+            MaxUses::PerLevel { class, per_level: 1 } => write!(f, "{}", levels(class)),
+            MaxUses::PerLevel { class, per_level } => write!(f, "{} × {}", per_level, levels(class)),
+            MaxUses::LevelTable { class, .. } => write!(f, "by {}", levels(class)),
+            MaxUses::AbilityModifier { ability, minimum } => write!(f, "{} modifier (min {})", ability.abbreviation(), minimum),
+            MaxUses::OnePlusAbilityModifier { ability } => write!(f, "1 + {} modifier", ability.abbreviation()),
+            MaxUses::ProficiencyBonus => write!(f, "proficiency bonus"),
+        }
+    }
+}
+
+/// When spent uses come back
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum Recharge {
+    ShortRest,
+    LongRest,
+    /// Magic items that regain their charges at dawn
+    Dawn,
+    /// All uses return on a d6 roll of `min` or higher
+    D6 { min: i32 },
+}
+
+impl std::fmt::Display for Recharge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Recharge::ShortRest => f.write_str("Short rest"),
+            Recharge::LongRest => f.write_str("Long rest"),
+            Recharge::Dawn => f.write_str("Dawn"),
+            Recharge::D6 { min: 6 } => f.write_str("Recharge 6"),
+            Recharge::D6 { min } => write!(f, "Recharge {}-6", min),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LimitedUse {
+    pub max: MaxUses,
+    pub recharge: Recharge,
+    /// Uses spent since the last recharge
+    #[serde(default)]
+    pub used: i32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Feature {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub uses: Option<LimitedUse>,
+}
+
+impl Feature {
+    /// A feature by name, tracking uses when it's a resource [`preset`] knows
+    pub fn named(name: &str) -> Self {
+        Self { name: name.to_string(), description: String::new(), uses: preset(name).map(|(_, uses)| uses) }
+    }
+
+    /// Uses left and the maximum, for limited-use features
+    pub fn remaining(&self, character: &CharacterDetail) -> Option<(i32, i32)> {
+        self.uses.as_ref().map(|uses| {
+            let max = uses.max.evaluate(character);
+            ((max - uses.used).max(0), max)
+        })
+    }
+}
+
+/// The usual limited-use class resources, by the name the level-up table gives them. Returns the
+/// resource's base name too, since later levels add "Channel Divinity (2/rest)" after
+/// "Channel Divinity (1/rest)" and only one of them should track uses.
+pub fn preset(name: &str) -> Option<(&'static str, LimitedUse)> {
+    let uses = |max: MaxUses, recharge: Recharge| LimitedUse { max, recharge, used: 0 };
+    let steps = |class: Class, steps: &[(i32, i32)]| MaxUses::LevelTable { class: Some(class), steps: steps.to_vec() };
+    let base = [
+        "Rage",
+        "Ki",
+        "Bardic Inspiration",
+        "Channel Divinity",
+        "Wild Shape",
+        "Second Wind",
+        "Action Surge",
+        "Indomitable",
+        "Divine Sense",
+        "Lay on Hands",
+        "Font of Magic",
+        "Arcane Recovery",
+    ]
+    .into_iter()
+    .find(|base| name == *base || name.strip_prefix(base).is_some_and(|rest| rest.starts_with(" (")))?;
+
+    let limited = match base {
+        "Rage" => uses(steps(Class::Barbarian, &[(1, 2), (3, 3), (6, 4), (12, 5), (17, 6)]), Recharge::LongRest),
+        "Ki" => uses(MaxUses::PerLevel { class: Some(Class::Monk), per_level: 1 }, Recharge::ShortRest),
+        "Bardic Inspiration" => uses(MaxUses::AbilityModifier { ability: Ability::Charisma, minimum: 1 }, Recharge::LongRest),
+        "Channel Divinity" => uses(steps(Class::Cleric, &[(2, 1), (6, 2), (18, 3)]), Recharge::ShortRest),
+        "Wild Shape" => uses(MaxUses::Fixed { uses: 2 }, Recharge::ShortRest),
+        "Second Wind" => uses(MaxUses::Fixed { uses: 1 }, Recharge::ShortRest),
+        "Action Surge" => uses(steps(Class::Fighter, &[(2, 1), (17, 2)]), Recharge::ShortRest),
+        "Indomitable" => uses(steps(Class::Fighter, &[(9, 1), (13, 2), (17, 3)]), Recharge::LongRest),
+        "Divine Sense" => uses(MaxUses::OnePlusAbilityModifier { ability: Ability::Charisma }, Recharge::LongRest),
+        "Lay on Hands" => uses(MaxUses::PerLevel { class: Some(Class::Paladin), per_level: 5 }, Recharge::LongRest),
+        "Font of Magic" => uses(MaxUses::PerLevel { class: Some(Class::Sorcerer), per_level: 1 }, Recharge::LongRest),
+        "Arcane Recovery" => uses(MaxUses::Fixed { uses: 1 }, Recharge::LongRest),
+        _ => return None,
+    };
+    Some((base, limited))
+}
+
+/// Add `names` to `features`, skipping ones already there and not tracking a resource twice
+pub fn grant(features: &mut Vec<Feature>, names: &[String]) {
+    for name in names {
+        if features.iter().any(|feature| feature.name == *name) {
+            continue;
+        }
+        let mut feature = Feature::named(name);
+        let base = preset(name).map(|(base, _)| base);
+        let already_tracked = features.iter().any(|f| f.uses.is_some() && preset(&f.name).map(|(b, _)| b) == base);
+        if base.is_some() && already_tracked {
+            feature.uses = None;
+        }
+        features.push(feature);
+
+        // Font of Inspiration makes Bardic Inspiration come back on a short rest
+        if name == "Font of Inspiration" {
+            let tracked = features.iter_mut().filter(|f| f.name.starts_with("Bardic Inspiration")).filter_map(|f| f.uses.as_mut());
+            for uses in tracked {
+                uses.recharge = Recharge::ShortRest;
+            }
+        }
+    }
+}
+
+pub fn validate(features: &[Feature]) -> Vec<String> {
+    let mut errors = vec![];
+    for (index, feature) in features.iter().enumerate() {
+        if feature.name.trim().is_empty() {
+            errors.push("Every feature needs a name".to_string());
+        }
+        if features[..index].iter().any(|other| other.name == feature.name) {
+            errors.push(format!("{} is listed twice", feature.name));
+        }
+        if let Some(uses) = &feature.uses {
+            if uses.used < 0 {
+                errors.push(format!("{} can't have negative uses spent", feature.name));
+            }
+            if let Recharge::D6 { min } = uses.recharge {
+                if !(2..=6).contains(&min) {
+                    errors.push(format!("{} must recharge on a d6 roll between 2 and 6", feature.name));
+                }
+            }
+        }
+    }
+    errors
+}
@@ -0,0 +1,492 @@
+//! A character's belongings: items (some of them containers for others), the coin purse, and
+//! the carrying rules that follow from Strength: capacity, encumbrance and attunement.
+
+use serde::{Deserialize, Serialize};
+
+/// Magic items a character can be attuned to at once
+pub const ATTUNEMENT_LIMIT: usize = 3;
+
+/// Coins that weigh a pound
+pub const COINS_PER_POUND: i64 = 50;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Coin {
+    Copper,
+    Silver,
+    Electrum,
+    Gold,
+    Platinum,
+}
+
+impl Coin {
+    pub const ALL: [Coin; 5] = [Coin::Copper, Coin::Silver, Coin::Electrum, Coin::Gold, Coin::Platinum];
+
+    pub fn abbreviation(self) -> &'static str {
+        match self {
+            Coin::Copper => "cp",
+            Coin::Silver => "sp",
+            Coin::Electrum => "ep",
+            Coin::Gold => "gp",
+            Coin::Platinum => "pp",
+        }
+    }
+
+    pub fn from_abbreviation(abbreviation: &str) -> Option<Coin> {
+        Coin::ALL.into_iter().find(|coin| coin.abbreviation().eq_ignore_ascii_case(abbreviation.trim()))
+    }
+
+    /// What one coin is worth in copper
+    pub fn value_in_copper(self) -> i64 {
+        match self {
+            Coin::Copper => 1,
+            Coin::Silver => 10,
+            Coin::Electrum => 50,
+            Coin::Gold => 100,
+            Coin::Platinum => 1000,
+        }
+    }
+}
+
+/// The coin purse
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Coins {
+    #[serde(default)]
+    pub cp: i64,
+    #[serde(default)]
+    pub sp: i64,
+    #[serde(default)]
+    pub ep: i64,
+    #[serde(default)]
+    pub gp: i64,
+    #[serde(default)]
+    pub pp: i64,
+}
+
+impl Coins {
+    pub fn get(&self, coin: Coin) -> i64 {
+        match coin {
+            Coin::Copper => self.cp,
+            Coin::Silver => self.sp,
+            Coin::Electrum => self.ep,
+            Coin::Gold => self.gp,
+            Coin::Platinum => self.pp,
+        }
+    }
+
+    pub fn get_mut(&mut self, coin: Coin) -> &mut i64 {
+        match coin {
+            Coin::Copper => &mut self.cp,
+            Coin::Silver => &mut self.sp,
+            Coin::Electrum => &mut self.ep,
+            Coin::Gold => &mut self.gp,
+            Coin::Platinum => &mut self.pp,
+        }
+    }
+
+    /// Everything in the purse, in copper
+    pub fn total_in_copper(&self) -> i64 {
+        Coin::ALL.iter().map(|coin| self.get(*coin) * coin.value_in_copper()).sum()
+    }
+
+    pub fn count(&self) -> i64 {
+        Coin::ALL.iter().map(|coin| self.get(*coin)).sum()
+    }
+
+    pub fn weight(&self) -> f64 {
+        self.count() as f64 / COINS_PER_POUND as f64
+    }
+
+    /// Exchange `amount` coins of `from` for as many `to` coins as they are worth.
+    /// Anything too small to make up another `to` coin stays as `from` coins.
+    pub fn convert(&mut self, from: Coin, to: Coin, amount: i64) -> Result<(), String> {
+        if amount <= 0 {
+            return Err("Convert at least one coin".to_string());
+        }
+        if self.get(from) < amount {
+            return Err(format!("Only {} {} in the purse", self.get(from), from.abbreviation()));
+        }
+        let value = amount * from.value_in_copper();
+        let converted = value / to.value_in_copper();
+        if converted == 0 {
+            return Err(format!("{} {} isn't worth one {}", amount, from.abbreviation(), to.abbreviation()));
+        }
+        let leftover = (value - converted * to.value_in_copper()) / from.value_in_copper();
+        *self.get_mut(from) -= amount - leftover;
+        *self.get_mut(to) += converted;
+        Ok(())
+    }
+
+    /// The fewest coins (ignoring electrum) worth `copper`
+    pub fn from_copper(mut copper: i64) -> Coins {
+        let mut coins = Coins::default();
+        for coin in [Coin::Platinum, Coin::Gold, Coin::Silver, Coin::Copper] {
+            *coins.get_mut(coin) = copper / coin.value_in_copper();
+            copper %= coin.value_in_copper();
+        }
+        coins
+    }
+}
+
+/// e.g. 1234 -> "1 pp 2 gp 3 sp 4 cp"
+pub fn format_copper(copper: i64) -> String {
+    let coins = Coins::from_copper(copper);
+    let parts: Vec<String> = [Coin::Platinum, Coin::Gold, Coin::Silver, Coin::Copper]
+        .into_iter()
+        .filter(|coin| coins.get(*coin) > 0)
+        .map(|coin| format!("{} {}", coins.get(coin), coin.abbreviation()))
+        .collect();
+    if parts.is_empty() {
+        "0 cp".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Item {
+    /// Unique within the inventory; containers are referred to by it
+    pub id: u32,
+    pub name: String,
+    #[serde(default = "one")]
+    pub quantity: i32,
+    /// Pounds, for one of them
+    #[serde(default)]
+    pub weight: f64,
+    /// Copper pieces, for one of them
+    #[serde(default)]
+    pub value: i64,
+    #[serde(default)]
+    pub equipped: bool,
+    #[serde(default)]
+    pub requires_attunement: bool,
+    #[serde(default)]
+    pub attuned: bool,
+    /// Other items can be put inside this one
+    #[serde(default)]
+    pub is_container: bool,
+    /// Like a bag of holding: what's inside doesn't add to the carried weight
+    #[serde(default)]
+    pub weightless_contents: bool,
+    /// The container this item is in, if any
+    #[serde(default)]
+    pub container: Option<u32>,
+    #[serde(default)]
+    pub notes: String,
+}
+
+fn one() -> i32 {
+    1
+}
+
+impl Item {
+    pub fn new(id: u32, name: impl Into<String>) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            quantity: 1,
+            weight: 0.0,
+            value: 0,
+            equipped: false,
+            requires_attunement: false,
+            attuned: false,
+            is_container: false,
+            weightless_contents: false,
+            container: None,
+            notes: String::new(),
+        }
+    }
+
+    pub fn total_weight(&self) -> f64 {
+        self.weight * self.quantity as f64
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Encumbrance {
+    Unencumbered,
+    /// Over 5 × STR: speed drops by 10 ft
+    Encumbered,
+    /// Over 10 × STR: speed drops by 20 ft and STR, DEX and CON checks, attacks and saves have disadvantage
+    HeavilyEncumbered,
+    /// Over 15 × STR: can't carry it at all, only push, drag or lift up to 30 × STR
+    OverCapacity,
+}
+
+impl Encumbrance {
+    pub fn label(self) -> &'static str {
+        match self {
+            Encumbrance::Unencumbered => "Unencumbered",
+            Encumbrance::Encumbered => "Encumbered (−10 ft speed)",
+            Encumbrance::HeavilyEncumbered => "Heavily encumbered (−20 ft speed, disadvantage)",
+            Encumbrance::OverCapacity => "Over carrying capacity",
+        }
+    }
+
+    pub fn speed_penalty(self) -> i32 {
+        match self {
+            Encumbrance::Unencumbered => 0,
+            Encumbrance::Encumbered => 10,
+            Encumbrance::HeavilyEncumbered | Encumbrance::OverCapacity => 20,
+        }
+    }
+}
+
+/// Pounds a character can carry: 15 × STR
+pub fn carrying_capacity(strength: i32) -> f64 {
+    15.0 * strength as f64
+}
+
+/// Pounds a character can push, drag or lift: 30 × STR
+pub fn push_drag_lift(strength: i32) -> f64 {
+    30.0 * strength as f64
+}
+
+/// The variant encumbrance rule from the Player's Handbook
+pub fn encumbrance(weight: f64, strength: i32) -> Encumbrance {
+    let strength = strength as f64;
+    if weight > 15.0 * strength {
+        Encumbrance::OverCapacity
+    } else if weight > 10.0 * strength {
+        Encumbrance::HeavilyEncumbered
+    } else if weight > 5.0 * strength {
+        Encumbrance::Encumbered
+    } else {
+        Encumbrance::Unencumbered
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Inventory {
+    #[serde(default)]
+    pub items: Vec<Item>,
+    #[serde(default)]
+    pub coins: Coins,
+}
+
+impl Inventory {
+    /// One of each named item, e.g. a starting equipment package or a legacy equipment list
+    pub fn from_names<S: AsRef<str>>(names: &[S]) -> Self {
+        let items = names
+            .iter()
+            .map(|name| name.as_ref().trim())
+            .filter(|name| !name.is_empty())
+            .enumerate()
+            .map(|(index, name)| Item::new(index as u32 + 1, name))
+            .collect();
+        Self { items, coins: Coins::default() }
+    }
+
+    /// An id no item uses yet
+    pub fn next_id(&self) -> u32 {
+        self.items.iter().map(|item| item.id).max().unwrap_or(0) + 1
+    }
+
+    pub fn item(&self, id: u32) -> Option<&Item> {
+        self.items.iter().find(|item| item.id == id)
+    }
+
+    /// Items directly inside `container`, or carried loose when it's `None`
+    pub fn contents(&self, container: Option<u32>) -> impl Iterator<Item = &Item> {
+        self.items.iter().filter(move |item| item.container == container)
+    }
+
+    /// Whether anything about `item`'s weight reaches the character: false when it sits,
+    /// however deep, inside a container that makes its contents weightless
+    fn weighs_on_character(&self, item: &Item) -> bool {
+        let mut container = item.container;
+        // Bounded by the item count so a broken container loop can't spin forever
+        for _ in 0..self.items.len() {
+            match container.and_then(|id| self.item(id)) {
+                Some(parent) if parent.weightless_contents => return false,
+                Some(parent) => container = parent.container,
+                None => return true,
+            }
+        }
+        true
+    }
+
+    /// Weight of the items and coins the character carries
+    pub fn carried_weight(&self) -> f64 {
+        let items: f64 = self.items.iter().filter(|item| self.weighs_on_character(item)).map(Item::total_weight).sum();
+        items + self.coins.weight()
+    }
+
+    pub fn attuned_count(&self) -> usize {
+        self.items.iter().filter(|item| item.attuned).count()
+    }
+
+    /// Item names with quantities, the summary kept in the old `equipment` column
+    pub fn equipment_names(&self) -> Vec<String> {
+        self.items
+            .iter()
+            .map(|item| if item.quantity == 1 { item.name.clone() } else { format!("{} ({})", item.name, item.quantity) })
+            .collect()
+    }
+
+    /// Value of every item, in copper
+    pub fn item_value(&self) -> i64 {
+        self.items.iter().map(|item| item.value * item.quantity as i64).sum()
+    }
+
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = vec![];
+
+        if self.attuned_count() > ATTUNEMENT_LIMIT {
+            errors.push(format!("A character can be attuned to at most {} items", ATTUNEMENT_LIMIT));
+        }
+        if Coin::ALL.iter().any(|coin| self.coins.get(*coin) < 0) {
+            errors.push("Coin counts can't be negative".to_string());
+        }
+
+        for (index, item) in self.items.iter().enumerate() {
+            if item.name.trim().is_empty() {
+                errors.push("Every item needs a name".to_string());
+            }
+            if self.items[..index].iter().any(|other| other.id == item.id) {
+                errors.push(format!("Two items share the id {}", item.id));
+            }
+            if item.quantity < 0 || item.weight < 0.0 || item.value < 0 {
+                errors.push(format!("{} can't have a negative quantity, weight or value", item.name));
+            }
+            if item.attuned && !item.requires_attunement {
+                errors.push(format!("{} doesn't require attunement", item.name));
+            }
+            if let Some(container) = item.container {
+                match self.item(container) {
+                    Some(parent) if parent.is_container => {}
+                    Some(parent) => errors.push(format!("{} is not a container", parent.name)),
+                    None => errors.push(format!("{} is in a container that no longer exists", item.name)),
+                }
+                if self.contains_itself(item) {
+                    errors.push(format!("{} can't end up inside itself", item.name));
+                }
+            }
+        }
+
+        errors
+    }
+
+    fn contains_itself(&self, item: &Item) -> bool {
+        let mut container = item.container;
+        for _ in 0..self.items.len() {
+            match container {
+                Some(id) if id == item.id => return true,
+                Some(id) => container = self.item(id).and_then(|parent| parent.container),
+                None => return false,
+            }
+        }
+        container.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn container(id: u32, name: &str, weightless_contents: bool) -> Item {
+        Item { is_container: true, weightless_contents, ..Item::new(id, name) }
+    }
+
+    fn inside(item: Item, container: u32, weight: f64) -> Item {
+        Item { container: Some(container), weight, ..item }
+    }
+
+    #[test]
+    fn converting_coins_keeps_the_change() {
+        let table = [
+            (Coins { gp: 3, ..Coins::default() }, Coin::Gold, Coin::Silver, 2, Coins { gp: 1, sp: 20, ..Coins::default() }),
+            (Coins { cp: 250, ..Coins::default() }, Coin::Copper, Coin::Gold, 250, Coins { cp: 50, gp: 2, ..Coins::default() }),
+            (Coins { sp: 15, ..Coins::default() }, Coin::Silver, Coin::Gold, 15, Coins { sp: 5, gp: 1, ..Coins::default() }),
+            (Coins { pp: 1, ..Coins::default() }, Coin::Platinum, Coin::Electrum, 1, Coins { ep: 20, ..Coins::default() }),
+        ];
+        for (purse, from, to, amount, expected) in table {
+            let mut converted = purse;
+            converted.convert(from, to, amount).unwrap();
+            assert_eq!(converted, expected, "{} {} to {}", amount, from.abbreviation(), to.abbreviation());
+            assert_eq!(converted.total_in_copper(), purse.total_in_copper());
+        }
+    }
+
+    #[test]
+    fn converting_coins_rejects_what_it_cant_do() {
+        let mut purse = Coins { sp: 7, ..Coins::default() };
+        assert_eq!(purse.convert(Coin::Silver, Coin::Gold, 7), Err("7 sp isn't worth one gp".to_string()));
+        assert_eq!(purse.convert(Coin::Silver, Coin::Copper, 8), Err("Only 7 sp in the purse".to_string()));
+        assert!(purse.convert(Coin::Silver, Coin::Copper, 0).is_err());
+        assert_eq!(purse, Coins { sp: 7, ..Coins::default() });
+    }
+
+    #[test]
+    fn copper_formats_as_the_fewest_coins() {
+        let table = [(0, "0 cp"), (7, "7 cp"), (1234, "1 pp 2 gp 3 sp 4 cp"), (100_050, "100 pp 5 sp")];
+        for (copper, formatted) in table {
+            assert_eq!(format_copper(copper), formatted);
+            assert_eq!(Coins::from_copper(copper).total_in_copper(), copper);
+        }
+        assert_eq!(Coin::from_abbreviation(" GP "), Some(Coin::Gold));
+        assert_eq!(Coin::from_abbreviation("gold"), None);
+    }
+
+    #[test]
+    fn encumbrance_thresholds() {
+        let table = [
+            (75.0, Encumbrance::Unencumbered),
+            (75.5, Encumbrance::Encumbered),
+            (150.0, Encumbrance::Encumbered),
+            (151.0, Encumbrance::HeavilyEncumbered),
+            (225.0, Encumbrance::HeavilyEncumbered),
+            (226.0, Encumbrance::OverCapacity),
+        ];
+        for (weight, expected) in table {
+            assert_eq!(encumbrance(weight, 15), expected, "{} lb", weight);
+        }
+        assert_eq!(carrying_capacity(15), 225.0);
+        assert_eq!(push_drag_lift(15), 450.0);
+    }
+
+    #[test]
+    fn weightless_containers_hide_everything_inside() {
+        let inventory = Inventory {
+            items: vec![
+                Item { weight: 5.0, ..container(1, "Backpack", false) },
+                Item { weight: 15.0, ..inside(container(2, "Bag of Holding", true), 1, 15.0) },
+                inside(container(3, "Pouch", false), 2, 1.0),
+                inside(Item { quantity: 10, ..Item::new(4, "Torch") }, 1, 1.0),
+                inside(Item::new(5, "Gem"), 3, 2.0),
+                inside(Item::new(6, "Anvil"), 2, 100.0),
+            ],
+            coins: Coins { gp: 50, ..Coins::default() },
+        };
+        // Backpack, bag of holding and ten torches, plus a pound of gold
+        assert_eq!(inventory.carried_weight(), 5.0 + 15.0 + 10.0 + 1.0);
+        assert!(inventory.validate().is_empty());
+        assert_eq!(inventory.contents(Some(2)).map(|item| item.id).collect::<Vec<_>>(), vec![3, 6]);
+    }
+
+    #[test]
+    fn validation_catches_broken_inventories() {
+        let attuned = |id| Item { requires_attunement: true, attuned: true, ..Item::new(id, "Ring") };
+        let table: [(Vec<Item>, &str); 5] = [
+            ((1..=4).map(attuned).collect(), "A character can be attuned to at most 3 items"),
+            (vec![Item { attuned: true, ..Item::new(1, "Rope") }], "Rope doesn't require attunement"),
+            (vec![Item::new(1, "Rope"), inside(Item::new(2, "Gem"), 1, 0.0)], "Rope is not a container"),
+            (vec![inside(Item::new(2, "Gem"), 9, 0.0)], "Gem is in a container that no longer exists"),
+            (vec![inside(container(1, "Sack", false), 2, 0.0), inside(container(2, "Box", false), 1, 0.0)], "Sack can't end up inside itself"),
+        ];
+        for (items, error) in table {
+            let errors = Inventory { items, coins: Coins::default() }.validate();
+            assert!(errors.contains(&error.to_string()), "{:?} should contain {:?}", errors, error);
+        }
+        let negative = Inventory { items: vec![], coins: Coins { cp: -1, ..Coins::default() } };
+        assert_eq!(negative.validate(), vec!["Coin counts can't be negative".to_string()]);
+    }
+
+    #[test]
+    fn items_from_names() {
+        let mut inventory = Inventory::from_names(&["Rope", " ", "Torch"]);
+        assert_eq!(inventory.items.iter().map(|item| item.id).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(inventory.next_id(), 3);
+        inventory.items[1].quantity = 5;
+        assert_eq!(inventory.equipment_names(), vec!["Rope".to_string(), "Torch (5)".to_string()]);
+    }
+}
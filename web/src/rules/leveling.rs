@@ -0,0 +1,543 @@
+//! Gaining a level: hit points, Ability Score Improvements or feats, the class features each
+//! level grants and how many new spells a class learns. [`level_up`] applies all of it at once.
+
+use serde::{Deserialize, Serialize};
+use crate::api::character::CharacterDetail;
+use super::{ability_modifier, multiclass, Ability, Class, ClassLevel, Subclass};
+use crate::api::spells::{spell_level_label, Spell};
+use super::spellbook::{self, SpellbookEntry};
+
+/// The highest level a character can reach, and the highest an ability score can be raised to
+pub const MAX_LEVEL: i32 = 20;
+pub const MAX_ABILITY_SCORE: i32 = 20;
+
+/// Whether a class grants an Ability Score Improvement (or a feat) at `class_level`.
+/// Fighters get two extra and Rogues one extra on top of the usual 4th, 8th, 12th, 16th and 19th.
+pub fn grants_ability_score_improvement(class: Class, class_level: i32) -> bool {
+    matches!(
+        (class, class_level),
+        (_, 4 | 8 | 12 | 16 | 19) | (Class::Fighter, 6 | 14) | (Class::Rogue, 10)
+    )
+}
+
+/// Fixed hit points for a level instead of rolling: half the die, rounded up
+pub fn average_hit_die(hit_die: i32) -> i32 {
+    hit_die / 2 + 1
+}
+
+/// The class features gained on reaching `class_level`, not counting subclass features
+/// and Ability Score Improvements
+pub fn class_features(class: Class, class_level: i32) -> &'static [&'static str] {
+    match (class, class_level) {
+        (Class::Barbarian, 1) => &["Rage", "Unarmored Defense"],
+        (Class::Barbarian, 2) => &["Reckless Attack", "Danger Sense"],
+        (Class::Barbarian, 3) => &["Primal Path"],
+        (Class::Barbarian, 5) => &["Extra Attack", "Fast Movement"],
+        (Class::Barbarian, 7) => &["Feral Instinct"],
+        (Class::Barbarian, 9) => &["Brutal Critical (1 die)"],
+        (Class::Barbarian, 11) => &["Relentless Rage"],
+        (Class::Barbarian, 13) => &["Brutal Critical (2 dice)"],
+        (Class::Barbarian, 15) => &["Persistent Rage"],
+        (Class::Barbarian, 17) => &["Brutal Critical (3 dice)"],
+        (Class::Barbarian, 18) => &["Indomitable Might"],
+        (Class::Barbarian, 20) => &["Primal Champion"],
+
+        (Class::Bard, 1) => &["Spellcasting", "Bardic Inspiration (d6)"],
+        (Class::Bard, 2) => &["Jack of All Trades", "Song of Rest (d6)"],
+        (Class::Bard, 3) => &["Bard College", "Expertise"],
+        (Class::Bard, 5) => &["Bardic Inspiration (d8)", "Font of Inspiration"],
+        (Class::Bard, 6) => &["Countercharm"],
+        (Class::Bard, 9) => &["Song of Rest (d8)"],
+        (Class::Bard, 10) => &["Bardic Inspiration (d10)", "Expertise (2)", "Magical Secrets"],
+        (Class::Bard, 13) => &["Song of Rest (d10)"],
+        (Class::Bard, 14) => &["Magical Secrets (2)"],
+        (Class::Bard, 15) => &["Bardic Inspiration (d12)"],
+        (Class::Bard, 17) => &["Song of Rest (d12)"],
+        (Class::Bard, 18) => &["Magical Secrets (3)"],
+        (Class::Bard, 20) => &["Superior Inspiration"],
+
+        (Class::Cleric, 1) => &["Spellcasting", "Divine Domain"],
+        (Class::Cleric, 2) => &["Channel Divinity (1/rest)"],
+        (Class::Cleric, 5) => &["Destroy Undead (CR 1/2)"],
+        (Class::Cleric, 6) => &["Channel Divinity (2/rest)"],
+        (Class::Cleric, 8) => &["Destroy Undead (CR 1)"],
+        (Class::Cleric, 10) => &["Divine Intervention"],
+        (Class::Cleric, 11) => &["Destroy Undead (CR 2)"],
+        (Class::Cleric, 14) => &["Destroy Undead (CR 3)"],
+        (Class::Cleric, 17) => &["Destroy Undead (CR 4)"],
+        (Class::Cleric, 18) => &["Channel Divinity (3/rest)"],
+        (Class::Cleric, 20) => &["Divine Intervention Improvement"],
+
+        (Class::Druid, 1) => &["Druidic", "Spellcasting"],
+        (Class::Druid, 2) => &["Wild Shape", "Druid Circle"],
+        (Class::Druid, 4) => &["Wild Shape Improvement (CR 1/2)"],
+        (Class::Druid, 8) => &["Wild Shape Improvement (CR 1)"],
+        (Class::Druid, 18) => &["Timeless Body", "Beast Spells"],
+        (Class::Druid, 20) => &["Archdruid"],
+
+        (Class::Fighter, 1) => &["Fighting Style", "Second Wind"],
+        (Class::Fighter, 2) => &["Action Surge (one use)"],
+        (Class::Fighter, 3) => &["Martial Archetype"],
+        (Class::Fighter, 5) => &["Extra Attack"],
+        (Class::Fighter, 9) => &["Indomitable (one use)"],
+        (Class::Fighter, 11) => &["Extra Attack (2)"],
+        (Class::Fighter, 13) => &["Indomitable (two uses)"],
+        (Class::Fighter, 17) => &["Action Surge (two uses)", "Indomitable (three uses)"],
+        (Class::Fighter, 20) => &["Extra Attack (3)"],
+
+        (Class::Monk, 1) => &["Unarmored Defense", "Martial Arts"],
+        (Class::Monk, 2) => &["Ki", "Unarmored Movement"],
+        (Class::Monk, 3) => &["Monastic Tradition", "Deflect Missiles"],
+        (Class::Monk, 4) => &["Slow Fall"],
+        (Class::Monk, 5) => &["Extra Attack", "Stunning Strike"],
+        (Class::Monk, 6) => &["Ki-Empowered Strikes"],
+        (Class::Monk, 7) => &["Evasion", "Stillness of Mind"],
+        (Class::Monk, 10) => &["Purity of Body"],
+        (Class::Monk, 13) => &["Tongue of the Sun and Moon"],
+        (Class::Monk, 14) => &["Diamond Soul"],
+        (Class::Monk, 15) => &["Timeless Body"],
+        (Class::Monk, 18) => &["Empty Body"],
+        (Class::Monk, 20) => &["Perfect Self"],
+
+        (Class::Paladin, 1) => &["Divine Sense", "Lay on Hands"],
+        (Class::Paladin, 2) => &["Fighting Style", "Spellcasting", "Divine Smite"],
+        (Class::Paladin, 3) => &["Divine Health", "Sacred Oath"],
+        (Class::Paladin, 5) => &["Extra Attack"],
+        (Class::Paladin, 6) => &["Aura of Protection"],
+        (Class::Paladin, 10) => &["Aura of Courage"],
+        (Class::Paladin, 11) => &["Improved Divine Smite"],
+        (Class::Paladin, 14) => &["Cleansing Touch"],
+        (Class::Paladin, 18) => &["Aura Improvements"],
+
+        (Class::Ranger, 1) => &["Favored Enemy", "Natural Explorer"],
+        (Class::Ranger, 2) => &["Fighting Style", "Spellcasting"],
+        (Class::Ranger, 3) => &["Ranger Archetype", "Primeval Awareness"],
+        (Class::Ranger, 5) => &["Extra Attack"],
+        (Class::Ranger, 6) => &["Favored Enemy and Natural Explorer Improvements"],
+        (Class::Ranger, 8) => &["Land's Stride"],
+        (Class::Ranger, 10) => &["Natural Explorer Improvement", "Hide in Plain Sight"],
+        (Class::Ranger, 14) => &["Favored Enemy Improvement", "Vanish"],
+        (Class::Ranger, 18) => &["Feral Senses"],
+        (Class::Ranger, 20) => &["Foe Slayer"],
+
+        (Class::Rogue, 1) => &["Expertise", "Sneak Attack", "Thieves' Cant"],
+        (Class::Rogue, 2) => &["Cunning Action"],
+        (Class::Rogue, 3) => &["Roguish Archetype"],
+        (Class::Rogue, 5) => &["Uncanny Dodge"],
+        (Class::Rogue, 6) => &["Expertise (2)"],
+        (Class::Rogue, 7) => &["Evasion"],
+        (Class::Rogue, 11) => &["Reliable Talent"],
+        (Class::Rogue, 14) => &["Blindsense"],
+        (Class::Rogue, 15) => &["Slippery Mind"],
+        (Class::Rogue, 18) => &["Elusive"],
+        (Class::Rogue, 20) => &["Stroke of Luck"],
+
+        (Class::Sorcerer, 1) => &["Spellcasting", "Sorcerous Origin"],
+        (Class::Sorcerer, 2) => &["Font of Magic"],
+        (Class::Sorcerer, 3) => &["Metamagic"],
+        (Class::Sorcerer, 10) => &["Metamagic (2)"],
+        (Class::Sorcerer, 17) => &["Metamagic (3)"],
+        (Class::Sorcerer, 20) => &["Sorcerous Restoration"],
+
+        (Class::Warlock, 1) => &["Otherworldly Patron", "Pact Magic"],
+        (Class::Warlock, 2) => &["Eldritch Invocations"],
+        (Class::Warlock, 3) => &["Pact Boon"],
+        (Class::Warlock, 11) => &["Mystic Arcanum (6th level)"],
+        (Class::Warlock, 13) => &["Mystic Arcanum (7th level)"],
+        (Class::Warlock, 15) => &["Mystic Arcanum (8th level)"],
+        (Class::Warlock, 17) => &["Mystic Arcanum (9th level)"],
+        (Class::Warlock, 20) => &["Eldritch Master"],
+
+        (Class::Wizard, 1) => &["Spellcasting", "Arcane Recovery"],
+        (Class::Wizard, 2) => &["Arcane Tradition"],
+        (Class::Wizard, 18) => &["Spell Mastery"],
+        (Class::Wizard, 20) => &["Signature Spells"],
+
+        _ => &[],
+    }
+}
+
+/// Cantrips a class knows at `class_level`
+pub fn cantrips_known(class: Class, class_level: i32) -> i32 {
+    let (base, at_4, at_10) = match class {
+        Class::Cleric | Class::Wizard => (3, 4, 5),
+        Class::Sorcerer => (4, 5, 6),
+        Class::Bard | Class::Druid | Class::Warlock => (2, 3, 4),
+        _ => return 0,
+    };
+    match class_level {
+        ..=0 => 0,
+        1..=3 => base,
+        4..=9 => at_4,
+        _ => at_10,
+    }
+}
+
+/// Leveled spells a class knows at `class_level`, for the classes that learn a fixed list.
+/// Wizards count the spells in their spellbook. Clerics, Druids and Paladins prepare from their
+/// whole list instead, so they never learn spells by levelling.
+pub fn spells_known(class: Class, class_level: i32) -> i32 {
+    const BARD: [i32; 20] = [4, 5, 6, 7, 8, 9, 10, 11, 12, 14, 15, 15, 16, 18, 19, 19, 20, 22, 22, 22];
+    const RANGER: [i32; 20] = [0, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11];
+    const SORCERER: [i32; 20] = [2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 12, 13, 13, 14, 14, 15, 15, 15, 15];
+    const WARLOCK: [i32; 20] = [2, 3, 4, 5, 6, 7, 8, 9, 10, 10, 11, 11, 12, 12, 13, 13, 14, 14, 15, 15];
+
+    if class_level < 1 {
+        return 0;
+    }
+    let index = (class_level.min(MAX_LEVEL) - 1) as usize;
+    match class {
+        Class::Bard => BARD[index],
+        Class::Ranger => RANGER[index],
+        Class::Sorcerer => SORCERER[index],
+        Class::Warlock => WARLOCK[index],
+        // Six in the starting spellbook, then two more each level
+        Class::Wizard => 6 + 2 * index as i32,
+        _ => 0,
+    }
+}
+
+/// New spells (cantrips included) a class learns on reaching `class_level`
+pub fn spells_learned(class: Class, class_level: i32) -> i32 {
+    cantrips_known(class, class_level) - cantrips_known(class, class_level - 1) + spells_known(class, class_level)
+        - spells_known(class, class_level - 1)
+}
+
+/// How the player wants the hit points for a new level
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HitPointMethod {
+    Roll,
+    #[default]
+    Average,
+}
+
+impl std::fmt::Display for HitPointMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HitPointMethod::Roll => write!(f, "Rolled"),
+            HitPointMethod::Average => write!(f, "Average"),
+        }
+    }
+}
+
+/// What a character takes at an Ability Score Improvement level
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Improvement {
+    /// +1 to each; naming the same ability twice gives it +2
+    AbilityScores([Ability; 2]),
+    Feat(String),
+}
+
+impl std::fmt::Display for Improvement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Improvement::AbilityScores([first, second]) if first == second => write!(f, "{} +2", first.abbreviation()),
+            Improvement::AbilityScores([first, second]) => {
+                write!(f, "{} +1, {} +1", first.abbreviation(), second.abbreviation())
+            }
+            Improvement::Feat(feat) => write!(f, "Feat: {}", feat),
+        }
+    }
+}
+
+/// Everything the player decides when gaining a level
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LevelUpChoice {
+    /// The class the new level goes into; a class the character doesn't have yet multiclasses
+    pub class: Class,
+    /// Required when this level reaches the class's subclass level
+    pub subclass: Option<Subclass>,
+    pub hit_points: HitPointMethod,
+    /// Required at Ability Score Improvement levels, ignored otherwise
+    pub improvement: Option<Improvement>,
+    /// Spells learned through the new level, taken from the spell catalog
+    pub new_spells: Vec<SpellbookEntry>,
+}
+
+/// A character after a level-up, and what changed
+#[derive(Clone, Debug, PartialEq)]
+pub struct LevelUp {
+    pub character: CharacterDetail,
+    /// The new level in the chosen class
+    pub class_level: i32,
+    pub hit_points_gained: i32,
+    pub features: Vec<String>,
+}
+
+/// The catalog's own copies of `new_spells` learned through `class`, so a level-up can't
+/// bring in a spell that isn't in `catalog` or claim different details for one that is.
+/// Bards take spells from any list once Magical Secrets comes in at 10th level.
+pub fn catalog_spells(class: Class, class_level: i32, new_spells: &[SpellbookEntry], catalog: &[Spell]) -> Result<Vec<SpellbookEntry>, Vec<String>> {
+    let mut errors = vec![];
+    let mut entries = vec![];
+    for chosen in new_spells {
+        match catalog.iter().find(|spell| spell.slug == chosen.spell) {
+            Some(spell) if spell.classes.contains(&class) || (class == Class::Bard && class_level >= 10) => {
+                entries.push(spell.to_entry(Some(class)));
+            }
+            Some(spell) => errors.push(format!("{} isn't on the {} spell list", spell.name, class)),
+            None => errors.push(format!("{} isn't in the spell catalog", chosen.name)),
+        }
+    }
+    if errors.is_empty() {
+        Ok(entries)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Whether the next level in `class` has to come with a subclass choice
+pub fn needs_subclass(character: &CharacterDetail, class: Class) -> bool {
+    let current = character.classes.iter().find(|class_level| class_level.class == class);
+    let next_level = current.map_or(1, |class_level| class_level.level + 1);
+    current.and_then(|class_level| class_level.subclass).is_none() && next_level >= class.subclass_level()
+}
+
+/// Apply `choice` to a copy of `character`. `roll` is the hit die result when rolling for
+/// hit points; the server makes the roll so players can't pick their own.
+pub fn level_up(character: &CharacterDetail, choice: &LevelUpChoice, roll: Option<i32>) -> Result<LevelUp, Vec<String>> {
+    let mut errors = vec![];
+    let mut leveled = character.clone();
+    let class = choice.class;
+
+    if character.level() >= MAX_LEVEL {
+        errors.push(format!("{} is already level {}", character.name, MAX_LEVEL));
+    }
+
+    // Raise the class, or start a new one when multiclassing
+    let needs_subclass = needs_subclass(character, class);
+    let class_level = match leveled.classes.iter_mut().find(|class_level| class_level.class == class) {
+        Some(class_level) => {
+            class_level.level += 1;
+            class_level
+        }
+        None => {
+            leveled.classes.push(ClassLevel::new(class, 1));
+            leveled.classes.last_mut().unwrap()
+        }
+    };
+    let new_subclass = if needs_subclass {
+        match choice.subclass {
+            Some(subclass) if subclass.class() == class => {
+                class_level.subclass = Some(subclass);
+                Some(subclass)
+            }
+            Some(subclass) => {
+                errors.push(format!("{} is not a {} subclass", subclass, class));
+                None
+            }
+            None => {
+                errors.push(format!("Choose a {} subclass", class));
+                None
+            }
+        }
+    } else {
+        None
+    };
+    let leveled_class = *class_level;
+    let new_class_level = leveled_class.level;
+
+    // Ability Score Improvement or feat
+    let mut features: Vec<String> = class_features(class, new_class_level).iter().map(|f| f.to_string()).collect();
+    if let Some(subclass) = new_subclass {
+        features.push(subclass.to_string());
+    }
+    let old_constitution = ability_modifier(character.constitution);
+    if grants_ability_score_improvement(class, new_class_level) {
+        match &choice.improvement {
+            Some(Improvement::AbilityScores(abilities)) => {
+                for ability in abilities {
+                    *ability.score_mut(&mut leveled) += 1;
+                }
+                for ability in Ability::ALL {
+                    if ability.score(&leveled) > MAX_ABILITY_SCORE && abilities.contains(&ability) {
+                        errors.push(format!("{} can't be raised above {}", ability.name(), MAX_ABILITY_SCORE));
+                    }
+                }
+            }
+            Some(Improvement::Feat(feat)) if !feat.trim().is_empty() => features.push(feat.trim().to_string()),
+            _ => errors.push("Choose an Ability Score Improvement or a feat".to_string()),
+        }
+    }
+
+    // Hit points: the die (or its average) plus CON for the new level, and a higher CON
+    // modifier raises the hit points of every earlier level too
+    let hit_die = class.hit_die();
+    let die = match (choice.hit_points, roll) {
+        (HitPointMethod::Average, _) => average_hit_die(hit_die),
+        (HitPointMethod::Roll, Some(roll)) if (1..=hit_die).contains(&roll) => roll,
+        (HitPointMethod::Roll, _) => {
+            errors.push(format!("A hit die roll must be between 1 and {}", hit_die));
+            0
+        }
+    };
+    let constitution = ability_modifier(leveled.constitution);
+    let hit_points_gained = (die + constitution).max(1) + (constitution - old_constitution) * character.level();
+    leveled.hit_points += hit_points_gained;
+    // A wounded character heals by what their maximum grew; an unhurt one stays at the maximum
+    if let Some(current) = leveled.play_state.current_hit_points.as_mut() {
+        *current += hit_points_gained;
+    }
+
+    // New spells
+    let allowed = spells_learned(class, new_class_level);
+    if choice.new_spells.len() as i32 > allowed {
+        errors.push(format!("{} {} learns {} new spells at this level", class, new_class_level, allowed));
+    }
+    let max_spell_level = spellbook::max_spell_level(&leveled_class);
+    for spell in &choice.new_spells {
+        if spell.level > max_spell_level {
+            errors.push(format!("{} {} can't learn {} spells like {}", class, new_class_level, spell_level_label(spell.level), spell.name));
+        }
+        let entry = SpellbookEntry { class: Some(class), ..spell.clone() };
+        if let Err(error) = leveled.spellbook.learn(entry) {
+            errors.push(error);
+        }
+    }
+
+    super::features::grant(&mut leveled.features, &features);
+    leveled.proficiency_bonus = super::proficiency_bonus(multiclass::total_level(&leveled.classes));
+
+    // Multiclass prerequisites and the rest of the usual checks
+    errors.extend(leveled.validate());
+
+    if errors.is_empty() {
+        Ok(LevelUp { character: leveled, class_level: new_class_level, hit_points_gained, features })
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::spells::Components;
+    use crate::rules::tests::character;
+    use crate::rules::MagicSchool;
+
+    fn choice(class: Class) -> LevelUpChoice {
+        LevelUpChoice { class, subclass: None, hit_points: HitPointMethod::Average, improvement: None, new_spells: vec![] }
+    }
+
+    fn spell(slug: &str, level: i32, classes: Vec<Class>) -> Spell {
+        Spell {
+            id: 1,
+            slug: slug.to_string(),
+            name: slug.replace('-', " "),
+            level,
+            school: MagicSchool::Evocation,
+            ritual: false,
+            concentration: level > 1,
+            classes,
+            casting_time: "1 action".to_string(),
+            range: "60 feet".to_string(),
+            components: Components::default(),
+            duration: "Instantaneous".to_string(),
+            description: String::new(),
+            higher_levels: String::new(),
+            pack_id: None,
+            source: "SRD 5.1".to_string(),
+            version: 1,
+        }
+    }
+
+    #[test]
+    fn hit_points_use_the_average_or_the_roll() {
+        let fighter = character(vec![ClassLevel::new(Class::Fighter, 1)]);
+        let table = [(HitPointMethod::Average, None, 6), (HitPointMethod::Roll, Some(1), 1), (HitPointMethod::Roll, Some(10), 10)];
+        for (method, roll, gained) in table {
+            let level_up = level_up(&fighter, &LevelUpChoice { hit_points: method, ..choice(Class::Fighter) }, roll).unwrap();
+            assert_eq!(level_up.hit_points_gained, gained, "{:?} {:?}", method, roll);
+            assert_eq!(level_up.character.hit_points, 10 + gained);
+        }
+        for roll in [None, Some(0), Some(11)] {
+            let rolled = LevelUpChoice { hit_points: HitPointMethod::Roll, ..choice(Class::Fighter) };
+            assert!(level_up(&fighter, &rolled, roll).is_err(), "{:?}", roll);
+        }
+    }
+
+    #[test]
+    fn current_hit_points_rise_with_the_maximum() {
+        let mut fighter = character(vec![ClassLevel::new(Class::Fighter, 1)]);
+        let unhurt = level_up(&fighter, &choice(Class::Fighter), None).unwrap().character;
+        assert_eq!(unhurt.play_state.current_hit_points, None);
+
+        fighter.play_state.current_hit_points = Some(4);
+        let wounded = level_up(&fighter, &choice(Class::Fighter), None).unwrap().character;
+        assert_eq!(wounded.play_state.current_hit_points, Some(10));
+        assert_eq!(wounded.hit_points, 16);
+    }
+
+    #[test]
+    fn a_higher_constitution_raises_earlier_levels() {
+        let fighter = CharacterDetail {
+            constitution: 13,
+            ..character(vec![ClassLevel { subclass: Some(Subclass::Champion), ..ClassLevel::new(Class::Fighter, 3) }])
+        };
+        let improvement = Improvement::AbilityScores([Ability::Constitution, Ability::Strength]);
+        let level_up = level_up(&fighter, &LevelUpChoice { improvement: Some(improvement), ..choice(Class::Fighter) }, None).unwrap();
+        // 6 + CON +2 for the new level, and +1 for each of the three before
+        assert_eq!(level_up.hit_points_gained, 8 + 3);
+        assert_eq!(level_up.character.constitution, 14);
+        assert_eq!(level_up.character.strength, 11);
+    }
+
+    #[test]
+    fn choices_required_at_their_levels() {
+        let fighter = character(vec![ClassLevel::new(Class::Fighter, 2)]);
+        let errors = level_up(&fighter, &choice(Class::Fighter), None).unwrap_err();
+        assert_eq!(errors, vec!["Choose a Fighter subclass".to_string()]);
+        let wrong = LevelUpChoice { subclass: Some(Subclass::Thief), ..choice(Class::Fighter) };
+        assert!(level_up(&fighter, &wrong, None).is_err());
+        let champion = LevelUpChoice { subclass: Some(Subclass::Champion), ..choice(Class::Fighter) };
+        let level_up = level_up(&fighter, &champion, None).unwrap();
+        assert_eq!(level_up.character.classes[0].subclass, Some(Subclass::Champion));
+        assert!(level_up.features.contains(&"Champion".to_string()));
+
+        let fighter = character(vec![ClassLevel { subclass: Some(Subclass::Champion), ..ClassLevel::new(Class::Fighter, 5) }]);
+        let errors = super::level_up(&fighter, &choice(Class::Fighter), None).unwrap_err();
+        assert_eq!(errors, vec!["Choose an Ability Score Improvement or a feat".to_string()]);
+        let maxed = CharacterDetail { strength: 20, ..fighter.clone() };
+        let improvement = Improvement::AbilityScores([Ability::Strength, Ability::Strength]);
+        assert!(super::level_up(&maxed, &LevelUpChoice { improvement: Some(improvement), ..choice(Class::Fighter) }, None).is_err());
+        let feat = LevelUpChoice { improvement: Some(Improvement::Feat("Alert".to_string())), ..choice(Class::Fighter) };
+        assert!(super::level_up(&fighter, &feat, None).unwrap().features.contains(&"Alert".to_string()));
+    }
+
+    #[test]
+    fn no_level_past_twenty() {
+        let fighter = character(vec![ClassLevel { subclass: Some(Subclass::Champion), ..ClassLevel::new(Class::Fighter, MAX_LEVEL) }]);
+        assert!(level_up(&fighter, &choice(Class::Fighter), None).is_err());
+    }
+
+    #[test]
+    fn new_spells_fit_the_level() {
+        let wizard = character(vec![ClassLevel { subclass: Some(Subclass::Evocation), ..ClassLevel::new(Class::Wizard, 2) }]);
+        let learn = |spells: Vec<Spell>| LevelUpChoice {
+            new_spells: spells.iter().map(|spell| spell.to_entry(None)).collect(),
+            ..choice(Class::Wizard)
+        };
+
+        let level_up = level_up(&wizard, &learn(vec![spell("shield", 1, vec![]), spell("web", 2, vec![])]), None).unwrap();
+        assert!(level_up.character.spellbook.entries.iter().all(|entry| entry.class == Some(Class::Wizard)));
+        assert!(super::level_up(&wizard, &learn(vec![spell("fireball", 3, vec![])]), None).is_err());
+        let three = vec![spell("shield", 1, vec![]), spell("web", 2, vec![]), spell("sleep", 1, vec![])];
+        assert!(super::level_up(&wizard, &learn(three), None).is_err());
+    }
+
+    #[test]
+    fn catalog_spells_come_from_the_catalog() {
+        let catalog = [spell("fireball", 3, vec![Class::Sorcerer, Class::Wizard]), spell("cure-wounds", 1, vec![Class::Bard, Class::Cleric])];
+        let claimed = [SpellbookEntry { level: 1, concentration: false, ..catalog[0].to_entry(None) }];
+
+        let entries = catalog_spells(Class::Wizard, 5, &claimed, &catalog).unwrap();
+        assert_eq!(entries, vec![catalog[0].to_entry(Some(Class::Wizard))]);
+
+        let unknown = SpellbookEntry { spell: "wish-for-more".to_string(), ..claimed[0].clone() };
+        assert_eq!(catalog_spells(Class::Wizard, 5, &[unknown], &catalog).unwrap_err().len(), 1);
+        assert!(catalog_spells(Class::Cleric, 5, &claimed, &catalog).is_err());
+        // Magical Secrets
+        assert!(catalog_spells(Class::Bard, 9, &claimed, &catalog).is_err());
+        assert!(catalog_spells(Class::Bard, 10, &claimed, &catalog).is_ok());
+    }
+}
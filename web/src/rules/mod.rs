@@ -0,0 +1,564 @@
+//! 5e rules for the numbers derived from a character's stored scores, class and level.
+//! The races, classes and backgrounds those rules read from live in [`catalog`].
+//! Everything here is pure so the sheet, the edit form and the server agree on the results.
+
+pub mod catalog;
+pub mod combat;
+pub mod creation;
+pub mod dice;
+pub mod features;
+pub mod inventory;
+pub mod leveling;
+pub mod multiclass;
+pub mod play;
+pub mod spellbook;
+
+pub use catalog::{Alignment, Background, Class, CreatureType, Environment, ItemCategory, MagicSchool, Race, Rarity, Size, Subclass};
+pub use multiclass::{ClassLevel, PactSlots};
+
+use serde::{Deserialize, Serialize};
+use crate::api::character::CharacterDetail;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Ability {
+    Strength,
+    Dexterity,
+    Constitution,
+    Intelligence,
+    Wisdom,
+    Charisma,
+}
+
+impl Ability {
+    pub const ALL: [Ability; 6] = [
+        Ability::Strength,
+        Ability::Dexterity,
+        Ability::Constitution,
+        Ability::Intelligence,
+        Ability::Wisdom,
+        Ability::Charisma,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Ability::Strength => "Strength",
+            Ability::Dexterity => "Dexterity",
+            Ability::Constitution => "Constitution",
+            Ability::Intelligence => "Intelligence",
+            Ability::Wisdom => "Wisdom",
+            Ability::Charisma => "Charisma",
+        }
+    }
+
+    pub fn abbreviation(self) -> &'static str {
+        match self {
+            Ability::Strength => "STR",
+            Ability::Dexterity => "DEX",
+            Ability::Constitution => "CON",
+            Ability::Intelligence => "INT",
+            Ability::Wisdom => "WIS",
+            Ability::Charisma => "CHA",
+        }
+    }
+
+    pub fn score(self, character: &CharacterDetail) -> i32 {
+        match self {
+            Ability::Strength => character.strength,
+            Ability::Dexterity => character.dexterity,
+            Ability::Constitution => character.constitution,
+            Ability::Intelligence => character.intelligence,
+            Ability::Wisdom => character.wisdom,
+            Ability::Charisma => character.charisma,
+        }
+    }
+
+    pub fn score_mut(self, character: &mut CharacterDetail) -> &mut i32 {
+        match self {
+            Ability::Strength => &mut character.strength,
+            Ability::Dexterity => &mut character.dexterity,
+            Ability::Constitution => &mut character.constitution,
+            Ability::Intelligence => &mut character.intelligence,
+            Ability::Wisdom => &mut character.wisdom,
+            Ability::Charisma => &mut character.charisma,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Skill {
+    Acrobatics,
+    AnimalHandling,
+    Arcana,
+    Athletics,
+    Deception,
+    History,
+    Insight,
+    Intimidation,
+    Investigation,
+    Medicine,
+    Nature,
+    Perception,
+    Performance,
+    Persuasion,
+    Religion,
+    SleightOfHand,
+    Stealth,
+    Survival,
+}
+
+impl Skill {
+    pub const ALL: [Skill; 18] = [
+        Skill::Acrobatics,
+        Skill::AnimalHandling,
+        Skill::Arcana,
+        Skill::Athletics,
+        Skill::Deception,
+        Skill::History,
+        Skill::Insight,
+        Skill::Intimidation,
+        Skill::Investigation,
+        Skill::Medicine,
+        Skill::Nature,
+        Skill::Perception,
+        Skill::Performance,
+        Skill::Persuasion,
+        Skill::Religion,
+        Skill::SleightOfHand,
+        Skill::Stealth,
+        Skill::Survival,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Skill::Acrobatics => "Acrobatics",
+            Skill::AnimalHandling => "Animal Handling",
+            Skill::Arcana => "Arcana",
+            Skill::Athletics => "Athletics",
+            Skill::Deception => "Deception",
+            Skill::History => "History",
+            Skill::Insight => "Insight",
+            Skill::Intimidation => "Intimidation",
+            Skill::Investigation => "Investigation",
+            Skill::Medicine => "Medicine",
+            Skill::Nature => "Nature",
+            Skill::Perception => "Perception",
+            Skill::Performance => "Performance",
+            Skill::Persuasion => "Persuasion",
+            Skill::Religion => "Religion",
+            Skill::SleightOfHand => "Sleight of Hand",
+            Skill::Stealth => "Stealth",
+            Skill::Survival => "Survival",
+        }
+    }
+
+    pub fn ability(self) -> Ability {
+        match self {
+            Skill::Athletics => Ability::Strength,
+            Skill::Acrobatics | Skill::SleightOfHand | Skill::Stealth => Ability::Dexterity,
+            Skill::Arcana | Skill::History | Skill::Investigation | Skill::Nature | Skill::Religion => Ability::Intelligence,
+            Skill::AnimalHandling | Skill::Insight | Skill::Medicine | Skill::Perception | Skill::Survival => Ability::Wisdom,
+            Skill::Deception | Skill::Intimidation | Skill::Performance | Skill::Persuasion => Ability::Charisma,
+        }
+    }
+
+    /// Look a skill up by name, ignoring case and spacing ("sleight of hand", "SleightOfHand")
+    pub fn from_name(name: &str) -> Option<Skill> {
+        let wanted = normalize(name);
+        Skill::ALL.into_iter().find(|skill| normalize(skill.name()) == wanted)
+    }
+}
+
+/// How well a character knows a skill. Expertise doubles the proficiency bonus.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Proficiency {
+    None,
+    Proficient,
+    Expertise,
+}
+
+impl Proficiency {
+    pub fn bonus(self, proficiency_bonus: i32) -> i32 {
+        match self {
+            Proficiency::None => 0,
+            Proficiency::Proficient => proficiency_bonus,
+            Proficiency::Expertise => proficiency_bonus * 2,
+        }
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+}
+
+/// Read a stored skill entry: "Stealth" is proficiency, "Stealth (Expertise)" is expertise
+pub fn parse_skill_entry(entry: &str) -> Option<(Skill, Proficiency)> {
+    let (name, proficiency) = match entry.split_once('(') {
+        Some((name, marker)) if normalize(marker) == "expertise" => (name, Proficiency::Expertise),
+        Some((name, _)) => (name, Proficiency::Proficient),
+        None => (entry, Proficiency::Proficient),
+    };
+    Skill::from_name(name).map(|skill| (skill, proficiency))
+}
+
+/// The stored form of a skill entry, the inverse of [`parse_skill_entry`]
+pub fn skill_entry(skill: Skill, proficiency: Proficiency) -> Option<String> {
+    match proficiency {
+        Proficiency::None => None,
+        Proficiency::Proficient => Some(skill.name().to_string()),
+        Proficiency::Expertise => Some(format!("{} (Expertise)", skill.name())),
+    }
+}
+
+pub fn ability_modifier(score: i32) -> i32 {
+    // Round down, including for odd scores below 10 (9 is -1, not 0)
+    (score - 10).div_euclid(2)
+}
+
+/// +2 at levels 1-4, rising by one every four levels to +6 at 17-20
+pub fn proficiency_bonus(level: i32) -> i32 {
+    2 + (level.clamp(1, 20) - 1) / 4
+}
+
+/// Maximum hit points taking the full hit die at first level of the starting class and the
+/// fixed average of each class's die for every level after that. Every level grants at least 1.
+pub fn average_hit_points(classes: &[ClassLevel], constitution: i32) -> i32 {
+    let con = ability_modifier(constitution);
+    let mut hit_points = 0;
+    let mut first = true;
+    for class_level in classes {
+        for _ in 0..class_level.level {
+            let roll = if first { class_level.hit_die() } else { class_level.hit_die() / 2 + 1 };
+            hit_points += (roll + con).max(1);
+            first = false;
+        }
+    }
+    hit_points.max(1)
+}
+
+pub fn unarmored_armor_class(dexterity: i32) -> i32 {
+    10 + ability_modifier(dexterity)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AbilityScore {
+    pub ability: Ability,
+    pub score: i32,
+    pub modifier: i32,
+    pub saving_throw: i32,
+    pub save_proficient: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SkillBonus {
+    pub skill: Skill,
+    pub proficiency: Proficiency,
+    pub bonus: i32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Spellcasting {
+    pub ability: Ability,
+    pub save_dc: i32,
+    pub attack_bonus: i32,
+}
+
+/// Everything the sheet shows that follows from the stored character
+#[derive(Clone, Debug, PartialEq)]
+pub struct DerivedStats {
+    pub level: i32,
+    pub proficiency_bonus: i32,
+    pub abilities: Vec<AbilityScore>,
+    pub skills: Vec<SkillBonus>,
+    pub passive_perception: i32,
+    pub initiative: i32,
+    /// One entry per spellcasting ability; a Cleric / Druid shares a single Wisdom entry
+    pub spellcasting: Vec<Spellcasting>,
+    /// Shared slots by spell level, 1st to 9th
+    pub spell_slots: [i32; 9],
+    pub pact_slots: Option<PactSlots>,
+    /// `(die size, count)`, largest die first
+    pub hit_dice: Vec<(i32, i32)>,
+    pub average_hit_points: i32,
+    pub unarmored_armor_class: i32,
+}
+
+impl DerivedStats {
+    pub fn for_character(character: &CharacterDetail) -> Self {
+        let level = character.level();
+        let proficiency_bonus = proficiency_bonus(level);
+        let modifier = |ability: Ability| ability_modifier(ability.score(character));
+
+        // Only the starting class grants saving throw proficiencies
+        let save_proficiencies: Vec<Ability> =
+            character.classes.first().into_iter().flat_map(|class_level| class_level.class.saving_throws()).collect();
+        let abilities = Ability::ALL
+            .into_iter()
+            .map(|ability| {
+                let save_proficient = save_proficiencies.contains(&ability);
+                AbilityScore {
+                    ability,
+                    score: ability.score(character),
+                    modifier: modifier(ability),
+                    saving_throw: modifier(ability) + if save_proficient { proficiency_bonus } else { 0 },
+                    save_proficient,
+                }
+            })
+            .collect();
+
+        // The background's skills count even if they were never written into the skill list
+        let granted = character.background.into_iter().flat_map(Background::skill_proficiencies);
+        let known: Vec<(Skill, Proficiency)> = character
+            .skills
+            .iter()
+            .filter_map(|entry| parse_skill_entry(entry))
+            .chain(granted.map(|skill| (skill, Proficiency::Proficient)))
+            .collect();
+        let skills: Vec<SkillBonus> = Skill::ALL
+            .into_iter()
+            .map(|skill| {
+                let proficiency = known
+                    .iter()
+                    .filter(|(known_skill, _)| *known_skill == skill)
+                    .map(|(_, proficiency)| *proficiency)
+                    .max()
+                    .unwrap_or(Proficiency::None);
+                SkillBonus {
+                    skill,
+                    proficiency,
+                    bonus: modifier(skill.ability()) + proficiency.bonus(proficiency_bonus),
+                }
+            })
+            .collect();
+        let perception = skills.iter().find(|s| s.skill == Skill::Perception).map_or(0, |s| s.bonus);
+
+        let mut casting_abilities: Vec<Ability> = vec![];
+        for ability in character.classes.iter().filter_map(ClassLevel::spellcasting_ability) {
+            if !casting_abilities.contains(&ability) {
+                casting_abilities.push(ability);
+            }
+        }
+        let spellcasting = casting_abilities
+            .into_iter()
+            .map(|ability| Spellcasting {
+                ability,
+                save_dc: 8 + proficiency_bonus + modifier(ability),
+                attack_bonus: proficiency_bonus + modifier(ability),
+            })
+            .collect();
+
+        Self {
+            level,
+            proficiency_bonus,
+            abilities,
+            skills,
+            passive_perception: 10 + perception,
+            initiative: modifier(Ability::Dexterity),
+            spellcasting,
+            spell_slots: multiclass::spell_slots(&character.classes),
+            pact_slots: multiclass::pact_slots(&character.classes),
+            hit_dice: multiclass::hit_dice(&character.classes),
+            average_hit_points: average_hit_points(&character.classes, character.constitution),
+            unarmored_armor_class: unarmored_armor_class(character.dexterity),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::api::character::CharacterAccess;
+
+    /// A level-less Human with every score at 10 and nothing else; tests fill in what they need
+    pub(crate) fn character(classes: Vec<ClassLevel>) -> CharacterDetail {
+        CharacterDetail {
+            id: 1,
+            name: "Tester".to_string(),
+            slug: "tester".to_string(),
+            description: String::new(),
+            race: Some(Race::Human),
+            classes,
+            background: None,
+            alignment: Some(Alignment::Neutral),
+            strength: 10,
+            dexterity: 10,
+            constitution: 10,
+            intelligence: 10,
+            wisdom: 10,
+            charisma: 10,
+            ability_rolls: vec![],
+            hit_points: 10,
+            armor_class: 10,
+            speed: 30,
+            proficiency_bonus: 2,
+            skills: vec![],
+            languages: vec![],
+            inventory: Default::default(),
+            spellbook: Default::default(),
+            play_state: Default::default(),
+            features: vec![],
+            backstory: String::new(),
+            portrait_image: None,
+            created_at: None,
+            updated_at: None,
+            owner: "owner".to_string(),
+            access: CharacterAccess::Owner,
+        }
+    }
+
+    // STR 8, DEX 12, CON 14, INT 16, WIS 18, CHA 20: modifiers -1 to +5, all different
+    fn spread(classes: Vec<ClassLevel>) -> CharacterDetail {
+        CharacterDetail {
+            strength: 8,
+            dexterity: 12,
+            constitution: 14,
+            intelligence: 16,
+            wisdom: 18,
+            charisma: 20,
+            ..character(classes)
+        }
+    }
+
+    #[test]
+    fn ability_modifiers_round_down() {
+        for (score, modifier) in [(1, -5), (3, -4), (8, -1), (9, -1), (10, 0), (11, 0), (15, 2), (20, 5), (30, 10)] {
+            assert_eq!(ability_modifier(score), modifier, "score {}", score);
+        }
+    }
+
+    #[test]
+    fn proficiency_bonus_by_level() {
+        let table = [(1, 2), (4, 2), (5, 3), (8, 3), (9, 4), (12, 4), (13, 5), (16, 5), (17, 6), (20, 6), (0, 2), (25, 6)];
+        for (level, bonus) in table {
+            assert_eq!(proficiency_bonus(level), bonus, "level {}", level);
+        }
+    }
+
+    #[test]
+    fn saving_throws_come_from_the_starting_class() {
+        let stats = DerivedStats::for_character(&spread(vec![ClassLevel::new(Class::Fighter, 5), ClassLevel::new(Class::Wizard, 1)]));
+        assert_eq!(stats.proficiency_bonus, 3);
+        let table = [
+            (Ability::Strength, -1 + 3, true),
+            (Ability::Dexterity, 1, false),
+            (Ability::Constitution, 2 + 3, true),
+            (Ability::Intelligence, 3, false),
+            (Ability::Wisdom, 4, false),
+            (Ability::Charisma, 5, false),
+        ];
+        for (ability, save, proficient) in table {
+            let score = stats.abilities.iter().find(|a| a.ability == ability).unwrap();
+            assert_eq!(score.saving_throw, save, "{} save", ability.name());
+            assert_eq!(score.save_proficient, proficient, "{} save proficiency", ability.name());
+        }
+    }
+
+    #[test]
+    fn every_skill_uses_its_ability() {
+        let table = [
+            (Skill::Acrobatics, Ability::Dexterity, 1),
+            (Skill::AnimalHandling, Ability::Wisdom, 4),
+            (Skill::Arcana, Ability::Intelligence, 3),
+            (Skill::Athletics, Ability::Strength, -1),
+            (Skill::Deception, Ability::Charisma, 5),
+            (Skill::History, Ability::Intelligence, 3),
+            (Skill::Insight, Ability::Wisdom, 4),
+            (Skill::Intimidation, Ability::Charisma, 5),
+            (Skill::Investigation, Ability::Intelligence, 3),
+            (Skill::Medicine, Ability::Wisdom, 4),
+            (Skill::Nature, Ability::Intelligence, 3),
+            (Skill::Perception, Ability::Wisdom, 4),
+            (Skill::Performance, Ability::Charisma, 5),
+            (Skill::Persuasion, Ability::Charisma, 5),
+            (Skill::Religion, Ability::Intelligence, 3),
+            (Skill::SleightOfHand, Ability::Dexterity, 1),
+            (Skill::Stealth, Ability::Dexterity, 1),
+            (Skill::Survival, Ability::Wisdom, 4),
+        ];
+        assert_eq!(table.len(), Skill::ALL.len());
+
+        let stats = DerivedStats::for_character(&spread(vec![ClassLevel::new(Class::Rogue, 1)]));
+        for (skill, ability, bonus) in table {
+            assert_eq!(skill.ability(), ability, "{}", skill.name());
+            let derived = stats.skills.iter().find(|s| s.skill == skill).unwrap();
+            assert_eq!(derived.proficiency, Proficiency::None, "{}", skill.name());
+            assert_eq!(derived.bonus, bonus, "{}", skill.name());
+        }
+    }
+
+    #[test]
+    fn skill_proficiency_expertise_and_background() {
+        let mut rogue = spread(vec![ClassLevel::new(Class::Rogue, 5)]);
+        rogue.skills = vec!["Stealth (Expertise)".to_string(), "arcana".to_string()];
+        rogue.background = Some(Background::Sailor);
+        let stats = DerivedStats::for_character(&rogue);
+        let bonus = |skill: Skill| stats.skills.iter().find(|s| s.skill == skill).unwrap().bonus;
+
+        assert_eq!(bonus(Skill::Stealth), 1 + 6);
+        assert_eq!(bonus(Skill::Arcana), 3 + 3);
+        // Sailor grants Athletics and Perception
+        assert_eq!(bonus(Skill::Athletics), -1 + 3);
+        assert_eq!(bonus(Skill::Perception), 4 + 3);
+        assert_eq!(bonus(Skill::History), 3);
+    }
+
+    #[test]
+    fn skill_entries_round_trip() {
+        assert_eq!(parse_skill_entry("Sleight of Hand"), Some((Skill::SleightOfHand, Proficiency::Proficient)));
+        assert_eq!(parse_skill_entry("SleightOfHand (expertise)"), Some((Skill::SleightOfHand, Proficiency::Expertise)));
+        assert_eq!(parse_skill_entry("Basket Weaving"), None);
+        for skill in Skill::ALL {
+            for proficiency in [Proficiency::Proficient, Proficiency::Expertise] {
+                let entry = skill_entry(skill, proficiency).unwrap();
+                assert_eq!(parse_skill_entry(&entry), Some((skill, proficiency)));
+            }
+        }
+        assert_eq!(skill_entry(Skill::Arcana, Proficiency::None), None);
+    }
+
+    #[test]
+    fn passive_perception() {
+        let mut cleric = spread(vec![ClassLevel::new(Class::Cleric, 1)]);
+        assert_eq!(DerivedStats::for_character(&cleric).passive_perception, 10 + 4);
+        cleric.skills = vec!["Perception".to_string()];
+        assert_eq!(DerivedStats::for_character(&cleric).passive_perception, 10 + 4 + 2);
+        cleric.skills = vec!["Perception (Expertise)".to_string()];
+        assert_eq!(DerivedStats::for_character(&cleric).passive_perception, 10 + 4 + 4);
+    }
+
+    #[test]
+    fn spell_save_dc_and_attack_bonus() {
+        let table = [
+            (vec![ClassLevel::new(Class::Wizard, 5)], vec![(Ability::Intelligence, 8 + 3 + 3, 3 + 3)]),
+            (vec![ClassLevel::new(Class::Cleric, 1)], vec![(Ability::Wisdom, 8 + 2 + 4, 2 + 4)]),
+            // Cleric and Druid share one Wisdom entry
+            (vec![ClassLevel::new(Class::Cleric, 3), ClassLevel::new(Class::Druid, 2)], vec![(Ability::Wisdom, 8 + 3 + 4, 3 + 4)]),
+            (
+                vec![ClassLevel::new(Class::Paladin, 2), ClassLevel::new(Class::Wizard, 2)],
+                vec![(Ability::Charisma, 8 + 2 + 5, 2 + 5), (Ability::Intelligence, 8 + 2 + 3, 2 + 3)],
+            ),
+            (vec![ClassLevel::new(Class::Fighter, 3)], vec![]),
+        ];
+        for (classes, expected) in table {
+            let stats = DerivedStats::for_character(&spread(classes.clone()));
+            let got: Vec<(Ability, i32, i32)> =
+                stats.spellcasting.iter().map(|casting| (casting.ability, casting.save_dc, casting.attack_bonus)).collect();
+            assert_eq!(got, expected, "{:?}", classes);
+        }
+    }
+
+    #[test]
+    fn average_hit_points_by_class_and_constitution() {
+        let table = [
+            (vec![ClassLevel::new(Class::Fighter, 1)], 14, 10 + 2),
+            (vec![ClassLevel::new(Class::Fighter, 3)], 14, 12 + 2 * (6 + 2)),
+            (vec![ClassLevel::new(Class::Wizard, 1)], 8, 6 - 1),
+            (vec![ClassLevel::new(Class::Barbarian, 1), ClassLevel::new(Class::Wizard, 2)], 10, 12 + 2 * 4),
+            // Every level grants at least one hit point
+            (vec![ClassLevel::new(Class::Wizard, 3)], 1, 3),
+            (vec![], 10, 1),
+        ];
+        for (classes, constitution, hit_points) in table {
+            assert_eq!(average_hit_points(&classes, constitution), hit_points, "{:?} CON {}", classes, constitution);
+        }
+    }
+}
@@ -0,0 +1,298 @@
+//! A character's levels split across classes, e.g. Fighter 3 / Wizard 2, and the rules that
+//! combine them: prerequisites for taking a new class, hit dice and the multiclass spell slot table.
+
+use serde::{Deserialize, Serialize};
+use super::{Ability, Class, Subclass};
+
+/// Levels taken in one class. A character's first entry is the class they started in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClassLevel {
+    pub class: Class,
+    pub level: i32,
+    #[serde(default)]
+    pub subclass: Option<Subclass>,
+}
+
+/// How quickly a class gains spell slots, as counted by the multiclass spellcaster table
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CasterProgression {
+    Full,
+    Half,
+    /// Eldritch Knights and Arcane Tricksters
+    Third,
+    /// Warlocks, whose slots are kept separate from everyone else's
+    Pact,
+}
+
+impl ClassLevel {
+    pub fn new(class: Class, level: i32) -> Self {
+        Self { class, level, subclass: None }
+    }
+
+    pub fn hit_die(&self) -> i32 {
+        self.class.hit_die()
+    }
+
+    pub fn caster_progression(&self) -> Option<CasterProgression> {
+        match self.class {
+            Class::Bard | Class::Cleric | Class::Druid | Class::Sorcerer | Class::Wizard => Some(CasterProgression::Full),
+            Class::Paladin | Class::Ranger => Some(CasterProgression::Half),
+            Class::Warlock => Some(CasterProgression::Pact),
+            Class::Fighter if self.subclass == Some(Subclass::EldritchKnight) => Some(CasterProgression::Third),
+            Class::Rogue if self.subclass == Some(Subclass::ArcaneTrickster) => Some(CasterProgression::Third),
+            _ => None,
+        }
+    }
+
+    /// The ability these class levels cast with, including the spellcasting subclasses
+    pub fn spellcasting_ability(&self) -> Option<Ability> {
+        match self.caster_progression() {
+            Some(CasterProgression::Third) => Some(Ability::Intelligence),
+            Some(_) => self.class.spellcasting_ability(),
+            None => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ClassLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.class, self.level)?;
+        if let Some(subclass) = self.subclass {
+            write!(f, " ({})", subclass)?;
+        }
+        Ok(())
+    }
+}
+
+/// Character level: the sum of every class's levels
+pub fn total_level(classes: &[ClassLevel]) -> i32 {
+    classes.iter().map(|class_level| class_level.level).sum()
+}
+
+/// Ability scores of 13 or more a character needs to multiclass into or out of a class.
+/// Fighters need Strength *or* Dexterity; every other class needs all of its abilities.
+pub fn prerequisites(class: Class) -> &'static [Ability] {
+    use Ability::*;
+    match class {
+        Class::Barbarian => &[Strength],
+        Class::Bard | Class::Sorcerer | Class::Warlock => &[Charisma],
+        Class::Cleric | Class::Druid => &[Wisdom],
+        Class::Fighter => &[Strength, Dexterity],
+        Class::Monk | Class::Ranger => &[Dexterity, Wisdom],
+        Class::Paladin => &[Strength, Charisma],
+        Class::Rogue => &[Dexterity],
+        Class::Wizard => &[Intelligence],
+    }
+}
+
+/// Whether scores (looked up through `score`) meet a class's multiclassing prerequisites
+pub fn meets_prerequisites(class: Class, score: impl Fn(Ability) -> i32) -> bool {
+    let abilities = prerequisites(class);
+    if class == Class::Fighter {
+        abilities.iter().any(|ability| score(*ability) >= 13)
+    } else {
+        abilities.iter().all(|ability| score(*ability) >= 13)
+    }
+}
+
+/// Hit dice grouped by size, largest first, e.g. `[(10, 3), (6, 2)]` for 3d10 + 2d6
+pub fn hit_dice(classes: &[ClassLevel]) -> Vec<(i32, i32)> {
+    let mut dice: Vec<(i32, i32)> = vec![];
+    for class_level in classes {
+        match dice.iter_mut().find(|(die, _)| *die == class_level.hit_die()) {
+            Some((_, count)) => *count += class_level.level,
+            None => dice.push((class_level.hit_die(), class_level.level)),
+        }
+    }
+    dice.sort_by_key(|&(die, _)| std::cmp::Reverse(die));
+    dice
+}
+
+/// Spell slots by caster level, 1st through 9th level slots
+const SPELL_SLOTS: [[i32; 9]; 20] = [
+    [2, 0, 0, 0, 0, 0, 0, 0, 0],
+    [3, 0, 0, 0, 0, 0, 0, 0, 0],
+    [4, 2, 0, 0, 0, 0, 0, 0, 0],
+    [4, 3, 0, 0, 0, 0, 0, 0, 0],
+    [4, 3, 2, 0, 0, 0, 0, 0, 0],
+    [4, 3, 3, 0, 0, 0, 0, 0, 0],
+    [4, 3, 3, 1, 0, 0, 0, 0, 0],
+    [4, 3, 3, 2, 0, 0, 0, 0, 0],
+    [4, 3, 3, 3, 1, 0, 0, 0, 0],
+    [4, 3, 3, 3, 2, 0, 0, 0, 0],
+    [4, 3, 3, 3, 2, 1, 0, 0, 0],
+    [4, 3, 3, 3, 2, 1, 0, 0, 0],
+    [4, 3, 3, 3, 2, 1, 1, 0, 0],
+    [4, 3, 3, 3, 2, 1, 1, 0, 0],
+    [4, 3, 3, 3, 2, 1, 1, 1, 0],
+    [4, 3, 3, 3, 2, 1, 1, 1, 0],
+    [4, 3, 3, 3, 2, 1, 1, 1, 1],
+    [4, 3, 3, 3, 3, 1, 1, 1, 1],
+    [4, 3, 3, 3, 3, 2, 1, 1, 1],
+    [4, 3, 3, 3, 3, 2, 2, 1, 1],
+];
+
+/// The caster level used to look up shared spell slots. A single half or third caster
+/// rounds up (a level 2 Paladin already has slots); across several classes each rounds down.
+pub fn caster_level(classes: &[ClassLevel]) -> i32 {
+    let casters: Vec<(CasterProgression, i32)> = classes
+        .iter()
+        .filter_map(|class_level| class_level.caster_progression().map(|p| (p, class_level.level)))
+        .filter(|(progression, _)| *progression != CasterProgression::Pact)
+        .collect();
+
+    if let [(progression, level)] = casters.as_slice() {
+        return match progression {
+            CasterProgression::Full => *level,
+            CasterProgression::Half if *level >= 2 => (*level + 1) / 2,
+            CasterProgression::Third if *level >= 3 => (*level + 2) / 3,
+            _ => 0,
+        };
+    }
+    casters
+        .iter()
+        .map(|(progression, level)| match progression {
+            CasterProgression::Full => *level,
+            CasterProgression::Half => *level / 2,
+            CasterProgression::Third => *level / 3,
+            CasterProgression::Pact => 0,
+        })
+        .sum()
+}
+
+/// Shared spell slots for every class except Warlock, indexed by slot level - 1
+pub fn spell_slots(classes: &[ClassLevel]) -> [i32; 9] {
+    match caster_level(classes) {
+        0 => [0; 9],
+        level => SPELL_SLOTS[(level.min(20) - 1) as usize],
+    }
+}
+
+/// Warlock pact magic slots: how many there are and the level they are all cast at
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PactSlots {
+    pub count: i32,
+    pub slot_level: i32,
+}
+
+pub fn pact_slots(classes: &[ClassLevel]) -> Option<PactSlots> {
+    let level = classes.iter().find(|class_level| class_level.class == Class::Warlock)?.level;
+    let count = match level {
+        1 => 1,
+        2..=10 => 2,
+        11..=16 => 3,
+        _ => 4,
+    };
+    let slot_level = ((level + 1) / 2).min(5);
+    Some(PactSlots { count, slot_level })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subclassed(class: Class, level: i32, subclass: Subclass) -> ClassLevel {
+        ClassLevel { subclass: Some(subclass), ..ClassLevel::new(class, level) }
+    }
+
+    #[test]
+    fn slot_table_for_full_casters() {
+        let table = [
+            (1, [2, 0, 0, 0, 0, 0, 0, 0, 0]),
+            (2, [3, 0, 0, 0, 0, 0, 0, 0, 0]),
+            (3, [4, 2, 0, 0, 0, 0, 0, 0, 0]),
+            (5, [4, 3, 2, 0, 0, 0, 0, 0, 0]),
+            (7, [4, 3, 3, 1, 0, 0, 0, 0, 0]),
+            (9, [4, 3, 3, 3, 1, 0, 0, 0, 0]),
+            (11, [4, 3, 3, 3, 2, 1, 0, 0, 0]),
+            (13, [4, 3, 3, 3, 2, 1, 1, 0, 0]),
+            (15, [4, 3, 3, 3, 2, 1, 1, 1, 0]),
+            (17, [4, 3, 3, 3, 2, 1, 1, 1, 1]),
+            (18, [4, 3, 3, 3, 3, 1, 1, 1, 1]),
+            (19, [4, 3, 3, 3, 3, 2, 1, 1, 1]),
+            (20, [4, 3, 3, 3, 3, 2, 2, 1, 1]),
+        ];
+        for (level, slots) in table {
+            assert_eq!(spell_slots(&[ClassLevel::new(Class::Wizard, level)]), slots, "Wizard {}", level);
+        }
+    }
+
+    #[test]
+    fn slot_table_never_loses_slots() {
+        for (level, rows) in SPELL_SLOTS.windows(2).enumerate() {
+            for (slot, (before, after)) in rows[0].iter().zip(&rows[1]).enumerate() {
+                assert!(after >= before, "caster level {} slot {}", level + 2, slot + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn caster_level_of_a_single_class() {
+        let table = [
+            (ClassLevel::new(Class::Cleric, 5), 5),
+            (ClassLevel::new(Class::Paladin, 1), 0),
+            (ClassLevel::new(Class::Paladin, 2), 1),
+            (ClassLevel::new(Class::Paladin, 5), 3),
+            (ClassLevel::new(Class::Ranger, 20), 10),
+            (subclassed(Class::Fighter, 2, Subclass::EldritchKnight), 0),
+            (subclassed(Class::Fighter, 3, Subclass::EldritchKnight), 1),
+            (subclassed(Class::Rogue, 4, Subclass::ArcaneTrickster), 2),
+            (subclassed(Class::Fighter, 19, Subclass::EldritchKnight), 7),
+            (ClassLevel::new(Class::Fighter, 5), 0),
+            (ClassLevel::new(Class::Warlock, 5), 0),
+        ];
+        for (class_level, caster) in table {
+            assert_eq!(caster_level(&[class_level]), caster, "{}", class_level);
+        }
+    }
+
+    #[test]
+    fn caster_level_across_classes_rounds_each_down() {
+        let table = [
+            (vec![ClassLevel::new(Class::Wizard, 3), ClassLevel::new(Class::Paladin, 3)], 3 + 1),
+            (vec![ClassLevel::new(Class::Paladin, 1), ClassLevel::new(Class::Ranger, 1)], 0),
+            (vec![ClassLevel::new(Class::Cleric, 2), subclassed(Class::Fighter, 3, Subclass::EldritchKnight)], 2 + 1),
+            (vec![ClassLevel::new(Class::Sorcerer, 3), ClassLevel::new(Class::Warlock, 5)], 3),
+            (vec![ClassLevel::new(Class::Fighter, 5), ClassLevel::new(Class::Rogue, 5)], 0),
+        ];
+        for (classes, caster) in table {
+            assert_eq!(caster_level(&classes), caster, "{:?}", classes);
+        }
+        assert_eq!(
+            spell_slots(&[ClassLevel::new(Class::Wizard, 3), ClassLevel::new(Class::Paladin, 3)]),
+            [4, 3, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(spell_slots(&[ClassLevel::new(Class::Fighter, 5)]), [0; 9]);
+    }
+
+    #[test]
+    fn pact_slots_by_warlock_level() {
+        let table = [(1, 1, 1), (2, 2, 1), (5, 2, 3), (9, 2, 5), (11, 3, 5), (17, 4, 5), (20, 4, 5)];
+        for (level, count, slot_level) in table {
+            let classes = [ClassLevel::new(Class::Sorcerer, 2), ClassLevel::new(Class::Warlock, level)];
+            assert_eq!(pact_slots(&classes), Some(PactSlots { count, slot_level }), "Warlock {}", level);
+        }
+        assert_eq!(pact_slots(&[ClassLevel::new(Class::Wizard, 5)]), None);
+    }
+
+    #[test]
+    fn hit_dice_group_by_size() {
+        let classes = [ClassLevel::new(Class::Wizard, 2), ClassLevel::new(Class::Fighter, 3), ClassLevel::new(Class::Paladin, 1)];
+        assert_eq!(hit_dice(&classes), vec![(10, 4), (6, 2)]);
+        assert_eq!(total_level(&classes), 6);
+    }
+
+    #[test]
+    fn prerequisites_need_thirteen() {
+        let scores = |strength: i32, dexterity: i32| move |ability: Ability| match ability {
+            Ability::Strength => strength,
+            Ability::Dexterity => dexterity,
+            _ => 10,
+        };
+        // Fighters need Strength or Dexterity, Monks need Dexterity and Wisdom
+        assert!(meets_prerequisites(Class::Fighter, scores(8, 13)));
+        assert!(!meets_prerequisites(Class::Fighter, scores(12, 12)));
+        assert!(!meets_prerequisites(Class::Monk, scores(10, 16)));
+        assert!(meets_prerequisites(Class::Rogue, scores(8, 13)));
+    }
+}
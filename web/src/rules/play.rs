@@ -0,0 +1,659 @@
+//! Live play: hit points as they go up and down during a session, death saves, conditions,
+//! exhaustion, spent hit dice and the rests that bring them back. Every change goes through
+//! [`apply`] so the server can replay a player's action against the stored character.
+
+use serde::{Deserialize, Serialize};
+use crate::api::character::CharacterDetail;
+use super::features::Recharge;
+use super::{ability_modifier, multiclass};
+
+/// The highest exhaustion level; a creature reaching it dies
+pub const MAX_EXHAUSTION: i32 = 6;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Condition {
+    Blinded,
+    Charmed,
+    Deafened,
+    Frightened,
+    Grappled,
+    Incapacitated,
+    Invisible,
+    Paralyzed,
+    Petrified,
+    Poisoned,
+    Prone,
+    Restrained,
+    Stunned,
+    Unconscious,
+}
+
+impl Condition {
+    pub const ALL: [Condition; 14] = [
+        Condition::Blinded,
+        Condition::Charmed,
+        Condition::Deafened,
+        Condition::Frightened,
+        Condition::Grappled,
+        Condition::Incapacitated,
+        Condition::Invisible,
+        Condition::Paralyzed,
+        Condition::Petrified,
+        Condition::Poisoned,
+        Condition::Prone,
+        Condition::Restrained,
+        Condition::Stunned,
+        Condition::Unconscious,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Condition::Blinded => "Blinded",
+            Condition::Charmed => "Charmed",
+            Condition::Deafened => "Deafened",
+            Condition::Frightened => "Frightened",
+            Condition::Grappled => "Grappled",
+            Condition::Incapacitated => "Incapacitated",
+            Condition::Invisible => "Invisible",
+            Condition::Paralyzed => "Paralyzed",
+            Condition::Petrified => "Petrified",
+            Condition::Poisoned => "Poisoned",
+            Condition::Prone => "Prone",
+            Condition::Restrained => "Restrained",
+            Condition::Stunned => "Stunned",
+            Condition::Unconscious => "Unconscious",
+        }
+    }
+
+    /// A one-line reminder of what the condition does
+    pub fn effect(self) -> &'static str {
+        match self {
+            Condition::Blinded => "Fails sight checks; attacks against it have advantage, its attacks have disadvantage",
+            Condition::Charmed => "Can't attack the charmer, who has advantage on social checks against it",
+            Condition::Deafened => "Fails checks that require hearing",
+            Condition::Frightened => "Disadvantage while the source is in sight; can't move closer to it",
+            Condition::Grappled => "Speed 0",
+            Condition::Incapacitated => "No actions or reactions",
+            Condition::Invisible => "Attacks against it have disadvantage, its attacks have advantage",
+            Condition::Paralyzed => "Incapacitated, fails STR and DEX saves; hits within 5 ft are critical",
+            Condition::Petrified => "Turned to stone: incapacitated, resistant to all damage",
+            Condition::Poisoned => "Disadvantage on attack rolls and ability checks",
+            Condition::Prone => "Crawls; melee attacks against it have advantage, ranged have disadvantage",
+            Condition::Restrained => "Speed 0, disadvantage on attacks and DEX saves",
+            Condition::Stunned => "Incapacitated, fails STR and DEX saves",
+            Condition::Unconscious => "Incapacitated and prone; hits within 5 ft are critical",
+        }
+    }
+}
+
+impl std::fmt::Display for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// What each level of exhaustion adds; the effects are cumulative
+pub fn exhaustion_effect(level: i32) -> &'static str {
+    match level {
+        1 => "Disadvantage on ability checks",
+        2 => "Speed halved",
+        3 => "Disadvantage on attack rolls and saving throws",
+        4 => "Hit point maximum halved",
+        5 => "Speed reduced to 0",
+        6 => "Death",
+        _ => "None",
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeathSaves {
+    pub successes: i32,
+    pub failures: i32,
+}
+
+impl DeathSaves {
+    pub fn is_stable(self) -> bool {
+        self.successes >= 3
+    }
+
+    pub fn is_dead(self) -> bool {
+        self.failures >= 3
+    }
+}
+
+/// A character's state at the table, on top of the stored sheet
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayState {
+    /// `None` while the character is unhurt, so raising their maximum doesn't leave them wounded
+    pub current_hit_points: Option<i32>,
+    pub temporary_hit_points: i32,
+    pub death_saves: DeathSaves,
+    pub conditions: Vec<Condition>,
+    pub exhaustion: i32,
+    /// `(die size, count)` of hit dice spent since they were last recovered
+    pub hit_dice_spent: Vec<(i32, i32)>,
+}
+
+impl PlayState {
+    pub fn current_hit_points(&self, max: i32) -> i32 {
+        self.current_hit_points.unwrap_or(max).clamp(0, max)
+    }
+
+    pub fn has(&self, condition: Condition) -> bool {
+        self.conditions.contains(&condition)
+    }
+
+    pub fn is_dead(&self) -> bool {
+        self.death_saves.is_dead() || self.exhaustion >= MAX_EXHAUSTION
+    }
+
+    pub fn spent(&self, die: i32) -> i32 {
+        self.hit_dice_spent.iter().find(|(size, _)| *size == die).map_or(0, |(_, count)| *count)
+    }
+
+    fn add(&mut self, condition: Condition) {
+        if !self.has(condition) {
+            self.conditions.push(condition);
+        }
+    }
+
+    fn remove(&mut self, condition: Condition) {
+        self.conditions.retain(|c| *c != condition);
+    }
+
+    fn set_hit_points(&mut self, hit_points: i32, max: i32) {
+        self.current_hit_points = (hit_points < max).then_some(hit_points.max(0));
+    }
+
+    // Back on their feet with at least one hit point
+    fn revive(&mut self) {
+        self.death_saves = DeathSaves::default();
+        self.remove(Condition::Unconscious);
+    }
+
+    pub fn validate(&self, character: &CharacterDetail) -> Vec<String> {
+        let mut errors = vec![];
+        // Above the maximum is fine: it reads back as the maximum after the sheet is edited down
+        if self.current_hit_points.is_some_and(|current| current < 0) {
+            errors.push("Current hit points cannot be negative".to_string());
+        }
+        if self.temporary_hit_points < 0 {
+            errors.push("Temporary hit points cannot be negative".to_string());
+        }
+        if !(0..=3).contains(&self.death_saves.successes) || !(0..=3).contains(&self.death_saves.failures) {
+            errors.push("Death saves must be between 0 and 3".to_string());
+        }
+        if !(0..=MAX_EXHAUSTION).contains(&self.exhaustion) {
+            errors.push(format!("Exhaustion must be between 0 and {}", MAX_EXHAUSTION));
+        }
+        let dice = multiclass::hit_dice(&character.classes);
+        for (die, spent) in &self.hit_dice_spent {
+            let available = dice.iter().find(|(size, _)| size == die).map_or(0, |(_, count)| *count);
+            if *spent < 0 || *spent > available {
+                errors.push(format!("Only {} d{} hit dice can be spent", available, die));
+            }
+        }
+        errors
+    }
+}
+
+/// The character's hit point maximum right now, halved from the fourth level of exhaustion
+pub fn max_hit_points(character: &CharacterDetail) -> i32 {
+    if character.play_state.exhaustion >= 4 {
+        (character.hit_points / 2).max(1)
+    } else {
+        character.hit_points
+    }
+}
+
+/// Hit dice left to spend, `(die size, count)`, largest die first
+pub fn hit_dice_remaining(character: &CharacterDetail) -> Vec<(i32, i32)> {
+    multiclass::hit_dice(&character.classes)
+        .into_iter()
+        .map(|(die, count)| (die, (count - character.play_state.spent(die)).max(0)))
+        .collect()
+}
+
+/// Something that happens to a character during play
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum PlayAction {
+    Damage(i32),
+    Heal(i32),
+    SetTemporaryHitPoints(i32),
+    /// Roll a d20 death saving throw
+    RollDeathSave,
+    /// Mark a death save made at the table
+    DeathSave { success: bool },
+    ToggleCondition(Condition),
+    SetExhaustion(i32),
+    /// Spend one hit die of this size, healing the roll plus the Constitution modifier
+    SpendHitDie(i32),
+    /// Spend one use of the named limited-use feature
+    UseFeature(String),
+    /// Take back one spent use of the named feature
+    RestoreFeature(String),
+    /// Roll a d6 to see whether the named feature recharges
+    RollRecharge(String),
+    /// Short rests bring back pact slots and short-rest features
+    ShortRest,
+    /// Long rests bring back hit points, half the hit dice, spell slots and every rest or dawn feature
+    LongRest,
+    /// Dawn passing without a rest, for magic items
+    Dawn,
+}
+
+/// Apply `action` to `character`, rolling any dice with `roll` (which returns 1 to the given die
+/// size). Returns a line describing what happened for the play log.
+pub fn apply(character: &mut CharacterDetail, action: &PlayAction, roll: &mut dyn FnMut(i32) -> i32) -> Result<String, String> {
+    let name = character.name.clone();
+    let max = max_hit_points(character);
+    let level = character.level();
+    let hit_dice = multiclass::hit_dice(&character.classes);
+
+    if character.play_state.is_dead() && !matches!(action, PlayAction::ToggleCondition(_) | PlayAction::SetExhaustion(_)) {
+        return Err(format!("{} is dead", name));
+    }
+
+    if let PlayAction::UseFeature(feature) | PlayAction::RestoreFeature(feature) | PlayAction::RollRecharge(feature) = action {
+        return apply_to_feature(character, action, feature, roll);
+    }
+
+    let state = &mut character.play_state;
+    let current = state.current_hit_points(max);
+
+    let message = match *action {
+        PlayAction::Damage(amount) => {
+            if amount < 1 {
+                return Err("Damage must be at least 1".to_string());
+            }
+            let absorbed = amount.min(state.temporary_hit_points);
+            state.temporary_hit_points -= absorbed;
+            let remaining = amount - absorbed;
+            let mut message = if absorbed > 0 {
+                format!("{} takes {} damage ({} absorbed by temporary hit points)", name, amount, absorbed)
+            } else {
+                format!("{} takes {} damage", name, amount)
+            };
+
+            if current == 0 {
+                if remaining >= max {
+                    state.death_saves.failures = 3;
+                    message.push_str(" and dies");
+                } else if remaining > 0 {
+                    state.death_saves.failures = (state.death_saves.failures + 1).min(3);
+                    message.push_str(" while down: one death save failure");
+                }
+            } else if remaining >= current {
+                if remaining - current >= max {
+                    state.death_saves.failures = 3;
+                    message.push_str(" and is killed outright");
+                } else {
+                    message.push_str(" and falls unconscious");
+                }
+                state.set_hit_points(0, max);
+                state.add(Condition::Unconscious);
+                character.spellbook.concentration = None;
+            } else {
+                state.set_hit_points(current - remaining, max);
+                if remaining > 0 {
+                    if let Some(entry) = character.spellbook.concentrating_on() {
+                        message.push_str(&format!(
+                            "; DC {} Constitution save to keep concentrating on {}",
+                            (amount / 2).max(10),
+                            entry.name
+                        ));
+                    }
+                }
+            }
+            message
+        }
+        PlayAction::Heal(amount) => {
+            if amount < 1 {
+                return Err("Healing must be at least 1".to_string());
+            }
+            state.set_hit_points((current + amount).min(max), max);
+            if current == 0 {
+                state.revive();
+            }
+            format!("{} regains {} hit points", name, (current + amount).min(max) - current)
+        }
+        PlayAction::SetTemporaryHitPoints(amount) => {
+            if amount < 0 {
+                return Err("Temporary hit points cannot be negative".to_string());
+            }
+            // They don't stack; the player keeps whichever they choose
+            state.temporary_hit_points = amount;
+            format!("{} has {} temporary hit points", name, amount)
+        }
+        PlayAction::RollDeathSave => {
+            if current > 0 || state.death_saves.is_stable() {
+                return Err(format!("{} doesn't need to make death saves", name));
+            }
+            match roll(20) {
+                20 => {
+                    state.set_hit_points(1, max);
+                    state.revive();
+                    format!("{} rolls a natural 20 and regains 1 hit point", name)
+                }
+                1 => {
+                    state.death_saves.failures = (state.death_saves.failures + 2).min(3);
+                    format!("{} rolls a natural 1 on a death save: two failures", name)
+                }
+                result if result >= 10 => {
+                    state.death_saves.successes += 1;
+                    format!("{} rolls {} on a death save: success", name, result)
+                }
+                result => {
+                    state.death_saves.failures += 1;
+                    format!("{} rolls {} on a death save: failure", name, result)
+                }
+            }
+        }
+        PlayAction::DeathSave { success } => {
+            if current > 0 {
+                return Err(format!("{} doesn't need to make death saves", name));
+            }
+            if success {
+                state.death_saves.successes = (state.death_saves.successes + 1).min(3);
+                format!("{} succeeds on a death save", name)
+            } else {
+                state.death_saves.failures = (state.death_saves.failures + 1).min(3);
+                format!("{} fails a death save", name)
+            }
+        }
+        PlayAction::ToggleCondition(condition) => {
+            if state.has(condition) {
+                state.remove(condition);
+                format!("{} is no longer {}", name, condition.name().to_lowercase())
+            } else {
+                state.add(condition);
+                format!("{} is {}", name, condition.name().to_lowercase())
+            }
+        }
+        PlayAction::SetExhaustion(level) => {
+            if !(0..=MAX_EXHAUSTION).contains(&level) {
+                return Err(format!("Exhaustion must be between 0 and {}", MAX_EXHAUSTION));
+            }
+            state.exhaustion = level;
+            format!("{} has exhaustion level {} ({})", name, level, exhaustion_effect(level))
+        }
+        PlayAction::SpendHitDie(die) => {
+            let available = hit_dice.iter().find(|(size, _)| *size == die).map_or(0, |(_, count)| *count);
+            if state.spent(die) >= available {
+                return Err(format!("{} has no d{} hit dice left", name, die));
+            }
+            match state.hit_dice_spent.iter_mut().find(|(size, _)| *size == die) {
+                Some((_, spent)) => *spent += 1,
+                None => state.hit_dice_spent.push((die, 1)),
+            }
+            let result = roll(die);
+            let healed = (result + ability_modifier(character.constitution)).max(0);
+            state.set_hit_points((current + healed).min(max), max);
+            if current == 0 && healed > 0 {
+                state.revive();
+            }
+            format!("{} spends a d{} hit die, rolls {} and regains {} hit points", name, die, result, (current + healed).min(max) - current)
+        }
+        // Handled above, before the play state is borrowed
+        PlayAction::UseFeature(_) | PlayAction::RestoreFeature(_) | PlayAction::RollRecharge(_) => unreachable!(),
+        PlayAction::ShortRest => {
+            character.spellbook.short_rest();
+            recharge(character, &[Recharge::ShortRest]);
+            format!("{} takes a short rest", name)
+        }
+        PlayAction::LongRest => {
+            state.current_hit_points = None;
+            state.temporary_hit_points = 0;
+            state.revive();
+            state.exhaustion = (state.exhaustion - 1).max(0);
+
+            // Up to half the character's hit dice come back, at least one, largest dice first
+            let mut recovered = (level / 2).max(1);
+            state.hit_dice_spent.sort_by_key(|&(die, _)| std::cmp::Reverse(die));
+            for (_, spent) in state.hit_dice_spent.iter_mut() {
+                let back = recovered.min(*spent);
+                *spent -= back;
+                recovered -= back;
+            }
+            state.hit_dice_spent.retain(|(_, spent)| *spent > 0);
+
+            character.spellbook.long_rest();
+            recharge(character, &[Recharge::ShortRest, Recharge::LongRest, Recharge::Dawn]);
+            format!("{} takes a long rest", name)
+        }
+        PlayAction::Dawn => {
+            recharge(character, &[Recharge::Dawn]);
+            "Dawn breaks".to_string()
+        }
+    };
+
+    // Exhaustion can lower the maximum below the current total
+    let max = max_hit_points(character);
+    if let Some(current) = character.play_state.current_hit_points {
+        character.play_state.set_hit_points(current.min(max), max);
+    }
+
+    Ok(message)
+}
+
+// Spend, take back or try to recharge one use of a limited-use feature
+fn apply_to_feature(character: &mut CharacterDetail, action: &PlayAction, name: &str, roll: &mut dyn FnMut(i32) -> i32) -> Result<String, String> {
+    let index = character
+        .features
+        .iter()
+        .position(|feature| feature.name == name && feature.uses.is_some())
+        .ok_or_else(|| format!("{} doesn't have limited uses", name))?;
+    let (remaining, max) = character.features[index].remaining(character).unwrap_or_default();
+    let Some(uses) = character.features[index].uses.as_mut() else {
+        return Err(format!("{} doesn't have limited uses", name));
+    };
+
+    match action {
+        PlayAction::UseFeature(_) if remaining == 0 => Err(format!("No uses of {} left", name)),
+        PlayAction::UseFeature(_) => {
+            uses.used = max - remaining + 1;
+            Ok(format!("{} uses {} ({} of {} left)", character.name, name, remaining - 1, max))
+        }
+        PlayAction::RestoreFeature(_) => {
+            uses.used = (max - remaining - 1).max(0);
+            Ok(format!("{} regains a use of {}", character.name, name))
+        }
+        PlayAction::RollRecharge(_) => {
+            let Recharge::D6 { min } = uses.recharge else {
+                return Err(format!("{} doesn't recharge on a roll", name));
+            };
+            let result = roll(6);
+            if result >= min {
+                uses.used = 0;
+                Ok(format!("{} rolls {}: {} recharges", character.name, result, name))
+            } else {
+                Ok(format!("{} rolls {}: {} doesn't recharge", character.name, result, name))
+            }
+        }
+        _ => Err("Not a feature action".to_string()),
+    }
+}
+
+// Bring back every use of the features that recharge on any of `when`
+fn recharge(character: &mut CharacterDetail, when: &[Recharge]) {
+    for uses in character.features.iter_mut().filter_map(|feature| feature.uses.as_mut()) {
+        if when.contains(&uses.recharge) {
+            uses.used = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::features::{Feature, LimitedUse, MaxUses};
+    use crate::rules::spellbook::SpellbookEntry;
+    use crate::rules::tests::character;
+    use crate::rules::{Class, ClassLevel};
+
+    /// A 5th-level Fighter with 44 hit points and CON +2
+    fn fighter() -> CharacterDetail {
+        CharacterDetail { constitution: 14, hit_points: 44, ..character(vec![ClassLevel::new(Class::Fighter, 5)]) }
+    }
+
+    fn wounded(current: i32) -> CharacterDetail {
+        let mut fighter = fighter();
+        fighter.play_state.current_hit_points = Some(current);
+        fighter
+    }
+
+    fn apply_rolling(character: &mut CharacterDetail, action: PlayAction, result: i32) -> Result<String, String> {
+        apply(character, &action, &mut |_| result)
+    }
+
+    #[test]
+    fn damage_and_healing() {
+        let mut fighter = fighter();
+        fighter.play_state.temporary_hit_points = 5;
+        let message = apply_rolling(&mut fighter, PlayAction::Damage(8), 1).unwrap();
+        assert_eq!(message, "Tester takes 8 damage (5 absorbed by temporary hit points)");
+        assert_eq!((fighter.play_state.current_hit_points, fighter.play_state.temporary_hit_points), (Some(41), 0));
+
+        apply_rolling(&mut fighter, PlayAction::Heal(10), 1).unwrap();
+        assert_eq!(fighter.play_state.current_hit_points, None);
+        assert!(apply_rolling(&mut fighter, PlayAction::Damage(0), 1).is_err());
+        assert!(apply_rolling(&mut fighter, PlayAction::Heal(0), 1).is_err());
+    }
+
+    #[test]
+    fn dropping_to_zero() {
+        let table = [
+            (wounded(10), 10, "Tester takes 10 damage and falls unconscious", 0),
+            (wounded(10), 53, "Tester takes 53 damage and falls unconscious", 0),
+            (wounded(10), 54, "Tester takes 54 damage and is killed outright", 3),
+            (wounded(0), 5, "Tester takes 5 damage while down: one death save failure", 1),
+            (wounded(0), 44, "Tester takes 44 damage and dies", 3),
+        ];
+        for (mut fighter, damage, message, failures) in table {
+            assert_eq!(apply_rolling(&mut fighter, PlayAction::Damage(damage), 1).unwrap(), message);
+            assert_eq!(fighter.play_state.current_hit_points(44), 0);
+            assert_eq!(fighter.play_state.death_saves.failures, failures, "{}", message);
+        }
+    }
+
+    #[test]
+    fn concentration_checks_and_breaks() {
+        let mut fighter = wounded(30);
+        fighter.spellbook.entries.push(SpellbookEntry {
+            spell: "bless".to_string(),
+            name: "Bless".to_string(),
+            level: 1,
+            ritual: false,
+            concentration: true,
+            class: None,
+            prepared: false,
+            always_prepared: false,
+        });
+        fighter.spellbook.concentration = Some("bless".to_string());
+
+        let message = apply_rolling(&mut fighter, PlayAction::Damage(24), 1).unwrap();
+        assert!(message.ends_with("DC 12 Constitution save to keep concentrating on Bless"), "{}", message);
+        apply_rolling(&mut fighter, PlayAction::Damage(6), 1).unwrap();
+        assert!(fighter.play_state.has(Condition::Unconscious));
+        assert_eq!(fighter.spellbook.concentration, None);
+    }
+
+    #[test]
+    fn death_saves() {
+        let table = [(20, 0, 0, Some(1)), (1, 0, 2, Some(0)), (10, 1, 0, Some(0)), (9, 0, 1, Some(0))];
+        for (result, successes, failures, current) in table {
+            let mut fighter = wounded(0);
+            fighter.play_state.conditions.push(Condition::Unconscious);
+            apply_rolling(&mut fighter, PlayAction::RollDeathSave, result).unwrap();
+            assert_eq!(fighter.play_state.death_saves, DeathSaves { successes, failures }, "rolled {}", result);
+            assert_eq!(fighter.play_state.current_hit_points, current);
+            assert_eq!(fighter.play_state.has(Condition::Unconscious), result != 20);
+        }
+
+        let mut fighter = wounded(0);
+        for _ in 0..3 {
+            apply_rolling(&mut fighter, PlayAction::DeathSave { success: false }, 1).unwrap();
+        }
+        assert!(fighter.play_state.is_dead());
+        assert_eq!(apply_rolling(&mut fighter, PlayAction::Heal(5), 1), Err("Tester is dead".to_string()));
+        assert!(apply_rolling(&mut fighter, PlayAction::ToggleCondition(Condition::Prone), 1).is_ok());
+        assert!(apply_rolling(&mut wounded(5), PlayAction::RollDeathSave, 20).is_err());
+    }
+
+    #[test]
+    fn hit_dice_and_rests() {
+        let mut fighter = wounded(10);
+        let message = apply_rolling(&mut fighter, PlayAction::SpendHitDie(10), 6).unwrap();
+        assert_eq!(message, "Tester spends a d10 hit die, rolls 6 and regains 8 hit points");
+        assert_eq!(fighter.play_state.current_hit_points, Some(18));
+        for _ in 0..4 {
+            apply_rolling(&mut fighter, PlayAction::SpendHitDie(10), 1).unwrap();
+        }
+        assert_eq!(apply_rolling(&mut fighter, PlayAction::SpendHitDie(10), 1), Err("Tester has no d10 hit dice left".to_string()));
+        assert!(apply_rolling(&mut fighter, PlayAction::SpendHitDie(8), 1).is_err());
+
+        fighter.play_state.exhaustion = 2;
+        fighter.play_state.temporary_hit_points = 3;
+        apply_rolling(&mut fighter, PlayAction::LongRest, 1).unwrap();
+        // Half of five hit dice, rounded down, come back
+        assert_eq!(hit_dice_remaining(&fighter), vec![(10, 2)]);
+        assert_eq!(fighter.play_state.current_hit_points, None);
+        assert_eq!((fighter.play_state.temporary_hit_points, fighter.play_state.exhaustion), (0, 1));
+    }
+
+    #[test]
+    fn exhaustion_halves_the_maximum() {
+        let mut fighter = wounded(40);
+        assert!(apply_rolling(&mut fighter, PlayAction::SetExhaustion(7), 1).is_err());
+        apply_rolling(&mut fighter, PlayAction::SetExhaustion(4), 1).unwrap();
+        assert_eq!(max_hit_points(&fighter), 22);
+        assert_eq!(fighter.play_state.current_hit_points, None);
+        apply_rolling(&mut fighter, PlayAction::SetExhaustion(6), 1).unwrap();
+        assert!(fighter.play_state.is_dead());
+    }
+
+    #[test]
+    fn limited_use_features() {
+        let mut fighter = fighter();
+        let limited = |name: &str, recharge| Feature {
+            name: name.to_string(),
+            description: String::new(),
+            uses: Some(LimitedUse { max: MaxUses::Fixed { uses: 2 }, recharge, used: 0 }),
+        };
+        fighter.features = vec![limited("Second Wind", Recharge::ShortRest), limited("Breath", Recharge::D6 { min: 5 }), Feature::named("Extra Attack")];
+
+        let use_feature = |name: &str| PlayAction::UseFeature(name.to_string());
+        assert_eq!(apply_rolling(&mut fighter, use_feature("Second Wind"), 1).unwrap(), "Tester uses Second Wind (1 of 2 left)");
+        apply_rolling(&mut fighter, use_feature("Second Wind"), 1).unwrap();
+        assert_eq!(apply_rolling(&mut fighter, use_feature("Second Wind"), 1), Err("No uses of Second Wind left".to_string()));
+        apply_rolling(&mut fighter, PlayAction::RestoreFeature("Second Wind".to_string()), 1).unwrap();
+        assert_eq!(fighter.features[0].remaining(&fighter.clone()), Some((1, 2)));
+        assert!(apply_rolling(&mut fighter, use_feature("Extra Attack"), 1).is_err());
+
+        apply_rolling(&mut fighter, use_feature("Breath"), 1).unwrap();
+        let recharge = PlayAction::RollRecharge("Breath".to_string());
+        assert_eq!(apply_rolling(&mut fighter, recharge.clone(), 4).unwrap(), "Tester rolls 4: Breath doesn't recharge");
+        assert_eq!(apply_rolling(&mut fighter, recharge, 5).unwrap(), "Tester rolls 5: Breath recharges");
+        assert!(apply_rolling(&mut fighter, PlayAction::RollRecharge("Second Wind".to_string()), 6).is_err());
+
+        apply_rolling(&mut fighter, PlayAction::ShortRest, 1).unwrap();
+        assert_eq!(fighter.features[0].uses.as_ref().map(|uses| uses.used), Some(0));
+    }
+
+    #[test]
+    fn validation_catches_impossible_states() {
+        let table = [
+            (PlayState { current_hit_points: Some(-1), ..PlayState::default() }, "Current hit points cannot be negative".to_string()),
+            (PlayState { temporary_hit_points: -1, ..PlayState::default() }, "Temporary hit points cannot be negative".to_string()),
+            (PlayState { death_saves: DeathSaves { successes: 4, failures: 0 }, ..PlayState::default() }, "Death saves must be between 0 and 3".to_string()),
+            (PlayState { exhaustion: 7, ..PlayState::default() }, format!("Exhaustion must be between 0 and {}", MAX_EXHAUSTION)),
+            (PlayState { hit_dice_spent: vec![(10, 6)], ..PlayState::default() }, "Only 5 d10 hit dice can be spent".to_string()),
+            (PlayState { hit_dice_spent: vec![(8, 1)], ..PlayState::default() }, "Only 0 d8 hit dice can be spent".to_string()),
+        ];
+        for (state, error) in table {
+            assert_eq!(state.validate(&fighter()), vec![error]);
+        }
+        assert!(PlayState { current_hit_points: Some(60), ..PlayState::default() }.validate(&fighter()).is_empty());
+    }
+}
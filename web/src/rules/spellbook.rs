@@ -0,0 +1,436 @@
+//! A character's spells: which they know and which they've prepared, the slots they've spent,
+//! and the one spell they're concentrating on. Each entry points at a spell in the shared
+//! catalog by its slug and keeps a copy of the few details the rules need.
+
+use serde::{Deserialize, Serialize};
+use super::{ability_modifier, multiclass, Ability, Class, ClassLevel};
+
+/// One spell a character knows, through one of their classes
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpellbookEntry {
+    /// Slug of the spell's catalog entry
+    pub spell: String,
+    pub name: String,
+    /// 0 for cantrips
+    pub level: i32,
+    #[serde(default)]
+    pub ritual: bool,
+    #[serde(default)]
+    pub concentration: bool,
+    /// The class the spell was learned through, which decides the ability it's cast with and
+    /// whose preparation limit it counts against
+    #[serde(default)]
+    pub class: Option<Class>,
+    /// Only meaningful for classes that prepare spells; everyone else always has their known spells ready
+    #[serde(default)]
+    pub prepared: bool,
+    /// Domain, oath and circle spells are always prepared and don't count against the limit
+    #[serde(default)]
+    pub always_prepared: bool,
+}
+
+impl SpellbookEntry {
+    pub fn is_cantrip(&self) -> bool {
+        self.level == 0
+    }
+
+    /// Whether the spell can be cast right now (given a slot), which for preparing classes
+    /// means it has to be prepared
+    pub fn is_ready(&self) -> bool {
+        self.is_cantrip() || self.always_prepared || !self.class.is_some_and(prepares_spells) || self.prepared
+    }
+}
+
+/// Whether a class picks its spells for the day after a long rest rather than knowing a fixed list
+pub fn prepares_spells(class: Class) -> bool {
+    matches!(class, Class::Cleric | Class::Druid | Class::Paladin | Class::Wizard)
+}
+
+/// How many spells a preparing class can have prepared: the casting ability modifier plus the
+/// class level (half of it for Paladins), at least one
+pub fn preparation_limit(class_level: &ClassLevel, score: impl Fn(Ability) -> i32) -> Option<i32> {
+    if !prepares_spells(class_level.class) {
+        return None;
+    }
+    let ability = class_level.spellcasting_ability()?;
+    let levels = if class_level.class == Class::Paladin { class_level.level / 2 } else { class_level.level };
+    Some((ability_modifier(score(ability)) + levels).max(1))
+}
+
+/// The highest spell level `class_level` alone has slots for, which is the highest it can learn
+pub fn max_spell_level(class_level: &ClassLevel) -> i32 {
+    let classes = [*class_level];
+    let shared = multiclass::spell_slots(&classes).iter().rposition(|count| *count > 0).map_or(0, |index| index as i32 + 1);
+    let pact = multiclass::pact_slots(&classes).map_or(0, |pact| pact.slot_level);
+    shared.max(pact)
+}
+
+/// Where a spell is being cast from
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CastWith {
+    /// A shared spell slot of this level
+    Slot(i32),
+    /// A warlock pact magic slot, always at the pact slot level
+    PactSlot,
+    /// As a ritual, taking ten minutes longer and no slot
+    Ritual,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Spellbook {
+    #[serde(default)]
+    pub entries: Vec<SpellbookEntry>,
+    /// Shared slots spent since the last long rest, by spell level - 1
+    #[serde(default)]
+    pub expended: [i32; 9],
+    /// Pact magic slots spent since the last short or long rest
+    #[serde(default)]
+    pub pact_expended: i32,
+    /// Slug of the spell being concentrated on, if any
+    #[serde(default)]
+    pub concentration: Option<String>,
+}
+
+impl Spellbook {
+    /// One known spell per name, e.g. a legacy list of spell names with no catalog details
+    pub fn from_names<S: AsRef<str>>(names: &[S]) -> Self {
+        let entries = names
+            .iter()
+            .map(|name| name.as_ref().trim())
+            .filter(|name| !name.is_empty())
+            .map(|name| SpellbookEntry {
+                spell: crate::api::character::slugify(name),
+                name: name.to_string(),
+                level: 1,
+                ritual: false,
+                concentration: false,
+                class: None,
+                prepared: false,
+                always_prepared: false,
+            })
+            .collect();
+        Self { entries, ..Self::default() }
+    }
+
+    pub fn entry(&self, spell: &str) -> Option<&SpellbookEntry> {
+        self.entries.iter().find(|entry| entry.spell == spell)
+    }
+
+    pub fn knows(&self, spell: &str) -> bool {
+        self.entry(spell).is_some()
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.entries.iter().map(|entry| entry.name.clone()).collect()
+    }
+
+    pub fn learn(&mut self, entry: SpellbookEntry) -> Result<(), String> {
+        if self.knows(&entry.spell) {
+            return Err(format!("{} is already in the spellbook", entry.name));
+        }
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    pub fn forget(&mut self, spell: &str) {
+        self.entries.retain(|entry| entry.spell != spell);
+        if self.concentration.as_deref() == Some(spell) {
+            self.concentration = None;
+        }
+    }
+
+    /// Spells counting against `class`'s preparation limit
+    pub fn prepared_count(&self, class: Class) -> i32 {
+        self.entries
+            .iter()
+            .filter(|entry| entry.class == Some(class) && entry.prepared && !entry.always_prepared && !entry.is_cantrip())
+            .count() as i32
+    }
+
+    pub fn set_prepared(&mut self, spell: &str, prepared: bool) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.spell == spell) {
+            entry.prepared = prepared;
+        }
+    }
+
+    /// Shared slots left at `level` (1-9) out of `slots`, the character's maximum
+    pub fn slots_remaining(&self, slots: &[i32; 9], level: i32) -> i32 {
+        match slot_index(level) {
+            Some(index) => (slots[index] - self.expended[index]).max(0),
+            None => 0,
+        }
+    }
+
+    pub fn expend_slot(&mut self, slots: &[i32; 9], level: i32) -> Result<(), String> {
+        let index = slot_index(level).ok_or_else(|| format!("There are no level {} spell slots", level))?;
+        if self.expended[index] >= slots[index] {
+            return Err(format!("No level {} spell slots left", level));
+        }
+        self.expended[index] += 1;
+        Ok(())
+    }
+
+    pub fn recover_slot(&mut self, level: i32) {
+        if let Some(index) = slot_index(level) {
+            self.expended[index] = (self.expended[index] - 1).max(0);
+        }
+    }
+
+    pub fn expend_pact_slot(&mut self, pact: Option<multiclass::PactSlots>) -> Result<(), String> {
+        let pact = pact.ok_or_else(|| "No pact magic slots".to_string())?;
+        if self.pact_expended >= pact.count {
+            return Err("No pact magic slots left".to_string());
+        }
+        self.pact_expended += 1;
+        Ok(())
+    }
+
+    pub fn recover_pact_slot(&mut self) {
+        self.pact_expended = (self.pact_expended - 1).max(0);
+    }
+
+    /// A short rest brings back pact magic slots
+    pub fn short_rest(&mut self) {
+        self.pact_expended = 0;
+    }
+
+    /// A long rest brings back every slot and ends concentration
+    pub fn long_rest(&mut self) {
+        self.expended = [0; 9];
+        self.pact_expended = 0;
+        self.concentration = None;
+    }
+
+    /// Cast a known spell, spending the slot and starting concentration if it needs it.
+    /// Starting a new concentration spell ends the old one.
+    pub fn cast(&mut self, spell: &str, with: CastWith, classes: &[ClassLevel]) -> Result<(), String> {
+        let entry = self.entry(spell).cloned().ok_or_else(|| "That spell isn't in the spellbook".to_string())?;
+        if !entry.is_ready() {
+            return Err(format!("{} isn't prepared", entry.name));
+        }
+        if !entry.is_cantrip() {
+            match with {
+                CastWith::Ritual if !entry.ritual => return Err(format!("{} can't be cast as a ritual", entry.name)),
+                CastWith::Ritual => {}
+                CastWith::Slot(level) if level < entry.level => {
+                    return Err(format!("{} needs a slot of level {} or higher", entry.name, entry.level))
+                }
+                CastWith::Slot(level) => self.expend_slot(&multiclass::spell_slots(classes), level)?,
+                CastWith::PactSlot => {
+                    let pact = multiclass::pact_slots(classes);
+                    if pact.is_some_and(|pact| pact.slot_level < entry.level) {
+                        return Err(format!("{} is higher level than your pact slots", entry.name));
+                    }
+                    self.expend_pact_slot(pact)?;
+                }
+            }
+        }
+        if entry.concentration {
+            self.concentration = Some(entry.spell);
+        }
+        Ok(())
+    }
+
+    pub fn concentrating_on(&self) -> Option<&SpellbookEntry> {
+        self.concentration.as_deref().and_then(|spell| self.entry(spell))
+    }
+
+    pub fn validate(&self, classes: &[ClassLevel], score: impl Fn(Ability) -> i32) -> Vec<String> {
+        let mut errors = vec![];
+
+        for (index, entry) in self.entries.iter().enumerate() {
+            if self.entries[..index].iter().any(|other| other.spell == entry.spell) {
+                errors.push(format!("{} is in the spellbook twice", entry.name));
+            }
+            if !(0..=9).contains(&entry.level) {
+                errors.push(format!("{} has an impossible spell level", entry.name));
+            }
+            if let Some(class) = entry.class {
+                if !classes.iter().any(|class_level| class_level.class == class) {
+                    errors.push(format!("{} is listed under {}, which the character has no levels in", entry.name, class));
+                }
+            }
+        }
+
+        for class_level in classes {
+            if let Some(limit) = preparation_limit(class_level, &score) {
+                let prepared = self.prepared_count(class_level.class);
+                if prepared > limit {
+                    errors.push(format!("{} can prepare {} spells, not {}", class_level.class, limit, prepared));
+                }
+            }
+        }
+
+        let slots = multiclass::spell_slots(classes);
+        if self.expended.iter().zip(slots).any(|(expended, slots)| *expended < 0 || *expended > slots) {
+            errors.push("More spell slots are marked as used than the character has".to_string());
+        }
+        let pact_count = multiclass::pact_slots(classes).map_or(0, |pact| pact.count);
+        if !(0..=pact_count).contains(&self.pact_expended) {
+            errors.push("More pact slots are marked as used than the character has".to_string());
+        }
+
+        if let Some(spell) = &self.concentration {
+            match self.entry(spell) {
+                Some(entry) if entry.concentration => {}
+                Some(entry) => errors.push(format!("{} doesn't require concentration", entry.name)),
+                None => errors.push("The concentration spell isn't in the spellbook".to_string()),
+            }
+        }
+
+        errors
+    }
+}
+
+fn slot_index(level: i32) -> Option<usize> {
+    (1..=9).contains(&level).then(|| (level - 1) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::Subclass;
+
+    fn entry(spell: &str, level: i32, class: Class) -> SpellbookEntry {
+        SpellbookEntry {
+            spell: spell.to_string(),
+            name: spell.to_string(),
+            level,
+            ritual: false,
+            concentration: false,
+            class: Some(class),
+            prepared: false,
+            always_prepared: false,
+        }
+    }
+
+    fn wizard_book() -> Spellbook {
+        Spellbook {
+            entries: vec![
+                SpellbookEntry { prepared: true, ..entry("fire-bolt", 0, Class::Wizard) },
+                SpellbookEntry { prepared: true, ritual: true, ..entry("detect-magic", 1, Class::Wizard) },
+                SpellbookEntry { ritual: true, ..entry("alarm", 1, Class::Wizard) },
+                SpellbookEntry { prepared: true, concentration: true, ..entry("web", 2, Class::Wizard) },
+                SpellbookEntry { prepared: true, concentration: true, ..entry("fly", 3, Class::Wizard) },
+                SpellbookEntry { prepared: true, ..entry("fireball", 3, Class::Wizard) },
+            ],
+            ..Spellbook::default()
+        }
+    }
+
+    #[test]
+    fn preparation_limits() {
+        let table = [
+            (ClassLevel::new(Class::Wizard, 5), 16, Some(3 + 5)),
+            (ClassLevel::new(Class::Paladin, 5), 14, Some(2 + 2)),
+            (ClassLevel::new(Class::Cleric, 1), 8, Some(1)),
+            (ClassLevel::new(Class::Sorcerer, 5), 18, None),
+        ];
+        for (class_level, score, limit) in table {
+            assert_eq!(preparation_limit(&class_level, |_| score), limit, "{}", class_level);
+        }
+    }
+
+    #[test]
+    fn highest_learnable_spell_level() {
+        let table = [
+            (ClassLevel::new(Class::Wizard, 5), 3),
+            (ClassLevel::new(Class::Wizard, 17), 9),
+            (ClassLevel::new(Class::Paladin, 5), 2),
+            (ClassLevel::new(Class::Warlock, 5), 3),
+            (ClassLevel::new(Class::Fighter, 7), 0),
+            (ClassLevel { subclass: Some(Subclass::EldritchKnight), ..ClassLevel::new(Class::Fighter, 7) }, 2),
+        ];
+        for (class_level, level) in table {
+            assert_eq!(max_spell_level(&class_level), level, "{}", class_level);
+        }
+    }
+
+    #[test]
+    fn casting_spends_the_right_slot() {
+        let classes = [ClassLevel::new(Class::Wizard, 5)];
+        let mut book = wizard_book();
+
+        book.cast("fire-bolt", CastWith::Slot(1), &classes).unwrap();
+        book.cast("detect-magic", CastWith::Ritual, &classes).unwrap();
+        assert_eq!(book.expended, [0; 9]);
+
+        book.cast("web", CastWith::Slot(3), &classes).unwrap();
+        book.cast("fireball", CastWith::Slot(3), &classes).unwrap();
+        assert_eq!(book.slots_remaining(&multiclass::spell_slots(&classes), 3), 0);
+        assert_eq!(book.cast("fireball", CastWith::Slot(3), &classes), Err("No level 3 spell slots left".to_string()));
+        assert_eq!(book.cast("fireball", CastWith::Slot(2), &classes), Err("fireball needs a slot of level 3 or higher".to_string()));
+        assert_eq!(book.cast("fireball", CastWith::Ritual, &classes), Err("fireball can't be cast as a ritual".to_string()));
+        assert_eq!(book.cast("fireball", CastWith::PactSlot, &classes), Err("No pact magic slots".to_string()));
+        assert_eq!(book.cast("alarm", CastWith::Slot(1), &classes), Err("alarm isn't prepared".to_string()));
+        assert!(book.cast("wish", CastWith::Slot(9), &classes).is_err());
+
+        book.recover_slot(3);
+        assert_eq!(book.slots_remaining(&multiclass::spell_slots(&classes), 3), 1);
+    }
+
+    #[test]
+    fn casting_with_pact_slots() {
+        let classes = [ClassLevel::new(Class::Warlock, 5)];
+        let mut book = Spellbook {
+            entries: vec![entry("hex", 1, Class::Warlock), entry("counterspell", 3, Class::Warlock), entry("banishment", 4, Class::Warlock)],
+            ..Spellbook::default()
+        };
+        book.cast("hex", CastWith::PactSlot, &classes).unwrap();
+        book.cast("counterspell", CastWith::PactSlot, &classes).unwrap();
+        assert_eq!(book.cast("hex", CastWith::PactSlot, &classes), Err("No pact magic slots left".to_string()));
+        assert!(book.cast("banishment", CastWith::PactSlot, &classes).is_err());
+        book.short_rest();
+        assert_eq!(book.pact_expended, 0);
+    }
+
+    #[test]
+    fn one_concentration_spell_at_a_time() {
+        let classes = [ClassLevel::new(Class::Wizard, 5)];
+        let mut book = wizard_book();
+        book.cast("web", CastWith::Slot(2), &classes).unwrap();
+        assert_eq!(book.concentrating_on().map(|entry| entry.spell.as_str()), Some("web"));
+        book.cast("fireball", CastWith::Slot(3), &classes).unwrap();
+        assert_eq!(book.concentration.as_deref(), Some("web"));
+        book.cast("fly", CastWith::Slot(3), &classes).unwrap();
+        assert_eq!(book.concentration.as_deref(), Some("fly"));
+
+        book.forget("fly");
+        assert_eq!(book.concentration, None);
+        book.cast("web", CastWith::Slot(2), &classes).unwrap();
+        book.long_rest();
+        assert_eq!((book.expended, book.concentration.clone()), ([0; 9], None));
+    }
+
+    #[test]
+    fn learning_and_preparing() {
+        let mut book = wizard_book();
+        assert_eq!(book.learn(entry("web", 2, Class::Wizard)), Err("web is already in the spellbook".to_string()));
+        book.learn(entry("shield", 1, Class::Wizard)).unwrap();
+        // Cantrips and always-prepared spells don't count
+        book.learn(SpellbookEntry { always_prepared: true, prepared: true, ..entry("magic-missile", 1, Class::Wizard) }).unwrap();
+        assert_eq!(book.prepared_count(Class::Wizard), 4);
+        book.set_prepared("shield", true);
+        assert_eq!(book.prepared_count(Class::Wizard), 5);
+    }
+
+    #[test]
+    fn validation_catches_broken_spellbooks() {
+        let classes = [ClassLevel::new(Class::Wizard, 1)];
+        let table = [
+            (Spellbook { entries: vec![entry("shield", 1, Class::Wizard), entry("shield", 1, Class::Wizard)], ..Spellbook::default() }, "shield is in the spellbook twice"),
+            (Spellbook { entries: vec![entry("shield", 10, Class::Wizard)], ..Spellbook::default() }, "shield has an impossible spell level"),
+            (Spellbook { entries: vec![entry("bless", 1, Class::Cleric)], ..Spellbook::default() }, "bless is listed under Cleric, which the character has no levels in"),
+            (wizard_book(), "Wizard can prepare 1 spells, not 4"),
+            (Spellbook { expended: [3, 0, 0, 0, 0, 0, 0, 0, 0], ..Spellbook::default() }, "More spell slots are marked as used than the character has"),
+            (Spellbook { pact_expended: 1, ..Spellbook::default() }, "More pact slots are marked as used than the character has"),
+            (Spellbook { concentration: Some("web".to_string()), ..Spellbook::default() }, "The concentration spell isn't in the spellbook"),
+            (Spellbook { concentration: Some("fireball".to_string()), ..wizard_book() }, "fireball doesn't require concentration"),
+        ];
+        for (book, error) in table {
+            let errors = book.validate(&classes, |_| 10);
+            assert!(errors.contains(&error.to_string()), "{:?} should contain {:?}", errors, error);
+        }
+        assert!(Spellbook::from_names(&["Shield", ""]).validate(&classes, |_| 10).is_empty());
+    }
+}
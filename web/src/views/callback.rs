@@ -1,34 +1,55 @@
 #[allow(unused_imports)]
 use crate::api::auth::set_session;
 #[allow(unused_imports)]
-use crate::views::Protected;
+use crate::views::routes::GuardContext;
 use dioxus::prelude::*;
 use crate::Route;
+#[allow(unused_imports)]
 use std::collections::HashMap;
 #[cfg(target_arch = "wasm32")]
 use web_sys::window;
 
 #[component]
 pub fn Callback() -> Element {
-    spawn(async move {
-        client! {
-            #[cfg(target_arch = "wasm32")]
-            {
-                let hash = window().unwrap().location().hash().unwrap();
-                let params_parsed: HashMap<String, String> = serde_urlencoded::from_str(hash
-                    .strip_prefix("#").unwrap()).unwrap();
-                
-                if let (Some(access_token), Some(refresh_token)) = (params_parsed.get("access_token"), 
-                    params_parsed.get("refresh_token")) {
-                    set_session(
-                        access_token.to_owned(),
-                        refresh_token.to_owned(),
-                    ).await;
-                    let nav = navigator();
-                    nav.replace(Route::Protected {});
+    #[allow(unused_mut)]
+    let mut error = use_signal::<Option<String>>(|| None);
+
+    use_effect(move || {
+        spawn(async move {
+            client! {
+                #[cfg(target_arch = "wasm32")]
+                {
+                    // The OAuth provider hands the tokens back in the URL hash
+                    let hash = window().and_then(|w| w.location().hash().ok()).unwrap_or_default();
+                    let params_parsed: HashMap<String, String> =
+                        serde_urlencoded::from_str(hash.trim_start_matches('#')).unwrap_or_default();
+
+                    if let Some(description) = params_parsed.get("error_description") {
+                        error.set(Some(description.to_owned()));
+                    } else if let (Some(access_token), Some(refresh_token)) = (params_parsed.get("access_token"),
+                        params_parsed.get("refresh_token")) {
+                        match set_session(access_token.to_owned(), refresh_token.to_owned()).await {
+                            Ok(_) => GuardContext::redirect_next_or_home(),
+                            Err(e) => error.set(Some(format!("Sign-in failed: {}", e))),
+                        }
+                    } else {
+                        navigator().replace(Route::Login {});
+                    }
                 }
             }
-        }
+        });
     });
-    rsx! {}
-}
\ No newline at end of file
+
+    rsx! {
+        div { class: "max-w-lg mx-auto py-8 text-center",
+            if let Some(err) = error.read().as_ref() {
+                div { class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6", role: "alert",
+                    p { "{err}" }
+                }
+                Link { to: Route::Login {}, class: "text-indigo-600 hover:text-indigo-500", "Back to sign in" }
+            } else {
+                p { class: "text-gray-600", "Signing you in..." }
+            }
+        }
+    }
+}
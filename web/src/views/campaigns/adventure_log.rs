@@ -1,54 +1,27 @@
 use dioxus::prelude::*;
-use serde::{Deserialize, Serialize};
-
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct Adventure {
-    id: u32,
-    title: String,
-    date_played: String,
-    campaign_name: String,
-    character_used: String,
-    character_level: u8,
-    summary: String,
-    rewards: Vec<String>,
-    notable_events: Vec<String>,
-    dm_notes: Option<String>,
-}
+use crate::api::campaign::{get_adventures, Adventure};
+use super::{use_active_campaign, use_is_dm};
 
 #[component]
-pub fn AdventureLog() -> Element {
-    let adventures = use_signal(|| vec![
-        Adventure {
-            id: 1,
-            title: "The Lost Mine of Phandelver".to_string(),
-            date_played: "2023-10-15".to_string(),
-            campaign_name: "Dragon's Hoard".to_string(),
-            character_used: "Elandril Moonwhisper".to_string(),
-            character_level: 3,
-            summary: "The party discovered the entrance to Wave Echo Cave and battled a group of undead guardians.".to_string(),
-            rewards: vec!["+1 Longbow".to_string(), "250 GP".to_string()],
-            notable_events: vec![
-                "Found the map to Wave Echo Cave".to_string(),
-                "Thorin nearly died to a wraith".to_string()
-            ],
-            dm_notes: Some("Party is getting stronger - need to increase difficulty".to_string()),
-        },
-        Adventure {
-            id: 2,
-            title: "The Forge of Fury".to_string(),
-            date_played: "2023-11-02".to_string(),
-            campaign_name: "Dragon's Hoard".to_string(),
-            character_used: "Thorin Ironfist".to_string(),
-            character_level: 5,
-            summary: "Descended into the ancient dwarven stronghold to recover the legendary hammer of Durgeddin.".to_string(),
-            rewards: vec!["Hammer of Durgeddin".to_string(), "Dwarven Plate Mail".to_string()],
-            notable_events: vec![
-                "Defeated the young black dragon".to_string(),
-                "Unlocked the secret forge".to_string()
-            ],
-            dm_notes: None,
-        },
-    ]);
+pub fn AdventureLog(campaign_slug: String) -> Element {
+    let (campaign, campaign_error) = use_active_campaign();
+    let is_dm = use_is_dm();
+    let mut adventures = use_signal::<Vec<Adventure>>(|| vec![]);
+    let mut error = use_signal::<Option<String>>(|| None);
+
+    use_effect(move || {
+        let Some(campaign_id) = campaign.read().as_ref().map(|c| c.id) else {
+            return;
+        };
+        spawn(async move {
+            match get_adventures(campaign_id).await {
+                Ok(fetched) => adventures.set(fetched),
+                Err(e) => error.set(Some(format!("Failed to load adventures: {}", e))),
+            }
+        });
+    });
+
+    let campaign_name = campaign.read().as_ref().map(|c| c.name.clone()).unwrap_or_default();
 
     let mut selected_adventure = use_signal(|| None);
 
@@ -62,6 +35,14 @@ pub fn AdventureLog() -> Element {
                 }
             }
 
+            if let Some(err) = campaign_error.read().as_ref().or(error.read().as_ref()) {
+                div {
+                    class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6",
+                    role: "alert",
+                    p { "{err}" }
+                }
+            }
+
             // Main Content
             div { class: "grid grid-cols-1 lg:grid-cols-3 gap-6",
                 // Adventure List
@@ -86,7 +67,7 @@ pub fn AdventureLog() -> Element {
                                             
                                             div { class: "font-medium text-amber-900", "{adventure.title}" }
                                             div { class: "text-sm text-gray-600", 
-                                                "{adventure.date_played} • {adventure.campaign_name}"
+                                                "{adventure.date_played} • {campaign_name}"
                                             }
                                             div { class: "text-sm mt-1", 
                                                 span { class: "font-medium", "Character: " }
@@ -113,7 +94,7 @@ pub fn AdventureLog() -> Element {
                                     div {
                                         h2 { class: "text-2xl font-bold text-gray-800", "{adventure.title}" }
                                         p { class: "text-gray-600", 
-                                            "{adventure.campaign_name} • {adventure.date_played}"
+                                            "{campaign_name} • {adventure.date_played}"
                                         }
                                     }
                                     div { class: "bg-amber-100 text-amber-800 px-3 py-1 rounded-full text-sm",
@@ -158,7 +139,7 @@ pub fn AdventureLog() -> Element {
                             }
 
                             // DM Notes
-                            if let Some(notes) = &adventure.dm_notes {
+                            if let Some(notes) = adventure.dm_notes.as_ref().filter(|_| is_dm()) {
                                 div { class: "mt-6 p-4 bg-blue-50 rounded border border-blue-200",
                                     h3 { class: "font-semibold text-blue-800 mb-2", "DM Notes" }
                                     p { class: "text-blue-700 whitespace-pre-line", "{notes}" }
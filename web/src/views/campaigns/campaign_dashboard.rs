@@ -1,127 +1,124 @@
 use dioxus::prelude::*;
-use chrono::NaiveDate;
+use crate::api::campaign::{
+    get_campaign_events, get_campaign_packs, get_party_members, get_party_vitals, get_quests, save_campaign,
+    set_campaign_pack, CampaignEvent, CampaignPack, CharacterCondition, EventImpact, PartyMember, PartyVitals, Quest,
+    QuestStatus,
+};
+use crate::api::live::LiveEvent;
+use crate::Route;
+use super::{upsert, use_active_campaign, use_is_dm, use_live_events};
 
-#[derive(Clone, Debug)]
-pub struct Campaign {
-    name: String,
-    current_location: String,
-    next_session: Option<NaiveDate>,
-    active_quests: Vec<Quest>,
-    recent_events: Vec<Event>,
-    party_status: Vec<CharacterStatus>,
-    dm_notes: String,
-}
-
-#[derive(Clone, Debug)]
-pub struct Quest {
-    title: String,
-    status: QuestStatus,
-    progress: u8, // 0-100%
-}
-
-#[derive(Clone, Debug)]
-pub enum QuestStatus {
-    Active,
-    OnHold,
-    Completed,
-}
+#[component]
+pub fn CampaignDashboard(campaign_slug: String) -> Element {
+    let (mut campaign, campaign_error) = use_active_campaign();
+    let is_dm = use_is_dm();
+    let mut quests = use_signal::<Vec<Quest>>(|| vec![]);
+    let mut events = use_signal::<Vec<CampaignEvent>>(|| vec![]);
+    let mut party = use_signal::<Vec<PartyMember>>(|| vec![]);
+    let mut vitals = use_signal::<Vec<PartyVitals>>(|| vec![]);
+    let mut error = use_signal::<Option<String>>(|| None);
 
-#[derive(Clone, Debug)]
-pub struct Event {
-    title: String,
-    date: NaiveDate,
-    description: String,
-    impact: EventImpact,
-}
+    // Load everything hanging off the campaign once we know which campaign it is
+    use_effect(move || {
+        let Some(campaign_id) = campaign.read().as_ref().map(|c| c.id) else {
+            return;
+        };
+        spawn(async move {
+            match get_quests(campaign_id).await {
+                Ok(fetched) => quests.set(fetched),
+                Err(e) => error.set(Some(format!("Failed to load quests: {}", e))),
+            }
+            match get_campaign_events(campaign_id).await {
+                Ok(fetched) => events.set(fetched),
+                Err(e) => error.set(Some(format!("Failed to load events: {}", e))),
+            }
+            match get_party_members(campaign_id).await {
+                Ok(fetched) => party.set(fetched),
+                Err(e) => error.set(Some(format!("Failed to load party: {}", e))),
+            }
+            match get_party_vitals(campaign_id).await {
+                Ok(fetched) => vitals.set(fetched),
+                Err(e) => error.set(Some(format!("Failed to load party hit points: {}", e))),
+            }
+        });
+    });
 
-#[derive(Clone, Debug)]
-pub enum EventImpact {
-    Positive,
-    Neutral,
-    Negative,
-    MajorPlotPoint,
-}
+    let refresh_vitals = move |campaign_id: i32| {
+        spawn(async move {
+            match get_party_vitals(campaign_id).await {
+                Ok(fetched) => vitals.set(fetched),
+                Err(e) => error.set(Some(format!("Failed to load party hit points: {}", e))),
+            }
+        });
+    };
 
-#[derive(Clone, Debug)]
-pub struct CharacterStatus {
-    name: String,
-    player: String,
-    level: u8,
-    status: CharacterCondition,
-    notes: String,
-}
+    // Quests and the party's hit points change as others play
+    let campaign_id = use_memo(move || campaign.read().as_ref().map(|c| c.id));
+    use_live_events(campaign_id, move |event| match event {
+        LiveEvent::Quest(quest) => upsert(&mut quests.write(), quest, |q| q.id),
+        LiveEvent::QuestDeleted { id } => quests.write().retain(|q| q.id != id),
+        LiveEvent::Character { character_id } => {
+            if let (Some(campaign_id), true) = (campaign_id(), vitals.read().iter().any(|v| v.character_id == character_id)) {
+                refresh_vitals(campaign_id);
+            }
+        }
+        LiveEvent::Connected => {
+            if let Some(campaign_id) = campaign_id() {
+                refresh_vitals(campaign_id);
+                spawn(async move {
+                    if let Ok(fetched) = get_quests(campaign_id).await {
+                        quests.set(fetched);
+                    }
+                });
+            }
+        }
+        _ => {}
+    });
 
-#[derive(Clone, Debug)]
-pub enum CharacterCondition {
-    Healthy,
-    Injured,
-    Cursed,
-    SpecialCondition(String),
-}
+    let save_dm_notes = move |_| {
+        let Some(current) = campaign.read().clone() else {
+            return;
+        };
+        spawn(async move {
+            match save_campaign(current).await {
+                Ok(saved) => campaign.set(Some(saved)),
+                Err(e) => error.set(Some(format!("Failed to save DM notes: {}", e))),
+            }
+        });
+    };
 
-#[component]
-pub fn CampaignDashboard() -> Element {
-    let mut campaign = use_signal(|| Campaign {
-        name: "Dragon's Hoard".to_string(),
-        current_location: "The Ruins of Castle Rend".to_string(),
-        next_session: Some(NaiveDate::from_ymd_opt(2023, 12, 15).unwrap()),
-        active_quests: vec![
-            Quest {
-                title: "Recover the Dragon Orb".to_string(),
-                status: QuestStatus::Active,
-                progress: 65,
-            },
-            Quest {
-                title: "Clear the Bandit Camp".to_string(),
-                status: QuestStatus::OnHold,
-                progress: 30,
-            },
-        ],
-        recent_events: vec![
-            Event {
-                title: "Discovered the Orb's Location".to_string(),
-                date: NaiveDate::from_ymd_opt(2023, 12, 1).unwrap(),
-                description: "Found a map leading to the Dragon Orb in the wizard's tower".to_string(),
-                impact: EventImpact::MajorPlotPoint,
-            },
-            Event {
-                title: "Thorin's Curse".to_string(),
-                date: NaiveDate::from_ymd_opt(2023, 11, 24).unwrap(),
-                description: "The dwarf fighter was cursed by a witch's ghost".to_string(),
-                impact: EventImpact::Negative,
-            },
-        ],
-        party_status: vec![
-            CharacterStatus {
-                name: "Elandril Moonwhisper".to_string(),
-                player: "Sarah".to_string(),
-                level: 5,
-                status: CharacterCondition::Healthy,
-                notes: "Has the map to the orb".to_string(),
-            },
-            CharacterStatus {
-                name: "Thorin Ironfist".to_string(),
-                player: "Mike".to_string(),
-                level: 5,
-                status: CharacterCondition::Cursed,
-                notes: "Cursed - disadvantage on CHA checks".to_string(),
-            },
-        ],
-        dm_notes: "The dragon cult is aware of the party's movements. Prepare ambush for next session.".to_string(),
-    });
+    let Some(current_campaign) = campaign.read().clone() else {
+        return rsx! {
+            div { class: "max-w-7xl mx-auto py-6 px-4 text-center text-gray-600",
+                if let Some(err) = campaign_error.read().as_ref() {
+                    "{err}"
+                } else {
+                    "Loading campaign..."
+                }
+            }
+        };
+    };
 
     rsx! {
         div { class: "max-w-7xl mx-auto py-6 px-4",
             // Header
             div { class: "mb-8 text-center",
-                h1 { class: "text-4xl font-bold text-purple-900", "{campaign.read().name} Campaign" }
+                h1 { class: "text-4xl font-bold text-purple-900", "{current_campaign.name} Campaign" }
                 p { class: "mt-2 text-lg text-gray-600",
-                    "Current Location: {campaign.read().current_location}"
+                    "Current Location: {current_campaign.current_location}"
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6",
+                    role: "alert",
+                    p { "{err}" }
                 }
             }
 
             // Next Session Banner
-            if let Some(next_date) = campaign.read().next_session {
+            if let Some(next_date) = current_campaign.next_session {
                 div { class: "mb-8 p-4 bg-blue-50 border border-blue-200 rounded-lg",
                     div { class: "flex items-center justify-between",
                         div {
@@ -147,9 +144,12 @@ pub fn CampaignDashboard() -> Element {
                             "Active Quests"
                         }
                         div { class: "space-y-4",
-                            for quest in campaign.read().active_quests.iter() {
+                            for quest in quests.read().iter().filter(|q| matches!(q.status, QuestStatus::Active | QuestStatus::OnHold)) {
                                 {
                                     let (border_class, status_class, progress_class) = match quest.status {
+                                        QuestStatus::Available => {
+                                            ("border-blue-200", "bg-blue-100 text-blue-800", "bg-blue-600")
+                                        }
                                         QuestStatus::Active => {
                                             ("border-amber-200", "bg-amber-100 text-amber-800", "bg-amber-600")
                                         }
@@ -159,6 +159,9 @@ pub fn CampaignDashboard() -> Element {
                                         QuestStatus::Completed => {
                                             ("border-green-200", "bg-green-100 text-green-800", "bg-green-600")
                                         }
+                                        QuestStatus::Failed => {
+                                            ("border-red-200", "bg-red-100 text-red-800", "bg-red-600")
+                                        }
                                     };
                                     rsx! {
                                         div { class: "border rounded-lg p-4 {border_class}",
@@ -191,7 +194,7 @@ pub fn CampaignDashboard() -> Element {
                             "Recent Events"
                         }
                         div { class: "space-y-4",
-                            for event in campaign.read().recent_events.iter() {
+                            for event in events.read().iter().take(5) {
                                 {
                                     let event_class = match event.impact {
                                         EventImpact::Positive => "border-green-500 bg-green-50",
@@ -222,7 +225,7 @@ pub fn CampaignDashboard() -> Element {
                             "Party Status"
                         }
                         div { class: "space-y-3",
-                            for character in campaign.read().party_status.iter() {
+                            for character in party.read().iter() {
                                 {
                                     let status_class = match character.status {
                                         CharacterCondition::Healthy => "bg-green-100 text-green-800",
@@ -245,7 +248,18 @@ pub fn CampaignDashboard() -> Element {
                                                         "Player: {character.player} • Level {character.level}"
                                                     }
                                                 }
-                                                span { class: "px-2 py-1 text-xs rounded-full {status_class}", "{status_label}" }
+                                                if character.character_id.is_some() && !character.accepted {
+                                                    span {
+                                                        class: "px-2 py-1 text-xs rounded-full bg-gray-100 text-gray-600",
+                                                        title: "The character's player hasn't accepted the invite yet",
+                                                        "Invite pending"
+                                                    }
+                                                } else {
+                                                    span { class: "px-2 py-1 text-xs rounded-full {status_class}", "{status_label}" }
+                                                }
+                                            }
+                                            if let Some(vital) = vitals.read().iter().find(|v| Some(v.character_id) == character.character_id) {
+                                                PartyMemberVitals { vitals: vital.clone() }
                                             }
                                             if !character.notes.is_empty() {
                                                 div { class: "mt-2 p-2 bg-gray-50 rounded text-sm", "{character.notes}" }
@@ -257,38 +271,51 @@ pub fn CampaignDashboard() -> Element {
                         }
                     }
 
-                    // DM Notes
-                    div { class: "bg-white rounded-lg shadow p-6",
-                        h2 { class: "text-xl font-semibold mb-4 text-gray-800 border-b pb-2",
-                            "DM Notes"
-                        }
-                        textarea {
-                            class: "w-full p-3 border border-gray-300 rounded focus:ring-2 focus:ring-purple-500 focus:border-transparent",
-                            rows: "5",
-                            value: "{campaign.read().dm_notes}",
-                            oninput: move |e| {
-                                campaign.write().dm_notes = e.data.value();
-                            },
+                    // DM Notes, only ever shown to the DM
+                    if is_dm() {
+                        div { class: "bg-white rounded-lg shadow p-6",
+                            h2 { class: "text-xl font-semibold mb-4 text-gray-800 border-b pb-2",
+                                "DM Notes"
+                            }
+                            textarea {
+                                class: "w-full p-3 border border-gray-300 rounded focus:ring-2 focus:ring-purple-500 focus:border-transparent",
+                                rows: "5",
+                                value: "{current_campaign.dm_notes}",
+                                oninput: move |e| {
+                                    if let Some(c) = campaign.write().as_mut() {
+                                        c.dm_notes = e.data.value();
+                                    }
+                                },
+                                onchange: save_dm_notes,
+                            }
                         }
                     }
 
+                    if is_dm() {
+                        HomebrewPacks { campaign_id: current_campaign.id }
+                    }
+
                     // Quick Actions
                     div { class: "bg-white rounded-lg shadow p-6",
                         h2 { class: "text-xl font-semibold mb-4 text-gray-800 border-b pb-2",
                             "Quick Actions"
                         }
                         div { class: "grid grid-cols-2 gap-3",
-                            button { class: "p-3 bg-blue-50 text-blue-700 rounded hover:bg-blue-100 transition-colors",
-                                "Session Prep"
+                            Link { to: Route::AdventureLog { campaign_slug: campaign_slug.clone() },
+                                class: "p-3 bg-blue-50 text-blue-700 rounded hover:bg-blue-100 transition-colors text-center",
+                                "Adventure Log"
                             }
-                            button { class: "p-3 bg-green-50 text-green-700 rounded hover:bg-green-100 transition-colors",
+                            Link { to: Route::NpcView { campaign_slug: campaign_slug.clone() },
+                                class: "p-3 bg-green-50 text-green-700 rounded hover:bg-green-100 transition-colors text-center",
                                 "NPC Manager"
                             }
-                            button { class: "p-3 bg-purple-50 text-purple-700 rounded hover:bg-purple-100 transition-colors",
+                            Link { to: Route::Lore { campaign_slug: campaign_slug.clone() },
+                                class: "p-3 bg-purple-50 text-purple-700 rounded hover:bg-purple-100 transition-colors text-center",
                                 "Lore Archive"
                             }
-                            button { class: "p-3 bg-amber-50 text-amber-700 rounded hover:bg-amber-100 transition-colors",
-                                "Treasure Tracker"
+                            Link { to: Route::MapLocations { campaign_slug: campaign_slug.clone() },
+                                class: "p-3 bg-amber-50 text-amber-700 rounded hover:bg-amber-100 transition-colors text-center",
+                                "Map & Locations"
                             }
                         }
                     }
@@ -296,4 +323,116 @@ pub fn CampaignDashboard() -> Element {
             }
         }
     }
-}
\ No newline at end of file
+}
+// Homebrew packs the DM can turn on for the table; enabled packs show up in everyone's reference pages
+#[component]
+fn HomebrewPacks(campaign_id: i32) -> Element {
+    let mut packs = use_signal::<Vec<CampaignPack>>(|| vec![]);
+    let mut error = use_signal::<Option<String>>(|| None);
+
+    use_effect(move || {
+        spawn(async move {
+            match get_campaign_packs(campaign_id).await {
+                Ok(fetched) => packs.set(fetched),
+                Err(e) => error.set(Some(format!("Failed to load homebrew packs: {}", e))),
+            }
+        });
+    });
+
+    let toggle = move |pack_id: i32, enabled: bool| {
+        spawn(async move {
+            match set_campaign_pack(campaign_id, pack_id, enabled).await {
+                Ok(()) => {
+                    if let Some(entry) = packs.write().iter_mut().find(|entry| entry.pack.id == pack_id) {
+                        entry.enabled = enabled;
+                    }
+                }
+                Err(e) => error.set(Some(format!("Failed to update homebrew packs: {}", e))),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "bg-white rounded-lg shadow p-6",
+            h2 { class: "text-xl font-semibold mb-4 text-gray-800 border-b pb-2", "Homebrew" }
+            if let Some(err) = error.read().as_ref() {
+                p { class: "text-sm text-red-700 mb-2", "{err}" }
+            }
+            if packs.read().is_empty() {
+                p { class: "text-gray-600 text-sm",
+                    "No homebrew packs yet. "
+                    Link { to: Route::Homebrew {}, class: "text-blue-600 hover:underline", "Make one" }
+                    " or ask another DM to share theirs."
+                }
+            } else {
+                ul { class: "space-y-2",
+                    for entry in packs.read().iter() {
+                        li { key: "{entry.pack.id}",
+                            label { class: "flex items-center gap-2 text-gray-700",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: entry.enabled,
+                                    onchange: {
+                                        let pack_id = entry.pack.id;
+                                        move |e: FormEvent| toggle(pack_id, e.checked())
+                                    },
+                                }
+                                Link {
+                                    to: Route::HomebrewPackView { slug: entry.pack.slug.clone() },
+                                    class: "hover:underline",
+                                    "{entry.pack.name}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Live hit points and conditions of a party member's character, as tracked in play mode
+#[component]
+fn PartyMemberVitals(vitals: PartyVitals) -> Element {
+    let percent = if vitals.max_hit_points > 0 { vitals.hit_points * 100 / vitals.max_hit_points } else { 0 };
+    let bar_class = match percent {
+        0 => "bg-gray-400",
+        1..=25 => "bg-red-500",
+        26..=50 => "bg-yellow-500",
+        _ => "bg-green-500",
+    };
+
+    rsx! {
+        div { class: "mt-2",
+            div { class: "flex justify-between text-sm text-gray-600",
+                span {
+                    "HP {vitals.hit_points} / {vitals.max_hit_points}"
+                    if vitals.temporary_hit_points > 0 { " (+{vitals.temporary_hit_points})" }
+                }
+                if vitals.dead {
+                    span { class: "font-semibold text-red-700", "Dead" }
+                } else if vitals.hit_points == 0 {
+                    span { class: "text-red-600",
+                        "Death saves: {vitals.death_saves.successes} ✓ {vitals.death_saves.failures} ✗"
+                    }
+                }
+            }
+            div { class: "w-full h-2 bg-gray-200 rounded-full mt-1",
+                div { class: "h-2 rounded-full {bar_class}", style: "width: {percent}%" }
+            }
+            if !vitals.conditions.is_empty() || vitals.exhaustion > 0 || vitals.concentration.is_some() {
+                div { class: "flex flex-wrap gap-1 mt-2",
+                    for condition in vitals.conditions.iter() {
+                        span { class: "px-2 py-0.5 text-xs rounded-full bg-red-100 text-red-800", title: condition.effect(), "{condition}" }
+                    }
+                    if vitals.exhaustion > 0 {
+                        span { class: "px-2 py-0.5 text-xs rounded-full bg-orange-100 text-orange-800", "Exhaustion {vitals.exhaustion}" }
+                    }
+                    if let Some(spell) = &vitals.concentration {
+                        span { class: "px-2 py-0.5 text-xs rounded-full bg-purple-100 text-purple-800", "Concentrating: {spell}" }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use dioxus::prelude::*;
+use futures::StreamExt;
+use crate::api::campaign::watch_campaign;
+use crate::api::live::{self, LiveEvent};
+
+// Pauses between attempts to connect, doubling while the server can't be reached
+const RETRY_FIRST_MS: u32 = 2_000;
+const RETRY_MAX_MS: u32 = 60_000;
+
+// Errors that trying again won't fix: the user signed out, isn't (or is no longer) in the
+// party, or the campaign is gone
+fn is_final(error: &ServerFnError) -> bool {
+    let ServerFnError::ServerError(message) = error else {
+        return false;
+    };
+    ["Not signed in", "You are not part of this campaign", "No campaigns row"].iter().any(|prefix| message.starts_with(prefix))
+}
+
+// Follow the campaign until the connection drops, then connect again after a pause. Gives up
+// for good when the server says the user may not watch it.
+async fn follow(campaign_id: i32, mut on_event: impl FnMut(LiveEvent) + 'static) {
+    let mut reconnecting = false;
+    let mut delay = RETRY_FIRST_MS;
+    loop {
+        match watch_campaign(campaign_id).await {
+            Ok(stream) => {
+                let mut events = std::pin::pin!(live::events(stream));
+                while let Some(event) = events.next().await {
+                    // The connection worked, so the next drop starts the backoff over
+                    delay = RETRY_FIRST_MS;
+                    match event {
+                        LiveEvent::Connected if !reconnecting => reconnecting = true,
+                        event => on_event(event),
+                    }
+                }
+            }
+            Err(error) if is_final(&error) => return,
+            Err(_) => {}
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            use gloo_timers::future::TimeoutFuture;
+            TimeoutFuture::new(delay).await;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            tokio::time::sleep(std::time::Duration::from_millis(delay.into())).await;
+        }
+        delay = (delay * 2).min(RETRY_MAX_MS);
+    }
+}
+
+/// Hand `on_event` everything that happens in `campaign_id` that the user may see, while the
+/// component is mounted. Views load their data as they mount, so the first
+/// [`LiveEvent::Connected`] is kept back; a later one means changes may have been missed while
+/// the connection was down, and the view should reload.
+pub(crate) fn use_live_events(campaign_id: Memo<Option<i32>>, on_event: impl FnMut(LiveEvent) + Copy + 'static) {
+    let mut watcher = use_signal::<Option<Task>>(|| None);
+
+    use_effect(move || {
+        let campaign_id = campaign_id();
+        if let Some(task) = watcher.take() {
+            task.cancel();
+        }
+        if let Some(campaign_id) = campaign_id {
+            watcher.set(Some(spawn(follow(campaign_id, on_event))));
+        }
+    });
+}
+
+/// Put `item` in place of the entry with the same key, or at the end if it is new
+pub(crate) fn upsert<T>(list: &mut Vec<T>, item: T, key: impl Fn(&T) -> i32) {
+    match list.iter_mut().find(|existing| key(existing) == key(&item)) {
+        Some(existing) => *existing = item,
+        None => list.push(item),
+    }
+}
@@ -1,63 +1,24 @@
 use dioxus::prelude::*;
-use serde::{Deserialize, Serialize};
-
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
-pub struct LoreCategory {
-    id: u32,
-    title: String,
-    description: String,
-    entries: Vec<LoreEntry>,
-}
-
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
-pub struct LoreEntry {
-    id: u32,
-    title: String,
-    content: String,
-    related_entries: Vec<u32>, // IDs of related entries
-    tags: Vec<String>,
-}
+use crate::api::campaign::{get_lore, LoreCategory};
+use super::use_active_campaign;
 
 #[component]
-pub fn Lore() -> Element {
-    let lore_categories = use_signal(|| vec![
-        LoreCategory {
-            id: 1,
-            title: "World History".to_string(),
-            description: "The chronicles of ages past that shaped the realm".to_string(),
-            entries: vec![
-                LoreEntry {
-                    id: 101,
-                    title: "The Sundering Wars".to_string(),
-                    content: "A series of cataclysmic conflicts between the ancient empires that fractured the continent into its current form...".to_string(),
-                    related_entries: vec![102, 103],
-                    tags: vec!["war".to_string(), "ancient".to_string()],
-                },
-                LoreEntry {
-                    id: 102,
-                    title: "Rise of the Dragon Lords".to_string(),
-                    content: "After the Sundering, chromatic dragons established dominion over vast territories...".to_string(),
-                    related_entries: vec![101],
-                    tags: vec!["dragons".to_string(), "rule".to_string()],
-                },
-            ],
-        },
-        LoreCategory {
-            id: 2,
-            title: "Geography".to_string(),
-            description: "Lands, cities, and natural features of the world".to_string(),
-            entries: vec![
-                LoreEntry {
-                    id: 201,
-                    title: "The Shattered Peaks".to_string(),
-                    content: "A mountain range formed from the remains of the Godspire after its destruction in the Sundering...".to_string(),
-                    related_entries: vec![101],
-                    tags: vec!["mountains".to_string(), "dangerous".to_string()],
-                },
-            ],
-        },
-        // More categories can be added here
-    ]);
+pub fn Lore(campaign_slug: String) -> Element {
+    let (campaign, campaign_error) = use_active_campaign();
+    let mut lore_categories = use_signal::<Vec<LoreCategory>>(|| vec![]);
+    let mut error = use_signal::<Option<String>>(|| None);
+
+    use_effect(move || {
+        let Some(campaign_id) = campaign.read().as_ref().map(|c| c.id) else {
+            return;
+        };
+        spawn(async move {
+            match get_lore(campaign_id).await {
+                Ok(fetched) => lore_categories.set(fetched),
+                Err(e) => error.set(Some(format!("Failed to load lore: {}", e))),
+            }
+        });
+    });
 
     let mut selected_category = use_signal(|| None);
     let mut selected_entry = use_signal(|| None);
@@ -71,6 +32,13 @@ pub fn Lore() -> Element {
                 p { class: "mt-2 text-lg text-gray-600",
                     "The collected knowledge of the realm, available to scholars and adventurers alike"
                 }
+                if let Some(err) = campaign_error.read().as_ref().or(error.read().as_ref()) {
+                    div {
+                        class: "mt-4 bg-red-100 border-l-4 border-red-500 text-red-700 p-4",
+                        role: "alert",
+                        p { "{err}" }
+                    }
+                }
                 // Search Bar
                 div { class: "mt-6",
                     input {
@@ -13,38 +13,371 @@ pub use map_locations::MapLocations;
 mod npc;
 pub use npc::NpcView;
 
+mod live;
+pub(crate) use live::{upsert, use_live_events};
+
+mod roll_log;
+pub use roll_log::CampaignRolls;
+pub(crate) use roll_log::{use_roll_log, RollLogPanel};
+
 
 
 use dioxus::prelude::*;
 use crate::Route;
+use crate::api::campaign::{
+    get_campaign_role, get_campaign_with_slug, get_campaigns, get_party_invites, respond_to_party_invite, save_campaign,
+    Campaign, CampaignListing, CampaignRole, PartyInvite,
+};
 
+/// The campaign selected by the `/campaign/:campaign_slug` routes, shared with every
+/// campaign view rendered inside [`CampaignLayout`].
+#[derive(Clone, Copy)]
+struct CampaignContext {
+    campaign: Signal<Option<Campaign>>,
+    role: Signal<Option<CampaignRole>>,
+    error: Signal<Option<String>>,
+}
+
+/// The campaign the current route points at, plus any error from loading it.
+pub(crate) fn use_active_campaign() -> (Signal<Option<Campaign>>, Signal<Option<String>>) {
+    let context = use_context::<CampaignContext>();
+    (context.campaign, context.error)
+}
+
+/// True when the signed-in user runs the current campaign. Gate DM-only content on this.
+pub(crate) fn use_is_dm() -> Memo<bool> {
+    let role = use_context::<CampaignContext>().role;
+    use_memo(move || role() == Some(CampaignRole::Dm))
+}
+
+/// The campaign slug carried by `route`, if it is one of the campaign-scoped routes.
+pub fn route_campaign_slug(route: &Route) -> Option<String> {
+    match route {
+        Route::CampaignDashboard { campaign_slug }
+        | Route::AdventureLog { campaign_slug }
+        | Route::Lore { campaign_slug }
+        | Route::MapLocations { campaign_slug }
+        | Route::NpcView { campaign_slug }
+        | Route::CampaignRolls { campaign_slug }
+        | Route::Encounters { campaign_slug }
+        | Route::CombatTracker { campaign_slug, .. }
+        | Route::DMTools { campaign_slug } => Some(campaign_slug.clone()),
+        _ => None,
+    }
+}
+
+/// The tabs every campaign has, in the order they are shown.
+pub fn campaign_tabs(campaign_slug: &str) -> Vec<(Route, &'static str)> {
+    let campaign_slug = campaign_slug.to_string();
+    vec![
+        (Route::CampaignDashboard { campaign_slug: campaign_slug.clone() }, "Dashboard"),
+        (Route::AdventureLog { campaign_slug: campaign_slug.clone() }, "Adventure Log"),
+        (Route::Lore { campaign_slug: campaign_slug.clone() }, "Lore"),
+        (Route::MapLocations { campaign_slug: campaign_slug.clone() }, "Map & Locations"),
+        (Route::NpcView { campaign_slug: campaign_slug.clone() }, "NPCs"),
+        (Route::CampaignRolls { campaign_slug }, "Rolls"),
+    ]
+}
+
+/// Extra tabs shown only to the campaign's DM.
+pub fn dm_tabs(campaign_slug: &str) -> Vec<(Route, &'static str)> {
+    let campaign_slug = campaign_slug.to_string();
+    vec![
+        (Route::Encounters { campaign_slug: campaign_slug.clone() }, "Combat"),
+        (Route::DMTools { campaign_slug }, "DM Tools"),
+    ]
+}
 
 
 
 #[component]
-pub fn Campaigns() -> Element {
-    rsx! {
-        div { class: "max-w-lg mx-auto py-2",
-            h1 { class: "text-3xl", "Adventure Log" }
-            p { class: "text-lg text-gray-700 mt-4",
-                "This section will contain detailed logs of adventures, quests, and significant events in the campaign."
+pub fn CampaignLayout(campaign_slug: String) -> Element {
+    let mut campaign = use_signal::<Option<Campaign>>(|| None);
+    let mut role = use_signal::<Option<CampaignRole>>(|| None);
+    let mut error = use_signal::<Option<String>>(|| None);
+    use_context_provider(|| CampaignContext { campaign, role, error });
+
+    // Reload whenever the route switches to another campaign
+    use_effect(use_reactive!(|campaign_slug| {
+        spawn(async move {
+            campaign.set(None);
+            role.set(None);
+            error.set(None);
+            match get_campaign_role(campaign_slug.clone()).await {
+                Ok(fetched) => role.set(fetched),
+                Err(e) => error.set(Some(format!("Failed to load campaign: {}", e))),
             }
-            p { class: "text-lg text-gray-700 mt-2",
-                "Stay tuned for updates as we build out this feature to enhance your campaign experience."
+            match get_campaign_with_slug(campaign_slug).await {
+                Ok(fetched) => campaign.set(Some(fetched)),
+                Err(e) => error.set(Some(format!("Failed to load campaign: {}", e))),
             }
+        });
+    }));
 
+    rsx! {
+        div { class: "max-w-7xl mx-auto px-4 pt-4",
+            div { class: "flex items-center justify-between",
+                Link { to: Route::Campaigns {}, class: "text-sm text-blue-600 hover:underline", "← All campaigns" }
+                div { class: "flex gap-2",
+                    if role() == Some(CampaignRole::Dm) {
+                        span { class: "px-2 py-1 text-xs rounded-full bg-purple-100 text-purple-800", "Dungeon Master" }
+                    }
+                    if let Some(current) = campaign.read().as_ref() {
+                        if current.archived {
+                            span { class: "px-2 py-1 text-xs rounded-full bg-gray-200 text-gray-700", "Archived" }
+                        }
+                    }
+                }
+            }
             div { class: "border-b border-gray-200",
                 ul { class: "flex flex-wrap -mb-px",
-                    CampaignTabLink { to: Route::CampaignDashboard {}, name: "Dashboard" }
-                    CampaignTabLink { to: Route::AdventureLog {}, name: "Adventure Log" }
-                    CampaignTabLink { to: Route::Lore {}, name: "Lore" }
-                    CampaignTabLink { to: Route::MapLocations {}, name: "Map & Locations" }
-                    CampaignTabLink { to: Route::NpcView {}, name: "NPCs" }
+                    for (to, name) in campaign_tabs(&campaign_slug) {
+                        CampaignTabLink { to, name }
+                    }
+                    if role() == Some(CampaignRole::Dm) {
+                        for (to, name) in dm_tabs(&campaign_slug) {
+                            CampaignTabLink { to, name }
+                        }
+                    }
                 }
             }
         }
+        Outlet::<Route> {}
     }
-} 
+}
+
+
+
+#[component]
+pub fn Campaigns() -> Element {
+    let navigator = use_navigator();
+    let mut campaigns = use_signal::<Vec<CampaignListing>>(|| vec![]);
+    let mut loading = use_signal(|| true);
+    let mut error = use_signal::<Option<String>>(|| None);
+    let mut new_name = use_signal(|| String::new());
+    let mut new_description = use_signal(|| String::new());
+    let mut show_archived = use_signal(|| false);
+    let mut invites = use_signal::<Vec<PartyInvite>>(|| vec![]);
+
+    use_effect(move || {
+        spawn(async move {
+            match get_campaigns().await {
+                Ok(fetched) => campaigns.set(fetched),
+                Err(e) => error.set(Some(format!("Failed to load campaigns: {}", e))),
+            }
+            match get_party_invites().await {
+                Ok(fetched) => invites.set(fetched),
+                Err(e) => error.set(Some(format!("Failed to load party invites: {}", e))),
+            }
+            loading.set(false);
+        });
+    });
+
+    // An accepted invite makes the campaign one of the caller's, so reload the list
+    let respond = move |invite: PartyInvite, accept: bool| {
+        spawn(async move {
+            match respond_to_party_invite(invite.id, accept).await {
+                Ok(()) => {
+                    invites.write().retain(|pending| pending.id != invite.id);
+                    if accept {
+                        match get_campaigns().await {
+                            Ok(fetched) => campaigns.set(fetched),
+                            Err(e) => error.set(Some(format!("Failed to load campaigns: {}", e))),
+                        }
+                    }
+                }
+                Err(e) => error.set(Some(format!("Failed to answer the invite: {}", e))),
+            }
+        });
+    };
+
+    let create_campaign = move |evt: FormEvent| {
+        evt.prevent_default();
+        let name = new_name.read().trim().to_string();
+        if name.is_empty() {
+            error.set(Some("Campaign name is required".to_string()));
+            return;
+        }
+        let campaign = Campaign {
+            id: 0,
+            name,
+            slug: String::new(),
+            description: new_description.read().trim().to_string(),
+            current_location: String::new(),
+            next_session: None,
+            dm_notes: String::new(),
+            owner: String::new(),
+            archived: false,
+            created_at: None,
+            updated_at: None,
+        };
+        spawn(async move {
+            match save_campaign(campaign).await {
+                Ok(saved) => {
+                    navigator.push(Route::CampaignDashboard { campaign_slug: saved.slug });
+                }
+                Err(e) => error.set(Some(format!("Failed to create campaign: {}", e))),
+            }
+        });
+    };
+
+    let set_archived = move |campaign: Campaign, archived: bool| {
+        spawn(async move {
+            match save_campaign(Campaign { archived, ..campaign }).await {
+                Ok(saved) => {
+                    let mut list = campaigns.write();
+                    if let Some(existing) = list.iter_mut().find(|listing| listing.campaign.id == saved.id) {
+                        existing.campaign = saved;
+                    }
+                }
+                Err(e) => error.set(Some(format!("Failed to update campaign: {}", e))),
+            }
+        });
+    };
+
+    let active: Vec<CampaignListing> = campaigns.read().iter().filter(|listing| !listing.campaign.archived).cloned().collect();
+    let archived: Vec<CampaignListing> = campaigns.read().iter().filter(|listing| listing.campaign.archived).cloned().collect();
+
+    rsx! {
+        div { class: "max-w-5xl mx-auto py-6 px-4",
+            h1 { class: "text-3xl font-bold text-purple-900 mb-6", "Campaigns" }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6", role: "alert",
+                    p { "{err}" }
+                }
+            }
+
+            if !invites.read().is_empty() {
+                div { class: "bg-white rounded-lg shadow p-6 mb-8",
+                    h2 { class: "text-xl font-semibold mb-4 text-gray-800", "Party Invites" }
+                    ul { class: "divide-y",
+                        for invite in invites.read().iter().cloned() {
+                            li { key: "{invite.id}", class: "py-3 flex items-center justify-between",
+                                p { class: "text-gray-700",
+                                    span { class: "font-medium", "{invite.character_name}" }
+                                    " is invited to "
+                                    span { class: "font-medium", "{invite.campaign_name}" }
+                                }
+                                div { class: "flex gap-2",
+                                    button {
+                                        class: "px-3 py-1 text-sm bg-blue-600 text-white rounded hover:bg-blue-700",
+                                        onclick: {
+                                            let invite = invite.clone();
+                                            move |_| respond(invite.clone(), true)
+                                        },
+                                        "Accept"
+                                    }
+                                    button {
+                                        class: "px-3 py-1 text-sm bg-gray-100 text-gray-700 rounded hover:bg-gray-200",
+                                        onclick: move |_| respond(invite.clone(), false),
+                                        "Decline"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // New campaign
+            form { class: "bg-white rounded-lg shadow p-6 mb-8", onsubmit: create_campaign,
+                h2 { class: "text-xl font-semibold mb-4 text-gray-800", "Start a New Campaign" }
+                div { class: "grid grid-cols-1 md:grid-cols-3 gap-4",
+                    input {
+                        class: "p-2 border rounded",
+                        placeholder: "Campaign name",
+                        value: "{new_name}",
+                        oninput: move |e| new_name.set(e.value()),
+                    }
+                    input {
+                        class: "p-2 border rounded md:col-span-2",
+                        placeholder: "Short description",
+                        value: "{new_description}",
+                        oninput: move |e| new_description.set(e.value()),
+                    }
+                }
+                button {
+                    r#type: "submit",
+                    class: "mt-4 px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700 transition-colors",
+                    "Create Campaign"
+                }
+            }
+
+            if *loading.read() {
+                p { class: "text-gray-600", "Loading campaigns..." }
+            } else if active.is_empty() {
+                p { class: "text-gray-600", "No active campaigns yet. Create one above to get started." }
+            } else {
+                div { class: "grid grid-cols-1 md:grid-cols-2 gap-6",
+                    for CampaignListing { campaign, role } in active {
+                        div { key: "{campaign.id}", class: "bg-white rounded-lg shadow p-6 flex flex-col",
+                            Link {
+                                to: Route::CampaignDashboard { campaign_slug: campaign.slug.clone() },
+                                class: "text-xl font-semibold text-purple-800 hover:underline",
+                                "{campaign.name}"
+                            }
+                            p { class: "text-gray-700 mt-2 flex-grow", "{campaign.description}" }
+                            if !campaign.current_location.is_empty() {
+                                p { class: "text-sm text-gray-500 mt-2", "Currently in {campaign.current_location}" }
+                            }
+                            // Only the DM can archive a campaign
+                            if role == CampaignRole::Dm {
+                                div { class: "mt-4 flex justify-end",
+                                    button {
+                                        class: "px-3 py-1 text-sm bg-gray-100 text-gray-700 rounded hover:bg-gray-200",
+                                        onclick: {
+                                            let campaign = campaign.clone();
+                                            move |_| set_archived(campaign.clone(), true)
+                                        },
+                                        "Archive"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !archived.is_empty() {
+                div { class: "mt-10",
+                    button {
+                        class: "text-sm text-gray-600 hover:text-gray-800",
+                        onclick: move |_| show_archived.toggle(),
+                        if *show_archived.read() {
+                            "Hide archived campaigns ({archived.len()})"
+                        } else {
+                            "Show archived campaigns ({archived.len()})"
+                        }
+                    }
+                    if *show_archived.read() {
+                        ul { class: "mt-4 divide-y bg-white rounded-lg shadow",
+                            for CampaignListing { campaign, role } in archived {
+                                li { key: "{campaign.id}", class: "p-4 flex items-center justify-between",
+                                    Link {
+                                        to: Route::CampaignDashboard { campaign_slug: campaign.slug.clone() },
+                                        class: "text-gray-700 hover:underline",
+                                        "{campaign.name}"
+                                    }
+                                    if role == CampaignRole::Dm {
+                                        button {
+                                            class: "px-3 py-1 text-sm bg-blue-50 text-blue-700 rounded hover:bg-blue-100",
+                                            onclick: {
+                                                let campaign = campaign.clone();
+                                                move |_| set_archived(campaign.clone(), false)
+                                            },
+                                            "Restore"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
 
 
 #[component]
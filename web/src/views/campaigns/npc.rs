@@ -1,88 +1,77 @@
 use dioxus::prelude::*;
-use serde::{Deserialize, Serialize};
+use crate::api::campaign::{get_npcs, get_quests, Quest, QuestStatus, NPC};
+use crate::api::live::LiveEvent;
+use super::{upsert, use_active_campaign, use_live_events};
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
-pub struct NPC {
-    id: u32,
-    name: String,
-    race: String,
-    role: String,
-    location: String,
-    affiliation: String,
-    description: String,
-    personality: String,
-    appearance: String,
-    relationship: String, // Party's relationship status
-    important_notes: Vec<String>,
-    quests: Vec<Quest>,
-    portrait: Option<String>,
-}
-
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
-pub struct Quest {
-    id: u32,
-    title: String,
-    status: QuestStatus,
-}
+#[component]
+pub fn NpcView(campaign_slug: String) -> Element {
+    let (campaign, campaign_error) = use_active_campaign();
+    let mut npcs = use_signal::<Vec<NPC>>(|| vec![]);
+    let mut quests = use_signal::<Vec<Quest>>(|| vec![]);
+    let mut error = use_signal::<Option<String>>(|| None);
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
-enum QuestStatus {
-    Available,
-    Active,
-    Completed,
-    Failed,
-}
+    use_effect(move || {
+        let Some(campaign_id) = campaign.read().as_ref().map(|c| c.id) else {
+            return;
+        };
+        spawn(async move {
+            match get_npcs(campaign_id).await {
+                Ok(fetched) => npcs.set(fetched),
+                Err(e) => error.set(Some(format!("Failed to load NPCs: {}", e))),
+            }
+            match get_quests(campaign_id).await {
+                Ok(fetched) => quests.set(fetched),
+                Err(e) => error.set(Some(format!("Failed to load quests: {}", e))),
+            }
+        });
+    });
 
-#[component]
-pub fn NpcView() -> Element {
-    let npcs = use_signal(|| vec![
-        NPC {
-            id: 1,
-            name: "Eldrin the Wise".to_string(),
-            race: "Human".to_string(),
-            role: "Archmage".to_string(),
-            location: "Tower of High Sorcery".to_string(),
-            affiliation: "Arcane Brotherhood".to_string(),
-            description: "The ancient archmage who oversees magical training in the region.".to_string(),
-            personality: "Patient but stern, values knowledge above all".to_string(),
-            appearance: "Long white beard, piercing blue eyes, wears deep blue robes".to_string(),
-            relationship: "Mentor to party wizard".to_string(),
-            important_notes: vec![
-                "Knows the location of the Lost Tome".to_string(),
-                "Hates the Red Wizards".to_string(),
-            ],
-            quests: vec![
-                Quest {
-                    id: 101,
-                    title: "Recover the Lost Tome".to_string(),
-                    status: QuestStatus::Active,
-                },
-            ],
-            portrait: Some("https://example.com/portraits/eldrin.jpg".to_string()),
-        },
-        NPC {
-            id: 2,
-            name: "Grimbold".to_string(),
-            race: "Dwarf".to_string(),
-            role: "Blacksmith".to_string(),
-            location: "Stonehelm City".to_string(),
-            affiliation: "Dwarven Forge Guild".to_string(),
-            description: "Master weaponsmith who can craft magical arms".to_string(),
-            personality: "Grumpy but kind-hearted, loves ale".to_string(),
-            appearance: "Bald with a fiery red beard, covered in soot".to_string(),
-            relationship: "Friendly with party fighter".to_string(),
-            important_notes: vec![
-                "Will trade rare weapons for dragon scales".to_string(),
-            ],
-            quests: vec![],
-            portrait: None,
-        },
-    ]);
+    let mut selected_npc = use_signal::<Option<NPC>>(|| None);
 
-    let mut selected_npc = use_signal(|| None);
+    // Edits the DM makes elsewhere show up here, including in the open NPC
+    let campaign_id = use_memo(move || campaign.read().as_ref().map(|c| c.id));
+    use_live_events(campaign_id, move |event| match event {
+        LiveEvent::Npc(npc) => {
+            if selected_npc.read().as_ref().is_some_and(|selected| selected.id == npc.id) {
+                selected_npc.set(Some(npc.clone()));
+            }
+            let mut npcs = npcs.write();
+            upsert(&mut npcs, npc, |n| n.id);
+            npcs.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+        LiveEvent::NpcDeleted { id } => {
+            if selected_npc.read().as_ref().is_some_and(|selected| selected.id == id) {
+                selected_npc.set(None);
+            }
+            npcs.write().retain(|n| n.id != id);
+        }
+        LiveEvent::Quest(quest) => upsert(&mut quests.write(), quest, |q| q.id),
+        LiveEvent::QuestDeleted { id } => quests.write().retain(|q| q.id != id),
+        LiveEvent::Connected => {
+            if let Some(campaign_id) = campaign_id() {
+                spawn(async move {
+                    if let Ok(fetched) = get_npcs(campaign_id).await {
+                        npcs.set(fetched);
+                    }
+                    if let Ok(fetched) = get_quests(campaign_id).await {
+                        quests.set(fetched);
+                    }
+                });
+            }
+        }
+        _ => {}
+    });
     let mut search_query = use_signal(|| String::new());
     let mut filter_role = use_signal(|| String::new());
 
+    // Quests handed out by the selected NPC
+    let npc_quests = use_memo(move || {
+        let Some(npc_id) = selected_npc.read().as_ref().map(|npc| npc.id) else {
+            return vec![];
+        };
+        quests.read().iter().filter(|quest| quest.npc_id == Some(npc_id)).cloned().collect::<Vec<Quest>>()
+    });
+
     rsx! {
         div { class: "max-w-7xl mx-auto py-6 px-4",
             // Header
@@ -93,6 +82,14 @@ pub fn NpcView() -> Element {
                 }
             }
 
+            if let Some(err) = campaign_error.read().as_ref().or(error.read().as_ref()) {
+                div {
+                    class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6",
+                    role: "alert",
+                    p { "{err}" }
+                }
+            }
+
             // Filters
             div { class: "grid grid-cols-1 md:grid-cols-2 gap-4 mb-6",
                 // Search
@@ -252,23 +249,25 @@ pub fn NpcView() -> Element {
                             }
 
                             // Quests
-                            if !npc.quests.is_empty() {
+                            if !npc_quests.read().is_empty() {
                                 div { class: "mt-6",
                                     h3 { class: "text-lg font-semibold mb-2 text-amber-800", "Quests" }
                                     div { class: "space-y-3",
-                                        for quest in &npc.quests {
+                                        for quest in npc_quests.read().iter() {
                                             {
                                                 let quest_class = match quest.status {
                                                     QuestStatus::Available => "p-3 border rounded-lg border-green-200 bg-green-50",
                                                     QuestStatus::Active => "p-3 border rounded-lg border-amber-200 bg-amber-50",
                                                     QuestStatus::Completed => "p-3 border rounded-lg border-blue-200 bg-blue-50",
                                                     QuestStatus::Failed => "p-3 border rounded-lg border-red-200 bg-red-50",
+                                                    QuestStatus::OnHold => "p-3 border rounded-lg border-gray-200 bg-gray-50",
                                                 };
                                                 let status_class = match quest.status {
                                                     QuestStatus::Available => "text-sm mt-1 text-green-600",
                                                     QuestStatus::Active => "text-sm mt-1 text-amber-600",
                                                     QuestStatus::Completed => "text-sm mt-1 text-blue-600",
                                                     QuestStatus::Failed => "text-sm mt-1 text-red-600",
+                                                    QuestStatus::OnHold => "text-sm mt-1 text-gray-600",
                                                 };
                                                 rsx! {
                                                     div { class: quest_class,
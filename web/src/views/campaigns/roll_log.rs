@@ -0,0 +1,223 @@
+use dioxus::prelude::*;
+use crate::api::campaign::{get_party_vitals, get_roll_log, record_roll, NewRoll, PartyVitals, RollEntry, RollVisibility, ROLL_LOG_LENGTH};
+use crate::api::live::LiveEvent;
+use crate::components::dice_tray::total_class;
+use crate::components::{DiceTray, RolledLine};
+use super::{use_active_campaign, use_live_events};
+
+/// A campaign's shared roll log as one view sees it, and how that view's own rolls are shared
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct RollLog {
+    campaign_id: Memo<Option<i32>>,
+    character_id: Memo<Option<i32>>,
+    entries: Signal<Vec<RollEntry>>,
+    party: Signal<Vec<PartyVitals>>,
+    visibility: Signal<RollVisibility>,
+    whisper_to: Signal<Option<i32>>,
+    error: Signal<Option<String>>,
+}
+
+impl RollLog {
+    /// Roll `line` again on the server for the campaign's log, when there is a campaign to add
+    /// it to. The server's dice then replace the local ones wherever `line` shows in `rolls`.
+    pub fn share(mut self, line: RolledLine, mut rolls: Signal<Vec<RolledLine>>) {
+        let Some(campaign_id) = (self.campaign_id)() else {
+            return;
+        };
+        let roll = NewRoll {
+            campaign_id,
+            character_id: (self.character_id)(),
+            purpose: line.label.clone(),
+            expression: line.result.expression.clone(),
+            visibility: (self.visibility)(),
+            whisper_to: (self.whisper_to)(),
+        };
+        spawn(async move {
+            match record_roll(roll).await {
+                Ok(entry) => {
+                    self.error.set(None);
+                    if let Some(shown) = rolls.write().iter_mut().find(|shown| **shown == line) {
+                        shown.result = entry.result.clone();
+                    }
+                    self.add(entry);
+                }
+                Err(e) => self.error.set(Some(format!("Failed to share roll: {}", e))),
+            }
+        });
+    }
+
+    /// Keep up with the campaign's events, as passed to [`use_live_events`]
+    pub fn handle(self, event: &LiveEvent) {
+        match event {
+            LiveEvent::Roll(entry) => self.add(entry.clone()),
+            LiveEvent::Connected => self.reload(),
+            _ => {}
+        }
+    }
+
+    fn reload(self) {
+        let RollLog { mut entries, mut party, mut error, .. } = self;
+        let Some(campaign_id) = (self.campaign_id)() else {
+            entries.set(vec![]);
+            return;
+        };
+        spawn(async move {
+            match get_roll_log(campaign_id).await {
+                Ok(fetched) => entries.set(fetched),
+                Err(e) => error.set(Some(format!("Failed to load rolls: {}", e))),
+            }
+            if let Ok(fetched) = get_party_vitals(campaign_id).await {
+                party.set(fetched);
+            }
+        });
+    }
+
+    // The roller hears about their roll twice, once in the reply and once from the stream
+    fn add(mut self, entry: RollEntry) {
+        let mut entries = self.entries.write();
+        if !entries.iter().any(|existing| existing.id == entry.id) {
+            entries.insert(0, entry);
+            entries.truncate(ROLL_LOG_LENGTH);
+        }
+    }
+
+    fn party_name(&self, character_id: i32) -> String {
+        self.party
+            .read()
+            .iter()
+            .find(|member| member.character_id == character_id)
+            .map(|member| member.name.clone())
+            .unwrap_or_else(|| "someone".to_string())
+    }
+}
+
+/// The roll log of `campaign_id`. Pass the campaign's events to [`RollLog::handle`] to see
+/// others' rolls as they happen. Rolls shared through it are made as `character_id` when set,
+/// and start out shown to `visibility`.
+pub(crate) fn use_roll_log(campaign_id: Memo<Option<i32>>, character_id: Memo<Option<i32>>, visibility: RollVisibility) -> RollLog {
+    let log = RollLog {
+        campaign_id,
+        character_id,
+        entries: use_signal(Vec::new),
+        party: use_signal(Vec::new),
+        visibility: use_signal(|| visibility),
+        whisper_to: use_signal(|| None),
+        error: use_signal(|| None),
+    };
+
+    // Load whenever the campaign changes
+    use_effect(move || {
+        campaign_id();
+        log.reload();
+    });
+
+    log
+}
+
+fn visibility_badge(entry: &RollEntry, log: &RollLog) -> Option<String> {
+    match entry.visibility {
+        RollVisibility::Public => None,
+        RollVisibility::Dm => Some("DM only".to_string()),
+        RollVisibility::Whisper => Some(format!("whisper to {}", entry.whisper_to.map(|id| log.party_name(id)).unwrap_or_default())),
+    }
+}
+
+/// Who the next shared roll goes to, and the campaign's latest rolls
+#[component]
+pub(crate) fn RollLogPanel(log: RollLog, class: Option<String>) -> Element {
+    let RollLog { mut visibility, mut whisper_to, .. } = log;
+    let custom_class = class.unwrap_or_else(|| "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg".to_string());
+
+    rsx! {
+        div { class: "{custom_class}",
+            h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "Roll Log" }
+
+            div { class: "flex flex-wrap items-center gap-2 mb-4 text-sm",
+                span { class: "text-gray-500 dark:text-gray-400", "Share with" }
+                select {
+                    class: "px-2 py-1 border border-gray-300 dark:border-gray-600 rounded dark:bg-gray-700 dark:text-white",
+                    onchange: move |e| {
+                        let chosen = RollVisibility::ALL.into_iter().find(|v| v.label() == e.value()).unwrap_or_default();
+                        visibility.set(chosen);
+                        if chosen == RollVisibility::Whisper && whisper_to().is_none() {
+                            whisper_to.set(log.party.read().first().map(|member| member.character_id));
+                        }
+                    },
+                    for option in RollVisibility::ALL {
+                        option { value: option.label(), selected: visibility() == option, "{option.label()}" }
+                    }
+                }
+                if visibility() == RollVisibility::Whisper {
+                    select {
+                        class: "px-2 py-1 border border-gray-300 dark:border-gray-600 rounded dark:bg-gray-700 dark:text-white",
+                        onchange: move |e| whisper_to.set(e.value().parse().ok()),
+                        for member in log.party.read().iter() {
+                            option {
+                                value: "{member.character_id}",
+                                selected: whisper_to() == Some(member.character_id),
+                                "{member.name}"
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(message) = log.error.read().as_ref() {
+                p { class: "text-sm text-red-600 mb-2", role: "alert", "{message}" }
+            }
+
+            if log.entries.read().is_empty() {
+                p { class: "text-sm text-gray-500 dark:text-gray-400", "No rolls yet." }
+            }
+            ul { class: "space-y-2",
+                for entry in log.entries.read().iter() {
+                    li { key: "{entry.id}", class: "flex items-center justify-between gap-3 border-t border-gray-100 dark:border-gray-700 pt-2",
+                        div { class: "min-w-0",
+                            div { class: "text-sm text-gray-700 dark:text-gray-300",
+                                span { class: "font-semibold", "{entry.roller_name}" }
+                                if !entry.purpose.is_empty() {
+                                    " · {entry.purpose}"
+                                }
+                                if let Some(badge) = visibility_badge(entry, &log) {
+                                    span { class: "ml-2 px-2 py-0.5 text-xs rounded-full bg-gray-200 text-gray-700", "{badge}" }
+                                }
+                            }
+                            div { class: "text-xs font-mono text-gray-500 dark:text-gray-400 break-all", "{entry.result.breakdown}" }
+                        }
+                        span { class: total_class(&entry.result), "{entry.total}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Everyone's rolls in the campaign, with a dice tray whose rolls go straight into the log
+#[component]
+pub fn CampaignRolls(campaign_slug: String) -> Element {
+    let (campaign, campaign_error) = use_active_campaign();
+    let campaign_id = use_memo(move || campaign.read().as_ref().map(|c| c.id));
+    let character_id = use_memo(|| None);
+    let log = use_roll_log(campaign_id, character_id, RollVisibility::Public);
+    use_live_events(campaign_id, move |event| log.handle(&event));
+    let rolls = use_signal::<Vec<RolledLine>>(|| vec![]);
+
+    rsx! {
+        div { class: "max-w-5xl mx-auto py-6 px-4",
+            h1 { class: "text-3xl font-bold text-purple-900 mb-6", "Rolls" }
+
+            if let Some(err) = campaign_error.read().as_ref() {
+                div {
+                    class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6",
+                    role: "alert",
+                    p { "{err}" }
+                }
+            }
+
+            div { class: "grid grid-cols-1 md:grid-cols-2 gap-6",
+                DiceTray { rolls, on_roll: move |line: RolledLine| log.share(line, rolls) }
+                RollLogPanel { log }
+            }
+        }
+    }
+}
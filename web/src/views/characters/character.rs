@@ -1,7 +1,12 @@
 use dioxus::prelude::*;
 use crate::api::{get_character};
 use crate::Route;
-use crate::api::character::CharacterDetail;
+use crate::api::character::{CharacterAccess, CharacterDetail};
+use crate::rules::features::Feature;
+use crate::rules::inventory::Inventory;
+use crate::rules::play::PlayState;
+use crate::rules::spellbook::Spellbook;
+use crate::rules::{self, Alignment, Background, Class, ClassLevel, Race, Subclass};
 
 /// The Character page component
 #[component]
@@ -42,62 +47,69 @@ pub fn Character() -> Element {
                                 slug: "gandalf-the-wizard".to_string(),
                                 name: "Gandalf the Grey".to_string(),
                                 description: "A wise and powerful wizard who guides the Fellowship on their quest.".to_string(),
-                                race: "Maia".to_string(),
-                                class: "Wizard".to_string(),
-                                level: 20,
-                                background: "Sage".to_string(),
-                                alignment: "Neutral Good".to_string(),
+                                race: Some(Race::Human),
+                                classes: vec![ClassLevel { class: Class::Wizard, level: 20, subclass: Some(Subclass::Evocation) }],
+                                background: Some(Background::Sage),
+                                alignment: Some(Alignment::NeutralGood),
                                 strength: 13,
                                 dexterity: 16,
                                 constitution: 16,
                                 intelligence: 25,
                                 wisdom: 18,
                                 charisma: 16,
+                                ability_rolls: vec![],
                                 hit_points: 165,
                                 armor_class: 17,
                                 speed: 30,
                                 proficiency_bonus: 6,
                                 skills: vec!["Arcana".to_string(), "History".to_string(), "Investigation".to_string(), "Religion".to_string()],
                                 languages: vec!["Common".to_string(), "Elvish".to_string(), "Dwarven".to_string(), "Orcish".to_string()],
-                                equipment: vec!["Staff of Power".to_string(), "Robes of the Archmagi".to_string(), "Ring of Protection".to_string()],
-                                spells: vec!["Fireball".to_string(), "Lightning Bolt".to_string(), "Teleport".to_string(), "Counterspell".to_string()],
-                                features: vec!["Spellcasting".to_string(), "Arcane Recovery".to_string(), "Spell Mastery".to_string()],
+                                inventory: Inventory::from_names(&["Staff of Power", "Robes of the Archmagi", "Ring of Protection"]),
+                                spellbook: Spellbook::from_names(&["Fireball", "Lightning Bolt", "Teleport", "Counterspell"]),
+                                play_state: PlayState::default(),
+                                features: vec![Feature::named("Spellcasting"), Feature::named("Arcane Recovery"), Feature::named("Spell Mastery")],
                                 backstory: "One of the Istari, sent to Middle-earth to guide and counsel the free peoples in their struggle against the dark lord Sauron.".to_string(),
                                 portrait_image: None,
                                 created_at: Some("2024-01-01".to_string()),
                                 updated_at: None,
                                 owner: "System".to_string(),
+                                access: CharacterAccess::NoAccess,
                             },
                             CharacterDetail {
                                 id: 2,
                                 slug: "aragorn-ranger".to_string(),
                                 name: "Aragorn, Son of Arathorn".to_string(),
                                 description: "A skilled ranger and rightful heir to the throne of Gondor.".to_string(),
-                                race: "Human".to_string(),
-                                class: "Ranger".to_string(),
-                                level: 15,
-                                background: "Folk Hero".to_string(),
-                                alignment: "Lawful Good".to_string(),
+                                race: Some(Race::Human),
+                                classes: vec![
+                                    ClassLevel { class: Class::Ranger, level: 12, subclass: Some(Subclass::Hunter) },
+                                    ClassLevel { class: Class::Fighter, level: 3, subclass: Some(Subclass::Champion) },
+                                ],
+                                background: Some(Background::FolkHero),
+                                alignment: Some(Alignment::LawfulGood),
                                 strength: 18,
                                 dexterity: 16,
                                 constitution: 16,
                                 intelligence: 14,
                                 wisdom: 15,
                                 charisma: 16,
+                                ability_rolls: vec![],
                                 hit_points: 142,
                                 armor_class: 18,
                                 speed: 30,
                                 proficiency_bonus: 5,
                                 skills: vec!["Animal Handling".to_string(), "Athletics".to_string(), "Insight".to_string(), "Perception".to_string(), "Survival".to_string()],
                                 languages: vec!["Common".to_string(), "Elvish".to_string(), "Dwarven".to_string()],
-                                equipment: vec!["Andúril".to_string(), "Chainmail".to_string(), "Longbow".to_string(), "Ranger's Pack".to_string()],
-                                spells: vec!["Hunter's Mark".to_string(), "Cure Wounds".to_string(), "Pass Without Trace".to_string()],
-                                features: vec!["Favored Enemy (Orcs)".to_string(), "Natural Explorer (Forest)".to_string(), "Primeval Awareness".to_string()],
+                                inventory: Inventory::from_names(&["Andúril", "Chainmail", "Longbow", "Ranger's Pack"]),
+                                spellbook: Spellbook::from_names(&["Hunter's Mark", "Cure Wounds", "Pass Without Trace"]),
+                                play_state: PlayState::default(),
+                                features: vec![Feature::named("Favored Enemy (Orcs)"), Feature::named("Natural Explorer (Forest)"), Feature::named("Primeval Awareness")],
                                 backstory: "Raised by Elrond in Rivendell, Aragorn is the last heir of Isildur and destined to become King of Gondor and Arnor.".to_string(),
                                 portrait_image: None,
                                 created_at: Some("2024-01-01".to_string()),
                                 updated_at: None,
                                 owner: "System".to_string(),
+                                access: CharacterAccess::NoAccess,
                             },
                         ];
                         characters.set(mock_characters);
@@ -125,31 +137,33 @@ pub fn Character() -> Element {
                             slug: "connection-error".to_string(),
                             name: "Connection Error".to_string(),
                             description: "Failed to connect to Supabase - check configuration...".to_string(),
-                            race: "Unknown".to_string(),
-                            class: "System".to_string(),
-                            level: 1,
-                            background: "Error".to_string(),
-                            alignment: "True Neutral".to_string(),
+                            race: None,
+                            classes: vec![],
+                            background: None,
+                            alignment: None,
                             strength: 10,
                             dexterity: 10,
                             constitution: 10,
                             intelligence: 10,
                             wisdom: 10,
                             charisma: 10,
+                            ability_rolls: vec![],
                             hit_points: 8,
                             armor_class: 10,
                             speed: 30,
                             proficiency_bonus: 2,
                             skills: vec!["Investigation".to_string()],
                             languages: vec!["Common".to_string()],
-                            equipment: vec!["Debugging Tools".to_string()],
-                            spells: vec![],
-                            features: vec!["Error Detection".to_string()],
+                            inventory: Inventory::from_names(&["Debugging Tools"]),
+                            spellbook: Spellbook::default(),
+                            play_state: PlayState::default(),
+                            features: vec![Feature::named("Error Detection")],
                             backstory: "Unable to connect to Supabase. Please check your environment variables and database configuration.".to_string(),
                             portrait_image: None,
                             created_at: Some("2024-01-01".to_string()),
                             updated_at: None,
                             owner: "System".to_string(),
+                            access: CharacterAccess::NoAccess,
                         },
                     ];
                     characters.set(debug_characters);
@@ -169,7 +183,7 @@ pub fn Character() -> Element {
                     }
                 }
                 Link {
-                    to: Route::CharacterEdit { slug: "new".to_string() },
+                    to: Route::CharacterCreate {},
                     class: "inline-flex items-center px-6 py-3 bg-blue-600 text-white rounded-lg hover:bg-blue-700 transition-colors",
                     svg {
                         class: "w-5 h-5 mr-2",
@@ -251,10 +265,6 @@ pub fn Character() -> Element {
 /// Individual character card component
 #[component]
 fn CharacterCard(character: CharacterDetail) -> Element {
-    let ability_modifier = |score: i32| -> i32 {
-        (score - 10) / 2
-    };
-
     rsx! {
         article { class: "bg-white dark:bg-gray-800 rounded-xl shadow-lg hover:shadow-xl transition-all duration-300 overflow-hidden group",
             // Portrait image if available
@@ -282,7 +292,7 @@ fn CharacterCard(character: CharacterDetail) -> Element {
                 div { class: "flex items-center justify-between mb-4",
                     div { class: "flex items-center text-sm text-gray-500 dark:text-gray-400",
                         span { class: "bg-blue-100 dark:bg-blue-900 text-blue-800 dark:text-blue-200 px-2 py-1 rounded-full text-xs font-medium",
-                            "Level {character.level}"
+                            "Level {character.level()}"
                         }
                     }
                     div { class: "text-sm text-gray-500 dark:text-gray-400", 
@@ -303,7 +313,7 @@ fn CharacterCard(character: CharacterDetail) -> Element {
                 
                 // Race and Class
                 div { class: "text-sm text-gray-600 dark:text-gray-400 mb-3",
-                    "{character.race} {character.class}"
+                    "{character.race_and_class()}"
                 }
                 
                 // Description
@@ -316,19 +326,19 @@ fn CharacterCard(character: CharacterDetail) -> Element {
                     div { class: "text-center bg-gray-50 dark:bg-gray-700 rounded p-2",
                         div { class: "font-semibold text-gray-900 dark:text-white", "STR" }
                         div { class: "text-gray-600 dark:text-gray-400", 
-                            "{character.strength} ({ability_modifier(character.strength):+})" 
+                            "{character.strength} ({rules::ability_modifier(character.strength):+})" 
                         }
                     }
                     div { class: "text-center bg-gray-50 dark:bg-gray-700 rounded p-2",
                         div { class: "font-semibold text-gray-900 dark:text-white", "DEX" }
                         div { class: "text-gray-600 dark:text-gray-400", 
-                            "{character.dexterity} ({ability_modifier(character.dexterity):+})" 
+                            "{character.dexterity} ({rules::ability_modifier(character.dexterity):+})" 
                         }
                     }
                     div { class: "text-center bg-gray-50 dark:bg-gray-700 rounded p-2",
                         div { class: "font-semibold text-gray-900 dark:text-white", "CON" }
                         div { class: "text-gray-600 dark:text-gray-400", 
-                            "{character.constitution} ({ability_modifier(character.constitution):+})" 
+                            "{character.constitution} ({rules::ability_modifier(character.constitution):+})" 
                         }
                     }
                 }
@@ -1,15 +1,36 @@
 use dioxus::prelude::*;
-use crate::api::character::CharacterDetail;
+use crate::api::campaign::{get_character_campaigns, Campaign, RollVisibility};
+use crate::api::character::{get_character_with_slug, CharacterDetail};
+use crate::components::{DiceTray, RolledLine};
+use crate::rules::dice::{self, Advantage};
+use crate::rules::inventory::format_copper;
+use crate::rules::leveling::MAX_LEVEL;
+use crate::rules::play;
+use crate::rules::{DerivedStats, Proficiency};
+use crate::api::live::LiveEvent;
+use crate::views::campaigns::{use_live_events, use_roll_log, RollLogPanel};
+use crate::views::references::{use_reference_favorites, PinnedReferences};
 use crate::Route;
+use super::features::FeatureList;
+use super::level_up::{LevelHistory, LevelUpPanel};
+use super::play::PlayTracker;
 
 // Character Detail Component
 #[component]
 pub fn CharacterById(slug: String) -> Element {
-    let character = use_signal::<Option<CharacterDetail>>(|| None);
+    let mut character = use_signal::<Option<CharacterDetail>>(|| None);
+    let mut levelling_up = use_signal(|| false);
+    let mut playing = use_signal(|| false);
+    // Bumped after each level-up so the history reloads
+    let mut history_version = use_signal(|| 0);
     let loading = use_signal(|| true);
     let error = use_signal::<Option<String>>(|| None);
+    let mut rolls = use_signal::<Vec<RolledLine>>(|| vec![]);
+    let mut campaigns = use_signal::<Vec<Campaign>>(|| vec![]);
+    let mut rolling_in = use_signal::<Option<i32>>(|| None);
+    let (pinned, pinned_error) = use_reference_favorites();
 
-    // Set up mock data for the character based on slug
+    // Load the character for this slug
     use_effect(move || {
         let mut character_signal = character.clone();
         let mut loading_signal = loading.clone();
@@ -20,113 +41,71 @@ pub fn CharacterById(slug: String) -> Element {
             loading_signal.set(true);
             error_signal.set(None);
 
-            // For now, use mock data based on the slug
-            let mock_character = if current_slug == "gandalf-the-wizard" {
-                CharacterDetail {
-                    id: 1,
-                    slug: "gandalf-the-wizard".to_string(),
-                    name: "Gandalf the Grey".to_string(),
-                    description: "A wise and powerful wizard who guides the Fellowship on their quest.".to_string(),
-                    race: "Maia".to_string(),
-                    class: "Wizard".to_string(),
-                    level: 20,
-                    background: "Sage".to_string(),
-                    alignment: "Neutral Good".to_string(),
-                    strength: 13,
-                    dexterity: 16,
-                    constitution: 16,
-                    intelligence: 25,
-                    wisdom: 18,
-                    charisma: 16,
-                    hit_points: 165,
-                    armor_class: 17,
-                    speed: 30,
-                    proficiency_bonus: 6,
-                    skills: vec!["Arcana".to_string(), "History".to_string(), "Investigation".to_string(), "Religion".to_string()],
-                    languages: vec!["Common".to_string(), "Elvish".to_string(), "Dwarven".to_string(), "Orcish".to_string()],
-                    equipment: vec!["Staff of Power".to_string(), "Robes of the Archmagi".to_string(), "Ring of Protection".to_string()],
-                    spells: vec!["Fireball".to_string(), "Lightning Bolt".to_string(), "Teleport".to_string(), "Counterspell".to_string()],
-                    features: vec!["Spellcasting".to_string(), "Arcane Recovery".to_string(), "Spell Mastery".to_string()],
-                    backstory: "One of the Istari, sent to Middle-earth to guide and counsel the free peoples in their struggle against the dark lord Sauron.".to_string(),
-                    portrait_image: None,
-                    created_at: Some("2024-01-01".to_string()),
-                    updated_at: None,
-                    owner: "System".to_string(),
-                }
-            } else if current_slug == "aragorn-ranger" {
-                CharacterDetail {
-                    id: 2,
-                    slug: "aragorn-ranger".to_string(),
-                    name: "Aragorn, Son of Arathorn".to_string(),
-                    description: "A skilled ranger and rightful heir to the throne of Gondor.".to_string(),
-                    race: "Human".to_string(),
-                    class: "Ranger".to_string(),
-                    level: 15,
-                    background: "Folk Hero".to_string(),
-                    alignment: "Lawful Good".to_string(),
-                    strength: 18,
-                    dexterity: 16,
-                    constitution: 16,
-                    intelligence: 14,
-                    wisdom: 15,
-                    charisma: 16,
-                    hit_points: 142,
-                    armor_class: 18,
-                    speed: 30,
-                    proficiency_bonus: 5,
-                    skills: vec!["Animal Handling".to_string(), "Athletics".to_string(), "Insight".to_string(), "Perception".to_string(), "Survival".to_string()],
-                    languages: vec!["Common".to_string(), "Elvish".to_string(), "Dwarven".to_string()],
-                    equipment: vec!["Andúril".to_string(), "Chainmail".to_string(), "Longbow".to_string(), "Ranger's Pack".to_string()],
-                    spells: vec!["Hunter's Mark".to_string(), "Cure Wounds".to_string(), "Pass Without Trace".to_string()],
-                    features: vec!["Favored Enemy (Orcs)".to_string(), "Natural Explorer (Forest)".to_string(), "Primeval Awareness".to_string()],
-                    backstory: "Raised by Elrond in Rivendell, Aragorn is the last heir of Isildur and destined to become King of Gondor and Arnor.".to_string(),
-                    portrait_image: None,
-                    created_at: Some("2024-01-01".to_string()),
-                    updated_at: None,
-                    owner: "System".to_string(),
-                }
-            } else {
-                // Default character for unknown slugs
-                CharacterDetail {
-                    id: 999,
-                    slug: current_slug.clone(),
-                    name: "Unknown Character".to_string(),
-                    description: "Character not found".to_string(),
-                    race: "Unknown".to_string(),
-                    class: "Unknown".to_string(),
-                    level: 1,
-                    background: "Unknown".to_string(),
-                    alignment: "True Neutral".to_string(),
-                    strength: 10,
-                    dexterity: 10,
-                    constitution: 10,
-                    intelligence: 10,
-                    wisdom: 10,
-                    charisma: 10,
-                    hit_points: 8,
-                    armor_class: 10,
-                    speed: 30,
-                    proficiency_bonus: 2,
-                    skills: vec![],
-                    languages: vec!["Common".to_string()],
-                    equipment: vec![],
-                    spells: vec![],
-                    features: vec![],
-                    backstory: "This character could not be found.".to_string(),
-                    portrait_image: None,
-                    created_at: Some("2024-01-01".to_string()),
-                    updated_at: None,
-                    owner: "System".to_string(),
-                }
-            };
-
-            character_signal.set(Some(mock_character));
+            match get_character_with_slug(current_slug).await {
+                Ok(fetched_character) => character_signal.set(Some(fetched_character)),
+                Err(e) => error_signal.set(Some(format!("Failed to load character: {}", e))),
+            }
             loading_signal.set(false);
         });
     });
 
-    let ability_modifier = |score: i32| -> i32 {
-        (score - 10) / 2
+    // Everything on the sheet that follows from the stored scores, class and level
+    let stats = use_memo(move || character.read().as_ref().map(DerivedStats::for_character));
+
+    let on_leveled = move |leveled: CharacterDetail| {
+        character.set(Some(leveled));
+        levelling_up.set(false);
+        history_version += 1;
+    };
+
+    let on_played = move |changed: CharacterDetail| character.set(Some(changed));
+
+    // Whoever can play the character shares its rolls with the campaign it plays in
+    let character_id = use_memo(move || character.read().as_ref().map(|c| c.id));
+    let rolling_as = use_memo(move || character.read().as_ref().filter(|c| c.access.can_edit()).map(|c| c.id));
+    let campaign_id = use_memo(move || rolling_in());
+    let roll_log = use_roll_log(campaign_id, rolling_as, RollVisibility::Public);
+
+    // The DM and the player can both change the character mid-session
+    use_live_events(campaign_id, move |event| {
+        roll_log.handle(&event);
+        let changed = match event {
+            LiveEvent::Character { character_id: changed } => character_id() == Some(changed),
+            LiveEvent::Connected => true,
+            _ => false,
+        };
+        let Some(current_slug) = character.read().as_ref().map(|c| c.slug.clone()).filter(|_| changed) else {
+            return;
+        };
+        spawn(async move {
+            if let Ok(fetched) = get_character_with_slug(current_slug).await {
+                character.set(Some(fetched));
+            }
+        });
+    });
+
+    use_effect(move || {
+        let Some(character_id) = character_id() else {
+            return;
+        };
+        spawn(async move {
+            if let Ok(fetched) = get_character_campaigns(character_id).await {
+                rolling_in.set(fetched.first().map(|c| c.id));
+                campaigns.set(fetched);
+            }
+        });
+    });
+
+    // Checks, saves and attacks from the sheet land in the same list as the dice tray
+    let mut roll_d20 = move |label: String, bonus: i32| {
+        if let Ok(line) = RolledLine::roll(label, &dice::d20(bonus, Advantage::Normal)) {
+            if rolling_as().is_some() {
+                roll_log.share(line.clone(), rolls);
+            }
+            let mut rolls = rolls.write();
+            rolls.insert(0, line);
+            rolls.truncate(10);
+        }
     };
 
     rsx! {
@@ -150,7 +129,7 @@ pub fn CharacterById(slug: String) -> Element {
                 }
             }
             // Character content
-            if let Some(character) = character.read().as_ref() {
+            if let (Some(character), Some(stats)) = (character.read().as_ref(), stats.read().as_ref()) {
                 div {
                     // Back button and edit button
                     div { class: "flex items-center justify-between mb-8",
@@ -171,22 +150,57 @@ pub fn CharacterById(slug: String) -> Element {
                             }
                             "Back to Characters"
                         }
-                        Link {
-                            to: Route::CharacterEdit { slug: character.slug.clone() },
-                            class: "inline-flex items-center px-4 py-2 bg-blue-600 text-white rounded-lg hover:bg-blue-700 transition-colors",
-                            svg {
-                                class: "w-4 h-4 mr-2",
-                                fill: "none",
-                                stroke: "currentColor",
-                                view_box: "0 0 24 24",
-                                path {
-                                    stroke_linecap: "round",
-                                    stroke_linejoin: "round",
-                                    stroke_width: "2",
-                                    d: "M11 5H6a2 2 0 00-2 2v11a2 2 0 002 2h11a2 2 0 002-2v-5m-1.414-9.414a2 2 0 112.828 2.828L11.828 15H9v-2.828l8.586-8.586z",
+                        if character.access.can_edit() {
+                            div { class: "flex items-center gap-3",
+                                button {
+                                    r#type: "button",
+                                    onclick: move |_| playing.toggle(),
+                                    class: "inline-flex items-center px-4 py-2 bg-gray-700 text-white rounded-lg hover:bg-gray-800 transition-colors",
+                                    if playing() { "Close Play Mode" } else { "Play Mode" }
+                                }
+                                if stats.level < MAX_LEVEL && !levelling_up() {
+                                    button {
+                                        r#type: "button",
+                                        onclick: move |_| levelling_up.set(true),
+                                        class: "inline-flex items-center px-4 py-2 bg-green-600 text-white rounded-lg hover:bg-green-700 transition-colors",
+                                        "Level Up"
+                                    }
+                                }
+                                Link {
+                                    to: Route::CharacterEdit { slug: character.slug.clone() },
+                                    class: "inline-flex items-center px-4 py-2 bg-blue-600 text-white rounded-lg hover:bg-blue-700 transition-colors",
+                                    svg {
+                                        class: "w-4 h-4 mr-2",
+                                        fill: "none",
+                                        stroke: "currentColor",
+                                        view_box: "0 0 24 24",
+                                        path {
+                                            stroke_linecap: "round",
+                                            stroke_linejoin: "round",
+                                            stroke_width: "2",
+                                            d: "M11 5H6a2 2 0 00-2 2v11a2 2 0 002 2h11a2 2 0 002-2v-5m-1.414-9.414a2 2 0 112.828 2.828L11.828 15H9v-2.828l8.586-8.586z",
+                                        }
+                                    }
+                                    "Edit Character"
                                 }
                             }
-                            "Edit Character"
+                        } else {
+                            span { class: "px-3 py-1 text-sm bg-gray-100 text-gray-600 rounded-full", "Read only" }
+                        }
+                    }
+
+                    if playing() && character.access.can_edit() {
+                        PlayTracker {
+                            character: character.clone(),
+                            on_change: on_played,
+                        }
+                    }
+
+                    if levelling_up() {
+                        LevelUpPanel {
+                            character: character.clone(),
+                            on_close: move |_| levelling_up.set(false),
+                            on_leveled,
                         }
                     }
 
@@ -218,16 +232,16 @@ pub fn CharacterById(slug: String) -> Element {
                                     "{character.name}"
                                 }
                                 div { class: "text-lg text-gray-600 dark:text-gray-400 mb-4",
-                                    "Level {character.level} {character.race} {character.class}"
+                                    "Level {stats.level} {character.race_and_class()}"
                                 }
                                 div { class: "grid grid-cols-2 md:grid-cols-4 gap-4 mb-4",
                                     div {
                                         div { class: "text-sm text-gray-500 dark:text-gray-400", "Background" }
-                                        div { class: "font-semibold", "{character.background}" }
+                                        div { class: "font-semibold", {character.background.map(|b| b.to_string()).unwrap_or_else(|| "None".to_string())} }
                                     }
                                     div {
                                         div { class: "text-sm text-gray-500 dark:text-gray-400", "Alignment" }
-                                        div { class: "font-semibold", "{character.alignment}" }
+                                        div { class: "font-semibold", {character.alignment.map(|a| a.to_string()).unwrap_or_else(|| "Unknown".to_string())} }
                                     }
                                     div {
                                         div { class: "text-sm text-gray-500 dark:text-gray-400", "Armor Class" }
@@ -235,7 +249,32 @@ pub fn CharacterById(slug: String) -> Element {
                                     }
                                     div {
                                         div { class: "text-sm text-gray-500 dark:text-gray-400", "Hit Points" }
-                                        div { class: "font-semibold", "{character.hit_points}" }
+                                        div { class: "font-semibold",
+                                            {
+                                                let max = play::max_hit_points(character);
+                                                let current = character.play_state.current_hit_points(max);
+                                                let temporary = character.play_state.temporary_hit_points;
+                                                if temporary > 0 { format!("{} / {} (+{})", current, max, temporary) } else { format!("{} / {}", current, max) }
+                                            }
+                                        }
+                                    }
+                                }
+                                if !character.play_state.conditions.is_empty() || character.play_state.exhaustion > 0 {
+                                    div { class: "flex flex-wrap gap-2 mb-4",
+                                        for condition in character.play_state.conditions.iter() {
+                                            span {
+                                                class: "px-2 py-1 text-xs rounded-full bg-red-100 text-red-800",
+                                                title: condition.effect(),
+                                                "{condition}"
+                                            }
+                                        }
+                                        if character.play_state.exhaustion > 0 {
+                                            span {
+                                                class: "px-2 py-1 text-xs rounded-full bg-orange-100 text-orange-800",
+                                                title: play::exhaustion_effect(character.play_state.exhaustion),
+                                                "Exhaustion {character.play_state.exhaustion}"
+                                            }
+                                        }
                                     }
                                 }
                                 p { class: "text-gray-700 dark:text-gray-300",
@@ -250,35 +289,41 @@ pub fn CharacterById(slug: String) -> Element {
                         div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg",
                             h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "Ability Scores" }
                             div { class: "grid grid-cols-2 gap-4",
-                                div { class: "text-center bg-gray-50 dark:bg-gray-700 rounded-lg p-3",
-                                    div { class: "text-sm text-gray-500 dark:text-gray-400", "STR" }
-                                    div { class: "text-2xl font-bold text-gray-900 dark:text-white", "{character.strength}" }
-                                    div { class: "text-sm text-gray-600 dark:text-gray-400", "{ability_modifier(character.strength):+}" }
-                                }
-                                div { class: "text-center bg-gray-50 dark:bg-gray-700 rounded-lg p-3",
-                                    div { class: "text-sm text-gray-500 dark:text-gray-400", "DEX" }
-                                    div { class: "text-2xl font-bold text-gray-900 dark:text-white", "{character.dexterity}" }
-                                    div { class: "text-sm text-gray-600 dark:text-gray-400", "{ability_modifier(character.dexterity):+}" }
-                                }
-                                div { class: "text-center bg-gray-50 dark:bg-gray-700 rounded-lg p-3",
-                                    div { class: "text-sm text-gray-500 dark:text-gray-400", "CON" }
-                                    div { class: "text-2xl font-bold text-gray-900 dark:text-white", "{character.constitution}" }
-                                    div { class: "text-sm text-gray-600 dark:text-gray-400", "{ability_modifier(character.constitution):+}" }
-                                }
-                                div { class: "text-center bg-gray-50 dark:bg-gray-700 rounded-lg p-3",
-                                    div { class: "text-sm text-gray-500 dark:text-gray-400", "INT" }
-                                    div { class: "text-2xl font-bold text-gray-900 dark:text-white", "{character.intelligence}" }
-                                    div { class: "text-sm text-gray-600 dark:text-gray-400", "{ability_modifier(character.intelligence):+}" }
-                                }
-                                div { class: "text-center bg-gray-50 dark:bg-gray-700 rounded-lg p-3",
-                                    div { class: "text-sm text-gray-500 dark:text-gray-400", "WIS" }
-                                    div { class: "text-2xl font-bold text-gray-900 dark:text-white", "{character.wisdom}" }
-                                    div { class: "text-sm text-gray-600 dark:text-gray-400", "{ability_modifier(character.wisdom):+}" }
+                                for ability in stats.abilities.iter() {
+                                    button {
+                                        r#type: "button",
+                                        title: "Roll a {ability.ability.name()} check",
+                                        onclick: {
+                                            let (label, bonus) = (format!("{} check", ability.ability.name()), ability.modifier);
+                                            move |_| roll_d20(label.clone(), bonus)
+                                        },
+                                        class: "text-center bg-gray-50 dark:bg-gray-700 rounded-lg p-3 hover:bg-gray-100 dark:hover:bg-gray-600",
+                                        div { class: "text-sm text-gray-500 dark:text-gray-400", "{ability.ability.abbreviation()}" }
+                                        div { class: "text-2xl font-bold text-gray-900 dark:text-white", "{ability.score}" }
+                                        div { class: "text-sm text-gray-600 dark:text-gray-400", "{ability.modifier:+}" }
+                                    }
                                 }
-                                div { class: "text-center bg-gray-50 dark:bg-gray-700 rounded-lg p-3",
-                                    div { class: "text-sm text-gray-500 dark:text-gray-400", "CHA" }
-                                    div { class: "text-2xl font-bold text-gray-900 dark:text-white", "{character.charisma}" }
-                                    div { class: "text-sm text-gray-600 dark:text-gray-400", "{ability_modifier(character.charisma):+}" }
+                            }
+
+                            // Saving Throws
+                            h3 { class: "text-lg font-semibold mt-6 mb-3 text-gray-900 dark:text-white", "Saving Throws" }
+                            ul { class: "space-y-1",
+                                for ability in stats.abilities.iter() {
+                                    li { class: "flex items-center justify-between text-sm",
+                                        span { class: if ability.save_proficient { "font-semibold text-gray-900 dark:text-white" } else { "text-gray-600 dark:text-gray-400" },
+                                            if ability.save_proficient { "● " } else { "○ " }
+                                            "{ability.ability.name()}"
+                                        }
+                                        button {
+                                            r#type: "button",
+                                            onclick: {
+                                                let (label, bonus) = (format!("{} save", ability.ability.name()), ability.saving_throw);
+                                                move |_| roll_d20(label.clone(), bonus)
+                                            },
+                                            class: "font-mono hover:text-blue-600",
+                                            "{ability.saving_throw:+}"
+                                        }
+                                    }
                                 }
                             }
                         }
@@ -295,19 +340,89 @@ pub fn CharacterById(slug: String) -> Element {
                                     }
                                     div {
                                         div { class: "text-sm text-gray-500 dark:text-gray-400", "Proficiency" }
-                                        div { class: "text-lg font-semibold", "+{character.proficiency_bonus}" }
+                                        div { class: "text-lg font-semibold", "{stats.proficiency_bonus:+}" }
+                                    }
+                                    div {
+                                        div { class: "text-sm text-gray-500 dark:text-gray-400", "Initiative" }
+                                        button {
+                                            r#type: "button",
+                                            onclick: {
+                                                let bonus = stats.initiative;
+                                                move |_| roll_d20("Initiative".to_string(), bonus)
+                                            },
+                                            class: "text-lg font-semibold hover:text-blue-600",
+                                            "{stats.initiative:+}"
+                                        }
+                                    }
+                                    div {
+                                        div { class: "text-sm text-gray-500 dark:text-gray-400", "Passive Perception" }
+                                        div { class: "text-lg font-semibold", "{stats.passive_perception}" }
+                                    }
+                                    div {
+                                        div { class: "text-sm text-gray-500 dark:text-gray-400", "Hit Dice" }
+                                        div { class: "text-lg font-semibold",
+                                            {stats.hit_dice.iter().map(|(die, count)| format!("{}d{}", count, die)).collect::<Vec<_>>().join(" + ")}
+                                        }
+                                    }
+                                    for spellcasting in stats.spellcasting.iter() {
+                                        div {
+                                            div { class: "text-sm text-gray-500 dark:text-gray-400", "Spell Save DC ({spellcasting.ability.abbreviation()})" }
+                                            div { class: "text-lg font-semibold", "{spellcasting.save_dc}" }
+                                        }
+                                        div {
+                                            div { class: "text-sm text-gray-500 dark:text-gray-400", "Spell Attack ({spellcasting.ability.abbreviation()})" }
+                                            button {
+                                                r#type: "button",
+                                                onclick: {
+                                                    let bonus = spellcasting.attack_bonus;
+                                                    move |_| roll_d20("Spell attack".to_string(), bonus)
+                                                },
+                                                class: "text-lg font-semibold hover:text-blue-600",
+                                                "{spellcasting.attack_bonus:+}"
+                                            }
+                                        }
+                                    }
+                                }
+                                if stats.spell_slots.iter().any(|slots| *slots > 0) || stats.pact_slots.is_some() {
+                                    h3 { class: "text-lg font-semibold mt-6 mb-3 text-gray-900 dark:text-white", "Spell Slots" }
+                                    div { class: "flex flex-wrap gap-2",
+                                        for (index, slots) in stats.spell_slots.iter().enumerate().filter(|(_, slots)| **slots > 0) {
+                                            span { class: "px-3 py-1 bg-purple-100 dark:bg-purple-900 text-purple-800 dark:text-purple-200 rounded-full text-sm font-medium",
+                                                "Level {index + 1}: {slots}"
+                                            }
+                                        }
+                                        if let Some(pact) = stats.pact_slots {
+                                            span { class: "px-3 py-1 bg-indigo-100 dark:bg-indigo-900 text-indigo-800 dark:text-indigo-200 rounded-full text-sm font-medium",
+                                                "Pact: {pact.count} × level {pact.slot_level}"
+                                            }
+                                        }
                                     }
                                 }
                             }
 
                             // Skills
-                            if !character.skills.is_empty() {
-                                div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg",
-                                    h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "Skills" }
-                                    div { class: "flex flex-wrap gap-2",
-                                        for skill in &character.skills {
-                                            span { class: "px-3 py-1 bg-green-100 dark:bg-green-900 text-green-800 dark:text-green-200 rounded-full text-sm font-medium",
-                                                "{skill}"
+                            div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg",
+                                h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "Skills" }
+                                ul { class: "space-y-1",
+                                    for skill in stats.skills.iter() {
+                                        li { class: "flex items-center justify-between text-sm",
+                                            span { class: if skill.proficiency == Proficiency::None { "text-gray-600 dark:text-gray-400" } else { "font-semibold text-gray-900 dark:text-white" },
+                                                match skill.proficiency {
+                                                    Proficiency::Expertise => "◆ ",
+                                                    Proficiency::Proficient => "● ",
+                                                    Proficiency::None => "○ ",
+                                                }
+                                                "{skill.skill.name()} "
+                                                span { class: "text-xs text-gray-400", "({skill.skill.ability().abbreviation()})" }
+                                            }
+                                            button {
+                                                r#type: "button",
+                                                onclick: {
+                                                    let (label, bonus) = (skill.skill.name().to_string(), skill.bonus);
+                                                    move |_| roll_d20(label.clone(), bonus)
+                                                },
+                                                class: "font-mono hover:text-blue-600",
+                                                "{skill.bonus:+}"
                                             }
                                         }
                                     }
@@ -331,62 +446,102 @@ pub fn CharacterById(slug: String) -> Element {
 
                         // Equipment & Features
                         div { class: "space-y-6",
-                            // Equipment
-                            if !character.equipment.is_empty() {
-                                div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg",
-                                    h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "Equipment" }
-                                    ul { class: "space-y-2",
-                                        for item in &character.equipment {
-                                            li { class: "flex items-center",
-                                                svg {
-                                                    class: "w-4 h-4 mr-2 text-gray-400",
-                                                    fill: "currentColor",
-                                                    view_box: "0 0 20 20",
-                                                    path { d: "M9 12l2 2 4-4m6 2a9 9 0 11-18 0 9 9 0 0118 0z" }
-                                                }
-                                                "{item}"
+                            DiceTray {
+                                rolls,
+                                on_roll: move |line: RolledLine| {
+                                    if rolling_as().is_some() {
+                                        roll_log.share(line, rolls);
+                                    }
+                                },
+                            }
+                            if rolling_in().is_some() {
+                                if campaigns.read().len() > 1 {
+                                    div { class: "bg-white dark:bg-gray-800 rounded-lg p-4 shadow-lg text-sm flex items-center gap-2",
+                                        span { class: "text-gray-500 dark:text-gray-400", "Rolling in" }
+                                        select {
+                                            class: "flex-1 px-2 py-1 border border-gray-300 dark:border-gray-600 rounded dark:bg-gray-700 dark:text-white",
+                                            onchange: move |e| rolling_in.set(e.value().parse().ok()),
+                                            for campaign in campaigns.read().iter() {
+                                                option { value: "{campaign.id}", selected: rolling_in() == Some(campaign.id), "{campaign.name}" }
                                             }
                                         }
                                     }
                                 }
+                                RollLogPanel { log: roll_log }
                             }
 
-                            // Spells
-                            if !character.spells.is_empty() {
-                                div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg",
-                                    h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "Spells" }
-                                    div { class: "flex flex-wrap gap-2",
-                                        for spell in &character.spells {
-                                            span { class: "px-3 py-1 bg-purple-100 dark:bg-purple-900 text-purple-800 dark:text-purple-200 rounded-full text-sm font-medium",
-                                                "{spell}"
+                            // Equipment
+                            div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg",
+                                div { class: "flex items-center justify-between mb-4",
+                                    h2 { class: "text-xl font-semibold text-gray-900 dark:text-white", "Equipment" }
+                                    Link {
+                                        to: Route::PlayerLoot { slug: character.slug.clone() },
+                                        class: "text-sm text-blue-600 dark:text-blue-400 hover:underline",
+                                        if character.access.can_edit() { "Manage" } else { "Details" }
+                                    }
+                                }
+                                ul { class: "space-y-2",
+                                    for item in character.inventory.items.iter().filter(|item| item.equipped || item.attuned) {
+                                        li { class: "flex items-center",
+                                            svg {
+                                                class: "w-4 h-4 mr-2 text-gray-400",
+                                                fill: "currentColor",
+                                                view_box: "0 0 20 20",
+                                                path { d: "M9 12l2 2 4-4m6 2a9 9 0 11-18 0 9 9 0 0118 0z" }
+                                            }
+                                            "{item.name}"
+                                            if item.attuned {
+                                                span { class: "ml-2 text-xs text-purple-600 dark:text-purple-300", "attuned" }
                                             }
                                         }
                                     }
                                 }
+                                div { class: "text-sm text-gray-600 dark:text-gray-400 mt-4",
+                                    "{character.inventory.items.len()} items carried • "
+                                    {format_copper(character.inventory.coins.total_in_copper())}
+                                }
                             }
 
-                            // Features
-                            if !character.features.is_empty() {
+                            // Spells
+                            if !character.spellbook.entries.is_empty() || !stats.spellcasting.is_empty() {
                                 div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg",
-                                    h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "Features" }
-                                    ul { class: "space-y-2",
-                                        for feature in &character.features {
-                                            li { class: "flex items-center",
-                                                svg {
-                                                    class: "w-4 h-4 mr-2 text-yellow-500",
-                                                    fill: "currentColor",
-                                                    view_box: "0 0 20 20",
-                                                    path { d: "M9.049 2.927c.3-.921 1.603-.921 1.902 0l1.07 3.292a1 1 0 00.95.69h3.462c.969 0 1.371 1.24.588 1.81l-2.8 2.034a1 1 0 00-.364 1.118l1.07 3.292c.3.921-.755 1.688-1.54 1.118l-2.8-2.034a1 1 0 00-1.175 0l-2.8 2.034c-.784.57-1.838-.197-1.539-1.118l1.07-3.292a1 1 0 00-.364-1.118L2.98 8.72c-.783-.57-.38-1.81.588-1.81h3.461a1 1 0 00.951-.69l1.07-3.292z" }
-                                                }
-                                                "{feature}"
+                                    div { class: "flex items-center justify-between mb-4",
+                                        h2 { class: "text-xl font-semibold text-gray-900 dark:text-white", "Spells" }
+                                        Link {
+                                            to: Route::CharacterSpellbook { slug: character.slug.clone() },
+                                            class: "text-sm text-blue-600 dark:text-blue-400 hover:underline",
+                                            if character.access.can_edit() { "Spellbook" } else { "Details" }
+                                        }
+                                    }
+                                    if let Some(entry) = character.spellbook.concentrating_on() {
+                                        p { class: "text-sm text-purple-700 dark:text-purple-300 mb-3",
+                                            "Concentrating on {entry.name}"
+                                        }
+                                    }
+                                    div { class: "flex flex-wrap gap-2",
+                                        for entry in character.spellbook.entries.iter().filter(|entry| entry.is_ready()) {
+                                            Link {
+                                                to: Route::SpellDetail { slug: entry.spell.clone() },
+                                                class: "px-3 py-1 bg-purple-100 dark:bg-purple-900 text-purple-800 dark:text-purple-200 rounded-full text-sm font-medium hover:bg-purple-200",
+                                                "{entry.name}"
                                             }
                                         }
                                     }
                                 }
                             }
+
+                            // Features
+                            FeatureList { character: character.clone(), on_change: on_played }
+
+                            // The viewer's own pinned rules, whoever's sheet this is
+                            PinnedReferences { favorites: pinned, error: pinned_error }
                         }
                     }
 
+                    if character.access.can_edit() {
+                        LevelHistory { character_id: character.id, version: history_version() }
+                    }
+
                     // Backstory
                     if !character.backstory.is_empty() {
                         div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 mt-6 shadow-lg",
@@ -403,4 +558,3 @@ pub fn CharacterById(slug: String) -> Element {
         }
     }
 }
-
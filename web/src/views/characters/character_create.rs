@@ -0,0 +1,785 @@
+use dioxus::prelude::*;
+use crate::Route;
+use crate::api::character::{create_character, roll_ability_scores, slugify, CharacterAccess, CharacterDetail};
+use crate::components::SelectInput;
+use crate::rules::creation::{
+    self, AbilityRoll, EquipmentPackage, POINT_BUY_BUDGET, POINT_BUY_MAX, POINT_BUY_MIN, STANDARD_ARRAY,
+};
+use crate::rules::inventory::Inventory;
+use crate::rules::{features, leveling};
+use crate::rules::play::PlayState;
+use crate::rules::spellbook::Spellbook;
+use crate::rules::{ability_modifier, Ability, Alignment, Background, Class, ClassLevel, DerivedStats, Race, Skill};
+
+const FIELD_CLASS: &str = "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-700 dark:text-white";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Step {
+    Details,
+    Race,
+    Class,
+    Background,
+    AbilityScores,
+    Skills,
+    Equipment,
+    Review,
+}
+
+impl Step {
+    const ALL: [Step; 8] = [
+        Step::Details,
+        Step::Race,
+        Step::Class,
+        Step::Background,
+        Step::AbilityScores,
+        Step::Skills,
+        Step::Equipment,
+        Step::Review,
+    ];
+
+    fn title(self) -> &'static str {
+        match self {
+            Step::Details => "Details",
+            Step::Race => "Race",
+            Step::Class => "Class",
+            Step::Background => "Background",
+            Step::AbilityScores => "Ability Scores",
+            Step::Skills => "Skills",
+            Step::Equipment => "Equipment",
+            Step::Review => "Review",
+        }
+    }
+}
+
+/// How the player generates their base ability scores
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum ScoreMethod {
+    #[default]
+    PointBuy,
+    StandardArray,
+    Roll,
+}
+
+/// Everything picked so far in the wizard
+#[derive(Clone, Debug, PartialEq)]
+struct Draft {
+    name: String,
+    description: String,
+    alignment: Option<Alignment>,
+    race: Option<Race>,
+    class: Option<Class>,
+    background: Option<Background>,
+    method: ScoreMethod,
+    /// Base scores bought with points, in `Ability::ALL` order
+    point_buy: [i32; 6],
+    /// For the standard array and rolling: which entry of the score pool each ability took
+    assigned: [Option<usize>; 6],
+    rolls: Vec<AbilityRoll>,
+    class_skills: Vec<Skill>,
+    package: usize,
+}
+
+impl Default for Draft {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            description: String::new(),
+            alignment: None,
+            race: None,
+            class: None,
+            background: None,
+            method: ScoreMethod::default(),
+            point_buy: [POINT_BUY_MIN; 6],
+            assigned: [None; 6],
+            rolls: vec![],
+            class_skills: vec![],
+            package: 0,
+        }
+    }
+}
+
+impl Draft {
+    /// The scores handed out by the standard array or the latest set of rolls
+    fn score_pool(&self) -> Vec<i32> {
+        match self.method {
+            ScoreMethod::PointBuy => vec![],
+            ScoreMethod::StandardArray => STANDARD_ARRAY.to_vec(),
+            ScoreMethod::Roll => {
+                let latest = self.latest_roll_set();
+                self.rolls.iter().filter(|roll| roll.set == latest).map(AbilityRoll::total).collect()
+            }
+        }
+    }
+
+    fn latest_roll_set(&self) -> i32 {
+        self.rolls.iter().map(|roll| roll.set).max().unwrap_or(0)
+    }
+
+    /// Base scores before racial bonuses, once every ability has one
+    fn base_scores(&self) -> Option<[i32; 6]> {
+        match self.method {
+            ScoreMethod::PointBuy => Some(self.point_buy),
+            ScoreMethod::StandardArray | ScoreMethod::Roll => {
+                let pool = self.score_pool();
+                let mut scores = [0; 6];
+                for (score, assigned) in scores.iter_mut().zip(self.assigned) {
+                    *score = *pool.get(assigned?)?;
+                }
+                Some(scores)
+            }
+        }
+    }
+
+    fn final_score(&self, ability: Ability) -> Option<i32> {
+        let index = Ability::ALL.iter().position(|a| *a == ability)?;
+        let racial = self.race.map_or(0, |race| race.ability_bonus(ability));
+        self.base_scores().map(|scores| scores[index] + racial)
+    }
+
+    fn granted_skills(&self) -> Vec<Skill> {
+        self.background.map(|background| background.skill_proficiencies().to_vec()).unwrap_or_default()
+    }
+
+    fn package(&self) -> Option<&'static EquipmentPackage> {
+        self.class.and_then(|class| creation::equipment_packages(class).get(self.package))
+    }
+
+    /// Why the player can't move on from `step` yet
+    fn step_error(&self, step: Step) -> Option<String> {
+        match step {
+            Step::Details if slugify(&self.name).is_empty() => Some("Give your character a name".to_string()),
+            Step::Details if self.alignment.is_none() => Some("Choose an alignment".to_string()),
+            Step::Race if self.race.is_none() => Some("Choose a race".to_string()),
+            Step::Class if self.class.is_none() => Some("Choose a class".to_string()),
+            Step::Background if self.background.is_none() => Some("Choose a background".to_string()),
+            Step::AbilityScores => match self.method {
+                ScoreMethod::PointBuy => {
+                    let spent = creation::point_buy_spent(&self.point_buy).unwrap_or(i32::MAX);
+                    (spent > POINT_BUY_BUDGET)
+                        .then(|| format!("You've spent {} points; the budget is {}", spent, POINT_BUY_BUDGET))
+                }
+                ScoreMethod::Roll if self.rolls.is_empty() => Some("Roll your ability scores".to_string()),
+                _ => self.base_scores().is_none().then(|| "Assign a score to every ability".to_string()),
+            },
+            Step::Skills => {
+                let (count, _) = self.class?.skill_choices();
+                (self.class_skills.len() != count).then(|| format!("Choose {} skills from your class list", count))
+            }
+            _ => None,
+        }
+    }
+
+    /// The character this draft creates, once every step is complete
+    fn to_character(&self) -> Option<CharacterDetail> {
+        if Step::ALL.iter().any(|step| self.step_error(*step).is_some()) {
+            return None;
+        }
+        let class = self.class?;
+        let race = self.race?;
+        let score = |ability: Ability| self.final_score(ability).unwrap_or(10);
+        let package = self.package()?;
+
+        let mut skills: Vec<String> = self.granted_skills().iter().map(|skill| skill.name().to_string()).collect();
+        skills.extend(self.class_skills.iter().map(|skill| skill.name().to_string()));
+
+        let mut features = vec![];
+        let class_feature_names: Vec<String> = leveling::class_features(class, 1).iter().map(|name| name.to_string()).collect();
+        features::grant(&mut features, &class_feature_names);
+
+        Some(CharacterDetail {
+            id: 0,
+            name: self.name.trim().to_string(),
+            slug: String::new(),
+            description: self.description.clone(),
+            race: Some(race),
+            classes: vec![ClassLevel::new(class, 1)],
+            background: self.background,
+            alignment: self.alignment,
+            strength: score(Ability::Strength),
+            dexterity: score(Ability::Dexterity),
+            constitution: score(Ability::Constitution),
+            intelligence: score(Ability::Intelligence),
+            wisdom: score(Ability::Wisdom),
+            charisma: score(Ability::Charisma),
+            ability_rolls: self.rolls.clone(),
+            hit_points: (class.hit_die() + ability_modifier(score(Ability::Constitution))).max(1),
+            armor_class: package.armor_class(class, score),
+            speed: race.speed(),
+            proficiency_bonus: 2,
+            skills,
+            languages: vec!["Common".to_string()],
+            inventory: Inventory::from_names(package.items),
+            spellbook: Spellbook::default(),
+            play_state: PlayState::default(),
+            features,
+            backstory: String::new(),
+            portrait_image: None,
+            created_at: None,
+            updated_at: None,
+            owner: String::new(),
+            access: CharacterAccess::Owner,
+        })
+    }
+}
+
+fn progress_class(index: usize, current: usize) -> &'static str {
+    match index.cmp(&current) {
+        std::cmp::Ordering::Equal => "px-3 py-1 rounded-full text-sm font-medium bg-blue-600 text-white",
+        std::cmp::Ordering::Less => {
+            "px-3 py-1 rounded-full text-sm font-medium bg-blue-100 dark:bg-blue-900 text-blue-800 dark:text-blue-200 cursor-pointer"
+        }
+        std::cmp::Ordering::Greater => {
+            "px-3 py-1 rounded-full text-sm font-medium bg-gray-100 dark:bg-gray-700 text-gray-500 dark:text-gray-400"
+        }
+    }
+}
+
+fn with_placeholder(placeholder: &str, options: Vec<(String, String)>) -> Vec<(String, String)> {
+    std::iter::once((String::new(), placeholder.to_string())).chain(options).collect()
+}
+
+fn selected<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+/// Step-by-step creation of a new 1st-level character
+#[component]
+pub fn CharacterCreate() -> Element {
+    let draft = use_signal(Draft::default);
+    let mut step = use_signal(|| 0usize);
+    let mut saving = use_signal(|| false);
+    let mut error = use_signal::<Option<String>>(|| None);
+    let navigator = use_navigator();
+
+    let current = Step::ALL[step()];
+    let step_error = draft.read().step_error(current);
+    let is_last = step() == Step::ALL.len() - 1;
+
+    let create = move |_| {
+        let Some(character) = draft.read().to_character() else {
+            return;
+        };
+        saving.set(true);
+        error.set(None);
+        spawn(async move {
+            match create_character(character).await {
+                Ok(saved) => {
+                    navigator.push(Route::CharacterById { slug: saved.slug });
+                }
+                Err(e) => error.set(Some(format!("Failed to create character: {}", e))),
+            }
+            saving.set(false);
+        });
+    };
+
+    rsx! {
+        div { class: "max-w-4xl mx-auto py-8",
+            div { class: "flex items-center justify-between mb-8",
+                h1 { class: "text-3xl font-bold text-gray-900 dark:text-white", "Create Character" }
+                Link {
+                    to: Route::Character {},
+                    class: "text-blue-600 dark:text-blue-400 hover:underline",
+                    "Cancel"
+                }
+            }
+
+            // Progress
+            ol { class: "flex flex-wrap gap-2 mb-8",
+                for (index, each) in Step::ALL.iter().enumerate() {
+                    li {
+                        class: progress_class(index, step()),
+                        onclick: move |_| {
+                            // Earlier steps can be revisited; later ones are reached with Next
+                            if index < step() {
+                                step.set(index);
+                            }
+                        },
+                        "{index + 1}. {each.title()}"
+                    }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6",
+                    role: "alert",
+                    p { class: "font-bold", "Error" }
+                    p { "{err}" }
+                }
+            }
+
+            div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg mb-6",
+                h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "{current.title()}" }
+                match current {
+                    Step::Details => rsx! { DetailsStep { draft } },
+                    Step::Race => rsx! { RaceStep { draft } },
+                    Step::Class => rsx! { ClassStep { draft } },
+                    Step::Background => rsx! { BackgroundStep { draft } },
+                    Step::AbilityScores => rsx! { AbilityScoresStep { draft } },
+                    Step::Skills => rsx! { SkillsStep { draft } },
+                    Step::Equipment => rsx! { EquipmentStep { draft } },
+                    Step::Review => rsx! { ReviewStep { draft } },
+                }
+            }
+
+            div { class: "flex items-center justify-between",
+                button {
+                    r#type: "button",
+                    disabled: step() == 0,
+                    onclick: move |_| step -= 1,
+                    class: "px-4 py-2 text-gray-700 dark:text-gray-300 hover:underline disabled:opacity-50 disabled:cursor-not-allowed",
+                    "Back"
+                }
+                div { class: "flex items-center gap-4",
+                    if let Some(message) = step_error.as_ref() {
+                        span { class: "text-sm text-gray-500 dark:text-gray-400", "{message}" }
+                    }
+                    if is_last {
+                        button {
+                            r#type: "button",
+                            disabled: saving() || step_error.is_some(),
+                            onclick: create,
+                            class: "px-6 py-2 bg-green-600 text-white rounded-lg hover:bg-green-700 disabled:opacity-50 disabled:cursor-not-allowed transition-colors",
+                            if saving() { "Creating..." } else { "Create Character" }
+                        }
+                    } else {
+                        button {
+                            r#type: "button",
+                            disabled: step_error.is_some(),
+                            onclick: move |_| step += 1,
+                            class: "px-6 py-2 bg-blue-600 text-white rounded-lg hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed transition-colors",
+                            "Next"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn DetailsStep(draft: Signal<Draft>) -> Element {
+    rsx! {
+        div { class: "space-y-6",
+            div {
+                label { class: "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2", "Character Name" }
+                input {
+                    r#type: "text",
+                    value: "{draft.read().name}",
+                    oninput: move |e| draft.write().name = e.value(),
+                    class: FIELD_CLASS,
+                    placeholder: "Enter character name",
+                }
+            }
+            div {
+                label { class: "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2", "Alignment" }
+                SelectInput {
+                    i_value: selected(draft.read().alignment),
+                    options: with_placeholder("Select alignment", Alignment::options()),
+                    on_input: move |e: FormEvent| draft.write().alignment = Alignment::from_name(&e.value()),
+                    class: FIELD_CLASS,
+                }
+            }
+            div {
+                label { class: "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2", "Description" }
+                textarea {
+                    value: "{draft.read().description}",
+                    oninput: move |e| draft.write().description = e.value(),
+                    class: FIELD_CLASS,
+                    rows: "3",
+                    placeholder: "Brief description of your character's appearance and personality",
+                }
+            }
+        }
+    }
+}
+
+/// A grid of clickable cards, one per option, highlighting the selected one
+#[component]
+fn ChoiceCard(selected: bool, title: String, detail: String, on_select: EventHandler<MouseEvent>) -> Element {
+    rsx! {
+        button {
+            r#type: "button",
+            onclick: move |e| on_select.call(e),
+            class: if selected {
+                "text-left p-4 rounded-lg border-2 border-blue-600 bg-blue-50 dark:bg-blue-900"
+            } else {
+                "text-left p-4 rounded-lg border-2 border-gray-200 dark:border-gray-700 hover:border-blue-400"
+            },
+            div { class: "font-semibold text-gray-900 dark:text-white", "{title}" }
+            div { class: "text-sm text-gray-500 dark:text-gray-400 mt-1", "{detail}" }
+        }
+    }
+}
+
+#[component]
+fn RaceStep(draft: Signal<Draft>) -> Element {
+    rsx! {
+        div { class: "grid grid-cols-1 md:grid-cols-3 gap-4",
+            for race in Race::ALL.iter().copied() {
+                ChoiceCard {
+                    selected: draft.read().race == Some(race),
+                    title: race.name().to_string(),
+                    detail: {
+                        let bonuses: Vec<String> = race
+                            .ability_bonuses()
+                            .iter()
+                            .map(|(ability, bonus)| format!("{} {:+}", ability.abbreviation(), bonus))
+                            .collect();
+                        format!("{} • Speed {} ft", bonuses.join(", "), race.speed())
+                    },
+                    on_select: move |_| draft.write().race = Some(race),
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ClassStep(draft: Signal<Draft>) -> Element {
+    rsx! {
+        div { class: "grid grid-cols-1 md:grid-cols-3 gap-4",
+            for class in Class::ALL.iter().copied() {
+                ChoiceCard {
+                    selected: draft.read().class == Some(class),
+                    title: class.name().to_string(),
+                    detail: format!(
+                        "Hit die d{} • Saves {}, {}",
+                        class.hit_die(),
+                        class.saving_throws()[0].abbreviation(),
+                        class.saving_throws()[1].abbreviation(),
+                    ),
+                    on_select: move |_| {
+                        let mut draft = draft.write();
+                        if draft.class != Some(class) {
+                            // Skill and equipment choices depend on the class
+                            draft.class = Some(class);
+                            draft.class_skills.clear();
+                            draft.package = 0;
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn BackgroundStep(draft: Signal<Draft>) -> Element {
+    rsx! {
+        div { class: "grid grid-cols-1 md:grid-cols-3 gap-4",
+            for background in Background::ALL.iter().copied() {
+                ChoiceCard {
+                    selected: draft.read().background == Some(background),
+                    title: background.name().to_string(),
+                    detail: {
+                        let [first, second] = background.skill_proficiencies();
+                        format!("Skills: {}, {}", first.name(), second.name())
+                    },
+                    on_select: move |_| {
+                        let mut draft = draft.write();
+                        draft.background = Some(background);
+                        // Skills the background now grants no longer need to be picked from the class list
+                        let granted = background.skill_proficiencies();
+                        draft.class_skills.retain(|skill| !granted.contains(skill));
+                    },
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn AbilityScoresStep(draft: Signal<Draft>) -> Element {
+    let method = draft.read().method;
+    let mut set_method = move |method: ScoreMethod| {
+        let mut draft = draft.write();
+        draft.method = method;
+        draft.assigned = [None; 6];
+    };
+    let spent = creation::point_buy_spent(&draft.read().point_buy).unwrap_or(0);
+    let pool = draft.read().score_pool();
+    let mut rolling = use_signal(|| false);
+    let mut roll_error = use_signal::<Option<String>>(|| None);
+
+    rsx! {
+        div { class: "flex flex-wrap gap-2 mb-6",
+            for (option, label) in [
+                (ScoreMethod::PointBuy, "Point Buy"),
+                (ScoreMethod::StandardArray, "Standard Array"),
+                (ScoreMethod::Roll, "Roll 4d6, drop lowest"),
+            ] {
+                button {
+                    r#type: "button",
+                    onclick: move |_| set_method(option),
+                    class: if method == option {
+                        "px-4 py-2 rounded-lg bg-blue-600 text-white"
+                    } else {
+                        "px-4 py-2 rounded-lg bg-gray-100 dark:bg-gray-700 text-gray-700 dark:text-gray-300 hover:bg-gray-200"
+                    },
+                    "{label}"
+                }
+            }
+        }
+
+        if method == ScoreMethod::PointBuy {
+            p { class: "text-sm text-gray-600 dark:text-gray-400 mb-4",
+                "Points spent: {spent} / {POINT_BUY_BUDGET}. Scores run from {POINT_BUY_MIN} to {POINT_BUY_MAX} before racial bonuses."
+            }
+        }
+        if method == ScoreMethod::Roll {
+            div { class: "mb-4",
+                button {
+                    r#type: "button",
+                    disabled: rolling(),
+                    onclick: move |_| {
+                        // The server rolls, so the character can show it didn't pick its scores
+                        rolling.set(true);
+                        roll_error.set(None);
+                        spawn(async move {
+                            match roll_ability_scores().await {
+                                Ok(rolls) => {
+                                    let mut draft = draft.write();
+                                    draft.rolls.extend(rolls);
+                                    draft.assigned = [None; 6];
+                                }
+                                Err(e) => roll_error.set(Some(format!("Failed to roll: {}", e))),
+                            }
+                            rolling.set(false);
+                        });
+                    },
+                    class: "px-4 py-2 bg-purple-600 text-white rounded-lg hover:bg-purple-700 transition-colors disabled:opacity-50",
+                    if draft.read().rolls.is_empty() { "Roll" } else { "Roll again" }
+                }
+                if let Some(message) = roll_error() {
+                    p { class: "text-sm text-red-600 dark:text-red-400 mt-2", "{message}" }
+                }
+                RollLog { rolls: draft.read().rolls.clone() }
+            }
+        }
+
+        div { class: "grid grid-cols-2 md:grid-cols-3 gap-4",
+            for (index, ability) in Ability::ALL.iter().copied().enumerate() {
+                div { class: "text-center bg-gray-50 dark:bg-gray-700 rounded-lg p-3",
+                    div { class: "text-sm font-medium text-gray-700 dark:text-gray-300 mb-2", "{ability.name()}" }
+                    if method == ScoreMethod::PointBuy {
+                        div { class: "flex items-center justify-center gap-3",
+                            button {
+                                r#type: "button",
+                                disabled: draft.read().point_buy[index] <= POINT_BUY_MIN,
+                                onclick: move |_| draft.write().point_buy[index] -= 1,
+                                class: "w-8 h-8 rounded-full bg-gray-200 dark:bg-gray-600 disabled:opacity-50",
+                                "−"
+                            }
+                            span { class: "text-2xl font-bold w-8", "{draft.read().point_buy[index]}" }
+                            button {
+                                r#type: "button",
+                                disabled: {
+                                    let draft = draft.read();
+                                    let score = draft.point_buy[index];
+                                    let extra = creation::point_buy_cost(score + 1).zip(creation::point_buy_cost(score)).map(|(next, now)| next - now);
+                                    extra.is_none_or(|extra| spent + extra > POINT_BUY_BUDGET)
+                                },
+                                onclick: move |_| draft.write().point_buy[index] += 1,
+                                class: "w-8 h-8 rounded-full bg-gray-200 dark:bg-gray-600 disabled:opacity-50",
+                                "+"
+                            }
+                        }
+                    } else {
+                        SelectInput {
+                            i_value: selected(draft.read().assigned[index]),
+                            options: {
+                                // Each pool entry can go to one ability only
+                                let assigned = draft.read().assigned;
+                                let options = pool
+                                    .iter()
+                                    .enumerate()
+                                    .filter(|(entry, _)| {
+                                        assigned.iter().enumerate().all(|(other, taken)| other == index || *taken != Some(*entry))
+                                    })
+                                    .map(|(entry, score)| (entry.to_string(), score.to_string()))
+                                    .collect();
+                                with_placeholder("–", options)
+                            },
+                            on_input: move |e: FormEvent| draft.write().assigned[index] = e.value().parse().ok(),
+                            class: FIELD_CLASS,
+                        }
+                    }
+                    if let Some(score) = draft.read().final_score(ability) {
+                        div { class: "text-sm text-gray-600 dark:text-gray-400 mt-2",
+                            if let Some(bonus) = draft.read().race.map(|race| race.ability_bonus(ability)).filter(|bonus| *bonus != 0) {
+                                "{bonus:+} racial → "
+                            }
+                            "{score} ({ability_modifier(score):+})"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Every set rolled so far, newest first, with the dropped die struck through
+#[component]
+fn RollLog(rolls: Vec<AbilityRoll>) -> Element {
+    // Numbered in the order they were rolled, newest first
+    let mut sets: Vec<i32> = rolls.iter().map(|roll| roll.set).collect();
+    sets.dedup();
+    let mut sets: Vec<(usize, i32)> = sets.into_iter().enumerate().map(|(index, set)| (index + 1, set)).collect();
+    sets.reverse();
+
+    rsx! {
+        if !rolls.is_empty() {
+            div { class: "mt-4 space-y-2",
+                h3 { class: "text-sm font-semibold text-gray-700 dark:text-gray-300", "Roll log" }
+                for (number, set) in sets {
+                    div { class: "text-sm text-gray-600 dark:text-gray-400",
+                        span { class: "font-medium mr-2", "Set {number}:" }
+                        for roll in rolls.iter().filter(|roll| roll.set == set) {
+                            span { class: "mr-3 font-mono",
+                                "["
+                                {
+                                    // Strike through one copy of the lowest die
+                                    let dropped = roll.dice.iter().position(|die| *die == roll.dropped());
+                                    rsx! {
+                                        for (position, die) in roll.dice.iter().enumerate() {
+                                            if Some(position) == dropped {
+                                                s { class: "text-gray-400", "{die}" }
+                                            } else {
+                                                "{die}"
+                                            }
+                                            if position < 3 { " " }
+                                        }
+                                    }
+                                }
+                                "] = {roll.total()}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn SkillsStep(draft: Signal<Draft>) -> Element {
+    let Some(class) = draft.read().class else {
+        return rsx! { p { "Choose a class first." } };
+    };
+    let (count, options) = class.skill_choices();
+    let granted = draft.read().granted_skills();
+    let chosen = draft.read().class_skills.clone();
+
+    rsx! {
+        p { class: "text-sm text-gray-600 dark:text-gray-400 mb-4",
+            "Choose {count} skills from the {class} list ({chosen.len()} chosen). Skills from your background are already included."
+        }
+        div { class: "grid grid-cols-1 md:grid-cols-2 gap-2",
+            for skill in Skill::ALL.iter().copied().filter(|skill| options.contains(skill) || granted.contains(skill)) {
+                label { class: "flex items-center gap-2 text-gray-700 dark:text-gray-300",
+                    input {
+                        r#type: "checkbox",
+                        checked: granted.contains(&skill) || chosen.contains(&skill),
+                        disabled: granted.contains(&skill) || (!chosen.contains(&skill) && chosen.len() >= count),
+                        onchange: move |_| {
+                            let mut draft = draft.write();
+                            if let Some(position) = draft.class_skills.iter().position(|s| *s == skill) {
+                                draft.class_skills.remove(position);
+                            } else {
+                                draft.class_skills.push(skill);
+                            }
+                        },
+                    }
+                    "{skill.name()}"
+                    span { class: "text-xs text-gray-400", "({skill.ability().abbreviation()})" }
+                    if granted.contains(&skill) {
+                        span { class: "text-xs text-green-600", "background" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn EquipmentStep(draft: Signal<Draft>) -> Element {
+    let Some(class) = draft.read().class else {
+        return rsx! { p { "Choose a class first." } };
+    };
+    let score = move |ability: Ability| draft.read().final_score(ability).unwrap_or(10);
+
+    rsx! {
+        div { class: "grid grid-cols-1 md:grid-cols-2 gap-4",
+            for (index, package) in creation::equipment_packages(class).iter().enumerate() {
+                ChoiceCard {
+                    selected: draft.read().package == index,
+                    title: format!("{} (AC {})", package.name, package.armor_class(class, score)),
+                    detail: package.items.join(", "),
+                    on_select: move |_| draft.write().package = index,
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ReviewStep(draft: Signal<Draft>) -> Element {
+    let Some(character) = draft.read().to_character() else {
+        let missing: Vec<String> = Step::ALL.iter().filter_map(|step| draft.read().step_error(*step)).collect();
+        return rsx! {
+            ul { class: "list-disc pl-5 text-red-700",
+                for message in missing {
+                    li { "{message}" }
+                }
+            }
+        };
+    };
+    let stats = DerivedStats::for_character(&character);
+
+    rsx! {
+        div { class: "space-y-4",
+            div {
+                div { class: "text-2xl font-bold text-gray-900 dark:text-white", "{character.name}" }
+                div { class: "text-gray-600 dark:text-gray-400",
+                    "Level 1 {character.race_and_class()} • {selected(character.background)} • {selected(character.alignment)}"
+                }
+            }
+            div { class: "grid grid-cols-3 md:grid-cols-6 gap-3",
+                for ability in stats.abilities.iter() {
+                    div { class: "text-center bg-gray-50 dark:bg-gray-700 rounded-lg p-2",
+                        div { class: "text-xs text-gray-500 dark:text-gray-400", "{ability.ability.abbreviation()}" }
+                        div { class: "text-xl font-bold", "{ability.score}" }
+                        div { class: "text-xs text-gray-600 dark:text-gray-400", "{ability.modifier:+}" }
+                    }
+                }
+            }
+            div { class: "grid grid-cols-2 md:grid-cols-4 gap-4",
+                div {
+                    div { class: "text-sm text-gray-500 dark:text-gray-400", "Hit Points" }
+                    div { class: "text-lg font-semibold", "{character.hit_points}" }
+                }
+                div {
+                    div { class: "text-sm text-gray-500 dark:text-gray-400", "Armor Class" }
+                    div { class: "text-lg font-semibold", "{character.armor_class}" }
+                }
+                div {
+                    div { class: "text-sm text-gray-500 dark:text-gray-400", "Speed" }
+                    div { class: "text-lg font-semibold", "{character.speed} ft" }
+                }
+                div {
+                    div { class: "text-sm text-gray-500 dark:text-gray-400", "Passive Perception" }
+                    div { class: "text-lg font-semibold", "{stats.passive_perception}" }
+                }
+            }
+            div {
+                div { class: "text-sm text-gray-500 dark:text-gray-400", "Skills" }
+                div { "{character.skills.join(\", \")}" }
+            }
+            div {
+                div { class: "text-sm text-gray-500 dark:text-gray-400", "Equipment" }
+                div { "{character.inventory.equipment_names().join(\", \")}" }
+            }
+        }
+    }
+}
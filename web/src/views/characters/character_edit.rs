@@ -1,5 +1,13 @@
 use dioxus::prelude::*;
 use crate::Route;
+use crate::api::character::{
+    create_character, delete_character, get_character_with_slug, update_character, CharacterAccess, CharacterDetail,
+};
+use crate::components::SelectInput;
+use crate::rules::inventory::Inventory;
+use crate::rules::play::PlayState;
+use crate::rules::spellbook::Spellbook;
+use crate::rules::{self, multiclass, Ability, Alignment, Background, Class, ClassLevel, DerivedStats, Proficiency, Race, Skill, Subclass};
 
 #[component]
 pub fn CharacterEdit(slug: String) -> Element {
@@ -10,11 +18,10 @@ pub fn CharacterEdit(slug: String) -> Element {
     // Character stats signals
     let mut name = use_signal(|| String::new());
     let mut description = use_signal(|| String::new());
-    let mut race = use_signal(|| String::new());
-    let mut class = use_signal(|| String::new());
-    let mut level = use_signal(|| 1i32);
-    let mut background = use_signal(|| String::new());
-    let mut alignment = use_signal(|| String::new());
+    let mut race = use_signal::<Option<Race>>(|| None);
+    let mut classes = use_signal::<Vec<ClassLevel>>(|| vec![]);
+    let mut background = use_signal::<Option<Background>>(|| None);
+    let mut alignment = use_signal::<Option<Alignment>>(|| None);
     
     // Ability scores
     let mut strength = use_signal(|| 10i32);
@@ -28,43 +35,211 @@ pub fn CharacterEdit(slug: String) -> Element {
     let mut hit_points = use_signal(|| 8i32);
     let mut armor_class = use_signal(|| 10i32);
     let mut speed = use_signal(|| 30i32);
-    let mut proficiency_bonus = use_signal(|| 2i32);
+    let mut skills = use_signal::<Vec<String>>(|| vec![]);
     
     // Text fields
     let mut backstory = use_signal(|| String::new());
 
+    // The character as loaded from Supabase; keeps the fields this form doesn't edit
+    let mut existing = use_signal::<Option<CharacterDetail>>(|| None);
+    let mut errors = use_signal::<Vec<String>>(|| vec![]);
+    let mut deleting = use_signal(|| false);
+    let navigator = use_navigator();
+
     // Load character data if editing existing character
     use_effect(move || {
         if slug != "new" {
-            // TODO: Load existing character
-            loading.set(false);
+            let current_slug = slug.clone();
+            spawn(async move {
+                match get_character_with_slug(current_slug).await {
+                    Ok(character) if !character.access.can_edit() => {
+                        errors.set(vec!["You don't have permission to edit this character".to_string()]);
+                    }
+                    Ok(character) => {
+                        name.set(character.name.clone());
+                        description.set(character.description.clone());
+                        race.set(character.race);
+                        classes.set(character.classes.clone());
+                        background.set(character.background);
+                        alignment.set(character.alignment);
+                        strength.set(character.strength);
+                        dexterity.set(character.dexterity);
+                        constitution.set(character.constitution);
+                        intelligence.set(character.intelligence);
+                        wisdom.set(character.wisdom);
+                        charisma.set(character.charisma);
+                        hit_points.set(character.hit_points);
+                        armor_class.set(character.armor_class);
+                        speed.set(character.speed);
+                        skills.set(character.skills.clone());
+                        backstory.set(character.backstory.clone());
+                        existing.set(Some(character));
+                    }
+                    Err(e) => errors.set(server_error_messages(e)),
+                }
+                loading.set(false);
+            });
         } else {
             loading.set(false);
         }
     });
 
-    let ability_modifier = |score: i32| -> i32 {
-        (score - 10) / 2
+    // Merge the form fields over the loaded character (or a blank one when creating)
+    let form_character = move || -> CharacterDetail {
+        let base = existing.read().clone().unwrap_or_else(|| CharacterDetail {
+            id: 0,
+            name: String::new(),
+            slug: String::new(),
+            description: String::new(),
+            race: None,
+            classes: vec![],
+            background: None,
+            alignment: None,
+            strength: 10,
+            dexterity: 10,
+            constitution: 10,
+            intelligence: 10,
+            wisdom: 10,
+            charisma: 10,
+            ability_rolls: vec![],
+            hit_points: 8,
+            armor_class: 10,
+            speed: 30,
+            proficiency_bonus: 2,
+            skills: vec![],
+            languages: vec![],
+            inventory: Inventory::default(),
+            spellbook: Spellbook::default(),
+            play_state: PlayState::default(),
+            features: vec![],
+            backstory: String::new(),
+            portrait_image: None,
+            created_at: None,
+            updated_at: None,
+            owner: String::new(),
+            access: CharacterAccess::Owner,
+        });
+        CharacterDetail {
+            name: name(),
+            description: description(),
+            race: race(),
+            classes: classes(),
+            background: background(),
+            alignment: alignment(),
+            strength: strength(),
+            dexterity: dexterity(),
+            constitution: constitution(),
+            intelligence: intelligence(),
+            wisdom: wisdom(),
+            charisma: charisma(),
+            hit_points: hit_points(),
+            armor_class: armor_class(),
+            speed: speed(),
+            proficiency_bonus: rules::proficiency_bonus(multiclass::total_level(&classes())),
+            skills: skills(),
+            backstory: backstory(),
+            ..base
+        }
     };
 
-    let save_character_form = move |e: Event<FormData>| {
-        e.prevent_default();
+    let racial_bonuses = race().map(|race| {
+        let bonuses: Vec<String> = race
+            .ability_bonuses()
+            .iter()
+            .map(|(ability, bonus)| format!("{} {:+}", ability.abbreviation(), bonus))
+            .collect();
+        format!("{} ability score increases: {}", race, bonuses.join(", "))
+    });
+    let score = move |ability: Ability| match ability {
+        Ability::Strength => strength(),
+        Ability::Dexterity => dexterity(),
+        Ability::Constitution => constitution(),
+        Ability::Intelligence => intelligence(),
+        Ability::Wisdom => wisdom(),
+        Ability::Charisma => charisma(),
+    };
+    // Classes that can still be added, flagging the ones whose multiclass prerequisites aren't met
+    let add_class_options = {
+        let taken = classes();
+        let options: Vec<(String, String)> = Class::ALL
+            .iter()
+            .filter(|class| !taken.iter().any(|class_level| class_level.class == **class))
+            .map(|class| {
+                let label = if taken.is_empty() || multiclass::meets_prerequisites(*class, score) {
+                    class.name().to_string()
+                } else {
+                    let needs: Vec<String> =
+                        multiclass::prerequisites(*class).iter().map(|a| format!("{} 13", a.abbreviation())).collect();
+                    let joiner = if *class == Class::Fighter { " or " } else { " and " };
+                    format!("{} (needs {})", class, needs.join(joiner))
+                };
+                (class.name().to_string(), label)
+            })
+            .collect();
+        with_placeholder(if taken.is_empty() { "Select class" } else { "Add a class..." }, options)
+    };
+
+    let derived = use_memo(move || DerivedStats::for_character(&form_character()));
+
+    // Replace whatever entry the character has for `skill` with one at the chosen proficiency
+    let mut set_skill_proficiency = move |skill: Skill, proficiency: Proficiency| {
+        let mut entries: Vec<String> = skills()
+            .into_iter()
+            .filter(|entry| rules::parse_skill_entry(entry).map(|(known, _)| known) != Some(skill))
+            .collect();
+        entries.extend(rules::skill_entry(skill, proficiency));
+        skills.set(entries);
+    };
+
+    let mut save_character = move || {
+        let character = form_character();
+        let validation_errors = character.validate();
+        if !validation_errors.is_empty() {
+            errors.set(validation_errors);
+            return;
+        }
+
+        errors.set(vec![]);
         saving.set(true);
-        // TODO: Implement save functionality
         spawn(async move {
-            // Simulate save
-            gloo::timers::future::sleep(std::time::Duration::from_secs(1)).await;
+            let result = if is_new {
+                create_character(character).await
+            } else {
+                update_character(character).await
+            };
             saving.set(false);
+
+            match result {
+                Ok(saved) => {
+                    navigator.push(Route::CharacterById { slug: saved.slug });
+                }
+                Err(e) => errors.set(server_error_messages(e)),
+            }
         });
     };
 
+    let save_character_form = move |e: Event<FormData>| {
+        e.prevent_default();
+        save_character();
+    };
+
     let save_character_click = move |_e: Event<MouseData>| {
-        saving.set(true);
-        // TODO: Implement save functionality
+        save_character();
+    };
+
+    let delete_character_click = move |_e: Event<MouseData>| {
+        let Some(id) = existing.read().as_ref().map(|character| character.id) else {
+            return;
+        };
+        deleting.set(true);
         spawn(async move {
-            // Simulate save
-            gloo::timers::future::sleep(std::time::Duration::from_secs(1)).await;
-            saving.set(false);
+            match delete_character(id).await {
+                Ok(()) => {
+                    navigator.push(Route::Character {});
+                }
+                Err(e) => errors.set(server_error_messages(e)),
+            }
+            deleting.set(false);
         });
     };
 
@@ -81,6 +256,14 @@ pub fn CharacterEdit(slug: String) -> Element {
                     }
                 }
                 div { class: "flex gap-4",
+                    if existing.read().as_ref().is_some_and(|c| c.access.can_delete()) {
+                        button {
+                            onclick: delete_character_click,
+                            disabled: *deleting.read() || existing.read().is_none(),
+                            class: "px-4 py-2 text-red-600 dark:text-red-400 hover:text-red-800 dark:hover:text-red-300 disabled:opacity-50 disabled:cursor-not-allowed transition-colors",
+                            if *deleting.read() { "Deleting..." } else { "Delete" }
+                        }
+                    }
                     Link {
                         to: Route::Character {},
                         class: "px-4 py-2 text-gray-600 dark:text-gray-400 hover:text-gray-900 dark:hover:text-white transition-colors",
@@ -88,13 +271,27 @@ pub fn CharacterEdit(slug: String) -> Element {
                     }
                     button {
                         onclick: save_character_click,
-                        disabled: *saving.read(),
+                        disabled: *saving.read() || *loading.read() || (!is_new && existing.read().is_none()),
                         class: "px-6 py-2 bg-blue-600 text-white rounded-lg hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed transition-colors",
                         if *saving.read() { "Saving..." } else { "Save Character" }
                     }
                 }
             }
 
+            // Validation / save errors
+            if !errors.read().is_empty() {
+                div {
+                    class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6",
+                    role: "alert",
+                    p { class: "font-bold", "Could not save character" }
+                    ul { class: "list-disc pl-5 mt-2",
+                        for message in errors.read().iter() {
+                            li { "{message}" }
+                        }
+                    }
+                }
+            }
+
             // Character Form
             form { class: "space-y-8",
                 onsubmit: save_character_form,
@@ -116,69 +313,116 @@ pub fn CharacterEdit(slug: String) -> Element {
                         }
                         div {
                             label { class: "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2", "Race" }
-                            input {
-                                r#type: "text",
-                                value: "{race.read()}",
-                                oninput: move |e| race.set(e.value()),
+                            SelectInput {
+                                i_value: selected(race()),
+                                options: with_placeholder("Select race", Race::options()),
+                                on_input: move |e: FormEvent| {
+                                    let chosen = Race::from_name(&e.value());
+                                    if let Some(chosen) = chosen {
+                                        speed.set(chosen.speed());
+                                    }
+                                    race.set(chosen);
+                                },
                                 class: "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-700 dark:text-white",
-                                placeholder: "e.g., Human, Elf, Dwarf",
-                                required: true,
                             }
                         }
-                        div {
-                            label { class: "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2", "Class" }
-                            input {
-                                r#type: "text",
-                                value: "{class.read()}",
-                                oninput: move |e| class.set(e.value()),
-                                class: "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-700 dark:text-white",
-                                placeholder: "e.g., Fighter, Wizard, Rogue",
-                                required: true,
+                        div { class: "md:col-span-2",
+                            label { class: "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2",
+                                "Classes (level {derived.read().level})"
                             }
-                        }
-                        div {
-                            label { class: "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2", "Level" }
-                            input {
-                                r#type: "number",
-                                value: "{level.read()}",
-                                oninput: move |e| {
-                                    if let Ok(val) = e.value().parse::<i32>() {
-                                        level.set(val);
+                            div { class: "space-y-3",
+                                for (index, class_level) in classes().into_iter().enumerate() {
+                                    div { class: "grid grid-cols-1 md:grid-cols-12 gap-3 items-start",
+                                        div { class: "md:col-span-4",
+                                            SelectInput {
+                                                i_value: class_level.class.name().to_string(),
+                                                options: Class::options(),
+                                                on_input: move |e: FormEvent| {
+                                                    if let Some(chosen) = Class::from_name(&e.value()) {
+                                                        // A subclass only makes sense for the class it belongs to
+                                                        classes.write()[index] = ClassLevel::new(chosen, class_level.level);
+                                                    }
+                                                },
+                                                class: "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-700 dark:text-white",
+                                            }
+                                            div { class: "text-sm text-gray-500 dark:text-gray-400 mt-1",
+                                                "Hit die d{class_level.hit_die()}"
+                                                if index == 0 {
+                                                    " • Saves {class_level.class.saving_throws()[0].abbreviation()}, {class_level.class.saving_throws()[1].abbreviation()}"
+                                                }
+                                            }
+                                        }
+                                        div { class: "md:col-span-2",
+                                            input {
+                                                r#type: "number",
+                                                value: "{class_level.level}",
+                                                oninput: move |e| {
+                                                    if let Ok(val) = e.value().parse::<i32>() {
+                                                        classes.write()[index].level = val;
+                                                    }
+                                                },
+                                                class: "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-700 dark:text-white",
+                                                min: "1",
+                                                max: "20",
+                                                aria_label: "{class_level.class} level",
+                                            }
+                                        }
+                                        div { class: "md:col-span-5",
+                                            SelectInput {
+                                                i_value: selected(class_level.subclass),
+                                                options: with_placeholder(
+                                                    &format!("No subclass (chosen at level {})", class_level.class.subclass_level()),
+                                                    class_level.class.subclasses().into_iter().map(|s| (s.name().to_string(), s.name().to_string())).collect(),
+                                                ),
+                                                on_input: move |e: FormEvent| classes.write()[index].subclass = Subclass::from_name(&e.value()),
+                                                class: "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-700 dark:text-white",
+                                            }
+                                        }
+                                        button {
+                                            r#type: "button",
+                                            class: "md:col-span-1 px-3 py-2 text-sm text-red-600 dark:text-red-400 hover:underline",
+                                            onclick: move |_| {
+                                                classes.write().remove(index);
+                                            },
+                                            "Remove"
+                                        }
                                     }
-                                },
-                                class: "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-700 dark:text-white",
-                                min: "1",
-                                max: "20",
-                                required: true,
+                                }
+                                if add_class_options.len() > 1 {
+                                    SelectInput {
+                                        i_value: String::new(),
+                                        options: add_class_options,
+                                        on_input: move |e: FormEvent| {
+                                            if let Some(chosen) = Class::from_name(&e.value()) {
+                                                classes.write().push(ClassLevel::new(chosen, 1));
+                                            }
+                                        },
+                                        class: "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-700 dark:text-white",
+                                    }
+                                }
                             }
                         }
                         div {
                             label { class: "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2", "Background" }
-                            input {
-                                r#type: "text",
-                                value: "{background.read()}",
-                                oninput: move |e| background.set(e.value()),
+                            SelectInput {
+                                i_value: selected(background()),
+                                options: with_placeholder("No background", Background::options()),
+                                on_input: move |e: FormEvent| background.set(Background::from_name(&e.value())),
                                 class: "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-700 dark:text-white",
-                                placeholder: "e.g., Acolyte, Criminal, Folk Hero",
+                            }
+                            if let Some(background) = background() {
+                                div { class: "text-sm text-gray-500 dark:text-gray-400 mt-1",
+                                    "Skill proficiencies: {background.skill_proficiencies()[0].name()}, {background.skill_proficiencies()[1].name()}"
+                                }
                             }
                         }
                         div {
                             label { class: "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2", "Alignment" }
-                            select {
-                                value: "{alignment.read()}",
-                                onchange: move |e| alignment.set(e.value()),
+                            SelectInput {
+                                i_value: selected(alignment()),
+                                options: with_placeholder("Select alignment", Alignment::options()),
+                                on_input: move |e: FormEvent| alignment.set(Alignment::from_name(&e.value())),
                                 class: "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-700 dark:text-white",
-                                required: true,
-                                option { value: "", "Select alignment" }
-                                option { value: "Lawful Good", "Lawful Good" }
-                                option { value: "Neutral Good", "Neutral Good" }
-                                option { value: "Chaotic Good", "Chaotic Good" }
-                                option { value: "Lawful Neutral", "Lawful Neutral" }
-                                option { value: "True Neutral", "True Neutral" }
-                                option { value: "Chaotic Neutral", "Chaotic Neutral" }
-                                option { value: "Lawful Evil", "Lawful Evil" }
-                                option { value: "Neutral Evil", "Neutral Evil" }
-                                option { value: "Chaotic Evil", "Chaotic Evil" }
                             }
                         }
                     }
@@ -197,6 +441,9 @@ pub fn CharacterEdit(slug: String) -> Element {
                 // Ability Scores
                 div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg",
                     h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "Ability Scores" }
+                    if let Some(bonuses) = racial_bonuses {
+                        p { class: "text-sm text-gray-500 dark:text-gray-400 mb-4", "{bonuses}" }
+                    }
                     div { class: "grid grid-cols-2 md:grid-cols-3 gap-6",
                         // Strength
                         div { class: "text-center",
@@ -214,7 +461,7 @@ pub fn CharacterEdit(slug: String) -> Element {
                                 max: "30",
                             }
                             div { class: "text-sm text-gray-500 dark:text-gray-400 mt-1",
-                                "Modifier: {ability_modifier(*strength.read()):+}"
+                                "Modifier: {rules::ability_modifier(strength()):+}"
                             }
                         }
                         // Dexterity
@@ -233,7 +480,7 @@ pub fn CharacterEdit(slug: String) -> Element {
                                 max: "30",
                             }
                             div { class: "text-sm text-gray-500 dark:text-gray-400 mt-1",
-                                "Modifier: {ability_modifier(*dexterity.read()):+}"
+                                "Modifier: {rules::ability_modifier(dexterity()):+}"
                             }
                         }
                         // Constitution
@@ -252,7 +499,7 @@ pub fn CharacterEdit(slug: String) -> Element {
                                 max: "30",
                             }
                             div { class: "text-sm text-gray-500 dark:text-gray-400 mt-1",
-                                "Modifier: {ability_modifier(*constitution.read()):+}"
+                                "Modifier: {rules::ability_modifier(constitution()):+}"
                             }
                         }
                         // Intelligence
@@ -271,7 +518,7 @@ pub fn CharacterEdit(slug: String) -> Element {
                                 max: "30",
                             }
                             div { class: "text-sm text-gray-500 dark:text-gray-400 mt-1",
-                                "Modifier: {ability_modifier(*intelligence.read()):+}"
+                                "Modifier: {rules::ability_modifier(intelligence()):+}"
                             }
                         }
                         // Wisdom
@@ -290,7 +537,7 @@ pub fn CharacterEdit(slug: String) -> Element {
                                 max: "30",
                             }
                             div { class: "text-sm text-gray-500 dark:text-gray-400 mt-1",
-                                "Modifier: {ability_modifier(*wisdom.read()):+}"
+                                "Modifier: {rules::ability_modifier(wisdom()):+}"
                             }
                         }
                         // Charisma
@@ -309,7 +556,7 @@ pub fn CharacterEdit(slug: String) -> Element {
                                 max: "30",
                             }
                             div { class: "text-sm text-gray-500 dark:text-gray-400 mt-1",
-                                "Modifier: {ability_modifier(*charisma.read()):+}"
+                                "Modifier: {rules::ability_modifier(charisma()):+}"
                             }
                         }
                     }
@@ -332,6 +579,12 @@ pub fn CharacterEdit(slug: String) -> Element {
                                 class: "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-700 dark:text-white",
                                 min: "1",
                             }
+                            button {
+                                r#type: "button",
+                                class: "text-sm text-blue-600 dark:text-blue-400 hover:underline mt-1",
+                                onclick: move |_| hit_points.set(derived.read().average_hit_points),
+                                "Use average ({derived.read().average_hit_points})"
+                            }
                         }
                         div {
                             label { class: "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2", "Armor Class" }
@@ -346,6 +599,9 @@ pub fn CharacterEdit(slug: String) -> Element {
                                 class: "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-700 dark:text-white",
                                 min: "1",
                             }
+                            div { class: "text-sm text-gray-500 dark:text-gray-400 mt-1",
+                                "Unarmored: {derived.read().unarmored_armor_class}"
+                            }
                         }
                         div {
                             label { class: "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2", "Speed (ft)" }
@@ -363,16 +619,66 @@ pub fn CharacterEdit(slug: String) -> Element {
                         }
                         div {
                             label { class: "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2", "Proficiency Bonus" }
-                            input {
-                                r#type: "number",
-                                value: "{proficiency_bonus.read()}",
-                                oninput: move |e| {
-                                    if let Ok(val) = e.value().parse::<i32>() {
-                                        proficiency_bonus.set(val);
+                            div { class: "px-3 py-2 text-lg font-semibold text-gray-900 dark:text-white",
+                                "{derived.read().proficiency_bonus:+}"
+                            }
+                            div { class: "text-sm text-gray-500 dark:text-gray-400", "Set by level" }
+                        }
+                    }
+                    div { class: "grid grid-cols-2 md:grid-cols-4 gap-6 mt-6 pt-6 border-t border-gray-200 dark:border-gray-700",
+                        div {
+                            div { class: "text-sm text-gray-500 dark:text-gray-400", "Initiative" }
+                            div { class: "text-lg font-semibold", "{derived.read().initiative:+}" }
+                        }
+                        div {
+                            div { class: "text-sm text-gray-500 dark:text-gray-400", "Passive Perception" }
+                            div { class: "text-lg font-semibold", "{derived.read().passive_perception}" }
+                        }
+                        for spellcasting in derived.read().spellcasting.iter() {
+                            div {
+                                div { class: "text-sm text-gray-500 dark:text-gray-400", "Spell Save DC ({spellcasting.ability.abbreviation()})" }
+                                div { class: "text-lg font-semibold", "{spellcasting.save_dc}" }
+                            }
+                            div {
+                                div { class: "text-sm text-gray-500 dark:text-gray-400", "Spell Attack" }
+                                div { class: "text-lg font-semibold", "{spellcasting.attack_bonus:+}" }
+                            }
+                        }
+                    }
+                }
+
+                // Skills
+                div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg",
+                    h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "Skills" }
+                    div { class: "grid grid-cols-1 md:grid-cols-2 gap-x-8 gap-y-2",
+                        for skill_bonus in derived.read().skills.iter().cloned() {
+                            div { class: "flex items-center justify-between",
+                                span { class: "text-sm text-gray-700 dark:text-gray-300",
+                                    "{skill_bonus.skill.name()} "
+                                    span { class: "text-xs text-gray-400", "({skill_bonus.skill.ability().abbreviation()})" }
+                                }
+                                div { class: "flex items-center gap-3",
+                                    span { class: "font-mono text-sm w-8 text-right", "{skill_bonus.bonus:+}" }
+                                    select {
+                                        class: "px-2 py-1 text-sm border border-gray-300 dark:border-gray-600 rounded-lg dark:bg-gray-700 dark:text-white",
+                                        value: match skill_bonus.proficiency {
+                                            Proficiency::None => "none",
+                                            Proficiency::Proficient => "proficient",
+                                            Proficiency::Expertise => "expertise",
+                                        },
+                                        onchange: move |e| {
+                                            let proficiency = match e.value().as_str() {
+                                                "proficient" => Proficiency::Proficient,
+                                                "expertise" => Proficiency::Expertise,
+                                                _ => Proficiency::None,
+                                            };
+                                            set_skill_proficiency(skill_bonus.skill, proficiency);
+                                        },
+                                        option { value: "none", "Not proficient" }
+                                        option { value: "proficient", "Proficient" }
+                                        option { value: "expertise", "Expertise" }
                                     }
-                                },
-                                class: "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-700 dark:text-white",
-                                min: "1",
+                                }
                             }
                         }
                     }
@@ -393,3 +699,21 @@ pub fn CharacterEdit(slug: String) -> Element {
         }
     }
 }
+
+// The value a `SelectInput` shows for an optional catalog entry; "" selects the placeholder
+fn selected<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+fn with_placeholder(placeholder: &str, options: Vec<(String, String)>) -> Vec<(String, String)> {
+    std::iter::once((String::new(), placeholder.to_string())).chain(options).collect()
+}
+
+// Server-side validation joins its messages with newlines; split them back out for the error list
+pub(super) fn server_error_messages(error: ServerFnError) -> Vec<String> {
+    let message = match error {
+        ServerFnError::ServerError(message) => message,
+        other => other.to_string(),
+    };
+    message.lines().map(|line| line.to_string()).collect()
+}
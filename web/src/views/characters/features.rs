@@ -0,0 +1,337 @@
+use dioxus::prelude::*;
+use crate::api::character::{apply_play_action, update_features, CharacterDetail};
+use crate::components::SelectInput;
+use crate::rules::features::{Feature, LimitedUse, MaxUses, Recharge};
+use crate::rules::play::PlayAction;
+use crate::rules::{Ability, Class};
+use super::character_edit::server_error_messages;
+
+const FIELD_CLASS: &str = "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-700 dark:text-white";
+const STEP_BUTTON_CLASS: &str = "w-7 h-7 text-sm rounded border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-200 hover:bg-gray-100 dark:hover:bg-gray-700 disabled:opacity-50 disabled:cursor-not-allowed";
+
+fn pairs(values: &[&str]) -> Vec<(String, String)> {
+    values.iter().map(|value| (value.to_string(), value.to_string())).collect()
+}
+
+fn ability_options() -> Vec<(String, String)> {
+    Ability::ALL.iter().map(|ability| (ability.name().to_string(), ability.name().to_string())).collect()
+}
+
+fn class_options() -> Vec<(String, String)> {
+    let mut options = vec![(String::new(), "Character level".to_string())];
+    options.extend(Class::options());
+    options
+}
+
+fn uses_class(remaining: i32) -> &'static str {
+    if remaining == 0 {
+        "w-16 text-center font-semibold text-red-600 dark:text-red-400"
+    } else {
+        "w-16 text-center font-semibold text-gray-900 dark:text-white"
+    }
+}
+
+/// The sheet's feature list. Limited-use features get +/- controls that go through the play
+/// actions, so the DM sees the same counts; adding and removing features needs edit access.
+#[component]
+pub fn FeatureList(character: CharacterDetail, on_change: EventHandler<CharacterDetail>) -> Element {
+    let mut busy = use_signal(|| false);
+    let mut adding = use_signal(|| false);
+    let mut errors = use_signal::<Vec<String>>(|| vec![]);
+
+    let id = character.id;
+    let can_edit = character.access.can_edit();
+
+    let mut run = move |action: PlayAction| {
+        if busy() {
+            return;
+        }
+        busy.set(true);
+        errors.set(vec![]);
+        spawn(async move {
+            match apply_play_action(id, action).await {
+                Ok(update) => on_change.call(update.character),
+                Err(e) => errors.set(server_error_messages(e)),
+            }
+            busy.set(false);
+        });
+    };
+
+    // Saves over the version of the character the list was drawn from
+    let mut save = move |features: Vec<Feature>, loaded_at: Option<String>| {
+        busy.set(true);
+        errors.set(vec![]);
+        spawn(async move {
+            match update_features(id, features, loaded_at).await {
+                Ok(saved) => {
+                    adding.set(false);
+                    on_change.call(saved);
+                }
+                Err(e) => errors.set(server_error_messages(e)),
+            }
+            busy.set(false);
+        });
+    };
+
+    let features = character.features.clone();
+    let loaded_at = character.updated_at.clone();
+    let rows: Vec<(Feature, Option<(i32, i32)>)> =
+        character.features.iter().map(|feature| (feature.clone(), feature.remaining(&character))).collect();
+
+    rsx! {
+        div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg",
+            div { class: "flex items-center justify-between mb-4",
+                h2 { class: "text-xl font-semibold text-gray-900 dark:text-white", "Features" }
+                if can_edit {
+                    button {
+                        r#type: "button",
+                        onclick: move |_| adding.toggle(),
+                        class: "text-sm text-blue-600 dark:text-blue-400 hover:underline",
+                        if adding() { "Cancel" } else { "Add" }
+                    }
+                }
+            }
+
+            if !errors.read().is_empty() {
+                div {
+                    class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6",
+                    role: "alert",
+                    for message in errors.read().iter() {
+                        p { "{message}" }
+                    }
+                }
+            }
+
+            if adding() {
+                AddFeatureForm {
+                    on_add: move |feature: Feature| {
+                        let mut features = features.clone();
+                        features.push(feature);
+                        save(features, loaded_at.clone());
+                    },
+                }
+            }
+
+            if rows.is_empty() && !adding() {
+                p { class: "text-gray-500 dark:text-gray-400", "No features yet." }
+            }
+
+            ul { class: "space-y-3",
+                for (index, (feature, remaining)) in rows.into_iter().enumerate() {
+                    li { key: "{feature.name}",
+                        div { class: "flex items-center gap-2",
+                            svg {
+                                class: "w-4 h-4 text-yellow-500 shrink-0",
+                                fill: "currentColor",
+                                view_box: "0 0 20 20",
+                                path { d: "M9.049 2.927c.3-.921 1.603-.921 1.902 0l1.07 3.292a1 1 0 00.95.69h3.462c.969 0 1.371 1.24.588 1.81l-2.8 2.034a1 1 0 00-.364 1.118l1.07 3.292c.3.921-.755 1.688-1.54 1.118l-2.8-2.034a1 1 0 00-1.175 0l-2.8 2.034c-.784.57-1.838-.197-1.539-1.118l1.07-3.292a1 1 0 00-.364-1.118L2.98 8.72c-.783-.57-.38-1.81.588-1.81h3.461a1 1 0 00.951-.69l1.07-3.292z" }
+                            }
+                            span { class: "flex-1 text-gray-900 dark:text-white", title: "{feature.description}", "{feature.name}" }
+
+                            if let (Some(uses), Some((left, max))) = (feature.uses.clone(), remaining) {
+                                if can_edit {
+                                    button {
+                                        r#type: "button",
+                                        disabled: busy() || left == 0,
+                                        title: "Use",
+                                        onclick: {
+                                            let name = feature.name.clone();
+                                            move |_| run(PlayAction::UseFeature(name.clone()))
+                                        },
+                                        class: STEP_BUTTON_CLASS,
+                                        "−"
+                                    }
+                                }
+                                span { class: uses_class(left), "{left}/{max}" }
+                                if can_edit {
+                                    button {
+                                        r#type: "button",
+                                        disabled: busy() || left == max,
+                                        title: "Regain a use",
+                                        onclick: {
+                                            let name = feature.name.clone();
+                                            move |_| run(PlayAction::RestoreFeature(name.clone()))
+                                        },
+                                        class: STEP_BUTTON_CLASS,
+                                        "+"
+                                    }
+                                }
+                                span { class: "px-2 py-0.5 text-xs rounded-full bg-gray-100 dark:bg-gray-700 text-gray-600 dark:text-gray-300",
+                                    title: "Uses: {uses.max}",
+                                    "{uses.recharge}"
+                                }
+                                if can_edit && matches!(uses.recharge, Recharge::D6 { .. }) && left < max {
+                                    button {
+                                        r#type: "button",
+                                        disabled: busy(),
+                                        onclick: {
+                                            let name = feature.name.clone();
+                                            move |_| run(PlayAction::RollRecharge(name.clone()))
+                                        },
+                                        class: "px-2 py-1 text-xs text-white bg-gray-700 hover:bg-gray-800 rounded disabled:opacity-50",
+                                        "Roll"
+                                    }
+                                }
+                            }
+
+                            if can_edit {
+                                button {
+                                    r#type: "button",
+                                    disabled: busy(),
+                                    title: "Remove",
+                                    onclick: {
+                                        let features = character.features.clone();
+                                        let loaded_at = character.updated_at.clone();
+                                        move |_| {
+                                            let mut features = features.clone();
+                                            features.remove(index);
+                                            save(features, loaded_at.clone());
+                                        }
+                                    },
+                                    class: "text-gray-400 hover:text-red-600",
+                                    "×"
+                                }
+                            }
+                        }
+                        if !feature.description.is_empty() {
+                            p { class: "ml-6 text-sm text-gray-500 dark:text-gray-400", "{feature.description}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A homebrew or not-yet-tracked feature, optionally with limited uses
+#[component]
+fn AddFeatureForm(on_add: EventHandler<Feature>) -> Element {
+    let mut name = use_signal(String::new);
+    let mut description = use_signal(String::new);
+    let mut kind = use_signal(|| "Unlimited".to_string());
+    let mut number = use_signal(|| "1".to_string());
+    let mut class = use_signal(String::new);
+    let mut ability = use_signal(|| Ability::Charisma.name().to_string());
+    let mut recharge = use_signal(|| "Long rest".to_string());
+    let mut recharge_on = use_signal(|| "5".to_string());
+
+    let max_uses = move || {
+        let number = number().trim().parse::<i32>().unwrap_or(1).max(0);
+        let ability = Ability::ALL.into_iter().find(|a| a.name() == ability()).unwrap_or(Ability::Charisma);
+        match kind().as_str() {
+            "Fixed" => Some(MaxUses::Fixed { uses: number }),
+            "Per level" => Some(MaxUses::PerLevel { class: Class::from_name(&class()), per_level: number }),
+            "Ability modifier" => Some(MaxUses::AbilityModifier { ability, minimum: number }),
+            "1 + ability modifier" => Some(MaxUses::OnePlusAbilityModifier { ability }),
+            "Proficiency bonus" => Some(MaxUses::ProficiencyBonus),
+            _ => None,
+        }
+    };
+
+    let chosen_recharge = move || match recharge().as_str() {
+        "Short rest" => Recharge::ShortRest,
+        "Dawn" => Recharge::Dawn,
+        "Recharge roll" => Recharge::D6 { min: recharge_on().trim().parse().unwrap_or(5) },
+        _ => Recharge::LongRest,
+    };
+
+    let limited = kind() != "Unlimited";
+    let takes_number = matches!(kind().as_str(), "Fixed" | "Per level" | "Ability modifier");
+    let takes_ability = matches!(kind().as_str(), "Ability modifier" | "1 + ability modifier");
+    let number_label = if kind() == "Ability modifier" { "Minimum" } else { "Uses" };
+
+    rsx! {
+        form {
+            class: "space-y-3 mb-6 p-4 rounded-lg bg-gray-50 dark:bg-gray-900",
+            onsubmit: move |e| {
+                e.prevent_default();
+                if name().trim().is_empty() {
+                    return;
+                }
+                on_add.call(Feature {
+                    name: name().trim().to_string(),
+                    description: description().trim().to_string(),
+                    uses: max_uses().map(|max| LimitedUse { max, recharge: chosen_recharge(), used: 0 }),
+                });
+                name.set(String::new());
+                description.set(String::new());
+                kind.set("Unlimited".to_string());
+            },
+            input {
+                r#type: "text",
+                value: "{name}",
+                oninput: move |e| name.set(e.value()),
+                class: FIELD_CLASS,
+                placeholder: "Feature name",
+            }
+            textarea {
+                value: "{description}",
+                oninput: move |e| description.set(e.value()),
+                class: FIELD_CLASS,
+                rows: "2",
+                placeholder: "What it does",
+            }
+            div { class: "grid grid-cols-2 gap-2",
+                div {
+                    label { class: "block text-xs text-gray-500 dark:text-gray-400", "Uses" }
+                    SelectInput {
+                        i_value: kind(),
+                        on_input: move |e: FormEvent| kind.set(e.value()),
+                        class: FIELD_CLASS,
+                        options: pairs(&["Unlimited", "Fixed", "Per level", "Ability modifier", "1 + ability modifier", "Proficiency bonus"]),
+                    }
+                }
+                if takes_number {
+                    div {
+                        label { class: "block text-xs text-gray-500 dark:text-gray-400", "{number_label}" }
+                        input { r#type: "number", min: "0", value: "{number}", oninput: move |e| number.set(e.value()), class: FIELD_CLASS }
+                    }
+                }
+                if kind() == "Per level" {
+                    div {
+                        label { class: "block text-xs text-gray-500 dark:text-gray-400", "Levels in" }
+                        SelectInput {
+                            i_value: class(),
+                            on_input: move |e: FormEvent| class.set(e.value()),
+                            class: FIELD_CLASS,
+                            options: class_options(),
+                        }
+                    }
+                }
+                if takes_ability {
+                    div {
+                        label { class: "block text-xs text-gray-500 dark:text-gray-400", "Ability" }
+                        SelectInput {
+                            i_value: ability(),
+                            on_input: move |e: FormEvent| ability.set(e.value()),
+                            class: FIELD_CLASS,
+                            options: ability_options(),
+                        }
+                    }
+                }
+                if limited {
+                    div {
+                        label { class: "block text-xs text-gray-500 dark:text-gray-400", "Recharges" }
+                        SelectInput {
+                            i_value: recharge(),
+                            on_input: move |e: FormEvent| recharge.set(e.value()),
+                            class: FIELD_CLASS,
+                            options: pairs(&["Short rest", "Long rest", "Dawn", "Recharge roll"]),
+                        }
+                    }
+                    if recharge() == "Recharge roll" {
+                        div {
+                            label { class: "block text-xs text-gray-500 dark:text-gray-400", "On a d6 of at least" }
+                            input { r#type: "number", min: "2", max: "6", value: "{recharge_on}", oninput: move |e| recharge_on.set(e.value()), class: FIELD_CLASS }
+                        }
+                    }
+                }
+            }
+            button {
+                r#type: "submit",
+                class: "px-4 py-2 bg-blue-600 text-white rounded-lg hover:bg-blue-700 transition-colors",
+                "Add Feature"
+            }
+        }
+    }
+}
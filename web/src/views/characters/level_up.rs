@@ -0,0 +1,408 @@
+use dioxus::prelude::*;
+use crate::api::character::{get_level_history, level_up_character, CharacterDetail, LevelUpRecord};
+use crate::api::spells::Spell;
+use crate::components::SelectInput;
+use crate::rules::leveling::{self, HitPointMethod, Improvement, LevelUpChoice};
+use crate::rules::spellbook::{self, SpellbookEntry};
+use crate::rules::{ability_modifier, multiclass, Ability, Class, ClassLevel, DerivedStats, Subclass};
+use super::character_edit::server_error_messages;
+use super::spellbook::SpellPicker;
+
+const FIELD_CLASS: &str = "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-700 dark:text-white";
+
+fn ability_options() -> Vec<(String, String)> {
+    Ability::ALL.iter().map(|ability| (ability.name().to_string(), ability.name().to_string())).collect()
+}
+
+fn ability_from_name(name: &str) -> Option<Ability> {
+    Ability::ALL.into_iter().find(|ability| ability.name() == name)
+}
+
+fn slot_summary(stats: &DerivedStats) -> String {
+    let mut slots: Vec<String> = stats
+        .spell_slots
+        .iter()
+        .enumerate()
+        .filter(|(_, count)| **count > 0)
+        .map(|(index, count)| format!("L{}: {}", index + 1, count))
+        .collect();
+    if let Some(pact) = stats.pact_slots {
+        slots.push(format!("Pact: {} × L{}", pact.count, pact.slot_level));
+    }
+    if slots.is_empty() {
+        "None".to_string()
+    } else {
+        slots.join(", ")
+    }
+}
+
+/// Gain a level: pick the class, hit points, any Ability Score Improvement, subclass and new
+/// spells, preview the result and send it to the server
+#[component]
+pub fn LevelUpPanel(character: CharacterDetail, on_close: EventHandler<()>, on_leveled: EventHandler<CharacterDetail>) -> Element {
+    let starting = character.classes.first().map(|class_level| class_level.class).unwrap_or(Class::Fighter);
+    let mut class = use_signal(|| starting);
+    let mut subclass = use_signal::<Option<Subclass>>(|| None);
+    let mut hit_points = use_signal(HitPointMethod::default);
+    let mut take_feat = use_signal(|| false);
+    let mut increases = use_signal(|| [Ability::Strength, Ability::Strength]);
+    let mut feat = use_signal(String::new);
+    let mut new_spells = use_signal::<Vec<SpellbookEntry>>(Vec::new);
+    let mut saving = use_signal(|| false);
+    let mut errors = use_signal::<Vec<String>>(|| vec![]);
+
+    let choice = move || LevelUpChoice {
+        class: class(),
+        subclass: subclass(),
+        hit_points: hit_points(),
+        improvement: Some(if take_feat() { Improvement::Feat(feat()) } else { Improvement::AbilityScores(increases()) }),
+        new_spells: new_spells(),
+    };
+
+    // Preview with the average so the result shows before the server rolls
+    let preview_character = character.clone();
+    let preview = use_memo(move || {
+        let choice = LevelUpChoice { hit_points: HitPointMethod::Average, ..choice() };
+        leveling::level_up(&preview_character, &choice, None)
+    });
+
+    let class_level = character
+        .classes
+        .iter()
+        .find(|class_level| class_level.class == class())
+        .map_or(1, |class_level| class_level.level + 1);
+    let needs_subclass = leveling::needs_subclass(&character, class());
+    let grants_improvement = leveling::grants_ability_score_improvement(class(), class_level);
+    let spells_learned = leveling::spells_learned(class(), class_level).max(0) as usize;
+    let current_subclass = character.classes.iter().find(|c| c.class == class()).and_then(|c| c.subclass);
+    let max_spell_level =
+        spellbook::max_spell_level(&ClassLevel { subclass: current_subclass.or(subclass()), ..ClassLevel::new(class(), class_level) });
+    let mut known: Vec<String> = character.spellbook.entries.iter().map(|entry| entry.spell.clone()).collect();
+    known.extend(new_spells.read().iter().map(|entry| entry.spell.clone()));
+    let hit_die = class().hit_die();
+    let before = DerivedStats::for_character(&character);
+    let constitution = ability_modifier(character.constitution);
+
+    let class_options: Vec<(String, String)> = Class::ALL
+        .iter()
+        .map(|option| {
+            let current = character.classes.iter().find(|class_level| class_level.class == *option);
+            let label = match current {
+                Some(class_level) => format!("{} {} → {}", option, class_level.level, class_level.level + 1),
+                None if multiclass::meets_prerequisites(*option, |ability| ability.score(&character)) => {
+                    format!("{} 1 (multiclass)", option)
+                }
+                None => format!("{} 1 (prerequisites not met)", option),
+            };
+            (option.name().to_string(), label)
+        })
+        .collect();
+
+    let character_id = character.id;
+    let confirm = move |_| {
+        saving.set(true);
+        errors.set(vec![]);
+        spawn(async move {
+            match level_up_character(character_id, choice()).await {
+                Ok(leveled) => on_leveled.call(leveled),
+                Err(e) => errors.set(server_error_messages(e)),
+            }
+            saving.set(false);
+        });
+    };
+
+    rsx! {
+        div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 mb-6 shadow-lg border-2 border-green-500",
+            div { class: "flex items-center justify-between mb-4",
+                h2 { class: "text-xl font-semibold text-gray-900 dark:text-white",
+                    "Level Up to {character.level() + 1}"
+                }
+                button {
+                    r#type: "button",
+                    onclick: move |_| on_close.call(()),
+                    class: "text-gray-500 hover:text-gray-700 dark:hover:text-gray-300",
+                    "Cancel"
+                }
+            }
+
+            if !errors.read().is_empty() {
+                div {
+                    class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6",
+                    role: "alert",
+                    p { class: "font-bold", "Could not level up" }
+                    ul { class: "list-disc pl-5 mt-2",
+                        for message in errors.read().iter() {
+                            li { "{message}" }
+                        }
+                    }
+                }
+            }
+
+            div { class: "grid grid-cols-1 md:grid-cols-2 gap-6",
+                div { class: "space-y-4",
+                    div {
+                        label { class: "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2", "Class" }
+                        SelectInput {
+                            i_value: class().to_string(),
+                            options: class_options,
+                            on_input: move |e: FormEvent| {
+                                if let Some(picked) = Class::from_name(&e.value()) {
+                                    class.set(picked);
+                                    subclass.set(None);
+                                    new_spells.set(vec![]);
+                                }
+                            },
+                            class: FIELD_CLASS,
+                        }
+                    }
+
+                    if needs_subclass {
+                        div {
+                            label { class: "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2", "Subclass" }
+                            SelectInput {
+                                i_value: subclass().map(|s| s.to_string()).unwrap_or_default(),
+                                options: std::iter::once((String::new(), "Select subclass".to_string()))
+                                    .chain(class().subclasses().iter().map(|s| (s.name().to_string(), s.name().to_string())))
+                                    .collect::<Vec<_>>(),
+                                on_input: move |e: FormEvent| subclass.set(Subclass::from_name(&e.value())),
+                                class: FIELD_CLASS,
+                            }
+                        }
+                    }
+
+                    div {
+                        label { class: "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2", "Hit Points" }
+                        div { class: "flex flex-col gap-2",
+                            label { class: "flex items-center gap-2",
+                                input {
+                                    r#type: "radio",
+                                    name: "hit_points",
+                                    checked: hit_points() == HitPointMethod::Average,
+                                    onchange: move |_| hit_points.set(HitPointMethod::Average),
+                                }
+                                "Take the average: {leveling::average_hit_die(hit_die)} {constitution:+} CON"
+                            }
+                            label { class: "flex items-center gap-2",
+                                input {
+                                    r#type: "radio",
+                                    name: "hit_points",
+                                    checked: hit_points() == HitPointMethod::Roll,
+                                    onchange: move |_| hit_points.set(HitPointMethod::Roll),
+                                }
+                                "Roll 1d{hit_die} {constitution:+} CON (rolled on the server)"
+                            }
+                        }
+                    }
+
+                    if grants_improvement {
+                        div {
+                            label { class: "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2", "Ability Score Improvement" }
+                            div { class: "flex gap-4 mb-2",
+                                label { class: "flex items-center gap-2",
+                                    input {
+                                        r#type: "radio",
+                                        name: "improvement",
+                                        checked: !take_feat(),
+                                        onchange: move |_| take_feat.set(false),
+                                    }
+                                    "Increase scores"
+                                }
+                                label { class: "flex items-center gap-2",
+                                    input {
+                                        r#type: "radio",
+                                        name: "improvement",
+                                        checked: take_feat(),
+                                        onchange: move |_| take_feat.set(true),
+                                    }
+                                    "Take a feat"
+                                }
+                            }
+                            if take_feat() {
+                                input {
+                                    r#type: "text",
+                                    value: "{feat}",
+                                    oninput: move |e| feat.set(e.value()),
+                                    class: FIELD_CLASS,
+                                    placeholder: "Feat name, e.g. Alert",
+                                }
+                            } else {
+                                div { class: "grid grid-cols-2 gap-2",
+                                    for index in 0..2 {
+                                        SelectInput {
+                                            i_value: increases()[index].name().to_string(),
+                                            options: ability_options(),
+                                            on_input: move |e: FormEvent| {
+                                                if let Some(ability) = ability_from_name(&e.value()) {
+                                                    increases.write()[index] = ability;
+                                                }
+                                            },
+                                            class: FIELD_CLASS,
+                                        }
+                                    }
+                                }
+                                p { class: "text-xs text-gray-500 dark:text-gray-400 mt-1",
+                                    "+1 to each; pick the same ability twice for +2"
+                                }
+                            }
+                        }
+                    }
+
+                    if spells_learned > 0 {
+                        div {
+                            label { class: "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2",
+                                "New Spells ({spells_learned})"
+                            }
+                            div { class: "flex flex-wrap gap-2 mb-2",
+                                for (index, entry) in new_spells.read().iter().enumerate() {
+                                    span { class: "inline-flex items-center px-3 py-1 bg-purple-100 dark:bg-purple-900 text-purple-800 dark:text-purple-200 rounded-full text-sm font-medium",
+                                        "{entry.name}"
+                                        button {
+                                            r#type: "button",
+                                            onclick: move |_| {
+                                                new_spells.write().remove(index);
+                                            },
+                                            class: "ml-2 hover:text-purple-600",
+                                            "✕"
+                                        }
+                                    }
+                                }
+                            }
+                            if new_spells.read().len() < spells_learned {
+                                SpellPicker {
+                                    class: Some(class()),
+                                    max_level: Some(max_spell_level),
+                                    known,
+                                    on_pick: move |spell: Spell| new_spells.write().push(spell.to_entry(Some(class()))),
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // What the new level brings
+                div { class: "bg-gray-50 dark:bg-gray-700 rounded-lg p-4 space-y-3",
+                    h3 { class: "font-semibold text-gray-900 dark:text-white", "{class()} {class_level}" }
+                    match &*preview.read() {
+                        Ok(level_up) => {
+                            let after = DerivedStats::for_character(&level_up.character);
+                            rsx! {
+                                div { class: "text-sm",
+                                    span { class: "text-gray-500 dark:text-gray-400", "Hit points: " }
+                                    if hit_points() == HitPointMethod::Average {
+                                        "{character.hit_points} → {level_up.character.hit_points}"
+                                    } else {
+                                        "{character.hit_points} + 1d{hit_die} {constitution:+}"
+                                    }
+                                }
+                                div { class: "text-sm",
+                                    span { class: "text-gray-500 dark:text-gray-400", "Proficiency bonus: " }
+                                    "{before.proficiency_bonus:+} → {after.proficiency_bonus:+}"
+                                }
+                                div { class: "text-sm",
+                                    span { class: "text-gray-500 dark:text-gray-400", "Spell slots: " }
+                                    "{slot_summary(&before)} → {slot_summary(&after)}"
+                                }
+                                if !level_up.features.is_empty() {
+                                    div { class: "text-sm",
+                                        span { class: "text-gray-500 dark:text-gray-400", "New features: " }
+                                        "{level_up.features.join(\", \")}"
+                                    }
+                                }
+                            }
+                        }
+                        Err(problems) => rsx! {
+                            ul { class: "list-disc pl-5 text-sm text-red-700",
+                                for problem in problems.iter() {
+                                    li { "{problem}" }
+                                }
+                            }
+                        },
+                    }
+                }
+            }
+
+            div { class: "flex justify-end mt-6",
+                button {
+                    r#type: "button",
+                    disabled: saving() || preview.read().is_err(),
+                    onclick: confirm,
+                    class: "px-6 py-2 bg-green-600 text-white rounded-lg hover:bg-green-700 disabled:opacity-50 disabled:cursor-not-allowed transition-colors",
+                    if saving() { "Levelling up..." } else { "Confirm Level Up" }
+                }
+            }
+        }
+    }
+}
+
+/// Every recorded level-up for a character, oldest first. Only the owner and DM can load it.
+#[component]
+pub fn LevelHistory(character_id: i32, version: i32) -> Element {
+    let history = use_resource(use_reactive!(|(character_id, version)| async move {
+        let _ = version;
+        get_level_history(character_id).await
+    }));
+
+    rsx! {
+        div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 mt-6 shadow-lg",
+            h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "Level History" }
+            match &*history.read() {
+                None => rsx! { p { class: "text-gray-500", "Loading..." } },
+                Some(Err(e)) => rsx! { p { class: "text-red-700", "Failed to load level history: {e}" } },
+                Some(Ok(records)) if records.is_empty() => rsx! {
+                    p { class: "text-gray-500 dark:text-gray-400", "No level-ups recorded yet." }
+                },
+                Some(Ok(records)) => rsx! {
+                    div { class: "overflow-x-auto",
+                        table { class: "w-full text-sm text-left",
+                            thead { class: "text-gray-500 dark:text-gray-400",
+                                tr {
+                                    th { class: "py-2 pr-4", "Level" }
+                                    th { class: "py-2 pr-4", "Class" }
+                                    th { class: "py-2 pr-4", "Hit Points" }
+                                    th { class: "py-2 pr-4", "Improvement" }
+                                    th { class: "py-2 pr-4", "Features & Spells" }
+                                    th { class: "py-2", "When" }
+                                }
+                            }
+                            tbody {
+                                for record in records.iter() {
+                                    LevelHistoryRow { record: record.clone() }
+                                }
+                            }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+fn LevelHistoryRow(record: LevelUpRecord) -> Element {
+    let hit_points = match record.hit_die_roll {
+        Some(roll) => format!("+{} (rolled {} on d{})", record.hit_points_gained, roll, record.hit_die),
+        None => format!("+{} ({})", record.hit_points_gained, record.hit_point_method),
+    };
+    let mut gained = record.features.clone();
+    gained.extend(record.spells.iter().cloned());
+
+    rsx! {
+        tr { class: "border-t border-gray-200 dark:border-gray-700",
+            td { class: "py-2 pr-4 font-semibold", "{record.level}" }
+            td { class: "py-2 pr-4",
+                "{record.class} {record.class_level}"
+                if let Some(subclass) = record.subclass {
+                    " ({subclass})"
+                }
+            }
+            td { class: "py-2 pr-4", "{hit_points}" }
+            td { class: "py-2 pr-4",
+                {record.improvement.as_ref().map(|improvement| improvement.to_string()).unwrap_or_else(|| "—".to_string())}
+            }
+            td { class: "py-2 pr-4", "{gained.join(\", \")}" }
+            td { class: "py-2 text-gray-500 dark:text-gray-400",
+                {record.created_at.as_deref().and_then(|at| at.get(..10)).unwrap_or("")}
+            }
+        }
+    }
+}
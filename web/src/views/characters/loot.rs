@@ -1,16 +1,492 @@
-
 use dioxus::prelude::*;
+use crate::api::character::{get_character_with_slug, update_inventory, CharacterDetail};
+use crate::components::SelectInput;
+use crate::rules::inventory::{self, format_copper, Coin, Inventory, Item, ATTUNEMENT_LIMIT};
+use crate::Route;
+use super::character_edit::server_error_messages;
+
+const FIELD_CLASS: &str = "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-700 dark:text-white";
+const CELL_INPUT_CLASS: &str = "w-20 px-2 py-1 border border-gray-300 dark:border-gray-600 rounded dark:bg-gray-700 dark:text-white";
+
+fn coin_options() -> Vec<(String, String)> {
+    Coin::ALL.iter().map(|coin| (coin.abbreviation().to_string(), coin.abbreviation().to_string())).collect()
+}
+
+// Values are typed in gold pieces and stored in copper
+fn gold_to_copper(value: &str) -> Option<i64> {
+    value.trim().parse::<f64>().ok().filter(|gold| *gold >= 0.0).map(|gold| (gold * 100.0).round() as i64)
+}
+
+/// A character's inventory: items and containers, the coin purse, and how much they're carrying
+#[component]
+pub fn PlayerLoot(slug: String) -> Element {
+    let mut character = use_signal::<Option<CharacterDetail>>(|| None);
+    let mut inventory = use_signal(Inventory::default);
+    let mut loading = use_signal(|| true);
+    let mut saving = use_signal(|| false);
+    let mut dirty = use_signal(|| false);
+    let mut errors = use_signal::<Vec<String>>(|| vec![]);
+
+    use_effect(use_reactive!(|slug| {
+        spawn(async move {
+            loading.set(true);
+            errors.set(vec![]);
+            match get_character_with_slug(slug).await {
+                Ok(fetched) => {
+                    inventory.set(fetched.inventory.clone());
+                    character.set(Some(fetched));
+                    dirty.set(false);
+                }
+                Err(e) => errors.set(server_error_messages(e)),
+            }
+            loading.set(false);
+        });
+    }));
+
+    let can_edit = character.read().as_ref().is_some_and(|c| c.access.can_edit());
+    let strength = character.read().as_ref().map_or(10, |c| c.strength);
+    let speed = character.read().as_ref().map_or(30, |c| c.speed);
+
+    let save = move |_| {
+        let Some((id, loaded_at)) = character.read().as_ref().map(|c| (c.id, c.updated_at.clone())) else {
+            return;
+        };
+        let validation_errors = inventory.read().validate();
+        if !validation_errors.is_empty() {
+            errors.set(validation_errors);
+            return;
+        }
+        saving.set(true);
+        errors.set(vec![]);
+        spawn(async move {
+            match update_inventory(id, inventory(), loaded_at).await {
+                Ok(saved) => {
+                    inventory.set(saved.inventory.clone());
+                    character.set(Some(saved));
+                    dirty.set(false);
+                }
+                Err(e) => errors.set(server_error_messages(e)),
+            }
+            saving.set(false);
+        });
+    };
+
+    let add_item = move |mut item: Item| {
+        let mut inventory = inventory.write();
+        item.id = inventory.next_id();
+        inventory.items.push(item);
+        dirty.set(true);
+    };
+
+    rsx! {
+        div { class: "max-w-6xl mx-auto py-8",
+            if loading() {
+                div { class: "flex justify-center py-12",
+                    div {
+                        class: "animate-spin rounded-full h-12 w-12 border-t-2 border-b-2 border-blue-500",
+                        aria_label: "Loading...",
+                    }
+                }
+            }
+
+            if !errors.read().is_empty() {
+                div {
+                    class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6",
+                    role: "alert",
+                    p { class: "font-bold", "Could not update inventory" }
+                    ul { class: "list-disc pl-5 mt-2",
+                        for message in errors.read().iter() {
+                            li { "{message}" }
+                        }
+                    }
+                }
+            }
+
+            if let Some(character) = character.read().as_ref() {
+                div { class: "flex items-center justify-between mb-8",
+                    div {
+                        Link {
+                            to: Route::CharacterById { slug: character.slug.clone() },
+                            class: "text-blue-600 dark:text-blue-400 hover:underline",
+                            "← Back to {character.name}"
+                        }
+                        h1 { class: "text-3xl font-bold text-gray-900 dark:text-white mt-2", "Inventory" }
+                    }
+                    if can_edit {
+                        button {
+                            r#type: "button",
+                            disabled: saving() || !dirty(),
+                            onclick: save,
+                            class: "px-6 py-2 bg-blue-600 text-white rounded-lg hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed transition-colors",
+                            if saving() { "Saving..." } else if dirty() { "Save Changes" } else { "Saved" }
+                        }
+                    } else {
+                        span { class: "px-3 py-1 text-sm bg-gray-100 text-gray-600 rounded-full", "Read only" }
+                    }
+                }
+            }
+
+            if character.read().is_some() {
+                div { class: "grid grid-cols-1 lg:grid-cols-3 gap-6 mb-6",
+                    CarryingSummary { inventory: inventory(), strength, speed }
+                    CoinPurse { inventory, can_edit, on_change: move |_| dirty.set(true) }
+                    if can_edit {
+                        AddItemForm { on_add: add_item }
+                    }
+                }
+
+                div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg",
+                    h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "Items" }
+                    if inventory.read().items.is_empty() {
+                        p { class: "text-gray-500 dark:text-gray-400", "Nothing carried yet." }
+                    } else {
+                        div { class: "overflow-x-auto",
+                            table { class: "w-full text-sm text-left",
+                                thead { class: "text-gray-500 dark:text-gray-400",
+                                    tr {
+                                        th { class: "py-2 pr-3", "Item" }
+                                        th { class: "py-2 pr-3", "Qty" }
+                                        th { class: "py-2 pr-3", "Weight (lb)" }
+                                        th { class: "py-2 pr-3", "Value" }
+                                        th { class: "py-2 pr-3", "Equipped" }
+                                        th { class: "py-2 pr-3", "Attuned" }
+                                        th { class: "py-2 pr-3", "In" }
+                                        th { class: "py-2" }
+                                    }
+                                }
+                                tbody {
+                                    ItemRows { inventory, container: None, depth: 0, can_edit, on_change: move |_| dirty.set(true) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn CarryingSummary(inventory: Inventory, strength: i32, speed: i32) -> Element {
+    let weight = inventory.carried_weight();
+    let capacity = inventory::carrying_capacity(strength);
+    let encumbrance = inventory::encumbrance(weight, strength);
+    let attuned = inventory.attuned_count();
+    let percent = if capacity > 0.0 { (weight / capacity * 100.0).min(100.0) } else { 100.0 };
+    let bar_class = match encumbrance {
+        inventory::Encumbrance::Unencumbered => "bg-green-500",
+        inventory::Encumbrance::Encumbered => "bg-yellow-500",
+        _ => "bg-red-500",
+    };
+
+    rsx! {
+        div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg",
+            h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "Carrying" }
+            div { class: "text-2xl font-bold", "{weight:.1} / {capacity:.0} lb" }
+            div { class: "w-full h-2 bg-gray-200 dark:bg-gray-700 rounded-full mt-2 mb-2",
+                div { class: "h-2 rounded-full {bar_class}", style: "width: {percent:.0}%" }
+            }
+            div { class: "text-sm text-gray-600 dark:text-gray-400 space-y-1",
+                p { "{encumbrance.label()}" }
+                p { "Speed: {(speed - encumbrance.speed_penalty()).max(0)} ft" }
+                p { "Push, drag or lift: {inventory::push_drag_lift(strength):.0} lb" }
+                p { "Attuned: {attuned} / {ATTUNEMENT_LIMIT}" }
+                p { "Item value: {format_copper(inventory.item_value())}" }
+            }
+        }
+    }
+}
 
+#[component]
+fn CoinPurse(inventory: Signal<Inventory>, can_edit: bool, on_change: EventHandler<()>) -> Element {
+    let mut amount = use_signal(|| "1".to_string());
+    let mut from = use_signal(|| Coin::Copper);
+    let mut to = use_signal(|| Coin::Silver);
+    let mut convert_error = use_signal::<Option<String>>(|| None);
+    let coins = inventory.read().coins;
+
+    rsx! {
+        div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg",
+            h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "Coin Purse" }
+            div { class: "grid grid-cols-5 gap-2 mb-2",
+                for coin in Coin::ALL {
+                    div { class: "text-center",
+                        label { class: "block text-xs font-medium text-gray-500 dark:text-gray-400 uppercase", "{coin.abbreviation()}" }
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            disabled: !can_edit,
+                            value: "{coins.get(coin)}",
+                            oninput: move |e| {
+                                if let Ok(count) = e.value().parse::<i64>() {
+                                    *inventory.write().coins.get_mut(coin) = count.max(0);
+                                    on_change.call(());
+                                }
+                            },
+                            class: "w-full px-1 py-1 text-center border border-gray-300 dark:border-gray-600 rounded dark:bg-gray-700 dark:text-white",
+                        }
+                    }
+                }
+            }
+            p { class: "text-sm text-gray-600 dark:text-gray-400 mb-4",
+                "Worth {format_copper(coins.total_in_copper())} • {coins.weight():.1} lb"
+            }
+            if can_edit {
+                div { class: "flex items-center gap-2",
+                    input {
+                        r#type: "number",
+                        min: "1",
+                        value: "{amount}",
+                        oninput: move |e| amount.set(e.value()),
+                        class: CELL_INPUT_CLASS,
+                    }
+                    SelectInput {
+                        i_value: from().abbreviation().to_string(),
+                        options: coin_options(),
+                        on_input: move |e: FormEvent| from.set(Coin::from_abbreviation(&e.value()).unwrap_or(Coin::Copper)),
+                        class: CELL_INPUT_CLASS,
+                    }
+                    span { "→" }
+                    SelectInput {
+                        i_value: to().abbreviation().to_string(),
+                        options: coin_options(),
+                        on_input: move |e: FormEvent| to.set(Coin::from_abbreviation(&e.value()).unwrap_or(Coin::Silver)),
+                        class: CELL_INPUT_CLASS,
+                    }
+                    button {
+                        r#type: "button",
+                        onclick: move |_| {
+                            let count = amount().trim().parse::<i64>().unwrap_or(0);
+                            let result = inventory.write().coins.convert(from(), to(), count);
+                            match result {
+                                Ok(()) => {
+                                    convert_error.set(None);
+                                    on_change.call(());
+                                }
+                                Err(e) => convert_error.set(Some(e)),
+                            }
+                        },
+                        class: "px-3 py-1 bg-yellow-500 text-white rounded hover:bg-yellow-600",
+                        "Convert"
+                    }
+                }
+                if let Some(e) = convert_error() {
+                    p { class: "text-sm text-red-600 mt-2", "{e}" }
+                }
+            }
+        }
+    }
+}
 
+#[component]
+fn AddItemForm(on_add: EventHandler<Item>) -> Element {
+    let mut name = use_signal(String::new);
+    let mut quantity = use_signal(|| "1".to_string());
+    let mut weight = use_signal(|| "0".to_string());
+    let mut value = use_signal(|| "0".to_string());
+    let mut requires_attunement = use_signal(|| false);
+    let mut is_container = use_signal(|| false);
 
+    rsx! {
+        div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg",
+            h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "Add Item" }
+            form {
+                class: "space-y-3",
+                onsubmit: move |e| {
+                    e.prevent_default();
+                    if name().trim().is_empty() {
+                        return;
+                    }
+                    let mut item = Item::new(0, name().trim());
+                    item.quantity = quantity().trim().parse().unwrap_or(1).max(0);
+                    item.weight = weight().trim().parse::<f64>().unwrap_or(0.0).max(0.0);
+                    item.value = gold_to_copper(&value()).unwrap_or(0);
+                    item.requires_attunement = requires_attunement();
+                    item.is_container = is_container();
+                    on_add.call(item);
+                    name.set(String::new());
+                    quantity.set("1".to_string());
+                    weight.set("0".to_string());
+                    value.set("0".to_string());
+                    requires_attunement.set(false);
+                    is_container.set(false);
+                },
+                input {
+                    r#type: "text",
+                    value: "{name}",
+                    oninput: move |e| name.set(e.value()),
+                    class: FIELD_CLASS,
+                    placeholder: "Item name",
+                }
+                div { class: "grid grid-cols-3 gap-2",
+                    div {
+                        label { class: "block text-xs text-gray-500 dark:text-gray-400", "Quantity" }
+                        input { r#type: "number", min: "0", value: "{quantity}", oninput: move |e| quantity.set(e.value()), class: FIELD_CLASS }
+                    }
+                    div {
+                        label { class: "block text-xs text-gray-500 dark:text-gray-400", "Weight (lb each)" }
+                        input { r#type: "number", min: "0", step: "0.1", value: "{weight}", oninput: move |e| weight.set(e.value()), class: FIELD_CLASS }
+                    }
+                    div {
+                        label { class: "block text-xs text-gray-500 dark:text-gray-400", "Value (gp each)" }
+                        input { r#type: "number", min: "0", step: "0.01", value: "{value}", oninput: move |e| value.set(e.value()), class: FIELD_CLASS }
+                    }
+                }
+                label { class: "flex items-center gap-2 text-sm",
+                    input { r#type: "checkbox", checked: requires_attunement(), onchange: move |e| requires_attunement.set(e.checked()) }
+                    "Requires attunement"
+                }
+                label { class: "flex items-center gap-2 text-sm",
+                    input { r#type: "checkbox", checked: is_container(), onchange: move |e| is_container.set(e.checked()) }
+                    "Container"
+                }
+                button {
+                    r#type: "submit",
+                    class: "px-4 py-2 bg-blue-600 text-white rounded-lg hover:bg-blue-700 transition-colors",
+                    "Add"
+                }
+            }
+        }
+    }
+}
 
+/// The items inside `container` (or carried loose), each followed by its own contents
 #[component]
-pub fn PlayerLoot() -> Element {
+fn ItemRows(inventory: Signal<Inventory>, container: Option<u32>, depth: usize, can_edit: bool, on_change: EventHandler<()>) -> Element {
+    let ids: Vec<u32> = inventory.read().contents(container).map(|item| item.id).collect();
+
+    rsx! {
+        for id in ids {
+            ItemRow { key: "{id}", inventory, id, depth, can_edit, on_change }
+            if inventory.read().item(id).is_some_and(|item| item.is_container) {
+                ItemRows { inventory, container: Some(id), depth: depth + 1, can_edit, on_change }
+            }
+        }
+    }
+}
 
+#[component]
+fn ItemRow(inventory: Signal<Inventory>, id: u32, depth: usize, can_edit: bool, on_change: EventHandler<()>) -> Element {
+    let Some(item) = inventory.read().item(id).cloned() else {
+        return rsx! {};
+    };
+    let mut update = move |change: &dyn Fn(&mut Item)| {
+        if let Some(item) = inventory.write().items.iter_mut().find(|item| item.id == id) {
+            change(item);
+        }
+        on_change.call(());
+    };
+    let at_limit = inventory.read().attuned_count() >= ATTUNEMENT_LIMIT;
+    // Anywhere but inside itself
+    let containers: Vec<(String, String)> = std::iter::once((String::new(), "Carried".to_string()))
+        .chain(
+            inventory
+                .read()
+                .items
+                .iter()
+                .filter(|other| other.is_container && other.id != id)
+                .map(|other| (other.id.to_string(), other.name.clone())),
+        )
+        .collect();
+    let indent = format!("padding-left: {}rem", depth as f32 * 1.5);
 
     rsx! {
-        div { class: "max-w-lg mx-auto py-2",
-            h1 { class: "text-3xl", "Player Loot" }
+        tr { class: "border-t border-gray-200 dark:border-gray-700",
+            td { class: "py-2 pr-3", style: "{indent}",
+                span { class: "font-medium", "{item.name}" }
+                if item.is_container {
+                    span { class: "ml-2 text-xs text-gray-500", "container" }
+                }
+                if item.requires_attunement {
+                    span { class: "ml-2 text-xs text-purple-600 dark:text-purple-300", "requires attunement" }
+                }
+            }
+            td { class: "py-2 pr-3",
+                input {
+                    r#type: "number",
+                    min: "0",
+                    disabled: !can_edit,
+                    value: "{item.quantity}",
+                    oninput: move |e| {
+                        if let Ok(quantity) = e.value().parse::<i32>() {
+                            update(&|item| item.quantity = quantity.max(0));
+                        }
+                    },
+                    class: CELL_INPUT_CLASS,
+                }
+            }
+            td { class: "py-2 pr-3",
+                input {
+                    r#type: "number",
+                    min: "0",
+                    step: "0.1",
+                    disabled: !can_edit,
+                    value: "{item.weight}",
+                    oninput: move |e| {
+                        if let Ok(weight) = e.value().parse::<f64>() {
+                            update(&|item| item.weight = weight.max(0.0));
+                        }
+                    },
+                    class: CELL_INPUT_CLASS,
+                }
+            }
+            td { class: "py-2 pr-3 whitespace-nowrap", "{format_copper(item.value)}" }
+            td { class: "py-2 pr-3",
+                input {
+                    r#type: "checkbox",
+                    disabled: !can_edit,
+                    checked: item.equipped,
+                    onchange: move |e| {
+                        let equipped = e.checked();
+                        update(&|item| item.equipped = equipped);
+                    },
+                }
+            }
+            td { class: "py-2 pr-3",
+                input {
+                    r#type: "checkbox",
+                    disabled: !can_edit || !item.requires_attunement || (at_limit && !item.attuned),
+                    checked: item.attuned,
+                    onchange: move |e| {
+                        let attuned = e.checked();
+                        update(&|item| item.attuned = attuned);
+                    },
+                }
+            }
+            td { class: "py-2 pr-3",
+                if can_edit {
+                    SelectInput {
+                        i_value: item.container.map(|container| container.to_string()).unwrap_or_default(),
+                        options: containers,
+                        on_input: move |e: FormEvent| {
+                            let container = e.value().parse::<u32>().ok();
+                            update(&|item| item.container = container);
+                        },
+                        class: "px-2 py-1 border border-gray-300 dark:border-gray-600 rounded dark:bg-gray-700 dark:text-white",
+                    }
+                } else {
+                    {containers.into_iter().find(|(value, _)| *value == item.container.map(|c| c.to_string()).unwrap_or_default()).map(|(_, name)| name)}
+                }
+            }
+            td { class: "py-2 text-right",
+                if can_edit {
+                    button {
+                        r#type: "button",
+                        onclick: move |_| {
+                            let mut inventory = inventory.write();
+                            // Whatever was inside spills out to wherever the container was
+                            let parent = inventory.item(id).and_then(|item| item.container);
+                            for other in inventory.items.iter_mut().filter(|other| other.container == Some(id)) {
+                                other.container = parent;
+                            }
+                            inventory.items.retain(|item| item.id != id);
+                            on_change.call(());
+                        },
+                        class: "text-red-600 hover:underline",
+                        "Remove"
+                    }
+                }
+            }
         }
     }
-} 
\ No newline at end of file
+}
@@ -4,8 +4,19 @@ pub use character::Character;
 mod character_by_id;
 pub use character_by_id::CharacterById;
 
+mod character_create;
+pub use character_create::CharacterCreate;
+
 mod character_edit;
 pub use character_edit::CharacterEdit;
 
+mod features;
+
+mod level_up;
+
+mod play;
+
 mod loot;
-pub use loot::PlayerLoot;
\ No newline at end of file
+pub use loot::PlayerLoot;
+mod spellbook;
+pub use spellbook::CharacterSpellbook;
@@ -0,0 +1,269 @@
+use dioxus::prelude::*;
+use crate::api::character::{apply_play_action, CharacterDetail};
+use crate::components::SelectInput;
+use crate::rules::play::{self, exhaustion_effect, Condition, PlayAction, MAX_EXHAUSTION};
+use super::character_edit::server_error_messages;
+
+const BUTTON_CLASS: &str = "px-3 py-2 text-sm text-white rounded-lg disabled:opacity-50 disabled:cursor-not-allowed transition-colors";
+
+fn condition_class(active: bool) -> &'static str {
+    if active {
+        "px-3 py-1 text-sm rounded-full border border-red-500 bg-red-100 text-red-800"
+    } else {
+        "px-3 py-1 text-sm rounded-full border border-gray-300 dark:border-gray-600 text-gray-600 dark:text-gray-300 hover:bg-gray-100 dark:hover:bg-gray-700"
+    }
+}
+
+fn save_pip_class(marked: bool, success: bool) -> &'static str {
+    match (marked, success) {
+        (true, true) => "w-4 h-4 rounded-full bg-green-500",
+        (true, false) => "w-4 h-4 rounded-full bg-red-500",
+        (false, _) => "w-4 h-4 rounded-full border-2 border-gray-300 dark:border-gray-600",
+    }
+}
+
+fn exhaustion_options() -> Vec<(String, String)> {
+    (0..=MAX_EXHAUSTION).map(|level| (level.to_string(), format!("{}: {}", level, exhaustion_effect(level)))).collect()
+}
+
+/// Play mode for the sheet: hit points, death saves, conditions, exhaustion, hit dice and rests.
+/// Each change is applied by the server so the DM's view of the party stays the same.
+#[component]
+pub fn PlayTracker(character: CharacterDetail, on_change: EventHandler<CharacterDetail>) -> Element {
+    let mut amount = use_signal(String::new);
+    let mut busy = use_signal(|| false);
+    let mut errors = use_signal::<Vec<String>>(|| vec![]);
+    let mut log = use_signal::<Vec<String>>(|| vec![]);
+
+    let id = character.id;
+    let state = &character.play_state;
+    let max = play::max_hit_points(&character);
+    let current = state.current_hit_points(max);
+    let temporary = state.temporary_hit_points;
+    let death_saves = state.death_saves;
+    let dead = state.is_dead();
+    let hit_dice = play::hit_dice_remaining(&character);
+    let health_percent = if max > 0 { current * 100 / max } else { 0 };
+
+    let mut run = move |action: PlayAction| {
+        if busy() {
+            return;
+        }
+        busy.set(true);
+        errors.set(vec![]);
+        spawn(async move {
+            match apply_play_action(id, action).await {
+                Ok(update) => {
+                    let mut log = log.write();
+                    log.insert(0, update.message);
+                    log.truncate(8);
+                    on_change.call(update.character);
+                }
+                Err(e) => errors.set(server_error_messages(e)),
+            }
+            busy.set(false);
+        });
+    };
+
+    // Damage, healing and temporary hit points all read the same amount box
+    let mut with_amount = move |action: fn(i32) -> PlayAction| match amount().trim().parse::<i32>() {
+        Ok(value) => {
+            run(action(value));
+            amount.set(String::new());
+        }
+        Err(_) => errors.set(vec!["Enter a whole number".to_string()]),
+    };
+
+    rsx! {
+        div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 mb-6 shadow-lg border-2 border-green-500",
+            div { class: "flex items-center justify-between mb-4",
+                h2 { class: "text-xl font-semibold text-gray-900 dark:text-white", "Play Mode" }
+                if busy() {
+                    span { class: "text-sm text-gray-500 dark:text-gray-400", "Saving..." }
+                }
+            }
+
+            if !errors.read().is_empty() {
+                div {
+                    class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6",
+                    role: "alert",
+                    for message in errors.read().iter() {
+                        p { "{message}" }
+                    }
+                }
+            }
+
+            div { class: "grid grid-cols-1 lg:grid-cols-3 gap-6",
+                // Hit points
+                div {
+                    div { class: "flex items-baseline gap-2",
+                        span { class: "text-4xl font-bold text-gray-900 dark:text-white", "{current}" }
+                        span { class: "text-xl text-gray-500 dark:text-gray-400", "/ {max}" }
+                        if temporary > 0 {
+                            span { class: "px-2 py-0.5 text-sm rounded-full bg-blue-100 text-blue-800", "+{temporary} temp" }
+                        }
+                    }
+                    div { class: "w-full h-3 bg-gray-200 dark:bg-gray-700 rounded-full mt-2 mb-4",
+                        div { class: "h-3 bg-green-500 rounded-full", style: "width: {health_percent}%" }
+                    }
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        value: amount(),
+                        oninput: move |e| amount.set(e.value()),
+                        class: "w-full px-3 py-2 mb-2 border border-gray-300 dark:border-gray-600 rounded-lg dark:bg-gray-700 dark:text-white",
+                        placeholder: "Amount",
+                    }
+                    div { class: "flex gap-2",
+                        button {
+                            r#type: "button",
+                            disabled: busy() || dead,
+                            onclick: move |_| with_amount(PlayAction::Damage),
+                            class: "{BUTTON_CLASS} bg-red-600 hover:bg-red-700",
+                            "Damage"
+                        }
+                        button {
+                            r#type: "button",
+                            disabled: busy() || dead,
+                            onclick: move |_| with_amount(PlayAction::Heal),
+                            class: "{BUTTON_CLASS} bg-green-600 hover:bg-green-700",
+                            "Heal"
+                        }
+                        button {
+                            r#type: "button",
+                            disabled: busy() || dead,
+                            onclick: move |_| with_amount(PlayAction::SetTemporaryHitPoints),
+                            class: "{BUTTON_CLASS} bg-blue-600 hover:bg-blue-700",
+                            "Set Temp"
+                        }
+                    }
+
+                    if dead {
+                        p { class: "mt-4 font-bold text-red-700", "{character.name} is dead." }
+                    } else if current == 0 {
+                        div { class: "mt-4",
+                            h3 { class: "font-semibold text-gray-900 dark:text-white mb-2",
+                                if death_saves.is_stable() { "Death Saves (stable)" } else { "Death Saves" }
+                            }
+                            div { class: "flex items-center gap-2 mb-1",
+                                span { class: "w-20 text-sm text-gray-600 dark:text-gray-400", "Successes" }
+                                for pip in 0..3 {
+                                    span { class: save_pip_class(pip < death_saves.successes, true) }
+                                }
+                            }
+                            div { class: "flex items-center gap-2 mb-2",
+                                span { class: "w-20 text-sm text-gray-600 dark:text-gray-400", "Failures" }
+                                for pip in 0..3 {
+                                    span { class: save_pip_class(pip < death_saves.failures, false) }
+                                }
+                            }
+                            if !death_saves.is_stable() {
+                                div { class: "flex gap-2",
+                                    button {
+                                        r#type: "button",
+                                        disabled: busy(),
+                                        onclick: move |_| run(PlayAction::RollDeathSave),
+                                        class: "{BUTTON_CLASS} bg-gray-700 hover:bg-gray-800",
+                                        "Roll"
+                                    }
+                                    button {
+                                        r#type: "button",
+                                        disabled: busy(),
+                                        onclick: move |_| run(PlayAction::DeathSave { success: true }),
+                                        class: "{BUTTON_CLASS} bg-green-600 hover:bg-green-700",
+                                        "+ Success"
+                                    }
+                                    button {
+                                        r#type: "button",
+                                        disabled: busy(),
+                                        onclick: move |_| run(PlayAction::DeathSave { success: false }),
+                                        class: "{BUTTON_CLASS} bg-red-600 hover:bg-red-700",
+                                        "+ Failure"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Conditions and exhaustion
+                div {
+                    h3 { class: "font-semibold text-gray-900 dark:text-white mb-2", "Conditions" }
+                    div { class: "flex flex-wrap gap-2 mb-4",
+                        for condition in Condition::ALL {
+                            button {
+                                r#type: "button",
+                                disabled: busy(),
+                                title: condition.effect(),
+                                onclick: move |_| run(PlayAction::ToggleCondition(condition)),
+                                class: condition_class(state.has(condition)),
+                                "{condition}"
+                            }
+                        }
+                    }
+                    h3 { class: "font-semibold text-gray-900 dark:text-white mb-2", "Exhaustion" }
+                    SelectInput {
+                        i_value: state.exhaustion.to_string(),
+                        on_input: move |e: FormEvent| {
+                            if let Ok(level) = e.value().parse() {
+                                run(PlayAction::SetExhaustion(level));
+                            }
+                        },
+                        class: "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg dark:bg-gray-700 dark:text-white",
+                        options: exhaustion_options(),
+                    }
+                }
+
+                // Hit dice, rests and what just happened
+                div {
+                    h3 { class: "font-semibold text-gray-900 dark:text-white mb-2", "Hit Dice" }
+                    div { class: "space-y-2 mb-4",
+                        for (die, remaining) in hit_dice {
+                            div { class: "flex items-center justify-between",
+                                span { class: "text-gray-700 dark:text-gray-300", "d{die}: {remaining} left" }
+                                button {
+                                    r#type: "button",
+                                    disabled: busy() || dead || remaining == 0 || current == max,
+                                    onclick: move |_| run(PlayAction::SpendHitDie(die)),
+                                    class: "{BUTTON_CLASS} bg-purple-600 hover:bg-purple-700",
+                                    "Spend"
+                                }
+                            }
+                        }
+                    }
+                    div { class: "flex gap-2 mb-4",
+                        button {
+                            r#type: "button",
+                            disabled: busy() || dead,
+                            onclick: move |_| run(PlayAction::ShortRest),
+                            class: "{BUTTON_CLASS} bg-indigo-600 hover:bg-indigo-700",
+                            "Short Rest"
+                        }
+                        button {
+                            r#type: "button",
+                            disabled: busy() || dead,
+                            onclick: move |_| run(PlayAction::LongRest),
+                            class: "{BUTTON_CLASS} bg-indigo-800 hover:bg-indigo-900",
+                            "Long Rest"
+                        }
+                        button {
+                            r#type: "button",
+                            disabled: busy() || dead,
+                            title: "Items that regain charges at dawn",
+                            onclick: move |_| run(PlayAction::Dawn),
+                            class: "{BUTTON_CLASS} bg-amber-600 hover:bg-amber-700",
+                            "Dawn"
+                        }
+                    }
+                    if !log.read().is_empty() {
+                        ul { class: "text-sm text-gray-600 dark:text-gray-400 space-y-1",
+                            for entry in log.read().iter() {
+                                li { "{entry}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
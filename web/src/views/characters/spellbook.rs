@@ -0,0 +1,494 @@
+use dioxus::prelude::*;
+use crate::api::character::{get_character_with_slug, update_spellbook, CharacterDetail};
+use crate::api::spells::{search_spells, spell_level_label, Spell};
+use crate::components::SelectInput;
+use crate::rules::spellbook::{self, CastWith, Spellbook, SpellbookEntry};
+use crate::rules::{Class, DerivedStats, PactSlots};
+use crate::Route;
+use super::character_edit::server_error_messages;
+
+const FIELD_CLASS: &str = "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-700 dark:text-white";
+const TAG_CLASS: &str = "px-2 py-0.5 text-xs rounded-full font-medium";
+
+fn pip_class(remaining: bool) -> &'static str {
+    if remaining {
+        "w-5 h-5 rounded-full border-2 border-purple-600 bg-purple-600 hover:bg-purple-400 disabled:cursor-default"
+    } else {
+        "w-5 h-5 rounded-full border-2 border-purple-600 bg-transparent hover:bg-purple-200 disabled:cursor-default"
+    }
+}
+
+// The ways a spell can be cast right now, as (value, label) options for its Cast select
+fn cast_options(entry: &SpellbookEntry, spellbook: &Spellbook, slots: &[i32; 9], pact: Option<PactSlots>) -> Vec<(String, String)> {
+    if entry.is_cantrip() {
+        return vec![("cantrip".to_string(), "Cantrip".to_string())];
+    }
+    let mut options: Vec<(String, String)> = (entry.level..=9)
+        .filter(|level| spellbook.slots_remaining(slots, *level) > 0)
+        .map(|level| (format!("slot-{}", level), format!("{} slot", spell_level_label(level))))
+        .collect();
+    if let Some(pact) = pact.filter(|pact| pact.slot_level >= entry.level && spellbook.pact_expended < pact.count) {
+        options.push(("pact".to_string(), format!("Pact slot ({})", spell_level_label(pact.slot_level))));
+    }
+    if entry.ritual {
+        options.push(("ritual".to_string(), "As a ritual".to_string()));
+    }
+    options
+}
+
+fn cast_with(value: &str) -> Option<CastWith> {
+    match value {
+        "pact" => Some(CastWith::PactSlot),
+        "ritual" => Some(CastWith::Ritual),
+        // Cantrips ignore the slot entirely
+        "cantrip" => Some(CastWith::Slot(0)),
+        _ => value.strip_prefix("slot-").and_then(|level| level.parse().ok()).map(CastWith::Slot),
+    }
+}
+
+/// A character's spellbook: known and prepared spells, spell slots and concentration
+#[component]
+pub fn CharacterSpellbook(slug: String) -> Element {
+    let mut character = use_signal::<Option<CharacterDetail>>(|| None);
+    let mut loading = use_signal(|| true);
+    let mut saving = use_signal(|| false);
+    let mut errors = use_signal::<Vec<String>>(|| vec![]);
+    let mut learn_class = use_signal::<Option<Class>>(|| None);
+
+    use_effect(use_reactive!(|slug| {
+        spawn(async move {
+            loading.set(true);
+            errors.set(vec![]);
+            match get_character_with_slug(slug).await {
+                Ok(fetched) => {
+                    let casters = fetched.classes.iter().find(|class_level| class_level.spellcasting_ability().is_some());
+                    learn_class.set(casters.map(|class_level| class_level.class));
+                    character.set(Some(fetched));
+                }
+                Err(e) => errors.set(server_error_messages(e)),
+            }
+            loading.set(false);
+        });
+    }));
+
+    // Every change is saved straight away so slots spent mid-session survive a reload.
+    // The sheet shows the change at once and goes back if the server refuses it.
+    let mut change = move |update: &dyn Fn(&mut Spellbook) -> Result<(), String>| {
+        if saving() {
+            return;
+        }
+        let Some((id, loaded_at, previous)) = character.read().as_ref().map(|c| (c.id, c.updated_at.clone(), c.spellbook.clone())) else {
+            return;
+        };
+        let mut next = previous.clone();
+        if let Err(error) = update(&mut next) {
+            errors.set(vec![error]);
+            return;
+        }
+        if let Some(character) = character.write().as_mut() {
+            character.spellbook = next.clone();
+        }
+        saving.set(true);
+        errors.set(vec![]);
+        spawn(async move {
+            match update_spellbook(id, next, loaded_at).await {
+                Ok(saved) => character.set(Some(saved)),
+                Err(e) => {
+                    if let Some(character) = character.write().as_mut() {
+                        character.spellbook = previous;
+                    }
+                    errors.set(server_error_messages(e));
+                }
+            }
+            saving.set(false);
+        });
+    };
+
+    let classes = move || character.read().as_ref().map(|c| c.classes.clone()).unwrap_or_default();
+
+    rsx! {
+        div { class: "max-w-6xl mx-auto py-8",
+            if loading() {
+                div { class: "flex justify-center py-12",
+                    div {
+                        class: "animate-spin rounded-full h-12 w-12 border-t-2 border-b-2 border-blue-500",
+                        aria_label: "Loading...",
+                    }
+                }
+            }
+
+            if !errors.read().is_empty() {
+                div {
+                    class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6",
+                    role: "alert",
+                    p { class: "font-bold", "Could not update spellbook" }
+                    ul { class: "list-disc pl-5 mt-2",
+                        for message in errors.read().iter() {
+                            li { "{message}" }
+                        }
+                    }
+                }
+            }
+
+            if let Some(character) = character.read().as_ref() {
+                {
+                    let can_edit = character.access.can_edit();
+                    let stats = DerivedStats::for_character(character);
+                    let book = &character.spellbook;
+                    let casters: Vec<_> = character.classes.iter().filter(|c| c.spellcasting_ability().is_some()).copied().collect();
+                    let known: Vec<String> = book.entries.iter().map(|entry| entry.spell.clone()).collect();
+                    let learn_level = learn_class().and_then(|class| casters.iter().find(|c| c.class == class)).map(spellbook::max_spell_level);
+                    let class_options: Vec<(String, String)> =
+                        casters.iter().map(|c| (c.class.name().to_string(), c.class.name().to_string())).collect();
+
+                    rsx! {
+                        div { class: "flex items-center justify-between mb-8",
+                            div {
+                                Link {
+                                    to: Route::CharacterById { slug: character.slug.clone() },
+                                    class: "text-blue-600 dark:text-blue-400 hover:underline",
+                                    "← Back to {character.name}"
+                                }
+                                h1 { class: "text-3xl font-bold text-gray-900 dark:text-white mt-2", "Spellbook" }
+                            }
+                            if saving() {
+                                span { class: "px-3 py-1 text-sm bg-blue-100 text-blue-700 rounded-full", "Saving..." }
+                            } else if !can_edit {
+                                span { class: "px-3 py-1 text-sm bg-gray-100 text-gray-600 rounded-full", "Read only" }
+                            }
+                        }
+
+                        if let Some(entry) = book.concentrating_on() {
+                            div { class: "flex items-center justify-between bg-purple-100 dark:bg-purple-900 text-purple-900 dark:text-purple-100 rounded-lg px-4 py-3 mb-6",
+                                span {
+                                    "Concentrating on "
+                                    span { class: "font-semibold", "{entry.name}" }
+                                }
+                                if can_edit {
+                                    button {
+                                        r#type: "button",
+                                        onclick: move |_| change(&|book| {
+                                            book.concentration = None;
+                                            Ok(())
+                                        }),
+                                        class: "px-3 py-1 text-sm bg-white dark:bg-purple-800 rounded-lg hover:bg-purple-50",
+                                        "End"
+                                    }
+                                }
+                            }
+                        }
+
+                        div { class: "grid grid-cols-1 lg:grid-cols-3 gap-6 mb-6",
+                            // Casting ability, save DC and preparation per class
+                            div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg",
+                                h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "Spellcasting" }
+                                if casters.is_empty() {
+                                    p { class: "text-gray-500 dark:text-gray-400", "None of this character's classes cast spells." }
+                                }
+                                for class_level in casters.iter() {
+                                    {
+                                        let ability = class_level.spellcasting_ability();
+                                        let casting = stats.spellcasting.iter().find(|s| Some(s.ability) == ability);
+                                        let limit = spellbook::preparation_limit(class_level, |ability| ability.score(character));
+                                        rsx! {
+                                            div { class: "mb-3 last:mb-0",
+                                                p { class: "font-semibold text-gray-900 dark:text-white", "{class_level}" }
+                                                if let Some(casting) = casting {
+                                                    p { class: "text-sm text-gray-600 dark:text-gray-400",
+                                                        "{casting.ability.abbreviation()} • Save DC {casting.save_dc} • Attack +{casting.attack_bonus}"
+                                                    }
+                                                }
+                                                if let Some(limit) = limit {
+                                                    p { class: "text-sm text-gray-600 dark:text-gray-400",
+                                                        "Prepared {book.prepared_count(class_level.class)} / {limit}"
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            // Slots: filled pips are left, hollow ones are spent
+                            div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg",
+                                h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "Spell Slots" }
+                                if stats.spell_slots.iter().all(|count| *count == 0) && stats.pact_slots.is_none() {
+                                    p { class: "text-gray-500 dark:text-gray-400", "No spell slots" }
+                                }
+                                for (index, count) in stats.spell_slots.into_iter().enumerate().filter(|(_, count)| *count > 0) {
+                                    {
+                                        let level = index as i32 + 1;
+                                        let remaining = book.slots_remaining(&stats.spell_slots, level);
+                                        let slots = stats.spell_slots;
+                                        rsx! {
+                                            div { class: "flex items-center gap-3 mb-2",
+                                                span { class: "w-20 text-sm text-gray-700 dark:text-gray-300", "{spell_level_label(level)}" }
+                                                for pip in 0..count {
+                                                    button {
+                                                        r#type: "button",
+                                                        disabled: !can_edit,
+                                                        title: if pip < remaining { "Expend a slot" } else { "Recover a slot" },
+                                                        onclick: move |_| {
+                                                            if pip < remaining {
+                                                                change(&|book| book.expend_slot(&slots, level));
+                                                            } else {
+                                                                change(&|book| {
+                                                                    book.recover_slot(level);
+                                                                    Ok(())
+                                                                });
+                                                            }
+                                                        },
+                                                        class: pip_class(pip < remaining),
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                                if let Some(pact) = stats.pact_slots {
+                                    div { class: "flex items-center gap-3 mt-3",
+                                        span { class: "w-20 text-sm text-gray-700 dark:text-gray-300", "Pact ({spell_level_label(pact.slot_level)})" }
+                                        for pip in 0..pact.count {
+                                            {
+                                                let remaining = pact.count - book.pact_expended;
+                                                rsx! {
+                                                    button {
+                                                        r#type: "button",
+                                                        disabled: !can_edit,
+                                                        title: if pip < remaining { "Expend a pact slot" } else { "Recover a pact slot" },
+                                                        onclick: move |_| {
+                                                            if pip < remaining {
+                                                                change(&|book| book.expend_pact_slot(Some(pact)));
+                                                            } else {
+                                                                change(&|book| {
+                                                                    book.recover_pact_slot();
+                                                                    Ok(())
+                                                                });
+                                                            }
+                                                        },
+                                                        class: pip_class(pip < remaining),
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            if can_edit && !casters.is_empty() {
+                                div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg",
+                                    h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "Learn a Spell" }
+                                    if casters.len() > 1 {
+                                        div { class: "mb-3",
+                                            SelectInput {
+                                                i_value: learn_class().map(|class| class.name().to_string()).unwrap_or_default(),
+                                                on_input: move |e: FormEvent| learn_class.set(Class::from_name(&e.value())),
+                                                class: FIELD_CLASS,
+                                                options: class_options,
+                                            }
+                                        }
+                                    }
+                                    SpellPicker {
+                                        class: learn_class(),
+                                        max_level: learn_level,
+                                        known: known.clone(),
+                                        on_pick: move |spell: Spell| {
+                                            let entry = spell.to_entry(learn_class());
+                                            change(&|book| book.learn(entry.clone()));
+                                        },
+                                    }
+                                }
+                            }
+                        }
+
+                        div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg",
+                            h2 { class: "text-xl font-semibold mb-4 text-gray-900 dark:text-white", "Spells" }
+                            if book.entries.is_empty() {
+                                p { class: "text-gray-500 dark:text-gray-400", "No spells yet." }
+                            }
+                            for level in 0..=9 {
+                                if book.entries.iter().any(|entry| entry.level == level) {
+                                    div { class: "mb-6 last:mb-0",
+                                        h3 { class: "text-sm font-semibold uppercase tracking-wide text-gray-500 dark:text-gray-400 mb-2",
+                                            "{spell_level_label(level)}"
+                                        }
+                                        for entry in book.entries.iter().filter(|entry| entry.level == level) {
+                                            SpellRow {
+                                                key: "{entry.spell}",
+                                                entry: entry.clone(),
+                                                options: cast_options(entry, book, &stats.spell_slots, stats.pact_slots),
+                                                can_edit,
+                                                on_prepare: move |(spell, prepared): (String, bool)| change(&|book| {
+                                                    book.set_prepared(&spell, prepared);
+                                                    Ok(())
+                                                }),
+                                                on_cast: move |(spell, with): (String, CastWith)| {
+                                                    let classes = classes();
+                                                    change(&|book| book.cast(&spell, with, &classes));
+                                                },
+                                                on_forget: move |spell: String| change(&|book| {
+                                                    book.forget(&spell);
+                                                    Ok(())
+                                                }),
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn SpellRow(
+    entry: SpellbookEntry,
+    options: Vec<(String, String)>,
+    can_edit: bool,
+    on_prepare: EventHandler<(String, bool)>,
+    on_cast: EventHandler<(String, CastWith)>,
+    on_forget: EventHandler<String>,
+) -> Element {
+    let mut selected = use_signal(String::new);
+    // Fall back to the first way of casting whenever the chosen one runs out
+    let chosen = options
+        .iter()
+        .find(|(value, _)| *value == selected())
+        .or(options.first())
+        .map(|(value, _)| value.clone());
+    let preparable = entry.class.is_some_and(spellbook::prepares_spells) && !entry.is_cantrip() && !entry.always_prepared;
+    let spell = entry.spell.clone();
+    let prepare_spell = spell.clone();
+    let forget_spell = spell.clone();
+
+    rsx! {
+        div { class: "flex flex-wrap items-center gap-3 py-2 border-t border-gray-100 dark:border-gray-700 first:border-t-0",
+            if preparable {
+                input {
+                    r#type: "checkbox",
+                    checked: entry.prepared,
+                    disabled: !can_edit,
+                    title: "Prepared",
+                    onchange: move |e| on_prepare.call((prepare_spell.clone(), e.checked())),
+                    class: "w-4 h-4",
+                }
+            }
+            Link {
+                to: Route::SpellDetail { slug: spell.clone() },
+                class: "font-medium text-gray-900 dark:text-white hover:underline",
+                "{entry.name}"
+            }
+            if entry.always_prepared {
+                span { class: "{TAG_CLASS} bg-green-100 text-green-800", "Always prepared" }
+            }
+            if entry.ritual {
+                span { class: "{TAG_CLASS} bg-blue-100 text-blue-800", "Ritual" }
+            }
+            if entry.concentration {
+                span { class: "{TAG_CLASS} bg-purple-100 text-purple-800", "Concentration" }
+            }
+            if let Some(class) = entry.class {
+                span { class: "{TAG_CLASS} bg-gray-100 text-gray-700", "{class}" }
+            }
+            if can_edit {
+                div { class: "ml-auto flex items-center gap-2",
+                    if entry.is_ready() && !options.is_empty() {
+                        if options.len() > 1 {
+                            SelectInput {
+                                i_value: chosen.clone().unwrap_or_default(),
+                                on_input: move |e: FormEvent| selected.set(e.value()),
+                                class: "px-2 py-1 text-sm border border-gray-300 dark:border-gray-600 rounded dark:bg-gray-700 dark:text-white",
+                                options: options.clone(),
+                            }
+                        }
+                        button {
+                            r#type: "button",
+                            onclick: move |_| {
+                                if let Some(with) = chosen.as_deref().and_then(cast_with) {
+                                    on_cast.call((spell.clone(), with));
+                                }
+                            },
+                            class: "px-3 py-1 text-sm bg-purple-600 text-white rounded-lg hover:bg-purple-700",
+                            "Cast"
+                        }
+                    } else if !entry.is_ready() {
+                        span { class: "text-sm text-gray-500 dark:text-gray-400", "Not prepared" }
+                    } else {
+                        span { class: "text-sm text-gray-500 dark:text-gray-400", "No slots" }
+                    }
+                    button {
+                        r#type: "button",
+                        onclick: move |_| on_forget.call(forget_spell.clone()),
+                        class: "px-2 py-1 text-sm text-red-600 hover:text-red-800",
+                        title: "Remove from spellbook",
+                        "✕"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Search the spell catalog and pick a spell. `class` limits the search to that class's list,
+/// `max_level` to spells it has slots for, and spells in `known` can't be picked again.
+#[component]
+pub(super) fn SpellPicker(class: Option<Class>, max_level: Option<i32>, known: Vec<String>, on_pick: EventHandler<Spell>) -> Element {
+    let mut query = use_signal(String::new);
+    let results = use_resource(use_reactive!(|class, max_level| {
+        let query = query();
+        async move { search_spells(query, class, max_level, 20).await }
+    }));
+
+    rsx! {
+        div { class: "space-y-2",
+            input {
+                r#type: "search",
+                value: query(),
+                oninput: move |e| query.set(e.value()),
+                class: FIELD_CLASS,
+                placeholder: "Search spells",
+            }
+            match &*results.read() {
+                None => rsx! {
+                    p { class: "text-sm text-gray-500 dark:text-gray-400", "Searching..." }
+                },
+                Some(Err(e)) => rsx! {
+                    p { class: "text-sm text-red-600", "Could not search spells: {e}" }
+                },
+                Some(Ok(spells)) if spells.is_empty() => rsx! {
+                    p { class: "text-sm text-gray-500 dark:text-gray-400", "No matching spells" }
+                },
+                Some(Ok(spells)) => rsx! {
+                    ul { class: "max-h-64 overflow-y-auto divide-y divide-gray-100 dark:divide-gray-700",
+                        for spell in spells.iter().cloned() {
+                            li { key: "{spell.slug}", class: "flex items-center justify-between py-2",
+                                div {
+                                    p { class: "text-sm font-medium text-gray-900 dark:text-white", "{spell.name}" }
+                                    p { class: "text-xs text-gray-500 dark:text-gray-400",
+                                        "{spell.level_label()} {spell.school}"
+                                        if spell.ritual { " • Ritual" }
+                                        if spell.concentration { " • Concentration" }
+                                    }
+                                }
+                                if known.contains(&spell.slug) {
+                                    span { class: "text-xs text-gray-500 dark:text-gray-400", "Known" }
+                                } else {
+                                    button {
+                                        r#type: "button",
+                                        onclick: move |_| on_pick.call(spell.clone()),
+                                        class: "px-3 py-1 text-sm bg-blue-600 text-white rounded-lg hover:bg-blue-700",
+                                        "Add"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
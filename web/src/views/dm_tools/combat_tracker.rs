@@ -0,0 +1,791 @@
+use dioxus::prelude::*;
+use crate::api::campaign::{get_encounter, get_party_vitals, save_encounter, EncounterRecord, PartyVitals, RollVisibility};
+use crate::api::character::apply_play_action;
+use crate::api::monsters::{get_monster, list_monsters, Monster, MonsterFilter};
+use crate::components::{DiceTray, RolledLine, SelectInput};
+use crate::rules::ability_modifier;
+use crate::rules::combat::{Combatant, Encounter, Side, TimedCondition};
+use crate::rules::dice;
+use crate::rules::play::{Condition, PlayAction};
+use crate::api::live::LiveEvent;
+use crate::views::campaigns::{use_live_events, use_roll_log, RollLogPanel};
+use crate::views::references::StatBlock;
+use crate::Route;
+
+const FIELD_CLASS: &str = "w-full p-2 border rounded";
+const SMALL_INPUT_CLASS: &str = "w-16 p-1 border rounded text-center";
+const BUTTON_CLASS: &str = "px-3 py-1 text-sm text-white rounded disabled:opacity-50 disabled:cursor-not-allowed transition-colors";
+
+fn row_class(current: bool, defeated: bool) -> &'static str {
+    match (current, defeated) {
+        (true, _) => "p-4 rounded-lg border-2 border-red-500 bg-red-50",
+        (false, true) => "p-4 rounded-lg border border-gray-200 bg-gray-100 opacity-60",
+        (false, false) => "p-4 rounded-lg border border-gray-200 bg-white",
+    }
+}
+
+fn side_badge(combatant: &Combatant) -> (&'static str, &'static str) {
+    if combatant.is_party() {
+        ("Party", "px-2 py-0.5 text-xs rounded-full bg-blue-100 text-blue-800")
+    } else {
+        ("Monster", "px-2 py-0.5 text-xs rounded-full bg-red-100 text-red-800")
+    }
+}
+
+fn condition_options() -> Vec<(String, String)> {
+    Condition::ALL.iter().map(|condition| (condition.name().to_string(), condition.name().to_string())).collect()
+}
+
+fn condition_label(timed: &TimedCondition) -> String {
+    match timed.rounds {
+        Some(1) => format!("{} (1 round)", timed.condition),
+        Some(rounds) => format!("{} ({} rounds)", timed.condition, rounds),
+        None => timed.condition.to_string(),
+    }
+}
+
+fn roll_die(sides: i32) -> i32 {
+    dice::die(sides, &mut rand::thread_rng())
+}
+
+fn party_combatant(vitals: &PartyVitals) -> Combatant {
+    Combatant {
+        id: 0,
+        name: vitals.name.clone(),
+        side: Side::Party { character_id: vitals.character_id, slug: vitals.slug.clone() },
+        initiative: None,
+        initiative_bonus: vitals.initiative,
+        dexterity: vitals.dexterity,
+        armor_class: vitals.armor_class,
+        hit_points: vitals.hit_points,
+        max_hit_points: vitals.max_hit_points.max(1),
+        temporary_hit_points: vitals.temporary_hit_points,
+        conditions: vitals.conditions.iter().map(|condition| TimedCondition { condition: *condition, rounds: None }).collect(),
+        concentration: vitals.concentration.clone(),
+        legendary_actions: 0,
+        legendary_used: 0,
+    }
+}
+
+// Party hit points live on the character sheets; copy them in, along with any conditions
+// the sheet has that the tracker doesn't
+fn sync_party(encounter: &mut Encounter, party: &[PartyVitals]) {
+    for combatant in encounter.combatants.iter_mut() {
+        let Side::Party { character_id, .. } = &combatant.side else {
+            continue;
+        };
+        let Some(vitals) = party.iter().find(|vitals| vitals.character_id == *character_id) else {
+            continue;
+        };
+        combatant.max_hit_points = vitals.max_hit_points.max(1);
+        combatant.hit_points = vitals.hit_points.min(combatant.max_hit_points);
+        combatant.temporary_hit_points = vitals.temporary_hit_points;
+        combatant.armor_class = vitals.armor_class;
+        combatant.concentration = vitals.concentration.clone();
+        for condition in &vitals.conditions {
+            if !combatant.has(*condition) {
+                combatant.conditions.push(TimedCondition { condition: *condition, rounds: None });
+            }
+        }
+    }
+}
+
+/// State shared by the tracker and its rows. Every change goes through [`Tracker::change`] so
+/// the log and the unsaved marker stay right.
+#[derive(Clone, Copy, PartialEq)]
+struct Tracker {
+    record: Signal<Option<EncounterRecord>>,
+    party: Signal<Vec<PartyVitals>>,
+    dirty: Signal<bool>,
+    log: Signal<Vec<String>>,
+    error: Signal<Option<String>>,
+    /// Slug of the bestiary entry picked to show; otherwise the one whose turn it is
+    viewing: Signal<Option<String>>,
+}
+
+impl Tracker {
+    fn change(mut self, change: impl FnOnce(&mut Encounter) -> Result<Vec<String>, String>) {
+        let result = match self.record.write().as_mut() {
+            Some(record) => {
+                let before = record.state.clone();
+                change(&mut record.state).map(|messages| (messages, record.state != before))
+            }
+            None => return,
+        };
+        match result {
+            Ok((messages, changed)) => {
+                self.error.set(None);
+                if changed {
+                    self.dirty.set(true);
+                }
+                self.note(messages);
+            }
+            Err(message) => self.error.set(Some(message)),
+        }
+    }
+
+    fn note(mut self, messages: Vec<String>) {
+        let mut log = self.log.write();
+        for message in messages {
+            log.insert(0, message);
+        }
+        log.truncate(30);
+    }
+
+    /// Reload the party's hit points and conditions from their sheets
+    fn refresh_party(mut self) {
+        let Some(campaign_id) = self.record.read().as_ref().map(|record| record.campaign_id) else {
+            return;
+        };
+        spawn(async move {
+            match get_party_vitals(campaign_id).await {
+                Ok(fetched) => {
+                    self.change(|encounter| {
+                        sync_party(encounter, &fetched);
+                        Ok(vec![])
+                    });
+                    self.party.set(fetched);
+                }
+                Err(e) => self.error.set(Some(format!("Failed to load the party: {}", e))),
+            }
+        });
+    }
+
+    /// Damage and healing for party members go onto their sheets, then come back from there
+    fn play(mut self, character_id: i32, action: PlayAction) {
+        spawn(async move {
+            match apply_play_action(character_id, action).await {
+                Ok(update) => {
+                    self.note(vec![update.message]);
+                    self.refresh_party();
+                }
+                Err(e) => self.error.set(Some(format!("Failed to update the character: {}", e))),
+            }
+        });
+    }
+}
+
+/// Runs one saved encounter: initiative, turns and rounds, hit points, conditions with
+/// durations, concentration and legendary actions
+#[component]
+pub fn CombatTracker(campaign_slug: String, encounter_id: i32) -> Element {
+    let record = use_signal::<Option<EncounterRecord>>(|| None);
+    let party = use_signal::<Vec<PartyVitals>>(|| vec![]);
+    let mut dirty = use_signal(|| false);
+    let log = use_signal::<Vec<String>>(|| vec![]);
+    let mut error = use_signal::<Option<String>>(|| None);
+    let mut saving = use_signal(|| false);
+    let rolls = use_signal::<Vec<RolledLine>>(|| vec![]);
+    let viewing = use_signal::<Option<String>>(|| None);
+    let tracker = Tracker { record, party, dirty, log, error, viewing };
+    let campaign_id = use_memo(move || record.read().as_ref().map(|r| r.campaign_id));
+    let character_id = use_memo(|| None);
+    let roll_log = use_roll_log(campaign_id, character_id, RollVisibility::Dm);
+
+    // The party's sheets change as players play, and the DM may have the fight open twice
+    use_live_events(campaign_id, move |event| {
+        roll_log.handle(&event);
+        let open = tracker.record.read().as_ref().map(|record| record.id);
+        match event {
+            LiveEvent::Character { character_id }
+                if tracker.party.read().iter().any(|member| member.character_id == character_id) =>
+            {
+                tracker.refresh_party();
+            }
+            LiveEvent::Connected => tracker.refresh_party(),
+            LiveEvent::Encounter(saved) if Some(saved.id) == open && !dirty() => {
+                let mut record = tracker.record;
+                record.set(Some(saved));
+            }
+            LiveEvent::EncounterDeleted { id } if Some(id) == open => {
+                error.set(Some("This encounter was deleted elsewhere".to_string()));
+            }
+            _ => {}
+        }
+    });
+
+    use_effect(use_reactive!(|encounter_id| {
+        let mut record = tracker.record;
+        spawn(async move {
+            match get_encounter(encounter_id).await {
+                Ok(fetched) => {
+                    record.set(Some(fetched));
+                    dirty.set(false);
+                    tracker.refresh_party();
+                }
+                Err(e) => error.set(Some(format!("Failed to load encounter: {}", e))),
+            }
+        });
+    }));
+
+    let save = move |_| {
+        let Some(encounter) = record.read().clone() else {
+            return;
+        };
+        let mut record = record;
+        saving.set(true);
+        spawn(async move {
+            match save_encounter(encounter).await {
+                Ok(saved) => {
+                    record.set(Some(saved));
+                    dirty.set(false);
+                }
+                Err(e) => error.set(Some(format!("Failed to save encounter: {}", e))),
+            }
+            saving.set(false);
+        });
+    };
+
+    let add_party = move |_| {
+        let party = party.read().clone();
+        tracker.change(|encounter| {
+            let mut added = vec![];
+            for vitals in &party {
+                let present = encounter
+                    .combatants
+                    .iter()
+                    .any(|c| matches!(&c.side, Side::Party { character_id, .. } if *character_id == vitals.character_id));
+                if !present {
+                    encounter.add(party_combatant(vitals));
+                    added.push(format!("{} joins the fight", vitals.name));
+                }
+            }
+            Ok(added)
+        });
+    };
+
+    let Some(current) = record.read().clone() else {
+        return rsx! {
+            div { class: "max-w-7xl mx-auto py-6 px-4",
+                if let Some(err) = error.read().as_ref() {
+                    div { class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6", role: "alert",
+                        p { "{err}" }
+                    }
+                } else {
+                    p { class: "text-gray-600", "Loading encounter..." }
+                }
+            }
+        };
+    };
+    let encounter = current.state;
+    let started = encounter.is_started();
+    let stat_block = viewing().or_else(|| match encounter.turn.and_then(|id| encounter.combatant(id)).map(|c| &c.side) {
+        Some(Side::Monster { monster }) => monster.clone(),
+        _ => None,
+    });
+    let order: Vec<Combatant> = encounter.order().into_iter().cloned().collect();
+    let missing_initiative = order.iter().any(|c| c.initiative.is_none());
+
+    rsx! {
+        div { class: "max-w-7xl mx-auto py-6 px-4",
+            div { class: "flex flex-wrap items-center justify-between gap-4 mb-6",
+                div {
+                    Link {
+                        to: Route::Encounters { campaign_slug: campaign_slug.clone() },
+                        class: "text-sm text-blue-600 hover:underline",
+                        "← All encounters"
+                    }
+                    h1 { class: "text-3xl font-bold text-red-900", "{current.name}" }
+                    p { class: "text-gray-600",
+                        if started { "Round {encounter.round}" } else { "Not started" }
+                        " · encounter #{current.id}"
+                    }
+                }
+                div { class: "flex flex-wrap gap-2",
+                    if !started {
+                        button {
+                            disabled: !missing_initiative,
+                            onclick: move |_| tracker.change(|encounter| Ok(encounter.roll_initiative(&mut roll_die))),
+                            class: "{BUTTON_CLASS} bg-gray-700 hover:bg-gray-800",
+                            "Roll Missing Initiative"
+                        }
+                        button {
+                            onclick: move |_| tracker.change(|encounter| encounter.start().map(|message| vec![message])),
+                            class: "{BUTTON_CLASS} bg-red-700 hover:bg-red-800",
+                            "Start Combat"
+                        }
+                    } else {
+                        button {
+                            onclick: move |_| tracker.change(|encounter| {
+                                encounter.previous_turn();
+                                Ok(vec![])
+                            }),
+                            class: "{BUTTON_CLASS} bg-gray-500 hover:bg-gray-600",
+                            "← Previous"
+                        }
+                        button {
+                            onclick: move |_| tracker.change(|encounter| Ok(encounter.next_turn())),
+                            class: "{BUTTON_CLASS} bg-red-700 hover:bg-red-800",
+                            "Next Turn →"
+                        }
+                        button {
+                            onclick: move |_| tracker.change(|encounter| {
+                                encounter.end();
+                                Ok(vec!["Combat ends".to_string()])
+                            }),
+                            class: "{BUTTON_CLASS} bg-gray-700 hover:bg-gray-800",
+                            "End Combat"
+                        }
+                    }
+                    button {
+                        disabled: saving() || !dirty(),
+                        onclick: save,
+                        class: "{BUTTON_CLASS} bg-blue-600 hover:bg-blue-700",
+                        if saving() { "Saving..." } else if dirty() { "Save" } else { "Saved" }
+                    }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6", role: "alert",
+                    p { "{err}" }
+                }
+            }
+
+            div { class: "grid grid-cols-1 lg:grid-cols-3 gap-6",
+                div { class: "lg:col-span-2 space-y-3",
+                    if order.is_empty() {
+                        p { class: "text-gray-600", "Add the party and some monsters to get started." }
+                    }
+                    for combatant in order {
+                        CombatantRow {
+                            key: "{combatant.id}",
+                            tracker,
+                            current: encounter.turn == Some(combatant.id),
+                            combatant,
+                        }
+                    }
+                }
+
+                div { class: "space-y-6",
+                    div { class: "bg-white rounded-lg shadow p-6",
+                        h2 { class: "text-xl font-semibold mb-4 text-gray-800", "Party" }
+                        div { class: "flex gap-2",
+                            button {
+                                disabled: party.read().is_empty(),
+                                onclick: add_party,
+                                class: "{BUTTON_CLASS} bg-blue-600 hover:bg-blue-700",
+                                "Add Party"
+                            }
+                            button {
+                                onclick: move |_| tracker.refresh_party(),
+                                class: "{BUTTON_CLASS} bg-gray-500 hover:bg-gray-600",
+                                "Sync From Sheets"
+                            }
+                        }
+                    }
+                    AddMonsterForm { tracker }
+                    if let Some(slug) = stat_block {
+                        BestiaryPanel {
+                            slug,
+                            tracker,
+                            on_roll: move |line: RolledLine| {
+                                let mut rolls = rolls;
+                                let mut list = rolls.write();
+                                list.insert(0, line.clone());
+                                list.truncate(10);
+                                drop(list);
+                                tracker.note(vec![line.to_string()]);
+                                roll_log.share(line, rolls);
+                            },
+                        }
+                    }
+                    DiceTray {
+                        rolls,
+                        on_roll: move |line: RolledLine| {
+                            tracker.note(vec![line.to_string()]);
+                            roll_log.share(line, rolls);
+                        },
+                        class: "bg-white rounded-lg shadow p-6",
+                    }
+                    div { class: "bg-white rounded-lg shadow p-6",
+                        h2 { class: "text-xl font-semibold mb-4 text-gray-800", "Log" }
+                        ul { class: "text-sm text-gray-600 space-y-1",
+                            for entry in log.read().iter() {
+                                li { "{entry}" }
+                            }
+                        }
+                    }
+                    RollLogPanel { log: roll_log, class: "bg-white rounded-lg shadow p-6" }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn CombatantRow(tracker: Tracker, combatant: Combatant, current: bool) -> Element {
+    let mut amount = use_signal(String::new);
+    let mut condition = use_signal(|| Condition::Prone.name().to_string());
+    let mut rounds = use_signal(String::new);
+    let mut concentration = use_signal(|| combatant.concentration.clone().unwrap_or_default());
+
+    let id = combatant.id;
+    let character_id = match &combatant.side {
+        Side::Party { character_id, .. } => Some(*character_id),
+        Side::Monster { .. } => None,
+    };
+    let (side_label, side_class) = side_badge(&combatant);
+    let health_percent = combatant.hit_points * 100 / combatant.max_hit_points.max(1);
+    let initiative = combatant.initiative.map_or_else(String::new, |value| value.to_string());
+
+    // Damage and healing read the amount box; party members are changed on their sheets
+    let mut hurt = move |damage: bool| {
+        let Ok(value) = amount().trim().parse::<i32>() else {
+            let mut error = tracker.error;
+            error.set(Some("Enter a whole number".to_string()));
+            return;
+        };
+        amount.set(String::new());
+        match (character_id, damage) {
+            (Some(character_id), true) => tracker.play(character_id, PlayAction::Damage(value)),
+            (Some(character_id), false) => tracker.play(character_id, PlayAction::Heal(value)),
+            (None, true) => tracker.change(|encounter| encounter.damage(id, value).map(|message| vec![message])),
+            (None, false) => tracker.change(|encounter| encounter.heal(id, value).map(|message| vec![message])),
+        }
+    };
+
+    rsx! {
+        div { class: row_class(current, combatant.is_defeated()),
+            div { class: "flex flex-wrap items-center gap-3",
+                input {
+                    r#type: "number",
+                    class: SMALL_INPUT_CLASS,
+                    title: "Initiative ({combatant.initiative_bonus:+}, DEX {combatant.dexterity})",
+                    value: "{initiative}",
+                    onchange: move |e| {
+                        let value = e.value().trim().parse::<i32>().ok();
+                        tracker.change(|encounter| encounter.set_initiative(id, value).map(|_| vec![]));
+                    },
+                }
+                div { class: "flex-1",
+                    div { class: "flex items-center gap-2",
+                        if let Side::Party { slug, .. } = &combatant.side {
+                            Link {
+                                to: Route::CharacterById { slug: slug.clone() },
+                                class: "font-semibold text-gray-900 hover:underline",
+                                "{combatant.name}"
+                            }
+                        } else if let Side::Monster { monster: Some(slug) } = &combatant.side {
+                            button {
+                                r#type: "button",
+                                onclick: {
+                                    let slug = slug.clone();
+                                    move |_| {
+                                        let mut viewing = tracker.viewing;
+                                        viewing.set(Some(slug.clone()));
+                                    }
+                                },
+                                class: "font-semibold text-gray-900 hover:underline",
+                                title: "Show stat block",
+                                "{combatant.name}"
+                            }
+                        } else {
+                            span { class: "font-semibold text-gray-900", "{combatant.name}" }
+                        }
+                        span { class: side_class, "{side_label}" }
+                        span { class: "text-sm text-gray-500", "AC {combatant.armor_class}" }
+                    }
+                    div { class: "flex items-center gap-2 mt-1",
+                        div { class: "w-40 h-2 bg-gray-200 rounded-full",
+                            div { class: "h-2 bg-green-500 rounded-full", style: "width: {health_percent}%" }
+                        }
+                        span { class: "text-sm text-gray-700", "{combatant.hit_points} / {combatant.max_hit_points}" }
+                        if combatant.temporary_hit_points > 0 {
+                            span { class: "px-2 py-0.5 text-xs rounded-full bg-blue-100 text-blue-800", "+{combatant.temporary_hit_points} temp" }
+                        }
+                    }
+                }
+                input {
+                    r#type: "number",
+                    min: "1",
+                    class: SMALL_INPUT_CLASS,
+                    placeholder: "HP",
+                    value: "{amount}",
+                    oninput: move |e| amount.set(e.value()),
+                }
+                button { onclick: move |_| hurt(true), class: "{BUTTON_CLASS} bg-red-600 hover:bg-red-700", "Damage" }
+                button { onclick: move |_| hurt(false), class: "{BUTTON_CLASS} bg-green-600 hover:bg-green-700", "Heal" }
+                button {
+                    title: "Remove from the fight",
+                    onclick: move |_| tracker.change(|encounter| {
+                        encounter.remove(id);
+                        Ok(vec![])
+                    }),
+                    class: "text-gray-400 hover:text-red-600",
+                    "×"
+                }
+            }
+
+            div { class: "flex flex-wrap items-center gap-2 mt-3",
+                for timed in combatant.conditions.iter().copied() {
+                    button {
+                        title: "{timed.condition.effect()} Click to remove.",
+                        onclick: move |_| tracker.change(|encounter| encounter.remove_condition(id, timed.condition).map(|_| vec![])),
+                        class: "px-2 py-0.5 text-xs rounded-full border border-red-500 bg-red-100 text-red-800",
+                        "{condition_label(&timed)}"
+                    }
+                }
+                SelectInput {
+                    i_value: condition(),
+                    on_input: move |e: FormEvent| condition.set(e.value()),
+                    class: "p-1 text-sm border rounded",
+                    options: condition_options(),
+                }
+                input {
+                    r#type: "number",
+                    min: "1",
+                    class: SMALL_INPUT_CLASS,
+                    placeholder: "Rounds",
+                    value: "{rounds}",
+                    oninput: move |e| rounds.set(e.value()),
+                }
+                button {
+                    onclick: move |_| {
+                        let Some(chosen) = Condition::ALL.into_iter().find(|c| c.name() == condition()) else {
+                            return;
+                        };
+                        let duration = rounds().trim().parse::<i32>().ok();
+                        tracker.change(|encounter| encounter.add_condition(id, chosen, duration).map(|_| vec![]));
+                        rounds.set(String::new());
+                    },
+                    class: "{BUTTON_CLASS} bg-gray-600 hover:bg-gray-700",
+                    "Add Condition"
+                }
+            }
+
+            div { class: "flex flex-wrap items-center gap-4 mt-3 text-sm",
+                label { class: "flex items-center gap-2",
+                    span { class: "text-gray-600", "Concentrating on" }
+                    input {
+                        class: "p-1 border rounded",
+                        placeholder: "Nothing",
+                        value: "{concentration}",
+                        oninput: move |e| concentration.set(e.value()),
+                        onchange: move |_| {
+                            let spell = Some(concentration().trim().to_string());
+                            tracker.change(|encounter| encounter.set_concentration(id, spell).map(|_| vec![]));
+                        },
+                    }
+                }
+                if !combatant.is_party() {
+                    label { class: "flex items-center gap-2",
+                        span { class: "text-gray-600", "Legendary actions" }
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            class: SMALL_INPUT_CLASS,
+                            value: "{combatant.legendary_actions}",
+                            onchange: move |e| {
+                                let count = e.value().trim().parse::<i32>().unwrap_or(0).max(0);
+                                tracker.change(|encounter| {
+                                    if let Some(combatant) = encounter.combatants.iter_mut().find(|c| c.id == id) {
+                                        combatant.legendary_actions = count;
+                                        combatant.legendary_used = combatant.legendary_used.min(count);
+                                    }
+                                    Ok(vec![])
+                                });
+                            },
+                        }
+                    }
+                    if combatant.legendary_actions > 0 {
+                        span { class: "text-gray-700", "{combatant.legendary_remaining()} left" }
+                        button {
+                            disabled: combatant.legendary_remaining() == 0 || current,
+                            onclick: move |_| tracker.change(|encounter| encounter.use_legendary_action(id).map(|message| vec![message])),
+                            class: "{BUTTON_CLASS} bg-purple-600 hover:bg-purple-700",
+                            "Use"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A bestiary stat block beside the fight, with its attacks rolled into the log
+#[component]
+fn BestiaryPanel(slug: String, tracker: Tracker, on_roll: EventHandler<RolledLine>) -> Element {
+    let monster = use_resource(use_reactive!(|slug| async move { get_monster(slug).await }));
+    let mut viewing = tracker.viewing;
+
+    rsx! {
+        div { class: "space-y-2",
+            div { class: "flex justify-end",
+                if viewing().is_some() {
+                    button {
+                        r#type: "button",
+                        onclick: move |_| viewing.set(None),
+                        class: "text-sm text-gray-600 hover:underline",
+                        "Follow the turn"
+                    }
+                }
+            }
+            match &*monster.read() {
+                None => rsx! {
+                    p { class: "text-sm text-gray-600", "Loading stat block..." }
+                },
+                Some(Err(e)) => rsx! {
+                    p { class: "text-sm text-red-600", "Could not load the stat block: {e}" }
+                },
+                Some(Ok(monster)) => rsx! {
+                    StatBlock { monster: monster.clone(), on_roll }
+                },
+            }
+        }
+    }
+}
+
+/// A monster or NPC from the bestiary or typed in by hand. Several copies get numbered names.
+#[component]
+fn AddMonsterForm(tracker: Tracker) -> Element {
+    let mut search = use_signal(String::new);
+    let mut picked = use_signal::<Option<Monster>>(|| None);
+    let mut roll_hit_points = use_signal(|| false);
+    let mut name = use_signal(String::new);
+    let mut count = use_signal(|| "1".to_string());
+    let mut armor_class = use_signal(|| "12".to_string());
+    let mut hit_points = use_signal(|| "10".to_string());
+    let mut dexterity = use_signal(|| "10".to_string());
+    let mut legendary_actions = use_signal(|| "0".to_string());
+
+    let number = |value: String, default: i32| value.trim().parse::<i32>().unwrap_or(default);
+
+    let found = use_resource(move || {
+        let query = search();
+        async move {
+            if query.trim().is_empty() {
+                return Ok(vec![]);
+            }
+            let filter = MonsterFilter { query, ..Default::default() };
+            list_monsters(filter, 0).await.map(|page| page.monsters.into_iter().take(5).collect::<Vec<_>>())
+        }
+    });
+
+    let mut pick = move |monster: Monster| {
+        name.set(monster.name.clone());
+        armor_class.set(monster.armor_class.to_string());
+        hit_points.set(monster.hit_points.to_string());
+        dexterity.set(monster.dexterity.to_string());
+        legendary_actions.set(monster.legendary_actions_per_round.to_string());
+        search.set(String::new());
+        picked.set(Some(monster));
+    };
+
+    rsx! {
+        form {
+            class: "bg-white rounded-lg shadow p-6 space-y-3",
+            onsubmit: move |e| {
+                e.prevent_default();
+                let base = name().trim().to_string();
+                if base.is_empty() {
+                    return;
+                }
+                let copies = number(count(), 1).clamp(1, 20);
+                // Bestiary monsters keep their slug so the tracker can show their stat block
+                let mut monster = match picked() {
+                    Some(entry) => entry.to_combatant(),
+                    None => Combatant::monster(&base, 10, 1, 10),
+                };
+                monster.armor_class = number(armor_class(), 10);
+                monster.max_hit_points = number(hit_points(), 1).max(1);
+                monster.hit_points = monster.max_hit_points;
+                monster.dexterity = number(dexterity(), 10);
+                monster.initiative_bonus = ability_modifier(monster.dexterity);
+                monster.legendary_actions = number(legendary_actions(), 0).max(0);
+                let hit_dice = picked().filter(|_| roll_hit_points()).map(|entry| entry.hit_dice);
+                tracker.change(|encounter| {
+                    for copy in 1..=copies {
+                        let name = if copies == 1 { base.clone() } else { format!("{} {}", base, copy) };
+                        let mut added = Combatant { name, ..monster.clone() };
+                        if let Some(hit_dice) = &hit_dice {
+                            added.max_hit_points = dice::roll(hit_dice)?.total.max(1);
+                            added.hit_points = added.max_hit_points;
+                        }
+                        encounter.add(added);
+                    }
+                    Ok(vec![format!("{} × {} join the fight", copies, base)])
+                });
+                name.set(String::new());
+                count.set("1".to_string());
+                picked.set(None);
+            },
+            h2 { class: "text-xl font-semibold text-gray-800", "Add Monster" }
+            input {
+                r#type: "search",
+                class: FIELD_CLASS,
+                placeholder: "Search the bestiary",
+                value: "{search}",
+                oninput: move |e| search.set(e.value()),
+            }
+            match &*found.read() {
+                Some(Ok(monsters)) if !monsters.is_empty() => rsx! {
+                    ul { class: "border rounded divide-y",
+                        for monster in monsters.iter().cloned() {
+                            li { key: "{monster.slug}",
+                                button {
+                                    r#type: "button",
+                                    onclick: move |_| pick(monster.clone()),
+                                    class: "w-full text-left px-3 py-1 text-sm hover:bg-gray-100",
+                                    "{monster.name}"
+                                    span { class: "text-gray-500", " · CR {monster.challenge_label()}, AC {monster.armor_class}, HP {monster.hit_points}" }
+                                }
+                            }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    p { class: "text-sm text-red-600", "Could not search the bestiary: {e}" }
+                },
+                _ => rsx! {},
+            }
+            if let Some(entry) = picked.read().as_ref() {
+                div { class: "flex items-center justify-between text-sm text-gray-600",
+                    span { "From the bestiary: {entry.name}" }
+                    button { r#type: "button", onclick: move |_| picked.set(None), class: "text-red-600 hover:underline", "Unlink" }
+                }
+                label { class: "flex items-center gap-2 text-sm text-gray-700",
+                    input {
+                        r#type: "checkbox",
+                        checked: roll_hit_points(),
+                        onchange: move |e| roll_hit_points.set(e.checked()),
+                    }
+                    "Roll hit points ({entry.hit_dice}) for each"
+                }
+            }
+            input {
+                class: FIELD_CLASS,
+                placeholder: "Name",
+                value: "{name}",
+                oninput: move |e| name.set(e.value()),
+            }
+            div { class: "grid grid-cols-2 gap-2",
+                label { class: "text-xs text-gray-500",
+                    "How many"
+                    input { r#type: "number", min: "1", max: "20", class: FIELD_CLASS, value: "{count}", oninput: move |e| count.set(e.value()) }
+                }
+                label { class: "text-xs text-gray-500",
+                    "Armor class"
+                    input { r#type: "number", min: "0", class: FIELD_CLASS, value: "{armor_class}", oninput: move |e| armor_class.set(e.value()) }
+                }
+                label { class: "text-xs text-gray-500",
+                    "Hit points"
+                    input { r#type: "number", min: "1", class: FIELD_CLASS, value: "{hit_points}", oninput: move |e| hit_points.set(e.value()) }
+                }
+                label { class: "text-xs text-gray-500",
+                    "DEX score"
+                    input { r#type: "number", min: "1", max: "30", class: FIELD_CLASS, value: "{dexterity}", oninput: move |e| dexterity.set(e.value()) }
+                }
+                label { class: "text-xs text-gray-500",
+                    "Legendary actions"
+                    input { r#type: "number", min: "0", class: FIELD_CLASS, value: "{legendary_actions}", oninput: move |e| legendary_actions.set(e.value()) }
+                }
+            }
+            button {
+                r#type: "submit",
+                class: "px-4 py-2 bg-red-700 text-white rounded hover:bg-red-800 transition-colors",
+                "Add"
+            }
+        }
+    }
+}
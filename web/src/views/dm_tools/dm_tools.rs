@@ -1,15 +1,25 @@
 use dioxus::prelude::*;
 use rand::Rng;
+use crate::api::campaign::RollVisibility;
+use crate::components::{DiceTray, RolledLine};
+use crate::views::campaigns::{use_active_campaign, use_live_events, use_roll_log, RollLogPanel};
+use crate::Route;
 
 #[component]
-pub fn DMTools() -> Element {
-    let mut dice_roll = use_signal(|| (0, 0));
-    let mut npc_name = use_signal(|| String::new());
+pub fn DMTools(campaign_slug: String) -> Element {
+    let rolls = use_signal::<Vec<RolledLine>>(|| vec![]);
+    let (campaign, _) = use_active_campaign();
+    let campaign_id = use_memo(move || campaign.read().as_ref().map(|c| c.id));
+    let character_id = use_memo(|| None);
+    // Rolls behind the DM screen stay hidden unless the DM chooses otherwise
+    let roll_log = use_roll_log(campaign_id, character_id, RollVisibility::Dm);
+    use_live_events(campaign_id, move |event| roll_log.handle(&event));
+    let npc_name = use_signal(|| String::new());
     let mut encounter_difficulty = use_signal(|| "medium".to_string());
-    let mut generated_encounter = use_signal(|| Vec::new());
-    let mut treasure_roll = use_signal(|| Vec::new());
+    let generated_encounter = use_signal(|| Vec::new());
+    let treasure_roll = use_signal(|| Vec::new());
     let mut weather_conditions = use_signal(|| String::new());
-    let mut random_event = use_signal(|| String::new());
+    let random_event = use_signal(|| String::new());
 
     // Sample data
     let name_generator = use_signal(|| vec![
@@ -52,15 +62,6 @@ pub fn DMTools() -> Element {
         "Find hidden treasure",
     ];
 
-    let mut roll_dice = {
-        let mut dice_roll = dice_roll.clone();
-        move |sides: u32| {
-            let mut rng = rand::thread_rng();
-            let roll = rng.gen_range(1..=sides);
-            dice_roll.set((sides, roll));
-        }
-    };
-
     let mut generate_npc = {
         let mut npc_name = npc_name.clone();
         let name_generator = name_generator.clone();
@@ -124,134 +125,144 @@ pub fn DMTools() -> Element {
     };
 
     rsx! {
-        // NPC Generator
-        div { class: "bg-white rounded-lg shadow p-6",
-            h2 { class: "text-xl font-semibold mb-4 text-gray-800 border-b pb-2", "NPC Generator" }
-            div { class: "grid grid-cols-3 gap-3 mb-4",
-                for (race , _) in name_generator.read().iter() {
-                    button {
-                        class: "p-3 bg-green-100 text-green-800 rounded hover:bg-green-200 transition-colors",
-                        onclick: {
-                            let race = race.to_string();
-                            move |_| generate_npc(&race)
-                        },
-                        "{race}"
+        div { class: "max-w-7xl mx-auto py-6 px-4",
+            h1 { class: "text-3xl font-bold text-gray-900 mb-6", "DM Tools" }
+            div { class: "grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-6",
+                DiceTray { rolls, on_roll: move |line: RolledLine| roll_log.share(line, rolls) }
+                RollLogPanel { log: roll_log }
+
+                // NPC Generator
+                div { class: "bg-white rounded-lg shadow p-6",
+                    h2 { class: "text-xl font-semibold mb-4 text-gray-800 border-b pb-2", "NPC Generator" }
+                    div { class: "grid grid-cols-3 gap-3 mb-4",
+                        for (race , _) in name_generator.read().iter() {
+                            button {
+                                class: "p-3 bg-green-100 text-green-800 rounded hover:bg-green-200 transition-colors",
+                                onclick: {
+                                    let race = race.to_string();
+                                    move |_| generate_npc(&race)
+                                },
+                                "{race}"
+                            }
+                        }
+                    }
+                    if !npc_name.read().is_empty() {
+                        div { class: "mt-4 p-3 bg-green-50 rounded border border-green-200",
+                            p { class: "text-xl font-medium text-center", "{npc_name.read()}" }
+                        }
                     }
                 }
-            }
-            if !npc_name.read().is_empty() {
-                div { class: "mt-4 p-3 bg-green-50 rounded border border-green-200",
-                    p { class: "text-xl font-medium text-center", "{npc_name.read()}" }
-                }
-            }
-        }
 
-        // Encounter Builder
-        div { class: "bg-white rounded-lg shadow p-6",
-            h2 { class: "text-xl font-semibold mb-4 text-gray-800 border-b pb-2", "Encounter Builder" }
-            select {
-                class: "mb-4",
-                value: "{encounter_difficulty.read()}",
-                onchange: move |e| encounter_difficulty.set(e.value().to_string()),
-                option { value: "easy", "Easy" }
-                option {
-                    value: "medium",
-                    selected: encounter_difficulty.read().as_str() == "medium",
-                    "Medium"
-                }
-                option { value: "hard", "Hard" }
-            }
-            button {
-                class: "w-full p-3 bg-red-100 text-red-800 rounded hover:bg-red-200 transition-colors mb-4",
-                onclick: generate_encounter,
-                "Generate Encounter"
-            }
-            if !generated_encounter.read().is_empty() {
-                div {
-                    for encounter in generated_encounter.read().iter() {
-                        div { class: "p-2 bg-red-50 rounded border border-red-200",
-                            "{encounter}"
+                // Encounter Builder
+                div { class: "bg-white rounded-lg shadow p-6",
+                    h2 { class: "text-xl font-semibold mb-4 text-gray-800 border-b pb-2", "Encounter Builder" }
+                    select {
+                        class: "mb-4",
+                        value: "{encounter_difficulty.read()}",
+                        onchange: move |e| encounter_difficulty.set(e.value().to_string()),
+                        option { value: "easy", "Easy" }
+                        option {
+                            value: "medium",
+                            selected: encounter_difficulty.read().as_str() == "medium",
+                            "Medium"
+                        }
+                        option { value: "hard", "Hard" }
+                    }
+                    button {
+                        class: "w-full p-3 bg-red-100 text-red-800 rounded hover:bg-red-200 transition-colors mb-4",
+                        onclick: generate_encounter,
+                        "Generate Encounter"
+                    }
+                    if !generated_encounter.read().is_empty() {
+                        div {
+                            for encounter in generated_encounter.read().iter() {
+                                div { class: "p-2 bg-red-50 rounded border border-red-200",
+                                    "{encounter}"
+                                }
+                            }
                         }
                     }
                 }
-            }
-        }
 
-        // Treasure Generator
-        div { class: "bg-white rounded-lg shadow p-6",
-            h2 { class: "text-xl font-semibold mb-4 text-gray-800 border-b pb-2", "Treasure Generator" }
-            div { class: "grid grid-cols-3 gap-3 mb-4",
-                button {
-                    class: "p-3 bg-yellow-100 text-yellow-800 rounded hover:bg-yellow-200 transition-colors",
-                    onclick: move |_| generate_treasure("common"),
-                    "Common"
-                }
-                button {
-                    class: "p-3 bg-yellow-100 text-yellow-800 rounded hover:bg-yellow-200 transition-colors",
-                    onclick: move |_| generate_treasure("uncommon"),
-                    "Uncommon"
-                }
-                button {
-                    class: "p-3 bg-yellow-100 text-yellow-800 rounded hover:bg-yellow-200 transition-colors",
-                    onclick: move |_| generate_treasure("rare"),
-                    "Rare"
-                }
-            }
-            if !treasure_roll.read().is_empty() {
-                div { class: "mt-2 space-y-2",
-                    for item in treasure_roll.read().iter() {
-                        div { class: "p-2 bg-yellow-50 rounded border border-yellow-200",
-                            "{item}"
+                // Treasure Generator
+                div { class: "bg-white rounded-lg shadow p-6",
+                    h2 { class: "text-xl font-semibold mb-4 text-gray-800 border-b pb-2", "Treasure Generator" }
+                    div { class: "grid grid-cols-3 gap-3 mb-4",
+                        button {
+                            class: "p-3 bg-yellow-100 text-yellow-800 rounded hover:bg-yellow-200 transition-colors",
+                            onclick: move |_| generate_treasure("common"),
+                            "Common"
+                        }
+                        button {
+                            class: "p-3 bg-yellow-100 text-yellow-800 rounded hover:bg-yellow-200 transition-colors",
+                            onclick: move |_| generate_treasure("uncommon"),
+                            "Uncommon"
+                        }
+                        button {
+                            class: "p-3 bg-yellow-100 text-yellow-800 rounded hover:bg-yellow-200 transition-colors",
+                            onclick: move |_| generate_treasure("rare"),
+                            "Rare"
+                        }
+                    }
+                    if !treasure_roll.read().is_empty() {
+                        div { class: "mt-2 space-y-2",
+                            for item in treasure_roll.read().iter() {
+                                div { class: "p-2 bg-yellow-50 rounded border border-yellow-200",
+                                    "{item}"
+                                }
+                            }
                         }
                     }
                 }
-            }
-        }
 
-        // Weather Generator
-        div { class: "bg-white rounded-lg shadow p-6",
-            h2 { class: "text-xl font-semibold mb-4 text-gray-800 border-b pb-2", "Weather Generator" }
-            button {
-                class: "w-full p-3 bg-blue-100 text-blue-800 rounded hover:bg-blue-200 transition-colors mb-4",
-                onclick: generate_weather,
-                "Generate Weather"
-            }
-            if !weather_conditions.read().is_empty() {
-                div { class: "p-3 bg-blue-50 rounded border border-blue-200 text-center",
-                    p { class: "text-lg", "{weather_conditions.read()}" }
+                // Weather Generator
+                div { class: "bg-white rounded-lg shadow p-6",
+                    h2 { class: "text-xl font-semibold mb-4 text-gray-800 border-b pb-2", "Weather Generator" }
+                    button {
+                        class: "w-full p-3 bg-blue-100 text-blue-800 rounded hover:bg-blue-200 transition-colors mb-4",
+                        onclick: generate_weather,
+                        "Generate Weather"
+                    }
+                    if !weather_conditions.read().is_empty() {
+                        div { class: "p-3 bg-blue-50 rounded border border-blue-200 text-center",
+                            p { class: "text-lg", "{weather_conditions.read()}" }
+                        }
+                    }
                 }
-            }
-        }
 
-        // Random Events
-        div { class: "bg-white rounded-lg shadow p-6",
-            h2 { class: "text-xl font-semibold mb-4 text-gray-800 border-b pb-2", "Random Events" }
-            button {
-                class: "w-full p-3 bg-purple-100 text-purple-800 rounded hover:bg-purple-200 transition-colors mb-4",
-                onclick: generate_event,
-                "Generate Event"
-            }
-            if !random_event.read().is_empty() {
-                div { class: "p-3 bg-purple-50 rounded border border-purple-200",
-                    p { class: "text-lg", "{random_event.read()}" }
+                // Random Events
+                div { class: "bg-white rounded-lg shadow p-6",
+                    h2 { class: "text-xl font-semibold mb-4 text-gray-800 border-b pb-2", "Random Events" }
+                    button {
+                        class: "w-full p-3 bg-purple-100 text-purple-800 rounded hover:bg-purple-200 transition-colors mb-4",
+                        onclick: generate_event,
+                        "Generate Event"
+                    }
+                    if !random_event.read().is_empty() {
+                        div { class: "p-3 bg-purple-50 rounded border border-purple-200",
+                            p { class: "text-lg", "{random_event.read()}" }
+                        }
+                    }
                 }
             }
-        }
 
-        // Advanced Tools Section
-        div { class: "mt-12 bg-gray-50 rounded-lg shadow p-6",
-            h2 { class: "text-2xl font-semibold mb-4 text-gray-800 border-b pb-2",
-                "Advanced Tools"
-            }
-            div { class: "grid grid-cols-1 md:grid-cols-3 gap-4",
-                button { class: "p-4 bg-white border border-gray-200 rounded-lg hover:bg-gray-100 transition-colors",
-                    "Initiative Tracker"
-                }
-                button { class: "p-4 bg-white border border-gray-200 rounded-lg hover:bg-gray-100 transition-colors",
-                    "Loot Distributor"
+            // Advanced Tools Section
+            div { class: "mt-12 bg-gray-50 rounded-lg shadow p-6",
+                h2 { class: "text-2xl font-semibold mb-4 text-gray-800 border-b pb-2",
+                    "Advanced Tools"
                 }
-                button { class: "p-4 bg-white border border-gray-200 rounded-lg hover:bg-gray-100 transition-colors",
-                    "XP Calculator"
+                div { class: "grid grid-cols-1 md:grid-cols-3 gap-4",
+                    Link {
+                        to: Route::Encounters { campaign_slug: campaign_slug.clone() },
+                        class: "p-4 bg-white border border-gray-200 rounded-lg hover:bg-gray-100 transition-colors text-center",
+                        "Initiative Tracker"
+                    }
+                    button { class: "p-4 bg-white border border-gray-200 rounded-lg hover:bg-gray-100 transition-colors",
+                        "Loot Distributor"
+                    }
+                    button { class: "p-4 bg-white border border-gray-200 rounded-lg hover:bg-gray-100 transition-colors",
+                        "XP Calculator"
+                    }
                 }
             }
         }
@@ -0,0 +1,146 @@
+use dioxus::prelude::*;
+use crate::api::campaign::{delete_encounter, get_encounters, save_encounter, EncounterRecord};
+use crate::api::live::LiveEvent;
+use crate::rules::combat::Encounter;
+use crate::views::campaigns::{upsert, use_active_campaign, use_live_events};
+use crate::Route;
+
+fn encounter_status(encounter: &Encounter) -> String {
+    if encounter.is_started() {
+        format!("Round {}", encounter.round)
+    } else {
+        "Not started".to_string()
+    }
+}
+
+/// The DM's saved encounters for a campaign: start a new one or resume one by id
+#[component]
+pub fn Encounters(campaign_slug: String) -> Element {
+    let navigator = use_navigator();
+    let (campaign, campaign_error) = use_active_campaign();
+    let mut encounters = use_signal::<Vec<EncounterRecord>>(|| vec![]);
+    let mut loading = use_signal(|| true);
+    let mut error = use_signal::<Option<String>>(|| None);
+    let mut new_name = use_signal(String::new);
+
+    use_effect(move || {
+        let Some(campaign_id) = campaign.read().as_ref().map(|c| c.id) else {
+            return;
+        };
+        spawn(async move {
+            match get_encounters(campaign_id).await {
+                Ok(fetched) => encounters.set(fetched),
+                Err(e) => error.set(Some(format!("Failed to load encounters: {}", e))),
+            }
+            loading.set(false);
+        });
+    });
+
+    let campaign_id = use_memo(move || campaign.read().as_ref().map(|c| c.id));
+    use_live_events(campaign_id, move |event| match event {
+        LiveEvent::Encounter(saved) => {
+            let mut encounters = encounters.write();
+            upsert(&mut encounters, saved, |e| e.id);
+            encounters.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+        }
+        LiveEvent::EncounterDeleted { id } => encounters.write().retain(|e| e.id != id),
+        _ => {}
+    });
+
+    let create_encounter = {
+        let campaign_slug = campaign_slug.clone();
+        move |evt: FormEvent| {
+            evt.prevent_default();
+            let Some(campaign_id) = campaign.read().as_ref().map(|c| c.id) else {
+                return;
+            };
+            let name = new_name.read().trim().to_string();
+            if name.is_empty() {
+                error.set(Some("Encounter name is required".to_string()));
+                return;
+            }
+            let encounter = EncounterRecord {
+                id: 0,
+                campaign_id,
+                name,
+                state: Encounter::default(),
+                created_at: None,
+                updated_at: None,
+            };
+            let campaign_slug = campaign_slug.clone();
+            spawn(async move {
+                match save_encounter(encounter).await {
+                    Ok(saved) => {
+                        navigator.push(Route::CombatTracker { campaign_slug, encounter_id: saved.id });
+                    }
+                    Err(e) => error.set(Some(format!("Failed to create encounter: {}", e))),
+                }
+            });
+        }
+    };
+
+    let remove_encounter = move |id: i32| {
+        spawn(async move {
+            match delete_encounter(id).await {
+                Ok(()) => encounters.write().retain(|e| e.id != id),
+                Err(e) => error.set(Some(format!("Failed to delete encounter: {}", e))),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "max-w-5xl mx-auto py-6 px-4",
+            h1 { class: "text-3xl font-bold text-red-900 mb-6", "Encounters" }
+
+            if let Some(err) = campaign_error.read().as_ref().or(error.read().as_ref()) {
+                div {
+                    class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6",
+                    role: "alert",
+                    p { "{err}" }
+                }
+            }
+
+            form { class: "bg-white rounded-lg shadow p-6 mb-8 flex gap-4", onsubmit: create_encounter,
+                input {
+                    class: "flex-1 p-2 border rounded",
+                    placeholder: "Encounter name, e.g. Goblin ambush",
+                    value: "{new_name}",
+                    oninput: move |e| new_name.set(e.value()),
+                }
+                button {
+                    r#type: "submit",
+                    class: "px-4 py-2 bg-red-700 text-white rounded hover:bg-red-800 transition-colors",
+                    "New Encounter"
+                }
+            }
+
+            if loading() {
+                p { class: "text-gray-600", "Loading encounters..." }
+            } else if encounters.read().is_empty() {
+                p { class: "text-gray-600", "No encounters yet. Create one above to start tracking a fight." }
+            } else {
+                ul { class: "divide-y bg-white rounded-lg shadow",
+                    for encounter in encounters.read().iter().cloned() {
+                        li { key: "{encounter.id}", class: "p-4 flex items-center justify-between",
+                            div {
+                                Link {
+                                    to: Route::CombatTracker { campaign_slug: campaign_slug.clone(), encounter_id: encounter.id },
+                                    class: "text-lg font-semibold text-red-800 hover:underline",
+                                    "{encounter.name}"
+                                }
+                                p { class: "text-sm text-gray-500",
+                                    "#{encounter.id} · {encounter_status(&encounter.state)} · {encounter.state.combatants.len()} combatants"
+                                }
+                            }
+                            button {
+                                class: "px-3 py-1 text-sm bg-gray-100 text-gray-700 rounded hover:bg-gray-200",
+                                onclick: move |_| remove_encounter(encounter.id),
+                                "Delete"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
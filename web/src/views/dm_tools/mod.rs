@@ -0,0 +1,8 @@
+mod dm_tools;
+pub use dm_tools::DMTools;
+
+mod encounters;
+pub use encounters::Encounters;
+
+mod combat_tracker;
+pub use combat_tracker::CombatTracker;
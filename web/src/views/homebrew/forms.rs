@@ -0,0 +1,560 @@
+use dioxus::prelude::*;
+use crate::api::feats::Feat;
+use crate::api::homebrew::HomebrewEntry;
+use crate::api::items::CatalogItem;
+use crate::api::monsters::{challenge_options, Bonus, Monster, MonsterAction};
+use crate::api::spells::{spell_level_label, Spell};
+use crate::components::SelectInput;
+use crate::rules::{Ability, Class, CreatureType, Environment, ItemCategory, MagicSchool, Rarity, Size};
+
+const FIELD_CLASS: &str = "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent dark:bg-gray-700 dark:text-white";
+
+fn toggle<T: PartialEq>(list: &mut Vec<T>, value: T, on: bool) {
+    list.retain(|entry| *entry != value);
+    if on {
+        list.push(value);
+    }
+}
+
+// "Dex +5, Wis +3", as saving throws and skills are written in a stat block
+fn bonus_text(bonuses: &[Bonus]) -> String {
+    bonuses.iter().map(|bonus| format!("{} {:+}", bonus.name, bonus.bonus)).collect::<Vec<_>>().join(", ")
+}
+
+// Read back what `bonus_text` writes; parts without a number after the name are left out
+fn parse_bonuses(text: &str) -> Vec<Bonus> {
+    text.split(',')
+        .filter_map(|part| {
+            let (name, bonus) = part.trim().rsplit_once(' ')?;
+            Some(Bonus { name: name.trim().to_string(), bonus: bonus.trim().parse().ok()? })
+        })
+        .collect()
+}
+
+/// Edit a homebrew entry, or fill in a new one when its slug is empty.
+/// Key it by the entry so switching entries starts a fresh draft.
+#[component]
+pub(super) fn EntryForm(entry: HomebrewEntry, saving: bool, on_save: EventHandler<HomebrewEntry>, on_cancel: EventHandler<()>) -> Element {
+    let title = if entry.slug().is_empty() {
+        format!("New {}", entry.kind().name().to_lowercase())
+    } else {
+        format!("Edit {}", entry.name())
+    };
+    match entry {
+        HomebrewEntry::Spell(spell) => rsx! { SpellForm { spell, title, saving, on_save, on_cancel } },
+        HomebrewEntry::Monster(monster) => rsx! { MonsterForm { monster: *monster, title, saving, on_save, on_cancel } },
+        HomebrewEntry::Item(item) => rsx! { ItemForm { item, title, saving, on_save, on_cancel } },
+        HomebrewEntry::Feat(feat) => rsx! { FeatForm { feat, title, saving, on_save, on_cancel } },
+    }
+}
+
+#[component]
+fn FormFrame(title: String, saving: bool, on_submit: EventHandler<()>, on_cancel: EventHandler<()>, children: Element) -> Element {
+    rsx! {
+        form {
+            class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg space-y-4",
+            onsubmit: move |e| {
+                e.prevent_default();
+                on_submit.call(());
+            },
+            h2 { class: "text-xl font-semibold text-gray-900 dark:text-white", "{title}" }
+            {children}
+            div { class: "flex justify-end gap-2",
+                button {
+                    r#type: "button",
+                    onclick: move |_| on_cancel.call(()),
+                    class: "px-4 py-2 text-gray-700 dark:text-gray-300 border border-gray-300 dark:border-gray-600 rounded-lg hover:bg-gray-100 dark:hover:bg-gray-700",
+                    "Cancel"
+                }
+                button {
+                    r#type: "submit",
+                    disabled: saving,
+                    class: "px-4 py-2 bg-blue-600 text-white rounded-lg hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed",
+                    if saving { "Saving..." } else { "Save" }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn Field(label: String, class: Option<String>, children: Element) -> Element {
+    rsx! {
+        div { class: class.unwrap_or_default(),
+            label { class: "block text-xs text-gray-500 dark:text-gray-400 mb-1", "{label}" }
+            {children}
+        }
+    }
+}
+
+#[component]
+fn Check(label: String, checked: bool, on_change: EventHandler<bool>) -> Element {
+    rsx! {
+        label { class: "flex items-center gap-2 text-sm text-gray-700 dark:text-gray-300",
+            input { r#type: "checkbox", checked, onchange: move |e| on_change.call(e.checked()) }
+            "{label}"
+        }
+    }
+}
+
+#[component]
+fn SpellForm(spell: Spell, title: String, saving: bool, on_save: EventHandler<HomebrewEntry>, on_cancel: EventHandler<()>) -> Element {
+    let mut draft = use_signal(|| spell);
+    let level_options: Vec<(String, String)> = (0..=9).map(|level| (level.to_string(), spell_level_label(level))).collect();
+    let higher_label = if draft.read().level == 0 { "Cantrip upgrade" } else { "At higher levels" };
+
+    rsx! {
+        FormFrame { title, saving, on_submit: move |_| on_save.call(HomebrewEntry::Spell(draft())), on_cancel,
+            div { class: "grid grid-cols-1 md:grid-cols-3 gap-3",
+                Field { label: "Name", class: "md:col-span-3",
+                    input { value: draft.read().name.clone(), oninput: move |e| draft.write().name = e.value(), class: FIELD_CLASS }
+                }
+                Field { label: "Level",
+                    SelectInput {
+                        i_value: draft.read().level.to_string(),
+                        on_input: move |e: FormEvent| {
+                            if let Ok(level) = e.value().parse() {
+                                draft.write().level = level;
+                            }
+                        },
+                        class: FIELD_CLASS,
+                        options: level_options,
+                    }
+                }
+                Field { label: "School",
+                    SelectInput {
+                        i_value: draft.read().school.to_string(),
+                        on_input: move |e: FormEvent| {
+                            if let Some(school) = MagicSchool::from_name(&e.value()) {
+                                draft.write().school = school;
+                            }
+                        },
+                        class: FIELD_CLASS,
+                        options: MagicSchool::options(),
+                    }
+                }
+                Field { label: "Casting time",
+                    input { value: draft.read().casting_time.clone(), oninput: move |e| draft.write().casting_time = e.value(), class: FIELD_CLASS }
+                }
+                Field { label: "Range",
+                    input { value: draft.read().range.clone(), oninput: move |e| draft.write().range = e.value(), class: FIELD_CLASS, placeholder: "60 feet" }
+                }
+                Field { label: "Duration",
+                    input { value: draft.read().duration.clone(), oninput: move |e| draft.write().duration = e.value(), class: FIELD_CLASS }
+                }
+                Field { label: "Material component",
+                    input {
+                        value: draft.read().components.material.clone().unwrap_or_default(),
+                        oninput: move |e| draft.write().components.material = Some(e.value()).filter(|material| !material.trim().is_empty()),
+                        class: FIELD_CLASS,
+                        placeholder: "None",
+                    }
+                }
+            }
+            div { class: "flex flex-wrap gap-4",
+                Check { label: "Verbal", checked: draft.read().components.verbal, on_change: move |on| draft.write().components.verbal = on }
+                Check { label: "Somatic", checked: draft.read().components.somatic, on_change: move |on| draft.write().components.somatic = on }
+                Check { label: "Concentration", checked: draft.read().concentration, on_change: move |on| draft.write().concentration = on }
+                Check { label: "Ritual", checked: draft.read().ritual, on_change: move |on| draft.write().ritual = on }
+            }
+            Field { label: "Class lists",
+                div { class: "flex flex-wrap gap-4",
+                    for class in Class::ALL.iter().copied() {
+                        Check {
+                            key: "{class}",
+                            label: class.to_string(),
+                            checked: draft.read().classes.contains(&class),
+                            on_change: move |on| toggle(&mut draft.write().classes, class, on),
+                        }
+                    }
+                }
+            }
+            Field { label: "Description",
+                textarea { rows: "6", value: draft.read().description.clone(), oninput: move |e| draft.write().description = e.value(), class: FIELD_CLASS }
+            }
+            Field { label: higher_label,
+                textarea { rows: "2", value: draft.read().higher_levels.clone(), oninput: move |e| draft.write().higher_levels = e.value(), class: FIELD_CLASS }
+            }
+        }
+    }
+}
+
+// Traits, actions, reactions or legendary actions, one row each
+#[component]
+fn ActionsField(label: String, actions: Signal<Vec<MonsterAction>>) -> Element {
+    rsx! {
+        Field { label,
+            div { class: "space-y-2",
+                for (index, action) in actions.read().iter().cloned().enumerate() {
+                    div { key: "{index}", class: "grid grid-cols-2 md:grid-cols-6 gap-2 p-2 border border-gray-200 dark:border-gray-700 rounded-lg",
+                        input {
+                            value: action.name,
+                            oninput: move |e| actions.write()[index].name = e.value(),
+                            class: "{FIELD_CLASS} md:col-span-2",
+                            placeholder: "Name",
+                        }
+                        input {
+                            r#type: "number",
+                            value: action.attack_bonus.map(|bonus| bonus.to_string()).unwrap_or_default(),
+                            oninput: move |e| actions.write()[index].attack_bonus = e.value().trim().parse().ok(),
+                            class: FIELD_CLASS,
+                            placeholder: "To hit",
+                        }
+                        input {
+                            value: action.damage.unwrap_or_default(),
+                            oninput: move |e| actions.write()[index].damage = Some(e.value()).filter(|damage| !damage.trim().is_empty()),
+                            class: FIELD_CLASS,
+                            placeholder: "Damage, e.g. 1d6+2",
+                        }
+                        button {
+                            r#type: "button",
+                            onclick: move |_| {
+                                actions.write().remove(index);
+                            },
+                            class: "md:col-span-2 px-3 py-2 text-sm text-red-700 bg-red-50 rounded-lg hover:bg-red-100",
+                            "Remove"
+                        }
+                        textarea {
+                            rows: "2",
+                            value: action.description,
+                            oninput: move |e| actions.write()[index].description = e.value(),
+                            class: "{FIELD_CLASS} col-span-2 md:col-span-6",
+                            placeholder: "Description",
+                        }
+                    }
+                }
+                button {
+                    r#type: "button",
+                    onclick: move |_| {
+                        actions.write().push(MonsterAction {
+                            name: String::new(),
+                            description: String::new(),
+                            attack_bonus: None,
+                            damage: None,
+                        })
+                    },
+                    class: "px-3 py-1 text-sm text-blue-700 bg-blue-50 rounded-lg hover:bg-blue-100",
+                    "+ Add"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn MonsterForm(monster: Monster, title: String, saving: bool, on_save: EventHandler<HomebrewEntry>, on_cancel: EventHandler<()>) -> Element {
+    let mut saving_throws = use_signal(|| bonus_text(&monster.saving_throws));
+    let mut skills = use_signal(|| bonus_text(&monster.skills));
+    let traits = use_signal(|| monster.traits.clone());
+    let actions = use_signal(|| monster.actions.clone());
+    let reactions = use_signal(|| monster.reactions.clone());
+    let legendary_actions = use_signal(|| monster.legendary_actions.clone());
+    let mut draft = use_signal(|| monster);
+
+    let submit = move |_| {
+        let mut monster = draft();
+        monster.saving_throws = parse_bonuses(&saving_throws());
+        monster.skills = parse_bonuses(&skills());
+        monster.traits = traits();
+        monster.actions = actions();
+        monster.reactions = reactions();
+        monster.legendary_actions = legendary_actions();
+        on_save.call(HomebrewEntry::Monster(Box::new(monster)));
+    };
+
+    rsx! {
+        FormFrame { title, saving, on_submit: submit, on_cancel,
+            div { class: "grid grid-cols-2 md:grid-cols-4 gap-3",
+                Field { label: "Name", class: "col-span-2",
+                    input { value: draft.read().name.clone(), oninput: move |e| draft.write().name = e.value(), class: FIELD_CLASS }
+                }
+                Field { label: "Size",
+                    SelectInput {
+                        i_value: draft.read().size.to_string(),
+                        on_input: move |e: FormEvent| {
+                            if let Some(size) = Size::from_name(&e.value()) {
+                                draft.write().size = size;
+                            }
+                        },
+                        class: FIELD_CLASS,
+                        options: Size::options(),
+                    }
+                }
+                Field { label: "Type",
+                    SelectInput {
+                        i_value: draft.read().monster_type.to_string(),
+                        on_input: move |e: FormEvent| {
+                            if let Some(kind) = CreatureType::from_name(&e.value()) {
+                                draft.write().monster_type = kind;
+                            }
+                        },
+                        class: FIELD_CLASS,
+                        options: CreatureType::options(),
+                    }
+                }
+                Field { label: "Subtype",
+                    input { value: draft.read().subtype.clone(), oninput: move |e| draft.write().subtype = e.value(), class: FIELD_CLASS }
+                }
+                Field { label: "Alignment",
+                    input { value: draft.read().alignment.clone(), oninput: move |e| draft.write().alignment = e.value(), class: FIELD_CLASS }
+                }
+                Field { label: "Challenge",
+                    SelectInput {
+                        i_value: draft.read().challenge_rating.to_string(),
+                        on_input: move |e: FormEvent| {
+                            if let Ok(challenge) = e.value().parse() {
+                                draft.write().challenge_rating = challenge;
+                            }
+                        },
+                        class: FIELD_CLASS,
+                        options: challenge_options(),
+                    }
+                }
+                Field { label: "Armor class",
+                    input {
+                        r#type: "number",
+                        value: draft.read().armor_class.to_string(),
+                        oninput: move |e| {
+                            if let Ok(armor_class) = e.value().parse() {
+                                draft.write().armor_class = armor_class;
+                            }
+                        },
+                        class: FIELD_CLASS,
+                    }
+                }
+                Field { label: "Armor from",
+                    input { value: draft.read().armor_desc.clone(), oninput: move |e| draft.write().armor_desc = e.value(), class: FIELD_CLASS, placeholder: "natural armor" }
+                }
+                Field { label: "Hit points",
+                    input {
+                        r#type: "number",
+                        min: "1",
+                        value: draft.read().hit_points.to_string(),
+                        oninput: move |e| {
+                            if let Ok(hit_points) = e.value().parse() {
+                                draft.write().hit_points = hit_points;
+                            }
+                        },
+                        class: FIELD_CLASS,
+                    }
+                }
+                Field { label: "Hit dice",
+                    input { value: draft.read().hit_dice.clone(), oninput: move |e| draft.write().hit_dice = e.value(), class: FIELD_CLASS, placeholder: "2d8+2" }
+                }
+            }
+
+            Field { label: "Speed (feet)",
+                div { class: "grid grid-cols-2 md:grid-cols-6 gap-2 items-center",
+                    for (mode, feet) in [
+                        ("Walk", draft.read().speed.walk),
+                        ("Fly", draft.read().speed.fly),
+                        ("Swim", draft.read().speed.swim),
+                        ("Climb", draft.read().speed.climb),
+                        ("Burrow", draft.read().speed.burrow),
+                    ] {
+                        input {
+                            key: "{mode}",
+                            r#type: "number",
+                            min: "0",
+                            step: "5",
+                            title: mode,
+                            placeholder: mode,
+                            value: feet.to_string(),
+                            oninput: move |e| {
+                                if let Ok(feet) = e.value().parse() {
+                                    let speed = &mut draft.write().speed;
+                                    match mode {
+                                        "Walk" => speed.walk = feet,
+                                        "Fly" => speed.fly = feet,
+                                        "Swim" => speed.swim = feet,
+                                        "Climb" => speed.climb = feet,
+                                        _ => speed.burrow = feet,
+                                    }
+                                }
+                            },
+                            class: FIELD_CLASS,
+                        }
+                    }
+                    Check { label: "Hovers", checked: draft.read().speed.hover, on_change: move |on| draft.write().speed.hover = on }
+                }
+            }
+
+            div { class: "grid grid-cols-3 md:grid-cols-6 gap-2",
+                for ability in Ability::ALL {
+                    Field { key: "{ability.abbreviation()}", label: ability.abbreviation(),
+                        input {
+                            r#type: "number",
+                            min: "1",
+                            max: "30",
+                            value: draft.read().score(ability).to_string(),
+                            oninput: move |e| {
+                                if let Ok(score) = e.value().parse() {
+                                    draft.write().set_score(ability, score);
+                                }
+                            },
+                            class: FIELD_CLASS,
+                        }
+                    }
+                }
+            }
+
+            div { class: "grid grid-cols-1 md:grid-cols-2 gap-3",
+                Field { label: "Saving throws",
+                    input { value: saving_throws(), oninput: move |e| saving_throws.set(e.value()), class: FIELD_CLASS, placeholder: "Dex +5, Wis +3" }
+                }
+                Field { label: "Skills",
+                    input { value: skills(), oninput: move |e| skills.set(e.value()), class: FIELD_CLASS, placeholder: "Perception +4, Stealth +6" }
+                }
+                Field { label: "Damage vulnerabilities",
+                    input { value: draft.read().damage_vulnerabilities.clone(), oninput: move |e| draft.write().damage_vulnerabilities = e.value(), class: FIELD_CLASS }
+                }
+                Field { label: "Damage resistances",
+                    input { value: draft.read().damage_resistances.clone(), oninput: move |e| draft.write().damage_resistances = e.value(), class: FIELD_CLASS }
+                }
+                Field { label: "Damage immunities",
+                    input { value: draft.read().damage_immunities.clone(), oninput: move |e| draft.write().damage_immunities = e.value(), class: FIELD_CLASS }
+                }
+                Field { label: "Condition immunities",
+                    input { value: draft.read().condition_immunities.clone(), oninput: move |e| draft.write().condition_immunities = e.value(), class: FIELD_CLASS }
+                }
+                Field { label: "Senses",
+                    input { value: draft.read().senses.clone(), oninput: move |e| draft.write().senses = e.value(), class: FIELD_CLASS, placeholder: "darkvision 60 ft., passive Perception 10" }
+                }
+                Field { label: "Languages",
+                    input { value: draft.read().languages.clone(), oninput: move |e| draft.write().languages = e.value(), class: FIELD_CLASS }
+                }
+            }
+
+            Field { label: "Environments",
+                div { class: "flex flex-wrap gap-4",
+                    for environment in Environment::ALL.iter().copied() {
+                        Check {
+                            key: "{environment}",
+                            label: environment.to_string(),
+                            checked: draft.read().environments.contains(&environment),
+                            on_change: move |on| toggle(&mut draft.write().environments, environment, on),
+                        }
+                    }
+                }
+            }
+
+            ActionsField { label: "Traits", actions: traits }
+            ActionsField { label: "Actions", actions }
+            ActionsField { label: "Reactions", actions: reactions }
+            Field { label: "Legendary actions per round",
+                input {
+                    r#type: "number",
+                    min: "0",
+                    value: draft.read().legendary_actions_per_round.to_string(),
+                    oninput: move |e| {
+                        if let Ok(count) = e.value().parse() {
+                            draft.write().legendary_actions_per_round = count;
+                        }
+                    },
+                    class: "{FIELD_CLASS} max-w-32",
+                }
+            }
+            if draft.read().legendary_actions_per_round > 0 {
+                ActionsField { label: "Legendary actions", actions: legendary_actions }
+            }
+        }
+    }
+}
+
+#[component]
+fn ItemForm(item: CatalogItem, title: String, saving: bool, on_save: EventHandler<HomebrewEntry>, on_cancel: EventHandler<()>) -> Element {
+    // Edited in gold pieces, stored in copper like inventory items
+    let mut value = use_signal(|| (item.value as f64 / 100.0).to_string());
+    let mut draft = use_signal(|| item);
+
+    let submit = move |_| {
+        let mut item = draft();
+        item.value = value().trim().parse::<f64>().map(|gold| (gold * 100.0).round() as i64).unwrap_or(0).max(0);
+        on_save.call(HomebrewEntry::Item(item));
+    };
+
+    rsx! {
+        FormFrame { title, saving, on_submit: submit, on_cancel,
+            div { class: "grid grid-cols-2 md:grid-cols-4 gap-3",
+                Field { label: "Name", class: "col-span-2 md:col-span-4",
+                    input { value: draft.read().name.clone(), oninput: move |e| draft.write().name = e.value(), class: FIELD_CLASS }
+                }
+                Field { label: "Category",
+                    SelectInput {
+                        i_value: draft.read().category.to_string(),
+                        on_input: move |e: FormEvent| {
+                            if let Some(category) = ItemCategory::from_name(&e.value()) {
+                                draft.write().category = category;
+                            }
+                        },
+                        class: FIELD_CLASS,
+                        options: ItemCategory::options(),
+                    }
+                }
+                Field { label: "Rarity",
+                    SelectInput {
+                        i_value: draft.read().rarity.to_string(),
+                        on_input: move |e: FormEvent| {
+                            if let Some(rarity) = Rarity::from_name(&e.value()) {
+                                draft.write().rarity = rarity;
+                            }
+                        },
+                        class: FIELD_CLASS,
+                        options: Rarity::options(),
+                    }
+                }
+                Field { label: "Weight (lb)",
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        step: "0.1",
+                        value: draft.read().weight.to_string(),
+                        oninput: move |e| {
+                            if let Ok(weight) = e.value().parse::<f64>() {
+                                draft.write().weight = weight.max(0.0);
+                            }
+                        },
+                        class: FIELD_CLASS,
+                    }
+                }
+                Field { label: "Value (gp)",
+                    input { r#type: "number", min: "0", step: "0.01", value: value(), oninput: move |e| value.set(e.value()), class: FIELD_CLASS }
+                }
+            }
+            Check {
+                label: "Requires attunement",
+                checked: draft.read().requires_attunement,
+                on_change: move |on| draft.write().requires_attunement = on,
+            }
+            Field { label: "Description",
+                textarea { rows: "6", value: draft.read().description.clone(), oninput: move |e| draft.write().description = e.value(), class: FIELD_CLASS }
+            }
+        }
+    }
+}
+
+#[component]
+fn FeatForm(feat: Feat, title: String, saving: bool, on_save: EventHandler<HomebrewEntry>, on_cancel: EventHandler<()>) -> Element {
+    let mut draft = use_signal(|| feat);
+
+    rsx! {
+        FormFrame { title, saving, on_submit: move |_| on_save.call(HomebrewEntry::Feat(draft())), on_cancel,
+            div { class: "grid grid-cols-1 md:grid-cols-2 gap-3",
+                Field { label: "Name",
+                    input { value: draft.read().name.clone(), oninput: move |e| draft.write().name = e.value(), class: FIELD_CLASS }
+                }
+                Field { label: "Prerequisite",
+                    input {
+                        value: draft.read().prerequisite.clone(),
+                        oninput: move |e| draft.write().prerequisite = e.value(),
+                        class: FIELD_CLASS,
+                        placeholder: "None",
+                    }
+                }
+            }
+            Field { label: "Description",
+                textarea { rows: "6", value: draft.read().description.clone(), oninput: move |e| draft.write().description = e.value(), class: FIELD_CLASS }
+            }
+        }
+    }
+}
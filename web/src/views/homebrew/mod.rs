@@ -0,0 +1,140 @@
+mod forms;
+
+mod pack;
+pub use pack::HomebrewPackView;
+
+use dioxus::prelude::*;
+use crate::api::homebrew::{get_homebrew_library, save_homebrew_pack, HomebrewLibrary, HomebrewPack};
+use crate::Route;
+
+/// The caller's homebrew packs, a form to start a new one, and the packs others have shared
+#[component]
+pub fn Homebrew() -> Element {
+    let navigator = use_navigator();
+    let mut library = use_signal(HomebrewLibrary::default);
+    let mut loading = use_signal(|| true);
+    let mut error = use_signal::<Option<String>>(|| None);
+    let mut new_name = use_signal(String::new);
+    let mut new_description = use_signal(String::new);
+
+    use_effect(move || {
+        spawn(async move {
+            match get_homebrew_library().await {
+                Ok(fetched) => library.set(fetched),
+                Err(e) => error.set(Some(format!("Failed to load homebrew: {}", e))),
+            }
+            loading.set(false);
+        });
+    });
+
+    let create_pack = move |evt: FormEvent| {
+        evt.prevent_default();
+        let name = new_name.read().trim().to_string();
+        if name.is_empty() {
+            error.set(Some("Pack name is required".to_string()));
+            return;
+        }
+        let pack = HomebrewPack::new(name, new_description.read().trim().to_string());
+        spawn(async move {
+            match save_homebrew_pack(pack).await {
+                Ok(saved) => {
+                    navigator.push(Route::HomebrewPackView { slug: saved.slug });
+                }
+                Err(e) => error.set(Some(format!("Failed to create pack: {}", e))),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "max-w-5xl mx-auto py-6 px-4",
+            h1 { class: "text-3xl font-bold text-purple-900 mb-2", "Homebrew" }
+            p { class: "text-gray-600 mb-6",
+                "Write your own spells, monsters, items and feats in packs. They show up in the reference pages next to the SRD, "
+                "and DMs can enable shared packs in their campaigns."
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6", role: "alert",
+                    p { "{err}" }
+                }
+            }
+
+            form { class: "bg-white rounded-lg shadow p-6 mb-8", onsubmit: create_pack,
+                h2 { class: "text-xl font-semibold mb-4 text-gray-800", "Start a New Pack" }
+                div { class: "grid grid-cols-1 md:grid-cols-3 gap-4",
+                    input {
+                        class: "p-2 border rounded",
+                        placeholder: "Pack name",
+                        value: "{new_name}",
+                        oninput: move |e| new_name.set(e.value()),
+                    }
+                    input {
+                        class: "p-2 border rounded md:col-span-2",
+                        placeholder: "What's in it",
+                        value: "{new_description}",
+                        oninput: move |e| new_description.set(e.value()),
+                    }
+                }
+                button {
+                    r#type: "submit",
+                    class: "mt-4 px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700 transition-colors",
+                    "Create Pack"
+                }
+            }
+
+            if loading() {
+                p { class: "text-gray-600", "Loading homebrew..." }
+            } else {
+                h2 { class: "text-xl font-semibold mb-4 text-gray-800", "Your Packs" }
+                if library.read().own.is_empty() {
+                    p { class: "text-gray-600 mb-8", "You haven't made any packs yet. Start one above." }
+                } else {
+                    div { class: "grid grid-cols-1 md:grid-cols-2 gap-6 mb-8",
+                        for pack in library.read().own.iter() {
+                            PackCard { key: "{pack.id}", pack: pack.clone() }
+                        }
+                    }
+                }
+
+                h2 { class: "text-xl font-semibold mb-4 text-gray-800", "Shared by Others" }
+                if library.read().shared.is_empty() {
+                    p { class: "text-gray-600", "Nobody has shared a pack yet." }
+                } else {
+                    div { class: "grid grid-cols-1 md:grid-cols-2 gap-6",
+                        for pack in library.read().shared.iter() {
+                            PackCard { key: "{pack.id}", pack: pack.clone() }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn PackCard(pack: HomebrewPack) -> Element {
+    rsx! {
+        div { class: "bg-white rounded-lg shadow p-6 flex flex-col",
+            div { class: "flex items-start justify-between gap-2",
+                Link {
+                    to: Route::HomebrewPackView { slug: pack.slug.clone() },
+                    class: "text-xl font-semibold text-purple-800 hover:underline",
+                    "{pack.name}"
+                }
+                SharedBadge { shared: pack.shared }
+            }
+            p { class: "text-gray-700 mt-2", "{pack.description}" }
+        }
+    }
+}
+
+#[component]
+fn SharedBadge(shared: bool) -> Element {
+    rsx! {
+        if shared {
+            span { class: "px-2 py-1 text-xs rounded-full bg-emerald-100 text-emerald-800", "Shared" }
+        } else {
+            span { class: "px-2 py-1 text-xs rounded-full bg-gray-200 text-gray-700", "Private" }
+        }
+    }
+}
@@ -0,0 +1,400 @@
+use dioxus::prelude::*;
+use crate::api::homebrew::{
+    delete_homebrew_entry, delete_homebrew_pack, get_homebrew_history, get_homebrew_pack, restore_homebrew_version,
+    save_homebrew_entry, save_homebrew_pack, HomebrewEntry, HomebrewKind, HomebrewPack,
+};
+use super::forms::EntryForm;
+use crate::Route;
+
+// Where an entry can be seen the way players will see it, for the catalogs with detail pages
+fn detail_route(entry: &HomebrewEntry) -> Option<Route> {
+    match entry {
+        HomebrewEntry::Spell(spell) => Some(Route::SpellDetail { slug: spell.slug.clone() }),
+        HomebrewEntry::Monster(monster) => Some(Route::MonsterDetail { slug: monster.slug.clone() }),
+        _ => None,
+    }
+}
+
+/// One homebrew pack: its details, its entries by kind, and (for the owner) editing and version history
+#[component]
+pub fn HomebrewPackView(slug: String) -> Element {
+    let navigator = use_navigator();
+    let mut pack = use_signal::<Option<HomebrewPack>>(|| None);
+    let mut can_edit = use_signal(|| false);
+    let mut entries = use_signal(Vec::<HomebrewEntry>::new);
+    let mut loading = use_signal(|| true);
+    let mut error = use_signal::<Option<String>>(|| None);
+    let mut kind = use_signal(|| HomebrewKind::Spell);
+    let mut editing = use_signal::<Option<HomebrewEntry>>(|| None);
+    let mut history = use_signal::<Option<HomebrewEntry>>(|| None);
+    let mut saving = use_signal(|| false);
+    let mut confirm_delete = use_signal(|| false);
+
+    let slug_signal = use_signal(|| slug.clone());
+    use_effect(move || {
+        spawn(async move {
+            match get_homebrew_pack(slug_signal()).await {
+                Ok(contents) => {
+                    pack.set(Some(contents.pack));
+                    can_edit.set(contents.can_edit);
+                    entries.set(contents.entries);
+                }
+                Err(e) => error.set(Some(format!("Failed to load pack: {}", e))),
+            }
+            loading.set(false);
+        });
+    });
+
+    let save_details = move |updated: HomebrewPack| {
+        spawn(async move {
+            match save_homebrew_pack(updated).await {
+                Ok(saved) => {
+                    error.set(None);
+                    if saved.slug != slug_signal() {
+                        navigator.replace(Route::HomebrewPackView { slug: saved.slug.clone() });
+                    }
+                    pack.set(Some(saved));
+                }
+                Err(e) => error.set(Some(format!("Failed to save pack: {}", e))),
+            }
+        });
+    };
+
+    let delete_pack = move |_| {
+        let Some(id) = pack.read().as_ref().map(|pack| pack.id) else { return };
+        spawn(async move {
+            match delete_homebrew_pack(id).await {
+                Ok(()) => {
+                    navigator.push(Route::Homebrew {});
+                }
+                Err(e) => error.set(Some(format!("Failed to delete pack: {}", e))),
+            }
+        });
+    };
+
+    let save_entry = move |entry: HomebrewEntry| {
+        if let Some(problem) = entry.problem() {
+            error.set(Some(problem));
+            return;
+        }
+        let Some(pack_id) = pack.read().as_ref().map(|pack| pack.id) else { return };
+        let what = entry.kind().name().to_lowercase();
+        saving.set(true);
+        spawn(async move {
+            match save_homebrew_entry(pack_id, entry).await {
+                Ok(saved) => {
+                    error.set(None);
+                    editing.set(None);
+                    let mut list = entries.write();
+                    match list.iter_mut().find(|entry| entry.kind() == saved.kind() && entry.slug() == saved.slug()) {
+                        Some(existing) => *existing = saved,
+                        None => list.push(saved),
+                    }
+                }
+                Err(e) => error.set(Some(format!("Failed to save {}: {}", what, e))),
+            }
+            saving.set(false);
+        });
+    };
+
+    let delete_entry = move |entry: HomebrewEntry| {
+        let (entry_kind, entry_slug) = (entry.kind(), entry.slug().to_string());
+        spawn(async move {
+            match delete_homebrew_entry(entry_kind, entry_slug.clone()).await {
+                Ok(()) => entries.write().retain(|entry| !(entry.kind() == entry_kind && entry.slug() == entry_slug)),
+                Err(e) => error.set(Some(format!("Failed to delete {}: {}", entry_kind.name().to_lowercase(), e))),
+            }
+        });
+    };
+
+    let on_restore = move |restored: HomebrewEntry| {
+        history.set(Some(restored.clone()));
+        let mut list = entries.write();
+        match list.iter_mut().find(|entry| entry.kind() == restored.kind() && entry.slug() == restored.slug()) {
+            Some(existing) => *existing = restored,
+            None => list.push(restored),
+        }
+    };
+
+    if loading() {
+        return rsx! {
+            div { class: "flex justify-center py-12",
+                div {
+                    class: "animate-spin rounded-full h-12 w-12 border-t-2 border-b-2 border-blue-500",
+                    aria_label: "Loading...",
+                }
+            }
+        };
+    }
+
+    let Some(current) = pack() else {
+        return rsx! {
+            div { class: "max-w-5xl mx-auto py-6 px-4",
+                div { class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6", role: "alert",
+                    p { {error().unwrap_or_else(|| "Pack not found".to_string())} }
+                }
+                Link { to: Route::Homebrew {}, class: "text-blue-600 hover:underline", "Back to homebrew" }
+            }
+        };
+    };
+
+    let shown: Vec<HomebrewEntry> = entries.read().iter().filter(|entry| entry.kind() == kind()).cloned().collect();
+
+    rsx! {
+        div { class: "max-w-5xl mx-auto py-6 px-4",
+            Link { to: Route::Homebrew {}, class: "text-sm text-blue-600 hover:underline", "← All homebrew" }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 my-6", role: "alert",
+                    p { "{err}" }
+                }
+            }
+
+            if can_edit() {
+                PackDetails { key: "{current.id}-{current.updated_at.clone().unwrap_or_default()}", pack: current.clone(), on_save: save_details }
+                div { class: "flex justify-end gap-2 mt-2",
+                    if confirm_delete() {
+                        span { class: "text-sm text-red-700 self-center", "Delete this pack and everything in it?" }
+                        button {
+                            onclick: delete_pack,
+                            class: "px-3 py-1 text-sm bg-red-600 text-white rounded hover:bg-red-700",
+                            "Delete"
+                        }
+                        button {
+                            onclick: move |_| confirm_delete.set(false),
+                            class: "px-3 py-1 text-sm border border-gray-300 rounded hover:bg-gray-100",
+                            "Keep it"
+                        }
+                    } else {
+                        button {
+                            onclick: move |_| confirm_delete.set(true),
+                            class: "px-3 py-1 text-sm text-red-600 hover:text-red-800",
+                            "Delete pack"
+                        }
+                    }
+                }
+            } else {
+                div { class: "mt-4",
+                    h1 { class: "text-3xl font-bold text-purple-900", "{current.name}" }
+                    p { class: "text-gray-600 mt-1", "{current.description}" }
+                }
+            }
+
+            div { class: "flex flex-wrap gap-2 mt-6 border-b border-gray-200",
+                for tab in HomebrewKind::ALL.iter().copied() {
+                    button {
+                        key: "{tab.name()}",
+                        onclick: move |_| {
+                            kind.set(tab);
+                            editing.set(None);
+                            history.set(None);
+                        },
+                        class: if kind() == tab {
+                            "px-4 py-2 -mb-px border-b-2 border-purple-600 text-purple-800 font-semibold"
+                        } else {
+                            "px-4 py-2 -mb-px border-b-2 border-transparent text-gray-600 hover:text-gray-900"
+                        },
+                        {format!("{} ({})", tab.plural(), entries.read().iter().filter(|entry| entry.kind() == tab).count())}
+                    }
+                }
+            }
+
+            if can_edit() && editing.read().is_none() {
+                button {
+                    onclick: move |_| {
+                        history.set(None);
+                        editing.set(Some(HomebrewEntry::blank(kind())));
+                    },
+                    class: "mt-4 px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700 transition-colors",
+                    {format!("New {}", kind().name().to_lowercase())}
+                }
+            }
+
+            if let Some(draft) = editing() {
+                div { class: "mt-4",
+                    EntryForm {
+                        key: "{draft.kind().name()}-{draft.slug()}-{draft.version()}",
+                        entry: draft.clone(),
+                        saving: saving(),
+                        on_save: save_entry,
+                        on_cancel: move |_| editing.set(None),
+                    }
+                }
+            }
+
+            if shown.is_empty() {
+                p { class: "text-gray-600 py-8 text-center", {format!("No {} in this pack yet.", kind().plural().to_lowercase())} }
+            } else {
+                div { class: "space-y-3 mt-4",
+                    for entry in shown {
+                        div { key: "{entry.slug()}", class: "bg-white rounded-lg shadow p-4",
+                            div { class: "flex items-start justify-between gap-4",
+                                div {
+                                    if let Some(route) = detail_route(&entry) {
+                                        Link { to: route, class: "text-lg font-semibold text-purple-800 hover:underline", "{entry.name()}" }
+                                    } else {
+                                        h3 { class: "text-lg font-semibold text-gray-900", "{entry.name()}" }
+                                    }
+                                    p { class: "text-sm italic text-gray-600", {entry.summary()} }
+                                }
+                                div { class: "flex items-center gap-3 text-sm",
+                                    span { class: "px-2 py-1 rounded-full bg-gray-100 text-gray-700", "v{entry.version()}" }
+                                    if can_edit() {
+                                        button {
+                                            onclick: {
+                                                let entry = entry.clone();
+                                                move |_| {
+                                                    history.set(None);
+                                                    editing.set(Some(entry.clone()));
+                                                }
+                                            },
+                                            class: "text-blue-600 hover:text-blue-800",
+                                            "Edit"
+                                        }
+                                    }
+                                    button {
+                                        onclick: {
+                                            let entry = entry.clone();
+                                            move |_| {
+                                                let open = history.read().as_ref().is_some_and(|shown| shown.slug() == entry.slug());
+                                                history.set(if open { None } else { Some(entry.clone()) });
+                                            }
+                                        },
+                                        class: "text-gray-600 hover:text-gray-900",
+                                        "History"
+                                    }
+                                    if can_edit() {
+                                        button {
+                                            onclick: {
+                                                let entry = entry.clone();
+                                                move |_| delete_entry(entry.clone())
+                                            },
+                                            class: "text-red-600 hover:text-red-800",
+                                            "Delete"
+                                        }
+                                    }
+                                }
+                            }
+                            if history.read().as_ref().is_some_and(|shown| shown.slug() == entry.slug()) {
+                                HistoryPanel {
+                                    key: "{entry.slug()}-{entry.version()}",
+                                    kind: entry.kind(),
+                                    slug: entry.slug().to_string(),
+                                    can_edit: can_edit(),
+                                    on_restore,
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The owner's editable pack header: name, description and whether others can use it
+#[component]
+fn PackDetails(pack: HomebrewPack, on_save: EventHandler<HomebrewPack>) -> Element {
+    let mut name = use_signal(|| pack.name.clone());
+    let mut description = use_signal(|| pack.description.clone());
+    let mut shared = use_signal(|| pack.shared);
+
+    rsx! {
+        form {
+            class: "bg-white rounded-lg shadow p-6 mt-4 space-y-3",
+            onsubmit: move |e| {
+                e.prevent_default();
+                on_save.call(HomebrewPack { name: name(), description: description(), shared: shared(), ..pack.clone() });
+            },
+            input {
+                class: "w-full p-2 border rounded text-2xl font-bold text-purple-900",
+                value: "{name}",
+                oninput: move |e| name.set(e.value()),
+            }
+            textarea {
+                class: "w-full p-2 border rounded",
+                rows: 2,
+                placeholder: "What's in this pack",
+                value: "{description}",
+                oninput: move |e| description.set(e.value()),
+            }
+            div { class: "flex items-center justify-between",
+                label { class: "flex items-center gap-2 text-gray-700",
+                    input {
+                        r#type: "checkbox",
+                        checked: shared(),
+                        onchange: move |e| shared.set(e.checked()),
+                    }
+                    "Share with everyone, so other DMs can enable it in their campaigns"
+                }
+                button {
+                    r#type: "submit",
+                    class: "px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700 transition-colors",
+                    "Save Pack"
+                }
+            }
+        }
+    }
+}
+
+/// Every saved version of one entry, newest first, with a way back to any of them for the owner
+#[component]
+fn HistoryPanel(kind: HomebrewKind, slug: String, can_edit: bool, on_restore: EventHandler<HomebrewEntry>) -> Element {
+    let mut error = use_signal::<Option<String>>(|| None);
+    let versions = use_resource(move || {
+        let slug = slug.clone();
+        async move { get_homebrew_history(kind, slug).await }
+    });
+
+    let restore = move |id: i32| {
+        spawn(async move {
+            match restore_homebrew_version(id).await {
+                Ok(restored) => on_restore.call(restored),
+                Err(e) => error.set(Some(format!("Failed to restore version: {}", e))),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "mt-4 border-t border-gray-200 pt-3",
+            if let Some(err) = error.read().as_ref() {
+                div { class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-3", role: "alert",
+                    p { "{err}" }
+                }
+            }
+            match &*versions.read() {
+                None => rsx! { p { class: "text-sm text-gray-500", "Loading history..." } },
+                Some(Err(e)) => rsx! { p { class: "text-sm text-red-700", "Failed to load history: {e}" } },
+                Some(Ok(found)) if found.is_empty() => rsx! { p { class: "text-sm text-gray-500", "No saved versions yet." } },
+                Some(Ok(found)) => {
+                    let latest = found.first().map(|version| version.version).unwrap_or_default();
+                    rsx! {
+                        ul { class: "space-y-1 text-sm",
+                            for version in found.iter() {
+                                li { key: "{version.id}", class: "flex items-center justify-between gap-4",
+                                    span { class: "text-gray-700",
+                                        span { class: "font-semibold", "v{version.version}" }
+                                        " {version.name}"
+                                        if let Some(date) = version.created_at.as_deref().and_then(|at| at.get(..10)) {
+                                            span { class: "text-gray-500", " • saved {date}" }
+                                        }
+                                    }
+                                    if can_edit && version.version != latest {
+                                        button {
+                                            onclick: {
+                                                let id = version.id;
+                                                move |_| restore(id)
+                                            },
+                                            class: "text-blue-600 hover:text-blue-800",
+                                            "Restore"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
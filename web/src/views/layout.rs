@@ -3,14 +3,21 @@ use dioxus::prelude::*;
 use crate::Route;
 use crate::components::Footer;
 use super::Navbar;
+use super::routes::use_route_guard;
 
 #[component]
 pub fn AppLayout() -> Element {
+    let allowed = use_route_guard(use_route::<Route>());
+
     rsx! {
         div { class: "flex flex-col min-h-screen",
             Navbar {}
             main { class: "flex-1 bg-background font-display text-text",
-                div { class: "mx-auto px-6 sm:px-8 py-8", Outlet::<Route> {} }
+                div { class: "mx-auto px-6 sm:px-8 py-8",
+                    if allowed() {
+                        Outlet::<Route> {}
+                    }
+                }
             }
             Footer {}
         }
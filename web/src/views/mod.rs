@@ -2,6 +2,7 @@ pub mod characters;
 pub mod campaigns;
 pub mod references;
 pub mod dm_tools;
+pub mod homebrew;
  
 
 mod home;
@@ -21,6 +22,9 @@ pub use contact::Contact;
 mod projects;
 pub use projects::Projects;
 
+mod search;
+pub use search::Search;
+
 
 mod layout;
 pub use layout::AppLayout;
@@ -30,4 +34,4 @@ pub mod protected;
 pub use protected::Protected;
 pub mod callback;
 pub use callback::Callback;
-mod routes;
+pub mod routes;
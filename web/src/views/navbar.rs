@@ -1,10 +1,14 @@
 use crate::Route;
 use dioxus::prelude::*;
 use dioxus_router::prelude::use_route;
+use crate::views::campaigns::{campaign_tabs, route_campaign_slug};
 
 #[component]
 pub fn Navbar() -> Element {
     let current_route = use_route::<Route>();
+    let campaign_slug = route_campaign_slug(&current_route);
+    let navigator = use_navigator();
+    let mut search_query = use_signal(String::new);
 
     // Helper function to determine active class
     fn active_class(route: &Route, current_route: &Route, class: &str) -> String {
@@ -48,21 +52,22 @@ pub fn Navbar() -> Element {
                             ),
                             "Characters"
                         }
+                        Link {
+                            to: Route::Homebrew {},
+                            class: active_class(
+                                &Route::Homebrew {},
+                                &current_route,
+                                "text-text hover:text-accent px-1 py-2 text-sm font-medium transition-colors",
+                            ),
+                            "Homebrew"
+                        }
 
                         // Campaign dropdown
                         div { class: "relative group",
                             button {
                                 class: format!(
                                     "text-text hover:text-accent px-1 py-2 text-sm font-medium transition-colors flex items-center {}",
-                                    if matches!(
-                                        current_route,
-                                        Route::Campaigns {}
-                                        | Route::CampaignDashboard {}
-                                        | Route::AdventureLog {}
-                                        | Route::Lore {}
-                                        | Route::MapLocations {}
-                                        | Route::NpcView {}
-                                    ) {
+                                    if current_route == (Route::Campaigns {}) || campaign_slug.is_some() {
                                         "text-secondary font-medium border-b-2 border-secondary"
                                     } else {
                                         ""
@@ -77,32 +82,16 @@ pub fn Navbar() -> Element {
                                     Link {
                                         to: Route::Campaigns {},
                                         class: "block px-4 py-2 text-sm text-gray-700 hover:bg-gray-100",
-                                        "Overview"
-                                    }
-                                    Link {
-                                        to: Route::CampaignDashboard {},
-                                        class: "block px-4 py-2 text-sm text-gray-700 hover:bg-gray-100",
-                                        "Dashboard"
-                                    }
-                                    Link {
-                                        to: Route::AdventureLog {},
-                                        class: "block px-4 py-2 text-sm text-gray-700 hover:bg-gray-100",
-                                        "Adventure Log"
+                                        "All Campaigns"
                                     }
-                                    Link {
-                                        to: Route::Lore {},
-                                        class: "block px-4 py-2 text-sm text-gray-700 hover:bg-gray-100",
-                                        "Lore"
-                                    }
-                                    Link {
-                                        to: Route::MapLocations {},
-                                        class: "block px-4 py-2 text-sm text-gray-700 hover:bg-gray-100",
-                                        "Map & Locations"
-                                    }
-                                    Link {
-                                        to: Route::NpcView {},
-                                        class: "block px-4 py-2 text-sm text-gray-700 hover:bg-gray-100",
-                                        "NPCs"
+                                    if let Some(slug) = campaign_slug.as_ref() {
+                                        for (to, name) in campaign_tabs(slug) {
+                                            Link {
+                                                to,
+                                                class: "block px-4 py-2 text-sm text-gray-700 hover:bg-gray-100",
+                                                "{name}"
+                                            }
+                                        }
                                     }
                                     Link {
                                         to: Route::QuickReference {},
@@ -113,6 +102,27 @@ pub fn Navbar() -> Element {
                             }
                         } // END Campaign dropdown
                     }
+
+                    // Global search
+                    form {
+                        class: "flex items-center",
+                        role: "search",
+                        onsubmit: move |e| {
+                            e.prevent_default();
+                            let q = search_query.read().trim().to_string();
+                            if !q.is_empty() {
+                                navigator.push(Route::Search { q });
+                            }
+                        },
+                        input {
+                            r#type: "search",
+                            class: "w-48 lg:w-64 px-3 py-1.5 text-sm text-gray-900 bg-white/90 border border-gray-300 rounded-lg focus:ring-2 focus:ring-secondary focus:border-transparent",
+                            placeholder: "Search everything...",
+                            aria_label: "Search everything",
+                            value: "{search_query}",
+                            oninput: move |e| search_query.set(e.value()),
+                        }
+                    }
                 
                 }
             }
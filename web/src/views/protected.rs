@@ -1,15 +1,12 @@
 use crate::api::auth::signout;
 use crate::components::button::Button;
-use crate::views::routes::{protected, GuardContext};
+use crate::views::routes::GuardContext;
 use dioxus::prelude::*;
-use crate::Route;
 
 
 
 #[component]
 pub fn Protected() -> Element {
-    protected(Route::Login {}, Route::Protected {});
-
     let logout = move |_| {
         spawn(async move {
             client! {
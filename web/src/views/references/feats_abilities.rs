@@ -1,27 +1,81 @@
-
 use dioxus::prelude::*;
+use crate::api::feats::list_feats;
+use crate::components::SelectInput;
+use super::source::{source_options, source_value, SourceBadge};
 
+const FILTER_CLASS: &str = "px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg dark:bg-gray-700 dark:text-white";
 
-
-
+/// The feat catalog: the SRD's feats plus homebrew from the reader's packs and campaigns
 #[component]
 pub fn FeatsAbilities() -> Element {
-
+    let mut query = use_signal(String::new);
+    let mut homebrew = use_signal(|| None::<bool>);
+    let feats = use_resource(move || {
+        let query = query();
+        let homebrew = homebrew();
+        async move { list_feats(query, homebrew).await }
+    });
 
     rsx! {
-        div { class: "max-w-lg mx-auto py-2",
-            h1 { class: "text-3xl", "Feats and Abilities" }
-            p { class: "text-lg text-gray-700 mt-4",
-                "This section will contain detailed information about various feats and abilities available in the game."
+        div { class: "max-w-4xl mx-auto py-8 px-4",
+            h1 { class: "text-3xl font-bold text-gray-900 dark:text-white", "Feats and Abilities" }
+            p { class: "text-gray-600 dark:text-gray-400 mt-1",
+                "Feats from the System Reference Document 5.1, plus homebrew from your packs and campaigns."
             }
-            p { class: "text-lg text-gray-700 mt-2",
-                "Stay tuned for updates as we build out this feature to enhance your character's capabilities."
-            }
-            p { class: "text-lg text-gray-700 mt-2",
-                "For now, refer to the official rulebooks and resources for detailed information on feats and abilities."
+
+            div { class: "grid grid-cols-1 md:grid-cols-4 gap-3 mt-6",
+                input {
+                    r#type: "search",
+                    value: query(),
+                    oninput: move |e| query.set(e.value()),
+                    class: "{FILTER_CLASS} md:col-span-3",
+                    placeholder: "Search feats by name",
+                }
+                SelectInput {
+                    i_value: source_value(homebrew()),
+                    on_input: move |e: FormEvent| homebrew.set(e.value().parse().ok()),
+                    class: FILTER_CLASS,
+                    options: source_options(),
+                }
             }
-            p { class: "text-lg text-gray-700 mt-2",
-                "If you have any specific questions or need clarification on certain feats or abilities, feel free to reach out through our contact page."
+
+            match &*feats.read() {
+                None => rsx! {
+                    div { class: "flex justify-center py-12",
+                        div {
+                            class: "animate-spin rounded-full h-12 w-12 border-t-2 border-b-2 border-blue-500",
+                            aria_label: "Loading...",
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    div {
+                        class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 my-6",
+                        role: "alert",
+                        p { class: "font-bold", "Error" }
+                        p { "{e}" }
+                    }
+                },
+                Some(Ok(found)) if found.is_empty() => rsx! {
+                    p { class: "text-gray-500 dark:text-gray-400 py-12 text-center", "No feats match these filters." }
+                },
+                Some(Ok(found)) => rsx! {
+                    div { class: "space-y-4 mt-6",
+                        for feat in found.iter() {
+                            div { key: "{feat.slug}", class: "bg-white dark:bg-gray-800 rounded-lg p-4 shadow",
+                                div { class: "flex items-start justify-between gap-2",
+                                    h2 { class: "text-lg font-semibold text-gray-900 dark:text-white", "{feat.name}" }
+                                    SourceBadge { source: feat.source.clone(), homebrew: feat.pack_id.is_some() }
+                                }
+                                if !feat.prerequisite.is_empty() {
+                                    p { class: "text-sm italic text-gray-600 dark:text-gray-400", "Prerequisite: {feat.prerequisite}" }
+                                }
+                                p { class: "text-gray-700 dark:text-gray-300 mt-2 whitespace-pre-line", "{feat.description}" }
+                            }
+                        }
+                    }
+                },
             }
         }
-    } }
\ No newline at end of file
+    }
+}
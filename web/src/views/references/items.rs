@@ -0,0 +1,109 @@
+use dioxus::prelude::*;
+use crate::api::items::{list_items, ItemFilter};
+use crate::components::SelectInput;
+use crate::rules::inventory::format_copper;
+use crate::rules::{ItemCategory, Rarity};
+use super::source::{source_options, source_value, SourceBadge};
+
+const FILTER_CLASS: &str = "px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg dark:bg-gray-700 dark:text-white";
+
+// `(value, label)` pairs with an "any" choice in front, for filters that can be left unset
+fn with_any(any: &str, options: Vec<(String, String)>) -> Vec<(String, String)> {
+    std::iter::once((String::new(), any.to_string())).chain(options).collect()
+}
+
+/// The item catalog, narrowed down by name, category, rarity and source
+#[component]
+pub fn Items() -> Element {
+    let mut filter = use_signal(ItemFilter::default);
+    let items = use_resource(move || {
+        let filter = filter();
+        async move { list_items(filter).await }
+    });
+
+    rsx! {
+        div { class: "max-w-6xl mx-auto py-8 px-4",
+            h1 { class: "text-3xl font-bold text-gray-900 dark:text-white", "Items" }
+            p { class: "text-gray-600 dark:text-gray-400 mt-1",
+                "Magic items from the System Reference Document 5.1, plus homebrew from your packs and campaigns."
+            }
+
+            div { class: "grid grid-cols-2 md:grid-cols-4 gap-3 mt-6",
+                input {
+                    r#type: "search",
+                    value: filter.read().query.clone(),
+                    oninput: move |e| filter.write().query = e.value(),
+                    class: "{FILTER_CLASS} col-span-2 md:col-span-4",
+                    placeholder: "Search items by name",
+                }
+                SelectInput {
+                    i_value: filter.read().category.map(|category| category.to_string()).unwrap_or_default(),
+                    on_input: move |e: FormEvent| filter.write().category = ItemCategory::from_name(&e.value()),
+                    class: FILTER_CLASS,
+                    options: with_any("Any category", ItemCategory::options()),
+                }
+                SelectInput {
+                    i_value: filter.read().rarity.map(|rarity| rarity.to_string()).unwrap_or_default(),
+                    on_input: move |e: FormEvent| filter.write().rarity = Rarity::from_name(&e.value()),
+                    class: FILTER_CLASS,
+                    options: with_any("Any rarity", Rarity::options()),
+                }
+                SelectInput {
+                    i_value: source_value(filter.read().homebrew),
+                    on_input: move |e: FormEvent| filter.write().homebrew = e.value().parse().ok(),
+                    class: FILTER_CLASS,
+                    options: source_options(),
+                }
+                button {
+                    r#type: "button",
+                    onclick: move |_| filter.set(ItemFilter::default()),
+                    class: "px-3 py-2 text-sm text-gray-700 dark:text-gray-300 border border-gray-300 dark:border-gray-600 rounded-lg hover:bg-gray-100 dark:hover:bg-gray-700",
+                    "Clear filters"
+                }
+            }
+
+            match &*items.read() {
+                None => rsx! {
+                    div { class: "flex justify-center py-12",
+                        div {
+                            class: "animate-spin rounded-full h-12 w-12 border-t-2 border-b-2 border-blue-500",
+                            aria_label: "Loading...",
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    div {
+                        class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 my-6",
+                        role: "alert",
+                        p { class: "font-bold", "Error" }
+                        p { "{e}" }
+                    }
+                },
+                Some(Ok(found)) if found.is_empty() => rsx! {
+                    p { class: "text-gray-500 dark:text-gray-400 py-12 text-center", "No items match these filters." }
+                },
+                Some(Ok(found)) => rsx! {
+                    div { class: "grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-4 mt-6",
+                        for item in found.iter() {
+                            div { key: "{item.slug}", class: "bg-white dark:bg-gray-800 rounded-lg p-4 shadow",
+                                div { class: "flex items-start justify-between gap-2",
+                                    h2 { class: "text-lg font-semibold text-gray-900 dark:text-white", "{item.name}" }
+                                    SourceBadge { source: item.source.clone(), homebrew: item.pack_id.is_some() }
+                                }
+                                p { class: "text-sm italic text-gray-600 dark:text-gray-400", "{item.kind_line()}" }
+                                if item.weight > 0.0 || item.value > 0 {
+                                    p { class: "text-xs text-gray-500 dark:text-gray-400 mt-1",
+                                        if item.weight > 0.0 { "{item.weight} lb." }
+                                        if item.weight > 0.0 && item.value > 0 { " • " }
+                                        if item.value > 0 { "{format_copper(item.value)}" }
+                                    }
+                                }
+                                p { class: "text-sm text-gray-700 dark:text-gray-300 mt-2 whitespace-pre-line", "{item.description}" }
+                            }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
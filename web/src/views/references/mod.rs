@@ -8,10 +8,17 @@ mod feats_abilities;
 pub use feats_abilities::FeatsAbilities;
 
 mod monsters;
-pub use monsters::Monsters;
+pub use monsters::{MonsterDetail, Monsters};
+pub(crate) use monsters::StatBlock;
 
 mod spells;
-pub use spells::Spells;
+pub use spells::{SpellDetail, Spells};
+
+mod items;
+pub use items::Items;
+
+mod source;
 
 mod quick_reference;
-pub use quick_reference::QuickReference;
\ No newline at end of file
+pub use quick_reference::QuickReference;
+pub(crate) use quick_reference::{use_reference_favorites, PinnedReferences};
\ No newline at end of file
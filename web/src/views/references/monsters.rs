@@ -1,31 +1,362 @@
-
 use dioxus::prelude::*;
+use crate::api::monsters::{challenge_options, get_monster, list_monsters, score_label, Bonus, Monster, MonsterAction, MonsterFilter};
+use crate::components::{RolledLine, SelectInput};
+use crate::rules::dice::{self, Advantage};
+use crate::rules::{Ability, CreatureType, Environment};
+use super::source::{source_options, source_value, SourceBadge};
+use crate::Route;
 
+const FILTER_CLASS: &str = "px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg dark:bg-gray-700 dark:text-white";
 
+// `(value, label)` pairs with an "any" choice in front, for filters that can be left unset
+fn with_any(any: &str, options: Vec<(String, String)>) -> Vec<(String, String)> {
+    std::iter::once((String::new(), any.to_string())).chain(options).collect()
+}
 
+fn challenge_value(challenge: Option<f64>) -> String {
+    challenge.map(|challenge| challenge.to_string()).unwrap_or_default()
+}
 
+/// The bestiary, narrowed down by name, challenge rating, creature type, environment and source
 #[component]
 pub fn Monsters() -> Element {
+    let mut filter = use_signal(MonsterFilter::default);
+    let mut page = use_signal(|| 0);
+    let monsters = use_resource(move || {
+        let filter = filter();
+        let page = page();
+        async move { list_monsters(filter, page).await }
+    });
+
+    // Any change to the filter starts over from the first page
+    let mut narrow = move |change: Box<dyn FnOnce(&mut MonsterFilter)>| {
+        change(&mut filter.write());
+        page.set(0);
+    };
+
+    rsx! {
+        div { class: "max-w-6xl mx-auto py-8 px-4",
+            h1 { class: "text-3xl font-bold text-gray-900 dark:text-white", "Monsters" }
+            p { class: "text-gray-600 dark:text-gray-400 mt-1",
+                "Creatures from the System Reference Document 5.1, plus homebrew from your packs and campaigns."
+            }
+
+            div { class: "grid grid-cols-2 md:grid-cols-3 lg:grid-cols-6 gap-3 mt-6",
+                input {
+                    r#type: "search",
+                    value: filter.read().query.clone(),
+                    oninput: move |e| narrow(Box::new(move |f| f.query = e.value())),
+                    class: "{FILTER_CLASS} col-span-2 md:col-span-3 lg:col-span-6",
+                    placeholder: "Search monsters by name",
+                }
+                SelectInput {
+                    i_value: filter.read().monster_type.map(|kind| kind.to_string()).unwrap_or_default(),
+                    on_input: move |e: FormEvent| narrow(Box::new(move |f| f.monster_type = CreatureType::from_name(&e.value()))),
+                    class: FILTER_CLASS,
+                    options: with_any("Any type", CreatureType::options()),
+                }
+                SelectInput {
+                    i_value: filter.read().environment.map(|environment| environment.to_string()).unwrap_or_default(),
+                    on_input: move |e: FormEvent| narrow(Box::new(move |f| f.environment = Environment::from_name(&e.value()))),
+                    class: FILTER_CLASS,
+                    options: with_any("Any environment", Environment::options()),
+                }
+                SelectInput {
+                    i_value: challenge_value(filter.read().min_challenge),
+                    on_input: move |e: FormEvent| narrow(Box::new(move |f| f.min_challenge = e.value().parse().ok())),
+                    class: FILTER_CLASS,
+                    options: with_any("Lowest CR", challenge_options()),
+                }
+                SelectInput {
+                    i_value: challenge_value(filter.read().max_challenge),
+                    on_input: move |e: FormEvent| narrow(Box::new(move |f| f.max_challenge = e.value().parse().ok())),
+                    class: FILTER_CLASS,
+                    options: with_any("Highest CR", challenge_options()),
+                }
+                SelectInput {
+                    i_value: source_value(filter.read().homebrew),
+                    on_input: move |e: FormEvent| narrow(Box::new(move |f| f.homebrew = e.value().parse().ok())),
+                    class: FILTER_CLASS,
+                    options: source_options(),
+                }
+                button {
+                    r#type: "button",
+                    onclick: move |_| narrow(Box::new(|f| *f = MonsterFilter::default())),
+                    class: "px-3 py-2 text-sm text-gray-700 dark:text-gray-300 border border-gray-300 dark:border-gray-600 rounded-lg hover:bg-gray-100 dark:hover:bg-gray-700",
+                    "Clear filters"
+                }
+            }
+
+            match &*monsters.read() {
+                None => rsx! {
+                    div { class: "flex justify-center py-12",
+                        div {
+                            class: "animate-spin rounded-full h-12 w-12 border-t-2 border-b-2 border-blue-500",
+                            aria_label: "Loading...",
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    div {
+                        class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 my-6",
+                        role: "alert",
+                        p { class: "font-bold", "Error" }
+                        p { "{e}" }
+                    }
+                },
+                Some(Ok(found)) if found.monsters.is_empty() => rsx! {
+                    p { class: "text-gray-500 dark:text-gray-400 py-12 text-center", "No monsters match these filters." }
+                },
+                Some(Ok(found)) => rsx! {
+                    p { class: "text-sm text-gray-500 dark:text-gray-400 mt-6 mb-3",
+                        "{found.total} monsters"
+                    }
+                    div { class: "bg-white dark:bg-gray-800 rounded-lg shadow overflow-x-auto",
+                        table { class: "w-full text-sm",
+                            thead { class: "text-left text-gray-500 dark:text-gray-400 border-b border-gray-200 dark:border-gray-700",
+                                tr {
+                                    th { class: "px-4 py-2", "Name" }
+                                    th { class: "px-4 py-2", "CR" }
+                                    th { class: "px-4 py-2", "Type" }
+                                    th { class: "px-4 py-2", "Size" }
+                                    th { class: "px-4 py-2", "AC" }
+                                    th { class: "px-4 py-2", "HP" }
+                                }
+                            }
+                            tbody {
+                                for monster in found.monsters.iter() {
+                                    tr { key: "{monster.slug}", class: "border-t border-gray-100 dark:border-gray-700",
+                                        td { class: "px-4 py-2",
+                                            Link {
+                                                to: Route::MonsterDetail { slug: monster.slug.clone() },
+                                                class: "font-medium text-blue-600 dark:text-blue-400 hover:underline",
+                                                "{monster.name}"
+                                            }
+                                            if monster.pack_id.is_some() {
+                                                span { class: "ml-2",
+                                                    SourceBadge { source: monster.source.clone(), homebrew: true }
+                                                }
+                                            }
+                                        }
+                                        td { class: "px-4 py-2 text-gray-700 dark:text-gray-300", "{monster.challenge_label()}" }
+                                        td { class: "px-4 py-2 text-gray-700 dark:text-gray-300", "{monster.monster_type}" }
+                                        td { class: "px-4 py-2 text-gray-700 dark:text-gray-300", "{monster.size}" }
+                                        td { class: "px-4 py-2 text-gray-700 dark:text-gray-300", "{monster.armor_class}" }
+                                        td { class: "px-4 py-2 text-gray-700 dark:text-gray-300", "{monster.hit_points}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    if found.page_count() > 1 {
+                        div { class: "flex items-center justify-center gap-4 mt-6",
+                            button {
+                                r#type: "button",
+                                disabled: page() == 0,
+                                onclick: move |_| page -= 1,
+                                class: "px-4 py-2 text-sm bg-blue-600 text-white rounded-lg hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed",
+                                "Previous"
+                            }
+                            span { class: "text-sm text-gray-700 dark:text-gray-300",
+                                "Page {found.page + 1} of {found.page_count()}"
+                            }
+                            button {
+                                r#type: "button",
+                                disabled: found.page + 1 >= found.page_count(),
+                                onclick: move |_| page += 1,
+                                class: "px-4 py-2 text-sm bg-blue-600 text-white rounded-lg hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed",
+                                "Next"
+                            }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// One monster's stat block
+#[component]
+pub fn MonsterDetail(slug: String) -> Element {
+    let monster = use_resource(use_reactive!(|slug| async move { get_monster(slug).await }));
+
+    rsx! {
+        div { class: "max-w-3xl mx-auto py-8 px-4",
+            Link {
+                to: Route::Monsters {},
+                class: "text-blue-600 dark:text-blue-400 hover:underline",
+                "← All monsters"
+            }
+            match &*monster.read() {
+                None => rsx! {
+                    div { class: "flex justify-center py-12",
+                        div {
+                            class: "animate-spin rounded-full h-12 w-12 border-t-2 border-b-2 border-blue-500",
+                            aria_label: "Loading...",
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    div {
+                        class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 my-6",
+                        role: "alert",
+                        p { class: "font-bold", "Error" }
+                        p { "{e}" }
+                    }
+                },
+                Some(Ok(monster)) => rsx! {
+                    div { class: "mt-4",
+                        StatBlock { monster: monster.clone() }
+                    }
+                },
+            }
+        }
+    }
+}
+
+fn bonus_list(bonuses: &[Bonus]) -> String {
+    bonuses.iter().map(|bonus| format!("{} {:+}", bonus.name, bonus.bonus)).collect::<Vec<_>>().join(", ")
+}
+
+// Roll an action's attack and damage, damage first like the dice tray so the attack ends up on top
+fn roll_action(action: &MonsterAction) -> Result<Vec<RolledLine>, String> {
+    let mut rng = rand::thread_rng();
+    match (action.attack_bonus, action.damage.as_deref()) {
+        (Some(bonus), damage) => {
+            let attack = dice::attack(bonus, Advantage::Normal, damage, &mut rng)?;
+            let mut lines = vec![];
+            if let Some(damage) = attack.damage {
+                let label = if attack.to_hit.is_critical() { "damage (critical)" } else { "damage" };
+                lines.push(RolledLine::new(format!("{} {}", action.name, label), damage));
+            }
+            let label = if attack.to_hit.is_fumble() { "attack (miss)" } else { "attack" };
+            lines.push(RolledLine::new(format!("{} {}", action.name, label), attack.to_hit));
+            Ok(lines)
+        }
+        (None, Some(damage)) => Ok(vec![RolledLine::new(format!("{} damage", action.name), dice::roll_with(damage, &mut rng)?)]),
+        (None, None) => Ok(vec![]),
+    }
+}
 
+#[component]
+fn StatLine(label: String, value: String) -> Element {
+    if value.is_empty() {
+        return rsx! {};
+    }
+    rsx! {
+        p {
+            span { class: "font-bold", "{label} " }
+            "{value}"
+        }
+    }
+}
 
+#[component]
+fn ActionList(title: Option<String>, intro: Option<String>, actions: Vec<MonsterAction>, on_roll: Option<EventHandler<RolledLine>>) -> Element {
+    if actions.is_empty() {
+        return rsx! {};
+    }
     rsx! {
-        div { class: "max-w-lg mx-auto py-2",
-            h1 { class: "text-3xl", "Monsters" }
-            p { class: "text-lg text-gray-700 mt-4",
-                "This section will contain detailed information about various monsters, their abilities, and how to use them in your campaigns."
+        if let Some(title) = title {
+            h3 { class: "text-xl font-serif text-red-900 border-b border-red-900 mt-4 mb-2", "{title}" }
+        }
+        if let Some(intro) = intro {
+            p { class: "mb-2", "{intro}" }
+        }
+        for action in actions {
+            p { class: "mb-2",
+                span { class: "font-bold italic", "{action.name}. " }
+                "{action.description}"
+                if let Some(on_roll) = on_roll.filter(|_| action.attack_bonus.is_some() || action.damage.is_some()) {
+                    button {
+                        r#type: "button",
+                        onclick: move |_| {
+                            if let Ok(lines) = roll_action(&action) {
+                                for line in lines {
+                                    on_roll.call(line);
+                                }
+                            }
+                        },
+                        class: "ml-2 px-2 py-0.5 text-xs bg-red-800 text-white rounded hover:bg-red-900",
+                        "Roll"
+                    }
+                }
             }
-            p { class: "text-lg text-gray-700 mt-2",
-                "Stay tuned for updates as we build out this feature to enhance your gameplay experience."
+        }
+    }
+}
+
+/// A classic stat block. With `on_roll`, attacks and damage get buttons that roll them.
+#[component]
+pub fn StatBlock(monster: Monster, on_roll: Option<EventHandler<RolledLine>>) -> Element {
+    let armor = if monster.armor_desc.is_empty() {
+        monster.armor_class.to_string()
+    } else {
+        format!("{} ({})", monster.armor_class, monster.armor_desc)
+    };
+    let legendary_intro = format!(
+        "The {} can take {} legendary actions, choosing from the options below. Only one legendary action option can be used at a time and only at the end of another creature's turn. Spent legendary actions are regained at the start of its turn.",
+        monster.name.to_lowercase(),
+        monster.legendary_actions_per_round,
+    );
+
+    rsx! {
+        div { class: "bg-amber-50 text-gray-900 rounded-lg shadow-lg p-6 border-t-4 border-b-4 border-red-900 text-sm leading-relaxed",
+            h2 { class: "text-3xl font-serif font-bold text-red-900", "{monster.name}" }
+            p { class: "italic", "{monster.kind_line()}" }
+
+            div { class: "border-t-2 border-red-900 my-3" }
+            div { class: "text-red-900",
+                StatLine { label: "Armor Class", value: armor }
+                StatLine { label: "Hit Points", value: format!("{} ({})", monster.hit_points, monster.hit_dice.replace('+', " + ").replace('-', " - ")) }
+                StatLine { label: "Speed", value: monster.speed.to_string() }
             }
-            p { class: "text-lg text-gray-700 mt-2",
-                "For now, refer to the official monster manuals and resources for detailed information on monsters."
+
+            div { class: "border-t-2 border-red-900 my-3" }
+            div { class: "grid grid-cols-6 text-center text-red-900",
+                for ability in Ability::ALL {
+                    div {
+                        p { class: "font-bold", "{ability.abbreviation()}" }
+                        p { "{score_label(monster.score(ability))}" }
+                    }
+                }
             }
-            p { class: "text-lg text-gray-700 mt-2",
-                "If you have any specific questions or need clarification on certain monsters, feel free to reach out through our contact page."
+
+            div { class: "border-t-2 border-red-900 my-3" }
+            div { class: "text-red-900",
+                StatLine { label: "Saving Throws", value: bonus_list(&monster.saving_throws) }
+                StatLine { label: "Skills", value: bonus_list(&monster.skills) }
+                StatLine { label: "Damage Vulnerabilities", value: monster.damage_vulnerabilities.clone() }
+                StatLine { label: "Damage Resistances", value: monster.damage_resistances.clone() }
+                StatLine { label: "Damage Immunities", value: monster.damage_immunities.clone() }
+                StatLine { label: "Condition Immunities", value: monster.condition_immunities.clone() }
+                StatLine { label: "Senses", value: monster.senses.clone() }
+                StatLine { label: "Languages", value: if monster.languages.is_empty() { "—".to_string() } else { monster.languages.clone() } }
+                StatLine { label: "Challenge", value: format!("{} ({} XP)", monster.challenge_label(), monster.experience()) }
+            }
+
+            div { class: "border-t-2 border-red-900 my-3" }
+            ActionList { actions: monster.traits.clone(), on_roll }
+            ActionList { title: "Actions", actions: monster.actions.clone(), on_roll }
+            ActionList { title: "Reactions", actions: monster.reactions.clone(), on_roll }
+            if monster.legendary_actions_per_round > 0 {
+                ActionList {
+                    title: "Legendary Actions",
+                    intro: legendary_intro,
+                    actions: monster.legendary_actions.clone(),
+                    on_roll,
+                }
+            }
+
+            if !monster.environments.is_empty() {
+                p { class: "text-xs text-gray-500 mt-4",
+                    "Found in: "
+                    {monster.environments.iter().map(|environment| environment.to_string()).collect::<Vec<_>>().join(", ")}
+                }
             }
-            p { class: "text-lg text-gray-700 mt-2",
-                "We are continuously working to enhance this section and provide a more detailed and user-friendly experience."
+            div { class: "mt-4",
+                SourceBadge { source: monster.source.clone(), homebrew: monster.pack_id.is_some() }
             }
         }
     }
-} 
\ No newline at end of file
+}
@@ -1,77 +1,160 @@
 use dioxus::prelude::*;
-use serde::{Deserialize, Serialize};
+use crate::api::homebrew::HomebrewKind;
+use crate::api::reference::{get_reference_favorites, search_reference, set_reference_favorite, ReferenceEntry};
+use crate::Route;
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
-pub struct ReferenceItem {
-    id: u32,
-    name: String,
-    category: ReferenceCategory,
-    description: String,
-    source: String,
-    is_favorite: bool,
-    level: Option<u8>, // For spells
-    action_type: Option<String>, // For actions
+const STAR_PATH: &str = "M9.049 2.927c.3-.921 1.603-.921 1.902 0l1.07 3.292a1 1 0 00.95.69h3.462c.969 0 1.371 1.24.588 1.81l-2.8 2.034a1 1 0 00-.364 1.118l1.07 3.292c.3.921-.755 1.688-1.54 1.118l-2.8-2.034a1 1 0 00-1.175 0l-2.8 2.034c-.784.57-1.838-.197-1.539-1.118l1.07-3.292a1 1 0 00-.364-1.118L2.98 8.72c-.783-.57-.38-1.81.588-1.81h3.461a1 1 0 00.951-.69l1.07-3.292z";
+
+// Spells and monsters have pages of their own; items and feats are looked up in their catalogs
+fn entry_route(entry: &ReferenceEntry) -> Route {
+    match entry.kind {
+        HomebrewKind::Spell => Route::SpellDetail { slug: entry.slug.clone() },
+        HomebrewKind::Monster => Route::MonsterDetail { slug: entry.slug.clone() },
+        HomebrewKind::Item => Route::Items {},
+        HomebrewKind::Feat => Route::FeatsAbilities {},
+    }
+}
+
+fn kind_badge_class(kind: HomebrewKind) -> &'static str {
+    match kind {
+        HomebrewKind::Spell => "bg-purple-100 text-purple-800",
+        HomebrewKind::Monster => "bg-red-100 text-red-800",
+        HomebrewKind::Item => "bg-amber-100 text-amber-800",
+        HomebrewKind::Feat => "bg-green-100 text-green-800",
+    }
+}
+
+fn is_pinned(favorites: &[ReferenceEntry], entry: &ReferenceEntry) -> bool {
+    favorites.iter().any(|pin| pin.kind == entry.kind && pin.slug == entry.slug)
+}
+
+/// The signed-in user's pinned reference entries, loaded once, with any error loading them
+pub(crate) fn use_reference_favorites() -> (Signal<Vec<ReferenceEntry>>, Signal<Option<String>>) {
+    let mut favorites = use_signal(Vec::<ReferenceEntry>::new);
+    let mut error = use_signal::<Option<String>>(|| None);
+
+    use_effect(move || {
+        spawn(async move {
+            match get_reference_favorites().await {
+                Ok(fetched) => favorites.set(fetched),
+                Err(e) => error.set(Some(format!("Failed to load favorites: {}", e))),
+            }
+        });
+    });
+
+    (favorites, error)
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
-enum ReferenceCategory {
-    Spell,
-    Feat, 
-    Action,
-    Item,
+/// Pin `entry` when it isn't pinned yet, and unpin it when it is
+pub(crate) fn toggle_favorite(mut favorites: Signal<Vec<ReferenceEntry>>, mut error: Signal<Option<String>>, entry: ReferenceEntry) {
+    let pin = !is_pinned(&favorites.read(), &entry);
+    spawn(async move {
+        match set_reference_favorite(entry.kind, entry.slug.clone(), pin).await {
+            Ok(()) => {
+                let mut list = favorites.write();
+                list.retain(|pinned| !(pinned.kind == entry.kind && pinned.slug == entry.slug));
+                if pin {
+                    list.push(entry);
+                }
+            }
+            Err(e) => error.set(Some(format!("Failed to update favorites: {}", e))),
+        }
+    });
 }
 
+/// The rules a player keeps one click away: their pinned spells, monsters, items and feats
 #[component]
-pub fn QuickReference() -> Element {
-    let mut items = use_signal(|| vec![
-        ReferenceItem {
-            id: 1,
-            name: "Fireball".to_string(),
-            category: ReferenceCategory::Spell,
-            description: "A bright streak flashes from your pointing finger to a point you choose within range, then blossoms with a low roar into an explosion of flame.".to_string(),
-            source: "PHB p.241".to_string(),
-            is_favorite: false,
-            level: Some(3),
-            action_type: None,
-        },
-        ReferenceItem {
-            id: 2,
-            name: "Shield Master".to_string(),
-            category: ReferenceCategory::Feat,
-            description: "You use shields not just for protection but also for offense.".to_string(),
-            source: "PHB p.170".to_string(),
-            is_favorite: true,
-            level: None,
-            action_type: None,
-        },
-        ReferenceItem {
-            id: 3,
-            name: "Help".to_string(),
-            category: ReferenceCategory::Action,
-            description: "You can lend your aid to another creature in the completion of a task.".to_string(),
-            source: "PHB p.192".to_string(),
-            is_favorite: false,
-            level: None,
-            action_type: Some("Action".to_string()),
-        },
-    ]);
+pub(crate) fn PinnedReferences(favorites: Signal<Vec<ReferenceEntry>>, error: Signal<Option<String>>) -> Element {
+    rsx! {
+        div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg",
+            div { class: "flex items-center justify-between mb-4",
+                h2 { class: "text-xl font-semibold text-gray-900 dark:text-white", "Pinned Rules" }
+                Link {
+                    to: Route::QuickReference {},
+                    class: "text-sm text-blue-600 dark:text-blue-400 hover:underline",
+                    "Quick Reference"
+                }
+            }
+            if let Some(err) = error.read().as_ref() {
+                p { class: "text-sm text-red-700 mb-2", "{err}" }
+            }
+            if favorites.read().is_empty() {
+                p { class: "text-sm text-gray-600 dark:text-gray-400",
+                    "Star spells, monsters, items and feats in the Quick Reference to keep them here."
+                }
+            } else {
+                ul { class: "space-y-2",
+                    for entry in favorites.read().iter().cloned() {
+                        li { key: "{entry.kind.name()}-{entry.slug}",
+                            details { class: "group",
+                                summary { class: "flex items-center gap-2 cursor-pointer list-none",
+                                    span { class: "px-2 text-xs leading-5 font-semibold rounded-full {kind_badge_class(entry.kind)}",
+                                        "{entry.kind.name()}"
+                                    }
+                                    span { class: "flex-1 font-medium text-gray-900 dark:text-white", "{entry.name}" }
+                                    button {
+                                        r#type: "button",
+                                        class: "text-yellow-500 hover:text-gray-400",
+                                        title: "Unpin",
+                                        onclick: {
+                                            let entry = entry.clone();
+                                            move |e: MouseEvent| {
+                                                e.prevent_default();
+                                                toggle_favorite(favorites, error, entry.clone());
+                                            }
+                                        },
+                                        "★"
+                                    }
+                                }
+                                div { class: "mt-1 ml-2 pl-2 border-l-2 border-gray-200 dark:border-gray-700 text-sm",
+                                    p { class: "italic text-gray-600 dark:text-gray-400", "{entry.summary}" }
+                                    p { class: "text-gray-700 dark:text-gray-300 whitespace-pre-line mt-1", "{entry.description}" }
+                                    Link {
+                                        to: entry_route(&entry),
+                                        class: "text-blue-600 dark:text-blue-400 hover:underline",
+                                        "Full entry"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
 
-    let mut search_query = use_signal(|| String::new());
-    let mut selected_category = use_signal(|| None::<ReferenceCategory>);
+/// Search every reference catalog at once and pin the entries worth keeping at hand
+#[component]
+pub fn QuickReference() -> Element {
+    let (favorites, favorites_error) = use_reference_favorites();
+    let mut search_query = use_signal(String::new);
+    let mut selected_category = use_signal(|| None::<HomebrewKind>);
     let mut sort_by = use_signal(|| "name" as &'static str);
 
+    let results = use_resource(move || {
+        let query = search_query();
+        let kind = selected_category();
+        async move { search_reference(query, kind).await }
+    });
+
     rsx! {
         div { class: "max-w-6xl mx-auto py-6 px-4",
             h1 { class: "text-4xl font-bold text-purple-900 mb-6", "Quick Reference" }
+
+            div { class: "mb-6",
+                PinnedReferences { favorites, error: favorites_error }
+            }
+
             // Search and Filter Bar
             div { class: "grid grid-cols-1 md:grid-cols-3 gap-4 mb-6",
                 // Search Input
                 div {
                     input {
                         class: "w-full p-3 border border-gray-300 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent",
-                        placeholder: "Search spells, feats, actions...",
+                        placeholder: "Search spells, monsters, items, feats...",
                         value: "{search_query}",
-                        oninput: move |e| search_query.set(e.value().clone()),
+                        oninput: move |e| search_query.set(e.value()),
                     }
                 }
                 // Category Filter
@@ -80,22 +163,12 @@ pub fn QuickReference() -> Element {
                         class: "w-full p-3 border border-gray-300 rounded-lg focus:ring-2 focus:ring-purple-500 focus:border-transparent",
                         onchange: move |e| {
                             let value = e.value();
-                            selected_category
-                                .set(
-                                    match value.as_str() {
-                                        "spell" => Some(ReferenceCategory::Spell),
-                                        "feat" => Some(ReferenceCategory::Feat),
-                                        "action" => Some(ReferenceCategory::Action),
-                                        "item" => Some(ReferenceCategory::Item),
-                                        _ => None,
-                                    },
-                                )
+                            selected_category.set(HomebrewKind::ALL.iter().copied().find(|kind| kind.name() == value));
                         },
                         option { value: "", "All Categories" }
-                        option { value: "spell", "Spells" }
-                        option { value: "feat", "Feats" }
-                        option { value: "action", "Actions" }
-                        option { value: "item", "Items" }
+                        for kind in HomebrewKind::ALL.iter() {
+                            option { value: "{kind.name()}", "{kind.plural()}" }
+                        }
                     }
                 }
                 // Sort Options
@@ -106,6 +179,7 @@ pub fn QuickReference() -> Element {
                             sort_by
                                 .set(
                                     match e.value().as_str() {
+                                        "category" => "category",
                                         "level" => "level",
                                         "source" => "source",
                                         _ => "name",
@@ -113,168 +187,159 @@ pub fn QuickReference() -> Element {
                                 )
                         },
                         option { value: "name", "Sort by Name" }
+                        option { value: "category", "Sort by Category" }
                         option { value: "level", "Sort by Level" }
                         option { value: "source", "Sort by Source" }
                     }
                 }
             }
 
-            // Reference Table
-            div { class: "bg-white rounded-lg shadow overflow-hidden",
-                table { class: "min-w-full divide-y divide-gray-200",
-                    thead { class: "bg-gray-50",
-                        tr {
-                            th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
-                                "Name"
-                            }
-                            th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
-                                "Category"
-                            }
-                            th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
-                                "Level"
-                            }
-                            th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
-                                "Source"
-                            }
-                            th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
-                                "Actions"
-                            }
+            match &*results.read() {
+                None => rsx! {
+                    div { class: "flex justify-center py-12",
+                        div {
+                            class: "animate-spin rounded-full h-12 w-12 border-t-2 border-b-2 border-blue-500",
+                            aria_label: "Loading...",
                         }
                     }
-                    tbody { class: "bg-white divide-y divide-gray-200",
-                        for item in items
-                            .read()
-                            .iter()
-                            .filter(|item| {
-                                let matches_search = search_query.read().is_empty()
-                                    || item.name.to_lowercase().contains(&search_query.read().to_lowercase())
-                                    || item
-                                        .description
-                                        .to_lowercase()
-                                        .contains(&search_query.read().to_lowercase());
-                                let matches_category = match selected_category.read().as_ref() {
-                                    Some(cat) => item.category == *cat,
-                                    None => true,
-                                };
-                                matches_search && matches_category
-                            })
-                            .cloned()
-                            .collect::<Vec<_>>()
-                            .sorted_by(|a, b| match *sort_by.read() {
-                                "level" => a.level.cmp(&b.level),
-                                "source" => a.source.cmp(&b.source),
-                                _ => a.name.cmp(&b.name),
-                            })
-                        {
-                            tr { class: "hover:bg-gray-50",
-                                td { class: "px-6 py-4 whitespace-nowrap",
-                                    div { class: "flex items-center",
-                                        div { class: "flex-shrink-0 h-10 w-10",
-                                            if item.is_favorite {
-                                                svg {
-                                                    class: "h-5 w-5 text-yellow-400",
-                                                    xmlns: "http://www.w3.org/2000/svg",
-                                                    view_box: "0 0 20 20",
-                                                    fill: "currentColor",
-                                                    path { d: "M9.049 2.927c.3-.921 1.603-.921 1.902 0l1.07 3.292a1 1 0 00.95.69h3.462c.969 0 1.371 1.24.588 1.81l-2.8 2.034a1 1 0 00-.364 1.118l1.07 3.292c.3.921-.755 1.688-1.54 1.118l-2.8-2.034a1 1 0 00-1.175 0l-2.8 2.034c-.784.57-1.838-.197-1.539-1.118l1.07-3.292a1 1 0 00-.364-1.118L2.98 8.72c-.783-.57-.38-1.81.588-1.81h3.461a1 1 0 00.951-.69l1.07-3.292z" }
-                                                }
-                                            }
-                                            div { class: "ml-4",
-                                                div { class: "text-sm font-medium text-gray-900",
-                                                    {item.name.clone()}
-                                                }
-                                                div { class: "text-sm text-gray-500 truncate max-w-xs",
-                                                    {format!("{}...", item.description.chars().take(50).collect::<String>())}
-                                                }
-                                            }
+                },
+                Some(Err(e)) => rsx! {
+                    div {
+                        class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6",
+                        role: "alert",
+                        p { class: "font-bold", "Error" }
+                        p { "{e}" }
+                    }
+                },
+                Some(Ok(found)) => {
+                    let mut found = found.clone();
+                    match sort_by() {
+                        "category" => found.sort_by(|a, b| a.kind.name().cmp(b.kind.name()).then_with(|| a.name.cmp(&b.name))),
+                        "level" => found.sort_by(|a, b| a.level.cmp(&b.level).then_with(|| a.name.cmp(&b.name))),
+                        "source" => found.sort_by(|a, b| a.source.cmp(&b.source).then_with(|| a.name.cmp(&b.name))),
+                        _ => {}
+                    }
+                    let count = |kind: HomebrewKind| found.iter().filter(|entry| entry.kind == kind).count();
+                    let (spells, monsters, items, feats) =
+                        (count(HomebrewKind::Spell), count(HomebrewKind::Monster), count(HomebrewKind::Item), count(HomebrewKind::Feat));
+                    rsx! {
+                        // Reference Table
+                        div { class: "bg-white rounded-lg shadow overflow-hidden",
+                            table { class: "min-w-full divide-y divide-gray-200",
+                                thead { class: "bg-gray-50",
+                                    tr {
+                                        th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                            "Name"
+                                        }
+                                        th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                            "Category"
+                                        }
+                                        th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                            "Level"
+                                        }
+                                        th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                            "Source"
+                                        }
+                                        th { class: "px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider",
+                                            "Actions"
                                         }
                                     }
                                 }
-                                td { class: "px-6 py-4 whitespace-nowrap",
-                                    span { class: "px-2 inline-flex text-xs leading-5 font-semibold rounded-full bg-green-100 text-green-800",
-                                        match item.category {
-                                            ReferenceCategory::Spell => "Spell",
-                                            ReferenceCategory::Feat => "Feat",
-                                            ReferenceCategory::Action => "Action",
-                                            ReferenceCategory::Item => "Item",
+                                tbody { class: "bg-white divide-y divide-gray-200",
+                                    if found.is_empty() {
+                                        tr {
+                                            td { class: "px-6 py-8 text-center text-gray-500", colspan: "5", "Nothing matches this search." }
                                         }
                                     }
-                                }
-                                td { class: "px-6 py-4 whitespace-nowrap text-sm text-gray-500",
-                                    {item.level.map_or("-".to_string(), |l| l.to_string())}
-                                }
-                                td { class: "px-6 py-4 whitespace-nowrap text-sm text-gray-500",
-                                    {item.source.clone()}
-                                }
-                                td { class: "px-6 py-4 whitespace-nowrap text-right text-sm font-medium",
-                                    button {
-                                        class: "text-purple-600 hover:text-purple-900 mr-3",
-                                        onclick: move |_| {
-                                            let mut items = items.write();
-                                            if let Some(item) = items.iter_mut().find(|i| i.id == item.id) {
-                                                item.is_favorite = !item.is_favorite;
+                                    for entry in found.iter().cloned() {
+                                        tr { key: "{entry.kind.name()}-{entry.slug}", class: "hover:bg-gray-50",
+                                            td { class: "px-6 py-4",
+                                                div { class: "flex items-center",
+                                                    div { class: "flex-shrink-0 h-5 w-5",
+                                                        if is_pinned(&favorites.read(), &entry) {
+                                                            svg {
+                                                                class: "h-5 w-5 text-yellow-400",
+                                                                xmlns: "http://www.w3.org/2000/svg",
+                                                                view_box: "0 0 20 20",
+                                                                fill: "currentColor",
+                                                                path { d: STAR_PATH }
+                                                            }
+                                                        }
+                                                    }
+                                                    div { class: "ml-4",
+                                                        div { class: "text-sm font-medium text-gray-900", "{entry.name}" }
+                                                        div { class: "text-sm text-gray-500 truncate max-w-xs", "{entry.summary}" }
+                                                    }
+                                                }
+                                            }
+                                            td { class: "px-6 py-4 whitespace-nowrap",
+                                                span { class: "px-2 inline-flex text-xs leading-5 font-semibold rounded-full {kind_badge_class(entry.kind)}",
+                                                    "{entry.kind.name()}"
+                                                }
+                                            }
+                                            td { class: "px-6 py-4 whitespace-nowrap text-sm text-gray-500",
+                                                {entry.level.map_or("-".to_string(), |level| level.to_string())}
+                                            }
+                                            td { class: "px-6 py-4 whitespace-nowrap text-sm text-gray-500",
+                                                if entry.homebrew {
+                                                    span { class: "text-emerald-700", "{entry.source}" }
+                                                } else {
+                                                    "{entry.source}"
+                                                }
+                                            }
+                                            td { class: "px-6 py-4 whitespace-nowrap text-right text-sm font-medium",
+                                                button {
+                                                    class: "text-purple-600 hover:text-purple-900 mr-3",
+                                                    onclick: {
+                                                        let entry = entry.clone();
+                                                        move |_| toggle_favorite(favorites, favorites_error, entry.clone())
+                                                    },
+                                                    if is_pinned(&favorites.read(), &entry) {
+                                                        "★ Unfavorite"
+                                                    } else {
+                                                        "☆ Favorite"
+                                                    }
+                                                }
+                                                Link { to: entry_route(&entry), class: "text-blue-600 hover:text-blue-900",
+                                                    "View Details"
+                                                }
                                             }
-                                        },
-                                        if item.is_favorite {
-                                            "★ Unfavorite"
-                                        } else {
-                                            "☆ Favorite"
                                         }
                                     }
-                                    button { class: "text-blue-600 hover:text-blue-900",
-                                        "View Details"
-                                    }
                                 }
                             }
                         }
-                    }
-                }
-            }
 
-            // Quick Stats
-            div { class: "mt-6 grid grid-cols-1 md:grid-cols-4 gap-4",
-                div { class: "bg-blue-50 p-4 rounded-lg",
-                    h3 { class: "text-lg font-medium text-blue-800", "Total Entries" }
-                    p { class: "text-2xl font-bold", "{items.read().len()}" }
-                }
-                div { class: "bg-green-50 p-4 rounded-lg",
-                    h3 { class: "text-lg font-medium text-green-800", "Spells" }
-                    p { class: "text-2xl font-bold",
-                        "{items.read().iter().filter(|i| matches!(i.category, ReferenceCategory::Spell)).count()}"
-                    }
-                }
-                div { class: "bg-yellow-50 p-4 rounded-lg",
-                    h3 { class: "text-lg font-medium text-yellow-800", "Feats" }
-                    p { class: "text-2xl font-bold",
-                        "{items.read().iter().filter(|i| matches!(i.category, ReferenceCategory::Feat)).count()}"
-                    }
-                }
-                div { class: "bg-purple-50 p-4 rounded-lg",
-                    h3 { class: "text-lg font-medium text-purple-800", "Favorites" }
-                    p { class: "text-2xl font-bold",
-                        "{items.read().iter().filter(|i| i.is_favorite).count()}"
+                        // Quick Stats
+                        div { class: "mt-6 grid grid-cols-2 md:grid-cols-6 gap-4",
+                            div { class: "bg-blue-50 p-4 rounded-lg",
+                                h3 { class: "text-lg font-medium text-blue-800", "Total Entries" }
+                                p { class: "text-2xl font-bold", "{found.len()}" }
+                            }
+                            div { class: "bg-purple-50 p-4 rounded-lg",
+                                h3 { class: "text-lg font-medium text-purple-800", "Spells" }
+                                p { class: "text-2xl font-bold", "{spells}" }
+                            }
+                            div { class: "bg-red-50 p-4 rounded-lg",
+                                h3 { class: "text-lg font-medium text-red-800", "Monsters" }
+                                p { class: "text-2xl font-bold", "{monsters}" }
+                            }
+                            div { class: "bg-amber-50 p-4 rounded-lg",
+                                h3 { class: "text-lg font-medium text-amber-800", "Items" }
+                                p { class: "text-2xl font-bold", "{items}" }
+                            }
+                            div { class: "bg-green-50 p-4 rounded-lg",
+                                h3 { class: "text-lg font-medium text-green-800", "Feats" }
+                                p { class: "text-2xl font-bold", "{feats}" }
+                            }
+                            div { class: "bg-yellow-50 p-4 rounded-lg",
+                                h3 { class: "text-lg font-medium text-yellow-800", "Favorites" }
+                                p { class: "text-2xl font-bold", "{favorites.read().len()}" }
+                            }
+                        }
                     }
                 }
             }
         }
     }
 }
-
-// Helper trait for sorting
-trait Sortable {
-    type Item;
-    fn sorted_by<F>(self, compare: F) -> Self
-    where
-        F: FnMut(&Self::Item, &Self::Item) -> std::cmp::Ordering,
-        Self: Sized;
-}
-impl<T> Sortable for Vec<T> {
-    type Item = T;
-    fn sorted_by<F>(mut self, mut compare: F) -> Self
-    where
-        F: FnMut(&T, &T) -> std::cmp::Ordering,
-    {
-        self.sort_by(compare);
-        self
-    }
-}   
@@ -0,0 +1,26 @@
+use dioxus::prelude::*;
+
+/// `(value, label)` pairs for a `SelectInput` over a filter's `homebrew` flag
+pub(crate) fn source_options() -> Vec<(String, String)> {
+    vec![
+        (String::new(), "Any source".to_string()),
+        ("false".to_string(), "SRD only".to_string()),
+        ("true".to_string(), "Homebrew only".to_string()),
+    ]
+}
+
+pub(crate) fn source_value(homebrew: Option<bool>) -> String {
+    homebrew.map(|homebrew| homebrew.to_string()).unwrap_or_default()
+}
+
+/// Where a catalog entry comes from: the SRD, or the homebrew pack it was written in
+#[component]
+pub(crate) fn SourceBadge(source: String, homebrew: bool) -> Element {
+    let colors = if homebrew { "bg-emerald-100 text-emerald-800" } else { "bg-gray-100 text-gray-600" };
+    rsx! {
+        span { class: "px-2 py-0.5 text-xs rounded-full font-medium whitespace-nowrap {colors}",
+            title: if homebrew { "Homebrew" } else { "System Reference Document" },
+            "{source}"
+        }
+    }
+}
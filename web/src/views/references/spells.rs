@@ -1,29 +1,268 @@
 
 use dioxus::prelude::*;
+use crate::api::spells::{get_spell, list_spells, spell_level_label, Spell, SpellFilter};
+use crate::components::SelectInput;
+use crate::rules::{Class, MagicSchool};
+use super::source::{source_options, source_value, SourceBadge};
+use crate::Route;
 
+const FILTER_CLASS: &str = "px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg dark:bg-gray-700 dark:text-white";
 
+// `(value, label)` pairs with an "any" choice in front, for filters that can be left unset
+fn with_any(any: &str, options: Vec<(String, String)>) -> Vec<(String, String)> {
+    std::iter::once((String::new(), any.to_string())).chain(options).collect()
+}
 
+fn flag_options(label: &str) -> Vec<(String, String)> {
+    with_any(label, vec![("true".to_string(), "Yes".to_string()), ("false".to_string(), "No".to_string())])
+}
 
+fn flag_value(flag: Option<bool>) -> String {
+    flag.map(|flag| flag.to_string()).unwrap_or_default()
+}
+
+/// The spell catalog, narrowed down by name, class, level, school, concentration, ritual and source
 #[component]
 pub fn Spells() -> Element {
+    let mut filter = use_signal(SpellFilter::default);
+    let mut page = use_signal(|| 0);
+    let spells = use_resource(move || {
+        let filter = filter();
+        let page = page();
+        async move { list_spells(filter, page).await }
+    });
+
+    // Any change to the filter starts over from the first page
+    let mut narrow = move |change: Box<dyn FnOnce(&mut SpellFilter)>| {
+        change(&mut filter.write());
+        page.set(0);
+    };
+
+    let level_options = with_any(
+        "Any level",
+        (0..=9).map(|level| (level.to_string(), spell_level_label(level))).collect(),
+    );
+
+    rsx! {
+        div { class: "max-w-6xl mx-auto py-8 px-4",
+            h1 { class: "text-3xl font-bold text-gray-900 dark:text-white", "Spells" }
+            p { class: "text-gray-600 dark:text-gray-400 mt-1",
+                "Spells from the System Reference Document 5.1, plus homebrew from your packs and campaigns."
+            }
+
+            div { class: "grid grid-cols-2 md:grid-cols-4 lg:grid-cols-8 gap-3 mt-6",
+                input {
+                    r#type: "search",
+                    value: filter.read().query.clone(),
+                    oninput: move |e| narrow(Box::new(move |f| f.query = e.value())),
+                    class: "{FILTER_CLASS} col-span-2 md:col-span-4 lg:col-span-8",
+                    placeholder: "Search spells by name",
+                }
+                SelectInput {
+                    i_value: filter.read().class.map(|class| class.to_string()).unwrap_or_default(),
+                    on_input: move |e: FormEvent| narrow(Box::new(move |f| f.class = Class::from_name(&e.value()))),
+                    class: FILTER_CLASS,
+                    options: with_any("Any class", Class::options()),
+                }
+                SelectInput {
+                    i_value: filter.read().level.map(|level| level.to_string()).unwrap_or_default(),
+                    on_input: move |e: FormEvent| narrow(Box::new(move |f| f.level = e.value().parse().ok())),
+                    class: FILTER_CLASS,
+                    options: level_options,
+                }
+                SelectInput {
+                    i_value: filter.read().school.map(|school| school.to_string()).unwrap_or_default(),
+                    on_input: move |e: FormEvent| narrow(Box::new(move |f| f.school = MagicSchool::from_name(&e.value()))),
+                    class: FILTER_CLASS,
+                    options: with_any("Any school", MagicSchool::options()),
+                }
+                SelectInput {
+                    i_value: flag_value(filter.read().concentration),
+                    on_input: move |e: FormEvent| narrow(Box::new(move |f| f.concentration = e.value().parse().ok())),
+                    class: FILTER_CLASS,
+                    options: flag_options("Concentration?"),
+                }
+                SelectInput {
+                    i_value: flag_value(filter.read().ritual),
+                    on_input: move |e: FormEvent| narrow(Box::new(move |f| f.ritual = e.value().parse().ok())),
+                    class: FILTER_CLASS,
+                    options: flag_options("Ritual?"),
+                }
+                SelectInput {
+                    i_value: source_value(filter.read().homebrew),
+                    on_input: move |e: FormEvent| narrow(Box::new(move |f| f.homebrew = e.value().parse().ok())),
+                    class: FILTER_CLASS,
+                    options: source_options(),
+                }
+                button {
+                    r#type: "button",
+                    onclick: move |_| narrow(Box::new(|f| *f = SpellFilter::default())),
+                    class: "px-3 py-2 text-sm text-gray-700 dark:text-gray-300 border border-gray-300 dark:border-gray-600 rounded-lg hover:bg-gray-100 dark:hover:bg-gray-700",
+                    "Clear filters"
+                }
+            }
+
+            match &*spells.read() {
+                None => rsx! {
+                    div { class: "flex justify-center py-12",
+                        div {
+                            class: "animate-spin rounded-full h-12 w-12 border-t-2 border-b-2 border-blue-500",
+                            aria_label: "Loading...",
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    div {
+                        class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 my-6",
+                        role: "alert",
+                        p { class: "font-bold", "Error" }
+                        p { "{e}" }
+                    }
+                },
+                Some(Ok(found)) if found.spells.is_empty() => rsx! {
+                    p { class: "text-gray-500 dark:text-gray-400 py-12 text-center", "No spells match these filters." }
+                },
+                Some(Ok(found)) => rsx! {
+                    p { class: "text-sm text-gray-500 dark:text-gray-400 mt-6 mb-3",
+                        "{found.total} spells"
+                    }
+                    div { class: "grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-4",
+                        for spell in found.spells.iter() {
+                            SpellCard { key: "{spell.slug}", spell: spell.clone() }
+                        }
+                    }
+                    if found.page_count() > 1 {
+                        div { class: "flex items-center justify-center gap-4 mt-6",
+                            button {
+                                r#type: "button",
+                                disabled: page() == 0,
+                                onclick: move |_| page -= 1,
+                                class: "px-4 py-2 text-sm bg-blue-600 text-white rounded-lg hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed",
+                                "Previous"
+                            }
+                            span { class: "text-sm text-gray-700 dark:text-gray-300",
+                                "Page {found.page + 1} of {found.page_count()}"
+                            }
+                            button {
+                                r#type: "button",
+                                disabled: found.page + 1 >= found.page_count(),
+                                onclick: move |_| page += 1,
+                                class: "px-4 py-2 text-sm bg-blue-600 text-white rounded-lg hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed",
+                                "Next"
+                            }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
 
+fn school_line(spell: &Spell) -> String {
+    let mut line = if spell.level == 0 {
+        format!("{} cantrip", spell.school)
+    } else {
+        format!("{} {}", spell.level_label(), spell.school)
+    };
+    if spell.ritual {
+        line.push_str(" (ritual)");
+    }
+    line
+}
 
+#[component]
+fn SpellCard(spell: Spell) -> Element {
     rsx! {
-        div { class: "max-w-lg mx-auto py-2",
-            h1 { class: "text-3xl", "Spells" }
-            p { class: "text-lg text-gray-700 mt-4",
-                "This section will contain detailed information about various spells, their effects, and how to use them in your campaigns."
+        Link {
+            to: Route::SpellDetail { slug: spell.slug.clone() },
+            class: "block bg-white dark:bg-gray-800 rounded-lg p-4 shadow hover:shadow-lg transition-shadow",
+            div { class: "flex items-start justify-between gap-2",
+                h2 { class: "text-lg font-semibold text-gray-900 dark:text-white", "{spell.name}" }
+                div { class: "flex gap-1",
+                    if spell.pack_id.is_some() {
+                        SourceBadge { source: spell.source.clone(), homebrew: true }
+                    }
+                    if spell.concentration {
+                        span { class: "px-2 py-0.5 text-xs rounded-full font-medium bg-purple-100 text-purple-800", "C" }
+                    }
+                }
             }
-            p { class: "text-lg text-gray-700 mt-2",
-                "Stay tuned for updates as we build out this feature to enhance your gameplay experience."
+            p { class: "text-sm italic text-gray-600 dark:text-gray-400", "{school_line(&spell)}" }
+            p { class: "text-xs text-gray-500 dark:text-gray-400 mt-1",
+                "{spell.casting_time} • {spell.range} • {spell.duration}"
             }
-            p { class: "text-lg text-gray-700 mt-2",
-                "For now, refer to the official spell compendiums and resources for detailed information on spells."
+            p { class: "text-sm text-gray-700 dark:text-gray-300 mt-2 line-clamp-3", "{spell.description}" }
+        }
+    }
+}
+
+/// One spell from the shared catalog, linked from character spellbooks
+#[component]
+pub fn SpellDetail(slug: String) -> Element {
+    let spell = use_resource(use_reactive!(|slug| async move { get_spell(slug).await }));
+
+    rsx! {
+        div { class: "max-w-3xl mx-auto py-8",
+            Link {
+                to: Route::Spells {},
+                class: "text-blue-600 dark:text-blue-400 hover:underline",
+                "← All spells"
             }
-            p { class: "text-lg text-gray-700 mt-2",
-                "If you have any specific questions or need clarification on certain spells, feel free to reach
-                out through our contact page."
+            match &*spell.read() {
+                None => rsx! {
+                    div { class: "flex justify-center py-12",
+                        div {
+                            class: "animate-spin rounded-full h-12 w-12 border-t-2 border-b-2 border-blue-500",
+                            aria_label: "Loading...",
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    div {
+                        class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 my-6",
+                        role: "alert",
+                        p { class: "font-bold", "Error" }
+                        p { "{e}" }
+                    }
+                },
+                Some(Ok(spell)) => rsx! {
+                    div { class: "bg-white dark:bg-gray-800 rounded-lg p-6 shadow-lg mt-4",
+                        h1 { class: "text-3xl font-bold text-gray-900 dark:text-white", "{spell.name}" }
+                        p { class: "italic text-gray-600 dark:text-gray-400 mt-1", "{school_line(spell)}" }
+                        div { class: "flex flex-wrap gap-2 mt-3",
+                            SourceBadge { source: spell.source.clone(), homebrew: spell.pack_id.is_some() }
+                            if spell.concentration {
+                                span { class: "px-2 py-0.5 text-xs rounded-full font-medium bg-purple-100 text-purple-800", "Concentration" }
+                            }
+                            for class in spell.classes.iter() {
+                                span { class: "px-2 py-0.5 text-xs rounded-full font-medium bg-gray-100 text-gray-700", "{class}" }
+                            }
+                        }
+                        dl { class: "grid grid-cols-[auto_1fr] gap-x-4 gap-y-1 mt-4 text-sm",
+                            dt { class: "font-semibold text-gray-900 dark:text-white", "Casting Time" }
+                            dd { class: "text-gray-700 dark:text-gray-300", "{spell.casting_time}" }
+                            dt { class: "font-semibold text-gray-900 dark:text-white", "Range" }
+                            dd { class: "text-gray-700 dark:text-gray-300", "{spell.range}" }
+                            dt { class: "font-semibold text-gray-900 dark:text-white", "Components" }
+                            dd { class: "text-gray-700 dark:text-gray-300", "{spell.components}" }
+                            dt { class: "font-semibold text-gray-900 dark:text-white", "Duration" }
+                            dd { class: "text-gray-700 dark:text-gray-300", "{spell.duration}" }
+                        }
+                        p { class: "text-gray-700 dark:text-gray-300 mt-4 whitespace-pre-line", "{spell.description}" }
+                        if !spell.higher_levels.is_empty() {
+                            p { class: "text-gray-700 dark:text-gray-300 mt-3",
+                                span { class: "font-semibold italic",
+                                    if spell.level == 0 { "Cantrip Upgrade. " } else { "At Higher Levels. " }
+                                }
+                                "{spell.higher_levels}"
+                            }
+                        }
+                        if spell.pack_id.is_some() {
+                            p { class: "text-xs text-gray-500 mt-4", "Homebrew from {spell.source}, version {spell.version}" }
+                        }
+                    }
+                },
             }
         }
     }
-} 
\ No newline at end of file
+}
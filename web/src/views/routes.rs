@@ -1,35 +1,125 @@
 use crate::Route;
-use dioxus::prelude::{Router as DRouter, *};
+use dioxus::prelude::*;
 use crate::api::auth::get_user;
+use crate::api::campaign::{get_campaign_role, CampaignRole};
+use crate::views::campaigns::route_campaign_slug;
 
 
-/// Register the protected state of routes here
-fn is_guarded(current: Route) -> bool {
-    match current {
-        Route::Protected {} => true,
-        _ => false,
+/// Who may open a route. Checked by [`use_route_guard`] whenever the route changes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Guard {
+    /// Anyone, signed in or not
+    Public,
+    /// Only visitors who are not signed in, e.g. the login page
+    Anonymous,
+    /// Any signed-in user
+    Authenticated,
+    /// Signed-in users holding at least this role in the route's campaign
+    Campaign(CampaignRole),
+}
+
+/// Register the guard of each route here. There is deliberately no catch-all, so a new
+/// route doesn't build until it has a guard.
+pub fn route_guard(route: &Route) -> Guard {
+    match route {
+        Route::Home {}
+        | Route::About {}
+        | Route::Contact {}
+        | Route::Projects {}
+        | Route::Classes {}
+        | Route::CoreRules {}
+        | Route::FeatsAbilities {}
+        | Route::Monsters {}
+        | Route::MonsterDetail { .. }
+        | Route::Spells {}
+        | Route::SpellDetail { .. }
+        | Route::Items {}
+        | Route::Callback {} => Guard::Public,
+
+        Route::Login {} => Guard::Anonymous,
+
+        Route::Protected {}
+        | Route::Character {}
+        | Route::CharacterById { .. }
+        | Route::CharacterCreate {}
+        | Route::CharacterEdit { .. }
+        | Route::PlayerLoot { .. }
+        | Route::CharacterSpellbook { .. }
+        | Route::Campaigns {}
+        | Route::Search { .. }
+        | Route::QuickReference {}
+        | Route::Homebrew {}
+        | Route::HomebrewPackView { .. } => Guard::Authenticated,
+
+        Route::CampaignDashboard { .. }
+        | Route::AdventureLog { .. }
+        | Route::Lore { .. }
+        | Route::MapLocations { .. }
+        | Route::NpcView { .. }
+        | Route::CampaignRolls { .. } => Guard::Campaign(CampaignRole::Member),
+
+        Route::Encounters { .. }
+        | Route::CombatTracker { .. }
+        | Route::DMTools { .. } => Guard::Campaign(CampaignRole::Dm),
     }
 }
 
-#[component]
-pub fn Router() -> Element {
-    rsx! {
-        DRouter::<Route> {
-            config: || {
-                RouterConfig::default()
-                    .on_update(|state| {
-                        if is_guarded(state.current()) {
-                            on_not_authorized(move |_| {
-                                GuardContext::set_next(state.current());
-                            });
-                        }
-                        None
-                    })
-            },
+/// Check `route` against its guard, redirecting when the visitor may not see it.
+/// Returns whether the route may be rendered; it stays false while the check is running.
+pub fn use_route_guard(route: Route) -> Signal<bool> {
+    let mut allowed = use_signal(|| route_guard(&route) == Guard::Public);
+
+    use_effect(use_reactive!(|route| {
+        let guard = route_guard(&route);
+        allowed.set(guard == Guard::Public);
+        if guard == Guard::Public {
+            return;
+        }
+
+        spawn(async move {
+            match check_guard(guard, &route).await {
+                None => allowed.set(true),
+                Some(redirect) => {
+                    // Send them back here once they have signed in
+                    if redirect == (Route::Login {}) {
+                        GuardContext::set_next(route);
+                    }
+                    navigator().replace(redirect);
+                }
+            }
+        });
+    }));
+
+    allowed
+}
+
+/// Where to send the visitor instead, or `None` if `guard` lets them through
+async fn check_guard(guard: Guard, route: &Route) -> Option<Route> {
+    let user = get_user().await;
+    match guard {
+        Guard::Public => None,
+        Guard::Anonymous => user.is_some().then_some(Route::Home {}),
+        Guard::Authenticated => user.is_none().then_some(Route::Login {}),
+        Guard::Campaign(needed) => {
+            if user.is_none() {
+                return Some(Route::Login {});
+            }
+            // A campaign guard on a route without a campaign can't be satisfied
+            let Some(campaign_slug) = route_campaign_slug(route) else {
+                return Some(Route::Campaigns {});
+            };
+            match get_campaign_role(campaign_slug.clone()).await {
+                Ok(Some(role)) if role >= needed => None,
+                // Players who wander onto a DM page go back to the campaign they are in
+                Ok(Some(_)) => Some(Route::CampaignDashboard { campaign_slug }),
+                _ => Some(Route::Campaigns {}),
+            }
         }
     }
 }
 
+/// Remembers the route a signed-out visitor was heading to, so login can send them back.
+/// Provided as a `Signal<GuardContext>` at the root of the app.
 #[derive(Default)]
 pub struct GuardContext {
     next: Option<Route>,
@@ -37,13 +127,13 @@ pub struct GuardContext {
 
 impl GuardContext {
     pub fn set_next(next: Route) {
-        let mut guard = use_context::<Signal<GuardContext>>();
+        let mut guard = consume_context::<Signal<GuardContext>>();
         guard.write().next = Some(next);
     }
 
     pub fn redirect_next_or_home() {
         let nav = navigator();
-        let mut guard = use_context::<Signal<GuardContext>>();
+        let mut guard = consume_context::<Signal<GuardContext>>();
         let next_maybe = guard.write().next.take();
         if let Some(next) = next_maybe {
             nav.push(next);
@@ -53,29 +143,5 @@ impl GuardContext {
     }
 }
 
-fn on_not_authorized<F>(f: F)
-where
-    F: Fn(()) + 'static,
-{
-    spawn(async move {
-        let user = get_user().await;
-        if user.is_none() {
-            f(());
-        }
-    });
-}
-
-/// Declare a page view protected
-pub fn protected(redirect: Route, next: Route) {
-    spawn(async move {
-        let user = get_user().await;
-        if user.is_none() {
-            GuardContext::set_next(next);
-            let nav = navigator();
-            nav.replace(redirect);
-        }
-    });
-}
-
 #[cfg(target_arch = "wasm32")]
-use instant as _;
\ No newline at end of file
+use instant as _;
@@ -0,0 +1,126 @@
+use dioxus::prelude::*;
+use crate::api::search::{search_everything, SearchHit, SearchKind};
+use crate::Route;
+
+// Where a hit is looked at: its own page, or the campaign page that lists it
+fn hit_route(hit: &SearchHit) -> Option<Route> {
+    let slug = hit.slug.clone();
+    let campaign_slug = hit.campaign_slug.clone();
+    Some(match hit.kind {
+        SearchKind::Character => Route::CharacterById { slug: slug? },
+        SearchKind::Spell => Route::SpellDetail { slug: slug? },
+        SearchKind::Monster => Route::MonsterDetail { slug: slug? },
+        SearchKind::Npc => Route::NpcView { campaign_slug: campaign_slug? },
+        SearchKind::Lore => Route::Lore { campaign_slug: campaign_slug? },
+        SearchKind::Location => Route::MapLocations { campaign_slug: campaign_slug? },
+        SearchKind::Adventure => Route::AdventureLog { campaign_slug: campaign_slug? },
+    })
+}
+
+/// Results of the navbar search, grouped by what they are, best matches first
+#[component]
+pub fn Search(q: String) -> Element {
+    let navigator = use_navigator();
+    let mut query = use_signal(|| q.clone());
+    let mut searched = use_signal(|| q.clone());
+    // The navbar searches again from this page by changing the route's query
+    use_effect(use_reactive!(|q| {
+        query.set(q.clone());
+        searched.set(q);
+    }));
+
+    let results = use_resource(move || {
+        let searched = searched();
+        async move { search_everything(searched).await }
+    });
+
+    rsx! {
+        div { class: "max-w-4xl mx-auto py-8 px-4",
+            h1 { class: "text-3xl font-bold text-gray-900 dark:text-white mb-4", "Search" }
+            form {
+                class: "flex gap-2 mb-6",
+                onsubmit: move |e| {
+                    e.prevent_default();
+                    navigator.replace(Route::Search { q: query() });
+                },
+                input {
+                    r#type: "search",
+                    class: "flex-1 px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-lg dark:bg-gray-700 dark:text-white",
+                    placeholder: "Characters, NPCs, lore, places, adventures, spells, monsters",
+                    value: "{query}",
+                    oninput: move |e| query.set(e.value()),
+                }
+                button {
+                    r#type: "submit",
+                    class: "px-4 py-2 bg-blue-600 text-white rounded-lg hover:bg-blue-700",
+                    "Search"
+                }
+            }
+
+            if !searched().trim().is_empty() {
+                match &*results.read() {
+                    None => rsx! {
+                        div { class: "flex justify-center py-12",
+                            div {
+                                class: "animate-spin rounded-full h-12 w-12 border-t-2 border-b-2 border-blue-500",
+                                aria_label: "Loading...",
+                            }
+                        }
+                    },
+                    Some(Err(e)) => rsx! {
+                        div {
+                            class: "bg-red-100 border-l-4 border-red-500 text-red-700 p-4 mb-6",
+                            role: "alert",
+                            p { class: "font-bold", "Error" }
+                            p { "{e}" }
+                        }
+                    },
+                    Some(Ok(groups)) if groups.is_empty() => rsx! {
+                        p { class: "text-gray-500 dark:text-gray-400 py-12 text-center", "Nothing matches \"{searched}\"." }
+                    },
+                    Some(Ok(groups)) => rsx! {
+                        div { class: "space-y-8",
+                            for group in groups.iter() {
+                                section { key: "{group.kind.plural()}",
+                                    h2 { class: "text-xl font-semibold text-gray-900 dark:text-white border-b pb-2 mb-3",
+                                        "{group.kind.plural()} ({group.hits.len()})"
+                                    }
+                                    ul { class: "space-y-3",
+                                        for hit in group.hits.iter() {
+                                            SearchResult { hit: hit.clone() }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn SearchResult(hit: SearchHit) -> Element {
+    rsx! {
+        li { class: "bg-white dark:bg-gray-800 rounded-lg p-4 shadow",
+            if let Some(route) = hit_route(&hit) {
+                Link { to: route, class: "text-lg font-semibold text-purple-800 dark:text-purple-300 hover:underline", "{hit.title}" }
+            } else {
+                span { class: "text-lg font-semibold text-gray-900 dark:text-white", "{hit.title}" }
+            }
+            if let Some(campaign_slug) = hit.campaign_slug.as_ref() {
+                span { class: "ml-2 text-xs text-gray-500 dark:text-gray-400", "in {campaign_slug}" }
+            }
+            p { class: "text-sm text-gray-700 dark:text-gray-300 mt-1",
+                for (text, matched) in hit.snippet_parts() {
+                    if matched {
+                        mark { class: "bg-yellow-200 dark:bg-yellow-700 rounded px-0.5", "{text}" }
+                    } else {
+                        "{text}"
+                    }
+                }
+            }
+        }
+    }
+}
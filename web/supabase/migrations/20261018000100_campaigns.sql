@@ -0,0 +1,113 @@
+-- Campaign tables used by src/api/campaign.rs.
+-- Enum-like columns hold the Rust variant names (e.g. 'OnHold', 'PointOfInterest').
+
+create table if not exists campaigns (
+    id serial primary key,
+    name text not null,
+    slug text not null unique,
+    description text not null default '',
+    current_location text not null default '',
+    next_session date,
+    dm_notes text not null default '',
+    owner text not null default '',
+    created_at timestamptz not null default now(),
+    updated_at timestamptz
+);
+
+create table if not exists npcs (
+    id serial primary key,
+    campaign_id integer not null references campaigns (id) on delete cascade,
+    name text not null,
+    race text not null default '',
+    role text not null default '',
+    location text not null default '',
+    affiliation text not null default '',
+    description text not null default '',
+    personality text not null default '',
+    appearance text not null default '',
+    relationship text not null default '',
+    important_notes jsonb not null default '[]',
+    portrait text
+);
+
+create table if not exists quests (
+    id serial primary key,
+    campaign_id integer not null references campaigns (id) on delete cascade,
+    title text not null,
+    description text not null default '',
+    status text not null default 'Available',
+    progress integer not null default 0 check (progress between 0 and 100),
+    npc_id integer references npcs (id) on delete set null
+);
+
+create table if not exists campaign_events (
+    id serial primary key,
+    campaign_id integer not null references campaigns (id) on delete cascade,
+    title text not null,
+    date date not null default current_date,
+    description text not null default '',
+    impact text not null default 'Neutral'
+);
+
+create table if not exists party_members (
+    id serial primary key,
+    campaign_id integer not null references campaigns (id) on delete cascade,
+    character_id integer references characters (id) on delete set null,
+    name text not null,
+    player text not null default '',
+    level integer not null default 1,
+    -- "Healthy" or {"SpecialCondition": "..."}
+    status jsonb not null default '"Healthy"',
+    notes text not null default ''
+);
+
+create table if not exists adventures (
+    id serial primary key,
+    campaign_id integer not null references campaigns (id) on delete cascade,
+    title text not null,
+    date_played text not null default '',
+    character_used text not null default '',
+    character_level integer not null default 1,
+    summary text not null default '',
+    rewards jsonb not null default '[]',
+    notable_events jsonb not null default '[]',
+    dm_notes text
+);
+
+create table if not exists lore_categories (
+    id serial primary key,
+    campaign_id integer not null references campaigns (id) on delete cascade,
+    title text not null,
+    description text not null default ''
+);
+
+create table if not exists lore_entries (
+    id serial primary key,
+    campaign_id integer not null references campaigns (id) on delete cascade,
+    category_id integer not null references lore_categories (id) on delete cascade,
+    title text not null,
+    content text not null default '',
+    related_entries jsonb not null default '[]',
+    tags jsonb not null default '[]'
+);
+
+create table if not exists map_locations (
+    id serial primary key,
+    campaign_id integer not null references campaigns (id) on delete cascade,
+    name text not null,
+    description text not null default '',
+    x real not null default 50,
+    y real not null default 50,
+    location_type text not null default 'PointOfInterest',
+    is_visited boolean not null default false,
+    important_notes jsonb not null default '[]'
+);
+
+create index if not exists quests_campaign_id_idx on quests (campaign_id);
+create index if not exists campaign_events_campaign_id_idx on campaign_events (campaign_id);
+create index if not exists party_members_campaign_id_idx on party_members (campaign_id);
+create index if not exists adventures_campaign_id_idx on adventures (campaign_id);
+create index if not exists lore_categories_campaign_id_idx on lore_categories (campaign_id);
+create index if not exists lore_entries_campaign_id_idx on lore_entries (campaign_id);
+create index if not exists npcs_campaign_id_idx on npcs (campaign_id);
+create index if not exists map_locations_campaign_id_idx on map_locations (campaign_id);
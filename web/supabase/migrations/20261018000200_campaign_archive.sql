@@ -0,0 +1,2 @@
+-- Campaigns can be archived from the campaign list instead of being deleted.
+alter table campaigns add column archived boolean not null default false;
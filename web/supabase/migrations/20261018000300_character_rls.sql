@@ -0,0 +1,22 @@
+-- Row-level security for characters.
+-- Character writes run as the signed-in user (AuthenticatedUser in src/api/auth), so these
+-- policies decide what each caller may change. Anyone may read characters.
+
+alter table characters enable row level security;
+
+create policy "Characters are readable by everyone"
+    on characters for select
+    using (true);
+
+create policy "Users create their own characters"
+    on characters for insert
+    with check (owner = auth.uid()::text);
+
+create policy "Owners update their characters"
+    on characters for update
+    using (owner = auth.uid()::text)
+    with check (owner = auth.uid()::text);
+
+create policy "Owners delete their characters"
+    on characters for delete
+    using (owner = auth.uid()::text);
@@ -0,0 +1,48 @@
+-- Character permissions: owners have full access, the DM (owner) of a campaign a character
+-- plays in may view and edit it, and players with their own character in the same party may
+-- view it. Mirrors CharacterAccess in src/api/character.rs.
+-- The helpers are security definer so they can look across characters without recursing
+-- into the characters policies.
+
+create or replace function is_character_dm(p_character_id integer)
+returns boolean
+language sql stable security definer set search_path = public
+as $$
+    select exists (
+        select 1
+        from party_members pm
+        join campaigns c on c.id = pm.campaign_id
+        where pm.character_id = p_character_id
+          and c.owner = auth.uid()::text
+    );
+$$;
+
+create or replace function is_character_party_member(p_character_id integer)
+returns boolean
+language sql stable security definer set search_path = public
+as $$
+    select exists (
+        select 1
+        from party_members theirs
+        join party_members mine on mine.campaign_id = theirs.campaign_id
+        join characters ch on ch.id = mine.character_id
+        where theirs.character_id = p_character_id
+          and ch.owner = auth.uid()::text
+    );
+$$;
+
+drop policy if exists "Characters are readable by everyone" on characters;
+drop policy if exists "Owners update their characters" on characters;
+
+create policy "Characters are readable by owner, DM and party"
+    on characters for select
+    using (
+        owner = auth.uid()::text
+        or is_character_dm(id)
+        or is_character_party_member(id)
+    );
+
+create policy "Owners and DMs update characters"
+    on characters for update
+    using (owner = auth.uid()::text or is_character_dm(id))
+    with check (owner = auth.uid()::text or is_character_dm(id));
@@ -0,0 +1,186 @@
+-- Race, class, background and alignment used to be free text typed into the character form.
+-- They now come from the catalogs in src/rules/catalog.rs and are stored by display name.
+-- Rewrite older rows onto the catalog spelling wherever we can recognise the value, then stop
+-- new rows from storing anything outside the catalogs.
+
+alter table characters add column if not exists subclass text;
+
+-- A character without a background is stored as null rather than an empty string
+alter table characters alter column background drop not null;
+update characters set background = null where trim(background) = '';
+
+-- race: matched ignoring case, spacing and punctuation, as the app does
+update characters as c
+set race = m.name
+from (values
+    ('dragonborn', 'Dragonborn'),
+    ('dwarf', 'Dwarf'),
+    ('hilldwarf', 'Dwarf'),
+    ('mountaindwarf', 'Dwarf'),
+    ('elf', 'Elf'),
+    ('highelf', 'Elf'),
+    ('woodelf', 'Elf'),
+    ('drow', 'Elf'),
+    ('darkelf', 'Elf'),
+    ('gnome', 'Gnome'),
+    ('forestgnome', 'Gnome'),
+    ('rockgnome', 'Gnome'),
+    ('halfelf', 'Half-Elf'),
+    ('halfling', 'Halfling'),
+    ('lightfoothalfling', 'Halfling'),
+    ('stouthalfling', 'Halfling'),
+    ('halforc', 'Half-Orc'),
+    ('human', 'Human'),
+    ('tiefling', 'Tiefling')
+) as m(key, name)
+where regexp_replace(lower(c.race), '[^a-z0-9]', '', 'g') = m.key;
+
+-- class: matched ignoring case, spacing and punctuation, as the app does
+update characters as c
+set class = m.name
+from (values
+    ('barbarian', 'Barbarian'),
+    ('bard', 'Bard'),
+    ('cleric', 'Cleric'),
+    ('druid', 'Druid'),
+    ('fighter', 'Fighter'),
+    ('monk', 'Monk'),
+    ('paladin', 'Paladin'),
+    ('ranger', 'Ranger'),
+    ('rogue', 'Rogue'),
+    ('sorcerer', 'Sorcerer'),
+    ('warlock', 'Warlock'),
+    ('wizard', 'Wizard')
+) as m(key, name)
+where regexp_replace(lower(c.class), '[^a-z0-9]', '', 'g') = m.key;
+
+-- subclass: matched ignoring case, spacing and punctuation, as the app does
+update characters as c
+set subclass = m.name
+from (values
+    ('pathoftheberserker', 'Path of the Berserker'),
+    ('berserker', 'Path of the Berserker'),
+    ('pathofthetotemwarrior', 'Path of the Totem Warrior'),
+    ('totemwarrior', 'Path of the Totem Warrior'),
+    ('collegeoflore', 'College of Lore'),
+    ('collegeofvalor', 'College of Valor'),
+    ('knowledgedomain', 'Knowledge Domain'),
+    ('lifedomain', 'Life Domain'),
+    ('lightdomain', 'Light Domain'),
+    ('naturedomain', 'Nature Domain'),
+    ('tempestdomain', 'Tempest Domain'),
+    ('trickerydomain', 'Trickery Domain'),
+    ('wardomain', 'War Domain'),
+    ('circleoftheland', 'Circle of the Land'),
+    ('circleofthemoon', 'Circle of the Moon'),
+    ('champion', 'Champion'),
+    ('battlemaster', 'Battle Master'),
+    ('eldritchknight', 'Eldritch Knight'),
+    ('wayoftheopenhand', 'Way of the Open Hand'),
+    ('openhand', 'Way of the Open Hand'),
+    ('wayofshadow', 'Way of Shadow'),
+    ('wayofthefourelements', 'Way of the Four Elements'),
+    ('oathofdevotion', 'Oath of Devotion'),
+    ('oathoftheancients', 'Oath of the Ancients'),
+    ('oathofvengeance', 'Oath of Vengeance'),
+    ('hunter', 'Hunter'),
+    ('beastmaster', 'Beast Master'),
+    ('thief', 'Thief'),
+    ('assassin', 'Assassin'),
+    ('arcanetrickster', 'Arcane Trickster'),
+    ('draconicbloodline', 'Draconic Bloodline'),
+    ('wildmagic', 'Wild Magic'),
+    ('thearchfey', 'The Archfey'),
+    ('archfey', 'The Archfey'),
+    ('thefiend', 'The Fiend'),
+    ('fiend', 'The Fiend'),
+    ('thegreatoldone', 'The Great Old One'),
+    ('greatoldone', 'The Great Old One'),
+    ('schoolofabjuration', 'School of Abjuration'),
+    ('abjuration', 'School of Abjuration'),
+    ('schoolofconjuration', 'School of Conjuration'),
+    ('conjuration', 'School of Conjuration'),
+    ('schoolofdivination', 'School of Divination'),
+    ('divination', 'School of Divination'),
+    ('schoolofenchantment', 'School of Enchantment'),
+    ('enchantment', 'School of Enchantment'),
+    ('schoolofevocation', 'School of Evocation'),
+    ('evocation', 'School of Evocation'),
+    ('schoolofillusion', 'School of Illusion'),
+    ('illusion', 'School of Illusion'),
+    ('schoolofnecromancy', 'School of Necromancy'),
+    ('necromancy', 'School of Necromancy'),
+    ('schooloftransmutation', 'School of Transmutation'),
+    ('transmutation', 'School of Transmutation')
+) as m(key, name)
+where regexp_replace(lower(c.subclass), '[^a-z0-9]', '', 'g') = m.key;
+
+-- background: matched ignoring case, spacing and punctuation, as the app does
+update characters as c
+set background = m.name
+from (values
+    ('acolyte', 'Acolyte'),
+    ('charlatan', 'Charlatan'),
+    ('criminal', 'Criminal'),
+    ('spy', 'Criminal'),
+    ('entertainer', 'Entertainer'),
+    ('gladiator', 'Entertainer'),
+    ('folkhero', 'Folk Hero'),
+    ('guildartisan', 'Guild Artisan'),
+    ('guildmerchant', 'Guild Artisan'),
+    ('hermit', 'Hermit'),
+    ('noble', 'Noble'),
+    ('knight', 'Noble'),
+    ('outlander', 'Outlander'),
+    ('sage', 'Sage'),
+    ('sailor', 'Sailor'),
+    ('pirate', 'Sailor'),
+    ('soldier', 'Soldier'),
+    ('urchin', 'Urchin')
+) as m(key, name)
+where regexp_replace(lower(c.background), '[^a-z0-9]', '', 'g') = m.key;
+
+-- alignment: matched ignoring case, spacing and punctuation, as the app does
+update characters as c
+set alignment = m.name
+from (values
+    ('lawfulgood', 'Lawful Good'),
+    ('lg', 'Lawful Good'),
+    ('neutralgood', 'Neutral Good'),
+    ('ng', 'Neutral Good'),
+    ('chaoticgood', 'Chaotic Good'),
+    ('cg', 'Chaotic Good'),
+    ('lawfulneutral', 'Lawful Neutral'),
+    ('ln', 'Lawful Neutral'),
+    ('neutral', 'Neutral'),
+    ('trueneutral', 'Neutral'),
+    ('n', 'Neutral'),
+    ('tn', 'Neutral'),
+    ('chaoticneutral', 'Chaotic Neutral'),
+    ('cn', 'Chaotic Neutral'),
+    ('lawfulevil', 'Lawful Evil'),
+    ('le', 'Lawful Evil'),
+    ('neutralevil', 'Neutral Evil'),
+    ('ne', 'Neutral Evil'),
+    ('chaoticevil', 'Chaotic Evil'),
+    ('ce', 'Chaotic Evil')
+) as m(key, name)
+where regexp_replace(lower(c.alignment), '[^a-z0-9]', '', 'g') = m.key;
+
+-- Rows we could not recognise are left as they were; the app reads them back as unset and asks
+-- for a new value on the next edit. `not valid` keeps them loadable while checking every new write.
+
+alter table characters
+    add constraint characters_race_catalog check (race in ('Dragonborn', 'Dwarf', 'Elf', 'Gnome', 'Half-Elf', 'Halfling', 'Half-Orc', 'Human', 'Tiefling')) not valid;
+
+alter table characters
+    add constraint characters_class_catalog check (class in ('Barbarian', 'Bard', 'Cleric', 'Druid', 'Fighter', 'Monk', 'Paladin', 'Ranger', 'Rogue', 'Sorcerer', 'Warlock', 'Wizard')) not valid;
+
+alter table characters
+    add constraint characters_subclass_catalog check (subclass is null or subclass in ('Path of the Berserker', 'Path of the Totem Warrior', 'College of Lore', 'College of Valor', 'Knowledge Domain', 'Life Domain', 'Light Domain', 'Nature Domain', 'Tempest Domain', 'Trickery Domain', 'War Domain', 'Circle of the Land', 'Circle of the Moon', 'Champion', 'Battle Master', 'Eldritch Knight', 'Way of the Open Hand', 'Way of Shadow', 'Way of the Four Elements', 'Oath of Devotion', 'Oath of the Ancients', 'Oath of Vengeance', 'Hunter', 'Beast Master', 'Thief', 'Assassin', 'Arcane Trickster', 'Draconic Bloodline', 'Wild Magic', 'The Archfey', 'The Fiend', 'The Great Old One', 'School of Abjuration', 'School of Conjuration', 'School of Divination', 'School of Enchantment', 'School of Evocation', 'School of Illusion', 'School of Necromancy', 'School of Transmutation')) not valid;
+
+alter table characters
+    add constraint characters_background_catalog check (background is null or background in ('Acolyte', 'Charlatan', 'Criminal', 'Entertainer', 'Folk Hero', 'Guild Artisan', 'Hermit', 'Noble', 'Outlander', 'Sage', 'Sailor', 'Soldier', 'Urchin')) not valid;
+
+alter table characters
+    add constraint characters_alignment_catalog check (alignment in ('Lawful Good', 'Neutral Good', 'Chaotic Good', 'Lawful Neutral', 'Neutral', 'Chaotic Neutral', 'Lawful Evil', 'Neutral Evil', 'Chaotic Evil')) not valid;
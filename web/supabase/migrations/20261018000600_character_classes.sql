@@ -0,0 +1,21 @@
+-- Multiclassing: a character's levels are now a list of classes, each with its own level and
+-- subclass, e.g. [{"class": "Fighter", "level": 3, "subclass": "Champion"}, {"class": "Wizard", "level": 2}].
+-- The single class, subclass and level columns stay as a summary of that list (starting class,
+-- total level) so existing listings and sorts keep working; the app writes both on every save.
+
+alter table characters add column if not exists classes jsonb not null default '[]'::jsonb;
+
+-- Existing rows become a single class entry
+update characters
+set classes = jsonb_build_array(
+    jsonb_strip_nulls(jsonb_build_object('class', class, 'level', level, 'subclass', subclass))
+)
+where classes = '[]'::jsonb
+  and class is not null
+  and trim(class) <> '';
+
+alter table characters
+    add constraint characters_classes_is_array check (jsonb_typeof(classes) = 'array');
+
+alter table characters
+    add constraint characters_level_range check (level between 1 and 20) not valid;
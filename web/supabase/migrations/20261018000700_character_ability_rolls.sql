@@ -0,0 +1,7 @@
+-- Characters built with the creation wizard's 4d6-drop-lowest method keep every roll they made,
+-- e.g. [{"set": 1, "dice": [6, 4, 3, 1]}, ...], so the rest of the table can see how the scores came about.
+
+alter table characters add column if not exists ability_rolls jsonb not null default '[]'::jsonb;
+
+alter table characters
+    add constraint characters_ability_rolls_is_array check (jsonb_typeof(ability_rolls) = 'array');
@@ -0,0 +1,47 @@
+-- Level-up history: one row per level gained through the level-up flow, recording how hit points
+-- were gained (and the server's roll), the Ability Score Improvement or feat taken, and the
+-- features and spells added. Mirrors LevelUpRecord in src/api/character.rs.
+-- Owners and the character's DM can read and add rows; nobody can change or delete them.
+
+create table if not exists character_level_ups (
+    id serial primary key,
+    character_id integer not null references characters(id) on delete cascade,
+    level integer not null check (level between 2 and 20),
+    class text not null,
+    class_level integer not null check (class_level between 1 and 20),
+    subclass text,
+    hit_point_method text not null check (hit_point_method in ('Roll', 'Average')),
+    hit_die integer not null,
+    hit_die_roll integer check (hit_die_roll between 1 and hit_die),
+    hit_points_gained integer not null,
+    improvement jsonb,
+    features jsonb not null default '[]'::jsonb,
+    spells jsonb not null default '[]'::jsonb,
+    created_by text not null default auth.uid()::text,
+    created_at timestamptz not null default now()
+);
+
+create index if not exists character_level_ups_character_id_idx on character_level_ups (character_id, created_at);
+
+alter table character_level_ups enable row level security;
+
+create policy "Owners and DMs read level history"
+    on character_level_ups for select
+    using (
+        exists (
+            select 1 from characters c
+            where c.id = character_id
+              and (c.owner = auth.uid()::text or is_character_dm(c.id))
+        )
+    );
+
+create policy "Owners and DMs record level-ups"
+    on character_level_ups for insert
+    with check (
+        created_by = auth.uid()::text
+        and exists (
+            select 1 from characters c
+            where c.id = character_id
+              and (c.owner = auth.uid()::text or is_character_dm(c.id))
+        )
+    );
@@ -0,0 +1,31 @@
+-- Structured inventory: items with quantity, weight, value, equipped/attuned flags and
+-- containers, plus the coin purse, e.g.
+-- {"items": [{"id": 1, "name": "Backpack", "quantity": 1, "weight": 5, "is_container": true}], "coins": {"gp": 15}}.
+-- Mirrors Inventory in src/rules/inventory.rs. The `equipment` column stays as a list of item
+-- names for anything still reading it; the app writes both on every save.
+
+alter table characters
+    add column if not exists inventory jsonb not null default '{"items": [], "coins": {}}'::jsonb;
+
+-- Existing equipment lists become one item each
+update characters
+set inventory = jsonb_build_object(
+    'items',
+    coalesce(
+        (
+            select jsonb_agg(jsonb_build_object('id', ordinality, 'name', trim(name), 'quantity', 1) order by ordinality)
+            from jsonb_array_elements_text(equipment) with ordinality as entries(name, ordinality)
+            where trim(name) <> ''
+        ),
+        '[]'::jsonb
+    ),
+    'coins', '{}'::jsonb
+)
+where inventory = '{"items": [], "coins": {}}'::jsonb
+  and jsonb_typeof(equipment) = 'array';
+
+alter table characters
+    add constraint characters_inventory_is_object check (
+        jsonb_typeof(inventory) = 'object'
+        and jsonb_typeof(coalesce(inventory -> 'items', '[]'::jsonb)) = 'array'
+    );
@@ -0,0 +1,134 @@
+-- Shared spell catalog and per-character spellbooks.
+--
+-- `spells` is readable by everyone and mirrors Spell in src/api/spells.rs. Spellbooks point at
+-- it by slug and keep a copy of the level, ritual and concentration flags, e.g.
+-- {"entries": [{"spell": "fireball", "name": "Fireball", "level": 3, "class": "Wizard", "prepared": true}],
+--  "expended": [0, 1, 0, 0, 0, 0, 0, 0, 0], "pact_expended": 0, "concentration": null}.
+-- Mirrors Spellbook in src/rules/spellbook.rs. The `spells` column stays as a list of spell
+-- names for anything still reading it; the app writes both on every save.
+
+create table if not exists spells (
+    id serial primary key,
+    slug text not null unique,
+    name text not null,
+    level integer not null check (level between 0 and 9),
+    school text not null check (school in (
+        'Abjuration', 'Conjuration', 'Divination', 'Enchantment',
+        'Evocation', 'Illusion', 'Necromancy', 'Transmutation'
+    )),
+    ritual boolean not null default false,
+    concentration boolean not null default false,
+    classes jsonb not null default '[]'::jsonb check (jsonb_typeof(classes) = 'array'),
+    description text not null default ''
+);
+
+create index if not exists spells_level_name_idx on spells (level, name);
+create index if not exists spells_classes_idx on spells using gin (classes);
+
+alter table spells enable row level security;
+
+create policy "Anyone reads the spell catalog"
+    on spells for select
+    using (true);
+
+-- A starting set of SRD spells
+insert into spells (slug, name, level, school, ritual, concentration, classes, description) values
+    ('fire-bolt', 'Fire Bolt', 0, 'Evocation', false, false, '["Sorcerer", "Wizard"]',
+        'Ranged spell attack for 1d10 fire damage. Flammable objects hit ignite if they aren''t being worn or carried.'),
+    ('light', 'Light', 0, 'Evocation', false, false, '["Bard", "Cleric", "Sorcerer", "Wizard"]',
+        'An object you touch sheds bright light in a 20-foot radius for 1 hour.'),
+    ('mage-hand', 'Mage Hand', 0, 'Conjuration', false, false, '["Bard", "Sorcerer", "Warlock", "Wizard"]',
+        'A spectral hand appears within 30 feet and can manipulate objects for 1 minute.'),
+    ('sacred-flame', 'Sacred Flame', 0, 'Evocation', false, false, '["Cleric"]',
+        'A creature you can see must succeed on a Dexterity saving throw or take 1d8 radiant damage.'),
+    ('eldritch-blast', 'Eldritch Blast', 0, 'Evocation', false, false, '["Warlock"]',
+        'A beam of crackling energy: ranged spell attack for 1d10 force damage.'),
+    ('guidance', 'Guidance', 0, 'Divination', false, true, '["Cleric", "Druid"]',
+        'The target can add 1d4 to one ability check of its choice before the spell ends.'),
+    ('detect-magic', 'Detect Magic', 1, 'Divination', true, true, '["Bard", "Cleric", "Druid", "Paladin", "Ranger", "Sorcerer", "Wizard"]',
+        'For up to 10 minutes you sense the presence of magic within 30 feet.'),
+    ('magic-missile', 'Magic Missile', 1, 'Evocation', false, false, '["Sorcerer", "Wizard"]',
+        'Three glowing darts each deal 1d4 + 1 force damage. One more dart for each slot level above 1st.'),
+    ('shield', 'Shield', 1, 'Abjuration', false, false, '["Sorcerer", "Wizard"]',
+        'As a reaction, +5 to AC until the start of your next turn, including against the triggering attack.'),
+    ('cure-wounds', 'Cure Wounds', 1, 'Evocation', false, false, '["Bard", "Cleric", "Druid", "Paladin", "Ranger"]',
+        'A creature you touch regains 1d8 + your spellcasting modifier hit points. +1d8 for each slot level above 1st.'),
+    ('healing-word', 'Healing Word', 1, 'Evocation', false, false, '["Bard", "Cleric", "Druid"]',
+        'As a bonus action, a creature within 60 feet regains 1d4 + your spellcasting modifier hit points.'),
+    ('bless', 'Bless', 1, 'Enchantment', false, true, '["Cleric", "Paladin"]',
+        'Up to three creatures add 1d4 to attack rolls and saving throws for up to 1 minute.'),
+    ('hunters-mark', 'Hunter''s Mark', 1, 'Divination', false, true, '["Ranger"]',
+        'Deal an extra 1d6 damage to the marked creature whenever you hit it with a weapon attack.'),
+    ('find-familiar', 'Find Familiar', 1, 'Conjuration', true, false, '["Wizard"]',
+        'You gain the service of a familiar, a spirit that takes an animal form you choose.'),
+    ('sleep', 'Sleep', 1, 'Enchantment', false, false, '["Bard", "Sorcerer", "Wizard"]',
+        'Roll 5d8; creatures within 20 feet of a point fall unconscious in order of lowest hit points.'),
+    ('hex', 'Hex', 1, 'Enchantment', false, true, '["Warlock"]',
+        'Deal an extra 1d6 necrotic damage to the cursed target and give it disadvantage on checks with one ability.'),
+    ('misty-step', 'Misty Step', 2, 'Conjuration', false, false, '["Sorcerer", "Warlock", "Wizard"]',
+        'As a bonus action, teleport up to 30 feet to an unoccupied space you can see.'),
+    ('pass-without-trace', 'Pass without Trace', 2, 'Abjuration', false, true, '["Druid", "Ranger"]',
+        'You and your companions within 30 feet get +10 to Dexterity (Stealth) checks and leave no tracks.'),
+    ('hold-person', 'Hold Person', 2, 'Enchantment', false, true, '["Bard", "Cleric", "Druid", "Sorcerer", "Warlock", "Wizard"]',
+        'A humanoid must succeed on a Wisdom saving throw or be paralyzed for up to 1 minute.'),
+    ('spiritual-weapon', 'Spiritual Weapon', 2, 'Evocation', false, false, '["Cleric"]',
+        'A floating spectral weapon makes melee spell attacks for 1d8 + your spellcasting modifier force damage.'),
+    ('fireball', 'Fireball', 3, 'Evocation', false, false, '["Sorcerer", "Wizard"]',
+        'Each creature in a 20-foot-radius sphere makes a Dexterity saving throw, taking 8d6 fire damage on a failure or half as much on a success.'),
+    ('lightning-bolt', 'Lightning Bolt', 3, 'Evocation', false, false, '["Sorcerer", "Wizard"]',
+        'A 100-foot line of lightning: Dexterity saving throw for 8d6 lightning damage, half on a success.'),
+    ('counterspell', 'Counterspell', 3, 'Abjuration', false, false, '["Sorcerer", "Warlock", "Wizard"]',
+        'As a reaction, interrupt a creature casting a spell. Spells of 3rd level or lower fail; higher needs an ability check.'),
+    ('revivify', 'Revivify', 3, 'Necromancy', false, false, '["Cleric", "Paladin"]',
+        'A creature that died within the last minute returns to life with 1 hit point.'),
+    ('spirit-guardians', 'Spirit Guardians', 3, 'Conjuration', false, true, '["Cleric"]',
+        'Spirits surround you in a 15-foot radius, halving enemies'' speed and dealing 3d8 radiant or necrotic damage.'),
+    ('banishment', 'Banishment', 4, 'Abjuration', false, true, '["Cleric", "Paladin", "Sorcerer", "Warlock", "Wizard"]',
+        'A creature must succeed on a Charisma saving throw or be banished to a harmless demiplane.'),
+    ('cone-of-cold', 'Cone of Cold', 5, 'Evocation', false, false, '["Sorcerer", "Wizard"]',
+        'Each creature in a 60-foot cone makes a Constitution saving throw, taking 8d8 cold damage on a failure or half as much on a success.'),
+    ('teleport', 'Teleport', 7, 'Conjuration', false, false, '["Bard", "Sorcerer", "Wizard"]',
+        'You and up to eight willing creatures teleport to a destination you select on the same plane.'),
+    ('wish', 'Wish', 9, 'Conjuration', false, false, '["Sorcerer"]',
+        'The mightiest spell a mortal can cast: duplicate any spell of 8th level or lower, or alter reality.')
+on conflict (slug) do nothing;
+
+alter table characters
+    add column if not exists spellbook jsonb not null
+        default '{"entries": [], "expended": [0, 0, 0, 0, 0, 0, 0, 0, 0], "pact_expended": 0, "concentration": null}'::jsonb;
+
+-- Existing spell lists become known spells, with the catalog's details where the name matches
+update characters c
+set spellbook = jsonb_build_object(
+    'entries',
+    coalesce(
+        (
+            select jsonb_agg(
+                jsonb_build_object(
+                    'spell', coalesce(s.slug, trim(both '-' from regexp_replace(lower(trim(entries.name)), '[^a-z0-9]+', '-', 'g'))),
+                    'name', coalesce(s.name, trim(entries.name)),
+                    'level', coalesce(s.level, 1),
+                    'ritual', coalesce(s.ritual, false),
+                    'concentration', coalesce(s.concentration, false)
+                )
+                order by entries.ordinality
+            )
+            from jsonb_array_elements_text(c.spells) with ordinality as entries(name, ordinality)
+            left join spells s on lower(s.name) = lower(trim(entries.name))
+            where trim(entries.name) <> ''
+        ),
+        '[]'::jsonb
+    ),
+    'expended', '[0, 0, 0, 0, 0, 0, 0, 0, 0]'::jsonb,
+    'pact_expended', 0,
+    'concentration', null
+)
+where jsonb_typeof(c.spells) = 'array'
+  and jsonb_array_length(c.spells) > 0
+  and c.spellbook -> 'entries' = '[]'::jsonb;
+
+alter table characters
+    add constraint characters_spellbook_is_object check (
+        jsonb_typeof(spellbook) = 'object'
+        and jsonb_typeof(coalesce(spellbook -> 'entries', '[]'::jsonb)) = 'array'
+    );
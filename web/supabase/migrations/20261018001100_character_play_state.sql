@@ -0,0 +1,13 @@
+-- Live play state: current and temporary hit points, death saves, conditions, exhaustion and
+-- spent hit dice, e.g.
+-- {"current_hit_points": 12, "temporary_hit_points": 5, "death_saves": {"successes": 0, "failures": 0},
+--  "conditions": ["Poisoned"], "exhaustion": 1, "hit_dice_spent": [[10, 2]]}.
+-- Mirrors PlayState in src/rules/play.rs; a null current_hit_points means the character is unhurt.
+-- `hit_points` stays the maximum. The player and the campaign's DM both change this during a
+-- session through the existing character update policies, and the DM dashboard reads it back.
+
+alter table characters
+    add column if not exists play_state jsonb not null default '{}'::jsonb;
+
+alter table characters
+    add constraint characters_play_state_is_object check (jsonb_typeof(play_state) = 'object');
@@ -0,0 +1,16 @@
+-- Features with limited uses. Each element of `features` becomes an object, e.g.
+-- {"name": "Rage", "description": "", "uses": {"max": {"kind": "LevelTable", "class": "Barbarian",
+--  "steps": [[1, 2], [3, 3]]}, "recharge": {"kind": "LongRest"}, "used": 1}}.
+-- Mirrors Feature in src/rules/features.rs. Plain names from before are left as they are: the app
+-- reads them as features and sets up uses for known class resources like Rage and Ki, then writes
+-- the objects back on the next save.
+
+update characters
+set features = '[]'::jsonb
+where features is null or jsonb_typeof(features) <> 'array';
+
+alter table characters
+    alter column features set default '[]'::jsonb;
+
+alter table characters
+    add constraint characters_features_is_array check (jsonb_typeof(features) = 'array');
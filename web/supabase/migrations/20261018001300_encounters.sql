@@ -0,0 +1,18 @@
+-- Saved encounters for the DM's combat tracker, read and written by src/api/campaign.rs.
+-- `state` is the whole fight as Encounter in src/rules/combat.rs, e.g.
+-- {"combatants": [{"id": 1, "name": "Goblin 1", "side": {"kind": "Monster", "monster": null},
+--   "initiative": 14, "initiative_bonus": 2, "dexterity": 14, "armor_class": 15, "hit_points": 7,
+--   "max_hit_points": 7, "conditions": [{"condition": "Prone", "rounds": 1}], ...}],
+--  "round": 2, "turn": 1, "next_id": 1}.
+-- Only the campaign's DM gets at these; see 20261018002000_campaign_rls.sql.
+
+create table if not exists encounters (
+    id serial primary key,
+    campaign_id integer not null references campaigns (id) on delete cascade,
+    name text not null,
+    state jsonb not null default '{}'::jsonb check (jsonb_typeof(state) = 'object'),
+    created_at timestamptz not null default now(),
+    updated_at timestamptz
+);
+
+create index if not exists encounters_campaign_id_idx on encounters (campaign_id, updated_at desc);
@@ -0,0 +1,25 @@
+-- The campaign roll log, read and written by src/api/campaign.rs. Every roll made from a
+-- character sheet or the DM screen is kept with all of its dice: `result` is RollResult in
+-- src/rules/dice.rs, e.g.
+-- {"expression": "1d20+5", "rolls": [{"notation": "1d20", "sides": 20, "total": 14,
+--   "dice": [{"value": 14, "kept": true}]}], "breakdown": "1d20[14] + 5", "total": 19}.
+-- `visibility` decides who besides the DM and the roller sees it: everyone ('Public'), nobody
+-- ('Dm'), or the player of the party character in `whisper_to` ('Whisper').
+-- Who may read and add rolls is enforced by row-level security in 20261018002300_campaign_roll_rls.sql.
+
+create table if not exists campaign_rolls (
+    id serial primary key,
+    campaign_id integer not null references campaigns (id) on delete cascade,
+    roller text not null,
+    roller_name text not null,
+    character_id integer references characters (id) on delete set null,
+    purpose text not null default '' check (char_length(purpose) <= 100),
+    expression text not null,
+    result jsonb not null check (jsonb_typeof(result) = 'object'),
+    total integer not null,
+    visibility text not null default 'Public' check (visibility in ('Public', 'Dm', 'Whisper')),
+    whisper_to integer references characters (id) on delete set null,
+    created_at timestamptz not null default now()
+);
+
+create index if not exists campaign_rolls_campaign_id_idx on campaign_rolls (campaign_id, created_at desc);
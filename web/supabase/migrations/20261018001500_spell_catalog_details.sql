@@ -0,0 +1,1310 @@
+-- The rest of a spell's stat block, and the full SRD 5.1 spell list.
+--
+-- `components` mirrors Components in src/api/spells.rs, e.g.
+-- {"verbal": true, "somatic": true, "material": "a tiny ball of bat guano and sulfur"}, with a
+-- null `material` for spells that need none. Descriptions are short summaries of the SRD text
+-- (Open Game License 1.0a / CC-BY-4.0); `higher_levels` is empty for spells that don't scale.
+
+alter table spells add column if not exists casting_time text not null default '1 action';
+alter table spells add column if not exists range text not null default 'Self';
+alter table spells add column if not exists components jsonb not null
+    default '{"verbal": false, "somatic": false, "material": null}'::jsonb
+    check (jsonb_typeof(components) = 'object');
+alter table spells add column if not exists duration text not null default 'Instantaneous';
+alter table spells add column if not exists higher_levels text not null default '';
+
+create index if not exists spells_school_idx on spells (school);
+
+-- Rows seeded earlier keep their slugs, so spellbooks pointing at them stay intact
+insert into spells (slug, name, level, school, ritual, concentration, classes,
+                    casting_time, range, components, duration, description, higher_levels) values
+    ('acid-splash', 'Acid Splash', 0, 'Conjuration', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'One creature, or two within 5 feet of each other, must succeed on a Dexterity saving throw or take 1d6 acid damage.',
+        'The damage increases by 1d6 at 5th level (2d6), 11th level (3d6) and 17th level (4d6).'),
+    ('chill-touch', 'Chill Touch', 0, 'Necromancy', false, false, '["Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": null}', '1 round',
+        'Ranged spell attack for 1d8 necrotic damage. The target can''t regain hit points until your next turn, and undead have disadvantage attacking you.',
+        'The damage increases by 1d8 at 5th level (2d8), 11th level (3d8) and 17th level (4d8).'),
+    ('dancing-lights', 'Dancing Lights', 0, 'Evocation', false, true, '["Bard", "Sorcerer", "Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": "a bit of phosphorus or wychwood, or a glowworm"}', 'Concentration, up to 1 minute',
+        'Create up to four torch-sized lights, or one glowing Medium humanoid form, that you can move up to 60 feet as a bonus action.',
+        ''),
+    ('druidcraft', 'Druidcraft', 0, 'Transmutation', false, false, '["Druid"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Small nature effects: predict the weather, make a flower bloom, create a harmless sensory effect, or light or snuff a small flame.',
+        ''),
+    ('eldritch-blast', 'Eldritch Blast', 0, 'Evocation', false, false, '["Warlock"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'A beam of crackling energy: ranged spell attack for 1d10 force damage.',
+        'Fire two beams at 5th level, three at 11th and four at 17th, each with its own attack roll.'),
+    ('fire-bolt', 'Fire Bolt', 0, 'Evocation', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Hurl a mote of fire: ranged spell attack for 1d10 fire damage. Ignites unattended flammable objects.',
+        'The damage increases by 1d10 at 5th level (2d10), 11th level (3d10) and 17th level (4d10).'),
+    ('guidance', 'Guidance', 0, 'Divination', false, true, '["Cleric", "Druid"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'A willing creature adds 1d4 to one ability check of its choice before the spell ends.',
+        ''),
+    ('light', 'Light', 0, 'Evocation', false, false, '["Bard", "Cleric", "Sorcerer", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": false, "material": "a firefly or phosphorescent moss"}', '1 hour',
+        'An object you touch sheds bright light in a 20-foot radius and dim light for another 20 feet.',
+        ''),
+    ('mage-hand', 'Mage Hand', 0, 'Conjuration', false, false, '["Bard", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": null}', '1 minute',
+        'A spectral hand can manipulate objects, open doors or carry up to 10 pounds within range.',
+        ''),
+    ('mending', 'Mending', 0, 'Transmutation', false, false, '["Bard", "Cleric", "Druid", "Sorcerer", "Wizard"]',
+        '1 minute', 'Touch', '{"verbal": true, "somatic": true, "material": "two lodestones"}', 'Instantaneous',
+        'Repair a single break or tear in an object, no larger than 1 foot in any dimension.',
+        ''),
+    ('message', 'Message', 0, 'Transmutation', false, false, '["Bard", "Sorcerer", "Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": "a short piece of copper wire"}', '1 round',
+        'Whisper a message to a creature within range that only it hears, and it can whisper a reply.',
+        ''),
+    ('minor-illusion', 'Minor Illusion', 0, 'Illusion', false, false, '["Bard", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '30 feet', '{"verbal": false, "somatic": true, "material": "a bit of fleece"}', '1 minute',
+        'Create a sound or an image of an object no larger than a 5-foot cube.',
+        ''),
+    ('poison-spray', 'Poison Spray', 0, 'Conjuration', false, false, '["Druid", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '10 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'A creature must succeed on a Constitution save or take 1d12 poison damage.',
+        'The damage increases by 1d12 at 5th level (2d12), 11th level (3d12) and 17th level (4d12).'),
+    ('prestidigitation', 'Prestidigitation', 0, 'Transmutation', false, false, '["Bard", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '10 feet', '{"verbal": true, "somatic": true, "material": null}', 'Up to 1 hour',
+        'Minor magical tricks: sensory effects, lighting candles, cleaning, chilling or flavoring, and tiny marks or trinkets.',
+        ''),
+    ('produce-flame', 'Produce Flame', 0, 'Conjuration', false, false, '["Druid"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": null}', '10 minutes',
+        'A flame in your hand sheds light. Hurl it as a ranged spell attack for 1d8 fire damage.',
+        'The damage increases by 1d8 at 5th level (2d8), 11th level (3d8) and 17th level (4d8).'),
+    ('ray-of-frost', 'Ray of Frost', 0, 'Evocation', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Ranged spell attack for 1d8 cold damage, and the target''s speed drops by 10 feet until your next turn.',
+        'The damage increases by 1d8 at 5th level (2d8), 11th level (3d8) and 17th level (4d8).'),
+    ('resistance', 'Resistance', 0, 'Abjuration', false, true, '["Cleric", "Druid"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "a miniature cloak"}', 'Concentration, up to 1 minute',
+        'A willing creature adds 1d4 to one saving throw of its choice before the spell ends.',
+        ''),
+    ('sacred-flame', 'Sacred Flame', 0, 'Evocation', false, false, '["Cleric"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'A creature must succeed on a Dexterity save or take 1d8 radiant damage. Cover gives no benefit against it.',
+        'The damage increases by 1d8 at 5th level (2d8), 11th level (3d8) and 17th level (4d8).'),
+    ('shillelagh', 'Shillelagh', 0, 'Transmutation', false, false, '["Druid"]',
+        '1 bonus action', 'Touch', '{"verbal": true, "somatic": true, "material": "mistletoe, a shamrock leaf and a club or quarterstaff"}', '1 minute',
+        'Your club or quarterstaff uses your spellcasting ability for attacks and deals 1d8 damage.',
+        ''),
+    ('shocking-grasp', 'Shocking Grasp', 0, 'Evocation', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Melee spell attack with advantage against metal armor for 1d8 lightning damage. The target can''t take reactions until its next turn.',
+        'The damage increases by 1d8 at 5th level (2d8), 11th level (3d8) and 17th level (4d8).'),
+    ('spare-the-dying', 'Spare the Dying', 0, 'Necromancy', false, false, '["Cleric"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'A living creature at 0 hit points becomes stable.',
+        ''),
+    ('thaumaturgy', 'Thaumaturgy', 0, 'Transmutation', false, false, '["Cleric"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": false, "material": null}', 'Up to 1 minute',
+        'A minor wonder: boom your voice, make flames flicker, cause tremors or sounds, throw open doors or alter your eyes.',
+        ''),
+    ('true-strike', 'True Strike', 0, 'Divination', false, true, '["Bard", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '30 feet', '{"verbal": false, "somatic": true, "material": null}', 'Concentration, up to 1 round',
+        'Gain advantage on your first attack roll against the target on your next turn.',
+        ''),
+    ('vicious-mockery', 'Vicious Mockery', 0, 'Enchantment', false, false, '["Bard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": false, "material": null}', 'Instantaneous',
+        'A creature that hears you and fails a Wisdom save takes 1d4 psychic damage and has disadvantage on its next attack roll.',
+        'The damage increases by 1d4 at 5th level (2d4), 11th level (3d4) and 17th level (4d4).'),
+    ('alarm', 'Alarm', 1, 'Abjuration', true, false, '["Ranger", "Wizard"]',
+        '1 minute', '30 feet', '{"verbal": true, "somatic": true, "material": "a tiny bell and a piece of fine silver wire"}', '8 hours',
+        'Sets an alarm against intrusion on a door, window or area no larger than a 20-foot cube. The alarm is a mental ping or an audible bell when a Tiny or larger creature enters.',
+        ''),
+    ('animal-friendship', 'Animal Friendship', 1, 'Enchantment', false, false, '["Bard", "Druid", "Ranger"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": "a morsel of food"}', '24 hours',
+        'A beast with Intelligence 3 or lower that can see and hear you must succeed on a Wisdom saving throw or be charmed by you for the duration.',
+        'You can target one additional beast for each slot level above 1st.'),
+    ('bane', 'Bane', 1, 'Enchantment', false, true, '["Bard", "Cleric"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": "a drop of blood"}', 'Concentration, up to 1 minute',
+        'Up to three creatures must succeed on Charisma saving throws or subtract 1d4 from attack rolls and saving throws for the duration.',
+        'You can target one additional creature for each slot level above 1st.'),
+    ('bless', 'Bless', 1, 'Enchantment', false, true, '["Cleric", "Paladin"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": "a sprinkling of holy water"}', 'Concentration, up to 1 minute',
+        'Up to three creatures add 1d4 to attack rolls and saving throws for up to 1 minute.',
+        'You can target one additional creature for each slot level above 1st.'),
+    ('burning-hands', 'Burning Hands', 1, 'Evocation', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', 'Self (15-foot cone)', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Each creature in a 15-foot cone makes a Dexterity saving throw, taking 3d6 fire damage on a failure or half as much on a success.',
+        'The damage increases by 1d6 for each slot level above 1st.'),
+    ('charm-person', 'Charm Person', 1, 'Enchantment', false, false, '["Bard", "Druid", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": null}', '1 hour',
+        'A humanoid must succeed on a Wisdom saving throw, with advantage if you''re fighting it, or be charmed by you for the duration.',
+        'You can target one additional creature for each slot level above 1st.'),
+    ('color-spray', 'Color Spray', 1, 'Illusion', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', 'Self (15-foot cone)', '{"verbal": true, "somatic": true, "material": "a pinch of powder or sand colored red, yellow and blue"}', '1 round',
+        'Roll 6d10: creatures in the cone are blinded in order of lowest current hit points, each subtracting its hit points from the total.',
+        'Roll an additional 2d10 for each slot level above 1st.'),
+    ('command', 'Command', 1, 'Enchantment', false, false, '["Cleric", "Paladin"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": false, "material": null}', '1 round',
+        'Speak a one-word command to a creature. Unless it succeeds on a Wisdom saving throw, it follows the command on its next turn.',
+        'You can target one additional creature for each slot level above 1st.'),
+    ('comprehend-languages', 'Comprehend Languages', 1, 'Divination', true, false, '["Bard", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": "a pinch of soot and salt"}', '1 hour',
+        'You understand the literal meaning of any spoken language you hear and any written language you touch.',
+        ''),
+    ('create-or-destroy-water', 'Create or Destroy Water', 1, 'Transmutation', false, false, '["Cleric", "Druid"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": "a drop of water to create water or a few grains of sand to destroy it"}', 'Instantaneous',
+        'Create or destroy up to 10 gallons of water, or rain or fog in a 30-foot cube.',
+        'Create or destroy 10 additional gallons, or the cube grows by 5 feet, for each slot level above 1st.'),
+    ('cure-wounds', 'Cure Wounds', 1, 'Evocation', false, false, '["Bard", "Cleric", "Druid", "Paladin", "Ranger"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'A creature you touch regains 1d8 + your spellcasting modifier hit points. Has no effect on undead or constructs.',
+        'The healing increases by 1d8 for each slot level above 1st.'),
+    ('detect-evil-and-good', 'Detect Evil and Good', 1, 'Divination', false, true, '["Cleric", "Paladin"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 10 minutes',
+        'You know the location and type of any aberration, celestial, elemental, fey, fiend or undead within 30 feet, and of consecrated or desecrated places.',
+        ''),
+    ('detect-magic', 'Detect Magic', 1, 'Divination', true, true, '["Bard", "Cleric", "Druid", "Paladin", "Ranger", "Sorcerer", "Wizard"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 10 minutes',
+        'For up to 10 minutes you sense the presence of magic within 30 feet, and can see its aura and learn its school as an action.',
+        ''),
+    ('detect-poison-and-disease', 'Detect Poison and Disease', 1, 'Divination', true, true, '["Cleric", "Druid", "Paladin", "Ranger"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": "a yew leaf"}', 'Concentration, up to 10 minutes',
+        'You sense the presence and location of poisons, poisonous creatures and diseases within 30 feet.',
+        ''),
+    ('disguise-self', 'Disguise Self', 1, 'Illusion', false, false, '["Bard", "Sorcerer", "Wizard"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": null}', '1 hour',
+        'Make yourself, including your clothing and gear, look different until the spell ends. An Intelligence (Investigation) check sees through it.',
+        ''),
+    ('divine-favor', 'Divine Favor', 1, 'Evocation', false, true, '["Paladin"]',
+        '1 bonus action', 'Self', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'Your weapon attacks deal an extra 1d4 radiant damage on a hit.',
+        ''),
+    ('entangle', 'Entangle', 1, 'Conjuration', false, true, '["Druid"]',
+        '1 action', '90 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'Grasping plants fill a 20-foot square. Creatures there must succeed on a Strength save or be restrained.',
+        ''),
+    ('expeditious-retreat', 'Expeditious Retreat', 1, 'Transmutation', false, true, '["Sorcerer", "Warlock", "Wizard"]',
+        '1 bonus action', 'Self', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 10 minutes',
+        'You can take the Dash action as a bonus action on each of your turns.',
+        ''),
+    ('faerie-fire', 'Faerie Fire', 1, 'Evocation', false, true, '["Bard", "Druid"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": false, "material": null}', 'Concentration, up to 1 minute',
+        'Objects and creatures in a 20-foot cube that fail a Dexterity save shed light, can''t be invisible and grant advantage on attacks against them.',
+        ''),
+    ('false-life', 'False Life', 1, 'Necromancy', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": "a small amount of alcohol or distilled spirits"}', '1 hour',
+        'You gain 1d4 + 4 temporary hit points.',
+        'You gain 5 additional temporary hit points for each slot level above 1st.'),
+    ('feather-fall', 'Feather Fall', 1, 'Transmutation', false, false, '["Bard", "Sorcerer", "Wizard"]',
+        '1 reaction', '60 feet', '{"verbal": true, "somatic": false, "material": "a small feather or piece of down"}', '1 minute',
+        'Up to five falling creatures descend at 60 feet per round and take no falling damage.',
+        ''),
+    ('find-familiar', 'Find Familiar', 1, 'Conjuration', true, false, '["Wizard"]',
+        '1 hour', '10 feet', '{"verbal": true, "somatic": true, "material": "10 gp worth of charcoal, incense and herbs consumed by fire in a brass brazier"}', 'Instantaneous',
+        'Gain the service of a familiar, a spirit that takes an animal form you choose and acts independently of you.',
+        ''),
+    ('floating-disk', 'Floating Disk', 1, 'Conjuration', true, false, '["Wizard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": "a drop of mercury"}', '1 hour',
+        'A 3-foot floating disk of force follows you carrying up to 500 pounds.',
+        ''),
+    ('fog-cloud', 'Fog Cloud', 1, 'Conjuration', false, true, '["Druid", "Ranger", "Sorcerer", "Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 hour',
+        'A 20-foot-radius sphere of fog heavily obscures its area.',
+        'The radius increases by 20 feet for each slot level above 1st.'),
+    ('goodberry', 'Goodberry', 1, 'Transmutation', false, false, '["Druid", "Ranger"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "a sprig of mistletoe"}', 'Instantaneous',
+        'Up to ten magical berries appear. Eating one restores 1 hit point and provides a day''s nourishment.',
+        ''),
+    ('grease', 'Grease', 1, 'Conjuration', false, false, '["Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": "a bit of pork rind or butter"}', '1 minute',
+        'Slick grease covers a 10-foot square, making it difficult terrain. Creatures there must succeed on a Dexterity save or fall prone.',
+        ''),
+    ('guiding-bolt', 'Guiding Bolt', 1, 'Evocation', false, false, '["Cleric"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": null}', '1 round',
+        'Ranged spell attack for 4d6 radiant damage. The next attack roll against the target before your next turn has advantage.',
+        'The damage increases by 1d6 for each slot level above 1st.'),
+    ('healing-word', 'Healing Word', 1, 'Evocation', false, false, '["Bard", "Cleric", "Druid"]',
+        '1 bonus action', '60 feet', '{"verbal": true, "somatic": false, "material": null}', 'Instantaneous',
+        'A creature you can see regains 1d4 + your spellcasting modifier hit points.',
+        'The healing increases by 1d4 for each slot level above 1st.'),
+    ('hellish-rebuke', 'Hellish Rebuke', 1, 'Evocation', false, false, '["Warlock"]',
+        '1 reaction', '60 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'When a creature damages you, it is wreathed in flames and makes a Dexterity save against 2d10 fire damage.',
+        'The damage increases by 1d10 for each slot level above 1st.'),
+    ('heroism', 'Heroism', 1, 'Enchantment', false, true, '["Bard", "Paladin"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'A willing creature is immune to being frightened and gains temporary hit points equal to your spellcasting modifier each turn.',
+        'You can target one additional creature for each slot level above 1st.'),
+    ('hex', 'Hex', 1, 'Enchantment', false, true, '["Warlock"]',
+        '1 bonus action', '90 feet', '{"verbal": true, "somatic": true, "material": "the petrified eye of a newt"}', 'Concentration, up to 1 hour',
+        'Curse a creature: your attacks deal an extra 1d6 necrotic damage to it, and it has disadvantage on checks with one ability you choose.',
+        'The duration becomes up to 8 hours with a 3rd or 4th-level slot, and up to 24 hours with 5th level or higher.'),
+    ('hideous-laughter', 'Hideous Laughter', 1, 'Enchantment', false, true, '["Bard", "Wizard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": "tiny tarts and a feather that is waved in the air"}', 'Concentration, up to 1 minute',
+        'A creature that fails a Wisdom save falls prone in fits of laughter and is incapacitated.',
+        ''),
+    ('hunters-mark', 'Hunter''s Mark', 1, 'Divination', false, true, '["Ranger"]',
+        '1 bonus action', '90 feet', '{"verbal": true, "somatic": false, "material": null}', 'Concentration, up to 1 hour',
+        'Mark a creature as your quarry: your weapon attacks deal an extra 1d6 damage to it, and you have advantage on checks to find it.',
+        'The duration becomes up to 8 hours with a 3rd or 4th-level slot, and up to 24 hours with 5th level or higher.'),
+    ('identify', 'Identify', 1, 'Divination', true, false, '["Bard", "Wizard"]',
+        '1 minute', 'Touch', '{"verbal": true, "somatic": true, "material": "a pearl worth at least 100 gp and an owl feather"}', 'Instantaneous',
+        'Learn an object''s magical properties and how to use them, or which spells are affecting a creature.',
+        ''),
+    ('illusory-script', 'Illusory Script', 1, 'Illusion', true, false, '["Bard", "Warlock", "Wizard"]',
+        '1 minute', 'Touch', '{"verbal": false, "somatic": true, "material": "a lead-based ink worth at least 10 gp, which the spell consumes"}', '10 days',
+        'Write a message that only creatures you designate can read; everyone else sees gibberish or a different message.',
+        ''),
+    ('inflict-wounds', 'Inflict Wounds', 1, 'Necromancy', false, false, '["Cleric"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Melee spell attack for 3d10 necrotic damage.',
+        'The damage increases by 1d10 for each slot level above 1st.'),
+    ('jump', 'Jump', 1, 'Transmutation', false, false, '["Druid", "Ranger", "Sorcerer", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "a grasshopper''s hind leg"}', '1 minute',
+        'A creature''s jump distance is tripled.',
+        ''),
+    ('longstrider', 'Longstrider', 1, 'Transmutation', false, false, '["Bard", "Druid", "Ranger", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "a pinch of dirt"}', '1 hour',
+        'A creature''s speed increases by 10 feet.',
+        'You can target one additional creature for each slot level above 1st.'),
+    ('mage-armor', 'Mage Armor', 1, 'Abjuration', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "a piece of cured leather"}', '8 hours',
+        'A willing creature not wearing armor has a base AC of 13 + its Dexterity modifier.',
+        ''),
+    ('magic-missile', 'Magic Missile', 1, 'Evocation', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Three glowing darts each hit a creature of your choice for 1d4 + 1 force damage.',
+        'One more dart for each slot level above 1st.'),
+    ('protection-from-evil-and-good', 'Protection from Evil and Good', 1, 'Abjuration', false, true, '["Cleric", "Paladin", "Warlock", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "holy water or powdered silver and iron, which the spell consumes"}', 'Concentration, up to 10 minutes',
+        'Aberrations, celestials, elementals, fey, fiends and undead have disadvantage attacking the target, and can''t charm, frighten or possess it.',
+        ''),
+    ('purify-food-and-drink', 'Purify Food and Drink', 1, 'Transmutation', true, false, '["Cleric", "Druid", "Paladin"]',
+        '1 action', '10 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Nonmagical food and drink in a 5-foot sphere is rendered free of poison and disease.',
+        ''),
+    ('sanctuary', 'Sanctuary', 1, 'Abjuration', false, false, '["Cleric"]',
+        '1 bonus action', '30 feet', '{"verbal": true, "somatic": true, "material": "a small silver mirror"}', '1 minute',
+        'Creatures that target the warded creature with attacks or harmful spells must first succeed on a Wisdom save.',
+        ''),
+    ('shield', 'Shield', 1, 'Abjuration', false, false, '["Sorcerer", "Wizard"]',
+        '1 reaction', 'Self', '{"verbal": true, "somatic": true, "material": null}', '1 round',
+        'An invisible barrier grants +5 AC until your next turn, including against the triggering attack, and stops magic missile.',
+        ''),
+    ('shield-of-faith', 'Shield of Faith', 1, 'Abjuration', false, true, '["Cleric", "Paladin"]',
+        '1 bonus action', '60 feet', '{"verbal": true, "somatic": true, "material": "a small parchment with a bit of holy text written on it"}', 'Concentration, up to 10 minutes',
+        'A shimmering field surrounds a creature, granting it +2 AC.',
+        ''),
+    ('silent-image', 'Silent Image', 1, 'Illusion', false, true, '["Bard", "Sorcerer", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": "a bit of fleece"}', 'Concentration, up to 10 minutes',
+        'Create a soundless image of an object, creature or phenomenon no larger than a 15-foot cube.',
+        ''),
+    ('sleep', 'Sleep', 1, 'Enchantment', false, false, '["Bard", "Sorcerer", "Wizard"]',
+        '1 action', '90 feet', '{"verbal": true, "somatic": true, "material": "a pinch of fine sand, rose petals or a cricket"}', '1 minute',
+        'Roll 5d8: creatures in a 20-foot sphere fall asleep in order of lowest current hit points, each subtracting its hit points from the total.',
+        'Roll an additional 2d8 for each slot level above 1st.'),
+    ('speak-with-animals', 'Speak with Animals', 1, 'Divination', true, false, '["Bard", "Druid", "Ranger"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": null}', '10 minutes',
+        'You can comprehend and verbally communicate with beasts.',
+        ''),
+    ('thunderwave', 'Thunderwave', 1, 'Evocation', false, false, '["Bard", "Druid", "Sorcerer", "Wizard"]',
+        '1 action', 'Self (15-foot cube)', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Each creature in a 15-foot cube makes a Constitution save, taking 2d8 thunder damage and being pushed 10 feet on a failure.',
+        'The damage increases by 1d8 for each slot level above 1st.'),
+    ('unseen-servant', 'Unseen Servant', 1, 'Conjuration', true, false, '["Bard", "Warlock", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": "a piece of string and a bit of wood"}', '1 hour',
+        'An invisible, mindless force performs simple tasks at your command.',
+        ''),
+    ('acid-arrow', 'Acid Arrow', 2, 'Evocation', false, false, '["Wizard"]',
+        '1 action', '90 feet', '{"verbal": true, "somatic": true, "material": "powdered rhubarb leaf and an adder''s stomach"}', 'Instantaneous',
+        'Ranged spell attack: 4d4 acid damage on a hit and 2d4 acid damage at the end of the target''s next turn. Half the initial damage on a miss.',
+        'The initial and later damage each increase by 1d4 for each slot level above 2nd.'),
+    ('aid', 'Aid', 2, 'Abjuration', false, false, '["Cleric", "Paladin"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": "a tiny strip of white cloth"}', '8 hours',
+        'Up to three creatures each increase their hit point maximum and current hit points by 5 for the duration.',
+        'The hit points increase by 5 more for each slot level above 2nd.'),
+    ('alter-self', 'Alter Self', 2, 'Transmutation', false, true, '["Sorcerer", "Wizard"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 hour',
+        'Change your form: adapt to water (swim speed and water breathing), change your appearance, or grow natural weapons that deal 1d6 damage and count as magical.',
+        ''),
+    ('animal-messenger', 'Animal Messenger', 2, 'Enchantment', true, false, '["Bard", "Druid", "Ranger"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": "a morsel of food"}', '24 hours',
+        'A Tiny beast carries a message of up to twenty-five words to a place you have visited and a recipient you describe.',
+        'The duration increases by 48 hours for each slot level above 2nd.'),
+    ('arcane-lock', 'Arcane Lock', 2, 'Abjuration', false, false, '["Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "gold dust worth at least 25 gp, which the spell consumes"}', 'Until dispelled',
+        'A door, window, gate or chest is magically locked. You and creatures you designate open it normally; others need to break it or dispel it.',
+        ''),
+    ('arcanists-magic-aura', 'Arcanist''s Magic Aura', 2, 'Illusion', false, false, '["Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "a small square of silk"}', '24 hours',
+        'Make a creature or object read as a different creature type or as magical or nonmagical to divination spells.',
+        ''),
+    ('augury', 'Augury', 2, 'Divination', true, false, '["Cleric"]',
+        '1 minute', 'Self', '{"verbal": true, "somatic": true, "material": "specially marked sticks, bones or similar tokens worth at least 25 gp"}', 'Instantaneous',
+        'Learn whether a course of action you plan in the next 30 minutes will bring weal, woe, both or nothing.',
+        ''),
+    ('barkskin', 'Barkskin', 2, 'Transmutation', false, true, '["Druid", "Ranger"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "a handful of oak bark"}', 'Concentration, up to 1 hour',
+        'A willing creature''s skin becomes rough and bark-like, and its AC can''t be less than 16.',
+        ''),
+    ('blindness-deafness', 'Blindness/Deafness', 2, 'Necromancy', false, false, '["Bard", "Cleric", "Sorcerer", "Wizard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": false, "material": null}', '1 minute',
+        'A creature must succeed on a Constitution saving throw or be blinded or deafened. It repeats the save at the end of each of its turns.',
+        'You can target one additional creature for each slot level above 2nd.'),
+    ('blur', 'Blur', 2, 'Illusion', false, true, '["Sorcerer", "Wizard"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": false, "material": null}', 'Concentration, up to 1 minute',
+        'Your body becomes blurred, and creatures have disadvantage on attack rolls against you unless they don''t rely on sight.',
+        ''),
+    ('calm-emotions', 'Calm Emotions', 2, 'Enchantment', false, true, '["Bard", "Cleric"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'Humanoids in a 20-foot sphere can be calmed: suppress charm and fear effects, or make hostile creatures indifferent.',
+        ''),
+    ('continual-flame', 'Continual Flame', 2, 'Evocation', false, false, '["Cleric", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "ruby dust worth 50 gp, which the spell consumes"}', 'Until dispelled',
+        'A flame as bright as a torch springs from an object. It gives no heat, uses no oxygen and can''t be smothered or quenched.',
+        ''),
+    ('darkness', 'Darkness', 2, 'Evocation', false, true, '["Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": false, "material": "bat fur and a drop of pitch or piece of coal"}', 'Concentration, up to 10 minutes',
+        'Magical darkness fills a 15-foot sphere. Darkvision can''t see through it, and nonmagical light can''t illuminate it.',
+        ''),
+    ('darkvision', 'Darkvision', 2, 'Transmutation', false, false, '["Druid", "Ranger", "Sorcerer", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "either a pinch of dried carrot or an agate"}', '8 hours',
+        'A willing creature gains darkvision out to 60 feet.',
+        ''),
+    ('detect-thoughts', 'Detect Thoughts', 2, 'Divination', false, true, '["Bard", "Sorcerer", "Wizard"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": "a copper piece"}', 'Concentration, up to 1 minute',
+        'Read the surface thoughts of a creature within 30 feet, or probe deeper if it fails a Wisdom save.',
+        ''),
+    ('enhance-ability', 'Enhance Ability', 2, 'Transmutation', false, true, '["Bard", "Cleric", "Druid", "Sorcerer"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "fur or a feather from a beast"}', 'Concentration, up to 1 hour',
+        'Grant a creature advantage on checks with one ability, plus a benefit such as temporary hit points or halved falling damage.',
+        'You can target one additional creature for each slot level above 2nd.'),
+    ('enlarge-reduce', 'Enlarge/Reduce', 2, 'Transmutation', false, true, '["Sorcerer", "Wizard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": "a pinch of powdered iron"}', 'Concentration, up to 1 minute',
+        'A creature or object doubles in size, dealing an extra 1d4 weapon damage, or halves in size, dealing 1d4 less.',
+        ''),
+    ('enthrall', 'Enthrall', 2, 'Enchantment', false, false, '["Bard", "Warlock"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', '1 minute',
+        'Creatures that can hear you and fail a Wisdom save have disadvantage on Wisdom (Perception) checks to notice anyone but you.',
+        ''),
+    ('find-steed', 'Find Steed', 2, 'Conjuration', false, false, '["Paladin"]',
+        '10 minutes', '30 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Summon a spirit that takes the form of an unusually intelligent, strong and loyal steed.',
+        ''),
+    ('find-traps', 'Find Traps', 2, 'Divination', false, false, '["Cleric", "Druid", "Ranger"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Sense the presence of any trap within range that is within line of sight, but not its location.',
+        ''),
+    ('flame-blade', 'Flame Blade', 2, 'Evocation', false, true, '["Druid"]',
+        '1 bonus action', 'Self', '{"verbal": true, "somatic": true, "material": "leaf of sumac"}', 'Concentration, up to 10 minutes',
+        'Evoke a fiery blade in your hand and make melee spell attacks with it for 3d6 fire damage.',
+        'The damage increases by 1d6 for every two slot levels above 2nd.'),
+    ('flaming-sphere', 'Flaming Sphere', 2, 'Conjuration', false, true, '["Druid", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": "a bit of tallow, a pinch of brimstone and a dusting of powdered iron"}', 'Concentration, up to 1 minute',
+        'A 5-foot sphere of fire deals 2d6 fire damage to creatures that end their turn next to it. Ram it into creatures as a bonus action.',
+        'The damage increases by 1d6 for each slot level above 2nd.'),
+    ('gentle-repose', 'Gentle Repose', 2, 'Necromancy', true, false, '["Cleric", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "a pinch of salt and a copper piece on each of the corpse''s eyes"}', '10 days',
+        'A corpse is protected from decay and can''t become undead, and the time doesn''t count against raising it.',
+        ''),
+    ('gust-of-wind', 'Gust of Wind', 2, 'Evocation', false, true, '["Druid", "Sorcerer", "Wizard"]',
+        '1 action', 'Self (60-foot line)', '{"verbal": true, "somatic": true, "material": "a legume seed"}', 'Concentration, up to 1 minute',
+        'A 60-foot line of strong wind pushes creatures 15 feet away on a failed Strength save and disperses gas and fog.',
+        ''),
+    ('heat-metal', 'Heat Metal', 2, 'Transmutation', false, true, '["Bard", "Druid"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": "a piece of iron and a flame"}', 'Concentration, up to 1 minute',
+        'A manufactured metal object glows red-hot, dealing 2d8 fire damage to anyone touching it each turn.',
+        'The damage increases by 1d8 for each slot level above 2nd.'),
+    ('hold-person', 'Hold Person', 2, 'Enchantment', false, true, '["Bard", "Cleric", "Druid", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": "a small, straight piece of iron"}', 'Concentration, up to 1 minute',
+        'A humanoid must succeed on a Wisdom save or be paralyzed, repeating the save at the end of each of its turns.',
+        'You can target one additional humanoid for each slot level above 2nd.'),
+    ('invisibility', 'Invisibility', 2, 'Illusion', false, true, '["Bard", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "an eyelash encased in gum arabic"}', 'Concentration, up to 1 hour',
+        'A creature becomes invisible until it attacks or casts a spell.',
+        'You can target one additional creature for each slot level above 2nd.'),
+    ('knock', 'Knock', 2, 'Transmutation', false, false, '["Bard", "Sorcerer", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": false, "material": null}', 'Instantaneous',
+        'An object that is stuck, locked or barred opens with a loud knock audible 300 feet away.',
+        ''),
+    ('lesser-restoration', 'Lesser Restoration', 2, 'Abjuration', false, false, '["Bard", "Cleric", "Druid", "Paladin", "Ranger"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'End one disease or condition afflicting a creature: blinded, deafened, paralyzed or poisoned.',
+        ''),
+    ('levitate', 'Levitate', 2, 'Transmutation', false, true, '["Sorcerer", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": "a small leather loop or a piece of golden wire bent into a cup shape"}', 'Concentration, up to 10 minutes',
+        'A creature or object rises up to 20 feet and hangs in the air. Unwilling creatures get a Constitution save.',
+        ''),
+    ('locate-animals-or-plants', 'Locate Animals or Plants', 2, 'Divination', true, false, '["Bard", "Druid", "Ranger"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": "a bit of fur from a bloodhound"}', 'Instantaneous',
+        'Learn the direction and distance of the closest creature or plant of a kind you name within 5 miles.',
+        ''),
+    ('locate-object', 'Locate Object', 2, 'Divination', false, true, '["Bard", "Cleric", "Druid", "Paladin", "Ranger", "Wizard"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": "a forked twig"}', 'Concentration, up to 10 minutes',
+        'Sense the direction of an object you are familiar with, or the nearest of a kind, within 1,000 feet.',
+        ''),
+    ('magic-mouth', 'Magic Mouth', 2, 'Illusion', true, false, '["Bard", "Wizard"]',
+        '1 minute', '30 feet', '{"verbal": true, "somatic": true, "material": "a small bit of honeycomb and jade dust worth at least 10 gp, which the spell consumes"}', 'Until dispelled',
+        'An object speaks a message of up to 25 words when a trigger condition you set is met.',
+        ''),
+    ('magic-weapon', 'Magic Weapon', 2, 'Transmutation', false, true, '["Paladin", "Wizard"]',
+        '1 bonus action', 'Touch', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 hour',
+        'A nonmagical weapon becomes a +1 magic weapon.',
+        'The bonus increases to +2 with a 4th-level slot and +3 with a 6th-level slot.'),
+    ('mirror-image', 'Mirror Image', 2, 'Illusion', false, false, '["Sorcerer", "Warlock", "Wizard"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": null}', '1 minute',
+        'Three illusory duplicates of yourself appear and may take attacks meant for you.',
+        ''),
+    ('misty-step', 'Misty Step', 2, 'Conjuration', false, false, '["Sorcerer", "Warlock", "Wizard"]',
+        '1 bonus action', 'Self', '{"verbal": true, "somatic": false, "material": null}', 'Instantaneous',
+        'Briefly surrounded by silvery mist, you teleport up to 30 feet to an unoccupied space that you can see.',
+        ''),
+    ('moonbeam', 'Moonbeam', 2, 'Evocation', false, true, '["Druid"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": "several seeds of any moonseed plant and a piece of opalescent feldspar"}', 'Concentration, up to 1 minute',
+        'A 5-foot-radius beam of moonlight deals 2d10 radiant damage on a failed Constitution save. Move it 60 feet as an action.',
+        'The damage increases by 1d10 for each slot level above 2nd.'),
+    ('pass-without-trace', 'Pass without Trace', 2, 'Abjuration', false, true, '["Druid", "Ranger"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": "ashes from a burned leaf of mistletoe and a sprig of spruce"}', 'Concentration, up to 1 hour',
+        'You and allies within 30 feet get +10 to Dexterity (Stealth) checks and leave no tracks.',
+        ''),
+    ('prayer-of-healing', 'Prayer of Healing', 2, 'Evocation', false, false, '["Cleric"]',
+        '10 minutes', '30 feet', '{"verbal": true, "somatic": false, "material": null}', 'Instantaneous',
+        'Up to six creatures each regain 2d8 + your spellcasting modifier hit points.',
+        'The healing increases by 1d8 for each slot level above 2nd.'),
+    ('protection-from-poison', 'Protection from Poison', 2, 'Abjuration', false, false, '["Cleric", "Druid", "Paladin", "Ranger"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": null}', '1 hour',
+        'Neutralize one poison in a creature, and give it advantage on saves against poison and resistance to poison damage.',
+        ''),
+    ('ray-of-enfeeblement', 'Ray of Enfeeblement', 2, 'Necromancy', false, true, '["Warlock", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'Ranged spell attack: on a hit, the target deals only half damage with Strength-based weapon attacks.',
+        ''),
+    ('rope-trick', 'Rope Trick', 2, 'Transmutation', false, false, '["Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "powdered corn extract and a twisted loop of parchment"}', '1 hour',
+        'A rope rises to an invisible extradimensional space that holds up to eight Medium creatures.',
+        ''),
+    ('scorching-ray', 'Scorching Ray', 2, 'Evocation', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Three rays of fire each make a ranged spell attack for 2d6 fire damage.',
+        'One more ray for each slot level above 2nd.'),
+    ('see-invisibility', 'See Invisibility', 2, 'Divination', false, false, '["Bard", "Sorcerer", "Wizard"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": "a pinch of talc and a small sprinkling of powdered silver"}', '1 hour',
+        'You see invisible creatures and objects, and into the Ethereal Plane.',
+        ''),
+    ('shatter', 'Shatter', 2, 'Evocation', false, false, '["Bard", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": "a chip of mica"}', 'Instantaneous',
+        'A painful ringing in a 10-foot sphere: creatures make a Constitution save against 3d8 thunder damage.',
+        'The damage increases by 1d8 for each slot level above 2nd.'),
+    ('silence', 'Silence', 2, 'Illusion', true, true, '["Bard", "Cleric", "Ranger"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 10 minutes',
+        'No sound can be created within or pass through a 20-foot sphere. Creatures inside are deafened and can''t cast spells with verbal components.',
+        ''),
+    ('spider-climb', 'Spider Climb', 2, 'Transmutation', false, true, '["Sorcerer", "Warlock", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "a drop of bitumen and a spider"}', 'Concentration, up to 1 hour',
+        'A willing creature can climb walls and ceilings, hands free, and gains a climbing speed.',
+        ''),
+    ('spike-growth', 'Spike Growth', 2, 'Transmutation', false, true, '["Druid", "Ranger"]',
+        '1 action', '150 feet', '{"verbal": true, "somatic": true, "material": "seven sharp thorns or seven small twigs, each sharpened to a point"}', 'Concentration, up to 10 minutes',
+        'Ground in a 20-foot radius sprouts hidden spikes: 2d4 piercing damage for every 5 feet moved.',
+        ''),
+    ('spiritual-weapon', 'Spiritual Weapon', 2, 'Evocation', false, false, '["Cleric"]',
+        '1 bonus action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', '1 minute',
+        'A floating spectral weapon makes melee spell attacks for 1d8 + your spellcasting modifier force damage as a bonus action.',
+        'The damage increases by 1d8 for every two slot levels above 2nd.'),
+    ('suggestion', 'Suggestion', 2, 'Enchantment', false, true, '["Bard", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": false, "material": "a snake''s tongue and either a bit of honeycomb or a drop of sweet oil"}', 'Concentration, up to 8 hours',
+        'A creature that fails a Wisdom save follows a reasonable-sounding course of activity you suggest.',
+        ''),
+    ('warding-bond', 'Warding Bond', 2, 'Abjuration', false, false, '["Cleric"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "a pair of platinum rings worth at least 50 gp each"}', '1 hour',
+        'A willing creature gets +1 AC and saves and resistance to all damage, while you take the same damage it does.',
+        ''),
+    ('web', 'Web', 2, 'Conjuration', false, true, '["Sorcerer", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": "a bit of spiderweb"}', 'Concentration, up to 1 hour',
+        'Thick, sticky webbing fills a 20-foot cube, restraining creatures that fail a Dexterity save.',
+        ''),
+    ('zone-of-truth', 'Zone of Truth', 2, 'Enchantment', false, false, '["Bard", "Cleric", "Paladin"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', '10 minutes',
+        'Creatures in a 15-foot sphere that fail a Charisma save can''t deliberately lie.',
+        ''),
+    ('animate-dead', 'Animate Dead', 3, 'Necromancy', false, false, '["Cleric", "Wizard"]',
+        '1 minute', '10 feet', '{"verbal": true, "somatic": true, "material": "a drop of blood, a piece of flesh and a pinch of bone dust"}', 'Instantaneous',
+        'Turn a pile of bones or a corpse into a skeleton or zombie under your control for 24 hours. Recast to keep control of up to four creatures.',
+        'Animate or reassert control over two additional undead for each slot level above 3rd.'),
+    ('beacon-of-hope', 'Beacon of Hope', 3, 'Abjuration', false, true, '["Cleric"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'Any number of creatures gain advantage on Wisdom and death saving throws and regain the maximum possible hit points from healing.',
+        ''),
+    ('bestow-curse', 'Bestow Curse', 3, 'Necromancy', false, true, '["Bard", "Cleric", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'A creature must succeed on a Wisdom saving throw or be cursed: disadvantage on one ability, disadvantage on attacks against you, lost turns, or extra necrotic damage from you.',
+        'The duration grows with the slot: 10 minutes at 4th level, 8 hours at 5th, 24 hours at 7th, and until dispelled at 9th, without concentration from 5th.'),
+    ('blink', 'Blink', 3, 'Transmutation', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": null}', '1 minute',
+        'At the end of each of your turns, roll a d20: on 11 or higher you vanish to the Ethereal Plane until the start of your next turn.',
+        ''),
+    ('call-lightning', 'Call Lightning', 3, 'Conjuration', false, true, '["Druid"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 10 minutes',
+        'A storm cloud appears overhead. Each turn you can call a bolt down on a point: creatures within 5 feet make a Dexterity save against 3d10 lightning damage.',
+        'The damage increases by 1d10 for each slot level above 3rd.'),
+    ('clairvoyance', 'Clairvoyance', 3, 'Divination', false, true, '["Bard", "Cleric", "Sorcerer", "Wizard"]',
+        '10 minutes', '1 mile', '{"verbal": true, "somatic": true, "material": "a focus worth at least 100 gp"}', 'Concentration, up to 10 minutes',
+        'Create an invisible sensor in a familiar or obvious location within a mile that you can see or hear through.',
+        ''),
+    ('conjure-animals', 'Conjure Animals', 3, 'Conjuration', false, true, '["Druid", "Ranger"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 hour',
+        'Summon fey spirits in the form of beasts: one of challenge rating 2, two of 1, four of 1/2 or eight of 1/4 or lower.',
+        'Summon twice as many creatures with a 5th-level slot, three times with 7th and four times with 9th.'),
+    ('counterspell', 'Counterspell', 3, 'Abjuration', false, false, '["Sorcerer", "Warlock", "Wizard"]',
+        '1 reaction', '60 feet', '{"verbal": false, "somatic": true, "material": null}', 'Instantaneous',
+        'Interrupt a creature casting a spell. Spells of 3rd level or lower fail; for higher levels, make an ability check with your spellcasting ability against DC 10 + the spell''s level.',
+        'The interrupted spell automatically fails if its level is no higher than the slot you used.'),
+    ('create-food-and-water', 'Create Food and Water', 3, 'Conjuration', false, false, '["Cleric", "Paladin"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Create 45 pounds of food and 30 gallons of water, enough to sustain fifteen humanoids or five steeds for 24 hours.',
+        ''),
+    ('daylight', 'Daylight', 3, 'Evocation', false, false, '["Cleric", "Druid", "Paladin", "Ranger", "Sorcerer"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', '1 hour',
+        'A 60-foot sphere of bright light spreads from a point or an object you choose, dispelling darkness of 3rd level or lower.',
+        ''),
+    ('dispel-magic', 'Dispel Magic', 3, 'Abjuration', false, false, '["Bard", "Cleric", "Druid", "Paladin", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'End spells of 3rd level or lower on a creature, object or magical effect. Higher-level spells need an ability check against DC 10 + the spell''s level.',
+        'Spells no higher than the slot you used end automatically.'),
+    ('fear', 'Fear', 3, 'Illusion', false, true, '["Bard", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', 'Self (30-foot cone)', '{"verbal": true, "somatic": true, "material": "a white feather or the heart of a hen"}', 'Concentration, up to 1 minute',
+        'Creatures in a 30-foot cone must succeed on a Wisdom save or drop what they hold and become frightened, fleeing from you.',
+        ''),
+    ('fireball', 'Fireball', 3, 'Evocation', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', '150 feet', '{"verbal": true, "somatic": true, "material": "a tiny ball of bat guano and sulfur"}', 'Instantaneous',
+        'Each creature in a 20-foot-radius sphere makes a Dexterity save, taking 8d6 fire damage on a failure or half as much on a success.',
+        'The damage increases by 1d6 for each slot level above 3rd.'),
+    ('fly', 'Fly', 3, 'Transmutation', false, true, '["Sorcerer", "Warlock", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "a wing feather from any bird"}', 'Concentration, up to 10 minutes',
+        'A willing creature gains a flying speed of 60 feet.',
+        'You can target one additional creature for each slot level above 3rd.'),
+    ('gaseous-form', 'Gaseous Form', 3, 'Transmutation', false, true, '["Sorcerer", "Warlock", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "a bit of gauze and a wisp of smoke"}', 'Concentration, up to 1 hour',
+        'A willing creature turns into a misty cloud that can fly slowly and pass through small openings.',
+        ''),
+    ('glyph-of-warding', 'Glyph of Warding', 3, 'Abjuration', false, false, '["Bard", "Cleric", "Wizard"]',
+        '1 hour', 'Touch', '{"verbal": true, "somatic": true, "material": "incense and powdered diamond worth at least 200 gp, which the spell consumes"}', 'Until dispelled or triggered',
+        'Inscribe a hidden glyph that triggers an explosive rune (5d8 damage) or a stored spell when its conditions are met.',
+        'The rune''s damage increases by 1d8, and it can store a spell one level higher, for each slot level above 3rd.'),
+    ('haste', 'Haste', 3, 'Transmutation', false, true, '["Sorcerer", "Wizard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": "a shaving of licorice root"}', 'Concentration, up to 1 minute',
+        'A willing creature gets double speed, +2 AC, advantage on Dexterity saves and an extra limited action each turn. It loses a turn when the spell ends.',
+        ''),
+    ('hypnotic-pattern', 'Hypnotic Pattern', 3, 'Illusion', false, true, '["Bard", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '120 feet', '{"verbal": false, "somatic": true, "material": "a glowing stick of incense or a crystal vial filled with phosphorescent material"}', 'Concentration, up to 1 minute',
+        'Creatures in a 30-foot cube that see the pattern and fail a Wisdom save are charmed and incapacitated.',
+        ''),
+    ('lightning-bolt', 'Lightning Bolt', 3, 'Evocation', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', 'Self (100-foot line)', '{"verbal": true, "somatic": true, "material": "a bit of fur and a rod of amber, crystal or glass"}', 'Instantaneous',
+        'Each creature in a 100-foot line makes a Dexterity save, taking 8d6 lightning damage on a failure or half as much on a success.',
+        'The damage increases by 1d6 for each slot level above 3rd.'),
+    ('magic-circle', 'Magic Circle', 3, 'Abjuration', false, false, '["Cleric", "Paladin", "Warlock", "Wizard"]',
+        '1 minute', '10 feet', '{"verbal": true, "somatic": true, "material": "holy water or powdered silver and iron worth at least 100 gp, which the spell consumes"}', '1 hour',
+        'A 10-foot cylinder bars celestials, elementals, fey, fiends or undead from entering, charming or attacking those inside, or traps them within.',
+        'The duration increases by 1 hour for each slot level above 3rd.'),
+    ('major-image', 'Major Image', 3, 'Illusion', false, true, '["Bard", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": "a bit of fleece"}', 'Concentration, up to 10 minutes',
+        'Create an illusion of an object, creature or phenomenon no larger than a 20-foot cube, with sound, smell and temperature.',
+        'With a 6th-level slot or higher, the illusion lasts until dispelled without concentration.'),
+    ('mass-healing-word', 'Mass Healing Word', 3, 'Evocation', false, false, '["Cleric"]',
+        '1 bonus action', '60 feet', '{"verbal": true, "somatic": false, "material": null}', 'Instantaneous',
+        'Up to six creatures each regain 1d4 + your spellcasting modifier hit points.',
+        'The healing increases by 1d4 for each slot level above 3rd.'),
+    ('meld-into-stone', 'Meld into Stone', 3, 'Transmutation', true, false, '["Cleric"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": null}', '8 hours',
+        'Step into a stone object or surface large enough to hold you, along with your gear.',
+        ''),
+    ('nondetection', 'Nondetection', 3, 'Abjuration', false, false, '["Bard", "Ranger", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "a pinch of diamond dust worth 25 gp, which the spell consumes"}', '8 hours',
+        'Hide a creature, place or object from divination magic and magical scrying sensors.',
+        ''),
+    ('phantom-steed', 'Phantom Steed', 3, 'Illusion', true, false, '["Wizard"]',
+        '1 minute', '30 feet', '{"verbal": true, "somatic": true, "material": null}', '1 hour',
+        'A quasi-real, horselike creature with a speed of 100 feet appears for you or a creature you choose to ride.',
+        ''),
+    ('plant-growth', 'Plant Growth', 3, 'Transmutation', false, false, '["Bard", "Druid", "Ranger"]',
+        '1 action or 8 hours', '150 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Plants in a 100-foot radius become thick and overgrown, or land within half a mile becomes enriched for a year.',
+        ''),
+    ('protection-from-energy', 'Protection from Energy', 3, 'Abjuration', false, true, '["Cleric", "Druid", "Ranger", "Sorcerer", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 hour',
+        'A willing creature gains resistance to acid, cold, fire, lightning or thunder damage.',
+        ''),
+    ('remove-curse', 'Remove Curse', 3, 'Abjuration', false, false, '["Cleric", "Paladin", "Warlock", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'End all curses affecting a creature or object. Cursed magic items stay cursed, but the attunement breaks.',
+        ''),
+    ('revivify', 'Revivify', 3, 'Necromancy', false, false, '["Cleric", "Paladin"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "diamonds worth 300 gp, which the spell consumes"}', 'Instantaneous',
+        'Return a creature that died within the last minute to life with 1 hit point.',
+        ''),
+    ('sending', 'Sending', 3, 'Evocation', false, false, '["Bard", "Cleric", "Wizard"]',
+        '1 action', 'Unlimited', '{"verbal": true, "somatic": true, "material": "a short piece of fine copper wire"}', '1 round',
+        'Send a message of twenty-five words or less to a creature you are familiar with, which can reply in kind.',
+        ''),
+    ('sleet-storm', 'Sleet Storm', 3, 'Conjuration', false, true, '["Druid", "Sorcerer", "Wizard"]',
+        '1 action', '150 feet', '{"verbal": true, "somatic": true, "material": "a pinch of dust and a few drops of water"}', 'Concentration, up to 1 minute',
+        'Freezing rain and sleet fill a 40-foot-radius cylinder, heavily obscuring it, dousing flames and knocking creatures prone.',
+        ''),
+    ('slow', 'Slow', 3, 'Transmutation', false, true, '["Sorcerer", "Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": "a drop of molasses"}', 'Concentration, up to 1 minute',
+        'Up to six creatures that fail a Wisdom save have halved speed, -2 AC and Dexterity saves, and fewer actions.',
+        ''),
+    ('speak-with-dead', 'Speak with Dead', 3, 'Necromancy', false, false, '["Bard", "Cleric"]',
+        '1 action', '10 feet', '{"verbal": true, "somatic": true, "material": "burning incense"}', '10 minutes',
+        'A corpse answers up to five questions with what it knew in life.',
+        ''),
+    ('speak-with-plants', 'Speak with Plants', 3, 'Transmutation', false, false, '["Bard", "Druid", "Ranger"]',
+        '1 action', 'Self (30-foot radius)', '{"verbal": true, "somatic": true, "material": null}', '10 minutes',
+        'Plants within 30 feet can communicate with you and follow simple commands.',
+        ''),
+    ('spirit-guardians', 'Spirit Guardians', 3, 'Conjuration', false, true, '["Cleric"]',
+        '1 action', 'Self (15-foot radius)', '{"verbal": true, "somatic": true, "material": "a holy symbol"}', 'Concentration, up to 10 minutes',
+        'Spirits protect you in a 15-foot radius, halving enemies'' speed. Enemies make a Wisdom save against 3d8 radiant or necrotic damage.',
+        'The damage increases by 1d8 for each slot level above 3rd.'),
+    ('stinking-cloud', 'Stinking Cloud', 3, 'Conjuration', false, true, '["Bard", "Sorcerer", "Wizard"]',
+        '1 action', '90 feet', '{"verbal": true, "somatic": true, "material": "a rotten egg or several skunk cabbage leaves"}', 'Concentration, up to 1 minute',
+        'A 20-foot sphere of nauseating gas: creatures that fail a Constitution save waste their action retching.',
+        ''),
+    ('tiny-hut', 'Tiny Hut', 3, 'Evocation', true, false, '["Bard", "Wizard"]',
+        '1 minute', 'Self (10-foot-radius hemisphere)', '{"verbal": true, "somatic": true, "material": "a small crystal bead"}', '8 hours',
+        'A dome of force surrounds you for 8 hours, keeping out creatures, spells and the weather.',
+        ''),
+    ('tongues', 'Tongues', 3, 'Divination', false, false, '["Bard", "Cleric", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": false, "material": "a small clay model of a ziggurat"}', '1 hour',
+        'A creature understands any spoken language it hears and is understood by any creature that knows a language.',
+        ''),
+    ('vampiric-touch', 'Vampiric Touch', 3, 'Necromancy', false, true, '["Warlock", "Wizard"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'Melee spell attack for 3d6 necrotic damage, and you regain half the damage dealt. Repeat it as an action each turn.',
+        'The damage increases by 1d6 for each slot level above 3rd.'),
+    ('water-breathing', 'Water Breathing', 3, 'Transmutation', true, false, '["Druid", "Ranger", "Sorcerer", "Wizard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": "a short reed or piece of straw"}', '24 hours',
+        'Up to ten willing creatures can breathe underwater.',
+        ''),
+    ('water-walk', 'Water Walk', 3, 'Transmutation', true, false, '["Cleric", "Druid", "Ranger", "Sorcerer"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": "a piece of cork"}', '1 hour',
+        'Up to ten willing creatures can move across any liquid surface as if it were solid ground.',
+        ''),
+    ('wind-wall', 'Wind Wall', 3, 'Evocation', false, true, '["Druid", "Ranger"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": "a tiny fan and a feather of exotic origin"}', 'Concentration, up to 1 minute',
+        'A wall of strong wind deals 3d8 bludgeoning damage as it forms, keeps out gases and deflects arrows and small projectiles.',
+        ''),
+    ('arcane-eye', 'Arcane Eye', 4, 'Divination', false, true, '["Wizard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": "a bit of bat fur"}', 'Concentration, up to 1 hour',
+        'An invisible, floating eye with darkvision that you see through and can move 30 feet each turn.',
+        ''),
+    ('banishment', 'Banishment', 4, 'Abjuration', false, true, '["Cleric", "Paladin", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": "an item distasteful to the target"}', 'Concentration, up to 1 minute',
+        'A creature must succeed on a Charisma saving throw or be banished to a harmless demiplane, or to its home plane if it is native to another.',
+        'You can target one additional creature for each slot level above 4th.'),
+    ('black-tentacles', 'Black Tentacles', 4, 'Conjuration', false, true, '["Wizard"]',
+        '1 action', '90 feet', '{"verbal": true, "somatic": true, "material": "a piece of tentacle from a giant octopus or a giant squid"}', 'Concentration, up to 1 minute',
+        'Tentacles fill a 20-foot square. Creatures there must succeed on a Dexterity saving throw or take 3d6 bludgeoning damage and be restrained.',
+        ''),
+    ('blight', 'Blight', 4, 'Necromancy', false, false, '["Druid", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'A creature must make a Constitution saving throw, taking 8d8 necrotic damage on a failure or half as much on a success. Plants fail automatically and take maximum damage.',
+        'The damage increases by 1d8 for each slot level above 4th.'),
+    ('compulsion', 'Compulsion', 4, 'Enchantment', false, true, '["Bard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'Creatures that fail a Wisdom saving throw must move in a direction you choose on each of their turns.',
+        ''),
+    ('confusion', 'Confusion', 4, 'Enchantment', false, true, '["Bard", "Druid", "Sorcerer", "Wizard"]',
+        '1 action', '90 feet', '{"verbal": true, "somatic": true, "material": "three nut shells"}', 'Concentration, up to 1 minute',
+        'Creatures in a 10-foot sphere that fail a Wisdom save act randomly: wandering, doing nothing or attacking whoever is nearest.',
+        'The sphere''s radius increases by 5 feet for each slot level above 4th.'),
+    ('conjure-minor-elementals', 'Conjure Minor Elementals', 4, 'Conjuration', false, true, '["Druid", "Wizard"]',
+        '1 minute', '90 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 hour',
+        'Summon elementals: one of challenge rating 2, two of 1, four of 1/2 or eight of 1/4 or lower.',
+        'Summon twice as many creatures with a 6th-level slot and three times as many with an 8th-level slot.'),
+    ('conjure-woodland-beings', 'Conjure Woodland Beings', 4, 'Conjuration', false, true, '["Druid", "Ranger"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": "one holly berry per creature summoned"}', 'Concentration, up to 1 hour',
+        'Summon fey creatures: one of challenge rating 2, two of 1, four of 1/2 or eight of 1/4 or lower.',
+        'Summon twice as many creatures with a 6th-level slot and three times as many with an 8th-level slot.'),
+    ('control-water', 'Control Water', 4, 'Transmutation', false, true, '["Cleric", "Druid", "Wizard"]',
+        '1 action', '300 feet', '{"verbal": true, "somatic": true, "material": "a drop of water and a pinch of dust"}', 'Concentration, up to 10 minutes',
+        'Control freestanding water in a 100-foot cube: flood, part, redirect its flow or form a whirlpool.',
+        ''),
+    ('death-ward', 'Death Ward', 4, 'Abjuration', false, false, '["Cleric", "Paladin"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": null}', '8 hours',
+        'The first time the target would drop to 0 hit points, it drops to 1 instead, or an instant-death effect is negated.',
+        ''),
+    ('dimension-door', 'Dimension Door', 4, 'Conjuration', false, false, '["Bard", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '500 feet', '{"verbal": true, "somatic": false, "material": null}', 'Instantaneous',
+        'Teleport yourself, and one willing creature you carry, to a spot within 500 feet that you can see, picture or describe.',
+        ''),
+    ('divination', 'Divination', 4, 'Divination', true, false, '["Cleric"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": "incense and a sacrificial offering worth at least 25 gp, which the spell consumes"}', 'Instantaneous',
+        'Ask your god a single question about a goal, event or activity within the next 7 days and receive a truthful reply.',
+        ''),
+    ('dominate-beast', 'Dominate Beast', 4, 'Enchantment', false, true, '["Druid", "Sorcerer"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'A beast must succeed on a Wisdom save or be charmed by you, and you can command it telepathically.',
+        'The duration lengthens with the slot: 10 minutes at 5th level, 1 hour at 6th and 8 hours at 7th or higher.'),
+    ('fabricate', 'Fabricate', 4, 'Transmutation', false, false, '["Wizard"]',
+        '10 minutes', '120 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Turn raw materials into products of the same material, such as a bridge from trees or clothes from flax.',
+        ''),
+    ('faithful-hound', 'Faithful Hound', 4, 'Conjuration', false, false, '["Wizard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": "a tiny silver whistle, a piece of bone and a thread"}', '8 hours',
+        'An invisible watchdog guards an area, barks at intruders and bites hostile creatures for 4d8 piercing damage.',
+        ''),
+    ('fire-shield', 'Fire Shield', 4, 'Evocation', false, false, '["Wizard"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": "a bit of phosphorus or a firefly"}', '10 minutes',
+        'Flames wreathe you, granting resistance to cold or fire damage. Creatures that hit you in melee take 2d8 fire or cold damage.',
+        ''),
+    ('freedom-of-movement', 'Freedom of Movement', 4, 'Abjuration', false, false, '["Bard", "Cleric", "Druid", "Ranger"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "a leather strap bound around the arm or similar appendage"}', '1 hour',
+        'A willing creature ignores difficult terrain and can''t be paralyzed, restrained or magically slowed.',
+        ''),
+    ('giant-insect', 'Giant Insect', 4, 'Transmutation', false, true, '["Druid"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 10 minutes',
+        'Turn up to ten centipedes, three spiders, five wasps or one scorpion into giant versions that obey you.',
+        ''),
+    ('greater-invisibility', 'Greater Invisibility', 4, 'Illusion', false, true, '["Bard", "Sorcerer", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'A creature becomes invisible, even while it attacks or casts spells.',
+        ''),
+    ('guardian-of-faith', 'Guardian of Faith', 4, 'Conjuration', false, false, '["Cleric"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": false, "material": null}', '8 hours',
+        'A spectral guardian hovers near you. Hostile creatures that come within 10 feet make a Dexterity save against 20 radiant damage, up to 60 in total.',
+        ''),
+    ('hallucinatory-terrain', 'Hallucinatory Terrain', 4, 'Illusion', false, false, '["Bard", "Druid", "Warlock", "Wizard"]',
+        '10 minutes', '300 feet', '{"verbal": true, "somatic": true, "material": "a stone, a twig and a bit of green plant"}', '24 hours',
+        'Make natural terrain in a 150-foot cube look, sound and smell like a different kind of terrain.',
+        ''),
+    ('ice-storm', 'Ice Storm', 4, 'Evocation', false, false, '["Druid", "Sorcerer", "Wizard"]',
+        '1 action', '300 feet', '{"verbal": true, "somatic": true, "material": "a pinch of dust and a few drops of water"}', 'Instantaneous',
+        'Hail pounds a 20-foot-radius cylinder: a Dexterity save against 2d8 bludgeoning and 4d6 cold damage, and the ground becomes difficult terrain.',
+        'The bludgeoning damage increases by 1d8 for each slot level above 4th.'),
+    ('locate-creature', 'Locate Creature', 4, 'Divination', false, true, '["Bard", "Cleric", "Druid", "Paladin", "Ranger", "Wizard"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": "a bit of fur from a bloodhound"}', 'Concentration, up to 1 hour',
+        'Sense the direction of a creature you are familiar with, or the nearest of a kind, within 1,000 feet.',
+        ''),
+    ('phantasmal-killer', 'Phantasmal Killer', 4, 'Illusion', false, true, '["Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'A creature that fails a Wisdom save is frightened by its deepest fears and takes 4d10 psychic damage each turn it fails again.',
+        'The damage increases by 1d10 for each slot level above 4th.'),
+    ('polymorph', 'Polymorph', 4, 'Transmutation', false, true, '["Bard", "Druid", "Sorcerer", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": "a caterpillar cocoon"}', 'Concentration, up to 1 hour',
+        'A creature that fails a Wisdom save turns into a beast of challenge rating no higher than its own level or rating.',
+        ''),
+    ('private-sanctum', 'Private Sanctum', 4, 'Abjuration', false, false, '["Wizard"]',
+        '10 minutes', '120 feet', '{"verbal": true, "somatic": true, "material": "a thin sheet of lead, a piece of opaque glass, a wad of cotton or cloth, and powdered chrysolite"}', '24 hours',
+        'Secure an area of up to 100 feet against sound, vision, divination, teleportation and planar travel.',
+        'The area''s size increases by 100 feet for each slot level above 4th.'),
+    ('resilient-sphere', 'Resilient Sphere', 4, 'Evocation', false, true, '["Wizard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": "a hemispherical piece of clear crystal and a matching hemispherical piece of gum arabic"}', 'Concentration, up to 1 minute',
+        'A sphere of force encloses a creature or object that fails a Dexterity save. Nothing can pass through it.',
+        ''),
+    ('secret-chest', 'Secret Chest', 4, 'Conjuration', false, false, '["Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "an exquisite chest worth at least 5,000 gp and a Tiny replica worth at least 50 gp"}', 'Instantaneous',
+        'Hide a chest and its contents on the Ethereal Plane, recalling it with its tiny replica.',
+        ''),
+    ('stone-shape', 'Stone Shape', 4, 'Transmutation', false, false, '["Cleric", "Druid", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "soft clay"}', 'Instantaneous',
+        'Form a stone object of Medium size or smaller into any shape, such as a weapon, coffer or passage.',
+        ''),
+    ('stoneskin', 'Stoneskin', 4, 'Abjuration', false, true, '["Druid", "Ranger", "Sorcerer", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "diamond dust worth 100 gp, which the spell consumes"}', 'Concentration, up to 1 hour',
+        'A willing creature gains resistance to nonmagical bludgeoning, piercing and slashing damage.',
+        ''),
+    ('wall-of-fire', 'Wall of Fire', 4, 'Evocation', false, true, '["Druid", "Sorcerer", "Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": "a small piece of phosphorus"}', 'Concentration, up to 1 minute',
+        'A wall of fire up to 60 feet long deals 5d8 fire damage to creatures that end their turn near its hot side.',
+        'The damage increases by 1d8 for each slot level above 4th.'),
+    ('animate-objects', 'Animate Objects', 5, 'Transmutation', false, true, '["Bard", "Sorcerer", "Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'Up to ten nonmagical objects come to life and obey your commands, fighting with statistics based on their size.',
+        'You can animate two additional objects for each slot level above 5th.'),
+    ('antilife-shell', 'Antilife Shell', 5, 'Abjuration', false, true, '["Druid"]',
+        '1 action', 'Self (10-foot radius)', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 hour',
+        'A barrier extends 10 feet around you that creatures other than undead and constructs can''t pass or reach through.',
+        ''),
+    ('arcane-hand', 'Arcane Hand', 5, 'Evocation', false, true, '["Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": "an eggshell and a snakeskin glove"}', 'Concentration, up to 1 minute',
+        'A Large hand of force can punch (4d8 force), push, grapple or interpose itself as cover.',
+        'The clenched fist damage increases by 2d8 and the grasping hand damage by 2d6 for each slot level above 5th.'),
+    ('awaken', 'Awaken', 5, 'Transmutation', false, false, '["Bard", "Druid"]',
+        '8 hours', 'Touch', '{"verbal": true, "somatic": true, "material": "an agate worth at least 1,000 gp, which the spell consumes"}', 'Instantaneous',
+        'A beast or plant with Intelligence 3 or less gains Intelligence 10, speech and, for plants, mobility. It is charmed by you for 30 days.',
+        ''),
+    ('cloudkill', 'Cloudkill', 5, 'Conjuration', false, true, '["Sorcerer", "Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 10 minutes',
+        'A 20-foot sphere of poisonous fog drifts 10 feet away from you each turn. Creatures in it make a Constitution save against 5d8 poison damage.',
+        'The damage increases by 1d8 for each slot level above 5th.'),
+    ('commune', 'Commune', 5, 'Divination', true, false, '["Cleric"]',
+        '1 minute', 'Self', '{"verbal": true, "somatic": true, "material": "incense and a vial of holy or unholy water"}', '1 minute',
+        'Contact your deity or a divine proxy and ask up to three questions that can be answered yes or no.',
+        ''),
+    ('commune-with-nature', 'Commune with Nature', 5, 'Divination', true, false, '["Druid", "Ranger"]',
+        '1 minute', 'Self', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Gain knowledge of the surrounding land within 3 miles, or 300 feet underground: terrain, water, plants, animals, creatures and influences.',
+        ''),
+    ('cone-of-cold', 'Cone of Cold', 5, 'Evocation', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', 'Self (60-foot cone)', '{"verbal": true, "somatic": true, "material": "a small crystal or glass cone"}', 'Instantaneous',
+        'Each creature in a 60-foot cone makes a Constitution saving throw, taking 8d8 cold damage on a failure or half as much on a success.',
+        'The damage increases by 1d8 for each slot level above 5th.'),
+    ('conjure-elemental', 'Conjure Elemental', 5, 'Conjuration', false, true, '["Druid", "Wizard"]',
+        '1 minute', '90 feet', '{"verbal": true, "somatic": true, "material": "burning incense for air, soft clay for earth, sulfur and phosphorus for fire, or water and sand for water"}', 'Concentration, up to 1 hour',
+        'Summon an elemental of challenge rating 5 or lower from a matching source. It turns hostile if your concentration breaks.',
+        'The challenge rating increases by 1 for each slot level above 5th.'),
+    ('contact-other-plane', 'Contact Other Plane', 5, 'Divination', true, false, '["Warlock", "Wizard"]',
+        '1 minute', 'Self', '{"verbal": true, "somatic": false, "material": null}', '1 minute',
+        'Ask an extraplanar entity up to five questions. You must first succeed on a DC 15 Intelligence save or take 6d6 psychic damage and go insane.',
+        ''),
+    ('contagion', 'Contagion', 5, 'Necromancy', false, false, '["Cleric", "Druid"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": null}', '7 days',
+        'Melee spell attack: the target is poisoned and, after failing three Constitution saves, contracts a disease of your choice for 7 days.',
+        ''),
+    ('creation', 'Creation', 5, 'Illusion', false, false, '["Sorcerer", "Wizard"]',
+        '1 minute', '30 feet', '{"verbal": true, "somatic": true, "material": "a tiny piece of matter of the same type as the item you plan to create"}', 'Special',
+        'Pull wisps of shadow material to create a nonliving object of vegetable or mineral matter no larger than a 5-foot cube. Harder materials last less time.',
+        'The cube increases by 5 feet for each slot level above 5th.'),
+    ('dispel-evil-and-good', 'Dispel Evil and Good', 5, 'Abjuration', false, true, '["Cleric", "Paladin"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": "holy water or powdered silver and iron"}', 'Concentration, up to 1 minute',
+        'Celestials, elementals, fey, fiends and undead have disadvantage attacking you. End the spell early to break an enchantment or banish such a creature.',
+        ''),
+    ('dominate-person', 'Dominate Person', 5, 'Enchantment', false, true, '["Bard", "Sorcerer", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'A humanoid must succeed on a Wisdom save or be charmed by you, and you can command it telepathically.',
+        'The duration lengthens with the slot: 10 minutes at 6th level, 1 hour at 7th and 8 hours at 8th or higher.'),
+    ('dream', 'Dream', 5, 'Illusion', false, false, '["Bard", "Warlock", "Wizard"]',
+        '1 minute', 'Special', '{"verbal": true, "somatic": true, "material": "a handful of sand, a dab of ink and a writing quill plucked from a sleeping bird"}', '8 hours',
+        'A messenger enters the dreams of a creature you know on the same plane to converse with it, or to give it a nightmare.',
+        ''),
+    ('flame-strike', 'Flame Strike', 5, 'Evocation', false, false, '["Cleric"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": "pinch of sulfur"}', 'Instantaneous',
+        'A column of divine fire: creatures in a 10-foot-radius, 40-foot-high cylinder make a Dexterity save against 4d6 fire and 4d6 radiant damage.',
+        'The fire or radiant damage increases by 1d6 for each slot level above 5th.'),
+    ('geas', 'Geas', 5, 'Enchantment', false, false, '["Bard", "Cleric", "Druid", "Paladin", "Wizard"]',
+        '1 minute', '60 feet', '{"verbal": true, "somatic": false, "material": null}', '30 days',
+        'A creature that fails a Wisdom save must carry out a service you command, or take 5d10 psychic damage once per day it acts against it.',
+        'A 7th or 8th-level slot makes it last a year; a 9th-level slot lasts until ended by remove curse, greater restoration or wish.'),
+    ('greater-restoration', 'Greater Restoration', 5, 'Abjuration', false, false, '["Bard", "Cleric", "Druid"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "diamond dust worth at least 100 gp, which the spell consumes"}', 'Instantaneous',
+        'End one effect: a level of exhaustion, charm, petrification, a curse, an ability score reduction or a hit point maximum reduction.',
+        ''),
+    ('hallow', 'Hallow', 5, 'Evocation', false, false, '["Cleric"]',
+        '24 hours', 'Touch', '{"verbal": true, "somatic": true, "material": "herbs, oils and incense worth at least 1,000 gp, which the spell consumes"}', 'Until dispelled',
+        'Make an area of up to 60 feet holy or unholy, warding it against certain creature types and binding an extra effect to it.',
+        ''),
+    ('hold-monster', 'Hold Monster', 5, 'Enchantment', false, true, '["Bard", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '90 feet', '{"verbal": true, "somatic": true, "material": "a small, straight piece of iron"}', 'Concentration, up to 1 minute',
+        'A creature must succeed on a Wisdom save or be paralyzed, repeating the save at the end of each of its turns.',
+        'You can target one additional creature for each slot level above 5th.'),
+    ('insect-plague', 'Insect Plague', 5, 'Conjuration', false, true, '["Cleric", "Druid", "Sorcerer"]',
+        '1 action', '300 feet', '{"verbal": true, "somatic": true, "material": "a few grains of sugar, some kernels of grain and a smear of fat"}', 'Concentration, up to 10 minutes',
+        'Locusts fill a 20-foot sphere, making it difficult terrain and dealing 4d10 piercing damage on a failed Constitution save.',
+        'The damage increases by 1d10 for each slot level above 5th.'),
+    ('legend-lore', 'Legend Lore', 5, 'Divination', false, false, '["Bard", "Cleric", "Wizard"]',
+        '10 minutes', 'Self', '{"verbal": true, "somatic": true, "material": "incense worth at least 250 gp, which the spell consumes, and four ivory strips worth at least 50 gp each"}', 'Instantaneous',
+        'Learn significant lore about a person, place or object you name or have at hand.',
+        ''),
+    ('mass-cure-wounds', 'Mass Cure Wounds', 5, 'Evocation', false, false, '["Bard", "Cleric", "Druid"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Up to six creatures in a 30-foot sphere each regain 3d8 + your spellcasting modifier hit points.',
+        'The healing increases by 1d8 for each slot level above 5th.'),
+    ('mislead', 'Mislead', 5, 'Illusion', false, true, '["Bard", "Wizard"]',
+        '1 action', 'Self', '{"verbal": false, "somatic": true, "material": null}', 'Concentration, up to 1 hour',
+        'You become invisible while an illusory double of you appears, which you can see and hear through.',
+        ''),
+    ('modify-memory', 'Modify Memory', 5, 'Enchantment', false, true, '["Bard", "Wizard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'A creature that fails a Wisdom save has up to 10 minutes of its memory of the last 24 hours altered.',
+        'Modify memories up to 7 days old at 6th level, 30 days at 7th, a year at 8th and any time at 9th.'),
+    ('passwall', 'Passwall', 5, 'Transmutation', false, false, '["Wizard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": "a pinch of sesame seeds"}', '1 hour',
+        'A passage up to 20 feet deep opens in a wooden, plaster or stone surface.',
+        ''),
+    ('planar-binding', 'Planar Binding', 5, 'Abjuration', false, false, '["Bard", "Cleric", "Druid", "Warlock", "Wizard"]',
+        '1 hour', '60 feet', '{"verbal": true, "somatic": true, "material": "a jewel worth at least 1,000 gp, which the spell consumes"}', '24 hours',
+        'A celestial, elemental, fey or fiend that fails a Charisma save must serve you for the duration.',
+        'The duration becomes 10 days at 6th level, 30 days at 7th, 180 days at 8th and a year and a day at 9th.'),
+    ('raise-dead', 'Raise Dead', 5, 'Necromancy', false, false, '["Bard", "Cleric", "Paladin"]',
+        '1 hour', 'Touch', '{"verbal": true, "somatic": true, "material": "a diamond worth at least 500 gp, which the spell consumes"}', 'Instantaneous',
+        'Return a creature dead for no longer than 10 days to life with 1 hit point, with a -4 penalty that fades as it rests.',
+        ''),
+    ('reincarnate', 'Reincarnate', 5, 'Transmutation', false, false, '["Druid"]',
+        '1 hour', 'Touch', '{"verbal": true, "somatic": true, "material": "rare oils and unguents worth at least 1,000 gp, which the spell consumes"}', 'Instantaneous',
+        'Bring a humanoid dead for no longer than 10 days back in a new adult body of a randomly determined race.',
+        ''),
+    ('scrying', 'Scrying', 5, 'Divination', false, true, '["Bard", "Cleric", "Druid", "Warlock", "Wizard"]',
+        '10 minutes', 'Self', '{"verbal": true, "somatic": true, "material": "a focus worth at least 1,000 gp"}', 'Concentration, up to 10 minutes',
+        'See and hear a creature on the same plane that fails a Wisdom save, through an invisible sensor near it.',
+        ''),
+    ('seeming', 'Seeming', 5, 'Illusion', false, false, '["Bard", "Sorcerer", "Wizard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": null}', '8 hours',
+        'Change the appearance of any number of creatures you can see. Unwilling ones get a Charisma save.',
+        ''),
+    ('telekinesis', 'Telekinesis', 5, 'Transmutation', false, true, '["Sorcerer", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 10 minutes',
+        'Move a creature or an object of up to 1,000 pounds with your mind each turn.',
+        ''),
+    ('telepathic-bond', 'Telepathic Bond', 5, 'Divination', true, false, '["Wizard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": "pieces of eggshell from two different kinds of creatures"}', '1 hour',
+        'Link up to eight willing creatures telepathically for the duration, over any distance on the same plane.',
+        ''),
+    ('teleportation-circle', 'Teleportation Circle', 5, 'Conjuration', false, false, '["Bard", "Sorcerer", "Wizard"]',
+        '1 minute', '10 feet', '{"verbal": true, "somatic": false, "material": "rare chalks and inks infused with precious gems worth 50 gp, which the spell consumes"}', '1 round',
+        'Draw a circle linked to a permanent teleportation circle whose sigil sequence you know.',
+        ''),
+    ('tree-stride', 'Tree Stride', 5, 'Conjuration', false, true, '["Druid", "Ranger"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'Step into a tree and out of another of the same kind within 500 feet.',
+        ''),
+    ('wall-of-force', 'Wall of Force', 5, 'Evocation', false, true, '["Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": "a pinch of powder made by crushing a clear gemstone"}', 'Concentration, up to 10 minutes',
+        'An invisible wall of force that nothing can physically pass through. It is immune to all damage.',
+        ''),
+    ('wall-of-stone', 'Wall of Stone', 5, 'Evocation', false, true, '["Druid", "Sorcerer", "Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": "a small block of granite"}', 'Concentration, up to 10 minutes',
+        'A nonmagical wall of solid stone of ten 10-foot panels, which becomes permanent if held for the full duration.',
+        ''),
+    ('blade-barrier', 'Blade Barrier', 6, 'Evocation', false, true, '["Cleric"]',
+        '1 action', '90 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 10 minutes',
+        'A wall of whirling blades up to 100 feet long provides three-quarters cover. Creatures entering it take 6d10 slashing damage, halved on a Dexterity save.',
+        ''),
+    ('chain-lightning', 'Chain Lightning', 6, 'Evocation', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', '150 feet', '{"verbal": true, "somatic": true, "material": "a bit of fur, a piece of amber, glass or crystal rod, and three silver pins"}', 'Instantaneous',
+        'A bolt strikes one target and leaps to up to three others within 30 feet of it. Each makes a Dexterity save against 10d8 lightning damage.',
+        'One additional bolt leaps from the first target for each slot level above 6th.'),
+    ('circle-of-death', 'Circle of Death', 6, 'Necromancy', false, false, '["Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '150 feet', '{"verbal": true, "somatic": true, "material": "the powder of a crushed black pearl worth at least 500 gp"}', 'Instantaneous',
+        'Each creature in a 60-foot sphere makes a Constitution saving throw, taking 8d6 necrotic damage on a failure or half as much on a success.',
+        'The damage increases by 2d6 for each slot level above 6th.'),
+    ('conjure-fey', 'Conjure Fey', 6, 'Conjuration', false, true, '["Druid", "Warlock"]',
+        '1 minute', '90 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 hour',
+        'Summon a fey creature of challenge rating 6 or lower, or a fey spirit in the form of a beast. It turns hostile if your concentration breaks.',
+        'The challenge rating increases by 1 for each slot level above 6th.'),
+    ('contingency', 'Contingency', 6, 'Evocation', false, false, '["Wizard"]',
+        '10 minutes', 'Self', '{"verbal": true, "somatic": true, "material": "a statuette of yourself carved from ivory worth at least 1,500 gp"}', '10 days',
+        'Store a spell of 5th level or lower that takes effect on you when a circumstance you describe occurs.',
+        ''),
+    ('create-undead', 'Create Undead', 6, 'Necromancy', false, false, '["Cleric", "Warlock", "Wizard"]',
+        '1 minute', '10 feet', '{"verbal": true, "somatic": true, "material": "one clay pot filled with grave dirt, one with brackish water, and a 150 gp black onyx for each corpse"}', 'Instantaneous',
+        'Raise up to three corpses as ghouls under your control for 24 hours.',
+        'At 7th level, animate or control four ghouls; at 8th, five ghouls or two ghasts or wights; at 9th, six ghouls, three ghasts or wights, or two mummies.'),
+    ('disintegrate', 'Disintegrate', 6, 'Transmutation', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": "a lodestone and a pinch of dust"}', 'Instantaneous',
+        'A thin green ray: the target makes a Dexterity save or takes 10d6 + 40 force damage and is turned to dust if it drops to 0 hit points.',
+        'The damage increases by 3d6 for each slot level above 6th.'),
+    ('eyebite', 'Eyebite', 6, 'Necromancy', false, true, '["Bard", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'Each turn, target a creature within 60 feet with a Wisdom save or it falls asleep, panics or sickens.',
+        ''),
+    ('find-the-path', 'Find the Path', 6, 'Divination', false, true, '["Bard", "Cleric", "Druid"]',
+        '1 minute', 'Self', '{"verbal": true, "somatic": true, "material": "a set of divinatory tools worth 100 gp and an object from the destination"}', 'Concentration, up to 1 day',
+        'Learn the shortest, most direct physical route to a fixed location you are familiar with on the same plane.',
+        ''),
+    ('flesh-to-stone', 'Flesh to Stone', 6, 'Transmutation', false, true, '["Warlock", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": "a pinch of lime, water and earth"}', 'Concentration, up to 1 minute',
+        'A creature that fails a Constitution save is restrained, and after three failed saves is petrified.',
+        ''),
+    ('forbiddance', 'Forbiddance', 6, 'Abjuration', true, false, '["Cleric"]',
+        '10 minutes', 'Touch', '{"verbal": true, "somatic": true, "material": "a sprinkling of holy water, rare incense and powdered ruby worth at least 1,000 gp"}', '1 day',
+        'Ward up to 40,000 square feet against planar travel and harm chosen creature types that enter it with 5d10 radiant or necrotic damage.',
+        ''),
+    ('freezing-sphere', 'Freezing Sphere', 6, 'Evocation', false, false, '["Wizard"]',
+        '1 action', '300 feet', '{"verbal": true, "somatic": true, "material": "a small crystal sphere"}', 'Instantaneous',
+        'A frigid globe bursts in a 60-foot radius. Creatures make a Constitution save against 10d6 cold damage, and water freezes.',
+        'The damage increases by 1d6 for each slot level above 6th.'),
+    ('globe-of-invulnerability', 'Globe of Invulnerability', 6, 'Abjuration', false, true, '["Sorcerer", "Wizard"]',
+        '1 action', 'Self (10-foot radius)', '{"verbal": true, "somatic": true, "material": "a glass or crystal bead that shatters when the spell ends"}', 'Concentration, up to 1 minute',
+        'An immobile barrier around you that spells of 5th level or lower can''t affect from outside.',
+        'The barrier blocks spells of one level higher for each slot level above 6th.'),
+    ('guards-and-wards', 'Guards and Wards', 6, 'Abjuration', false, false, '["Bard", "Wizard"]',
+        '10 minutes', 'Touch', '{"verbal": true, "somatic": true, "material": "burning incense, a small measure of brimstone and oil, a knotted string, umber hulk blood and a silver rod worth 10 gp"}', '24 hours',
+        'Ward up to 2,500 square feet with fog, arcane locks, webs, confusing passages and other defenses.',
+        ''),
+    ('harm', 'Harm', 6, 'Necromancy', false, false, '["Cleric"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'A creature makes a Constitution save against 14d6 necrotic damage, and its hit point maximum is reduced on a failure.',
+        ''),
+    ('heal', 'Heal', 6, 'Evocation', false, false, '["Cleric", "Druid"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'A creature regains 70 hit points and is cured of blindness, deafness and diseases.',
+        'The healing increases by 10 for each slot level above 6th.'),
+    ('heroes-feast', 'Heroes'' Feast', 6, 'Conjuration', false, false, '["Cleric", "Druid"]',
+        '10 minutes', '30 feet', '{"verbal": true, "somatic": true, "material": "a gem-encrusted bowl worth at least 1,000 gp, which the spell consumes"}', 'Instantaneous',
+        'A great feast for up to twelve creatures cures disease and poison and grants immunity to fear and poison, advantage on Wisdom saves and 2d10 extra hit points for 24 hours.',
+        ''),
+    ('instant-summons', 'Instant Summons', 6, 'Conjuration', true, false, '["Wizard"]',
+        '1 minute', 'Touch', '{"verbal": true, "somatic": true, "material": "a sapphire worth 1,000 gp"}', 'Until dispelled',
+        'Mark an object, then crush the sapphire to summon it to your hand from anywhere on the same plane.',
+        ''),
+    ('irresistible-dance', 'Irresistible Dance', 6, 'Enchantment', false, true, '["Bard", "Wizard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": false, "material": null}', 'Concentration, up to 1 minute',
+        'A creature dances comically in place, with disadvantage on Dexterity saves and attacks while others have advantage against it.',
+        ''),
+    ('magic-jar', 'Magic Jar', 6, 'Necromancy', false, false, '["Wizard"]',
+        '1 minute', 'Self', '{"verbal": true, "somatic": true, "material": "a gem, crystal, reliquary or ornamental container worth at least 500 gp"}', 'Until dispelled',
+        'Move your soul into a container and possess nearby humanoids'' bodies from there.',
+        ''),
+    ('mass-suggestion', 'Mass Suggestion', 6, 'Enchantment', false, false, '["Bard", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": false, "material": "a snake''s tongue and either a bit of honeycomb or a drop of sweet oil"}', '24 hours',
+        'Up to twelve creatures that fail a Wisdom save follow a reasonable-sounding course of activity you describe.',
+        'The duration becomes 10 days at 7th level, 30 days at 8th and a year and a day at 9th.'),
+    ('move-earth', 'Move Earth', 6, 'Transmutation', false, true, '["Druid", "Sorcerer", "Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": "an iron blade and a small bag containing a mixture of soils"}', 'Concentration, up to 2 hours',
+        'Reshape dirt, sand or clay in a 40-foot square, raising or lowering its elevation and creating trenches or walls.',
+        ''),
+    ('planar-ally', 'Planar Ally', 6, 'Conjuration', false, false, '["Cleric"]',
+        '10 minutes', '60 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Beseech an otherworldly entity to send a celestial, elemental or fiend to aid you, for a price.',
+        ''),
+    ('programmed-illusion', 'Programmed Illusion', 6, 'Illusion', false, false, '["Bard", "Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": "a bit of fleece and jade dust worth at least 25 gp"}', 'Until dispelled',
+        'Create an illusion that plays out a scene of up to 5 minutes when a condition you set occurs.',
+        ''),
+    ('sunbeam', 'Sunbeam', 6, 'Evocation', false, true, '["Druid", "Sorcerer", "Wizard"]',
+        '1 action', 'Self (60-foot line)', '{"verbal": true, "somatic": true, "material": "a magnifying glass"}', 'Concentration, up to 1 minute',
+        'A 60-foot line of sunlight: creatures make a Constitution save against 6d8 radiant damage and blindness. Repeat as an action.',
+        ''),
+    ('transport-via-plants', 'Transport via Plants', 6, 'Conjuration', false, false, '["Druid"]',
+        '1 action', '10 feet', '{"verbal": true, "somatic": true, "material": null}', '1 round',
+        'Link a Large or larger plant to another on the same plane, so creatures can step from one to the other.',
+        ''),
+    ('true-seeing', 'True Seeing', 6, 'Divination', false, false, '["Bard", "Cleric", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "an ointment for the eyes that costs 25 gp, which the spell consumes"}', '1 hour',
+        'A willing creature gains truesight out to 120 feet.',
+        ''),
+    ('wall-of-ice', 'Wall of Ice', 6, 'Evocation', false, true, '["Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": "a small piece of quartz"}', 'Concentration, up to 10 minutes',
+        'A wall of ice appears, dealing 10d6 cold damage as it forms. Breaking through leaves a sheet of frigid air.',
+        'The damage increases by 2d6 for each slot level above 6th.'),
+    ('wall-of-thorns', 'Wall of Thorns', 6, 'Conjuration', false, true, '["Druid"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": "a handful of thorns"}', 'Concentration, up to 10 minutes',
+        'A wall of tough, pliable, tangled brush bristling with needle-sharp thorns deals 7d8 piercing damage.',
+        'The damage increases by 1d8 for each slot level above 6th.'),
+    ('wind-walk', 'Wind Walk', 6, 'Transmutation', false, false, '["Druid"]',
+        '1 minute', '30 feet', '{"verbal": true, "somatic": true, "material": "fire and holy water"}', '8 hours',
+        'You and up to ten willing creatures turn into clouds of vapor that can fly at 300 feet per round.',
+        ''),
+    ('word-of-recall', 'Word of Recall', 6, 'Conjuration', false, false, '["Cleric"]',
+        '1 action', '5 feet', '{"verbal": true, "somatic": false, "material": null}', 'Instantaneous',
+        'You and up to five willing creatures teleport to a sanctuary you previously designated.',
+        ''),
+    ('arcane-sword', 'Arcane Sword', 7, 'Evocation', false, true, '["Bard", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": "a miniature platinum sword worth 250 gp"}', 'Concentration, up to 1 minute',
+        'A sword of force hovers within range and makes melee spell attacks for 3d10 force damage. Move it and attack again as a bonus action.',
+        ''),
+    ('conjure-celestial', 'Conjure Celestial', 7, 'Conjuration', false, true, '["Cleric"]',
+        '1 minute', '90 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 hour',
+        'Summon a celestial of challenge rating 4 or lower that is friendly to you and obeys your commands.',
+        'With a 9th-level slot, summon a celestial of challenge rating 5 or lower.'),
+    ('delayed-blast-fireball', 'Delayed Blast Fireball', 7, 'Evocation', false, true, '["Sorcerer", "Wizard"]',
+        '1 action', '150 feet', '{"verbal": true, "somatic": true, "material": "a tiny ball of bat guano and sulfur"}', 'Concentration, up to 1 minute',
+        'A glowing bead waits to explode in a 20-foot fireball for 12d6 fire damage, growing by 1d6 for each of your turns that it waits.',
+        'The base damage increases by 1d6 for each slot level above 7th.'),
+    ('divine-word', 'Divine Word', 7, 'Evocation', false, false, '["Cleric"]',
+        '1 bonus action', '30 feet', '{"verbal": true, "somatic": false, "material": null}', 'Instantaneous',
+        'Creatures of your choice that fail a Charisma save are deafened, blinded, stunned or killed depending on their remaining hit points.',
+        ''),
+    ('etherealness', 'Etherealness', 7, 'Transmutation', false, false, '["Bard", "Cleric", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": null}', 'Up to 8 hours',
+        'Step into the border regions of the Ethereal Plane, where you can move through objects and only affect other ethereal creatures.',
+        'You can bring up to three additional willing creatures for each slot level above 7th.'),
+    ('finger-of-death', 'Finger of Death', 7, 'Necromancy', false, false, '["Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'A creature makes a Constitution save, taking 7d8 + 30 necrotic damage on a failure or half on a success. Humanoids it kills rise as zombies under your command.',
+        ''),
+    ('fire-storm', 'Fire Storm', 7, 'Evocation', false, false, '["Cleric", "Druid", "Sorcerer"]',
+        '1 action', '150 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Up to ten 10-foot cubes of fire erupt. Each creature in them makes a Dexterity save against 7d10 fire damage.',
+        ''),
+    ('forcecage', 'Forcecage', 7, 'Evocation', false, false, '["Bard", "Warlock", "Wizard"]',
+        '1 action', '100 feet', '{"verbal": true, "somatic": true, "material": "ruby dust worth 1,500 gp"}', '1 hour',
+        'An invisible cube-shaped prison of force traps creatures in an area of your choice.',
+        ''),
+    ('magnificent-mansion', 'Magnificent Mansion', 7, 'Conjuration', false, false, '["Bard", "Wizard"]',
+        '1 minute', '300 feet', '{"verbal": true, "somatic": true, "material": "a miniature portal carved from ivory, a polished piece of marble and a tiny silver spoon, each worth at least 5 gp"}', '24 hours',
+        'Conjure an extradimensional dwelling with servants and a feast for a hundred people.',
+        ''),
+    ('mirage-arcane', 'Mirage Arcane', 7, 'Illusion', false, false, '["Bard", "Druid", "Wizard"]',
+        '10 minutes', 'Sight', '{"verbal": true, "somatic": true, "material": null}', '10 days',
+        'Make terrain up to 1 mile square look, sound, smell and even feel like other terrain.',
+        ''),
+    ('plane-shift', 'Plane Shift', 7, 'Conjuration', false, false, '["Cleric", "Druid", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "a forked, metal rod worth at least 250 gp, attuned to a particular plane"}', 'Instantaneous',
+        'You and up to eight willing creatures travel to another plane, or an unwilling creature is banished there.',
+        ''),
+    ('prismatic-spray', 'Prismatic Spray', 7, 'Evocation', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', 'Self (60-foot cone)', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Rays of light in a 60-foot cone: each creature rolls to see which color hits it, each with its own damage or effect.',
+        ''),
+    ('project-image', 'Project Image', 7, 'Illusion', false, true, '["Bard", "Wizard"]',
+        '1 action', '500 miles', '{"verbal": true, "somatic": true, "material": "a small replica of you made from materials worth at least 5 gp"}', 'Concentration, up to 1 day',
+        'An illusory copy of yourself appears somewhere you have seen, through which you can see, hear and speak.',
+        ''),
+    ('regenerate', 'Regenerate', 7, 'Transmutation', false, false, '["Bard", "Cleric", "Druid"]',
+        '1 minute', 'Touch', '{"verbal": true, "somatic": true, "material": "a prayer wheel and holy water"}', '1 hour',
+        'A creature regains 4d8 + 15 hit points, then 1 hit point each turn, and severed body parts regrow.',
+        ''),
+    ('resurrection', 'Resurrection', 7, 'Necromancy', false, false, '["Bard", "Cleric"]',
+        '1 hour', 'Touch', '{"verbal": true, "somatic": true, "material": "a diamond worth at least 1,000 gp, which the spell consumes"}', 'Instantaneous',
+        'Return a creature dead for no more than a century to life with all its hit points, restoring missing body parts.',
+        ''),
+    ('reverse-gravity', 'Reverse Gravity', 7, 'Transmutation', false, true, '["Druid", "Wizard"]',
+        '1 action', '100 feet', '{"verbal": true, "somatic": true, "material": "a lodestone and iron filings"}', 'Concentration, up to 1 minute',
+        'Gravity reverses in a 50-foot-radius cylinder, and everything there falls upward.',
+        ''),
+    ('sequester', 'Sequester', 7, 'Transmutation', false, false, '["Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": "a powder of diamond, emerald, ruby and sapphire dust worth at least 5,000 gp, which the spell consumes"}', 'Until dispelled',
+        'Hide a willing creature or object from divination and detection, putting creatures into suspended animation.',
+        ''),
+    ('simulacrum', 'Simulacrum', 7, 'Illusion', false, false, '["Wizard"]',
+        '12 hours', 'Touch', '{"verbal": true, "somatic": true, "material": "snow or ice in quantities to make a life-size copy, hair or nail clippings of the creature, and powdered ruby worth 1,500 gp"}', 'Until dispelled',
+        'Create an illusory duplicate of a beast or humanoid with half its hit points that obeys you.',
+        ''),
+    ('symbol', 'Symbol', 7, 'Abjuration', false, false, '["Bard", "Cleric", "Wizard"]',
+        '1 minute', 'Touch', '{"verbal": true, "somatic": true, "material": "mercury, phosphorus and powdered diamond and opal worth at least 1,000 gp, which the spell consumes"}', 'Until dispelled or triggered',
+        'Inscribe a harmful glyph that, when triggered, inflicts death, discord, fear, hopelessness, insanity, pain, sleep or stunning.',
+        ''),
+    ('teleport', 'Teleport', 7, 'Conjuration', false, false, '["Bard", "Sorcerer", "Wizard"]',
+        '1 action', '10 feet', '{"verbal": true, "somatic": false, "material": null}', 'Instantaneous',
+        'Instantly transport you and up to eight willing creatures, or an object, to a destination you select. Familiarity determines whether you arrive on target.',
+        ''),
+    ('animal-shapes', 'Animal Shapes', 8, 'Transmutation', false, true, '["Druid"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 24 hours',
+        'Transform any number of willing creatures into beasts of challenge rating 4 or lower. Each keeps its mind but takes the beast''s statistics.',
+        ''),
+    ('antimagic-field', 'Antimagic Field', 8, 'Abjuration', false, true, '["Cleric", "Wizard"]',
+        '1 action', 'Self (10-foot-radius sphere)', '{"verbal": true, "somatic": true, "material": "a pinch of powdered iron or iron filings"}', 'Concentration, up to 1 hour',
+        'A 10-foot sphere of antimagic surrounds you. Spells can''t be cast inside it, magic items become mundane and summoned creatures vanish.',
+        ''),
+    ('antipathy-sympathy', 'Antipathy/Sympathy', 8, 'Enchantment', false, false, '["Druid", "Wizard"]',
+        '1 hour', '60 feet', '{"verbal": true, "somatic": true, "material": "a lump of alum soaked in vinegar for antipathy, or a drop of honey for sympathy"}', '10 days',
+        'An object or area repels or attracts a kind of creature you name. Creatures of that kind must save or flee from it, or be drawn to it.',
+        ''),
+    ('clone', 'Clone', 8, 'Necromancy', false, false, '["Wizard"]',
+        '1 hour', 'Touch', '{"verbal": true, "somatic": true, "material": "a diamond worth at least 1,000 gp and a 1-inch cube of flesh, which the spell consumes, and a sealable vessel worth at least 2,000 gp"}', 'Instantaneous',
+        'Grow an inert duplicate of a living creature that its soul moves into if the original dies.',
+        ''),
+    ('control-weather', 'Control Weather', 8, 'Transmutation', false, true, '["Cleric", "Druid", "Wizard"]',
+        '10 minutes', 'Self (5-mile radius)', '{"verbal": true, "somatic": true, "material": "burning incense and bits of earth and wood mixed in water"}', 'Concentration, up to 8 hours',
+        'Change the precipitation, temperature and wind within 5 miles of you, one stage at a time.',
+        ''),
+    ('demiplane', 'Demiplane', 8, 'Conjuration', false, false, '["Warlock", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": false, "somatic": true, "material": null}', '1 hour',
+        'A shadowy door opens onto an empty 30-foot stone room on its own plane, or onto one you created before.',
+        ''),
+    ('dominate-monster', 'Dominate Monster', 8, 'Enchantment', false, true, '["Bard", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 hour',
+        'A creature must succeed on a Wisdom save or be charmed by you, and you can command it telepathically.',
+        'With a 9th-level slot, the duration is up to 8 hours.'),
+    ('earthquake', 'Earthquake', 8, 'Evocation', false, true, '["Cleric", "Druid", "Sorcerer"]',
+        '1 action', '500 feet', '{"verbal": true, "somatic": true, "material": "a pinch of dirt, a piece of rock and a lump of clay"}', 'Concentration, up to 1 minute',
+        'The ground shakes in a 100-foot circle: concentration breaks, creatures are knocked prone, fissures open and structures take damage.',
+        ''),
+    ('feeblemind', 'Feeblemind', 8, 'Enchantment', false, false, '["Bard", "Druid", "Warlock", "Wizard"]',
+        '1 action', '150 feet', '{"verbal": true, "somatic": true, "material": "a handful of clay, crystal, glass or mineral spheres"}', 'Instantaneous',
+        'The target takes 4d6 psychic damage and, on a failed Intelligence save, its Intelligence and Charisma become 1.',
+        ''),
+    ('glibness', 'Glibness', 8, 'Transmutation', false, false, '["Bard", "Warlock"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": false, "material": null}', '1 hour',
+        'Treat any Charisma check roll below 15 as a 15, and magic can''t tell that you''re lying.',
+        ''),
+    ('holy-aura', 'Holy Aura', 8, 'Abjuration', false, true, '["Cleric"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": "a reliquary worth at least 1,000 gp"}', 'Concentration, up to 1 minute',
+        'Creatures of your choice within 30 feet have advantage on saves and attacks against them have disadvantage. Fiends and undead that hit them are blinded.',
+        ''),
+    ('incendiary-cloud', 'Incendiary Cloud', 8, 'Conjuration', false, true, '["Sorcerer", "Wizard"]',
+        '1 action', '150 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'A 20-foot sphere of embers drifts 10 feet each turn. Creatures in it make a Dexterity save against 10d8 fire damage.',
+        ''),
+    ('maze', 'Maze', 8, 'Conjuration', false, true, '["Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 10 minutes',
+        'Banish a creature into a labyrinthine demiplane until it escapes with a DC 20 Intelligence check.',
+        ''),
+    ('mind-blank', 'Mind Blank', 8, 'Abjuration', false, false, '["Bard", "Wizard"]',
+        '1 action', 'Touch', '{"verbal": true, "somatic": true, "material": null}', '24 hours',
+        'A willing creature is immune to psychic damage, thought reading, divination and being charmed.',
+        ''),
+    ('power-word-stun', 'Power Word Stun', 8, 'Enchantment', false, false, '["Bard", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": false, "material": null}', 'Instantaneous',
+        'A creature with 150 hit points or fewer is stunned until it succeeds on a Constitution save.',
+        ''),
+    ('sunburst', 'Sunburst', 8, 'Evocation', false, false, '["Druid", "Sorcerer", "Wizard"]',
+        '1 action', '150 feet', '{"verbal": true, "somatic": true, "material": "fire and a piece of sunstone"}', 'Instantaneous',
+        'Sunlight blazes in a 60-foot radius: creatures make a Constitution save against 12d6 radiant damage and blindness.',
+        ''),
+    ('astral-projection', 'Astral Projection', 9, 'Necromancy', false, false, '["Cleric", "Warlock", "Wizard"]',
+        '1 hour', '10 feet', '{"verbal": true, "somatic": true, "material": "for each creature, one jacinth worth 1,000 gp and an ornate silver bar worth 100 gp, all consumed"}', 'Special',
+        'You and up to eight willing creatures project your astral bodies into the Astral Plane, leaving your physical bodies behind.',
+        ''),
+    ('foresight', 'Foresight', 9, 'Divination', false, false, '["Bard", "Druid", "Warlock", "Wizard"]',
+        '1 minute', 'Touch', '{"verbal": true, "somatic": true, "material": "a hummingbird feather"}', '8 hours',
+        'A willing creature can''t be surprised and has advantage on attacks, checks and saves, while attacks against it have disadvantage.',
+        ''),
+    ('gate', 'Gate', 9, 'Conjuration', false, true, '["Cleric", "Sorcerer", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": "a diamond worth at least 5,000 gp"}', 'Concentration, up to 1 minute',
+        'Open a portal linking your location to a precise point on another plane, or call a named creature through.',
+        ''),
+    ('imprisonment', 'Imprisonment', 9, 'Abjuration', false, false, '["Warlock", "Wizard"]',
+        '1 minute', '30 feet', '{"verbal": true, "somatic": true, "material": "a vellum depiction or statuette of the target and a special component worth at least 500 gp per Hit Die"}', 'Until dispelled',
+        'A creature that fails a Wisdom save is bound by burial, chaining, a hedged prison, minimus containment or slumber.',
+        ''),
+    ('mass-heal', 'Mass Heal', 9, 'Evocation', false, false, '["Cleric"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Restore up to 700 hit points divided among creatures you can see, curing blindness, deafness and diseases.',
+        ''),
+    ('meteor-swarm', 'Meteor Swarm', 9, 'Evocation', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', '1 mile', '{"verbal": true, "somatic": true, "material": null}', 'Instantaneous',
+        'Four blazing orbs each explode in a 40-foot sphere. Creatures make a Dexterity save against 20d6 fire and 20d6 bludgeoning damage.',
+        ''),
+    ('power-word-kill', 'Power Word Kill', 9, 'Enchantment', false, false, '["Bard", "Sorcerer", "Warlock", "Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": false, "material": null}', 'Instantaneous',
+        'A creature with 100 hit points or fewer dies instantly.',
+        ''),
+    ('prismatic-wall', 'Prismatic Wall', 9, 'Abjuration', false, false, '["Wizard"]',
+        '1 action', '60 feet', '{"verbal": true, "somatic": true, "material": null}', '10 minutes',
+        'A shimmering wall of seven colored layers, each with its own effect and means of destruction.',
+        ''),
+    ('shapechange', 'Shapechange', 9, 'Transmutation', false, true, '["Druid", "Wizard"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": true, "material": "a jade circlet worth at least 1,500 gp"}', 'Concentration, up to 1 hour',
+        'Assume the form of a creature with a challenge rating no higher than your level, and change forms as an action.',
+        ''),
+    ('storm-of-vengeance', 'Storm of Vengeance', 9, 'Conjuration', false, true, '["Druid"]',
+        '1 action', 'Sight', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'A churning storm cloud 360 feet across deafens, then lashes the area with acid rain, lightning, hail and wind over several rounds.',
+        ''),
+    ('time-stop', 'Time Stop', 9, 'Transmutation', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": false, "material": null}', 'Instantaneous',
+        'You take 1d4 + 1 turns in a row while time stops for everyone else.',
+        ''),
+    ('true-polymorph', 'True Polymorph', 9, 'Transmutation', false, true, '["Bard", "Warlock", "Wizard"]',
+        '1 action', '30 feet', '{"verbal": true, "somatic": true, "material": "a drop of mercury, a dollop of gum arabic and a wisp of smoke"}', 'Concentration, up to 1 hour',
+        'Turn a creature into another creature, a creature into an object, or an object into a creature. Held for the full hour, it becomes permanent.',
+        ''),
+    ('true-resurrection', 'True Resurrection', 9, 'Necromancy', false, false, '["Cleric", "Druid"]',
+        '1 hour', 'Touch', '{"verbal": true, "somatic": true, "material": "a sprinkle of holy water and diamonds worth at least 25,000 gp, which the spell consumes"}', 'Instantaneous',
+        'Return a creature dead for no longer than 200 years to life, in a new body if needed.',
+        ''),
+    ('weird', 'Weird', 9, 'Illusion', false, true, '["Wizard"]',
+        '1 action', '120 feet', '{"verbal": true, "somatic": true, "material": null}', 'Concentration, up to 1 minute',
+        'Creatures in a 30-foot sphere that fail a Wisdom save are frightened and take 4d10 psychic damage each turn.',
+        ''),
+    ('wish', 'Wish', 9, 'Conjuration', false, false, '["Sorcerer", "Wizard"]',
+        '1 action', 'Self', '{"verbal": true, "somatic": false, "material": null}', 'Instantaneous',
+        'The mightiest spell a mortal can cast: duplicate any spell of 8th level or lower, or alter reality itself at great risk.',
+        '')
+
+on conflict (slug) do update set
+    name = excluded.name,
+    level = excluded.level,
+    school = excluded.school,
+    ritual = excluded.ritual,
+    concentration = excluded.concentration,
+    classes = excluded.classes,
+    casting_time = excluded.casting_time,
+    range = excluded.range,
+    components = excluded.components,
+    duration = excluded.duration,
+    description = excluded.description,
+    higher_levels = excluded.higher_levels;
@@ -0,0 +1,352 @@
+-- Shared monster bestiary, readable by everyone and mirroring Monster in src/api/monsters.rs.
+--
+-- Stat block lists are jsonb:
+--   speed: {"walk": 30, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}
+--   saving_throws / skills: [{"name": "Dex", "bonus": 5}]
+--   traits / actions / reactions / legendary_actions:
+--     [{"name": "Bite", "description": "...", "attack_bonus": 4, "damage": "1d6+2"}]
+-- `attack_bonus` and `damage` are set on entries the combat tracker can roll, null otherwise.
+-- Challenge ratings are stored as numbers (1/4 is 0.25) so they can be sorted and compared.
+-- Combat tracker combatants point at these rows by slug, like spellbooks do with spells.
+
+create table if not exists monsters (
+    id serial primary key,
+    slug text not null unique,
+    name text not null,
+    size text not null check (size in ('Tiny', 'Small', 'Medium', 'Large', 'Huge', 'Gargantuan')),
+    monster_type text not null check (monster_type in (
+        'Aberration', 'Beast', 'Celestial', 'Construct', 'Dragon', 'Elemental', 'Fey',
+        'Fiend', 'Giant', 'Humanoid', 'Monstrosity', 'Ooze', 'Plant', 'Undead'
+    )),
+    subtype text not null default '',
+    alignment text not null default 'unaligned',
+    armor_class integer not null,
+    armor_desc text not null default '',
+    hit_points integer not null check (hit_points > 0),
+    hit_dice text not null,
+    speed jsonb not null default '{"walk": 30}'::jsonb check (jsonb_typeof(speed) = 'object'),
+    strength integer not null default 10,
+    dexterity integer not null default 10,
+    constitution integer not null default 10,
+    intelligence integer not null default 10,
+    wisdom integer not null default 10,
+    charisma integer not null default 10,
+    saving_throws jsonb not null default '[]'::jsonb check (jsonb_typeof(saving_throws) = 'array'),
+    skills jsonb not null default '[]'::jsonb check (jsonb_typeof(skills) = 'array'),
+    damage_vulnerabilities text not null default '',
+    damage_resistances text not null default '',
+    damage_immunities text not null default '',
+    condition_immunities text not null default '',
+    senses text not null default '',
+    languages text not null default '',
+    challenge_rating numeric(6, 3) not null check (challenge_rating >= 0),
+    environments jsonb not null default '[]'::jsonb check (jsonb_typeof(environments) = 'array'),
+    traits jsonb not null default '[]'::jsonb check (jsonb_typeof(traits) = 'array'),
+    actions jsonb not null default '[]'::jsonb check (jsonb_typeof(actions) = 'array'),
+    reactions jsonb not null default '[]'::jsonb check (jsonb_typeof(reactions) = 'array'),
+    legendary_actions_per_round integer not null default 0,
+    legendary_actions jsonb not null default '[]'::jsonb check (jsonb_typeof(legendary_actions) = 'array')
+);
+
+create index if not exists monsters_challenge_name_idx on monsters (challenge_rating, name);
+create index if not exists monsters_type_idx on monsters (monster_type);
+create index if not exists monsters_environments_idx on monsters using gin (environments);
+
+alter table monsters enable row level security;
+
+create policy "Anyone reads the bestiary"
+    on monsters for select
+    using (true);
+
+-- The first SRD 5.1 monsters (Open Game License 1.0a / CC-BY-4.0), from CR 0 to 21;
+-- 20261018002400_srd_bestiary.sql seeds the rest of the SRD bestiary.
+-- The SRD doesn't list habitats; `environments` follows where each creature is usually met.
+insert into monsters (slug, name, size, monster_type, subtype, alignment,
+                      armor_class, armor_desc, hit_points, hit_dice, speed,
+                      strength, dexterity, constitution, intelligence, wisdom, charisma, saving_throws, skills,
+                      damage_vulnerabilities, damage_resistances, damage_immunities, condition_immunities,
+                      senses, languages, challenge_rating, environments,
+                      traits,
+                      actions,
+                      reactions, legendary_actions_per_round, legendary_actions) values
+    ('commoner', 'Commoner', 'Medium', 'Humanoid', 'any race', 'any alignment',
+        10, '', 4, '1d8', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        10, 10, 10, 10, 10, 10, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 10', 'any one language (usually Common)', 0, '["Urban", "Grassland", "Hill"]',
+        '[]',
+        '[{"name": "Club", "description": "Melee Weapon Attack: +2 to hit, reach 5 ft., one target. Hit: 2 (1d4) bludgeoning damage.", "attack_bonus": 2, "damage": "1d4"}]',
+        '[]', 0, '[]'),
+    ('rat', 'Rat', 'Tiny', 'Beast', '', 'unaligned',
+        10, '', 1, '1d4-1', '{"walk": 20, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        2, 11, 9, 2, 10, 4, '[]', '[]',
+        '', '', '', '',
+        'darkvision 30 ft., passive Perception 10', '', 0, '["Urban", "Forest", "Swamp", "Underdark"]',
+        '[{"name": "Keen Smell", "description": "The rat has advantage on Wisdom (Perception) checks that rely on smell.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +0 to hit, reach 5 ft., one target. Hit: 1 piercing damage.", "attack_bonus": 0, "damage": "1"}]',
+        '[]', 0, '[]'),
+    ('bandit', 'Bandit', 'Medium', 'Humanoid', 'any race', 'any non-lawful alignment',
+        12, 'leather armor', 11, '2d8+2', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        11, 12, 12, 10, 10, 10, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 10', 'any one language (usually Common)', 0.125, '["Urban", "Grassland", "Forest", "Hill", "Coastal", "Desert"]',
+        '[]',
+        '[{"name": "Scimitar", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 4 (1d6 + 1) slashing damage.", "attack_bonus": 3, "damage": "1d6+1"}, {"name": "Light Crossbow", "description": "Ranged Weapon Attack: +3 to hit, range 80/320 ft., one target. Hit: 5 (1d8 + 1) piercing damage.", "attack_bonus": 3, "damage": "1d8+1"}]',
+        '[]', 0, '[]'),
+    ('giant-rat', 'Giant Rat', 'Small', 'Beast', '', 'unaligned',
+        12, '', 7, '2d6', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        7, 15, 11, 2, 10, 4, '[]', '[]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 10', '', 0.125, '["Urban", "Forest", "Swamp", "Underdark"]',
+        '[{"name": "Keen Smell", "description": "The rat has advantage on Wisdom (Perception) checks that rely on smell.", "attack_bonus": null, "damage": null}, {"name": "Pack Tactics", "description": "The rat has advantage on an attack roll against a creature if at least one of the rat''s allies is within 5 feet of the creature and the ally isn''t incapacitated.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 4 (1d4 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d4+2"}]',
+        '[]', 0, '[]'),
+    ('kobold', 'Kobold', 'Small', 'Humanoid', 'kobold', 'lawful evil',
+        12, '', 5, '2d6-2', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        7, 15, 9, 8, 7, 8, '[]', '[]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 8', 'Common, Draconic', 0.125, '["Underdark", "Forest", "Hill", "Mountain", "Urban"]',
+        '[{"name": "Sunlight Sensitivity", "description": "While in sunlight, the kobold has disadvantage on attack rolls, as well as on Wisdom (Perception) checks that rely on sight.", "attack_bonus": null, "damage": null}, {"name": "Pack Tactics", "description": "The kobold has advantage on an attack roll against a creature if at least one of the kobold''s allies is within 5 feet of the creature and the ally isn''t incapacitated.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Dagger", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 4 (1d4 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d4+2"}, {"name": "Sling", "description": "Ranged Weapon Attack: +4 to hit, range 30/120 ft., one target. Hit: 4 (1d4 + 2) bludgeoning damage.", "attack_bonus": 4, "damage": "1d4+2"}]',
+        '[]', 0, '[]'),
+    ('goblin', 'Goblin', 'Small', 'Humanoid', 'goblinoid', 'neutral evil',
+        15, 'leather armor, shield', 7, '2d6', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        8, 14, 10, 10, 8, 8, '[]', '[{"name": "Stealth", "bonus": 6}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 9', 'Common, Goblin', 0.25, '["Forest", "Grassland", "Hill", "Underdark"]',
+        '[{"name": "Nimble Escape", "description": "The goblin can take the Disengage or Hide action as a bonus action on each of its turns.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Scimitar", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 5 (1d6 + 2) slashing damage.", "attack_bonus": 4, "damage": "1d6+2"}, {"name": "Shortbow", "description": "Ranged Weapon Attack: +4 to hit, range 80/320 ft., one target. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d6+2"}]',
+        '[]', 0, '[]'),
+    ('skeleton', 'Skeleton', 'Medium', 'Undead', '', 'lawful evil',
+        13, 'armor scraps', 13, '2d8+4', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        10, 14, 15, 6, 8, 5, '[]', '[]',
+        'bludgeoning', '', 'poison', 'exhaustion, poisoned',
+        'darkvision 60 ft., passive Perception 9', 'understands all languages it knew in life but can''t speak', 0.25, '["Underdark", "Urban"]',
+        '[]',
+        '[{"name": "Shortsword", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d6+2"}, {"name": "Shortbow", "description": "Ranged Weapon Attack: +4 to hit, range 80/320 ft., one target. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d6+2"}]',
+        '[]', 0, '[]'),
+    ('wolf', 'Wolf', 'Medium', 'Beast', '', 'unaligned',
+        13, 'natural armor', 11, '2d8+2', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        12, 15, 12, 3, 12, 6, '[]', '[{"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 4}]',
+        '', '', '', '',
+        'passive Perception 13', '', 0.25, '["Forest", "Grassland", "Hill", "Arctic"]',
+        '[{"name": "Keen Hearing and Smell", "description": "The wolf has advantage on Wisdom (Perception) checks that rely on hearing or smell.", "attack_bonus": null, "damage": null}, {"name": "Pack Tactics", "description": "The wolf has advantage on an attack roll against a creature if at least one of the wolf''s allies is within 5 feet of the creature and the ally isn''t incapacitated.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 7 (2d4 + 2) piercing damage. If the target is a creature, it must succeed on a DC 11 Strength saving throw or be knocked prone.", "attack_bonus": 4, "damage": "2d4+2"}]',
+        '[]', 0, '[]'),
+    ('zombie', 'Zombie', 'Medium', 'Undead', '', 'neutral evil',
+        8, '', 22, '3d8+9', '{"walk": 20, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        13, 6, 16, 3, 6, 5, '[{"name": "Wis", "bonus": 0}]', '[]',
+        '', '', 'poison', 'poisoned',
+        'darkvision 60 ft., passive Perception 8', 'understands the languages it knew in life but can''t speak', 0.25, '["Swamp", "Urban", "Underdark"]',
+        '[{"name": "Undead Fortitude", "description": "If damage reduces the zombie to 0 hit points, it must make a Constitution saving throw with a DC of 5 + the damage taken, unless the damage is radiant or from a critical hit. On a success, the zombie drops to 1 hit point instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Slam", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 4 (1d6 + 1) bludgeoning damage.", "attack_bonus": 3, "damage": "1d6+1"}]',
+        '[]', 0, '[]'),
+    ('black-bear', 'Black Bear', 'Medium', 'Beast', '', 'unaligned',
+        11, 'natural armor', 19, '3d8+6', '{"walk": 40, "fly": 0, "swim": 0, "climb": 30, "burrow": 0, "hover": false}',
+        15, 10, 14, 2, 12, 7, '[]', '[{"name": "Perception", "bonus": 3}]',
+        '', '', '', '',
+        'passive Perception 13', '', 0.5, '["Forest", "Hill", "Mountain"]',
+        '[{"name": "Keen Smell", "description": "The bear has advantage on Wisdom (Perception) checks that rely on smell.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The bear makes two attacks: one with its bite and one with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d6+2"}, {"name": "Claws", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 7 (2d4 + 2) slashing damage.", "attack_bonus": 4, "damage": "2d4+2"}]',
+        '[]', 0, '[]'),
+    ('gnoll', 'Gnoll', 'Medium', 'Humanoid', 'gnoll', 'chaotic evil',
+        15, 'hide armor, shield', 22, '5d8', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        14, 12, 11, 6, 10, 7, '[]', '[]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 10', 'Gnoll', 0.5, '["Grassland", "Desert", "Forest", "Hill"]',
+        '[{"name": "Rampage", "description": "When the gnoll reduces a creature to 0 hit points with a melee attack on its turn, the gnoll can take a bonus action to move up to half its speed and make a bite attack.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 4 (1d4 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d4+2"}, {"name": "Spear", "description": "Melee or Ranged Weapon Attack: +4 to hit, reach 5 ft. or range 20/60 ft., one target. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d6+2"}, {"name": "Longbow", "description": "Ranged Weapon Attack: +3 to hit, range 150/600 ft., one target. Hit: 5 (1d8 + 1) piercing damage.", "attack_bonus": 3, "damage": "1d8+1"}]',
+        '[]', 0, '[]'),
+    ('hobgoblin', 'Hobgoblin', 'Medium', 'Humanoid', 'goblinoid', 'lawful evil',
+        18, 'chain mail, shield', 11, '2d8+2', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        13, 12, 12, 10, 10, 9, '[]', '[]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 10', 'Common, Goblin', 0.5, '["Forest", "Grassland", "Hill", "Mountain", "Underdark"]',
+        '[{"name": "Martial Advantage", "description": "Once per turn, the hobgoblin can deal an extra 7 (2d6) damage to a creature it hits with a weapon attack if that creature is within 5 feet of an ally of the hobgoblin that isn''t incapacitated.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Longsword", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 5 (1d8 + 1) slashing damage, or 6 (1d10 + 1) slashing damage if used with two hands.", "attack_bonus": 3, "damage": "1d8+1"}, {"name": "Longbow", "description": "Ranged Weapon Attack: +3 to hit, range 150/600 ft., one target. Hit: 5 (1d8 + 1) piercing damage.", "attack_bonus": 3, "damage": "1d8+1"}]',
+        '[]', 0, '[]'),
+    ('orc', 'Orc', 'Medium', 'Humanoid', 'orc', 'chaotic evil',
+        13, 'hide armor', 15, '2d8+6', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 12, 16, 7, 11, 10, '[]', '[{"name": "Intimidation", "bonus": 2}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 10', 'Common, Orc', 0.5, '["Hill", "Mountain", "Forest", "Grassland", "Underdark", "Arctic", "Swamp"]',
+        '[{"name": "Aggressive", "description": "As a bonus action, the orc can move up to its speed toward a hostile creature that it can see.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Greataxe", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 9 (1d12 + 3) slashing damage.", "attack_bonus": 5, "damage": "1d12+3"}, {"name": "Javelin", "description": "Melee or Ranged Weapon Attack: +5 to hit, reach 5 ft. or range 30/120 ft., one target. Hit: 6 (1d6 + 3) piercing damage.", "attack_bonus": 5, "damage": "1d6+3"}]',
+        '[]', 0, '[]'),
+    ('brown-bear', 'Brown Bear', 'Large', 'Beast', '', 'unaligned',
+        11, 'natural armor', 34, '4d10+12', '{"walk": 40, "fly": 0, "swim": 0, "climb": 30, "burrow": 0, "hover": false}',
+        19, 10, 16, 2, 13, 7, '[]', '[{"name": "Perception", "bonus": 3}]',
+        '', '', '', '',
+        'passive Perception 13', '', 1, '["Forest", "Hill", "Arctic", "Mountain"]',
+        '[{"name": "Keen Smell", "description": "The bear has advantage on Wisdom (Perception) checks that rely on smell.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The bear makes two attacks: one with its bite and one with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 8 (1d8 + 4) piercing damage.", "attack_bonus": 6, "damage": "1d8+4"}, {"name": "Claws", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 11 (2d6 + 4) slashing damage.", "attack_bonus": 6, "damage": "2d6+4"}]',
+        '[]', 0, '[]'),
+    ('bugbear', 'Bugbear', 'Medium', 'Humanoid', 'goblinoid', 'chaotic evil',
+        16, 'hide armor, shield', 27, '5d8+5', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        15, 14, 13, 8, 11, 9, '[]', '[{"name": "Stealth", "bonus": 6}, {"name": "Survival", "bonus": 2}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 10', 'Common, Goblin', 1, '["Forest", "Grassland", "Hill", "Mountain", "Underdark"]',
+        '[{"name": "Brute", "description": "A melee weapon deals one extra die of its damage when the bugbear hits with it (included in the attack).", "attack_bonus": null, "damage": null}, {"name": "Surprise Attack", "description": "If the bugbear surprises a creature and hits it with an attack during the first round of combat, the target takes an extra 7 (2d6) damage from the attack.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Morningstar", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 11 (2d8 + 2) piercing damage.", "attack_bonus": 4, "damage": "2d8+2"}, {"name": "Javelin", "description": "Melee or Ranged Weapon Attack: +4 to hit, reach 5 ft. or range 30/120 ft., one target. Hit: 9 (2d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "2d6+2"}]',
+        '[]', 0, '[]'),
+    ('dire-wolf', 'Dire Wolf', 'Large', 'Beast', '', 'unaligned',
+        14, 'natural armor', 37, '5d10+10', '{"walk": 50, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        17, 15, 15, 3, 12, 7, '[]', '[{"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 4}]',
+        '', '', '', '',
+        'passive Perception 13', '', 1, '["Forest", "Hill", "Arctic"]',
+        '[{"name": "Keen Hearing and Smell", "description": "The wolf has advantage on Wisdom (Perception) checks that rely on hearing or smell.", "attack_bonus": null, "damage": null}, {"name": "Pack Tactics", "description": "The wolf has advantage on an attack roll against a creature if at least one of the wolf''s allies is within 5 feet of the creature and the ally isn''t incapacitated.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 10 (2d6 + 3) piercing damage. If the target is a creature, it must succeed on a DC 13 Strength saving throw or be knocked prone.", "attack_bonus": 5, "damage": "2d6+3"}]',
+        '[]', 0, '[]'),
+    ('ghoul', 'Ghoul', 'Medium', 'Undead', '', 'chaotic evil',
+        12, '', 22, '5d8', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        13, 15, 10, 7, 10, 6, '[]', '[]',
+        '', '', 'poison', 'charmed, exhaustion, poisoned',
+        'darkvision 60 ft., passive Perception 10', 'Common', 1, '["Underdark", "Swamp", "Urban"]',
+        '[]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +2 to hit, reach 5 ft., one creature. Hit: 9 (2d6 + 2) piercing damage.", "attack_bonus": 2, "damage": "2d6+2"}, {"name": "Claws", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 7 (2d4 + 2) slashing damage. If the target is a creature other than an elf or undead, it must succeed on a DC 10 Constitution saving throw or be paralyzed for 1 minute, repeating the saving throw at the end of each of its turns.", "attack_bonus": 4, "damage": "2d4+2"}]',
+        '[]', 0, '[]'),
+    ('giant-spider', 'Giant Spider', 'Large', 'Beast', '', 'unaligned',
+        14, 'natural armor', 26, '4d10+4', '{"walk": 30, "fly": 0, "swim": 0, "climb": 30, "burrow": 0, "hover": false}',
+        14, 16, 12, 2, 11, 4, '[]', '[{"name": "Stealth", "bonus": 7}]',
+        '', '', '', '',
+        'blindsight 10 ft., darkvision 60 ft., passive Perception 10', '', 1, '["Forest", "Underdark", "Swamp"]',
+        '[{"name": "Spider Climb", "description": "The spider can climb difficult surfaces, including upside down on ceilings, without needing to make an ability check.", "attack_bonus": null, "damage": null}, {"name": "Web Sense", "description": "While in contact with a web, the spider knows the exact location of any other creature in contact with the same web.", "attack_bonus": null, "damage": null}, {"name": "Web Walker", "description": "The spider ignores movement restrictions caused by webbing.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one creature. Hit: 7 (1d8 + 3) piercing damage, and the target must make a DC 11 Constitution saving throw, taking 9 (2d8) poison damage on a failed save, or half as much damage on a successful one.", "attack_bonus": 5, "damage": "1d8+3"}, {"name": "Web (Recharge 5-6)", "description": "Ranged Weapon Attack: +5 to hit, range 30/60 ft., one creature. Hit: The target is restrained by webbing. As an action, the restrained target can make a DC 12 Strength check, bursting the webbing on a success.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('bandit-captain', 'Bandit Captain', 'Medium', 'Humanoid', 'any race', 'any non-lawful alignment',
+        15, 'studded leather', 65, '10d8+20', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        15, 16, 14, 14, 11, 14, '[{"name": "Str", "bonus": 4}, {"name": "Dex", "bonus": 5}, {"name": "Wis", "bonus": 2}]', '[{"name": "Athletics", "bonus": 4}, {"name": "Deception", "bonus": 4}]',
+        '', '', '', '',
+        'passive Perception 10', 'any two languages', 2, '["Urban", "Coastal", "Grassland", "Forest", "Hill", "Desert"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The captain makes three melee attacks: two with its scimitar and one with its dagger. Or the captain makes two ranged attacks with its daggers.", "attack_bonus": null, "damage": null}, {"name": "Scimitar", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 6 (1d6 + 3) slashing damage.", "attack_bonus": 5, "damage": "1d6+3"}, {"name": "Dagger", "description": "Melee or Ranged Weapon Attack: +5 to hit, reach 5 ft. or range 20/60 ft., one target. Hit: 5 (1d4 + 3) piercing damage.", "attack_bonus": 5, "damage": "1d4+3"}]',
+        '[{"name": "Parry", "description": "The captain adds 2 to its AC against one melee attack that would hit it. To do so, the captain must see the attacker and be wielding a melee weapon.", "attack_bonus": null, "damage": null}]', 0, '[]'),
+    ('gelatinous-cube', 'Gelatinous Cube', 'Large', 'Ooze', '', 'unaligned',
+        6, '', 84, '8d10+40', '{"walk": 15, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        14, 3, 20, 1, 6, 1, '[]', '[]',
+        '', '', '', 'blinded, charmed, deafened, exhaustion, frightened, prone',
+        'blindsight 60 ft. (blind beyond this radius), passive Perception 8', '', 2, '["Underdark"]',
+        '[{"name": "Ooze Cube", "description": "The cube takes up its entire space. Other creatures can enter the space, but a creature that does so is subjected to the cube''s Engulf and has disadvantage on the saving throw.", "attack_bonus": null, "damage": null}, {"name": "Transparent", "description": "Even when the cube is in plain sight, it takes a successful DC 15 Wisdom (Perception) check to spot a cube that has neither moved nor attacked.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Pseudopod", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one creature. Hit: 10 (3d6) acid damage.", "attack_bonus": 4, "damage": "3d6"}, {"name": "Engulf", "description": "The cube moves up to its speed. Creatures in its way make a DC 12 Dexterity saving throw or are engulfed, taking 10 (3d6) acid damage, then 21 (6d6) acid damage at the start of each of the cube''s turns. An engulfed creature is restrained and can''t breathe.", "attack_bonus": null, "damage": "6d6"}]',
+        '[]', 0, '[]'),
+    ('ogre', 'Ogre', 'Large', 'Giant', '', 'chaotic evil',
+        11, 'hide armor', 59, '7d10+21', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        19, 8, 16, 5, 7, 7, '[]', '[]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 8', 'Common, Giant', 2, '["Hill", "Mountain", "Forest", "Swamp", "Grassland", "Arctic", "Desert", "Underdark"]',
+        '[]',
+        '[{"name": "Greatclub", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 13 (2d8 + 4) bludgeoning damage.", "attack_bonus": 6, "damage": "2d8+4"}, {"name": "Javelin", "description": "Melee or Ranged Weapon Attack: +6 to hit, reach 5 ft. or range 30/120 ft., one target. Hit: 11 (2d6 + 4) piercing damage.", "attack_bonus": 6, "damage": "2d6+4"}]',
+        '[]', 0, '[]'),
+    ('minotaur', 'Minotaur', 'Large', 'Monstrosity', '', 'chaotic evil',
+        14, 'natural armor', 76, '9d10+27', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 11, 16, 6, 16, 9, '[]', '[{"name": "Perception", "bonus": 7}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 17', 'Abyssal', 3, '["Underdark", "Hill", "Mountain"]',
+        '[{"name": "Charge", "description": "If the minotaur moves at least 10 feet straight toward a target and then hits it with a gore attack on the same turn, the target takes an extra 9 (2d8) piercing damage and must succeed on a DC 14 Strength saving throw or be pushed up to 10 feet away and knocked prone.", "attack_bonus": null, "damage": null}, {"name": "Labyrinthine Recall", "description": "The minotaur can perfectly recall any path it has traveled.", "attack_bonus": null, "damage": null}, {"name": "Reckless", "description": "At the start of its turn, the minotaur can gain advantage on all melee weapon attack rolls it makes during that turn, but attack rolls against it have advantage until the start of its next turn.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Greataxe", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 17 (2d12 + 4) slashing damage.", "attack_bonus": 6, "damage": "2d12+4"}, {"name": "Gore", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 13 (2d8 + 4) piercing damage.", "attack_bonus": 6, "damage": "2d8+4"}]',
+        '[]', 0, '[]'),
+    ('mummy', 'Mummy', 'Medium', 'Undead', '', 'lawful evil',
+        11, 'natural armor', 58, '9d8+18', '{"walk": 20, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 8, 15, 6, 10, 12, '[{"name": "Wis", "bonus": 2}]', '[]',
+        'fire', 'bludgeoning, piercing, and slashing from nonmagical attacks', 'necrotic, poison', 'charmed, exhaustion, frightened, paralyzed, poisoned',
+        'darkvision 60 ft., passive Perception 10', 'the languages it knew in life', 3, '["Desert"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The mummy can use its Dreadful Glare and makes one attack with its rotting fist.", "attack_bonus": null, "damage": null}, {"name": "Rotting Fist", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 10 (2d6 + 3) bludgeoning damage plus 10 (3d6) necrotic damage. If the target is a creature, it must succeed on a DC 12 Constitution saving throw or be cursed with mummy rot.", "attack_bonus": 5, "damage": "2d6+3"}, {"name": "Dreadful Glare", "description": "The mummy targets one creature it can see within 60 feet of it. If the target can see the mummy, it must succeed on a DC 11 Wisdom saving throw against this magic or become frightened until the end of the mummy''s next turn.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('owlbear', 'Owlbear', 'Large', 'Monstrosity', '', 'unaligned',
+        13, 'natural armor', 59, '7d10+21', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        20, 12, 17, 3, 12, 7, '[]', '[{"name": "Perception", "bonus": 3}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 13', '', 3, '["Forest"]',
+        '[{"name": "Keen Sight and Smell", "description": "The owlbear has advantage on Wisdom (Perception) checks that rely on sight or smell.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The owlbear makes two attacks: one with its beak and one with its claws.", "attack_bonus": null, "damage": null}, {"name": "Beak", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one creature. Hit: 10 (1d10 + 5) piercing damage.", "attack_bonus": 7, "damage": "1d10+5"}, {"name": "Claws", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 14 (2d8 + 5) slashing damage.", "attack_bonus": 7, "damage": "2d8+5"}]',
+        '[]', 0, '[]'),
+    ('veteran', 'Veteran', 'Medium', 'Humanoid', 'any race', 'any alignment',
+        17, 'splint', 58, '9d8+18', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 13, 14, 10, 11, 10, '[]', '[{"name": "Athletics", "bonus": 5}, {"name": "Perception", "bonus": 2}]',
+        '', '', '', '',
+        'passive Perception 12', 'any one language (usually Common)', 3, '["Urban", "Grassland", "Hill"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The veteran makes two longsword attacks. If it has a shortsword drawn, it can also make a shortsword attack.", "attack_bonus": null, "damage": null}, {"name": "Longsword", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 7 (1d8 + 3) slashing damage, or 8 (1d10 + 3) slashing damage if used with two hands.", "attack_bonus": 5, "damage": "1d8+3"}, {"name": "Shortsword", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 6 (1d6 + 3) piercing damage.", "attack_bonus": 5, "damage": "1d6+3"}, {"name": "Heavy Crossbow", "description": "Ranged Weapon Attack: +3 to hit, range 100/400 ft., one target. Hit: 6 (1d10 + 1) piercing damage.", "attack_bonus": 3, "damage": "1d10+1"}]',
+        '[]', 0, '[]'),
+    ('wight', 'Wight', 'Medium', 'Undead', '', 'neutral evil',
+        14, 'studded leather', 45, '6d8+18', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        15, 14, 16, 10, 13, 15, '[]', '[{"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 4}]',
+        '', 'necrotic; bludgeoning, piercing, and slashing from nonmagical attacks that aren''t silvered', 'poison', 'exhaustion, poisoned',
+        'darkvision 60 ft., passive Perception 13', 'the languages it knew in life', 3, '["Swamp", "Underdark", "Urban", "Arctic"]',
+        '[{"name": "Sunlight Sensitivity", "description": "While in sunlight, the wight has disadvantage on attack rolls, as well as on Wisdom (Perception) checks that rely on sight.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The wight makes two longsword attacks or two longbow attacks. It can use its Life Drain in place of one longsword attack.", "attack_bonus": null, "damage": null}, {"name": "Life Drain", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one creature. Hit: 5 (1d6 + 2) necrotic damage. The target must succeed on a DC 13 Constitution saving throw or its hit point maximum is reduced by an amount equal to the damage taken.", "attack_bonus": 4, "damage": "1d6+2"}, {"name": "Longsword", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 6 (1d8 + 2) slashing damage.", "attack_bonus": 4, "damage": "1d8+2"}, {"name": "Longbow", "description": "Ranged Weapon Attack: +4 to hit, range 150/600 ft., one target. Hit: 6 (1d8 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d8+2"}]',
+        '[]', 0, '[]'),
+    ('ghost', 'Ghost', 'Medium', 'Undead', '', 'any alignment',
+        11, '', 45, '10d8', '{"walk": 0, "fly": 40, "swim": 0, "climb": 0, "burrow": 0, "hover": true}',
+        7, 13, 10, 10, 12, 17, '[]', '[]',
+        '', 'acid, fire, lightning, thunder; bludgeoning, piercing, and slashing from nonmagical attacks', 'cold, necrotic, poison', 'charmed, exhaustion, frightened, grappled, paralyzed, petrified, poisoned, prone, restrained',
+        'darkvision 60 ft., passive Perception 11', 'any languages it knew in life', 4, '["Urban", "Underdark", "Swamp", "Coastal"]',
+        '[{"name": "Ethereal Sight", "description": "The ghost can see 60 feet into the Ethereal Plane when it is on the Material Plane, and vice versa.", "attack_bonus": null, "damage": null}, {"name": "Incorporeal Movement", "description": "The ghost can move through other creatures and objects as if they were difficult terrain. It takes 5 (1d10) force damage if it ends its turn inside an object.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Withering Touch", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 17 (4d6 + 3) necrotic damage.", "attack_bonus": 5, "damage": "4d6+3"}, {"name": "Etherealness", "description": "The ghost enters the Ethereal Plane from the Material Plane, or vice versa.", "attack_bonus": null, "damage": null}, {"name": "Horrifying Visage", "description": "Each non-undead creature within 60 feet that can see the ghost must succeed on a DC 13 Wisdom saving throw or be frightened for 1 minute, aging 1d4 × 10 years if it fails by 5 or more.", "attack_bonus": null, "damage": null}, {"name": "Possession (Recharge 6)", "description": "One humanoid within 5 feet must succeed on a DC 13 Charisma saving throw or be possessed by the ghost.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('hill-giant', 'Hill Giant', 'Huge', 'Giant', '', 'chaotic evil',
+        13, 'natural armor', 105, '10d12+40', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        21, 8, 19, 5, 9, 6, '[]', '[{"name": "Perception", "bonus": 2}]',
+        '', '', '', '',
+        'passive Perception 12', 'Giant', 5, '["Hill", "Grassland", "Forest"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The giant makes two greatclub attacks.", "attack_bonus": null, "damage": null}, {"name": "Greatclub", "description": "Melee Weapon Attack: +8 to hit, reach 10 ft., one target. Hit: 18 (3d8 + 5) bludgeoning damage.", "attack_bonus": 8, "damage": "3d8+5"}, {"name": "Rock", "description": "Ranged Weapon Attack: +8 to hit, range 60/240 ft., one target. Hit: 21 (3d10 + 5) bludgeoning damage.", "attack_bonus": 8, "damage": "3d10+5"}]',
+        '[]', 0, '[]'),
+    ('troll', 'Troll', 'Large', 'Giant', '', 'chaotic evil',
+        15, 'natural armor', 84, '8d10+40', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 13, 20, 7, 9, 7, '[]', '[{"name": "Perception", "bonus": 2}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 12', 'Giant', 5, '["Arctic", "Forest", "Hill", "Mountain", "Swamp", "Underdark"]',
+        '[{"name": "Keen Smell", "description": "The troll has advantage on Wisdom (Perception) checks that rely on smell.", "attack_bonus": null, "damage": null}, {"name": "Regeneration", "description": "The troll regains 10 hit points at the start of its turn. If the troll takes acid or fire damage, this trait doesn''t function at the start of the troll''s next turn. The troll dies only if it starts its turn with 0 hit points and doesn''t regenerate.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The troll makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 7 (1d6 + 4) piercing damage.", "attack_bonus": 7, "damage": "1d6+4"}, {"name": "Claw", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 11 (2d6 + 4) slashing damage.", "attack_bonus": 7, "damage": "2d6+4"}]',
+        '[]', 0, '[]'),
+    ('mage', 'Mage', 'Medium', 'Humanoid', 'any race', 'any alignment',
+        12, '15 with mage armor', 40, '9d8', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        9, 14, 11, 17, 12, 11, '[{"name": "Int", "bonus": 6}, {"name": "Wis", "bonus": 4}]', '[{"name": "Arcana", "bonus": 6}, {"name": "History", "bonus": 6}]',
+        '', '', '', '',
+        'passive Perception 11', 'any four languages', 6, '["Urban"]',
+        '[{"name": "Spellcasting", "description": "The mage is a 9th-level spellcaster. Its spellcasting ability is Intelligence (spell save DC 14, +6 to hit with spell attacks). Cantrips: fire bolt, light, mage hand, prestidigitation. 1st level (4 slots): detect magic, mage armor, magic missile, shield. 2nd level (3 slots): misty step, suggestion. 3rd level (3 slots): counterspell, fireball, fly. 4th level (3 slots): greater invisibility, ice storm. 5th level (1 slot): cone of cold.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Dagger", "description": "Melee or Ranged Weapon Attack: +5 to hit, reach 5 ft. or range 20/60 ft., one target. Hit: 4 (1d4 + 2) piercing damage.", "attack_bonus": 5, "damage": "1d4+2"}]',
+        '[]', 0, '[]'),
+    ('young-green-dragon', 'Young Green Dragon', 'Large', 'Dragon', '', 'lawful evil',
+        18, 'natural armor', 136, '16d10+48', '{"walk": 40, "fly": 80, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        19, 12, 17, 16, 13, 15, '[{"name": "Dex", "bonus": 4}, {"name": "Con", "bonus": 6}, {"name": "Wis", "bonus": 4}, {"name": "Cha", "bonus": 5}]', '[{"name": "Deception", "bonus": 5}, {"name": "Perception", "bonus": 7}, {"name": "Stealth", "bonus": 4}]',
+        '', '', 'poison', 'poisoned',
+        'blindsight 30 ft., darkvision 120 ft., passive Perception 17', 'Common, Draconic', 8, '["Forest"]',
+        '[{"name": "Amphibious", "description": "The dragon can breathe air and water.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +7 to hit, reach 10 ft., one target. Hit: 15 (2d10 + 4) piercing damage plus 7 (2d6) poison damage.", "attack_bonus": 7, "damage": "2d10+4"}, {"name": "Claw", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 11 (2d6 + 4) slashing damage.", "attack_bonus": 7, "damage": "2d6+4"}, {"name": "Poison Breath (Recharge 5-6)", "description": "The dragon exhales poisonous gas in a 30-foot cone. Each creature in that area must make a DC 14 Constitution saving throw, taking 42 (12d6) poison damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "12d6"}]',
+        '[]', 0, '[]'),
+    ('young-red-dragon', 'Young Red Dragon', 'Large', 'Dragon', '', 'chaotic evil',
+        18, 'natural armor', 178, '17d10+85', '{"walk": 40, "fly": 80, "swim": 0, "climb": 40, "burrow": 0, "hover": false}',
+        23, 10, 21, 14, 11, 19, '[{"name": "Dex", "bonus": 4}, {"name": "Con", "bonus": 9}, {"name": "Wis", "bonus": 4}, {"name": "Cha", "bonus": 8}]', '[{"name": "Perception", "bonus": 8}, {"name": "Stealth", "bonus": 4}]',
+        '', '', 'fire', '',
+        'blindsight 30 ft., darkvision 120 ft., passive Perception 18', 'Common, Draconic', 10, '["Mountain", "Hill"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The dragon makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +10 to hit, reach 10 ft., one target. Hit: 17 (2d10 + 6) piercing damage plus 3 (1d6) fire damage.", "attack_bonus": 10, "damage": "2d10+6"}, {"name": "Claw", "description": "Melee Weapon Attack: +10 to hit, reach 5 ft., one target. Hit: 13 (2d6 + 6) slashing damage.", "attack_bonus": 10, "damage": "2d6+6"}, {"name": "Fire Breath (Recharge 5-6)", "description": "The dragon exhales fire in a 30-foot cone. Each creature in that area must make a DC 17 Dexterity saving throw, taking 56 (16d6) fire damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "16d6"}]',
+        '[]', 0, '[]'),
+    ('vampire', 'Vampire', 'Medium', 'Undead', 'shapechanger', 'lawful evil',
+        16, 'natural armor', 144, '17d8+68', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 18, 18, 17, 15, 18, '[{"name": "Dex", "bonus": 9}, {"name": "Wis", "bonus": 7}, {"name": "Cha", "bonus": 9}]', '[{"name": "Perception", "bonus": 7}, {"name": "Stealth", "bonus": 9}]',
+        '', 'necrotic; bludgeoning, piercing, and slashing from nonmagical attacks', '', '',
+        'darkvision 120 ft., passive Perception 17', 'the languages it knew in life', 13, '["Urban"]',
+        '[{"name": "Legendary Resistance (3/Day)", "description": "If the vampire fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}, {"name": "Regeneration", "description": "The vampire regains 20 hit points at the start of its turn if it has at least 1 hit point and isn''t in sunlight or running water.", "attack_bonus": null, "damage": null}, {"name": "Spider Climb", "description": "The vampire can climb difficult surfaces, including upside down on ceilings, without needing to make an ability check.", "attack_bonus": null, "damage": null}, {"name": "Vampire Weaknesses", "description": "The vampire can''t enter a residence without an invitation, is harmed by running water and sunlight, and is paralyzed by a piercing weapon through its heart.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack (Vampire Form Only)", "description": "The vampire makes two attacks, only one of which can be a bite attack.", "attack_bonus": null, "damage": null}, {"name": "Unarmed Strike (Vampire Form Only)", "description": "Melee Weapon Attack: +9 to hit, reach 5 ft., one creature. Hit: 8 (1d8 + 4) bludgeoning damage. Instead of dealing damage, the vampire can grapple the target (escape DC 18).", "attack_bonus": 9, "damage": "1d8+4"}, {"name": "Bite (Bat or Vampire Form Only)", "description": "Melee Weapon Attack: +9 to hit, reach 5 ft., one willing creature, or a creature that is grappled by the vampire, incapacitated, or restrained. Hit: 7 (1d6 + 4) piercing damage plus 10 (3d6) necrotic damage. The target''s hit point maximum is reduced by the necrotic damage taken, and the vampire regains that many hit points.", "attack_bonus": 9, "damage": "1d6+4"}, {"name": "Charm", "description": "One humanoid within 30 feet that can see the vampire must succeed on a DC 17 Wisdom saving throw or be charmed by it for 24 hours.", "attack_bonus": null, "damage": null}]',
+        '[]', 3, '[{"name": "Move", "description": "The vampire moves up to its speed without provoking opportunity attacks.", "attack_bonus": null, "damage": null}, {"name": "Unarmed Strike", "description": "The vampire makes one unarmed strike.", "attack_bonus": null, "damage": null}, {"name": "Bite (Costs 2 Actions)", "description": "The vampire makes one bite attack.", "attack_bonus": null, "damage": null}]'),
+    ('adult-red-dragon', 'Adult Red Dragon', 'Huge', 'Dragon', '', 'chaotic evil',
+        19, 'natural armor', 256, '19d12+133', '{"walk": 40, "fly": 80, "swim": 0, "climb": 40, "burrow": 0, "hover": false}',
+        27, 10, 25, 16, 13, 21, '[{"name": "Dex", "bonus": 6}, {"name": "Con", "bonus": 13}, {"name": "Wis", "bonus": 7}, {"name": "Cha", "bonus": 11}]', '[{"name": "Perception", "bonus": 13}, {"name": "Stealth", "bonus": 6}]',
+        '', '', 'fire', '',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 23', 'Common, Draconic', 17, '["Mountain", "Hill"]',
+        '[{"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +14 to hit, reach 10 ft., one target. Hit: 19 (2d10 + 8) piercing damage plus 7 (2d6) fire damage.", "attack_bonus": 14, "damage": "2d10+8"}, {"name": "Claw", "description": "Melee Weapon Attack: +14 to hit, reach 5 ft., one target. Hit: 15 (2d6 + 8) slashing damage.", "attack_bonus": 14, "damage": "2d6+8"}, {"name": "Tail", "description": "Melee Weapon Attack: +14 to hit, reach 15 ft., one target. Hit: 17 (2d8 + 8) bludgeoning damage.", "attack_bonus": 14, "damage": "2d8+8"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice within 120 feet that is aware of it must succeed on a DC 19 Wisdom saving throw or become frightened for 1 minute.", "attack_bonus": null, "damage": null}, {"name": "Fire Breath (Recharge 5-6)", "description": "The dragon exhales fire in a 60-foot cone. Each creature in that area must make a DC 21 Dexterity saving throw, taking 63 (18d6) fire damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "18d6"}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "Each creature within 10 feet must succeed on a DC 22 Dexterity saving throw or take 15 (2d6 + 8) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+8"}]'),
+    ('lich', 'Lich', 'Medium', 'Undead', '', 'any evil alignment',
+        17, 'natural armor', 135, '18d8+54', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        11, 16, 16, 20, 14, 16, '[{"name": "Con", "bonus": 10}, {"name": "Int", "bonus": 12}, {"name": "Wis", "bonus": 9}]', '[{"name": "Arcana", "bonus": 19}, {"name": "History", "bonus": 12}, {"name": "Insight", "bonus": 9}, {"name": "Perception", "bonus": 9}]',
+        '', 'cold, lightning, necrotic', 'poison; bludgeoning, piercing, and slashing from nonmagical attacks', 'charmed, exhaustion, frightened, paralyzed, poisoned',
+        'truesight 120 ft., passive Perception 19', 'Common plus up to five other languages', 21, '["Underdark", "Urban"]',
+        '[{"name": "Legendary Resistance (3/Day)", "description": "If the lich fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}, {"name": "Rejuvenation", "description": "If it has a phylactery, a destroyed lich gains a new body in 1d10 days, regaining all its hit points and becoming active again.", "attack_bonus": null, "damage": null}, {"name": "Spellcasting", "description": "The lich is an 18th-level spellcaster (spell save DC 20, +12 to hit with spell attacks). It knows wizard spells including fireball, counterspell, dimension door, cloudkill, disintegrate, finger of death, power word stun and power word kill.", "attack_bonus": null, "damage": null}, {"name": "Turn Resistance", "description": "The lich has advantage on saving throws against any effect that turns undead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Paralyzing Touch", "description": "Melee Spell Attack: +12 to hit, reach 5 ft., one creature. Hit: 10 (3d6) cold damage. The target must succeed on a DC 18 Constitution saving throw or be paralyzed for 1 minute.", "attack_bonus": 12, "damage": "3d6"}]',
+        '[]', 3, '[{"name": "Cantrip", "description": "The lich casts a cantrip.", "attack_bonus": null, "damage": null}, {"name": "Paralyzing Touch (Costs 2 Actions)", "description": "The lich uses its Paralyzing Touch.", "attack_bonus": null, "damage": null}, {"name": "Frightening Gaze (Costs 2 Actions)", "description": "One creature within 10 feet must succeed on a DC 18 Wisdom saving throw or be frightened for 1 minute.", "attack_bonus": null, "damage": null}, {"name": "Disrupt Life (Costs 3 Actions)", "description": "Each non-undead creature within 20 feet makes a DC 18 Constitution saving throw, taking 21 (6d6) necrotic damage on a failed save, or half as much on a successful one.", "attack_bonus": null, "damage": "6d6"}]')
+on conflict (slug) do nothing;
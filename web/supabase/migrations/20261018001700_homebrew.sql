@@ -0,0 +1,250 @@
+-- Homebrew content, mirroring src/api/homebrew.rs.
+--
+-- Users author spells, monsters, items and feats in packs they own. Homebrew entries are
+-- rows of the catalog tables themselves, marked with the pack they belong to, so spellbooks
+-- and the combat tracker point at them by slug exactly like SRD entries. `source` is what the
+-- reference pages show next to an entry: 'SRD 5.1', or the pack's name for homebrew.
+--
+-- Every save of an entry bumps its `version` and keeps a snapshot in homebrew_versions, so
+-- earlier versions can be looked at and restored.
+--
+-- A pack is visible to its owner, to everyone once shared, and to the DM and players of
+-- campaigns that have it enabled. The reference pages list SRD entries plus the caller's own
+-- packs and the packs enabled in their campaigns; shared packs only join a campaign's
+-- catalog once its DM enables them.
+
+create table if not exists homebrew_packs (
+    id serial primary key,
+    slug text not null unique,
+    name text not null,
+    description text not null default '',
+    owner text not null,
+    shared boolean not null default false,
+    created_at timestamptz not null default now(),
+    updated_at timestamptz
+);
+
+create index if not exists homebrew_packs_owner_idx on homebrew_packs (owner);
+
+-- Packs a campaign's DM has enabled; a missing row means disabled
+create table if not exists campaign_homebrew_packs (
+    campaign_id integer not null references campaigns (id) on delete cascade,
+    pack_id integer not null references homebrew_packs (id) on delete cascade,
+    enabled_at timestamptz not null default now(),
+    primary key (campaign_id, pack_id)
+);
+
+create index if not exists campaign_homebrew_packs_pack_idx on campaign_homebrew_packs (pack_id);
+
+-- Snapshots of every saved version of a homebrew entry. `data` is the whole catalog row
+-- as the app serializes it, tagged with its kind: {"Spell": {...}}.
+create table if not exists homebrew_versions (
+    id serial primary key,
+    pack_id integer not null references homebrew_packs (id) on delete cascade,
+    kind text not null check (kind in ('Spell', 'Monster', 'Item', 'Feat')),
+    slug text not null,
+    version integer not null check (version > 0),
+    name text not null,
+    data jsonb not null check (jsonb_typeof(data) = 'object'),
+    author text not null,
+    created_at timestamptz not null default now(),
+    unique (kind, slug, version)
+);
+
+create or replace function owns_homebrew_pack(p_pack_id integer)
+returns boolean
+language sql stable security definer set search_path = public
+as $$
+    select exists (
+        select 1 from homebrew_packs p
+        where p.id = p_pack_id
+          and p.owner = auth.uid()::text
+    );
+$$;
+
+-- Owner, shared, or enabled in a campaign the caller runs or has a character in
+create or replace function can_read_homebrew_pack(p_pack_id integer)
+returns boolean
+language sql stable security definer set search_path = public
+as $$
+    select exists (
+        select 1 from homebrew_packs p
+        where p.id = p_pack_id
+          and (p.shared or p.owner = auth.uid()::text)
+    ) or exists (
+        select 1
+        from campaign_homebrew_packs cp
+        join campaigns c on c.id = cp.campaign_id
+        where cp.pack_id = p_pack_id
+          and (
+              c.owner = auth.uid()::text
+              or exists (
+                  select 1
+                  from party_members pm
+                  join characters ch on ch.id = pm.character_id
+                  where pm.campaign_id = c.id
+                    and ch.owner = auth.uid()::text
+              )
+          )
+    );
+$$;
+
+alter table homebrew_packs enable row level security;
+
+create policy "Homebrew packs are readable by owner, when shared and in their campaigns"
+    on homebrew_packs for select
+    using (can_read_homebrew_pack(id));
+
+create policy "Users create their own homebrew packs"
+    on homebrew_packs for insert
+    with check (owner = auth.uid()::text);
+
+create policy "Owners update their homebrew packs"
+    on homebrew_packs for update
+    using (owner = auth.uid()::text)
+    with check (owner = auth.uid()::text);
+
+create policy "Owners delete their homebrew packs"
+    on homebrew_packs for delete
+    using (owner = auth.uid()::text);
+
+alter table homebrew_versions enable row level security;
+
+create policy "Homebrew history is readable with its pack"
+    on homebrew_versions for select
+    using (can_read_homebrew_pack(pack_id));
+
+create policy "Pack owners record homebrew history"
+    on homebrew_versions for insert
+    with check (author = auth.uid()::text and owns_homebrew_pack(pack_id));
+
+-- Item and feat catalogs, alongside spells and monsters
+create table if not exists items (
+    id serial primary key,
+    slug text not null unique,
+    name text not null,
+    category text not null,
+    rarity text not null default 'Common' check (rarity in ('Common', 'Uncommon', 'Rare', 'Very Rare', 'Legendary', 'Artifact')),
+    requires_attunement boolean not null default false,
+    -- Pounds and copper pieces, like inventory items
+    weight numeric(8, 2) not null default 0,
+    value bigint not null default 0,
+    description text not null default ''
+);
+
+create index if not exists items_name_idx on items (name);
+
+create table if not exists feats (
+    id serial primary key,
+    slug text not null unique,
+    name text not null,
+    prerequisite text not null default '',
+    description text not null default ''
+);
+
+create index if not exists feats_name_idx on feats (name);
+
+alter table items enable row level security;
+alter table feats enable row level security;
+
+-- Every catalog gets the homebrew markers and the same policies
+alter table spells
+    add column if not exists pack_id integer references homebrew_packs (id) on delete cascade,
+    add column if not exists source text not null default 'SRD 5.1',
+    add column if not exists version integer not null default 1;
+alter table monsters
+    add column if not exists pack_id integer references homebrew_packs (id) on delete cascade,
+    add column if not exists source text not null default 'SRD 5.1',
+    add column if not exists version integer not null default 1;
+alter table items
+    add column if not exists pack_id integer references homebrew_packs (id) on delete cascade,
+    add column if not exists source text not null default 'SRD 5.1',
+    add column if not exists version integer not null default 1;
+alter table feats
+    add column if not exists pack_id integer references homebrew_packs (id) on delete cascade,
+    add column if not exists source text not null default 'SRD 5.1',
+    add column if not exists version integer not null default 1;
+
+create index if not exists spells_pack_idx on spells (pack_id);
+create index if not exists monsters_pack_idx on monsters (pack_id);
+create index if not exists items_pack_idx on items (pack_id);
+create index if not exists feats_pack_idx on feats (pack_id);
+
+drop policy if exists "Anyone reads the spell catalog" on spells;
+drop policy if exists "Anyone reads the bestiary" on monsters;
+
+create policy "SRD spells and readable homebrew"
+    on spells for select
+    using (pack_id is null or can_read_homebrew_pack(pack_id));
+create policy "Pack owners write homebrew spells"
+    on spells for all
+    using (pack_id is not null and owns_homebrew_pack(pack_id))
+    with check (pack_id is not null and owns_homebrew_pack(pack_id));
+
+create policy "SRD monsters and readable homebrew"
+    on monsters for select
+    using (pack_id is null or can_read_homebrew_pack(pack_id));
+create policy "Pack owners write homebrew monsters"
+    on monsters for all
+    using (pack_id is not null and owns_homebrew_pack(pack_id))
+    with check (pack_id is not null and owns_homebrew_pack(pack_id));
+
+create policy "SRD items and readable homebrew"
+    on items for select
+    using (pack_id is null or can_read_homebrew_pack(pack_id));
+create policy "Pack owners write homebrew items"
+    on items for all
+    using (pack_id is not null and owns_homebrew_pack(pack_id))
+    with check (pack_id is not null and owns_homebrew_pack(pack_id));
+
+create policy "SRD feats and readable homebrew"
+    on feats for select
+    using (pack_id is null or can_read_homebrew_pack(pack_id));
+create policy "Pack owners write homebrew feats"
+    on feats for all
+    using (pack_id is not null and owns_homebrew_pack(pack_id))
+    with check (pack_id is not null and owns_homebrew_pack(pack_id));
+
+-- A starting set of SRD magic items
+insert into items (slug, name, category, rarity, requires_attunement, weight, value, description) values
+    ('potion-of-healing', 'Potion of Healing', 'Potion', 'Common', false, 0.5, 5000,
+        'You regain 2d4 + 2 hit points when you drink this potion.'),
+    ('potion-of-greater-healing', 'Potion of Greater Healing', 'Potion', 'Uncommon', false, 0.5, 15000,
+        'You regain 4d4 + 4 hit points when you drink this potion.'),
+    ('potion-of-climbing', 'Potion of Climbing', 'Potion', 'Common', false, 0.5, 0,
+        'For 1 hour you gain a climbing speed equal to your walking speed and advantage on Strength (Athletics) checks to climb.'),
+    ('spell-scroll-cantrip', 'Spell Scroll (Cantrip)', 'Scroll', 'Common', false, 0, 0,
+        'A spell on your class''s list can be cast from the scroll without material components, after which the scroll crumbles.'),
+    ('bag-of-holding', 'Bag of Holding', 'Wondrous Item', 'Uncommon', false, 15, 0,
+        'Holds up to 500 pounds, not exceeding 64 cubic feet, and always weighs 15 pounds regardless of its contents.'),
+    ('cloak-of-protection', 'Cloak of Protection', 'Wondrous Item', 'Uncommon', true, 1, 0,
+        'You gain a +1 bonus to AC and saving throws while you wear this cloak.'),
+    ('boots-of-elvenkind', 'Boots of Elvenkind', 'Wondrous Item', 'Uncommon', false, 1, 0,
+        'Your steps make no sound, and you have advantage on Dexterity (Stealth) checks that rely on moving silently.'),
+    ('goggles-of-night', 'Goggles of Night', 'Wondrous Item', 'Uncommon', false, 0, 0,
+        'While wearing these lenses you have darkvision out to 60 feet, or 60 feet more if you already have it.'),
+    ('immovable-rod', 'Immovable Rod', 'Rod', 'Uncommon', false, 2, 0,
+        'Pressing the button fixes the rod in place. It holds up to 8,000 pounds and moves only if a creature succeeds on a DC 30 Strength check.'),
+    ('wand-of-magic-missiles', 'Wand of Magic Missiles', 'Wand', 'Uncommon', false, 1, 0,
+        'Has 7 charges. Expend 1 or more to cast magic missile, at 1st level plus one level per extra charge. Regains 1d6 + 1 charges at dawn.'),
+    ('weapon-plus-1', 'Weapon, +1', 'Weapon', 'Uncommon', false, 0, 0,
+        'You have a +1 bonus to attack and damage rolls made with this magic weapon.'),
+    ('armor-plus-1', 'Armor, +1', 'Armor', 'Rare', false, 0, 0,
+        'You have a +1 bonus to AC while wearing this armor.'),
+    ('ring-of-protection', 'Ring of Protection', 'Ring', 'Rare', true, 0, 0,
+        'You gain a +1 bonus to AC and saving throws while wearing this ring.'),
+    ('flame-tongue', 'Flame Tongue', 'Weapon', 'Rare', true, 3, 0,
+        'Speak its command word and flames erupt from the blade, shedding bright light and dealing an extra 2d6 fire damage on a hit.'),
+    ('staff-of-healing', 'Staff of Healing', 'Staff', 'Rare', true, 4, 0,
+        'Has 10 charges for casting cure wounds, lesser restoration and mass cure wounds. Regains 1d6 + 4 charges at dawn.'),
+    ('carpet-of-flying', 'Carpet of Flying', 'Wondrous Item', 'Very Rare', false, 0, 0,
+        'Speak the command word and the carpet hovers and flies; its speed and how much it carries depend on its size.'),
+    ('vorpal-sword', 'Vorpal Sword', 'Weapon', 'Legendary', true, 3, 0,
+        'A +3 slashing weapon that ignores resistance to slashing damage. On a natural 20 it lops off one of the target''s heads.')
+on conflict (slug) do nothing;
+
+-- The SRD's only feat
+insert into feats (slug, name, prerequisite, description) values
+    ('grappler', 'Grappler', 'Strength 13 or higher',
+        'You have advantage on attack rolls against a creature you are grappling, and you can use your action to try to pin a creature grappled by you.')
+on conflict (slug) do nothing;
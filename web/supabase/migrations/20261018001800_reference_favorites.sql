@@ -0,0 +1,34 @@
+-- Quick Reference favorites, read and written by src/api/reference.rs.
+--
+-- Each row pins one catalog entry for one user: a spell, monster, item or feat, found by the
+-- same slug spellbooks and combatants use. There is no foreign key since the entry can live in
+-- any of the four catalog tables; favorites of entries that are deleted, or whose homebrew
+-- pack the user can no longer read, are simply left out when the pins are listed.
+
+create table if not exists reference_favorites (
+    owner text not null,
+    kind text not null check (kind in ('Spell', 'Monster', 'Item', 'Feat')),
+    slug text not null,
+    created_at timestamptz not null default now(),
+    primary key (owner, kind, slug)
+);
+
+alter table reference_favorites enable row level security;
+
+create policy "Users read their own favorites"
+    on reference_favorites for select
+    using (owner = auth.uid()::text);
+
+create policy "Users pin their own favorites"
+    on reference_favorites for insert
+    with check (owner = auth.uid()::text);
+
+-- Pinning an entry twice is an upsert, which PostgREST runs as an update of the existing pin
+create policy "Users re-pin their own favorites"
+    on reference_favorites for update
+    using (owner = auth.uid()::text)
+    with check (owner = auth.uid()::text);
+
+create policy "Users unpin their own favorites"
+    on reference_favorites for delete
+    using (owner = auth.uid()::text);
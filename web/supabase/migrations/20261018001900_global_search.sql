@@ -0,0 +1,135 @@
+-- Global search, called by search_everything in src/api/search.rs.
+--
+-- Characters, NPCs, lore entries, map locations, adventures, spells and monsters each get a
+-- generated `search_document`: their name or title weighted highest, then what kind of thing
+-- they are, then their text. DM-only text (adventure DM notes) is never indexed.
+--
+-- search_everything runs as the caller, so row-level security decides which characters,
+-- campaign content, spells and monsters they find. The server also passes the campaigns the
+-- caller runs or plays in, and the homebrew packs their catalogs include, and nothing
+-- outside those is searched.
+--
+-- Snippets come from ts_headline with matches wrapped in control characters, chr(2) before
+-- and chr(3) after, which the app turns into highlights without trusting any markup.
+
+alter table characters add column if not exists search_document tsvector generated always as (
+    setweight(to_tsvector('english', coalesce(name, '')), 'A') ||
+    setweight(to_tsvector('english', coalesce(race, '') || ' ' || coalesce(class, '')), 'B') ||
+    setweight(to_tsvector('english', coalesce(description, '') || ' ' || coalesce(backstory, '')), 'C')
+) stored;
+
+alter table npcs add column if not exists search_document tsvector generated always as (
+    setweight(to_tsvector('english', name), 'A') ||
+    setweight(to_tsvector('english', race || ' ' || role || ' ' || location || ' ' || affiliation), 'B') ||
+    setweight(to_tsvector('english', description || ' ' || personality || ' ' || appearance), 'C')
+) stored;
+
+alter table lore_entries add column if not exists search_document tsvector generated always as (
+    setweight(to_tsvector('english', title), 'A') ||
+    setweight(to_tsvector('english', content), 'C')
+) stored;
+
+alter table map_locations add column if not exists search_document tsvector generated always as (
+    setweight(to_tsvector('english', name), 'A') ||
+    setweight(to_tsvector('english', description), 'C')
+) stored;
+
+alter table adventures add column if not exists search_document tsvector generated always as (
+    setweight(to_tsvector('english', title), 'A') ||
+    setweight(to_tsvector('english', character_used), 'B') ||
+    setweight(to_tsvector('english', summary), 'C')
+) stored;
+
+alter table spells add column if not exists search_document tsvector generated always as (
+    setweight(to_tsvector('english', name), 'A') ||
+    setweight(to_tsvector('english', school), 'B') ||
+    setweight(to_tsvector('english', description || ' ' || higher_levels), 'C')
+) stored;
+
+alter table monsters add column if not exists search_document tsvector generated always as (
+    setweight(to_tsvector('english', name), 'A') ||
+    setweight(to_tsvector('english', monster_type || ' ' || subtype || ' ' || alignment), 'B') ||
+    setweight(to_tsvector('english', languages || ' ' || senses), 'C')
+) stored;
+
+create index if not exists characters_search_idx on characters using gin (search_document);
+create index if not exists npcs_search_idx on npcs using gin (search_document);
+create index if not exists lore_entries_search_idx on lore_entries using gin (search_document);
+create index if not exists map_locations_search_idx on map_locations using gin (search_document);
+create index if not exists adventures_search_idx on adventures using gin (search_document);
+create index if not exists spells_search_idx on spells using gin (search_document);
+create index if not exists monsters_search_idx on monsters using gin (search_document);
+
+-- The best `p_limit` matches of each kind for `p_query`, written the way people type into a
+-- search box ("fire -ball", "\"red dragon\""). `rank` is normalized to 0..1 so kinds compare.
+create or replace function search_everything(
+    p_query text,
+    p_campaign_ids integer[],
+    p_pack_ids integer[],
+    p_limit integer default 8
+)
+returns table (
+    kind text,
+    slug text,
+    campaign_slug text,
+    title text,
+    snippet text,
+    rank real
+)
+language sql stable
+as $$
+    with q as (
+        select websearch_to_tsquery('english', p_query) as query
+    ),
+    hits as (
+        (select 'Character' as kind, c.slug, null::text as campaign_slug, c.name as title,
+                coalesce(c.description, '') || ' ' || coalesce(c.backstory, '') as body,
+                ts_rank(c.search_document, q.query, 32) as rank
+         from characters c, q
+         where c.search_document @@ q.query
+         order by rank desc limit p_limit)
+        union all
+        (select 'Npc', null, ca.slug, n.name, n.description || ' ' || n.personality,
+                ts_rank(n.search_document, q.query, 32)
+         from npcs n join campaigns ca on ca.id = n.campaign_id, q
+         where n.campaign_id = any(p_campaign_ids) and n.search_document @@ q.query
+         order by 6 desc limit p_limit)
+        union all
+        (select 'Lore', null, ca.slug, l.title, l.content,
+                ts_rank(l.search_document, q.query, 32)
+         from lore_entries l join campaigns ca on ca.id = l.campaign_id, q
+         where l.campaign_id = any(p_campaign_ids) and l.search_document @@ q.query
+         order by 6 desc limit p_limit)
+        union all
+        (select 'Location', null, ca.slug, m.name, m.description,
+                ts_rank(m.search_document, q.query, 32)
+         from map_locations m join campaigns ca on ca.id = m.campaign_id, q
+         where m.campaign_id = any(p_campaign_ids) and m.search_document @@ q.query
+         order by 6 desc limit p_limit)
+        union all
+        (select 'Adventure', null, ca.slug, a.title, a.summary,
+                ts_rank(a.search_document, q.query, 32)
+         from adventures a join campaigns ca on ca.id = a.campaign_id, q
+         where a.campaign_id = any(p_campaign_ids) and a.search_document @@ q.query
+         order by 6 desc limit p_limit)
+        union all
+        (select 'Spell', s.slug, null, s.name, s.description,
+                ts_rank(s.search_document, q.query, 32)
+         from spells s, q
+         where (s.pack_id is null or s.pack_id = any(p_pack_ids)) and s.search_document @@ q.query
+         order by 6 desc limit p_limit)
+        union all
+        (select 'Monster', m.slug, null, m.name,
+                m.size || ' ' || m.monster_type || ' ' || m.subtype || ', ' || m.alignment || '. ' || m.senses,
+                ts_rank(m.search_document, q.query, 32)
+         from monsters m, q
+         where (m.pack_id is null or m.pack_id = any(p_pack_ids)) and m.search_document @@ q.query
+         order by 6 desc limit p_limit)
+    )
+    select h.kind, h.slug, h.campaign_slug, h.title,
+           ts_headline('english', h.body, q.query,
+               'StartSel=' || chr(2) || ', StopSel=' || chr(3) || ', MaxWords=24, MinWords=10, MaxFragments=2'),
+           h.rank
+    from hits h, q
+    order by h.rank desc;
+$$;
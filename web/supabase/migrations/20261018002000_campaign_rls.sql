@@ -0,0 +1,182 @@
+-- Row-level security for the campaign tables.
+-- src/api/campaign.rs queries these as the signed-in user (create_user_client), so the
+-- database decides what each caller may read and write, not only the server: the campaign
+-- owner is its DM, and anyone with one of their own characters in the party is a member.
+-- Members read the campaign and everything in it; the DM writes it. Players may also log
+-- adventures, and encounters are the DM's alone.
+-- The helpers are security definer so they can look at campaigns and parties without
+-- recursing into the policies below.
+
+create or replace function is_campaign_dm(p_campaign_id integer)
+returns boolean
+language sql stable security definer set search_path = public
+as $$
+    select exists (
+        select 1 from campaigns c
+        where c.id = p_campaign_id
+          and c.owner = auth.uid()::text
+    );
+$$;
+
+create or replace function is_campaign_member(p_campaign_id integer)
+returns boolean
+language sql stable security definer set search_path = public
+as $$
+    select is_campaign_dm(p_campaign_id) or exists (
+        select 1
+        from party_members pm
+        join characters ch on ch.id = pm.character_id
+        where pm.campaign_id = p_campaign_id
+          and ch.owner = auth.uid()::text
+    );
+$$;
+
+-- The rows of `p_table` whose slug starts with `p_base`, across every owner, so a new slug
+-- can be picked that no row uses even where the caller can't read the others.
+-- Used by unique_slug in src/api/character.rs.
+create or replace function taken_slugs(p_table text, p_base text)
+returns table (id integer, slug text)
+language plpgsql stable security definer set search_path = public
+as $$
+begin
+    if p_table not in ('campaigns', 'characters', 'homebrew_packs', 'spells', 'monsters', 'items', 'feats') then
+        raise exception 'No slugs are kept in %', p_table;
+    end if;
+    -- A slug is only lowercase letters, digits and dashes, so it needs no escaping in a pattern
+    return query execute format('select id, slug from %I where slug like $1', p_table)
+        using p_base || '%';
+end;
+$$;
+
+alter table campaigns enable row level security;
+
+create policy "Campaigns are readable by their DM and party"
+    on campaigns for select
+    using (owner = auth.uid()::text or is_campaign_member(id));
+
+create policy "Users create their own campaigns"
+    on campaigns for insert
+    with check (owner = auth.uid()::text);
+
+create policy "DMs update their campaigns"
+    on campaigns for update
+    using (owner = auth.uid()::text)
+    with check (owner = auth.uid()::text);
+
+create policy "DMs delete their campaigns"
+    on campaigns for delete
+    using (owner = auth.uid()::text);
+
+alter table quests enable row level security;
+
+create policy "Quests are readable by the campaign"
+    on quests for select
+    using (is_campaign_member(campaign_id));
+
+create policy "DMs manage quests"
+    on quests for all
+    using (is_campaign_dm(campaign_id))
+    with check (is_campaign_dm(campaign_id));
+
+alter table campaign_events enable row level security;
+
+create policy "Campaign events are readable by the campaign"
+    on campaign_events for select
+    using (is_campaign_member(campaign_id));
+
+create policy "DMs manage campaign events"
+    on campaign_events for all
+    using (is_campaign_dm(campaign_id))
+    with check (is_campaign_dm(campaign_id));
+
+alter table party_members enable row level security;
+
+create policy "Party members are readable by the campaign"
+    on party_members for select
+    using (is_campaign_member(campaign_id));
+
+create policy "DMs manage party members"
+    on party_members for all
+    using (is_campaign_dm(campaign_id))
+    with check (is_campaign_dm(campaign_id));
+
+alter table adventures enable row level security;
+
+create policy "Adventures are readable by the campaign"
+    on adventures for select
+    using (is_campaign_member(campaign_id));
+
+create policy "Members log adventures"
+    on adventures for insert
+    with check (is_campaign_member(campaign_id));
+
+create policy "Members update adventures"
+    on adventures for update
+    using (is_campaign_member(campaign_id))
+    with check (is_campaign_member(campaign_id));
+
+create policy "DMs delete adventures"
+    on adventures for delete
+    using (is_campaign_dm(campaign_id));
+
+alter table lore_categories enable row level security;
+
+create policy "Lore categories are readable by the campaign"
+    on lore_categories for select
+    using (is_campaign_member(campaign_id));
+
+create policy "DMs manage lore categories"
+    on lore_categories for all
+    using (is_campaign_dm(campaign_id))
+    with check (is_campaign_dm(campaign_id));
+
+alter table lore_entries enable row level security;
+
+create policy "Lore entries are readable by the campaign"
+    on lore_entries for select
+    using (is_campaign_member(campaign_id));
+
+create policy "DMs manage lore entries"
+    on lore_entries for all
+    using (is_campaign_dm(campaign_id))
+    with check (is_campaign_dm(campaign_id));
+
+alter table npcs enable row level security;
+
+create policy "NPCs are readable by the campaign"
+    on npcs for select
+    using (is_campaign_member(campaign_id));
+
+create policy "DMs manage NPCs"
+    on npcs for all
+    using (is_campaign_dm(campaign_id))
+    with check (is_campaign_dm(campaign_id));
+
+alter table map_locations enable row level security;
+
+create policy "Map locations are readable by the campaign"
+    on map_locations for select
+    using (is_campaign_member(campaign_id));
+
+create policy "DMs manage map locations"
+    on map_locations for all
+    using (is_campaign_dm(campaign_id))
+    with check (is_campaign_dm(campaign_id));
+
+alter table encounters enable row level security;
+
+create policy "DMs manage encounters"
+    on encounters for all
+    using (is_campaign_dm(campaign_id))
+    with check (is_campaign_dm(campaign_id));
+
+alter table campaign_homebrew_packs enable row level security;
+
+create policy "Enabled homebrew packs are readable by the campaign"
+    on campaign_homebrew_packs for select
+    using (is_campaign_member(campaign_id));
+
+create policy "DMs enable homebrew packs"
+    on campaign_homebrew_packs for all
+    using (is_campaign_dm(campaign_id))
+    with check (is_campaign_dm(campaign_id));
@@ -0,0 +1,149 @@
+-- Party invites. A DM links a character to their party, but the link only counts once the
+-- character's owner accepts it: until then the DM can't see or edit the character, its
+-- owner isn't a member of the campaign, and its party mates can't see it either.
+-- `accepted` is never written directly. Linking a character, or moving the link to another
+-- character or campaign, resets it unless the caller owns the character; only the owner can
+-- set it, through respond_to_party_invite.
+
+alter table party_members add column if not exists accepted boolean not null default false;
+
+-- A DM's own characters in their own party need no invite
+update party_members pm
+set accepted = true
+from campaigns c, characters ch
+where c.id = pm.campaign_id
+  and ch.id = pm.character_id
+  and ch.owner = c.owner;
+
+create or replace function owns_character(p_character_id integer)
+returns boolean
+language sql stable security definer set search_path = public
+as $$
+    select exists (
+        select 1 from characters ch
+        where ch.id = p_character_id
+          and ch.owner = auth.uid()::text
+    );
+$$;
+
+create or replace function guard_party_invite()
+returns trigger
+language plpgsql
+as $$
+begin
+    if new.character_id is null then
+        new.accepted := false;
+    elsif tg_op = 'INSERT'
+        or new.character_id is distinct from old.character_id
+        or new.campaign_id is distinct from old.campaign_id then
+        new.accepted := owns_character(new.character_id);
+    elsif new.accepted is distinct from old.accepted and not owns_character(new.character_id) then
+        new.accepted := old.accepted;
+    end if;
+    return new;
+end;
+$$;
+
+drop trigger if exists party_members_guard_invite on party_members;
+create trigger party_members_guard_invite
+    before insert or update on party_members
+    for each row execute function guard_party_invite();
+
+-- Accept the invite of party row `p_party_member_id`, or decline it, which also unlinks the
+-- character. Declining an accepted invite takes the character out of the party.
+create or replace function respond_to_party_invite(p_party_member_id integer, p_accept boolean)
+returns void
+language plpgsql security definer set search_path = public
+as $$
+begin
+    if not exists (
+        select 1 from party_members pm
+        where pm.id = p_party_member_id
+          and owns_character(pm.character_id)
+    ) then
+        raise exception 'No invite for one of your characters with id %', p_party_member_id;
+    end if;
+
+    if p_accept then
+        update party_members set accepted = true where id = p_party_member_id;
+    else
+        update party_members set character_id = null where id = p_party_member_id;
+    end if;
+end;
+$$;
+
+-- The invites waiting on the caller, with enough of the campaign to decide on them
+create or replace function pending_party_invites()
+returns table (id integer, campaign_id integer, campaign_name text, character_id integer, character_name text)
+language sql stable security definer set search_path = public
+as $$
+    select pm.id, c.id, c.name, ch.id, ch.name
+    from party_members pm
+    join campaigns c on c.id = pm.campaign_id
+    join characters ch on ch.id = pm.character_id
+    where ch.owner = auth.uid()::text
+      and not pm.accepted
+    order by c.name, ch.name;
+$$;
+
+-- Only accepted links count from here on
+
+create or replace function is_campaign_member(p_campaign_id integer)
+returns boolean
+language sql stable security definer set search_path = public
+as $$
+    select is_campaign_dm(p_campaign_id) or exists (
+        select 1
+        from party_members pm
+        join characters ch on ch.id = pm.character_id
+        where pm.campaign_id = p_campaign_id
+          and pm.accepted
+          and ch.owner = auth.uid()::text
+    );
+$$;
+
+create or replace function is_character_dm(p_character_id integer)
+returns boolean
+language sql stable security definer set search_path = public
+as $$
+    select exists (
+        select 1
+        from party_members pm
+        join campaigns c on c.id = pm.campaign_id
+        where pm.character_id = p_character_id
+          and pm.accepted
+          and c.owner = auth.uid()::text
+    );
+$$;
+
+create or replace function is_character_party_member(p_character_id integer)
+returns boolean
+language sql stable security definer set search_path = public
+as $$
+    select exists (
+        select 1
+        from party_members theirs
+        join party_members mine on mine.campaign_id = theirs.campaign_id
+        join characters ch on ch.id = mine.character_id
+        where theirs.character_id = p_character_id
+          and theirs.accepted
+          and mine.accepted
+          and ch.owner = auth.uid()::text
+    );
+$$;
+
+create or replace function can_read_homebrew_pack(p_pack_id integer)
+returns boolean
+language sql stable security definer set search_path = public
+as $$
+    select exists (
+        select 1 from homebrew_packs p
+        where p.id = p_pack_id
+          and (p.shared or p.owner = auth.uid()::text)
+    ) or exists (
+        select 1
+        from campaign_homebrew_packs cp
+        where cp.pack_id = p_pack_id
+          and is_campaign_member(cp.campaign_id)
+    );
+$$;
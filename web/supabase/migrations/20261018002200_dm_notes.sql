@@ -0,0 +1,45 @@
+-- DM notes move out of campaigns and adventures into tables only the campaign's DM can read
+-- or write, so players can't get at them even by querying the database themselves.
+-- src/api/campaign.rs merges them back into Campaign.dm_notes and Adventure.dm_notes for
+-- the DM.
+
+create table if not exists campaign_dm_notes (
+    campaign_id integer primary key references campaigns (id) on delete cascade,
+    notes text not null default ''
+);
+
+create table if not exists adventure_dm_notes (
+    adventure_id integer primary key references adventures (id) on delete cascade,
+    campaign_id integer not null references campaigns (id) on delete cascade,
+    notes text not null default ''
+);
+
+create index if not exists adventure_dm_notes_campaign_id_idx on adventure_dm_notes (campaign_id);
+
+insert into campaign_dm_notes (campaign_id, notes)
+select id, dm_notes from campaigns where dm_notes <> ''
+on conflict (campaign_id) do nothing;
+
+insert into adventure_dm_notes (adventure_id, campaign_id, notes)
+select id, campaign_id, dm_notes from adventures where dm_notes is not null
+on conflict (adventure_id) do nothing;
+
+alter table campaigns drop column if exists dm_notes;
+alter table adventures drop column if exists dm_notes;
+
+alter table campaign_dm_notes enable row level security;
+
+create policy "DMs manage their campaign notes"
+    on campaign_dm_notes for all
+    using (is_campaign_dm(campaign_id))
+    with check (is_campaign_dm(campaign_id));
+
+alter table adventure_dm_notes enable row level security;
+
+create policy "DMs manage their adventure notes"
+    on adventure_dm_notes for all
+    using (is_campaign_dm(campaign_id))
+    with check (
+        is_campaign_dm(campaign_id)
+        and exists (select 1 from adventures a where a.id = adventure_id and a.campaign_id = adventure_dm_notes.campaign_id)
+    );
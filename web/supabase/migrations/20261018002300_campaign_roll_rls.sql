@@ -0,0 +1,35 @@
+-- Row-level security for the campaign roll log, which src/api/campaign.rs reads and writes as
+-- the signed-in user. The DM sees every roll and players see their own, the public ones, and
+-- whispers to their characters, as RollEntry::visible_to does on the server.
+-- Members add rolls under their own name, for their own characters (the DM for any); the dice
+-- are thrown by the server in record_roll. Nobody can change or delete a roll once it's logged.
+
+alter table campaign_rolls enable row level security;
+
+create policy "Rolls are readable by whoever they were shown to"
+    on campaign_rolls for select
+    using (
+        is_campaign_dm(campaign_id)
+        or roller = auth.uid()::text
+        or (
+            is_campaign_member(campaign_id)
+            and (visibility = 'Public' or (visibility = 'Whisper' and owns_character(whisper_to)))
+        )
+    );
+
+create policy "Members log their own rolls"
+    on campaign_rolls for insert
+    with check (
+        roller = auth.uid()::text
+        and is_campaign_member(campaign_id)
+        and (character_id is null or owns_character(character_id) or is_campaign_dm(campaign_id))
+        and (
+            whisper_to is null
+            or exists (
+                select 1 from party_members pm
+                where pm.campaign_id = campaign_rolls.campaign_id
+                  and pm.character_id = whisper_to
+                  and pm.accepted
+            )
+        )
+    );
@@ -0,0 +1,2270 @@
+-- The rest of the SRD 5.1 bestiary (Open Game License 1.0a / CC-BY-4.0): every monster,
+-- creature and NPC stat block that 20261018001600_monster_bestiary.sql didn't already seed,
+-- in the same shape. Slugs that are already taken are left alone.
+-- The SRD doesn't list habitats; `environments` follows where each creature is usually met,
+-- and is empty for creatures met mostly on other planes.
+insert into monsters (slug, name, size, monster_type, subtype, alignment,
+                      armor_class, armor_desc, hit_points, hit_dice, speed,
+                      strength, dexterity, constitution, intelligence, wisdom, charisma, saving_throws, skills,
+                      damage_vulnerabilities, damage_resistances, damage_immunities, condition_immunities,
+                      senses, languages, challenge_rating, environments,
+                      traits,
+                      actions,
+                      reactions, legendary_actions_per_round, legendary_actions) values
+    ('awakened-shrub', 'Awakened Shrub', 'Small', 'Plant', '', 'unaligned',
+        9, '', 10, '3d6', '{"walk": 20, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        3, 8, 11, 10, 10, 6, '[]', '[]',
+        'fire', 'piercing', '', '',
+        'passive Perception 10', 'one language known by its creator', 0, '["Forest"]',
+        '[{"name": "False Appearance", "description": "While the shrub remains motionless, it is indistinguishable from a normal shrub.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Rake", "description": "Melee Weapon Attack: +1 to hit, reach 5 ft., one target. Hit: 1 (1d4 - 1) slashing damage.", "attack_bonus": 1, "damage": "1d4-1"}]',
+        '[]', 0, '[]'),
+    ('baboon', 'Baboon', 'Small', 'Beast', '', 'unaligned',
+        12, '', 3, '1d6', '{"walk": 30, "fly": 0, "swim": 0, "climb": 30, "burrow": 0, "hover": false}',
+        8, 14, 11, 4, 12, 6, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 11', '', 0, '["Forest", "Hill"]',
+        '[{"name": "Pack Tactics", "description": "The baboon has advantage on an attack roll against a creature if at least one of the baboon''s allies is within 5 feet of the creature and the ally isn''t incapacitated.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +1 to hit, reach 5 ft., one target. Hit: 1 (1d4 - 1) piercing damage.", "attack_bonus": 1, "damage": "1d4-1"}]',
+        '[]', 0, '[]'),
+    ('badger', 'Badger', 'Tiny', 'Beast', '', 'unaligned',
+        10, '', 3, '1d4+1', '{"walk": 20, "fly": 0, "swim": 0, "climb": 0, "burrow": 5, "hover": false}',
+        4, 11, 12, 2, 12, 5, '[]', '[]',
+        '', '', '', '',
+        'darkvision 30 ft., passive Perception 11', '', 0, '["Forest", "Grassland"]',
+        '[{"name": "Keen Smell", "description": "The badger has advantage on Wisdom (Perception) checks that rely on smell.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +2 to hit, reach 5 ft., one target. Hit: 1 piercing damage.", "attack_bonus": 2, "damage": "1"}]',
+        '[]', 0, '[]'),
+    ('bat', 'Bat', 'Tiny', 'Beast', '', 'unaligned',
+        12, '', 1, '1d4-1', '{"walk": 5, "fly": 30, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        2, 15, 8, 2, 12, 4, '[]', '[]',
+        '', '', '', '',
+        'blindsight 60 ft., passive Perception 11', '', 0, '["Forest", "Underdark"]',
+        '[{"name": "Echolocation", "description": "The bat can''t use its blindsight while deafened.", "attack_bonus": null, "damage": null}, {"name": "Keen Hearing", "description": "The bat has advantage on Wisdom (Perception) checks that rely on hearing.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +0 to hit, reach 5 ft., one target. Hit: 1 piercing damage.", "attack_bonus": 0, "damage": "1"}]',
+        '[]', 0, '[]'),
+    ('cat', 'Cat', 'Tiny', 'Beast', '', 'unaligned',
+        12, '', 2, '1d4', '{"walk": 40, "fly": 0, "swim": 0, "climb": 30, "burrow": 0, "hover": false}',
+        3, 15, 10, 3, 12, 7, '[]', '[{"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 4}]',
+        '', '', '', '',
+        'passive Perception 13', '', 0, '["Grassland", "Urban"]',
+        '[{"name": "Keen Smell", "description": "The cat has advantage on Wisdom (Perception) checks that rely on smell.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Claws", "description": "Melee Weapon Attack: +0 to hit, reach 5 ft., one target. Hit: 1 slashing damage.", "attack_bonus": 0, "damage": "1"}]',
+        '[]', 0, '[]'),
+    ('crab', 'Crab', 'Tiny', 'Beast', '', 'unaligned',
+        11, 'natural armor', 2, '1d4', '{"walk": 20, "fly": 0, "swim": 20, "climb": 0, "burrow": 0, "hover": false}',
+        2, 11, 10, 1, 8, 2, '[]', '[{"name": "Stealth", "bonus": 2}]',
+        '', '', '', '',
+        'blindsight 30 ft., passive Perception 9', '', 0, '["Coastal"]',
+        '[{"name": "Amphibious", "description": "The crab can breathe air and water.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Claw", "description": "Melee Weapon Attack: +0 to hit, reach 5 ft., one target. Hit: 1 bludgeoning damage.", "attack_bonus": 0, "damage": "1"}]',
+        '[]', 0, '[]'),
+    ('deer', 'Deer', 'Medium', 'Beast', '', 'unaligned',
+        13, '', 4, '1d8', '{"walk": 50, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        11, 16, 11, 2, 14, 5, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 12', '', 0, '["Forest", "Grassland"]',
+        '[]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +2 to hit, reach 5 ft., one target. Hit: 2 (1d4) piercing damage.", "attack_bonus": 2, "damage": "1d4"}]',
+        '[]', 0, '[]'),
+    ('eagle', 'Eagle', 'Small', 'Beast', '', 'unaligned',
+        12, '', 3, '1d6', '{"walk": 10, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        6, 15, 10, 2, 14, 7, '[]', '[{"name": "Perception", "bonus": 4}]',
+        '', '', '', '',
+        'passive Perception 14', '', 0, '["Grassland", "Hill", "Mountain"]',
+        '[{"name": "Keen Sight", "description": "The eagle has advantage on Wisdom (Perception) checks that rely on sight.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Talons", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 4 (1d4 + 2) slashing damage.", "attack_bonus": 4, "damage": "1d4+2"}]',
+        '[]', 0, '[]'),
+    ('frog', 'Frog', 'Tiny', 'Beast', '', 'unaligned',
+        11, '', 1, '1d4-1', '{"walk": 20, "fly": 0, "swim": 20, "climb": 0, "burrow": 0, "hover": false}',
+        1, 13, 8, 1, 8, 3, '[]', '[{"name": "Perception", "bonus": 1}, {"name": "Stealth", "bonus": 3}]',
+        '', '', '', '',
+        'darkvision 30 ft., passive Perception 11', '', 0, '["Forest", "Swamp"]',
+        '[{"name": "Amphibious", "description": "The frog can breathe air and water.", "attack_bonus": null, "damage": null}, {"name": "Standing Leap", "description": "The frog''s long jump is up to 10 feet and its high jump is up to 5 feet, with or without a running start.", "attack_bonus": null, "damage": null}]',
+        '[]',
+        '[]', 0, '[]'),
+    ('giant-fire-beetle', 'Giant Fire Beetle', 'Small', 'Beast', '', 'unaligned',
+        13, 'natural armor', 4, '1d6+1', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        8, 10, 12, 1, 7, 3, '[]', '[]',
+        '', '', '', '',
+        'blindsight 30 ft., passive Perception 8', '', 0, '["Underdark"]',
+        '[{"name": "Illumination", "description": "The beetle sheds bright light in a 10-foot radius and dim light for an additional 10 feet.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +1 to hit, reach 5 ft., one target. Hit: 2 (1d6 - 1) slashing damage.", "attack_bonus": 1, "damage": "1d6-1"}]',
+        '[]', 0, '[]'),
+    ('goat', 'Goat', 'Medium', 'Beast', '', 'unaligned',
+        10, '', 4, '1d8', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        12, 10, 11, 2, 10, 5, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 10', '', 0, '["Hill", "Mountain"]',
+        '[{"name": "Charge", "description": "If the goat moves at least 20 feet straight toward a target and then hits it with a ram attack on the same turn, the target takes an extra 2 (1d4) bludgeoning damage. If the target is a creature, it must succeed on a DC 10 Strength saving throw or be knocked prone.", "attack_bonus": null, "damage": "1d4"}, {"name": "Sure-Footed", "description": "The goat has advantage on Strength and Dexterity saving throws made against effects that would knock it prone.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Ram", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 3 (1d4 + 1) bludgeoning damage.", "attack_bonus": 3, "damage": "1d4+1"}]',
+        '[]', 0, '[]'),
+    ('hawk', 'Hawk', 'Tiny', 'Beast', '', 'unaligned',
+        13, '', 1, '1d4-1', '{"walk": 10, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        5, 16, 8, 2, 14, 6, '[]', '[{"name": "Perception", "bonus": 4}]',
+        '', '', '', '',
+        'passive Perception 14', '', 0, '["Forest", "Grassland", "Hill", "Mountain"]',
+        '[{"name": "Keen Sight", "description": "The hawk has advantage on Wisdom (Perception) checks that rely on sight.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Talons", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 1 slashing damage.", "attack_bonus": 5, "damage": "1"}]',
+        '[]', 0, '[]'),
+    ('homunculus', 'Homunculus', 'Tiny', 'Construct', '', 'neutral',
+        13, 'natural armor', 5, '2d4', '{"walk": 20, "fly": 40, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        4, 15, 11, 10, 10, 7, '[]', '[]',
+        '', '', 'poison', 'charmed, poisoned',
+        'darkvision 60 ft., passive Perception 10', 'understands the languages of its creator but can''t speak', 0, '[]',
+        '[{"name": "Telepathic Bond", "description": "While the homunculus is on the same plane of existence as its master, it can magically convey what it senses to its master, and the two can communicate telepathically.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one creature. Hit: 1 piercing damage. The target must succeed on a DC 10 Constitution saving throw or be poisoned for 1 minute. If the saving throw fails by 5 or more, the target is instead poisoned for 5 (1d10) minutes and unconscious while poisoned in this way.", "attack_bonus": 4, "damage": "1"}]',
+        '[]', 0, '[]'),
+    ('hyena', 'Hyena', 'Medium', 'Beast', '', 'unaligned',
+        11, '', 5, '1d8+1', '{"walk": 50, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        11, 13, 12, 2, 12, 5, '[]', '[{"name": "Perception", "bonus": 3}]',
+        '', '', '', '',
+        'passive Perception 13', '', 0, '["Desert", "Grassland"]',
+        '[{"name": "Pack Tactics", "description": "The hyena has advantage on an attack roll against a creature if at least one of the hyena''s allies is within 5 feet of the creature and the ally isn''t incapacitated.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +2 to hit, reach 5 ft., one target. Hit: 3 (1d6) piercing damage.", "attack_bonus": 2, "damage": "1d6"}]',
+        '[]', 0, '[]'),
+    ('jackal', 'Jackal', 'Small', 'Beast', '', 'unaligned',
+        12, '', 3, '1d6', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        8, 15, 11, 3, 12, 6, '[]', '[{"name": "Perception", "bonus": 3}]',
+        '', '', '', '',
+        'passive Perception 13', '', 0, '["Desert", "Grassland"]',
+        '[{"name": "Keen Hearing and Smell", "description": "The jackal has advantage on Wisdom (Perception) checks that rely on hearing or smell.", "attack_bonus": null, "damage": null}, {"name": "Pack Tactics", "description": "The jackal has advantage on an attack roll against a creature if at least one of the jackal''s allies is within 5 feet of the creature and the ally isn''t incapacitated.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +1 to hit, reach 5 ft., one target. Hit: 1 (1d4 - 1) piercing damage.", "attack_bonus": 1, "damage": "1d4-1"}]',
+        '[]', 0, '[]'),
+    ('lemure', 'Lemure', 'Medium', 'Fiend', 'devil', 'lawful evil',
+        7, '', 13, '3d8', '{"walk": 15, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        10, 5, 11, 1, 11, 3, '[]', '[]',
+        '', 'cold', 'fire, poison', 'charmed, frightened, poisoned',
+        'darkvision 120 ft., passive Perception 10', 'understands Infernal but can''t speak', 0, '[]',
+        '[{"name": "Devil''s Sight", "description": "Magical darkness doesn''t impede the devil''s darkvision.", "attack_bonus": null, "damage": null}, {"name": "Hellish Rejuvenation", "description": "A lemure that dies in the Nine Hells comes back to life with all its hit points in 1d10 days unless it is killed by a good-aligned creature with a bless spell cast on that creature or its remains are sprinkled with holy water.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Fist", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 2 (1d4) bludgeoning damage.", "attack_bonus": 3, "damage": "1d4"}]',
+        '[]', 0, '[]'),
+    ('lizard', 'Lizard', 'Tiny', 'Beast', '', 'unaligned',
+        10, '', 2, '1d4', '{"walk": 20, "fly": 0, "swim": 0, "climb": 20, "burrow": 0, "hover": false}',
+        2, 11, 10, 1, 8, 3, '[]', '[]',
+        '', '', '', '',
+        'darkvision 30 ft., passive Perception 9', '', 0, '["Desert", "Forest", "Swamp"]',
+        '[]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +0 to hit, reach 5 ft., one target. Hit: 1 piercing damage.", "attack_bonus": 0, "damage": "1"}]',
+        '[]', 0, '[]'),
+    ('octopus', 'Octopus', 'Small', 'Beast', '', 'unaligned',
+        12, '', 3, '1d6', '{"walk": 5, "fly": 0, "swim": 30, "climb": 0, "burrow": 0, "hover": false}',
+        4, 15, 11, 3, 10, 4, '[]', '[{"name": "Perception", "bonus": 2}, {"name": "Stealth", "bonus": 4}]',
+        '', '', '', '',
+        'darkvision 30 ft., passive Perception 12', '', 0, '["Coastal", "Underwater"]',
+        '[{"name": "Hold Breath", "description": "The octopus can hold its breath for 30 minutes while out of water.", "attack_bonus": null, "damage": null}, {"name": "Underwater Camouflage", "description": "The octopus has advantage on Dexterity (Stealth) checks made while underwater.", "attack_bonus": null, "damage": null}, {"name": "Water Breathing", "description": "The octopus can breathe only underwater.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Tentacles", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 1 bludgeoning damage. The target is grappled (escape DC 10). Until this grapple ends, the octopus can''t use its tentacles on another target.", "attack_bonus": 4, "damage": "1"}, {"name": "Ink Cloud (Recharges after a Short or Long Rest)", "description": "A 5-foot-radius cloud of ink extends all around the octopus if it is underwater. The area is heavily obscured for 1 minute, although a significant current can disperse the ink. After releasing the ink, the octopus can use the Dash action as a bonus action.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('owl', 'Owl', 'Tiny', 'Beast', '', 'unaligned',
+        11, '', 1, '1d4-1', '{"walk": 5, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        3, 13, 8, 2, 12, 7, '[]', '[{"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 3}]',
+        '', '', '', '',
+        'darkvision 120 ft., passive Perception 13', '', 0, '["Forest", "Arctic"]',
+        '[{"name": "Flyby", "description": "The owl doesn''t provoke opportunity attacks when it flies out of an enemy''s reach.", "attack_bonus": null, "damage": null}, {"name": "Keen Hearing and Sight", "description": "The owl has advantage on Wisdom (Perception) checks that rely on hearing or sight.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Talons", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 1 slashing damage.", "attack_bonus": 3, "damage": "1"}]',
+        '[]', 0, '[]'),
+    ('quipper', 'Quipper', 'Tiny', 'Beast', '', 'unaligned',
+        13, '', 1, '1d4-1', '{"walk": 0, "fly": 0, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        2, 16, 9, 1, 7, 2, '[]', '[]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 8', '', 0, '["Swamp", "Underwater"]',
+        '[{"name": "Blood Frenzy", "description": "The quipper has advantage on melee attack rolls against any creature that doesn''t have all its hit points.", "attack_bonus": null, "damage": null}, {"name": "Water Breathing", "description": "The quipper can breathe only underwater.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 1 piercing damage.", "attack_bonus": 5, "damage": "1"}]',
+        '[]', 0, '[]'),
+    ('raven', 'Raven', 'Tiny', 'Beast', '', 'unaligned',
+        12, '', 1, '1d4-1', '{"walk": 10, "fly": 50, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        2, 14, 8, 2, 12, 6, '[]', '[{"name": "Perception", "bonus": 3}]',
+        '', '', '', '',
+        'passive Perception 13', '', 0, '["Forest", "Hill", "Urban"]',
+        '[{"name": "Mimicry", "description": "The raven can mimic simple sounds it has heard, such as a person whispering, a baby crying, or an animal chittering. A creature that hears the sounds can tell they are imitations with a successful DC 10 Wisdom (Insight) check.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Beak", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 1 piercing damage.", "attack_bonus": 4, "damage": "1"}]',
+        '[]', 0, '[]'),
+    ('scorpion', 'Scorpion', 'Tiny', 'Beast', '', 'unaligned',
+        11, 'natural armor', 1, '1d4-1', '{"walk": 10, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        2, 11, 8, 1, 8, 2, '[]', '[]',
+        '', '', '', '',
+        'blindsight 10 ft., passive Perception 9', '', 0, '["Desert"]',
+        '[]',
+        '[{"name": "Sting", "description": "Melee Weapon Attack: +2 to hit, reach 5 ft., one creature. Hit: 1 piercing damage. The target must make a DC 9 Constitution saving throw, taking 4 (1d8) poison damage on a failed save, or half as much damage on a successful one.", "attack_bonus": 2, "damage": "1"}]',
+        '[]', 0, '[]'),
+    ('sea-horse', 'Sea Horse', 'Tiny', 'Beast', '', 'unaligned',
+        11, '', 1, '1d4-1', '{"walk": 0, "fly": 0, "swim": 20, "climb": 0, "burrow": 0, "hover": false}',
+        1, 12, 8, 1, 10, 2, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 10', '', 0, '["Coastal", "Underwater"]',
+        '[{"name": "Water Breathing", "description": "The sea horse can breathe only underwater.", "attack_bonus": null, "damage": null}]',
+        '[]',
+        '[]', 0, '[]'),
+    ('shrieker', 'Shrieker', 'Medium', 'Plant', '', 'unaligned',
+        5, '', 13, '3d8', '{"walk": 0, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        1, 1, 10, 1, 3, 1, '[]', '[]',
+        '', '', '', 'blinded, deafened, frightened',
+        'blindsight 30 ft. (blind beyond this radius), passive Perception 6', '', 0, '["Underdark"]',
+        '[{"name": "False Appearance", "description": "While the shrieker remains motionless, it is indistinguishable from an ordinary fungus.", "attack_bonus": null, "damage": null}]',
+        '[]',
+        '[{"name": "Shriek", "description": "When bright light or a creature is within 30 feet of the shrieker, it emits a shriek audible within 300 feet of it. The shrieker continues to shriek until the disturbance moves out of range and for 1d4 of the shrieker''s turns afterward.", "attack_bonus": null, "damage": null}]', 0, '[]'),
+    ('spider', 'Spider', 'Tiny', 'Beast', '', 'unaligned',
+        12, '', 1, '1d4-1', '{"walk": 20, "fly": 0, "swim": 0, "climb": 20, "burrow": 0, "hover": false}',
+        2, 14, 8, 1, 10, 2, '[]', '[{"name": "Stealth", "bonus": 4}]',
+        '', '', '', '',
+        'darkvision 30 ft., passive Perception 10', '', 0, '["Forest", "Underdark", "Urban"]',
+        '[{"name": "Spider Climb", "description": "The spider can climb difficult surfaces, including upside down on ceilings, without needing to make an ability check.", "attack_bonus": null, "damage": null}, {"name": "Web Sense", "description": "While in contact with a web, the spider knows the exact location of any other creature in contact with the same web.", "attack_bonus": null, "damage": null}, {"name": "Web Walker", "description": "The spider ignores movement restrictions caused by webbing.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one creature. Hit: 1 piercing damage. The target must succeed on a DC 9 Constitution saving throw or take 2 (1d4) poison damage.", "attack_bonus": 4, "damage": "1"}]',
+        '[]', 0, '[]'),
+    ('vulture', 'Vulture', 'Medium', 'Beast', '', 'unaligned',
+        10, '', 5, '1d8+1', '{"walk": 10, "fly": 50, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        7, 10, 13, 2, 12, 4, '[]', '[{"name": "Perception", "bonus": 3}]',
+        '', '', '', '',
+        'passive Perception 13', '', 0, '["Desert", "Grassland"]',
+        '[{"name": "Keen Sight and Smell", "description": "The vulture has advantage on Wisdom (Perception) checks that rely on sight or smell.", "attack_bonus": null, "damage": null}, {"name": "Pack Tactics", "description": "The vulture has advantage on an attack roll against a creature if at least one of the vulture''s allies is within 5 feet of the creature and the ally isn''t incapacitated.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Beak", "description": "Melee Weapon Attack: +2 to hit, reach 5 ft., one target. Hit: 2 (1d4) piercing damage.", "attack_bonus": 2, "damage": "1d4"}]',
+        '[]', 0, '[]'),
+    ('weasel', 'Weasel', 'Tiny', 'Beast', '', 'unaligned',
+        13, '', 1, '1d4-1', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        3, 16, 8, 2, 12, 3, '[]', '[{"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 5}]',
+        '', '', '', '',
+        'passive Perception 13', '', 0, '["Forest", "Grassland"]',
+        '[{"name": "Keen Hearing and Smell", "description": "The weasel has advantage on Wisdom (Perception) checks that rely on hearing or smell.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 1 piercing damage.", "attack_bonus": 5, "damage": "1"}]',
+        '[]', 0, '[]'),
+    ('blood-hawk', 'Blood Hawk', 'Small', 'Beast', '', 'unaligned',
+        12, '', 7, '2d6', '{"walk": 10, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        6, 14, 10, 3, 14, 5, '[]', '[{"name": "Perception", "bonus": 4}]',
+        '', '', '', '',
+        'passive Perception 14', '', 0.125, '["Coastal", "Grassland", "Mountain"]',
+        '[{"name": "Keen Sight", "description": "The hawk has advantage on Wisdom (Perception) checks that rely on sight.", "attack_bonus": null, "damage": null}, {"name": "Pack Tactics", "description": "The hawk has advantage on an attack roll against a creature if at least one of the hawk''s allies is within 5 feet of the creature and the ally isn''t incapacitated.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Beak", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 4 (1d4 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d4+2"}]',
+        '[]', 0, '[]'),
+    ('camel', 'Camel', 'Large', 'Beast', '', 'unaligned',
+        9, '', 15, '2d10+4', '{"walk": 50, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 8, 14, 2, 8, 5, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 9', '', 0.125, '["Desert"]',
+        '[]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 2 (1d4) bludgeoning damage.", "attack_bonus": 5, "damage": "1d4"}]',
+        '[]', 0, '[]'),
+    ('cultist', 'Cultist', 'Medium', 'Humanoid', 'any race', 'any non-good alignment',
+        12, 'leather armor', 9, '2d8', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        11, 12, 10, 10, 11, 10, '[]', '[{"name": "Deception", "bonus": 2}, {"name": "Religion", "bonus": 2}]',
+        '', '', '', '',
+        'passive Perception 10', 'any one language (usually Common)', 0.125, '["Urban", "Underdark"]',
+        '[{"name": "Dark Devotion", "description": "The cultist has advantage on saving throws against being charmed or frightened.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Scimitar", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 4 (1d6 + 1) slashing damage.", "attack_bonus": 3, "damage": "1d6+1"}]',
+        '[]', 0, '[]'),
+    ('flying-snake', 'Flying Snake', 'Tiny', 'Beast', '', 'unaligned',
+        14, '', 5, '2d4', '{"walk": 30, "fly": 60, "swim": 30, "climb": 0, "burrow": 0, "hover": false}',
+        4, 18, 11, 2, 12, 5, '[]', '[]',
+        '', '', '', '',
+        'blindsight 10 ft., passive Perception 11', '', 0.125, '["Desert", "Forest"]',
+        '[{"name": "Flyby", "description": "The snake doesn''t provoke opportunity attacks when it flies out of an enemy''s reach.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 1 piercing damage plus 7 (3d4) poison damage.", "attack_bonus": 6, "damage": "1"}]',
+        '[]', 0, '[]'),
+    ('giant-crab', 'Giant Crab', 'Medium', 'Beast', '', 'unaligned',
+        15, 'natural armor', 13, '3d8', '{"walk": 30, "fly": 0, "swim": 30, "climb": 0, "burrow": 0, "hover": false}',
+        13, 15, 11, 1, 9, 3, '[]', '[{"name": "Stealth", "bonus": 4}]',
+        '', '', '', '',
+        'blindsight 30 ft., passive Perception 9', '', 0.125, '["Coastal"]',
+        '[{"name": "Amphibious", "description": "The crab can breathe air and water.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Claw", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 4 (1d6 + 1) bludgeoning damage. The target is grappled (escape DC 11). The crab has two claws, each of which can grapple only one target.", "attack_bonus": 3, "damage": "1d6+1"}]',
+        '[]', 0, '[]'),
+    ('giant-weasel', 'Giant Weasel', 'Medium', 'Beast', '', 'unaligned',
+        13, '', 9, '2d8', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        11, 16, 10, 4, 12, 5, '[]', '[{"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 5}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 13', '', 0.125, '["Forest", "Grassland"]',
+        '[{"name": "Keen Hearing and Smell", "description": "The weasel has advantage on Wisdom (Perception) checks that rely on hearing or smell.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 5 (1d4 + 3) piercing damage.", "attack_bonus": 5, "damage": "1d4+3"}]',
+        '[]', 0, '[]'),
+    ('guard', 'Guard', 'Medium', 'Humanoid', 'any race', 'any alignment',
+        16, 'chain shirt, shield', 11, '2d8+2', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        13, 12, 12, 10, 11, 10, '[]', '[{"name": "Perception", "bonus": 2}]',
+        '', '', '', '',
+        'passive Perception 12', 'any one language (usually Common)', 0.125, '["Urban"]',
+        '[]',
+        '[{"name": "Spear", "description": "Melee or Ranged Weapon Attack: +3 to hit, reach 5 ft. or range 20/60 ft., one target. Hit: 4 (1d6 + 1) piercing damage, or 5 (1d8 + 1) piercing damage if used with two hands to make a melee attack.", "attack_bonus": 3, "damage": "1d6+1"}]',
+        '[]', 0, '[]'),
+    ('mastiff', 'Mastiff', 'Medium', 'Beast', '', 'unaligned',
+        12, '', 5, '1d8+1', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        13, 14, 12, 3, 12, 7, '[]', '[{"name": "Perception", "bonus": 3}]',
+        '', '', '', '',
+        'passive Perception 13', '', 0.125, '["Urban"]',
+        '[{"name": "Keen Hearing and Smell", "description": "The mastiff has advantage on Wisdom (Perception) checks that rely on hearing or smell.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 4 (1d6 + 1) piercing damage. If the target is a creature, it must succeed on a DC 11 Strength saving throw or be knocked prone.", "attack_bonus": 3, "damage": "1d6+1"}]',
+        '[]', 0, '[]'),
+    ('merfolk', 'Merfolk', 'Medium', 'Humanoid', 'merfolk', 'neutral',
+        11, '', 11, '2d8+2', '{"walk": 10, "fly": 0, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        10, 13, 12, 11, 11, 12, '[]', '[{"name": "Perception", "bonus": 2}]',
+        '', '', '', '',
+        'passive Perception 12', 'Aquan, Common', 0.125, '["Coastal", "Underwater"]',
+        '[{"name": "Amphibious", "description": "The merfolk can breathe air and water.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Spear", "description": "Melee or Ranged Weapon Attack: +2 to hit, reach 5 ft. or range 20/60 ft., one target. Hit: 3 (1d6) piercing damage, or 4 (1d8) piercing damage if used with two hands to make a melee attack.", "attack_bonus": 2, "damage": "1d6"}]',
+        '[]', 0, '[]'),
+    ('mule', 'Mule', 'Medium', 'Beast', '', 'unaligned',
+        10, '', 11, '2d8+2', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        14, 10, 13, 2, 10, 5, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 10', '', 0.125, '["Hill", "Urban"]',
+        '[{"name": "Beast of Burden", "description": "The mule is considered to be a Large animal for the purpose of determining its carrying capacity.", "attack_bonus": null, "damage": null}, {"name": "Sure-Footed", "description": "The mule has advantage on Strength and Dexterity saving throws made against effects that would knock it prone.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Hooves", "description": "Melee Weapon Attack: +2 to hit, reach 5 ft., one target. Hit: 4 (1d4 + 2) bludgeoning damage.", "attack_bonus": 2, "damage": "1d4+2"}]',
+        '[]', 0, '[]'),
+    ('noble', 'Noble', 'Medium', 'Humanoid', 'any race', 'any alignment',
+        15, 'breastplate', 9, '2d8', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        11, 12, 11, 12, 14, 16, '[]', '[{"name": "Deception", "bonus": 5}, {"name": "Insight", "bonus": 4}, {"name": "Persuasion", "bonus": 5}]',
+        '', '', '', '',
+        'passive Perception 12', 'any two languages', 0.125, '["Urban"]',
+        '[]',
+        '[{"name": "Rapier", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 5 (1d8 + 1) piercing damage.", "attack_bonus": 3, "damage": "1d8+1"}]',
+        '[{"name": "Parry", "description": "The noble adds 2 to its AC against one melee attack that would hit it. To do so, the noble must see the attacker and be wielding a melee weapon.", "attack_bonus": null, "damage": null}]', 0, '[]'),
+    ('poisonous-snake', 'Poisonous Snake', 'Tiny', 'Beast', '', 'unaligned',
+        13, '', 2, '1d4', '{"walk": 30, "fly": 0, "swim": 30, "climb": 0, "burrow": 0, "hover": false}',
+        2, 16, 11, 1, 10, 3, '[]', '[]',
+        '', '', '', '',
+        'blindsight 10 ft., passive Perception 10', '', 0.125, '["Desert", "Forest", "Grassland", "Swamp"]',
+        '[]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 1 piercing damage. The target must make a DC 10 Constitution saving throw, taking 5 (2d4) poison damage on a failed save, or half as much damage on a successful one.", "attack_bonus": 5, "damage": "1"}]',
+        '[]', 0, '[]'),
+    ('pony', 'Pony', 'Medium', 'Beast', '', 'unaligned',
+        10, '', 11, '2d8+2', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        15, 10, 13, 2, 11, 7, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 10', '', 0.125, '["Grassland", "Hill", "Urban"]',
+        '[]',
+        '[{"name": "Hooves", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 7 (2d4 + 2) bludgeoning damage.", "attack_bonus": 4, "damage": "2d4+2"}]',
+        '[]', 0, '[]'),
+    ('stirge', 'Stirge', 'Tiny', 'Beast', '', 'unaligned',
+        14, 'natural armor', 2, '1d4', '{"walk": 10, "fly": 40, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        4, 16, 11, 2, 8, 6, '[]', '[]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 9', '', 0.125, '["Forest", "Swamp", "Underdark"]',
+        '[]',
+        '[{"name": "Blood Drain", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one creature. Hit: 5 (1d4 + 3) piercing damage. The stirge attaches to the target. While attached, the stirge doesn''t attack. Instead, at the start of each of the stirge''s turns, the target loses 5 (1d4 + 3) hit points due to blood loss. The stirge can detach itself by spending 5 feet of its movement. It does so after it drains 10 hit points of blood from the target or the target dies. A creature, including the target, can use its action to detach the stirge.", "attack_bonus": 5, "damage": "1d4+3"}]',
+        '[]', 0, '[]'),
+    ('tribal-warrior', 'Tribal Warrior', 'Medium', 'Humanoid', 'any race', 'any alignment',
+        12, 'hide armor', 11, '2d8+2', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        13, 11, 12, 8, 11, 8, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 10', 'any one language', 0.125, '["Arctic", "Desert", "Forest", "Grassland", "Hill"]',
+        '[{"name": "Pack Tactics", "description": "The warrior has advantage on an attack roll against a creature if at least one of the warrior''s allies is within 5 feet of the creature and the ally isn''t incapacitated.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Spear", "description": "Melee or Ranged Weapon Attack: +3 to hit, reach 5 ft. or range 20/60 ft., one target. Hit: 4 (1d6 + 1) piercing damage, or 5 (1d8 + 1) piercing damage if used with two hands to make a melee attack.", "attack_bonus": 3, "damage": "1d6+1"}]',
+        '[]', 0, '[]'),
+    ('acolyte', 'Acolyte', 'Medium', 'Humanoid', 'any race', 'any alignment',
+        10, '', 9, '2d8', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        10, 10, 10, 10, 14, 11, '[]', '[{"name": "Medicine", "bonus": 4}, {"name": "Religion", "bonus": 2}]',
+        '', '', '', '',
+        'passive Perception 12', 'any one language (usually Common)', 0.25, '["Urban"]',
+        '[{"name": "Spellcasting", "description": "The acolyte is a 1st-level spellcaster. Its spellcasting ability is Wisdom (spell save DC 12, +4 to hit with spell attacks). The acolyte has following cleric spells prepared. Cantrips (at will): light, sacred flame, thaumaturgy. 1st level (3 slots): bless, cure wounds, sanctuary.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Club", "description": "Melee Weapon Attack: +2 to hit, reach 5 ft., one target. Hit: 2 (1d4) bludgeoning damage.", "attack_bonus": 2, "damage": "1d4"}]',
+        '[]', 0, '[]'),
+    ('axe-beak', 'Axe Beak', 'Large', 'Beast', '', 'unaligned',
+        11, '', 19, '3d10+3', '{"walk": 50, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        14, 12, 12, 2, 10, 5, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 10', '', 0.25, '["Grassland", "Hill"]',
+        '[]',
+        '[{"name": "Beak", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 6 (1d8 + 2) slashing damage.", "attack_bonus": 4, "damage": "1d8+2"}]',
+        '[]', 0, '[]'),
+    ('blink-dog', 'Blink Dog', 'Medium', 'Fey', '', 'lawful good',
+        13, '', 22, '4d8+4', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        12, 17, 12, 10, 13, 11, '[]', '[{"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 5}]',
+        '', '', '', '',
+        'passive Perception 13', 'Blink Dog, understands Sylvan but can''t speak it', 0.25, '["Forest"]',
+        '[{"name": "Keen Hearing and Smell", "description": "The dog has advantage on Wisdom (Perception) checks that rely on hearing or smell.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 4 (1d6 + 1) piercing damage.", "attack_bonus": 3, "damage": "1d6+1"}, {"name": "Teleport (Recharge 4-6)", "description": "The dog magically teleports, along with any equipment it is wearing or carrying, up to 40 feet to an unoccupied space it can see. Before or after teleporting, the dog can make one bite attack.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('boar', 'Boar', 'Medium', 'Beast', '', 'unaligned',
+        11, 'natural armor', 11, '2d8+2', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        13, 11, 12, 2, 9, 5, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 9', '', 0.25, '["Forest", "Grassland", "Hill"]',
+        '[{"name": "Charge", "description": "If the boar moves at least 20 feet straight toward a target and then hits it with a tusk attack on the same turn, the target takes an extra 3 (1d6) slashing damage. If the target is a creature, it must succeed on a DC 11 Strength saving throw or be knocked prone.", "attack_bonus": null, "damage": "1d6"}, {"name": "Relentless (Recharges after a Short or Long Rest)", "description": "If the boar takes 7 damage or less that would reduce it to 0 hit points, it is reduced to 1 hit point instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Tusk", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 4 (1d6 + 1) slashing damage.", "attack_bonus": 3, "damage": "1d6+1"}]',
+        '[]', 0, '[]'),
+    ('constrictor-snake', 'Constrictor Snake', 'Large', 'Beast', '', 'unaligned',
+        12, '', 13, '2d10+2', '{"walk": 30, "fly": 0, "swim": 30, "climb": 0, "burrow": 0, "hover": false}',
+        15, 14, 12, 1, 10, 3, '[]', '[]',
+        '', '', '', '',
+        'blindsight 10 ft., passive Perception 10', '', 0.25, '["Forest", "Swamp"]',
+        '[]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one creature. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d6+2"}, {"name": "Constrict", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one creature. Hit: 6 (1d8 + 2) bludgeoning damage. The target is grappled (escape DC 14). Until this grapple ends, the creature is restrained, and the snake can''t constrict another target.", "attack_bonus": 4, "damage": "1d8+2"}]',
+        '[]', 0, '[]'),
+    ('draft-horse', 'Draft Horse', 'Large', 'Beast', '', 'unaligned',
+        10, '', 19, '3d10+3', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 10, 12, 2, 11, 7, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 10', '', 0.25, '["Grassland", "Urban"]',
+        '[]',
+        '[{"name": "Hooves", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 9 (2d4 + 4) bludgeoning damage.", "attack_bonus": 6, "damage": "2d4+4"}]',
+        '[]', 0, '[]'),
+    ('dretch', 'Dretch', 'Small', 'Fiend', 'demon', 'chaotic evil',
+        11, 'natural armor', 18, '4d6+4', '{"walk": 20, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        11, 11, 12, 5, 8, 3, '[]', '[]',
+        '', 'cold, fire, lightning', 'poison', 'poisoned',
+        'darkvision 60 ft., passive Perception 9', 'Abyssal, telepathy 60 ft. (works only with creatures that understand Abyssal)', 0.25, '[]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The dretch makes two attacks: one with its bite and one with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +2 to hit, reach 5 ft., one target. Hit: 3 (1d6) piercing damage.", "attack_bonus": 2, "damage": "1d6"}, {"name": "Claws", "description": "Melee Weapon Attack: +2 to hit, reach 5 ft., one target. Hit: 5 (2d4) slashing damage.", "attack_bonus": 2, "damage": "2d4"}, {"name": "Fetid Cloud (1/Day)", "description": "A 10-foot radius of disgusting green gas extends out from the dretch. The gas spreads around corners, and its area is lightly obscured. It lasts for 1 minute or until a strong wind disperses it. Any creature that starts its turn in that area must succeed on a DC 11 Constitution saving throw or be poisoned until the start of its next turn. While poisoned in this way, the target can take either an action or a bonus action on its turn, not both, and can''t take reactions.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('drow', 'Drow', 'Medium', 'Humanoid', 'elf', 'neutral evil',
+        15, 'chain shirt', 13, '3d8', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        10, 14, 10, 11, 11, 12, '[]', '[{"name": "Perception", "bonus": 2}, {"name": "Stealth", "bonus": 4}]',
+        '', '', '', '',
+        'darkvision 120 ft., passive Perception 12', 'Elvish, Undercommon', 0.25, '["Underdark"]',
+        '[{"name": "Fey Ancestry", "description": "The drow has advantage on saving throws against being charmed, and magic can''t put the drow to sleep.", "attack_bonus": null, "damage": null}, {"name": "Innate Spellcasting", "description": "The drow''s spellcasting ability is Charisma (spell save DC 11). It can innately cast the following spells, requiring no material components. At will: dancing lights. 1/day each: darkness, faerie fire.", "attack_bonus": null, "damage": null}, {"name": "Sunlight Sensitivity", "description": "While in sunlight, the drow has disadvantage on attack rolls, as well as on Wisdom (Perception) checks that rely on sight.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Shortsword", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d6+2"}, {"name": "Hand Crossbow", "description": "Ranged Weapon Attack: +4 to hit, range 30/120 ft., one target. Hit: 5 (1d6 + 2) piercing damage. The target must succeed on a DC 13 Constitution saving throw or be poisoned for 1 hour. If the saving throw fails by 5 or more, the target is also unconscious while poisoned in this way. The target wakes up if it takes damage or if another creature takes an action to shake it awake.", "attack_bonus": 4, "damage": "1d6+2"}]',
+        '[]', 0, '[]'),
+    ('elk', 'Elk', 'Large', 'Beast', '', 'unaligned',
+        10, '', 13, '2d10+2', '{"walk": 50, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 10, 12, 2, 10, 6, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 10', '', 0.25, '["Forest", "Grassland", "Hill"]',
+        '[{"name": "Charge", "description": "If the elk moves at least 20 feet straight toward a target and then hits it with a ram attack on the same turn, the target takes an extra 7 (2d6) bludgeoning damage. If the target is a creature, it must succeed on a DC 13 Strength saving throw or be knocked prone.", "attack_bonus": null, "damage": "2d6"}]',
+        '[{"name": "Ram", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 6 (1d6 + 3) bludgeoning damage.", "attack_bonus": 5, "damage": "1d6+3"}, {"name": "Hooves", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one prone creature. Hit: 8 (2d4 + 3) bludgeoning damage.", "attack_bonus": 5, "damage": "2d4+3"}]',
+        '[]', 0, '[]'),
+    ('flying-sword', 'Flying Sword', 'Small', 'Construct', '', 'unaligned',
+        17, 'natural armor', 17, '5d6', '{"walk": 0, "fly": 50, "swim": 0, "climb": 0, "burrow": 0, "hover": true}',
+        12, 15, 11, 1, 5, 1, '[{"name": "Dex", "bonus": 4}]', '[]',
+        '', '', 'poison, psychic', 'blinded, charmed, deafened, exhaustion, frightened, paralyzed, petrified, poisoned',
+        'blindsight 60 ft. (blind beyond this radius), passive Perception 7', '', 0.25, '["Urban"]',
+        '[{"name": "Antimagic Susceptibility", "description": "The sword is incapacitated while in the area of an antimagic field. If targeted by dispel magic, the sword must succeed on a Constitution saving throw against the caster''s spell save DC or fall unconscious for 1 minute.", "attack_bonus": null, "damage": null}, {"name": "False Appearance", "description": "While the sword remains motionless and isn''t flying, it is indistinguishable from a normal sword.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Longsword", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 5 (1d8 + 1) slashing damage.", "attack_bonus": 3, "damage": "1d8+1"}]',
+        '[]', 0, '[]'),
+    ('giant-badger', 'Giant Badger', 'Medium', 'Beast', '', 'unaligned',
+        10, '', 13, '2d8+4', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 10, "hover": false}',
+        13, 10, 15, 2, 12, 5, '[]', '[]',
+        '', '', '', '',
+        'darkvision 30 ft., passive Perception 11', '', 0.25, '["Forest", "Grassland"]',
+        '[{"name": "Keen Smell", "description": "The badger has advantage on Wisdom (Perception) checks that rely on smell.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The badger makes two attacks: one with its bite and one with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 4 (1d6 + 1) piercing damage.", "attack_bonus": 3, "damage": "1d6+1"}, {"name": "Claws", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 6 (2d4 + 1) slashing damage.", "attack_bonus": 3, "damage": "2d4+1"}]',
+        '[]', 0, '[]'),
+    ('giant-bat', 'Giant Bat', 'Large', 'Beast', '', 'unaligned',
+        13, '', 22, '4d10', '{"walk": 10, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        15, 16, 11, 2, 12, 6, '[]', '[]',
+        '', '', '', '',
+        'blindsight 60 ft., passive Perception 11', '', 0.25, '["Forest", "Underdark"]',
+        '[{"name": "Echolocation", "description": "The bat can''t use its blindsight while deafened.", "attack_bonus": null, "damage": null}, {"name": "Keen Hearing", "description": "The bat has advantage on Wisdom (Perception) checks that rely on hearing.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one creature. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d6+2"}]',
+        '[]', 0, '[]'),
+    ('giant-centipede', 'Giant Centipede', 'Small', 'Beast', '', 'unaligned',
+        13, 'natural armor', 4, '1d6+1', '{"walk": 30, "fly": 0, "swim": 0, "climb": 30, "burrow": 0, "hover": false}',
+        5, 14, 12, 1, 7, 3, '[]', '[]',
+        '', '', '', '',
+        'blindsight 30 ft., passive Perception 8', '', 0.25, '["Forest", "Underdark"]',
+        '[]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one creature. Hit: 4 (1d4 + 2) piercing damage. The target must succeed on a DC 11 Constitution saving throw or take 10 (3d6) poison damage. If the poison damage reduces the target to 0 hit points, the target is stable but poisoned for 1 hour, even after regaining hit points, and is paralyzed while poisoned in this way.", "attack_bonus": 4, "damage": "1d4+2"}]',
+        '[]', 0, '[]'),
+    ('giant-frog', 'Giant Frog', 'Medium', 'Beast', '', 'unaligned',
+        11, '', 18, '4d8', '{"walk": 30, "fly": 0, "swim": 30, "climb": 0, "burrow": 0, "hover": false}',
+        12, 13, 11, 2, 10, 3, '[]', '[{"name": "Perception", "bonus": 2}, {"name": "Stealth", "bonus": 3}]',
+        '', '', '', '',
+        'darkvision 30 ft., passive Perception 12', '', 0.25, '["Forest", "Swamp"]',
+        '[{"name": "Amphibious", "description": "The frog can breathe air and water.", "attack_bonus": null, "damage": null}, {"name": "Standing Leap", "description": "The frog''s long jump is up to 20 feet and its high jump is up to 10 feet, with or without a running start.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 4 (1d6 + 1) piercing damage. The target is grappled (escape DC 11). Until this grapple ends, the target is restrained, and the frog can''t bite another target.", "attack_bonus": 3, "damage": "1d6+1"}, {"name": "Swallow", "description": "The frog makes one bite attack against a Small or smaller target it is grappling. If the attack hits, the target is swallowed, and the grapple ends. The swallowed target is blinded and restrained, it has total cover against attacks and other effects outside the frog, and it takes 5 (2d4) acid damage at the start of each of the frog''s turns. The frog can have only one target swallowed at a time. If the frog dies, a swallowed creature is no longer restrained by it and can escape from the corpse using 5 feet of movement, exiting prone.", "attack_bonus": null, "damage": "2d4"}]',
+        '[]', 0, '[]'),
+    ('giant-lizard', 'Giant Lizard', 'Large', 'Beast', '', 'unaligned',
+        12, 'natural armor', 19, '3d10+3', '{"walk": 30, "fly": 0, "swim": 0, "climb": 30, "burrow": 0, "hover": false}',
+        15, 12, 13, 2, 10, 5, '[]', '[]',
+        '', '', '', '',
+        'darkvision 30 ft., passive Perception 10', '', 0.25, '["Desert", "Swamp", "Underdark"]',
+        '[]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 6 (1d8 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d8+2"}]',
+        '[]', 0, '[]'),
+    ('giant-owl', 'Giant Owl', 'Large', 'Beast', '', 'neutral',
+        12, '', 19, '3d10+3', '{"walk": 5, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        13, 15, 12, 8, 13, 10, '[]', '[{"name": "Perception", "bonus": 5}, {"name": "Stealth", "bonus": 4}]',
+        '', '', '', '',
+        'darkvision 120 ft., passive Perception 15', 'Giant Owl, understands Common, Elvish, and Sylvan but can''t speak them', 0.25, '["Forest"]',
+        '[{"name": "Flyby", "description": "The owl doesn''t provoke opportunity attacks when it flies out of an enemy''s reach.", "attack_bonus": null, "damage": null}, {"name": "Keen Hearing and Sight", "description": "The owl has advantage on Wisdom (Perception) checks that rely on hearing or sight.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Talons", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 8 (2d6 + 1) slashing damage.", "attack_bonus": 3, "damage": "2d6+1"}]',
+        '[]', 0, '[]'),
+    ('giant-poisonous-snake', 'Giant Poisonous Snake', 'Medium', 'Beast', '', 'unaligned',
+        14, '', 11, '2d8+2', '{"walk": 30, "fly": 0, "swim": 30, "climb": 0, "burrow": 0, "hover": false}',
+        10, 18, 13, 2, 10, 3, '[]', '[{"name": "Perception", "bonus": 2}]',
+        '', '', '', '',
+        'blindsight 10 ft., passive Perception 12', '', 0.25, '["Desert", "Forest", "Swamp", "Underdark"]',
+        '[]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +6 to hit, reach 10 ft., one target. Hit: 6 (1d4 + 4) piercing damage. The target must make a DC 11 Constitution saving throw, taking 10 (3d6) poison damage on a failed save, or half as much damage on a successful one.", "attack_bonus": 6, "damage": "1d4+4"}]',
+        '[]', 0, '[]'),
+    ('giant-wolf-spider', 'Giant Wolf Spider', 'Medium', 'Beast', '', 'unaligned',
+        13, '', 11, '2d8+2', '{"walk": 40, "fly": 0, "swim": 0, "climb": 40, "burrow": 0, "hover": false}',
+        12, 16, 13, 3, 12, 4, '[]', '[{"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 7}]',
+        '', '', '', '',
+        'blindsight 10 ft., darkvision 60 ft., passive Perception 13', '', 0.25, '["Desert", "Forest", "Grassland"]',
+        '[{"name": "Spider Climb", "description": "The spider can climb difficult surfaces, including upside down on ceilings, without needing to make an ability check.", "attack_bonus": null, "damage": null}, {"name": "Web Sense", "description": "While in contact with a web, the spider knows the exact location of any other creature in contact with the same web.", "attack_bonus": null, "damage": null}, {"name": "Web Walker", "description": "The spider ignores movement restrictions caused by webbing.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one creature. Hit: 4 (1d6 + 1) piercing damage. The target must make a DC 11 Constitution saving throw, taking 7 (2d6) poison damage on a failed save, or half as much damage on a successful one. If the poison damage reduces the target to 0 hit points, the target is stable but poisoned for 1 hour, even after regaining hit points, and is paralyzed while poisoned in this way.", "attack_bonus": 3, "damage": "1d6+1"}]',
+        '[]', 0, '[]'),
+    ('grimlock', 'Grimlock', 'Medium', 'Humanoid', 'grimlock', 'neutral evil',
+        11, '', 11, '2d8+2', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 12, 12, 9, 8, 6, '[]', '[{"name": "Athletics", "bonus": 5}, {"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 3}]',
+        '', '', '', 'blinded',
+        'blindsight 30 ft. or 10 ft. while deafened (blind beyond this radius), passive Perception 13', 'Undercommon', 0.25, '["Underdark"]',
+        '[{"name": "Blind Senses", "description": "The grimlock can''t use its blindsight while deafened and unable to smell.", "attack_bonus": null, "damage": null}, {"name": "Keen Hearing and Smell", "description": "The grimlock has advantage on Wisdom (Perception) checks that rely on hearing or smell.", "attack_bonus": null, "damage": null}, {"name": "Stone Camouflage", "description": "The grimlock has advantage on Dexterity (Stealth) checks made to hide in rocky terrain.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Spiked Bone Club", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 5 (1d4 + 3) bludgeoning damage plus 2 (1d4) piercing damage.", "attack_bonus": 5, "damage": "1d4+3"}]',
+        '[]', 0, '[]'),
+    ('panther', 'Panther', 'Medium', 'Beast', '', 'unaligned',
+        12, '', 13, '3d8', '{"walk": 50, "fly": 0, "swim": 0, "climb": 40, "burrow": 0, "hover": false}',
+        14, 15, 10, 3, 14, 7, '[]', '[{"name": "Perception", "bonus": 4}, {"name": "Stealth", "bonus": 6}]',
+        '', '', '', '',
+        'passive Perception 14', '', 0.25, '["Forest", "Grassland"]',
+        '[{"name": "Keen Smell", "description": "The panther has advantage on Wisdom (Perception) checks that rely on smell.", "attack_bonus": null, "damage": null}, {"name": "Pounce", "description": "If the panther moves at least 20 feet straight toward a creature and then hits it with a claw attack on the same turn, that target must succeed on a DC 12 Strength saving throw or be knocked prone. If the target is prone, the panther can make one bite attack against it as a bonus action.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d6+2"}, {"name": "Claw", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 4 (1d4 + 2) slashing damage.", "attack_bonus": 4, "damage": "1d4+2"}]',
+        '[]', 0, '[]'),
+    ('pseudodragon', 'Pseudodragon', 'Tiny', 'Dragon', '', 'neutral good',
+        13, 'natural armor', 7, '2d4+2', '{"walk": 15, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        6, 15, 13, 10, 12, 10, '[]', '[{"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 4}]',
+        '', '', '', '',
+        'blindsight 10 ft., darkvision 60 ft., passive Perception 13', 'understands Common and Draconic but can''t speak', 0.25, '["Forest", "Hill", "Mountain"]',
+        '[{"name": "Keen Senses", "description": "The pseudodragon has advantage on Wisdom (Perception) checks that rely on sight, hearing, or smell.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The pseudodragon has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}, {"name": "Limited Telepathy", "description": "The pseudodragon can magically communicate simple ideas, emotions, and images telepathically with any creature that knows a language.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 4 (1d4 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d4+2"}, {"name": "Sting", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one creature. Hit: 4 (1d4 + 2) piercing damage. The target must succeed on a DC 11 Constitution saving throw or become poisoned for 1 hour. If the saving throw fails by 5 or more, the target falls unconscious for the same duration, or until it takes damage or another creature uses an action to shake it awake.", "attack_bonus": 4, "damage": "1d4+2"}]',
+        '[]', 0, '[]'),
+    ('riding-horse', 'Riding Horse', 'Large', 'Beast', '', 'unaligned',
+        10, '', 13, '2d10+2', '{"walk": 60, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 10, 12, 2, 11, 7, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 10', '', 0.25, '["Grassland", "Urban"]',
+        '[]',
+        '[{"name": "Hooves", "description": "Melee Weapon Attack: +2 to hit, reach 5 ft., one target. Hit: 8 (2d4 + 3) bludgeoning damage.", "attack_bonus": 2, "damage": "2d4+3"}]',
+        '[]', 0, '[]'),
+    ('sprite', 'Sprite', 'Tiny', 'Fey', '', 'neutral good',
+        15, 'leather armor', 2, '1d4', '{"walk": 10, "fly": 40, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        3, 18, 10, 14, 13, 11, '[]', '[{"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 8}]',
+        '', '', '', '',
+        'passive Perception 13', 'Common, Elvish, Sylvan', 0.25, '["Forest"]',
+        '[]',
+        '[{"name": "Longsword", "description": "Melee Weapon Attack: +2 to hit, reach 5 ft., one target. Hit: 1 slashing damage.", "attack_bonus": 2, "damage": "1"}, {"name": "Shortbow", "description": "Ranged Weapon Attack: +6 to hit, range 40/160 ft., one target. Hit: 1 piercing damage. The target must succeed on a DC 10 Constitution saving throw or become poisoned for 1 minute. If its saving throw result is 5 or lower, the poisoned target falls unconscious for the same duration, or until it takes damage or another creature takes an action to shake it awake.", "attack_bonus": 6, "damage": "1"}, {"name": "Heart Sight", "description": "The sprite touches a creature and magically knows the creature''s current emotional state. If the target fails a DC 10 Charisma saving throw, the sprite also knows the creature''s alignment. Celestials, fiends, and undead automatically fail the saving throw.", "attack_bonus": null, "damage": null}, {"name": "Invisibility", "description": "The sprite magically turns invisible until it attacks or casts a spell, or until its concentration ends (as if concentrating on a spell). Any equipment the sprite wears or carries is invisible with it.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('steam-mephit', 'Steam Mephit', 'Small', 'Elemental', '', 'neutral evil',
+        10, '', 21, '6d6', '{"walk": 30, "fly": 30, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        5, 11, 10, 11, 10, 12, '[]', '[]',
+        '', '', 'fire, poison', 'poisoned',
+        'darkvision 60 ft., passive Perception 10', 'Aquan, Ignan', 0.25, '["Swamp"]',
+        '[{"name": "Death Burst", "description": "When the mephit dies, it explodes in a cloud of steam. Each creature within 5 feet of the mephit must succeed on a DC 10 Dexterity saving throw or take 4 (1d8) fire damage.", "attack_bonus": null, "damage": null}, {"name": "Innate Spellcasting (1/Day)", "description": "The mephit can innately cast blur, requiring no material components. Its innate spellcasting ability is Charisma.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Claws", "description": "Melee Weapon Attack: +2 to hit, reach 5 ft., one creature. Hit: 2 (1d4) slashing damage plus 2 (1d4) fire damage.", "attack_bonus": 2, "damage": "1d4"}, {"name": "Steam Breath (Recharge 6)", "description": "The mephit exhales a 15-foot cone of scalding steam. Each creature in that area must succeed on a DC 10 Dexterity saving throw, taking 4 (1d8) fire damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "1d8"}]',
+        '[]', 0, '[]'),
+    ('swarm-of-bats', 'Swarm of Bats', 'Medium', 'Beast', 'swarm of Tiny beasts', 'unaligned',
+        12, '', 22, '5d8', '{"walk": 0, "fly": 30, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        5, 15, 10, 2, 12, 4, '[]', '[]',
+        '', 'bludgeoning, piercing, slashing', '', 'charmed, frightened, grappled, paralyzed, petrified, prone, restrained, stunned',
+        'blindsight 60 ft., passive Perception 11', '', 0.25, '["Forest", "Underdark"]',
+        '[{"name": "Echolocation", "description": "The swarm can''t use its blindsight while deafened.", "attack_bonus": null, "damage": null}, {"name": "Keen Hearing", "description": "The swarm has advantage on Wisdom (Perception) checks that rely on hearing.", "attack_bonus": null, "damage": null}, {"name": "Swarm", "description": "The swarm can occupy another creature''s space and vice versa, and the swarm can move through any opening large enough for a Tiny bat. The swarm can''t regain hit points or gain temporary hit points.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bites", "description": "Melee Weapon Attack: +4 to hit, reach 0 ft., one creature in the swarm''s space. Hit: 5 (2d4) piercing damage, or 2 (1d4) piercing damage if the swarm has half of its hit points or fewer.", "attack_bonus": 4, "damage": "2d4"}]',
+        '[]', 0, '[]'),
+    ('swarm-of-rats', 'Swarm of Rats', 'Medium', 'Beast', 'swarm of Tiny beasts', 'unaligned',
+        10, '', 24, '7d8-7', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        9, 11, 9, 2, 10, 3, '[]', '[]',
+        '', 'bludgeoning, piercing, slashing', '', 'charmed, frightened, grappled, paralyzed, petrified, prone, restrained, stunned',
+        'darkvision 30 ft., passive Perception 10', '', 0.25, '["Underdark", "Urban"]',
+        '[{"name": "Keen Smell", "description": "The swarm has advantage on Wisdom (Perception) checks that rely on smell.", "attack_bonus": null, "damage": null}, {"name": "Swarm", "description": "The swarm can occupy another creature''s space and vice versa, and the swarm can move through any opening large enough for a Tiny rat. The swarm can''t regain hit points or gain temporary hit points.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bites", "description": "Melee Weapon Attack: +2 to hit, reach 0 ft., one creature in the swarm''s space. Hit: 7 (2d6) piercing damage, or 3 (1d6) piercing damage if the swarm has half of its hit points or fewer.", "attack_bonus": 2, "damage": "2d6"}]',
+        '[]', 0, '[]'),
+    ('swarm-of-ravens', 'Swarm of Ravens', 'Medium', 'Beast', 'swarm of Tiny beasts', 'unaligned',
+        12, '', 24, '7d8-7', '{"walk": 10, "fly": 50, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        6, 14, 8, 3, 12, 6, '[]', '[{"name": "Perception", "bonus": 5}]',
+        '', 'bludgeoning, piercing, slashing', '', 'charmed, frightened, grappled, paralyzed, petrified, prone, restrained, stunned',
+        'passive Perception 15', '', 0.25, '["Forest", "Hill", "Urban"]',
+        '[{"name": "Swarm", "description": "The swarm can occupy another creature''s space and vice versa, and the swarm can move through any opening large enough for a Tiny raven. The swarm can''t regain hit points or gain temporary hit points.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Beaks", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one creature in the swarm''s space. Hit: 7 (2d6) piercing damage, or 3 (1d6) piercing damage if the swarm has half of its hit points or fewer.", "attack_bonus": 4, "damage": "2d6"}]',
+        '[]', 0, '[]'),
+    ('violet-fungus', 'Violet Fungus', 'Medium', 'Plant', '', 'unaligned',
+        5, '', 18, '4d8', '{"walk": 5, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        3, 1, 10, 1, 3, 1, '[]', '[]',
+        '', '', '', 'blinded, deafened, frightened',
+        'blindsight 30 ft. (blind beyond this radius), passive Perception 6', '', 0.25, '["Underdark"]',
+        '[{"name": "False Appearance", "description": "While the violet fungus remains motionless, it is indistinguishable from an ordinary fungus.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The fungus makes 1d4 Rotting Touch attacks.", "attack_bonus": null, "damage": null}, {"name": "Rotting Touch", "description": "Melee Weapon Attack: +2 to hit, reach 10 ft., one creature. Hit: 4 (1d8) necrotic damage.", "attack_bonus": 2, "damage": "1d8"}]',
+        '[]', 0, '[]'),
+    ('ape', 'Ape', 'Medium', 'Beast', '', 'unaligned',
+        12, '', 19, '3d8+6', '{"walk": 30, "fly": 0, "swim": 0, "climb": 30, "burrow": 0, "hover": false}',
+        16, 14, 14, 6, 12, 7, '[]', '[{"name": "Athletics", "bonus": 5}, {"name": "Perception", "bonus": 3}]',
+        '', '', '', '',
+        'passive Perception 13', '', 0.5, '["Forest"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The ape makes two fist attacks.", "attack_bonus": null, "damage": null}, {"name": "Fist", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 6 (1d6 + 3) bludgeoning damage.", "attack_bonus": 5, "damage": "1d6+3"}, {"name": "Rock", "description": "Ranged Weapon Attack: +5 to hit, range 25/50 ft., one target. Hit: 6 (1d6 + 3) bludgeoning damage.", "attack_bonus": 5, "damage": "1d6+3"}]',
+        '[]', 0, '[]'),
+    ('cockatrice', 'Cockatrice', 'Small', 'Monstrosity', '', 'unaligned',
+        11, '', 27, '6d6+6', '{"walk": 20, "fly": 40, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        6, 12, 12, 2, 13, 5, '[]', '[]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 11', '', 0.5, '["Grassland"]',
+        '[]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one creature. Hit: 3 (1d4 + 1) piercing damage. The target must succeed on a DC 11 Constitution saving throw against being magically petrified. On a failed save, the creature begins to turn to stone and is restrained. It must repeat the saving throw at the end of its next turn. On a success, the effect ends. On a failure, the creature is petrified for 24 hours.", "attack_bonus": 3, "damage": "1d4+1"}]',
+        '[]', 0, '[]'),
+    ('crocodile', 'Crocodile', 'Large', 'Beast', '', 'unaligned',
+        12, 'natural armor', 19, '3d10+3', '{"walk": 20, "fly": 0, "swim": 30, "climb": 0, "burrow": 0, "hover": false}',
+        15, 10, 13, 2, 10, 5, '[]', '[{"name": "Stealth", "bonus": 2}]',
+        '', '', '', '',
+        'passive Perception 10', '', 0.5, '["Swamp"]',
+        '[{"name": "Hold Breath", "description": "The crocodile can hold its breath for 15 minutes.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one creature. Hit: 7 (1d10 + 2) piercing damage. The target is grappled (escape DC 12). Until this grapple ends, the target is restrained, and the crocodile can''t bite another target.", "attack_bonus": 4, "damage": "1d10+2"}]',
+        '[]', 0, '[]'),
+    ('darkmantle', 'Darkmantle', 'Small', 'Monstrosity', '', 'unaligned',
+        11, '', 22, '5d6+5', '{"walk": 10, "fly": 30, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 12, 13, 2, 10, 5, '[]', '[{"name": "Stealth", "bonus": 3}]',
+        '', '', '', '',
+        'blindsight 60 ft., passive Perception 10', '', 0.5, '["Underdark"]',
+        '[{"name": "Echolocation", "description": "The darkmantle can''t use its blindsight while deafened.", "attack_bonus": null, "damage": null}, {"name": "False Appearance", "description": "While the darkmantle remains motionless, it is indistinguishable from a cave formation such as a stalactite or stalagmite.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Crush", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one creature. Hit: 6 (1d6 + 3) bludgeoning damage. The darkmantle attaches to the target. If the target is Medium or smaller and the darkmantle has advantage on the attack roll, it attaches by engulfing the target''s head, and the target is also blinded and unable to breathe while the darkmantle is attached in this way. While attached to the target, the darkmantle can attack no other creature except the target but has advantage on its attack rolls. The darkmantle''s speed also becomes 0, it can''t benefit from any bonus to its speed, and it moves with the target. A creature can detach the darkmantle by making a successful DC 13 Strength check as an action. On its turn, the darkmantle can detach itself from the target by using 5 feet of movement.", "attack_bonus": 5, "damage": "1d6+3"}, {"name": "Darkness Aura (1/Day)", "description": "A 15-foot radius of magical darkness extends out from the darkmantle, moves with it, and spreads around corners. The darkness lasts as long as the darkmantle maintains concentration, up to 10 minutes (as if concentrating on a spell). Darkvision can''t penetrate this darkness, and no natural light can illuminate it. If any of the darkness overlaps with an area of light created by a spell of 2nd level or lower, the spell creating the light is dispelled.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('deep-gnome', 'Deep Gnome (Svirfneblin)', 'Small', 'Humanoid', 'gnome', 'neutral good',
+        15, 'chain shirt', 16, '3d6+6', '{"walk": 20, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        15, 14, 14, 12, 10, 9, '[]', '[{"name": "Investigation", "bonus": 3}, {"name": "Perception", "bonus": 2}, {"name": "Stealth", "bonus": 4}]',
+        '', '', '', '',
+        'darkvision 120 ft., passive Perception 12', 'Gnomish, Terran, Undercommon', 0.5, '["Underdark"]',
+        '[{"name": "Stone Camouflage", "description": "The gnome has advantage on Dexterity (Stealth) checks made to hide in rocky terrain.", "attack_bonus": null, "damage": null}, {"name": "Gnome Cunning", "description": "The gnome has advantage on Intelligence, Wisdom, and Charisma saving throws against magic.", "attack_bonus": null, "damage": null}, {"name": "Innate Spellcasting", "description": "The gnome''s innate spellcasting ability is Intelligence (spell save DC 11). It can innately cast the following spells, requiring no material components. At will: nondetection (self only). 1/day each: blindness/deafness, blur, disguise self.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "War Pick", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 6 (1d8 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d8+2"}, {"name": "Poisoned Dart", "description": "Ranged Weapon Attack: +4 to hit, range 30/120 ft., one creature. Hit: 4 (1d4 + 2) piercing damage. The target must succeed on a DC 12 Constitution saving throw or be poisoned for 1 minute. The target can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success.", "attack_bonus": 4, "damage": "1d4+2"}]',
+        '[]', 0, '[]'),
+    ('dust-mephit', 'Dust Mephit', 'Small', 'Elemental', '', 'neutral evil',
+        12, '', 17, '5d6', '{"walk": 30, "fly": 30, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        5, 14, 10, 9, 11, 10, '[]', '[{"name": "Perception", "bonus": 2}, {"name": "Stealth", "bonus": 4}]',
+        'fire', '', 'poison', 'poisoned',
+        'darkvision 60 ft., passive Perception 12', 'Auran, Terran', 0.5, '["Desert"]',
+        '[{"name": "Death Burst", "description": "When the mephit dies, it explodes in a burst of dust. Each creature within 5 feet of it must then succeed on a DC 10 Constitution saving throw or be blinded for 1 minute. A blinded creature can repeat the saving throw on each of its turns, ending the effect on itself on a success.", "attack_bonus": null, "damage": null}, {"name": "Innate Spellcasting (1/Day)", "description": "The mephit can innately cast sleep, requiring no material components. Its innate spellcasting ability is Charisma.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Claws", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 4 (1d4 + 2) slashing damage.", "attack_bonus": 4, "damage": "1d4+2"}, {"name": "Blinding Breath (Recharge 6)", "description": "The mephit exhales a 15-foot cone of blinding dust. Each creature in that area must succeed on a DC 10 Dexterity saving throw or be blinded for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('giant-goat', 'Giant Goat', 'Large', 'Beast', '', 'unaligned',
+        11, 'natural armor', 19, '3d10+3', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        17, 11, 12, 3, 12, 6, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 11', '', 0.5, '["Hill", "Mountain"]',
+        '[{"name": "Charge", "description": "If the goat moves at least 20 feet straight toward a target and then hits it with a ram attack on the same turn, the target takes an extra 5 (2d4) bludgeoning damage. If the target is a creature, it must succeed on a DC 13 Strength saving throw or be knocked prone.", "attack_bonus": null, "damage": "2d4"}, {"name": "Sure-Footed", "description": "The goat has advantage on Strength and Dexterity saving throws made against effects that would knock it prone.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Ram", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 8 (2d4 + 3) bludgeoning damage.", "attack_bonus": 5, "damage": "2d4+3"}]',
+        '[]', 0, '[]'),
+    ('giant-sea-horse', 'Giant Sea Horse', 'Large', 'Beast', '', 'unaligned',
+        13, 'natural armor', 16, '3d10', '{"walk": 0, "fly": 0, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        12, 15, 11, 2, 12, 5, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 11', '', 0.5, '["Underwater"]',
+        '[{"name": "Charge", "description": "If the sea horse moves at least 20 feet straight toward a target and then hits it with a ram attack on the same turn, the target takes an extra 7 (2d6) bludgeoning damage. If the target is a creature, it must succeed on a DC 11 Strength saving throw or be knocked prone.", "attack_bonus": null, "damage": "2d6"}, {"name": "Water Breathing", "description": "The sea horse can breathe only underwater.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Ram", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 4 (1d6 + 1) bludgeoning damage.", "attack_bonus": 3, "damage": "1d6+1"}]',
+        '[]', 0, '[]'),
+    ('giant-wasp', 'Giant Wasp', 'Medium', 'Beast', '', 'unaligned',
+        12, '', 13, '3d8', '{"walk": 10, "fly": 50, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        10, 14, 10, 1, 10, 3, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 10', '', 0.5, '["Forest", "Grassland"]',
+        '[]',
+        '[{"name": "Sting", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one creature. Hit: 5 (1d6 + 2) piercing damage. The target must make a DC 11 Constitution saving throw, taking 10 (3d6) poison damage on a failed save, or half as much damage on a successful one. If the poison damage reduces the target to 0 hit points, the target is stable but poisoned for 1 hour, even after regaining hit points, and is paralyzed while poisoned in this way.", "attack_bonus": 4, "damage": "1d6+2"}]',
+        '[]', 0, '[]'),
+    ('gray-ooze', 'Gray Ooze', 'Medium', 'Ooze', '', 'unaligned',
+        8, '', 22, '3d8+9', '{"walk": 10, "fly": 0, "swim": 0, "climb": 10, "burrow": 0, "hover": false}',
+        12, 6, 16, 1, 6, 2, '[]', '[{"name": "Stealth", "bonus": 2}]',
+        '', 'acid, cold, fire', '', 'blinded, charmed, deafened, exhaustion, frightened, prone',
+        'blindsight 60 ft. (blind beyond this radius), passive Perception 8', '', 0.5, '["Underdark", "Swamp"]',
+        '[{"name": "Amorphous", "description": "The ooze can move through a space as narrow as 1 inch wide without squeezing.", "attack_bonus": null, "damage": null}, {"name": "Corrode Metal", "description": "Any nonmagical weapon made of metal that hits the ooze corrodes. After dealing damage, the weapon takes a permanent and cumulative -1 penalty to damage rolls. If its penalty drops to -5, the weapon is destroyed. Nonmagical ammunition made of metal that hits the ooze is destroyed after dealing damage. The ooze can eat through 2-inch-thick, nonmagical metal in 1 round.", "attack_bonus": null, "damage": null}, {"name": "False Appearance", "description": "While the ooze remains motionless, it is indistinguishable from an oily pool or wet rock.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Pseudopod", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 4 (1d6 + 1) bludgeoning damage plus 7 (2d6) acid damage. If the target is wearing nonmagical metal armor, its armor is partly corroded and takes a permanent and cumulative -1 penalty to the AC it offers. The armor is destroyed if the penalty reduces its AC to 10.", "attack_bonus": 3, "damage": "1d6+1"}]',
+        '[]', 0, '[]'),
+    ('ice-mephit', 'Ice Mephit', 'Small', 'Elemental', '', 'neutral evil',
+        11, '', 21, '6d6', '{"walk": 30, "fly": 30, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        7, 13, 10, 9, 11, 12, '[]', '[{"name": "Perception", "bonus": 2}, {"name": "Stealth", "bonus": 3}]',
+        'bludgeoning, fire', '', 'cold, poison', 'poisoned',
+        'darkvision 60 ft., passive Perception 12', 'Aquan, Auran', 0.5, '["Arctic"]',
+        '[{"name": "Death Burst", "description": "When the mephit dies, it explodes in a burst of jagged ice. Each creature within 5 feet of it must make a DC 10 Dexterity saving throw, taking 4 (1d8) slashing damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": null}, {"name": "False Appearance", "description": "While the mephit remains motionless, it is indistinguishable from an ordinary shard of ice.", "attack_bonus": null, "damage": null}, {"name": "Innate Spellcasting (1/Day)", "description": "The mephit can innately cast fog cloud, requiring no material components. Its innate spellcasting ability is Charisma.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Claws", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one creature. Hit: 3 (1d4 + 1) slashing damage plus 2 (1d4) cold damage.", "attack_bonus": 3, "damage": "1d4+1"}, {"name": "Frost Breath (Recharge 6)", "description": "The mephit exhales a 15-foot cone of cold air. Each creature in that area must succeed on a DC 10 Dexterity saving throw, taking 5 (2d4) cold damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "2d4"}]',
+        '[]', 0, '[]'),
+    ('lizardfolk', 'Lizardfolk', 'Medium', 'Humanoid', 'lizardfolk', 'neutral',
+        15, 'natural armor, shield', 22, '4d8+4', '{"walk": 30, "fly": 0, "swim": 30, "climb": 0, "burrow": 0, "hover": false}',
+        15, 10, 13, 7, 12, 7, '[]', '[{"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 4}, {"name": "Survival", "bonus": 5}]',
+        '', '', '', '',
+        'passive Perception 13', 'Draconic', 0.5, '["Swamp"]',
+        '[{"name": "Hold Breath", "description": "The lizardfolk can hold its breath for 15 minutes.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The lizardfolk makes two melee attacks, each one with a different weapon.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d6+2"}, {"name": "Heavy Club", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 5 (1d6 + 2) bludgeoning damage.", "attack_bonus": 4, "damage": "1d6+2"}, {"name": "Javelin", "description": "Melee or Ranged Weapon Attack: +4 to hit, reach 5 ft. or range 30/120 ft., one target. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d6+2"}, {"name": "Spiked Shield", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d6+2"}]',
+        '[]', 0, '[]'),
+    ('magma-mephit', 'Magma Mephit', 'Small', 'Elemental', '', 'neutral evil',
+        11, '', 22, '5d6+5', '{"walk": 30, "fly": 30, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        8, 12, 12, 7, 10, 10, '[]', '[{"name": "Stealth", "bonus": 3}]',
+        'cold', '', 'fire, poison', 'poisoned',
+        'darkvision 60 ft., passive Perception 10', 'Ignan, Terran', 0.5, '["Mountain"]',
+        '[{"name": "Death Burst", "description": "When the mephit dies, it explodes in a burst of lava. Each creature within 5 feet of it must make a DC 11 Dexterity saving throw, taking 7 (2d6) fire damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": null}, {"name": "False Appearance", "description": "While the mephit remains motionless, it is indistinguishable from an ordinary mound of magma.", "attack_bonus": null, "damage": null}, {"name": "Innate Spellcasting (1/Day)", "description": "The mephit can innately cast heat metal (spell save DC 10), requiring no material components. Its innate spellcasting ability is Charisma.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Claws", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one creature. Hit: 3 (1d4 + 1) slashing damage plus 2 (1d4) fire damage.", "attack_bonus": 3, "damage": "1d4+1"}, {"name": "Fire Breath (Recharge 6)", "description": "The mephit exhales a 15-foot cone of fire. Each creature in that area must make a DC 11 Dexterity saving throw, taking 7 (2d6) fire damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "2d6"}]',
+        '[]', 0, '[]'),
+    ('magmin', 'Magmin', 'Small', 'Elemental', '', 'chaotic neutral',
+        14, 'natural armor', 9, '2d6+2', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        7, 15, 12, 8, 11, 10, '[]', '[]',
+        '', 'bludgeoning, piercing, and slashing from nonmagical attacks', 'fire', '',
+        'darkvision 60 ft., passive Perception 10', 'Ignan', 0.5, '["Mountain"]',
+        '[{"name": "Death Burst", "description": "When the magmin dies, it explodes in a burst of fire and magma. Each creature within 10 feet of it must make a DC 11 Dexterity saving throw, taking 7 (2d6) fire damage on a failed save, or half as much damage on a successful one. Flammable objects that aren''t being worn or carried in that area are ignited.", "attack_bonus": null, "damage": "2d6"}, {"name": "Ignited Illumination", "description": "As a bonus action, the magmin can set itself ablaze or extinguish its flames. While ablaze, the magmin sheds bright light in a 10-foot radius and dim light for an additional 10 feet.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Touch", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 7 (2d6) fire damage. If the target is a creature or a flammable object, it ignites. Until a creature takes an action to douse the fire, the target takes 3 (1d6) fire damage at the end of each of its turns.", "attack_bonus": 4, "damage": "2d6"}]',
+        '[]', 0, '[]'),
+    ('reef-shark', 'Reef Shark', 'Medium', 'Beast', '', 'unaligned',
+        12, 'natural armor', 22, '4d8+4', '{"walk": 0, "fly": 0, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        14, 13, 13, 1, 10, 4, '[]', '[{"name": "Perception", "bonus": 2}]',
+        '', '', '', '',
+        'blindsight 30 ft., passive Perception 12', '', 0.5, '["Coastal", "Underwater"]',
+        '[{"name": "Pack Tactics", "description": "The shark has advantage on an attack roll against a creature if at least one of the shark''s allies is within 5 feet of the creature and the ally isn''t incapacitated.", "attack_bonus": null, "damage": null}, {"name": "Water Breathing", "description": "The shark can breathe only underwater.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 6 (1d8 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d8+2"}]',
+        '[]', 0, '[]'),
+    ('rust-monster', 'Rust Monster', 'Medium', 'Monstrosity', '', 'unaligned',
+        14, 'natural armor', 27, '5d8+5', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        13, 12, 13, 2, 13, 6, '[]', '[]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 11', '', 0.5, '["Underdark"]',
+        '[{"name": "Iron Scent", "description": "The rust monster can pinpoint, by scent, the location of ferrous metal within 30 feet of it.", "attack_bonus": null, "damage": null}, {"name": "Rust Metal", "description": "Any nonmagical weapon made of metal that hits the rust monster corrodes. After dealing damage, the weapon takes a permanent and cumulative -1 penalty to damage rolls. If its penalty drops to -5, the weapon is destroyed. Nonmagical ammunition made of metal that hits the rust monster is destroyed after dealing damage.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 5 (1d8 + 1) piercing damage.", "attack_bonus": 3, "damage": "1d8+1"}, {"name": "Antennae", "description": "The rust monster corrodes a nonmagical ferrous metal object it can see within 5 feet of it. If the object isn''t being worn or carried, the touch destroys a 1-foot cube of it. If the object is being worn or carried by a creature, the creature can make a DC 11 Dexterity saving throw to avoid the rust monster''s touch. If the object touched is either metal armor or a metal shield being worn or carried, it takes a permanent and cumulative -1 penalty to the AC it offers. Armor reduced to an AC of 10 or a shield that drops to a +0 bonus is destroyed. If the object touched is a held metal weapon, it rusts as described in the Rust Metal trait.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('sahuagin', 'Sahuagin', 'Medium', 'Humanoid', 'sahuagin', 'lawful evil',
+        12, 'natural armor', 22, '4d8+4', '{"walk": 30, "fly": 0, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        13, 11, 12, 12, 13, 9, '[]', '[{"name": "Perception", "bonus": 5}]',
+        '', '', '', '',
+        'darkvision 120 ft., passive Perception 15', 'Sahuagin', 0.5, '["Coastal", "Underwater"]',
+        '[{"name": "Blood Frenzy", "description": "The sahuagin has advantage on melee attack rolls against any creature that doesn''t have all its hit points.", "attack_bonus": null, "damage": null}, {"name": "Limited Amphibiousness", "description": "The sahuagin can breathe air and water, but it needs to be submerged at least once every 4 hours to avoid suffocating.", "attack_bonus": null, "damage": null}, {"name": "Shark Telepathy", "description": "The sahuagin can magically command any shark within 120 feet of it, using a limited telepathy.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The sahuagin makes two melee attacks: one with its bite and one with its claws or spear.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 3 (1d4 + 1) piercing damage.", "attack_bonus": 3, "damage": "1d4+1"}, {"name": "Claws", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 3 (1d4 + 1) slashing damage.", "attack_bonus": 3, "damage": "1d4+1"}, {"name": "Spear", "description": "Melee or Ranged Weapon Attack: +3 to hit, reach 5 ft. or range 20/60 ft., one target. Hit: 4 (1d6 + 1) piercing damage, or 5 (1d8 + 1) piercing damage if used with two hands to make a melee attack.", "attack_bonus": 3, "damage": "1d6+1"}]',
+        '[]', 0, '[]'),
+    ('satyr', 'Satyr', 'Medium', 'Fey', '', 'chaotic neutral',
+        14, 'leather armor', 31, '7d8', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        12, 16, 11, 12, 10, 14, '[]', '[{"name": "Perception", "bonus": 2}, {"name": "Performance", "bonus": 6}, {"name": "Stealth", "bonus": 5}]',
+        '', '', '', '',
+        'passive Perception 12', 'Common, Elvish, Sylvan', 0.5, '["Forest"]',
+        '[{"name": "Magic Resistance", "description": "The satyr has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Ram", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 6 (2d4 + 1) bludgeoning damage.", "attack_bonus": 3, "damage": "2d4+1"}, {"name": "Shortsword", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 6 (1d6 + 3) piercing damage.", "attack_bonus": 5, "damage": "1d6+3"}, {"name": "Shortbow", "description": "Ranged Weapon Attack: +5 to hit, range 80/320 ft., one target. Hit: 6 (1d6 + 3) piercing damage.", "attack_bonus": 5, "damage": "1d6+3"}]',
+        '[]', 0, '[]'),
+    ('scout', 'Scout', 'Medium', 'Humanoid', 'any race', 'any alignment',
+        13, 'leather armor', 16, '3d8+3', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        11, 14, 12, 11, 13, 11, '[]', '[{"name": "Nature", "bonus": 4}, {"name": "Perception", "bonus": 5}, {"name": "Stealth", "bonus": 6}, {"name": "Survival", "bonus": 5}]',
+        '', '', '', '',
+        'passive Perception 15', 'any one language (usually Common)', 0.5, '["Forest", "Grassland", "Hill", "Mountain"]',
+        '[{"name": "Keen Hearing and Sight", "description": "The scout has advantage on Wisdom (Perception) checks that rely on hearing or sight.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The scout makes two melee attacks or two ranged attacks.", "attack_bonus": null, "damage": null}, {"name": "Shortsword", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d6+2"}, {"name": "Longbow", "description": "Ranged Weapon Attack: +4 to hit, range 150/600 ft., one target. Hit: 6 (1d8 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d8+2"}]',
+        '[]', 0, '[]'),
+    ('shadow', 'Shadow', 'Medium', 'Undead', '', 'chaotic evil',
+        12, '', 16, '3d8+3', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        6, 14, 13, 6, 10, 8, '[]', '[{"name": "Stealth", "bonus": 4}]',
+        'radiant', 'acid, cold, fire, lightning, thunder; bludgeoning, piercing, and slashing from nonmagical attacks', 'necrotic, poison', 'exhaustion, frightened, grappled, paralyzed, petrified, poisoned, prone, restrained',
+        'darkvision 60 ft., passive Perception 10', '', 0.5, '["Underdark", "Urban"]',
+        '[{"name": "Amorphous", "description": "The shadow can move through a space as narrow as 1 inch wide without squeezing.", "attack_bonus": null, "damage": null}, {"name": "Shadow Stealth", "description": "While in dim light or darkness, the shadow can take the Hide action as a bonus action.", "attack_bonus": null, "damage": null}, {"name": "Sunlight Weakness", "description": "While in sunlight, the shadow has disadvantage on attack rolls, ability checks, and saving throws.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Strength Drain", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one creature. Hit: 9 (2d6 + 2) necrotic damage. The target''s Strength score is reduced by 1d4. The target dies if this reduces its Strength to 0. Otherwise, the reduction lasts until the target finishes a short or long rest. If a non-evil humanoid dies from this attack, a new shadow rises from the corpse 1d4 hours later.", "attack_bonus": 4, "damage": "2d6+2"}]',
+        '[]', 0, '[]'),
+    ('swarm-of-insects', 'Swarm of Insects', 'Medium', 'Beast', 'swarm of Tiny beasts', 'unaligned',
+        12, 'natural armor', 22, '5d8', '{"walk": 20, "fly": 0, "swim": 0, "climb": 20, "burrow": 0, "hover": false}',
+        3, 13, 10, 1, 7, 1, '[]', '[]',
+        '', 'bludgeoning, piercing, slashing', '', 'charmed, frightened, grappled, paralyzed, petrified, prone, restrained, stunned',
+        'blindsight 10 ft., passive Perception 8', '', 0.5, '["Desert", "Forest", "Grassland", "Swamp", "Underdark"]',
+        '[{"name": "Swarm", "description": "The swarm can occupy another creature''s space and vice versa, and the swarm can move through any opening large enough for a Tiny insect. The swarm can''t regain hit points or gain temporary hit points.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bites", "description": "Melee Weapon Attack: +3 to hit, reach 0 ft., one creature in the swarm''s space. Hit: 10 (4d4) piercing damage, or 5 (2d4) piercing damage if the swarm has half of its hit points or fewer.", "attack_bonus": 3, "damage": "4d4"}]',
+        '[]', 0, '[]'),
+    ('thug', 'Thug', 'Medium', 'Humanoid', 'any race', 'any non-good alignment',
+        11, 'leather armor', 32, '5d8+10', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        15, 11, 14, 10, 10, 11, '[]', '[{"name": "Intimidation", "bonus": 2}]',
+        '', '', '', '',
+        'passive Perception 10', 'any one language (usually Common)', 0.5, '["Urban"]',
+        '[{"name": "Pack Tactics", "description": "The thug has advantage on an attack roll against a creature if at least one of the thug''s allies is within 5 feet of the creature and the ally isn''t incapacitated.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The thug makes two melee attacks.", "attack_bonus": null, "damage": null}, {"name": "Mace", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 5 (1d6 + 2) bludgeoning damage.", "attack_bonus": 4, "damage": "1d6+2"}, {"name": "Heavy Crossbow", "description": "Ranged Weapon Attack: +2 to hit, range 100/400 ft., one target. Hit: 5 (1d10) piercing damage.", "attack_bonus": 2, "damage": "1d10"}]',
+        '[]', 0, '[]'),
+    ('warhorse', 'Warhorse', 'Large', 'Beast', '', 'unaligned',
+        11, '', 19, '3d10+3', '{"walk": 60, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 12, 13, 2, 12, 7, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 11', '', 0.5, '["Grassland", "Urban"]',
+        '[{"name": "Trampling Charge", "description": "If the horse moves at least 20 feet straight toward a creature and then hits it with a hooves attack on the same turn, that target must succeed on a DC 14 Strength saving throw or be knocked prone. If the target is prone, the horse can make one hooves attack against it as a bonus action.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Hooves", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 11 (2d6 + 4) bludgeoning damage.", "attack_bonus": 6, "damage": "2d6+4"}]',
+        '[]', 0, '[]'),
+    ('warhorse-skeleton', 'Warhorse Skeleton', 'Large', 'Undead', '', 'lawful evil',
+        13, 'barding scraps', 22, '3d10+6', '{"walk": 60, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 12, 15, 2, 8, 5, '[]', '[]',
+        'bludgeoning', '', 'poison', 'exhaustion, poisoned',
+        'darkvision 60 ft., passive Perception 9', '', 0.5, '[]',
+        '[]',
+        '[{"name": "Hooves", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 11 (2d6 + 4) bludgeoning damage.", "attack_bonus": 6, "damage": "2d6+4"}]',
+        '[]', 0, '[]'),
+    ('worg', 'Worg', 'Large', 'Monstrosity', '', 'neutral evil',
+        13, 'natural armor', 26, '4d10+4', '{"walk": 50, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 13, 13, 7, 11, 8, '[]', '[{"name": "Perception", "bonus": 4}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 14', 'Goblin, Worg', 0.5, '["Forest", "Grassland", "Hill"]',
+        '[{"name": "Keen Hearing and Smell", "description": "The worg has advantage on Wisdom (Perception) checks that rely on hearing or smell.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 10 (2d6 + 3) piercing damage. If the target is a creature, it must succeed on a DC 13 Strength saving throw or be knocked prone.", "attack_bonus": 5, "damage": "2d6+3"}]',
+        '[]', 0, '[]'),
+    ('animated-armor', 'Animated Armor', 'Medium', 'Construct', '', 'unaligned',
+        18, 'natural armor', 33, '6d8+6', '{"walk": 25, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        14, 11, 13, 1, 3, 1, '[]', '[]',
+        '', '', 'poison, psychic', 'blinded, charmed, deafened, exhaustion, frightened, paralyzed, petrified, poisoned',
+        'blindsight 60 ft. (blind beyond this radius), passive Perception 6', '', 1, '["Urban"]',
+        '[{"name": "Antimagic Susceptibility", "description": "The armor is incapacitated while in the area of an antimagic field. If targeted by dispel magic, the armor must succeed on a Constitution saving throw against the caster''s spell save DC or fall unconscious for 1 minute.", "attack_bonus": null, "damage": null}, {"name": "False Appearance", "description": "While the armor remains motionless, it is indistinguishable from a normal suit of armor.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The armor makes two melee attacks.", "attack_bonus": null, "damage": null}, {"name": "Slam", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 5 (1d6 + 2) bludgeoning damage.", "attack_bonus": 4, "damage": "1d6+2"}]',
+        '[]', 0, '[]'),
+    ('brass-dragon-wyrmling', 'Brass Dragon Wyrmling', 'Medium', 'Dragon', '', 'chaotic good',
+        16, 'natural armor', 16, '3d8+3', '{"walk": 30, "fly": 60, "swim": 0, "climb": 0, "burrow": 15, "hover": false}',
+        15, 10, 13, 10, 11, 13, '[{"name": "Dex", "bonus": 2}, {"name": "Con", "bonus": 3}, {"name": "Wis", "bonus": 2}, {"name": "Cha", "bonus": 3}]', '[{"name": "Perception", "bonus": 4}, {"name": "Stealth", "bonus": 2}]',
+        '', '', 'fire', '',
+        'blindsight 10 ft., darkvision 60 ft., passive Perception 14', 'Draconic', 1, '["Desert"]',
+        '[]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 7 (1d10 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d10+2"}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Fire Breath. The dragon exhales fire in a 20-foot line that is 5 feet wide. Each creature in that line must make a DC 11 Dexterity saving throw, taking 14 (4d6) fire damage on a failed save, or half as much damage on a successful one. Sleep Breath. The dragon exhales sleep gas in a 15-foot cone. Each creature in that area must succeed on a DC 11 Constitution saving throw or fall unconscious for 1 minute. This effect ends for a creature if the creature takes damage or someone uses an action to wake it.", "attack_bonus": null, "damage": "4d6"}]',
+        '[]', 0, '[]'),
+    ('copper-dragon-wyrmling', 'Copper Dragon Wyrmling', 'Medium', 'Dragon', '', 'chaotic good',
+        16, 'natural armor', 22, '4d8+4', '{"walk": 30, "fly": 60, "swim": 0, "climb": 30, "burrow": 0, "hover": false}',
+        15, 12, 13, 14, 11, 13, '[{"name": "Dex", "bonus": 3}, {"name": "Con", "bonus": 3}, {"name": "Wis", "bonus": 2}, {"name": "Cha", "bonus": 3}]', '[{"name": "Perception", "bonus": 4}, {"name": "Stealth", "bonus": 3}]',
+        '', '', 'acid', '',
+        'blindsight 10 ft., darkvision 60 ft., passive Perception 14', 'Draconic', 1, '["Hill", "Mountain"]',
+        '[]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 7 (1d10 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d10+2"}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Acid Breath. The dragon exhales acid in a 20-foot line that is 5 feet wide. Each creature in that line must make a DC 11 Dexterity saving throw, taking 18 (4d8) acid damage on a failed save, or half as much damage on a successful one. Slowing Breath. The dragon exhales gas in a 15-foot cone. Each creature in that area must succeed on a DC 11 Constitution saving throw. On a failed save, the creature can''t use reactions, its speed is halved, and it can''t make more than one attack on its turn. In addition, the creature can use either an action or a bonus action on its turn, but not both. These effects last for 1 minute. The creature can repeat the saving throw at the end of each of its turns, ending the effect on itself with a successful save.", "attack_bonus": null, "damage": "4d8"}]',
+        '[]', 0, '[]'),
+    ('death-dog', 'Death Dog', 'Medium', 'Monstrosity', '', 'neutral evil',
+        12, '', 39, '6d8+12', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        15, 14, 14, 3, 13, 6, '[]', '[{"name": "Perception", "bonus": 5}, {"name": "Stealth", "bonus": 4}]',
+        '', '', '', '',
+        'darkvision 120 ft., passive Perception 15', '', 1, '["Desert"]',
+        '[{"name": "Two-Headed", "description": "The dog has advantage on Wisdom (Perception) checks and on saving throws against being blinded, charmed, deafened, frightened, stunned, or knocked unconscious.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dog makes two bite attacks.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 5 (1d6 + 2) piercing damage. If the target is a creature, it must succeed on a DC 12 Constitution saving throw against disease or become poisoned until the disease is cured. Every 24 hours that elapse, the creature must repeat the saving throw, reducing its hit point maximum by 5 (1d10) on a failure. This reduction lasts until the disease is cured. The creature dies if the disease reduces its hit point maximum to 0.", "attack_bonus": 4, "damage": "1d6+2"}]',
+        '[]', 0, '[]'),
+    ('dryad', 'Dryad', 'Medium', 'Fey', '', 'neutral',
+        11, '16 with barkskin', 22, '5d8', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        10, 12, 11, 14, 15, 18, '[]', '[{"name": "Perception", "bonus": 4}, {"name": "Stealth", "bonus": 5}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 14', 'Elvish, Sylvan', 1, '["Forest"]',
+        '[{"name": "Innate Spellcasting", "description": "The dryad''s innate spellcasting ability is Charisma (spell save DC 14). The dryad can innately cast the following spells, requiring no material components. At will: druidcraft. 3/day each: entangle, goodberry. 1/day each: barkskin, pass without trace, shillelagh.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The dryad has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}, {"name": "Speak with Beasts and Plants", "description": "The dryad can communicate with beasts and plants as if they shared a language.", "attack_bonus": null, "damage": null}, {"name": "Tree Stride", "description": "Once on her turn, the dryad can use 10 feet of her movement to step magically into one living tree within her reach and emerge from a second living tree within 60 feet of the first tree, appearing in an unoccupied space within 5 feet of the second tree. Both trees must be Large or bigger.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Club", "description": "Melee Weapon Attack: +2 to hit, reach 5 ft., one target. Hit: 2 (1d4) bludgeoning damage, or 8 (1d8 + 4) bludgeoning damage with shillelagh. The dryad has +6 to hit with shillelagh.", "attack_bonus": 2, "damage": "1d4"}, {"name": "Fey Charm", "description": "The dryad targets one humanoid or beast that she can see within 30 feet of her. If the target can see the dryad, it must succeed on a DC 14 Wisdom saving throw or be magically charmed. The charmed creature regards the dryad as a trusted friend to be heeded and protected. Although the target isn''t under the dryad''s control, it takes the dryad''s requests or actions in the most favorable way it can. Each time the dryad or its allies do anything harmful to the target, it can repeat the saving throw, ending the effect on itself on a success. Otherwise, the effect lasts 24 hours or until the dryad dies, is on a different plane of existence from the target, or ends the effect as a bonus action. If a target''s saving throw is successful, the target is immune to the dryad''s Fey Charm for the next 24 hours. The dryad can have no more than one humanoid and up to three beasts charmed at a time.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('duergar', 'Duergar', 'Medium', 'Humanoid', 'dwarf', 'lawful evil',
+        16, 'scale mail, shield', 26, '4d8+8', '{"walk": 25, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        14, 11, 14, 11, 10, 9, '[]', '[]',
+        '', 'poison', '', '',
+        'darkvision 120 ft., passive Perception 10', 'Dwarvish, Undercommon', 1, '["Underdark"]',
+        '[{"name": "Duergar Resilience", "description": "The duergar has advantage on saving throws against poison, spells, and illusions, as well as to resist being charmed or paralyzed.", "attack_bonus": null, "damage": null}, {"name": "Sunlight Sensitivity", "description": "While in sunlight, the duergar has disadvantage on attack rolls, as well as on Wisdom (Perception) checks that rely on sight.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Enlarge (Recharges after a Short or Long Rest)", "description": "For 1 minute, the duergar magically increases in size, along with anything it is wearing or carrying. While enlarged, the duergar is Large, doubles its damage dice on Strength-based weapon attacks (included in the attacks), and makes Strength checks and Strength saving throws with advantage. If the duergar lacks the room to become Large, it attains the maximum size possible in the space available.", "attack_bonus": null, "damage": null}, {"name": "War Pick", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 6 (1d8 + 2) piercing damage, or 11 (2d8 + 2) piercing damage while enlarged.", "attack_bonus": 4, "damage": "1d8+2"}, {"name": "Javelin", "description": "Melee or Ranged Weapon Attack: +4 to hit, reach 5 ft. or range 30/120 ft., one target. Hit: 5 (1d6 + 2) piercing damage, or 9 (2d6 + 2) piercing damage while enlarged.", "attack_bonus": 4, "damage": "1d6+2"}, {"name": "Invisibility (Recharges after a Short or Long Rest)", "description": "The duergar magically turns invisible until it attacks, casts a spell, or uses its Enlarge, or until its concentration is broken, up to 1 hour (as if concentrating on a spell). Any equipment the duergar wears or carries is invisible with it.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('giant-eagle', 'Giant Eagle', 'Large', 'Beast', '', 'neutral good',
+        13, '', 26, '4d10+4', '{"walk": 10, "fly": 80, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 17, 13, 8, 14, 10, '[]', '[{"name": "Perception", "bonus": 4}]',
+        '', '', '', '',
+        'passive Perception 14', 'Giant Eagle, understands Common and Auran but doesn''t speak them', 1, '["Coastal", "Grassland", "Hill", "Mountain"]',
+        '[{"name": "Keen Sight", "description": "The eagle has advantage on Wisdom (Perception) checks that rely on sight.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The eagle makes two attacks: one with its beak and one with its talons.", "attack_bonus": null, "damage": null}, {"name": "Beak", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 6 (1d6 + 3) piercing damage.", "attack_bonus": 5, "damage": "1d6+3"}, {"name": "Talons", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 10 (2d6 + 3) slashing damage.", "attack_bonus": 5, "damage": "2d6+3"}]',
+        '[]', 0, '[]'),
+    ('giant-hyena', 'Giant Hyena', 'Large', 'Beast', '', 'unaligned',
+        12, '', 45, '6d10+12', '{"walk": 50, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 14, 14, 2, 12, 7, '[]', '[{"name": "Perception", "bonus": 3}]',
+        '', '', '', '',
+        'passive Perception 13', '', 1, '["Desert", "Grassland"]',
+        '[{"name": "Rampage", "description": "When the hyena reduces a creature to 0 hit points with a melee attack on its turn, the hyena can take a bonus action to move up to half its speed and make a bite attack.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 10 (2d6 + 3) piercing damage.", "attack_bonus": 5, "damage": "2d6+3"}]',
+        '[]', 0, '[]'),
+    ('giant-octopus', 'Giant Octopus', 'Large', 'Beast', '', 'unaligned',
+        11, '', 52, '8d10+8', '{"walk": 10, "fly": 0, "swim": 60, "climb": 0, "burrow": 0, "hover": false}',
+        17, 13, 13, 4, 10, 4, '[]', '[{"name": "Perception", "bonus": 4}, {"name": "Stealth", "bonus": 5}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 14', '', 1, '["Underwater"]',
+        '[{"name": "Hold Breath", "description": "The octopus can hold its breath for 1 hour while out of water.", "attack_bonus": null, "damage": null}, {"name": "Underwater Camouflage", "description": "The octopus has advantage on Dexterity (Stealth) checks made while underwater.", "attack_bonus": null, "damage": null}, {"name": "Water Breathing", "description": "The octopus can breathe only underwater.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Tentacles", "description": "Melee Weapon Attack: +5 to hit, reach 15 ft., one target. Hit: 10 (2d6 + 3) bludgeoning damage. If the target is a creature, it is grappled (escape DC 16). Until this grapple ends, the target is restrained, and the octopus can''t use its tentacles on another target.", "attack_bonus": 5, "damage": "2d6+3"}, {"name": "Ink Cloud (Recharges after a Short or Long Rest)", "description": "A 20-foot-radius cloud of ink extends all around the octopus if it is underwater. The area is heavily obscured for 1 minute, although a significant current can disperse the ink. After releasing the ink, the octopus can use the Dash action as a bonus action.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('giant-toad', 'Giant Toad', 'Large', 'Beast', '', 'unaligned',
+        11, '', 39, '6d10+6', '{"walk": 20, "fly": 0, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        15, 13, 13, 2, 10, 3, '[]', '[]',
+        '', '', '', '',
+        'darkvision 30 ft., passive Perception 10', '', 1, '["Forest", "Swamp", "Underdark"]',
+        '[{"name": "Amphibious", "description": "The toad can breathe air and water.", "attack_bonus": null, "damage": null}, {"name": "Standing Leap", "description": "The toad''s long jump is up to 20 feet and its high jump is up to 10 feet, with or without a running start.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 7 (1d10 + 2) piercing damage plus 5 (1d10) poison damage. The target is grappled (escape DC 13). Until this grapple ends, the target is restrained, and the toad can''t bite another target.", "attack_bonus": 4, "damage": "1d10+2"}, {"name": "Swallow", "description": "The toad makes one bite attack against a Medium or smaller target it is grappling. If the attack hits, the target is swallowed, and the grapple ends. The swallowed target is blinded and restrained, it has total cover against attacks and other effects outside the toad, and it takes 10 (3d6) acid damage at the start of each of the toad''s turns. The toad can have only one target swallowed at a time. If the toad dies, a swallowed creature is no longer restrained by it and can escape from the corpse using 5 feet of movement, exiting prone.", "attack_bonus": null, "damage": "3d6"}]',
+        '[]', 0, '[]'),
+    ('giant-vulture', 'Giant Vulture', 'Large', 'Beast', '', 'neutral evil',
+        10, '', 22, '3d10+6', '{"walk": 10, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        15, 10, 15, 6, 12, 7, '[]', '[{"name": "Perception", "bonus": 3}]',
+        '', '', '', '',
+        'passive Perception 13', 'understands Common but can''t speak', 1, '["Desert", "Grassland"]',
+        '[{"name": "Keen Sight and Smell", "description": "The vulture has advantage on Wisdom (Perception) checks that rely on sight or smell.", "attack_bonus": null, "damage": null}, {"name": "Pack Tactics", "description": "The vulture has advantage on an attack roll against a creature if at least one of the vulture''s allies is within 5 feet of the creature and the ally isn''t incapacitated.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The vulture makes two attacks: one with its beak and one with its talons.", "attack_bonus": null, "damage": null}, {"name": "Beak", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 7 (2d4 + 2) piercing damage.", "attack_bonus": 4, "damage": "2d4+2"}, {"name": "Talons", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 9 (2d6 + 2) slashing damage.", "attack_bonus": 4, "damage": "2d6+2"}]',
+        '[]', 0, '[]'),
+    ('harpy', 'Harpy', 'Medium', 'Monstrosity', '', 'chaotic evil',
+        11, '', 38, '7d8+7', '{"walk": 20, "fly": 40, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        12, 13, 12, 7, 10, 13, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 10', 'Common', 1, '["Coastal", "Hill", "Mountain"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The harpy makes two attacks: one with its claws and one with its club.", "attack_bonus": null, "damage": null}, {"name": "Claws", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 6 (2d4 + 1) slashing damage.", "attack_bonus": 3, "damage": "2d4+1"}, {"name": "Club", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one target. Hit: 3 (1d4 + 1) bludgeoning damage.", "attack_bonus": 3, "damage": "1d4+1"}, {"name": "Luring Song", "description": "The harpy sings a magical melody. Every humanoid and giant within 300 feet of the harpy that can hear the song must succeed on a DC 11 Wisdom saving throw or be charmed until the song ends. The harpy must take a bonus action on its subsequent turns to continue singing. It can stop singing at any time. The song ends if the harpy is incapacitated. While charmed by the harpy, a target is incapacitated and ignores the songs of other harpies. If the charmed target is more than 5 feet away from the harpy, the target must move on its turn toward the harpy by the most direct route, trying to get within 5 feet. It doesn''t avoid opportunity attacks, but before moving into damaging terrain, such as lava or a pit, and whenever it takes damage from a source other than the harpy, the target can repeat the saving throw. A charmed target can also repeat the saving throw at the end of each of its turns. If the saving throw is successful, the effect ends on it. A target that successfully saves is immune to this harpy''s song for the next 24 hours.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('hippogriff', 'Hippogriff', 'Large', 'Monstrosity', '', 'unaligned',
+        11, '', 19, '3d10+3', '{"walk": 40, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        17, 13, 13, 2, 12, 8, '[]', '[{"name": "Perception", "bonus": 5}]',
+        '', '', '', '',
+        'passive Perception 15', '', 1, '["Grassland", "Hill", "Mountain"]',
+        '[{"name": "Keen Sight", "description": "The hippogriff has advantage on Wisdom (Perception) checks that rely on sight.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The hippogriff makes two attacks: one with its beak and one with its claws.", "attack_bonus": null, "damage": null}, {"name": "Beak", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 8 (1d10 + 3) piercing damage.", "attack_bonus": 5, "damage": "1d10+3"}, {"name": "Claws", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 10 (2d6 + 3) slashing damage.", "attack_bonus": 5, "damage": "2d6+3"}]',
+        '[]', 0, '[]'),
+    ('imp', 'Imp', 'Tiny', 'Fiend', 'devil, shapechanger', 'lawful evil',
+        13, '', 10, '3d4+3', '{"walk": 20, "fly": 40, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        6, 17, 13, 11, 12, 14, '[]', '[{"name": "Deception", "bonus": 4}, {"name": "Insight", "bonus": 3}, {"name": "Persuasion", "bonus": 4}, {"name": "Stealth", "bonus": 5}]',
+        '', 'cold; bludgeoning, piercing, and slashing from nonmagical attacks that aren''t silvered', 'fire, poison', 'poisoned',
+        'darkvision 120 ft., passive Perception 11', 'Infernal, Common', 1, '["Urban"]',
+        '[{"name": "Shapechanger", "description": "The imp can use its action to polymorph into a beast form that resembles a rat (speed 20 ft.), a raven (20 ft., fly 60 ft.), or a spider (20 ft., climb 20 ft.), or back into its true form. Its statistics are the same in each form, except for the speed changes noted. Any equipment it is wearing or carrying isn''t transformed. It reverts to its true form if it dies.", "attack_bonus": null, "damage": null}, {"name": "Devil''s Sight", "description": "Magical darkness doesn''t impede the devil''s darkvision.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The imp has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Sting (Bite in Beast Form)", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 5 (1d4 + 3) piercing damage. The target must make a DC 11 Constitution saving throw, taking 10 (3d6) poison damage on a failed save, or half as much damage on a successful one.", "attack_bonus": 5, "damage": "1d4+3"}, {"name": "Invisibility", "description": "The imp magically turns invisible until it attacks or until its concentration ends (as if concentrating on a spell). Any equipment the imp wears or carries is invisible with it.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('lion', 'Lion', 'Large', 'Beast', '', 'unaligned',
+        12, '', 26, '4d10+4', '{"walk": 50, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        17, 15, 13, 3, 12, 8, '[]', '[{"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 6}]',
+        '', '', '', '',
+        'passive Perception 13', '', 1, '["Desert", "Grassland"]',
+        '[{"name": "Keen Smell", "description": "The lion has advantage on Wisdom (Perception) checks that rely on smell.", "attack_bonus": null, "damage": null}, {"name": "Pack Tactics", "description": "The lion has advantage on an attack roll against a creature if at least one of the lion''s allies is within 5 feet of the creature and the ally isn''t incapacitated.", "attack_bonus": null, "damage": null}, {"name": "Pounce", "description": "If the lion moves at least 20 feet straight toward a creature and then hits it with a claw attack on the same turn, that target must succeed on a DC 13 Strength saving throw or be knocked prone. If the target is prone, the lion can make one bite attack against it as a bonus action.", "attack_bonus": null, "damage": null}, {"name": "Running Leap", "description": "With a 10-foot running start, the lion can long jump up to 25 feet.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 7 (1d8 + 3) piercing damage.", "attack_bonus": 5, "damage": "1d8+3"}, {"name": "Claw", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 6 (1d6 + 3) slashing damage.", "attack_bonus": 5, "damage": "1d6+3"}]',
+        '[]', 0, '[]'),
+    ('quasit', 'Quasit', 'Tiny', 'Fiend', 'demon, shapechanger', 'chaotic evil',
+        13, '', 7, '3d4', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        5, 17, 10, 7, 10, 10, '[]', '[{"name": "Stealth", "bonus": 5}]',
+        '', 'cold, fire, lightning; bludgeoning, piercing, and slashing from nonmagical attacks', 'poison', 'poisoned',
+        'darkvision 120 ft., passive Perception 10', 'Abyssal, Common', 1, '[]',
+        '[{"name": "Shapechanger", "description": "The quasit can use its action to polymorph into a beast form that resembles a bat (speed 10 ft. fly 40 ft.), a centipede (40 ft., climb 40 ft.), or a toad (40 ft., swim 40 ft.), or back into its true form. Its statistics are the same in each form, except for the speed changes noted. Any equipment it is wearing or carrying isn''t transformed. It reverts to its true form if it dies.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The quasit has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Claws (Bite in Beast Form)", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 5 (1d4 + 3) piercing damage. The target must succeed on a DC 10 Constitution saving throw or take 5 (2d4) poison damage and become poisoned for 1 minute. The target can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success.", "attack_bonus": 4, "damage": "1d4+3"}, {"name": "Scare (1/Day)", "description": "One creature of the quasit''s choice within 20 feet of it must succeed on a DC 10 Wisdom saving throw or be frightened for 1 minute. The target can repeat the saving throw at the end of each of its turns, with disadvantage if the quasit is within line of sight, ending the effect on itself on a success.", "attack_bonus": null, "damage": null}, {"name": "Invisibility", "description": "The quasit magically turns invisible until it attacks or uses Scare, or until its concentration ends (as if concentrating on a spell). Any equipment the quasit wears or carries is invisible with it.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('specter', 'Specter', 'Medium', 'Undead', '', 'chaotic evil',
+        12, '', 22, '5d8', '{"walk": 0, "fly": 50, "swim": 0, "climb": 0, "burrow": 0, "hover": true}',
+        1, 14, 11, 10, 10, 11, '[]', '[]',
+        '', 'acid, cold, fire, lightning, thunder; bludgeoning, piercing, and slashing from nonmagical attacks', 'necrotic, poison', 'charmed, exhaustion, grappled, paralyzed, petrified, poisoned, prone, restrained, unconscious',
+        'darkvision 60 ft., passive Perception 10', 'understands all languages it knew in life but can''t speak', 1, '["Underdark", "Urban"]',
+        '[{"name": "Incorporeal Movement", "description": "The specter can move through other creatures and objects as if they were difficult terrain. It takes 5 (1d10) force damage if it ends its turn inside an object.", "attack_bonus": null, "damage": null}, {"name": "Sunlight Sensitivity", "description": "While in sunlight, the specter has disadvantage on attack rolls, as well as on Wisdom (Perception) checks that rely on sight.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Life Drain", "description": "Melee Spell Attack: +4 to hit, reach 5 ft., one creature. Hit: 10 (3d6) necrotic damage. The target must succeed on a DC 10 Constitution saving throw or its hit point maximum is reduced by an amount equal to the damage taken. This reduction lasts until the creature finishes a long rest. The target dies if this effect reduces its hit point maximum to 0.", "attack_bonus": 4, "damage": "3d6"}]',
+        '[]', 0, '[]'),
+    ('spy', 'Spy', 'Medium', 'Humanoid', 'any race', 'any alignment',
+        12, '', 27, '6d8', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        10, 15, 10, 12, 14, 16, '[]', '[{"name": "Deception", "bonus": 5}, {"name": "Insight", "bonus": 4}, {"name": "Investigation", "bonus": 5}, {"name": "Perception", "bonus": 6}, {"name": "Persuasion", "bonus": 5}, {"name": "Sleight of Hand", "bonus": 4}, {"name": "Stealth", "bonus": 4}]',
+        '', '', '', '',
+        'passive Perception 16', 'any two languages', 1, '["Urban"]',
+        '[{"name": "Cunning Action", "description": "On each of its turns, the spy can use a bonus action to take the Dash, Disengage, or Hide action.", "attack_bonus": null, "damage": null}, {"name": "Sneak Attack (1/Turn)", "description": "The spy deals an extra 7 (2d6) damage when it hits a target with a weapon attack and has advantage on the attack roll, or when the target is within 5 feet of an ally of the spy that isn''t incapacitated and the spy doesn''t have disadvantage on the attack roll.", "attack_bonus": null, "damage": "2d6"}]',
+        '[{"name": "Multiattack", "description": "The spy makes two melee attacks.", "attack_bonus": null, "damage": null}, {"name": "Shortsword", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d6+2"}, {"name": "Hand Crossbow", "description": "Ranged Weapon Attack: +4 to hit, range 30/120 ft., one target. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d6+2"}]',
+        '[]', 0, '[]'),
+    ('swarm-of-quippers', 'Swarm of Quippers', 'Medium', 'Beast', 'swarm of Tiny beasts', 'unaligned',
+        13, '', 28, '8d8-8', '{"walk": 0, "fly": 0, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        13, 16, 9, 1, 7, 2, '[]', '[]',
+        '', 'bludgeoning, piercing, slashing', '', 'charmed, frightened, grappled, paralyzed, petrified, prone, restrained, stunned',
+        'darkvision 60 ft., passive Perception 8', '', 1, '["Swamp", "Underwater"]',
+        '[{"name": "Blood Frenzy", "description": "The swarm has advantage on melee attack rolls against any creature that doesn''t have all its hit points.", "attack_bonus": null, "damage": null}, {"name": "Swarm", "description": "The swarm can occupy another creature''s space and vice versa, and the swarm can move through any opening large enough for a Tiny quipper. The swarm can''t regain hit points or gain temporary hit points.", "attack_bonus": null, "damage": null}, {"name": "Water Breathing", "description": "The swarm can breathe only underwater.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bites", "description": "Melee Weapon Attack: +5 to hit, reach 0 ft., one creature in the swarm''s space. Hit: 14 (4d6) piercing damage, or 7 (2d6) piercing damage if the swarm has half of its hit points or fewer.", "attack_bonus": 5, "damage": "4d6"}]',
+        '[]', 0, '[]'),
+    ('tiger', 'Tiger', 'Large', 'Beast', '', 'unaligned',
+        12, '', 37, '5d10+10', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        17, 15, 14, 3, 12, 8, '[]', '[{"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 6}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 13', '', 1, '["Forest", "Grassland"]',
+        '[{"name": "Keen Smell", "description": "The tiger has advantage on Wisdom (Perception) checks that rely on smell.", "attack_bonus": null, "damage": null}, {"name": "Pounce", "description": "If the tiger moves at least 20 feet straight toward a creature and then hits it with a claw attack on the same turn, that target must succeed on a DC 13 Strength saving throw or be knocked prone. If the target is prone, the tiger can make one bite attack against it as a bonus action.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 8 (1d10 + 3) piercing damage.", "attack_bonus": 5, "damage": "1d10+3"}, {"name": "Claw", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 7 (1d8 + 3) slashing damage.", "attack_bonus": 5, "damage": "1d8+3"}]',
+        '[]', 0, '[]'),
+    ('ankheg', 'Ankheg', 'Large', 'Monstrosity', '', 'unaligned',
+        14, 'natural armor, 11 while prone', 39, '6d10+6', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 10, "hover": false}',
+        17, 11, 13, 1, 13, 6, '[]', '[]',
+        '', '', '', '',
+        'darkvision 60 ft., tremorsense 60 ft., passive Perception 11', '', 2, '["Forest", "Grassland"]',
+        '[]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 10 (2d6 + 3) slashing damage plus 3 (1d6) acid damage. If the target is a Large or smaller creature, it is grappled (escape DC 13). Until this grapple ends, the ankheg can bite only the grappled creature and has advantage on attack rolls to do so.", "attack_bonus": 5, "damage": "2d6+3"}, {"name": "Acid Spray (Recharge 6)", "description": "The ankheg spits acid in a line that is 30 feet long and 5 feet wide, provided that it has no creature grappled. Each creature in that line must make a DC 13 Dexterity saving throw, taking 10 (3d6) acid damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "3d6"}]',
+        '[]', 0, '[]'),
+    ('awakened-tree', 'Awakened Tree', 'Huge', 'Plant', '', 'unaligned',
+        13, 'natural armor', 59, '7d12+14', '{"walk": 20, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        19, 6, 15, 10, 10, 7, '[]', '[]',
+        'fire', 'bludgeoning, piercing', '', '',
+        'passive Perception 10', 'one language known by its creator', 2, '["Forest"]',
+        '[{"name": "False Appearance", "description": "While the tree remains motionless, it is indistinguishable from a normal tree.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Slam", "description": "Melee Weapon Attack: +6 to hit, reach 10 ft., one target. Hit: 14 (3d6 + 4) bludgeoning damage.", "attack_bonus": 6, "damage": "3d6+4"}]',
+        '[]', 0, '[]'),
+    ('azer', 'Azer', 'Medium', 'Elemental', '', 'lawful neutral',
+        17, 'natural armor, shield', 39, '6d8+12', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        17, 12, 15, 12, 13, 10, '[{"name": "Con", "bonus": 4}]', '[]',
+        '', '', 'fire, poison', 'poisoned',
+        'passive Perception 11', 'Ignan', 2, '["Mountain"]',
+        '[{"name": "Heated Body", "description": "A creature that touches the azer or hits it with a melee attack while within 5 feet of it takes 5 (1d10) fire damage.", "attack_bonus": null, "damage": "1d10"}, {"name": "Heated Weapons", "description": "When the azer hits with a metal melee weapon, it deals an extra 3 (1d6) fire damage (included in the attack).", "attack_bonus": null, "damage": null}, {"name": "Illumination", "description": "The azer sheds bright light in a 10-foot radius and dim light for an additional 10 feet.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Warhammer", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 7 (1d8 + 3) bludgeoning damage, or 8 (1d10 + 3) bludgeoning damage if used with two hands to make a melee attack plus 3 (1d6) fire damage.", "attack_bonus": 5, "damage": "1d8+3"}]',
+        '[]', 0, '[]'),
+    ('berserker', 'Berserker', 'Medium', 'Humanoid', 'any race', 'any chaotic alignment',
+        13, 'hide armor', 67, '9d8+27', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 12, 17, 9, 11, 9, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 10', 'any one language (usually Common)', 2, '["Arctic", "Forest", "Hill", "Mountain"]',
+        '[{"name": "Reckless", "description": "At the start of its turn, the berserker can gain advantage on all melee weapon attack rolls during that turn, but attack rolls against it have advantage until the start of its next turn.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Greataxe", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 9 (1d12 + 3) slashing damage.", "attack_bonus": 5, "damage": "1d12+3"}]',
+        '[]', 0, '[]'),
+    ('black-dragon-wyrmling', 'Black Dragon Wyrmling', 'Medium', 'Dragon', '', 'chaotic evil',
+        17, 'natural armor', 33, '6d8+6', '{"walk": 30, "fly": 60, "swim": 30, "climb": 0, "burrow": 0, "hover": false}',
+        15, 14, 13, 10, 11, 13, '[{"name": "Dex", "bonus": 4}, {"name": "Con", "bonus": 3}, {"name": "Wis", "bonus": 2}, {"name": "Cha", "bonus": 3}]', '[{"name": "Perception", "bonus": 4}, {"name": "Stealth", "bonus": 4}]',
+        '', '', 'acid', '',
+        'blindsight 10 ft., darkvision 60 ft., passive Perception 14', 'Draconic', 2, '["Swamp"]',
+        '[{"name": "Amphibious", "description": "The dragon can breathe air and water.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 7 (1d10 + 2) piercing damage plus 2 (1d4) acid damage.", "attack_bonus": 4, "damage": "1d10+2"}, {"name": "Acid Breath (Recharge 5-6)", "description": "The dragon exhales acid in a 15-foot line that is 5 feet wide. Each creature in that line must make a DC 11 Dexterity saving throw, taking 22 (5d8) acid damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "5d8"}]',
+        '[]', 0, '[]'),
+    ('bronze-dragon-wyrmling', 'Bronze Dragon Wyrmling', 'Medium', 'Dragon', '', 'lawful good',
+        17, 'natural armor', 32, '5d8+10', '{"walk": 30, "fly": 60, "swim": 30, "climb": 0, "burrow": 0, "hover": false}',
+        17, 10, 15, 12, 11, 15, '[{"name": "Dex", "bonus": 2}, {"name": "Con", "bonus": 4}, {"name": "Wis", "bonus": 2}, {"name": "Cha", "bonus": 4}]', '[{"name": "Perception", "bonus": 4}, {"name": "Stealth", "bonus": 2}]',
+        '', '', 'lightning', '',
+        'blindsight 10 ft., darkvision 60 ft., passive Perception 14', 'Draconic', 2, '["Coastal", "Underwater"]',
+        '[{"name": "Amphibious", "description": "The dragon can breathe air and water.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 8 (1d10 + 3) piercing damage.", "attack_bonus": 5, "damage": "1d10+3"}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Lightning Breath. The dragon exhales lightning in a 40-foot line that is 5 feet wide. Each creature in that line must make a DC 12 Dexterity saving throw, taking 16 (3d10) lightning damage on a failed save, or half as much damage on a successful one. Repulsion Breath. The dragon exhales repulsion energy in a 30-foot cone. Each creature in that area must succeed on a DC 12 Strength saving throw. On a failed save, the creature is pushed 30 feet away from the dragon.", "attack_bonus": null, "damage": "3d10"}]',
+        '[]', 0, '[]'),
+    ('centaur', 'Centaur', 'Large', 'Monstrosity', '', 'neutral good',
+        12, '', 45, '6d10+12', '{"walk": 50, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 14, 14, 9, 13, 11, '[]', '[{"name": "Athletics", "bonus": 6}, {"name": "Perception", "bonus": 3}, {"name": "Survival", "bonus": 3}]',
+        '', '', '', '',
+        'passive Perception 13', 'Elvish, Sylvan', 2, '["Forest", "Grassland"]',
+        '[{"name": "Charge", "description": "If the centaur moves at least 30 feet straight toward a target and then hits it with a pike attack on the same turn, the target takes an extra 10 (3d6) piercing damage.", "attack_bonus": null, "damage": "3d6"}]',
+        '[{"name": "Multiattack", "description": "The centaur makes two attacks: one with its pike and one with its hooves or two with its longbow.", "attack_bonus": null, "damage": null}, {"name": "Pike", "description": "Melee Weapon Attack: +6 to hit, reach 10 ft., one target. Hit: 9 (1d10 + 4) piercing damage.", "attack_bonus": 6, "damage": "1d10+4"}, {"name": "Hooves", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 11 (2d6 + 4) bludgeoning damage.", "attack_bonus": 6, "damage": "2d6+4"}, {"name": "Longbow", "description": "Ranged Weapon Attack: +4 to hit, range 150/600 ft., one target. Hit: 6 (1d8 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d8+2"}]',
+        '[]', 0, '[]'),
+    ('cult-fanatic', 'Cult Fanatic', 'Medium', 'Humanoid', 'any race', 'any non-good alignment',
+        13, 'leather armor', 33, '6d8+6', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        11, 14, 12, 10, 13, 14, '[]', '[{"name": "Deception", "bonus": 4}, {"name": "Persuasion", "bonus": 4}, {"name": "Religion", "bonus": 2}]',
+        '', '', '', '',
+        'passive Perception 11', 'any one language (usually Common)', 2, '["Urban", "Underdark"]',
+        '[{"name": "Dark Devotion", "description": "The fanatic has advantage on saving throws against being charmed or frightened.", "attack_bonus": null, "damage": null}, {"name": "Spellcasting", "description": "The fanatic is a 4th-level spellcaster. Its spellcasting ability is Wisdom (spell save DC 11, +3 to hit with spell attacks). The fanatic has the following cleric spells prepared. Cantrips (at will): light, sacred flame, thaumaturgy. 1st level (4 slots): command, inflict wounds, shield of faith. 2nd level (3 slots): hold person, spiritual weapon.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The fanatic makes two melee attacks.", "attack_bonus": null, "damage": null}, {"name": "Dagger", "description": "Melee or Ranged Weapon Attack: +4 to hit, reach 5 ft. or range 20/60 ft., one creature. Hit: 4 (1d4 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d4+2"}]',
+        '[]', 0, '[]'),
+    ('druid', 'Druid', 'Medium', 'Humanoid', 'any race', 'any alignment',
+        11, '16 with barkskin', 27, '5d8+5', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        10, 12, 13, 12, 15, 11, '[]', '[{"name": "Medicine", "bonus": 4}, {"name": "Nature", "bonus": 3}, {"name": "Perception", "bonus": 4}]',
+        '', '', '', '',
+        'passive Perception 14', 'Druidic plus any two languages', 2, '["Forest", "Grassland", "Hill"]',
+        '[{"name": "Spellcasting", "description": "The druid is a 4th-level spellcaster. Its spellcasting ability is Wisdom (spell save DC 12, +4 to hit with spell attacks). It has the following druid spells prepared. Cantrips (at will): druidcraft, produce flame, shillelagh. 1st level (4 slots): entangle, longstrider, speak with animals, thunderwave. 2nd level (3 slots): animal messenger, barkskin.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Quarterstaff", "description": "Melee Weapon Attack: +2 to hit, reach 5 ft., one target. Hit: 3 (1d6) bludgeoning damage, 4 (1d8) bludgeoning damage if wielded with two hands, or 6 (1d8 + 2) bludgeoning damage with shillelagh. The druid has +4 to hit with shillelagh.", "attack_bonus": 2, "damage": "1d6"}]',
+        '[]', 0, '[]'),
+    ('ettercap', 'Ettercap', 'Medium', 'Monstrosity', '', 'neutral evil',
+        13, 'natural armor', 44, '8d8+8', '{"walk": 30, "fly": 0, "swim": 0, "climb": 30, "burrow": 0, "hover": false}',
+        14, 15, 13, 7, 12, 8, '[]', '[{"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 4}, {"name": "Survival", "bonus": 3}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 13', '', 2, '["Forest"]',
+        '[{"name": "Spider Climb", "description": "The ettercap can climb difficult surfaces, including upside down on ceilings, without needing to make an ability check.", "attack_bonus": null, "damage": null}, {"name": "Web Sense", "description": "While in contact with a web, the ettercap knows the exact location of any other creature in contact with the same web.", "attack_bonus": null, "damage": null}, {"name": "Web Walker", "description": "The ettercap ignores movement restrictions caused by webbing.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The ettercap makes two attacks: one with its bite and one with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one creature. Hit: 6 (1d8 + 2) piercing damage plus 4 (1d8) poison damage. The target must succeed on a DC 11 Constitution saving throw or be poisoned for 1 minute. The creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success.", "attack_bonus": 4, "damage": "1d8+2"}, {"name": "Claws", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 7 (2d4 + 2) slashing damage.", "attack_bonus": 4, "damage": "2d4+2"}, {"name": "Web (Recharge 5-6)", "description": "Ranged Weapon Attack: +4 to hit, range 30/60 ft., one Large or smaller creature. Hit: The creature is restrained by webbing. As an action, the restrained creature can make a DC 11 Strength check, escaping from the webbing on a success. The effect also ends if the webbing is destroyed. The webbing has AC 10, 5 hit points, vulnerability to fire damage, and immunity to bludgeoning, poison, and psychic damage.", "attack_bonus": 4, "damage": null}]',
+        '[]', 0, '[]'),
+    ('gargoyle', 'Gargoyle', 'Medium', 'Elemental', '', 'chaotic evil',
+        15, 'natural armor', 52, '7d8+21', '{"walk": 30, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        15, 11, 16, 6, 11, 7, '[]', '[]',
+        '', 'bludgeoning, piercing, and slashing from nonmagical attacks that aren''t adamantine', 'poison', 'exhaustion, petrified, poisoned',
+        'darkvision 60 ft., passive Perception 10', 'Terran', 2, '["Mountain", "Urban"]',
+        '[{"name": "False Appearance", "description": "While the gargoyle remains motionless, it is indistinguishable from an inanimate statue.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The gargoyle makes two attacks: one with its bite and one with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d6+2"}, {"name": "Claws", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 5 (1d6 + 2) slashing damage.", "attack_bonus": 4, "damage": "1d6+2"}]',
+        '[]', 0, '[]'),
+    ('ghast', 'Ghast', 'Medium', 'Undead', '', 'chaotic evil',
+        13, '', 36, '8d8', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 17, 10, 11, 10, 8, '[]', '[]',
+        '', 'necrotic', 'poison', 'charmed, exhaustion, poisoned',
+        'darkvision 60 ft., passive Perception 10', 'Common', 2, '["Swamp", "Underdark", "Urban"]',
+        '[{"name": "Stench", "description": "Any creature that starts its turn within 5 feet of the ghast must succeed on a DC 10 Constitution saving throw or be poisoned until the start of its next turn. On a successful saving throw, the creature is immune to the ghast''s Stench for 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Turning Defiance", "description": "The ghast and any ghouls within 30 feet of it have advantage on saving throws against effects that turn undead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +3 to hit, reach 5 ft., one creature. Hit: 12 (2d8 + 3) piercing damage.", "attack_bonus": 3, "damage": "2d8+3"}, {"name": "Claws", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 10 (2d6 + 3) slashing damage. If the target is a creature other than an undead, it must succeed on a DC 10 Constitution saving throw or be paralyzed for 1 minute. The target can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success.", "attack_bonus": 5, "damage": "2d6+3"}]',
+        '[]', 0, '[]'),
+    ('giant-boar', 'Giant Boar', 'Large', 'Beast', '', 'unaligned',
+        12, 'natural armor', 42, '5d10+15', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        17, 10, 16, 2, 7, 5, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 8', '', 2, '["Forest", "Grassland", "Hill"]',
+        '[{"name": "Charge", "description": "If the boar moves at least 20 feet straight toward a target and then hits it with a tusk attack on the same turn, the target takes an extra 7 (2d6) slashing damage. If the target is a creature, it must succeed on a DC 13 Strength saving throw or be knocked prone.", "attack_bonus": null, "damage": "2d6"}, {"name": "Relentless (Recharges after a Short or Long Rest)", "description": "If the boar takes 10 damage or less that would reduce it to 0 hit points, it is reduced to 1 hit point instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Tusk", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 10 (2d6 + 3) slashing damage.", "attack_bonus": 5, "damage": "2d6+3"}]',
+        '[]', 0, '[]'),
+    ('giant-constrictor-snake', 'Giant Constrictor Snake', 'Huge', 'Beast', '', 'unaligned',
+        12, '', 60, '8d12+8', '{"walk": 30, "fly": 0, "swim": 30, "climb": 0, "burrow": 0, "hover": false}',
+        19, 14, 12, 1, 10, 3, '[]', '[{"name": "Perception", "bonus": 2}]',
+        '', '', '', '',
+        'blindsight 10 ft., passive Perception 12', '', 2, '["Forest", "Swamp", "Underdark"]',
+        '[]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +6 to hit, reach 10 ft., one creature. Hit: 11 (2d6 + 4) piercing damage.", "attack_bonus": 6, "damage": "2d6+4"}, {"name": "Constrict", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one creature. Hit: 13 (2d8 + 4) bludgeoning damage. The target is grappled (escape DC 16). Until this grapple ends, the creature is restrained, and the snake can''t constrict another target.", "attack_bonus": 6, "damage": "2d8+4"}]',
+        '[]', 0, '[]'),
+    ('giant-elk', 'Giant Elk', 'Huge', 'Beast', '', 'unaligned',
+        14, 'natural armor', 42, '5d12+10', '{"walk": 60, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        19, 16, 14, 7, 14, 10, '[]', '[{"name": "Perception", "bonus": 4}]',
+        '', '', '', '',
+        'passive Perception 14', 'Giant Elk, understands Common, Elvish, and Sylvan but can''t speak them', 2, '["Forest", "Grassland", "Hill"]',
+        '[{"name": "Charge", "description": "If the elk moves at least 20 feet straight toward a target and then hits it with a ram attack on the same turn, the target takes an extra 7 (2d6) bludgeoning damage. If the target is a creature, it must succeed on a DC 14 Strength saving throw or be knocked prone.", "attack_bonus": null, "damage": "2d6"}]',
+        '[{"name": "Ram", "description": "Melee Weapon Attack: +6 to hit, reach 10 ft., one target. Hit: 11 (2d6 + 4) bludgeoning damage.", "attack_bonus": 6, "damage": "2d6+4"}, {"name": "Hooves", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one prone creature. Hit: 22 (4d8 + 4) bludgeoning damage.", "attack_bonus": 6, "damage": "4d8+4"}]',
+        '[]', 0, '[]'),
+    ('gibbering-mouther', 'Gibbering Mouther', 'Medium', 'Aberration', '', 'neutral',
+        9, '', 67, '9d8+27', '{"walk": 10, "fly": 0, "swim": 10, "climb": 0, "burrow": 0, "hover": false}',
+        10, 8, 16, 3, 10, 6, '[]', '[]',
+        '', '', '', 'prone',
+        'darkvision 60 ft., passive Perception 10', '', 2, '["Underdark"]',
+        '[{"name": "Aberrant Ground", "description": "The ground in a 10-foot radius around the mouther is doughlike difficult terrain. Each creature that starts its turn in that area must succeed on a DC 10 Strength saving throw or have its speed reduced to 0 until the start of its next turn.", "attack_bonus": null, "damage": null}, {"name": "Gibbering", "description": "The mouther babbles incoherently while it can see any creature and isn''t incapacitated. Each creature that starts its turn within 20 feet of the mouther and can hear the gibbering must succeed on a DC 10 Wisdom saving throw. On a failure, the creature can''t take reactions until the start of its next turn and rolls a d8 to determine what it does during its turn. On a 1 to 4, the creature does nothing. On a 5 or 6, the creature takes no action or bonus action and uses all its movement to move in a randomly determined direction. On a 7 or 8, the creature makes a melee attack against a randomly determined creature within its reach or does nothing if it can''t make such an attack.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The gibbering mouther makes one bite attack and, if it can, uses its Blinding Spittle.", "attack_bonus": null, "damage": null}, {"name": "Bites", "description": "Melee Weapon Attack: +2 to hit, reach 5 ft., one creature. Hit: 17 (5d6) piercing damage. If the target is Medium or smaller, it must succeed on a DC 10 Strength saving throw or be knocked prone. If the target is killed by this damage, it is absorbed into the mouther.", "attack_bonus": 2, "damage": "5d6"}, {"name": "Blinding Spittle (Recharge 5-6)", "description": "The mouther spits a chemical glob at a point it can see within 15 feet of it. The glob explodes in a blinding flash of light on impact. Each creature within 5 feet of the flash must succeed on a DC 13 Dexterity saving throw or be blinded until the end of the mouther''s next turn.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('green-dragon-wyrmling', 'Green Dragon Wyrmling', 'Medium', 'Dragon', '', 'lawful evil',
+        17, 'natural armor', 38, '7d8+7', '{"walk": 30, "fly": 60, "swim": 30, "climb": 0, "burrow": 0, "hover": false}',
+        15, 12, 13, 14, 11, 13, '[{"name": "Dex", "bonus": 3}, {"name": "Con", "bonus": 3}, {"name": "Wis", "bonus": 2}, {"name": "Cha", "bonus": 3}]', '[{"name": "Perception", "bonus": 4}, {"name": "Stealth", "bonus": 3}]',
+        '', '', 'poison', 'poisoned',
+        'blindsight 10 ft., darkvision 60 ft., passive Perception 14', 'Draconic', 2, '["Forest"]',
+        '[{"name": "Amphibious", "description": "The dragon can breathe air and water.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 7 (1d10 + 2) piercing damage plus 3 (1d6) poison damage.", "attack_bonus": 4, "damage": "1d10+2"}, {"name": "Poison Breath (Recharge 5-6)", "description": "The dragon exhales poisonous gas in a 15-foot cone. Each creature in that area must make a DC 11 Constitution saving throw, taking 21 (6d6) poison damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "6d6"}]',
+        '[]', 0, '[]'),
+    ('grick', 'Grick', 'Medium', 'Monstrosity', '', 'neutral',
+        14, 'natural armor', 27, '6d8', '{"walk": 30, "fly": 0, "swim": 0, "climb": 30, "burrow": 0, "hover": false}',
+        14, 14, 11, 3, 14, 5, '[]', '[]',
+        '', 'bludgeoning, piercing, and slashing from nonmagical attacks', '', '',
+        'darkvision 60 ft., passive Perception 12', '', 2, '["Underdark", "Forest"]',
+        '[{"name": "Stone Camouflage", "description": "The grick has advantage on Dexterity (Stealth) checks made to hide in rocky terrain.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The grick makes one attack with its tentacles. If that attack hits, the grick can make one beak attack against the same target.", "attack_bonus": null, "damage": null}, {"name": "Tentacles", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 9 (2d6 + 2) slashing damage.", "attack_bonus": 4, "damage": "2d6+2"}, {"name": "Beak", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d6+2"}]',
+        '[]', 0, '[]'),
+    ('griffon', 'Griffon', 'Large', 'Monstrosity', '', 'unaligned',
+        12, '', 59, '7d10+21', '{"walk": 30, "fly": 80, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 15, 16, 2, 13, 8, '[]', '[{"name": "Perception", "bonus": 5}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 15', '', 2, '["Grassland", "Hill", "Mountain"]',
+        '[{"name": "Keen Sight", "description": "The griffon has advantage on Wisdom (Perception) checks that rely on sight.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The griffon makes two attacks: one with its beak and one with its claws.", "attack_bonus": null, "damage": null}, {"name": "Beak", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 8 (1d8 + 4) piercing damage.", "attack_bonus": 6, "damage": "1d8+4"}, {"name": "Claws", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 11 (2d6 + 4) slashing damage.", "attack_bonus": 6, "damage": "2d6+4"}]',
+        '[]', 0, '[]'),
+    ('hunter-shark', 'Hunter Shark', 'Large', 'Beast', '', 'unaligned',
+        12, 'natural armor', 45, '6d10+12', '{"walk": 0, "fly": 0, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        18, 13, 15, 1, 10, 4, '[]', '[{"name": "Perception", "bonus": 2}]',
+        '', '', '', '',
+        'blindsight 30 ft., passive Perception 12', '', 2, '["Underwater"]',
+        '[{"name": "Blood Frenzy", "description": "The shark has advantage on melee attack rolls against any creature that doesn''t have all its hit points.", "attack_bonus": null, "damage": null}, {"name": "Water Breathing", "description": "The shark can breathe only underwater.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 13 (2d8 + 4) piercing damage.", "attack_bonus": 6, "damage": "2d8+4"}]',
+        '[]', 0, '[]'),
+    ('merrow', 'Merrow', 'Large', 'Monstrosity', '', 'chaotic evil',
+        13, 'natural armor', 45, '6d10+12', '{"walk": 10, "fly": 0, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        18, 10, 15, 8, 10, 9, '[]', '[]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 10', 'Abyssal, Aquan', 2, '["Coastal", "Underwater"]',
+        '[{"name": "Amphibious", "description": "The merrow can breathe air and water.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The merrow makes two attacks: one with its bite and one with its claws or harpoon.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 8 (1d8 + 4) piercing damage.", "attack_bonus": 6, "damage": "1d8+4"}, {"name": "Claws", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 9 (2d4 + 4) slashing damage.", "attack_bonus": 6, "damage": "2d4+4"}, {"name": "Harpoon", "description": "Melee or Ranged Weapon Attack: +6 to hit, reach 5 ft. or range 20/60 ft., one target. Hit: 11 (2d6 + 4) piercing damage. If the target is a Huge or smaller creature, it must succeed on a Strength contest against the merrow or be pulled up to 20 feet toward the merrow.", "attack_bonus": 6, "damage": "2d6+4"}]',
+        '[]', 0, '[]'),
+    ('mimic', 'Mimic', 'Medium', 'Monstrosity', 'shapechanger', 'neutral',
+        12, 'natural armor', 58, '9d8+18', '{"walk": 15, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        17, 12, 15, 5, 13, 8, '[]', '[{"name": "Stealth", "bonus": 5}]',
+        '', '', 'acid', 'prone',
+        'darkvision 60 ft., passive Perception 11', '', 2, '["Underdark", "Urban"]',
+        '[{"name": "Shapechanger", "description": "The mimic can use its action to polymorph into an object or back into its true, amorphous form. Its statistics are the same in each form. Any equipment it is wearing or carrying isn''t transformed. It reverts to its true form if it dies.", "attack_bonus": null, "damage": null}, {"name": "Adhesive (Object Form Only)", "description": "The mimic adheres to anything that touches it. A Huge or smaller creature adhered to the mimic is also grappled by it (escape DC 13). Ability checks made to escape this grapple have disadvantage.", "attack_bonus": null, "damage": null}, {"name": "False Appearance (Object Form Only)", "description": "While the mimic remains motionless, it is indistinguishable from an ordinary object.", "attack_bonus": null, "damage": null}, {"name": "Grappler", "description": "The mimic has advantage on attack rolls against any creature grappled by it.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Pseudopod", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 7 (1d8 + 3) bludgeoning damage. If the mimic is in object form, the target is subjected to its Adhesive trait.", "attack_bonus": 5, "damage": "1d8+3"}, {"name": "Bite", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 7 (1d8 + 3) piercing damage plus 4 (1d8) acid damage.", "attack_bonus": 5, "damage": "1d8+3"}]',
+        '[]', 0, '[]'),
+    ('minotaur-skeleton', 'Minotaur Skeleton', 'Large', 'Undead', '', 'lawful evil',
+        12, 'natural armor', 67, '9d10+18', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 11, 15, 6, 8, 5, '[]', '[]',
+        'bludgeoning', '', 'poison', 'exhaustion, poisoned',
+        'darkvision 60 ft., passive Perception 9', 'understands Abyssal but can''t speak', 2, '["Underdark"]',
+        '[{"name": "Charge", "description": "If the skeleton moves at least 10 feet straight toward a target and then hits it with a gore attack on the same turn, the target takes an extra 9 (2d8) piercing damage. If the target is a creature, it must succeed on a DC 14 Strength saving throw or be pushed up to 10 feet away and knocked prone.", "attack_bonus": null, "damage": "2d8"}]',
+        '[{"name": "Greataxe", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 17 (2d12 + 4) slashing damage.", "attack_bonus": 6, "damage": "2d12+4"}, {"name": "Gore", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 13 (2d8 + 4) piercing damage.", "attack_bonus": 6, "damage": "2d8+4"}]',
+        '[]', 0, '[]'),
+    ('ochre-jelly', 'Ochre Jelly', 'Large', 'Ooze', '', 'unaligned',
+        8, '', 45, '6d10+12', '{"walk": 10, "fly": 0, "swim": 0, "climb": 10, "burrow": 0, "hover": false}',
+        15, 6, 14, 2, 6, 1, '[]', '[]',
+        '', 'acid', 'lightning, slashing', 'blinded, charmed, deafened, exhaustion, frightened, prone',
+        'blindsight 60 ft. (blind beyond this radius), passive Perception 8', '', 2, '["Underdark", "Swamp"]',
+        '[{"name": "Amorphous", "description": "The jelly can move through a space as narrow as 1 inch wide without squeezing.", "attack_bonus": null, "damage": null}, {"name": "Spider Climb", "description": "The jelly can climb difficult surfaces, including upside down on ceilings, without needing to make an ability check.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Pseudopod", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 9 (2d6 + 2) bludgeoning damage plus 3 (1d6) acid damage.", "attack_bonus": 4, "damage": "2d6+2"}]',
+        '[{"name": "Split", "description": "When a jelly that is Medium or larger is subjected to lightning or slashing damage, it splits into two new jellies if it has at least 10 hit points. Each new jelly has hit points equal to half the original jelly''s, rounded down. New jellies are one size smaller than the original jelly.", "attack_bonus": null, "damage": null}]', 0, '[]'),
+    ('ogre-zombie', 'Ogre Zombie', 'Large', 'Undead', '', 'neutral evil',
+        8, '', 85, '9d10+36', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        19, 6, 18, 3, 6, 5, '[{"name": "Wis", "bonus": 0}]', '[]',
+        '', '', 'poison', 'poisoned',
+        'darkvision 60 ft., passive Perception 8', 'understands Common and Giant but can''t speak', 2, '["Hill", "Swamp"]',
+        '[{"name": "Undead Fortitude", "description": "If damage reduces the zombie to 0 hit points, it must make a Constitution saving throw with a DC of 5 + the damage taken, unless the damage is radiant or from a critical hit. On a success, the zombie drops to 1 hit point instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Morningstar", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 13 (2d8 + 4) bludgeoning damage.", "attack_bonus": 6, "damage": "2d8+4"}]',
+        '[]', 0, '[]'),
+    ('pegasus', 'Pegasus', 'Large', 'Celestial', '', 'chaotic good',
+        12, '', 59, '7d10+21', '{"walk": 60, "fly": 90, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 15, 16, 10, 15, 13, '[{"name": "Dex", "bonus": 4}, {"name": "Wis", "bonus": 4}, {"name": "Cha", "bonus": 3}]', '[{"name": "Perception", "bonus": 6}]',
+        '', '', '', '',
+        'passive Perception 16', 'understands Celestial, Common, Elvish, and Sylvan but can''t speak', 2, '["Forest", "Grassland", "Hill", "Mountain"]',
+        '[]',
+        '[{"name": "Hooves", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 11 (2d6 + 4) bludgeoning damage.", "attack_bonus": 6, "damage": "2d6+4"}]',
+        '[]', 0, '[]'),
+    ('plesiosaurus', 'Plesiosaurus', 'Large', 'Beast', '', 'unaligned',
+        13, 'natural armor', 68, '8d10+24', '{"walk": 20, "fly": 0, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        18, 15, 16, 2, 12, 5, '[]', '[{"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 4}]',
+        '', '', '', '',
+        'passive Perception 13', '', 2, '["Coastal", "Underwater"]',
+        '[{"name": "Hold Breath", "description": "The plesiosaurus can hold its breath for 1 hour.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +6 to hit, reach 10 ft., one target. Hit: 14 (3d6 + 4) piercing damage.", "attack_bonus": 6, "damage": "3d6+4"}]',
+        '[]', 0, '[]'),
+    ('polar-bear', 'Polar Bear', 'Large', 'Beast', '', 'unaligned',
+        12, 'natural armor', 42, '5d10+15', '{"walk": 40, "fly": 0, "swim": 30, "climb": 0, "burrow": 0, "hover": false}',
+        20, 10, 16, 2, 13, 7, '[]', '[{"name": "Perception", "bonus": 3}]',
+        '', '', '', '',
+        'passive Perception 13', '', 2, '["Arctic"]',
+        '[{"name": "Keen Smell", "description": "The bear has advantage on Wisdom (Perception) checks that rely on smell.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The bear makes two attacks: one with its bite and one with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 9 (1d8 + 5) piercing damage.", "attack_bonus": 7, "damage": "1d8+5"}, {"name": "Claws", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 12 (2d6 + 5) slashing damage.", "attack_bonus": 7, "damage": "2d6+5"}]',
+        '[]', 0, '[]'),
+    ('priest', 'Priest', 'Medium', 'Humanoid', 'any race', 'any alignment',
+        13, 'chain shirt', 27, '5d8+5', '{"walk": 25, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        10, 10, 12, 13, 16, 13, '[]', '[{"name": "Medicine", "bonus": 7}, {"name": "Persuasion", "bonus": 3}, {"name": "Religion", "bonus": 4}]',
+        '', '', '', '',
+        'passive Perception 13', 'any two languages', 2, '["Urban"]',
+        '[{"name": "Divine Eminence", "description": "As a bonus action, the priest can expend a spell slot to cause its melee weapon attacks to magically deal an extra 10 (3d6) radiant damage to a target on a hit. This benefit lasts until the end of the turn. If the priest expends a spell slot of 2nd level or higher, the extra damage increases by 1d6 for each level above 1st.", "attack_bonus": null, "damage": "3d6"}, {"name": "Spellcasting", "description": "The priest is a 5th-level spellcaster. Its spellcasting ability is Wisdom (spell save DC 13, +5 to hit with spell attacks). The priest has the following cleric spells prepared. Cantrips (at will): light, sacred flame, thaumaturgy. 1st level (4 slots): cure wounds, guiding bolt, sanctuary. 2nd level (3 slots): lesser restoration, spiritual weapon. 3rd level (2 slots): dispel magic, spirit guardians.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Mace", "description": "Melee Weapon Attack: +2 to hit, reach 5 ft., one target. Hit: 3 (1d6) bludgeoning damage.", "attack_bonus": 2, "damage": "1d6"}]',
+        '[]', 0, '[]'),
+    ('rhinoceros', 'Rhinoceros', 'Large', 'Beast', '', 'unaligned',
+        11, 'natural armor', 45, '6d10+12', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        21, 8, 15, 2, 12, 6, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 11', '', 2, '["Grassland"]',
+        '[{"name": "Charge", "description": "If the rhinoceros moves at least 20 feet straight toward a target and then hits it with a gore attack on the same turn, the target takes an extra 9 (2d8) bludgeoning damage. If the target is a creature, it must succeed on a DC 15 Strength saving throw or be knocked prone.", "attack_bonus": null, "damage": "2d8"}]',
+        '[{"name": "Gore", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 14 (2d8 + 5) bludgeoning damage.", "attack_bonus": 7, "damage": "2d8+5"}]',
+        '[]', 0, '[]'),
+    ('rug-of-smothering', 'Rug of Smothering', 'Large', 'Construct', '', 'unaligned',
+        12, '', 33, '6d10', '{"walk": 10, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        17, 14, 10, 1, 3, 1, '[]', '[]',
+        '', '', 'poison, psychic', 'blinded, charmed, deafened, exhaustion, frightened, paralyzed, petrified, poisoned',
+        'blindsight 60 ft. (blind beyond this radius), passive Perception 6', '', 2, '["Urban"]',
+        '[{"name": "Antimagic Susceptibility", "description": "The rug is incapacitated while in the area of an antimagic field. If targeted by dispel magic, the rug must succeed on a Constitution saving throw against the caster''s spell save DC or fall unconscious for 1 minute.", "attack_bonus": null, "damage": null}, {"name": "Damage Transfer", "description": "While it is grappling a creature, the rug takes only half the damage dealt to it, and the creature grappled by the rug takes the other half.", "attack_bonus": null, "damage": null}, {"name": "False Appearance", "description": "While the rug remains motionless, it is indistinguishable from a normal rug.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Smother", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one Medium or smaller creature. Hit: The creature is grappled (escape DC 13). Until this grapple ends, the target is restrained, blinded, and at risk of suffocating, and the rug can''t smother another target. In addition, at the start of each of the target''s turns, the target takes 10 (2d6 + 3) bludgeoning damage.", "attack_bonus": 5, "damage": null}]',
+        '[]', 0, '[]'),
+    ('saber-toothed-tiger', 'Saber-Toothed Tiger', 'Large', 'Beast', '', 'unaligned',
+        12, '', 52, '7d10+14', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 14, 15, 3, 12, 8, '[]', '[{"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 6}]',
+        '', '', '', '',
+        'passive Perception 13', '', 2, '["Arctic", "Mountain"]',
+        '[{"name": "Keen Smell", "description": "The tiger has advantage on Wisdom (Perception) checks that rely on smell.", "attack_bonus": null, "damage": null}, {"name": "Pounce", "description": "If the tiger moves at least 20 feet straight toward a creature and then hits it with a claw attack on the same turn, that target must succeed on a DC 14 Strength saving throw or be knocked prone. If the target is prone, the tiger can make one bite attack against it as a bonus action.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 10 (1d10 + 5) piercing damage.", "attack_bonus": 6, "damage": "1d10+5"}, {"name": "Claw", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 12 (2d6 + 5) slashing damage.", "attack_bonus": 6, "damage": "2d6+5"}]',
+        '[]', 0, '[]'),
+    ('sea-hag', 'Sea Hag', 'Medium', 'Fey', '', 'chaotic evil',
+        14, 'natural armor', 52, '7d8+21', '{"walk": 30, "fly": 0, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        16, 13, 16, 12, 12, 13, '[]', '[]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 11', 'Aquan, Common, Giant', 2, '["Coastal", "Underwater"]',
+        '[{"name": "Amphibious", "description": "The hag can breathe air and water.", "attack_bonus": null, "damage": null}, {"name": "Horrific Appearance", "description": "Any humanoid that starts its turn within 30 feet of the hag and can see the hag''s true form must make a DC 11 Wisdom saving throw. On a failed save, the creature is frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, with disadvantage if the hag is within line of sight, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the hag''s Horrific Appearance for the next 24 hours. Unless the target is surprised or the revelation of the hag''s true form is sudden, the target can avert its eyes and avoid making the initial saving throw. Until the start of its next turn, a creature that averts its eyes has disadvantage on attack rolls against the hag.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Claws", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 10 (2d6 + 3) slashing damage.", "attack_bonus": 5, "damage": "2d6+3"}, {"name": "Death Glare", "description": "The hag targets one frightened creature she can see within 30 feet of her. If the target can see the hag, it must succeed on a DC 11 Wisdom saving throw against this magic or drop to 0 hit points.", "attack_bonus": null, "damage": null}, {"name": "Illusory Appearance", "description": "The hag covers herself and anything she is wearing or carrying with a magical illusion that makes her look like an ugly creature of her general size and humanoid shape. The effect ends if the hag takes a bonus action to end it or if she dies. The changes wrought by this effect fail to hold up to physical inspection. To discern that the hag is disguised, a creature must take an action to visually inspect the illusion and succeed on a DC 16 Intelligence (Investigation) check.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('silver-dragon-wyrmling', 'Silver Dragon Wyrmling', 'Medium', 'Dragon', '', 'lawful good',
+        17, 'natural armor', 45, '6d8+18', '{"walk": 30, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        19, 10, 17, 12, 11, 15, '[{"name": "Dex", "bonus": 2}, {"name": "Con", "bonus": 5}, {"name": "Wis", "bonus": 2}, {"name": "Cha", "bonus": 4}]', '[{"name": "Perception", "bonus": 4}, {"name": "Stealth", "bonus": 2}]',
+        '', '', 'cold', '',
+        'blindsight 10 ft., darkvision 60 ft., passive Perception 14', 'Draconic', 2, '["Mountain", "Urban"]',
+        '[]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 9 (1d10 + 4) piercing damage.", "attack_bonus": 6, "damage": "1d10+4"}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Cold Breath. The dragon exhales an icy blast in a 15-foot cone. Each creature in that area must make a DC 13 Constitution saving throw, taking 18 (4d8) cold damage on a failed save, or half as much damage on a successful one. Paralyzing Breath. The dragon exhales paralyzing gas in a 15-foot cone. Each creature in that area must succeed on a DC 13 Constitution saving throw or be paralyzed for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success.", "attack_bonus": null, "damage": "4d8"}]',
+        '[]', 0, '[]'),
+    ('swarm-of-poisonous-snakes', 'Swarm of Poisonous Snakes', 'Medium', 'Beast', 'swarm of Tiny beasts', 'unaligned',
+        14, '', 36, '8d8', '{"walk": 30, "fly": 0, "swim": 30, "climb": 0, "burrow": 0, "hover": false}',
+        8, 18, 11, 1, 10, 3, '[]', '[]',
+        '', 'bludgeoning, piercing, slashing', '', 'charmed, frightened, grappled, paralyzed, petrified, prone, restrained, stunned',
+        'blindsight 10 ft., passive Perception 10', '', 2, '["Desert", "Forest", "Swamp"]',
+        '[{"name": "Swarm", "description": "The swarm can occupy another creature''s space and vice versa, and the swarm can move through any opening large enough for a Tiny snake. The swarm can''t regain hit points or gain temporary hit points.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bites", "description": "Melee Weapon Attack: +6 to hit, reach 0 ft., one creature in the swarm''s space. Hit: 7 (2d6) piercing damage, or 3 (1d6) piercing damage if the swarm has half of its hit points or fewer. The target must make a DC 10 Constitution saving throw, taking 14 (4d6) poison damage on a failed save, or half as much damage on a successful one.", "attack_bonus": 6, "damage": "2d6"}]',
+        '[]', 0, '[]'),
+    ('wererat', 'Wererat', 'Medium', 'Humanoid', 'human, shapechanger', 'lawful evil',
+        12, '', 33, '6d8+6', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        10, 15, 12, 11, 10, 8, '[]', '[{"name": "Perception", "bonus": 2}, {"name": "Stealth", "bonus": 4}]',
+        '', '', 'bludgeoning, piercing, and slashing from nonmagical attacks that aren''t silvered', '',
+        'darkvision 60 ft. (rat form only), passive Perception 12', 'Common (can''t speak in rat form)', 2, '["Urban"]',
+        '[{"name": "Shapechanger", "description": "The wererat can use its action to polymorph into a rat-humanoid hybrid, or into a giant rat, or back into its true form, which is humanoid. Its statistics, other than its size and AC, are the same in each form. Any equipment it is wearing or carrying isn''t transformed. It reverts to its true form if it dies.", "attack_bonus": null, "damage": null}, {"name": "Keen Smell", "description": "The wererat has advantage on Wisdom (Perception) checks that rely on smell.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The wererat makes two attacks, only one of which can be a bite. (Humanoid or Hybrid Form Only)", "attack_bonus": null, "damage": null}, {"name": "Bite (Rat or Hybrid Form Only)", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 4 (1d4 + 2) piercing damage. If the target is a humanoid, it must succeed on a DC 11 Constitution saving throw or be cursed with wererat lycanthropy.", "attack_bonus": 4, "damage": "1d4+2"}, {"name": "Shortsword (Humanoid or Hybrid Form Only)", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d6+2"}, {"name": "Hand Crossbow (Humanoid or Hybrid Form Only)", "description": "Ranged Weapon Attack: +4 to hit, range 30/120 ft., one target. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d6+2"}]',
+        '[]', 0, '[]'),
+    ('white-dragon-wyrmling', 'White Dragon Wyrmling', 'Medium', 'Dragon', '', 'chaotic evil',
+        16, 'natural armor', 32, '5d8+10', '{"walk": 30, "fly": 60, "swim": 30, "climb": 0, "burrow": 15, "hover": false}',
+        14, 10, 14, 5, 10, 11, '[{"name": "Dex", "bonus": 2}, {"name": "Con", "bonus": 4}, {"name": "Wis", "bonus": 2}, {"name": "Cha", "bonus": 2}]', '[{"name": "Perception", "bonus": 4}, {"name": "Stealth", "bonus": 2}]',
+        '', '', 'cold', '',
+        'blindsight 10 ft., darkvision 60 ft., passive Perception 14', 'Draconic', 2, '["Arctic"]',
+        '[]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 7 (1d10 + 2) piercing damage plus 2 (1d4) cold damage.", "attack_bonus": 4, "damage": "1d10+2"}, {"name": "Cold Breath (Recharge 5-6)", "description": "The dragon exhales an icy blast in a 15-foot cone. Each creature in that area must make a DC 12 Constitution saving throw, taking 22 (5d8) cold damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "5d8"}]',
+        '[]', 0, '[]'),
+    ('will-o-wisp', 'Will-o''-Wisp', 'Tiny', 'Undead', '', 'chaotic evil',
+        19, '', 22, '9d4', '{"walk": 0, "fly": 50, "swim": 0, "climb": 0, "burrow": 0, "hover": true}',
+        1, 28, 10, 13, 14, 11, '[]', '[]',
+        '', 'acid, cold, fire, necrotic, thunder; bludgeoning, piercing, and slashing from nonmagical attacks', 'lightning, poison', 'exhaustion, grappled, paralyzed, poisoned, prone, restrained, unconscious',
+        'darkvision 120 ft., passive Perception 12', 'the languages it knew in life', 2, '["Forest", "Swamp"]',
+        '[{"name": "Consume Life", "description": "As a bonus action, the will-o''-wisp can target one creature it can see within 5 feet of it that has 0 hit points and is still alive. The target must succeed on a DC 10 Constitution saving throw against this magic or die. If the target dies, the will-o''-wisp regains 10 (3d6) hit points.", "attack_bonus": null, "damage": null}, {"name": "Ephemeral", "description": "The will-o''-wisp can''t wear or carry anything.", "attack_bonus": null, "damage": null}, {"name": "Incorporeal Movement", "description": "The will-o''-wisp can move through other creatures and objects as if they were difficult terrain. It takes 5 (1d10) force damage if it ends its turn inside an object.", "attack_bonus": null, "damage": null}, {"name": "Variable Illumination", "description": "The will-o''-wisp sheds bright light in a 5- to 20-foot radius and dim light for an additional number of feet equal to the chosen radius. The will-o''-wisp can alter the radius as a bonus action.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Shock", "description": "Melee Spell Attack: +4 to hit, reach 5 ft., one creature. Hit: 9 (2d8) lightning damage.", "attack_bonus": 4, "damage": "2d8"}, {"name": "Invisibility", "description": "The will-o''-wisp and its light magically become invisible until it attacks or uses its Consume Life, or until its concentration ends (as if concentrating on a spell).", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('basilisk', 'Basilisk', 'Medium', 'Monstrosity', '', 'unaligned',
+        15, 'natural armor', 52, '8d8+16', '{"walk": 20, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 8, 15, 2, 8, 7, '[]', '[]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 9', '', 3, '["Mountain", "Underdark"]',
+        '[{"name": "Petrifying Gaze", "description": "If a creature starts its turn within 30 feet of the basilisk and the two of them can see each other, the basilisk can force the creature to make a DC 12 Constitution saving throw if the basilisk isn''t incapacitated. On a failed save, the creature magically begins to turn to stone and is restrained. It must repeat the saving throw at the end of its next turn. On a success, the effect ends. On a failure, the creature is petrified until freed by the greater restoration spell or other magic. A creature that isn''t surprised can avert its eyes to avoid the saving throw at the start of its turn. If it does so, it can''t see the basilisk until the start of its next turn, when it can avert its eyes again. If it looks at the basilisk in the meantime, it must immediately make the save. If the basilisk sees its reflection within 30 feet of it in bright light, it mistakes itself for a rival and targets itself with its gaze.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 10 (2d6 + 3) piercing damage plus 7 (2d6) poison damage.", "attack_bonus": 5, "damage": "2d6+3"}]',
+        '[]', 0, '[]'),
+    ('bearded-devil', 'Bearded Devil', 'Medium', 'Fiend', 'devil', 'lawful evil',
+        13, 'natural armor', 52, '8d8+16', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 15, 15, 9, 11, 11, '[{"name": "Str", "bonus": 5}, {"name": "Con", "bonus": 4}, {"name": "Wis", "bonus": 2}]', '[]',
+        '', 'cold; bludgeoning, piercing, and slashing from nonmagical attacks that aren''t silvered', 'fire, poison', 'poisoned',
+        'darkvision 120 ft., passive Perception 10', 'Infernal, telepathy 120 ft.', 3, '[]',
+        '[{"name": "Devil''s Sight", "description": "Magical darkness doesn''t impede the devil''s darkvision.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The devil has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}, {"name": "Steadfast", "description": "The devil can''t be frightened while it can see an allied creature within 30 feet of it.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The devil makes two attacks: one with its beard and one with its glaive.", "attack_bonus": null, "damage": null}, {"name": "Beard", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one creature. Hit: 6 (1d8 + 2) piercing damage. The target must succeed on a DC 12 Constitution saving throw or be poisoned for 1 minute. While poisoned in this way, the target can''t regain hit points. The target can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success.", "attack_bonus": 5, "damage": "1d8+2"}, {"name": "Glaive", "description": "Melee Weapon Attack: +5 to hit, reach 10 ft., one target. Hit: 8 (1d10 + 3) slashing damage. If the target is a creature other than an undead or a construct, it must succeed on a DC 12 Constitution saving throw or lose 5 (1d10) hit points at the start of each of its turns due to an infernal wound. Each time the devil hits the wounded target with this attack, the damage dealt by the wound increases by 5 (1d10). Any creature can take an action to stanch the wound with a successful DC 12 Wisdom (Medicine) check. The wound also closes if the target receives magical healing.", "attack_bonus": 5, "damage": "1d10+3"}]',
+        '[]', 0, '[]'),
+    ('blue-dragon-wyrmling', 'Blue Dragon Wyrmling', 'Medium', 'Dragon', '', 'lawful evil',
+        17, 'natural armor', 52, '8d8+16', '{"walk": 30, "fly": 60, "swim": 0, "climb": 0, "burrow": 15, "hover": false}',
+        17, 10, 15, 12, 11, 15, '[{"name": "Dex", "bonus": 2}, {"name": "Con", "bonus": 4}, {"name": "Wis", "bonus": 2}, {"name": "Cha", "bonus": 4}]', '[{"name": "Perception", "bonus": 4}, {"name": "Stealth", "bonus": 2}]',
+        '', '', 'lightning', '',
+        'blindsight 10 ft., darkvision 60 ft., passive Perception 14', 'Draconic', 3, '["Desert", "Coastal"]',
+        '[]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 8 (1d10 + 3) piercing damage plus 3 (1d6) lightning damage.", "attack_bonus": 5, "damage": "1d10+3"}, {"name": "Lightning Breath (Recharge 5-6)", "description": "The dragon exhales lightning in a 30-foot line that is 5 feet wide. Each creature in that line must make a DC 12 Dexterity saving throw, taking 22 (4d10) lightning damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "4d10"}]',
+        '[]', 0, '[]'),
+    ('doppelganger', 'Doppelganger', 'Medium', 'Monstrosity', 'shapechanger', 'neutral',
+        14, '', 52, '8d8+16', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        11, 18, 14, 11, 12, 14, '[]', '[{"name": "Deception", "bonus": 6}, {"name": "Insight", "bonus": 3}]',
+        '', '', '', 'charmed',
+        'darkvision 60 ft., passive Perception 11', 'Common', 3, '["Urban"]',
+        '[{"name": "Shapechanger", "description": "The doppelganger can use its action to polymorph into a Small or Medium humanoid it has seen, or back into its true form. Its statistics, other than its size, are the same in each form. Any equipment it is wearing or carrying isn''t transformed. It reverts to its true form if it dies.", "attack_bonus": null, "damage": null}, {"name": "Ambusher", "description": "In the first round of a combat, the doppelganger has advantage on attack rolls against any creature it surprised.", "attack_bonus": null, "damage": null}, {"name": "Surprise Attack", "description": "If the doppelganger surprises a creature and hits it with an attack during the first round of combat, the target takes an extra 10 (3d6) damage from the attack.", "attack_bonus": null, "damage": "3d6"}]',
+        '[{"name": "Multiattack", "description": "The doppelganger makes two melee attacks.", "attack_bonus": null, "damage": null}, {"name": "Slam", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 7 (1d6 + 4) bludgeoning damage.", "attack_bonus": 6, "damage": "1d6+4"}, {"name": "Read Thoughts", "description": "The doppelganger magically reads the surface thoughts of one creature within 60 feet of it. The effect can penetrate barriers, but 3 feet of wood or dirt, 2 feet of stone, 2 inches of metal, or a thin sheet of lead blocks it. While the target is in range, the doppelganger can continue reading its thoughts, as long as the doppelganger''s concentration isn''t broken (as if concentrating on a spell). While reading the target''s mind, the doppelganger has advantage on Wisdom (Insight) and Charisma (Deception, Intimidation, and Persuasion) checks against the target.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('giant-scorpion', 'Giant Scorpion', 'Large', 'Beast', '', 'unaligned',
+        15, 'natural armor', 52, '7d10+14', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        15, 13, 15, 1, 9, 3, '[]', '[]',
+        '', '', '', '',
+        'blindsight 60 ft., passive Perception 9', '', 3, '["Desert"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The scorpion makes three attacks: two with its claws and one with its sting.", "attack_bonus": null, "damage": null}, {"name": "Claw", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 6 (1d8 + 2) bludgeoning damage. The target is grappled (escape DC 12). The scorpion has two claws, each of which can grapple only one target.", "attack_bonus": 4, "damage": "1d8+2"}, {"name": "Sting", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one creature. Hit: 7 (1d10 + 2) piercing damage. The target must make a DC 12 Constitution saving throw, taking 22 (4d10) poison damage on a failed save, or half as much damage on a successful one.", "attack_bonus": 4, "damage": "1d10+2"}]',
+        '[]', 0, '[]'),
+    ('gold-dragon-wyrmling', 'Gold Dragon Wyrmling', 'Medium', 'Dragon', '', 'lawful good',
+        17, 'natural armor', 60, '8d8+24', '{"walk": 30, "fly": 60, "swim": 30, "climb": 0, "burrow": 0, "hover": false}',
+        19, 14, 17, 14, 11, 16, '[{"name": "Dex", "bonus": 4}, {"name": "Con", "bonus": 5}, {"name": "Wis", "bonus": 2}, {"name": "Cha", "bonus": 5}]', '[{"name": "Perception", "bonus": 4}, {"name": "Stealth", "bonus": 4}]',
+        '', '', 'fire', '',
+        'blindsight 10 ft., darkvision 60 ft., passive Perception 14', 'Draconic', 3, '["Forest", "Grassland"]',
+        '[{"name": "Amphibious", "description": "The dragon can breathe air and water.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 9 (1d10 + 4) piercing damage.", "attack_bonus": 6, "damage": "1d10+4"}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Fire Breath. The dragon exhales fire in a 15-foot cone. Each creature in that area must make a DC 13 Dexterity saving throw, taking 22 (4d10) fire damage on a failed save, or half as much damage on a successful one. Weakening Breath. The dragon exhales gas in a 15-foot cone. Each creature in that area must succeed on a DC 13 Strength saving throw or have disadvantage on Strength-based attack rolls, Strength checks, and Strength saving throws for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success.", "attack_bonus": null, "damage": "4d10"}]',
+        '[]', 0, '[]'),
+    ('green-hag', 'Green Hag', 'Medium', 'Fey', '', 'neutral evil',
+        17, 'natural armor', 82, '11d8+33', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 12, 16, 13, 14, 14, '[]', '[{"name": "Arcana", "bonus": 3}, {"name": "Deception", "bonus": 4}, {"name": "Perception", "bonus": 4}, {"name": "Stealth", "bonus": 3}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 14', 'Common, Draconic, Sylvan', 3, '["Forest", "Swamp"]',
+        '[{"name": "Amphibious", "description": "The hag can breathe air and water.", "attack_bonus": null, "damage": null}, {"name": "Innate Spellcasting", "description": "The hag''s innate spellcasting ability is Charisma (spell save DC 12). She can innately cast the following spells, requiring no material components. At will: dancing lights, minor illusion, vicious mockery.", "attack_bonus": null, "damage": null}, {"name": "Mimicry", "description": "The hag can mimic animal sounds and humanoid voices. A creature that hears the sounds can tell they are imitations with a successful DC 14 Wisdom (Insight) check.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Claws", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 13 (2d8 + 4) slashing damage.", "attack_bonus": 6, "damage": "2d8+4"}, {"name": "Illusory Appearance", "description": "The hag covers herself and anything she is wearing or carrying with a magical illusion that makes her look like another creature of her general size and humanoid shape. The effect ends if the hag takes a bonus action to end it or if she dies. The changes wrought by this effect fail to hold up to physical inspection. To discern that the hag is disguised, a creature must take an action to visually inspect the illusion and succeed on a DC 20 Intelligence (Investigation) check.", "attack_bonus": null, "damage": null}, {"name": "Invisible Passage", "description": "The hag magically turns invisible until she attacks or casts a spell, or until her concentration ends (as if concentrating on a spell). While invisible, she leaves no physical evidence of her passage, so she can be tracked only by magic. Any equipment she wears or carries is invisible with her.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('hell-hound', 'Hell Hound', 'Medium', 'Fiend', '', 'lawful evil',
+        15, 'natural armor', 45, '7d8+14', '{"walk": 50, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        17, 12, 14, 6, 13, 6, '[]', '[{"name": "Perception", "bonus": 5}]',
+        '', '', 'fire', '',
+        'darkvision 60 ft., passive Perception 15', 'understands Infernal but can''t speak it', 3, '["Mountain", "Underdark"]',
+        '[{"name": "Keen Hearing and Smell", "description": "The hound has advantage on Wisdom (Perception) checks that rely on hearing or smell.", "attack_bonus": null, "damage": null}, {"name": "Pack Tactics", "description": "The hound has advantage on an attack roll against a creature if at least one of the hound''s allies is within 5 feet of the creature and the ally isn''t incapacitated.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 7 (1d8 + 3) piercing damage plus 7 (2d6) fire damage.", "attack_bonus": 5, "damage": "1d8+3"}, {"name": "Fire Breath (Recharge 5-6)", "description": "The hound exhales fire in a 15-foot cone. Each creature in that area must make a DC 12 Dexterity saving throw, taking 21 (6d6) fire damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "6d6"}]',
+        '[]', 0, '[]'),
+    ('killer-whale', 'Killer Whale', 'Huge', 'Beast', '', 'unaligned',
+        12, 'natural armor', 90, '12d12+12', '{"walk": 0, "fly": 0, "swim": 60, "climb": 0, "burrow": 0, "hover": false}',
+        19, 10, 13, 3, 12, 7, '[]', '[{"name": "Perception", "bonus": 3}]',
+        '', '', '', '',
+        'blindsight 120 ft., passive Perception 13', '', 3, '["Arctic", "Underwater"]',
+        '[{"name": "Echolocation", "description": "The whale can''t use its blindsight while deafened.", "attack_bonus": null, "damage": null}, {"name": "Hold Breath", "description": "The whale can hold its breath for 30 minutes.", "attack_bonus": null, "damage": null}, {"name": "Keen Hearing", "description": "The whale has advantage on Wisdom (Perception) checks that rely on hearing.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 21 (5d6 + 4) piercing damage.", "attack_bonus": 6, "damage": "5d6+4"}]',
+        '[]', 0, '[]'),
+    ('knight', 'Knight', 'Medium', 'Humanoid', 'any race', 'any alignment',
+        18, 'plate', 52, '8d8+16', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 11, 14, 11, 11, 15, '[{"name": "Con", "bonus": 4}, {"name": "Wis", "bonus": 2}]', '[]',
+        '', '', '', '',
+        'passive Perception 10', 'any one language (usually Common)', 3, '["Urban", "Grassland"]',
+        '[{"name": "Brave", "description": "The knight has advantage on saving throws against being frightened.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The knight makes two melee attacks.", "attack_bonus": null, "damage": null}, {"name": "Greatsword", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 10 (2d6 + 3) slashing damage.", "attack_bonus": 5, "damage": "2d6+3"}, {"name": "Heavy Crossbow", "description": "Ranged Weapon Attack: +2 to hit, range 100/400 ft., one target. Hit: 5 (1d10) piercing damage.", "attack_bonus": 2, "damage": "1d10"}, {"name": "Leadership (Recharges after a Short or Long Rest)", "description": "For 1 minute, the knight can utter a special command or warning whenever a nonhostile creature that it can see within 30 feet of it makes an attack roll or a saving throw. The creature can add a d4 to its roll provided it can hear and understand the knight. A creature can benefit from only one Leadership die at a time. This effect ends if the knight is incapacitated.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Parry", "description": "The knight adds 2 to its AC against one melee attack that would hit it. To do so, the knight must see the attacker and be wielding a melee weapon.", "attack_bonus": null, "damage": null}]', 0, '[]'),
+    ('manticore', 'Manticore', 'Large', 'Monstrosity', '', 'lawful evil',
+        14, 'natural armor', 68, '8d10+24', '{"walk": 30, "fly": 50, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        17, 16, 17, 7, 12, 8, '[]', '[]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 11', 'Common', 3, '["Grassland", "Hill", "Mountain"]',
+        '[{"name": "Tail Spike Regrowth", "description": "The manticore has twenty-four tail spikes. Used spikes regrow when the manticore finishes a long rest.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The manticore makes three attacks: one with its bite and two with its claws or three with its tail spikes.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 7 (1d8 + 3) piercing damage.", "attack_bonus": 5, "damage": "1d8+3"}, {"name": "Claw", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 6 (1d6 + 3) slashing damage.", "attack_bonus": 5, "damage": "1d6+3"}, {"name": "Tail Spike", "description": "Ranged Weapon Attack: +5 to hit, range 100/200 ft., one target. Hit: 7 (1d8 + 3) piercing damage.", "attack_bonus": 5, "damage": "1d8+3"}]',
+        '[]', 0, '[]'),
+    ('nightmare', 'Nightmare', 'Large', 'Fiend', '', 'neutral evil',
+        13, 'natural armor', 68, '8d10+24', '{"walk": 60, "fly": 90, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 15, 16, 10, 13, 15, '[]', '[]',
+        '', '', 'fire', '',
+        'passive Perception 11', 'understands Abyssal, Common, and Infernal but can''t speak', 3, '[]',
+        '[{"name": "Confer Fire Resistance", "description": "The nightmare can grant resistance to fire damage to anyone riding it.", "attack_bonus": null, "damage": null}, {"name": "Illumination", "description": "The nightmare sheds bright light in a 10-foot radius and dim light for an additional 10 feet.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Hooves", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 13 (2d8 + 4) bludgeoning damage plus 7 (2d6) fire damage.", "attack_bonus": 6, "damage": "2d8+4"}, {"name": "Ethereal Stride", "description": "The nightmare and up to three willing creatures within 5 feet of it magically enter the Ethereal Plane from the Material Plane, or vice versa.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('phase-spider', 'Phase Spider', 'Large', 'Monstrosity', '', 'unaligned',
+        13, 'natural armor', 32, '5d10+5', '{"walk": 30, "fly": 0, "swim": 0, "climb": 30, "burrow": 0, "hover": false}',
+        15, 15, 12, 6, 10, 6, '[]', '[{"name": "Stealth", "bonus": 6}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 10', '', 3, '["Forest", "Underdark"]',
+        '[{"name": "Ethereal Jaunt", "description": "As a bonus action, the spider can magically shift from the Material Plane to the Ethereal Plane, or vice versa.", "attack_bonus": null, "damage": null}, {"name": "Spider Climb", "description": "The spider can climb difficult surfaces, including upside down on ceilings, without needing to make an ability check.", "attack_bonus": null, "damage": null}, {"name": "Web Walker", "description": "The spider ignores movement restrictions caused by webbing.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one creature. Hit: 7 (1d10 + 2) piercing damage. The target must make a DC 11 Constitution saving throw, taking 18 (4d8) poison damage on a failed save, or half as much damage on a successful one. If the poison damage reduces the target to 0 hit points, the target is stable but poisoned for 1 hour, even after regaining hit points, and is paralyzed while poisoned in this way.", "attack_bonus": 4, "damage": "1d10+2"}]',
+        '[]', 0, '[]'),
+    ('werewolf', 'Werewolf', 'Medium', 'Humanoid', 'human, shapechanger', 'chaotic evil',
+        11, 'in humanoid form, 12 (natural armor) in wolf or hybrid form', 58, '9d8+18', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        15, 13, 14, 10, 11, 10, '[]', '[{"name": "Perception", "bonus": 4}, {"name": "Stealth", "bonus": 3}]',
+        '', '', 'bludgeoning, piercing, and slashing from nonmagical attacks that aren''t silvered', '',
+        'passive Perception 14', 'Common (can''t speak in wolf form)', 3, '["Forest", "Hill"]',
+        '[{"name": "Shapechanger", "description": "The werewolf can use its action to polymorph into a wolf-humanoid hybrid, or into a wolf (speed 40 ft.), or back into its true form, which is humanoid. Its statistics, other than its size and AC, are the same in each form. Any equipment it is wearing or carrying isn''t transformed. It reverts to its true form if it dies.", "attack_bonus": null, "damage": null}, {"name": "Keen Hearing and Smell", "description": "The werewolf has advantage on Wisdom (Perception) checks that rely on hearing or smell.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The werewolf makes two attacks: two with its spear (humanoid form) or one with its bite and one with its claws (hybrid form).", "attack_bonus": null, "damage": null}, {"name": "Bite (Wolf or Hybrid Form Only)", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one target. Hit: 6 (1d8 + 2) piercing damage. If the target is a humanoid, it must succeed on a DC 12 Constitution saving throw or be cursed with werewolf lycanthropy.", "attack_bonus": 4, "damage": "1d8+2"}, {"name": "Claws (Hybrid Form Only)", "description": "Melee Weapon Attack: +4 to hit, reach 5 ft., one creature. Hit: 7 (2d4 + 2) slashing damage.", "attack_bonus": 4, "damage": "2d4+2"}, {"name": "Spear (Humanoid Form Only)", "description": "Melee or Ranged Weapon Attack: +4 to hit, reach 5 ft. or range 20/60 ft., one creature. Hit: 5 (1d6 + 2) piercing damage, or 6 (1d8 + 2) piercing damage if used with two hands to make a melee attack.", "attack_bonus": 4, "damage": "1d6+2"}]',
+        '[]', 0, '[]'),
+    ('winter-wolf', 'Winter Wolf', 'Large', 'Monstrosity', '', 'neutral evil',
+        13, 'natural armor', 75, '10d10+20', '{"walk": 50, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 13, 14, 7, 12, 8, '[]', '[{"name": "Perception", "bonus": 5}, {"name": "Stealth", "bonus": 3}]',
+        '', '', 'cold', '',
+        'passive Perception 15', 'Common, Giant, Winter Wolf', 3, '["Arctic"]',
+        '[{"name": "Keen Hearing and Smell", "description": "The wolf has advantage on Wisdom (Perception) checks that rely on hearing or smell.", "attack_bonus": null, "damage": null}, {"name": "Pack Tactics", "description": "The wolf has advantage on an attack roll against a creature if at least one of the wolf''s allies is within 5 feet of the creature and the ally isn''t incapacitated.", "attack_bonus": null, "damage": null}, {"name": "Snow Camouflage", "description": "The wolf has advantage on Dexterity (Stealth) checks made to hide in snowy terrain.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 11 (2d6 + 4) piercing damage. If the target is a creature, it must succeed on a DC 14 Strength saving throw or be knocked prone.", "attack_bonus": 6, "damage": "2d6+4"}, {"name": "Cold Breath (Recharge 5-6)", "description": "The wolf exhales a blast of freezing wind in a 15-foot cone. Each creature in that area must make a DC 12 Dexterity saving throw, taking 18 (4d8) cold damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "4d8"}]',
+        '[]', 0, '[]'),
+    ('black-pudding', 'Black Pudding', 'Large', 'Ooze', '', 'unaligned',
+        7, '', 85, '10d10+30', '{"walk": 20, "fly": 0, "swim": 0, "climb": 20, "burrow": 0, "hover": false}',
+        16, 5, 16, 1, 6, 1, '[]', '[]',
+        '', '', 'acid, cold, lightning, slashing', 'blinded, charmed, deafened, exhaustion, frightened, prone',
+        'blindsight 60 ft. (blind beyond this radius), passive Perception 8', '', 4, '["Underdark"]',
+        '[{"name": "Amorphous", "description": "The pudding can move through a space as narrow as 1 inch wide without squeezing.", "attack_bonus": null, "damage": null}, {"name": "Corrosive Form", "description": "A creature that touches the pudding or hits it with a melee attack while within 5 feet of it takes 4 (1d8) acid damage. Any nonmagical weapon made of metal or wood that hits the pudding corrodes. After dealing damage, the weapon takes a permanent and cumulative -1 penalty to damage rolls. If its penalty drops to -5, the weapon is destroyed. Nonmagical ammunition made of metal or wood that hits the pudding is destroyed after dealing damage. The pudding can eat through 2-inch-thick, nonmagical wood or metal in 1 round.", "attack_bonus": null, "damage": "1d8"}, {"name": "Spider Climb", "description": "The pudding can climb difficult surfaces, including upside down on ceilings, without needing to make an ability check.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Pseudopod", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 6 (1d6 + 3) bludgeoning damage plus 18 (4d8) acid damage. In addition, nonmagical armor worn by the target is partly dissolved and takes a permanent and cumulative -1 penalty to the AC it offers. The armor is destroyed if the penalty reduces its AC to 10.", "attack_bonus": 5, "damage": "1d6+3"}]',
+        '[{"name": "Split", "description": "When a pudding that is Medium or larger is subjected to lightning or slashing damage, it splits into two new puddings if it has at least 10 hit points. Each new pudding has hit points equal to half the original pudding''s, rounded down. New puddings are one size smaller than the original pudding.", "attack_bonus": null, "damage": null}]', 0, '[]'),
+    ('chuul', 'Chuul', 'Large', 'Aberration', '', 'chaotic evil',
+        16, 'natural armor', 93, '11d10+33', '{"walk": 30, "fly": 0, "swim": 30, "climb": 0, "burrow": 0, "hover": false}',
+        19, 10, 16, 5, 11, 5, '[]', '[{"name": "Perception", "bonus": 4}]',
+        '', '', 'poison', 'poisoned',
+        'darkvision 60 ft., passive Perception 14', 'understands Deep Speech but can''t speak', 4, '["Swamp", "Underdark", "Underwater"]',
+        '[{"name": "Amphibious", "description": "The chuul can breathe air and water.", "attack_bonus": null, "damage": null}, {"name": "Sense Magic", "description": "The chuul senses magic within 120 feet of it at will. This trait otherwise works like the detect magic spell but isn''t itself magical.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The chuul makes two pincer attacks. If the chuul is grappling a creature, the chuul can also use its tentacles once.", "attack_bonus": null, "damage": null}, {"name": "Pincer", "description": "Melee Weapon Attack: +6 to hit, reach 10 ft., one target. Hit: 11 (2d6 + 4) bludgeoning damage. The target is grappled (escape DC 14) if it is a Large or smaller creature and the chuul doesn''t have two other creatures grappled.", "attack_bonus": 6, "damage": "2d6+4"}, {"name": "Tentacles", "description": "One creature grappled by the chuul must succeed on a DC 13 Constitution saving throw or be poisoned for 1 minute. Until this poison ends, the target is paralyzed. The target can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('couatl', 'Couatl', 'Medium', 'Celestial', '', 'lawful good',
+        19, 'natural armor', 97, '13d8+39', '{"walk": 30, "fly": 90, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 20, 17, 18, 20, 18, '[{"name": "Con", "bonus": 5}, {"name": "Wis", "bonus": 7}, {"name": "Cha", "bonus": 6}]', '[]',
+        '', 'radiant', 'psychic; bludgeoning, piercing, and slashing from nonmagical attacks', '',
+        'truesight 120 ft., passive Perception 15', 'all, telepathy 120 ft.', 4, '["Desert", "Forest", "Grassland"]',
+        '[{"name": "Innate Spellcasting", "description": "The couatl''s spellcasting ability is Charisma (spell save DC 14). It can innately cast the following spells, requiring only verbal components. At will: detect evil and good, detect magic, detect thoughts. 3/day each: bless, create food and water, cure wounds, lesser restoration, protection from poison, sanctuary, shield. 1/day each: dream, greater restoration, scrying.", "attack_bonus": null, "damage": null}, {"name": "Magic Weapons", "description": "The couatl''s weapon attacks are magical.", "attack_bonus": null, "damage": null}, {"name": "Shielded Mind", "description": "The couatl is immune to scrying and to any effect that would sense its emotions, read its thoughts, or detect its location.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +8 to hit, reach 5 ft., one creature. Hit: 8 (1d6 + 5) piercing damage. The target must succeed on a DC 13 Constitution saving throw or be poisoned for 24 hours. Until this poison ends, the target is unconscious. Another creature can use an action to shake the target awake.", "attack_bonus": 8, "damage": "1d6+5"}, {"name": "Constrict", "description": "Melee Weapon Attack: +6 to hit, reach 10 ft., one Medium or smaller creature. Hit: 10 (2d6 + 3) bludgeoning damage. The target is grappled (escape DC 15). Until this grapple ends, the target is restrained, and the couatl can''t constrict another target.", "attack_bonus": 6, "damage": "2d6+3"}, {"name": "Change Shape", "description": "The couatl magically polymorphs into a humanoid or beast that has a challenge rating equal to or less than its own, or back into its true form. It reverts to its true form if it dies. Any equipment it is wearing or carrying is absorbed or borne by the new form (the couatl''s choice). In a new form, the couatl retains its game statistics and ability to speak, but its AC, movement modes, Strength, Dexterity, and other actions are replaced by those of the new form, and it gains any statistics and capabilities (except class features, legendary actions, and lair actions) that the new form has but that it lacks.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('elephant', 'Elephant', 'Huge', 'Beast', '', 'unaligned',
+        12, 'natural armor', 76, '8d12+24', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        22, 9, 17, 3, 11, 6, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 10', '', 4, '["Grassland"]',
+        '[{"name": "Trampling Charge", "description": "If the elephant moves at least 20 feet straight toward a creature and then hits it with a gore attack on the same turn, that target must succeed on a DC 12 Strength saving throw or be knocked prone. If the target is prone, the elephant can make one stomp attack against it as a bonus action.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Gore", "description": "Melee Weapon Attack: +8 to hit, reach 5 ft., one target. Hit: 19 (3d8 + 6) piercing damage.", "attack_bonus": 8, "damage": "3d8+6"}, {"name": "Stomp", "description": "Melee Weapon Attack: +8 to hit, reach 5 ft., one prone creature. Hit: 22 (3d10 + 6) bludgeoning damage.", "attack_bonus": 8, "damage": "3d10+6"}]',
+        '[]', 0, '[]'),
+    ('ettin', 'Ettin', 'Large', 'Giant', '', 'chaotic evil',
+        12, 'natural armor', 85, '10d10+30', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        21, 8, 17, 6, 10, 8, '[]', '[{"name": "Perception", "bonus": 4}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 14', 'Giant, Orc', 4, '["Hill", "Mountain", "Underdark"]',
+        '[{"name": "Two Heads", "description": "The ettin has advantage on Wisdom (Perception) checks and on saving throws against being blinded, charmed, deafened, frightened, stunned, and knocked unconscious.", "attack_bonus": null, "damage": null}, {"name": "Wakeful", "description": "When one of the ettin''s heads is asleep, its other head is awake.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The ettin makes two attacks: one with its battleaxe and one with its morningstar.", "attack_bonus": null, "damage": null}, {"name": "Battleaxe", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 14 (2d8 + 5) slashing damage.", "attack_bonus": 7, "damage": "2d8+5"}, {"name": "Morningstar", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 14 (2d8 + 5) piercing damage.", "attack_bonus": 7, "damage": "2d8+5"}]',
+        '[]', 0, '[]'),
+    ('lamia', 'Lamia', 'Large', 'Monstrosity', '', 'chaotic evil',
+        13, 'natural armor', 97, '13d10+26', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 13, 15, 14, 15, 16, '[]', '[{"name": "Deception", "bonus": 7}, {"name": "Insight", "bonus": 4}, {"name": "Stealth", "bonus": 3}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 12', 'Abyssal, Common', 4, '["Desert"]',
+        '[{"name": "Innate Spellcasting", "description": "The lamia''s innate spellcasting ability is Charisma (spell save DC 13). It can innately cast the following spells, requiring no material components. At will: disguise self (any humanoid form), major image. 3/day each: charm person, mirror image, scrying, suggestion. 1/day: geas.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The lamia makes two attacks: one with its claws and one with its dagger or Intoxicating Touch.", "attack_bonus": null, "damage": null}, {"name": "Claws", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 14 (2d10 + 3) slashing damage.", "attack_bonus": 5, "damage": "2d10+3"}, {"name": "Dagger", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 5 (1d4 + 3) piercing damage.", "attack_bonus": 5, "damage": "1d4+3"}, {"name": "Intoxicating Touch", "description": "Melee Spell Attack: +5 to hit, reach 5 ft., one creature. Hit: The target is magically cursed for 1 hour. Until the curse ends, the target has disadvantage on Wisdom saving throws and all ability checks.", "attack_bonus": 5, "damage": null}]',
+        '[]', 0, '[]'),
+    ('red-dragon-wyrmling', 'Red Dragon Wyrmling', 'Medium', 'Dragon', '', 'chaotic evil',
+        17, 'natural armor', 75, '10d8+30', '{"walk": 30, "fly": 60, "swim": 0, "climb": 30, "burrow": 0, "hover": false}',
+        19, 10, 17, 12, 11, 15, '[{"name": "Dex", "bonus": 2}, {"name": "Con", "bonus": 5}, {"name": "Wis", "bonus": 2}, {"name": "Cha", "bonus": 4}]', '[{"name": "Perception", "bonus": 4}, {"name": "Stealth", "bonus": 2}]',
+        '', '', 'fire', '',
+        'blindsight 10 ft., darkvision 60 ft., passive Perception 14', 'Draconic', 4, '["Mountain", "Hill"]',
+        '[]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 9 (1d10 + 4) piercing damage plus 3 (1d6) fire damage.", "attack_bonus": 6, "damage": "1d10+4"}, {"name": "Fire Breath (Recharge 5-6)", "description": "The dragon exhales fire in a 15-foot cone. Each creature in that area must make a DC 13 Dexterity saving throw, taking 24 (7d6) fire damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "7d6"}]',
+        '[]', 0, '[]'),
+    ('succubus-incubus', 'Succubus/Incubus', 'Medium', 'Fiend', 'shapechanger', 'neutral evil',
+        15, 'natural armor', 66, '12d8+12', '{"walk": 30, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        8, 17, 13, 15, 12, 20, '[]', '[{"name": "Deception", "bonus": 9}, {"name": "Insight", "bonus": 5}, {"name": "Perception", "bonus": 5}, {"name": "Persuasion", "bonus": 9}, {"name": "Stealth", "bonus": 7}]',
+        '', 'cold, fire, lightning, poison; bludgeoning, piercing, and slashing from nonmagical attacks', '', '',
+        'darkvision 60 ft., passive Perception 15', 'Abyssal, Common, Infernal, telepathy 60 ft.', 4, '["Urban"]',
+        '[{"name": "Telepathic Bond", "description": "The fiend ignores the range restriction on its telepathy when communicating with a creature it has charmed. The two don''t even need to be on the same plane of existence.", "attack_bonus": null, "damage": null}, {"name": "Shapechanger", "description": "The fiend can use its action to polymorph into a Small or Medium humanoid, or back into its true form. Without wings, the fiend loses its flying speed. Other than its size and speed, its statistics are the same in each form. Any equipment it is wearing or carrying isn''t transformed. It reverts to its true form if it dies.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Claw (Fiend Form Only)", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 6 (1d6 + 3) slashing damage.", "attack_bonus": 5, "damage": "1d6+3"}, {"name": "Charm", "description": "One humanoid the fiend can see within 30 feet of it must succeed on a DC 15 Wisdom saving throw or be magically charmed for 1 day. The charmed target obeys the fiend''s verbal or telepathic commands. If the target suffers any harm or receives a suicidal command, it can repeat the saving throw, ending the effect on a success. If the target successfully saves against the effect, or if the effect on it ends, the target is immune to this fiend''s Charm for the next 24 hours. The fiend can have only one target charmed at a time. If it charms another, the effect on the previous target ends.", "attack_bonus": null, "damage": null}, {"name": "Draining Kiss", "description": "The fiend kisses a creature charmed by it or a willing creature. The target must make a DC 15 Constitution saving throw against this magic, taking 32 (5d10 + 5) psychic damage on a failed save, or half as much damage on a successful one. The target''s hit point maximum is reduced by an amount equal to the damage taken. This reduction lasts until the target finishes a long rest. The target dies if this effect reduces its hit point maximum to 0.", "attack_bonus": null, "damage": "5d10+5"}, {"name": "Etherealness", "description": "The fiend magically enters the Ethereal Plane from the Material Plane, or vice versa.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('wereboar', 'Wereboar', 'Medium', 'Humanoid', 'human, shapechanger', 'neutral evil',
+        10, 'in humanoid form, 11 (natural armor) in boar or hybrid form', 78, '12d8+24', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        17, 10, 15, 10, 11, 8, '[]', '[{"name": "Perception", "bonus": 2}]',
+        '', '', 'bludgeoning, piercing, and slashing from nonmagical attacks that aren''t silvered', '',
+        'passive Perception 12', 'Common (can''t speak in boar form)', 4, '["Forest", "Grassland"]',
+        '[{"name": "Shapechanger", "description": "The wereboar can use its action to polymorph into a boar-humanoid hybrid, or into a boar (speed 40 ft.), or back into its true form, which is humanoid. Its statistics, other than its size and AC, are the same in each form. Any equipment it is wearing or carrying isn''t transformed. It reverts to its true form if it dies.", "attack_bonus": null, "damage": null}, {"name": "Charge (Boar or Hybrid Form Only)", "description": "If the wereboar moves at least 15 feet straight toward a target and then hits it with its tusks on the same turn, the target takes an extra 7 (2d6) slashing damage. If the target is a creature, it must succeed on a DC 13 Strength saving throw or be knocked prone.", "attack_bonus": null, "damage": "2d6"}, {"name": "Relentless (Recharges after a Short or Long Rest)", "description": "If the wereboar takes 14 damage or less that would reduce it to 0 hit points, it is reduced to 1 hit point instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The wereboar makes two attacks, only one of which can be with its tusks. (Humanoid or Hybrid Form Only)", "attack_bonus": null, "damage": null}, {"name": "Maul (Humanoid or Hybrid Form Only)", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 10 (2d6 + 3) bludgeoning damage.", "attack_bonus": 5, "damage": "2d6+3"}, {"name": "Tusks (Boar or Hybrid Form Only)", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 10 (2d6 + 3) slashing damage. If the target is a humanoid, it must succeed on a DC 12 Constitution saving throw or be cursed with wereboar lycanthropy.", "attack_bonus": 5, "damage": "2d6+3"}]',
+        '[]', 0, '[]'),
+    ('weretiger', 'Weretiger', 'Medium', 'Humanoid', 'human, shapechanger', 'neutral',
+        12, '', 120, '16d8+48', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        17, 15, 16, 10, 13, 11, '[]', '[{"name": "Perception", "bonus": 5}, {"name": "Stealth", "bonus": 4}]',
+        '', '', 'bludgeoning, piercing, and slashing from nonmagical attacks that aren''t silvered', '',
+        'darkvision 60 ft., passive Perception 15', 'Common (can''t speak in tiger form)', 4, '["Forest", "Grassland"]',
+        '[{"name": "Shapechanger", "description": "The weretiger can use its action to polymorph into a tiger-humanoid hybrid, or into a tiger (speed 40 ft.), or back into its true form, which is humanoid. Its statistics, other than its size and AC, are the same in each form. Any equipment it is wearing or carrying isn''t transformed. It reverts to its true form if it dies.", "attack_bonus": null, "damage": null}, {"name": "Keen Hearing and Smell", "description": "The weretiger has advantage on Wisdom (Perception) checks that rely on hearing or smell.", "attack_bonus": null, "damage": null}, {"name": "Pounce (Tiger or Hybrid Form Only)", "description": "If the weretiger moves at least 15 feet straight toward a creature and then hits it with a claw attack on the same turn, that target must succeed on a DC 14 Strength saving throw or be knocked prone. If the target is prone, the weretiger can make one bite attack against it as a bonus action.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "In humanoid form, the weretiger makes two scimitar attacks or two longbow attacks. In hybrid form, it can attack like a humanoid or make two claw attacks.", "attack_bonus": null, "damage": null}, {"name": "Bite (Tiger or Hybrid Form Only)", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 8 (1d10 + 3) piercing damage. If the target is a humanoid, it must succeed on a DC 13 Constitution saving throw or be cursed with weretiger lycanthropy.", "attack_bonus": 5, "damage": "1d10+3"}, {"name": "Claw (Tiger or Hybrid Form Only)", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 7 (1d8 + 3) slashing damage.", "attack_bonus": 5, "damage": "1d8+3"}, {"name": "Scimitar (Humanoid or Hybrid Form Only)", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 6 (1d6 + 3) slashing damage.", "attack_bonus": 5, "damage": "1d6+3"}, {"name": "Longbow (Humanoid or Hybrid Form Only)", "description": "Ranged Weapon Attack: +4 to hit, range 150/600 ft., one target. Hit: 6 (1d8 + 2) piercing damage.", "attack_bonus": 4, "damage": "1d8+2"}]',
+        '[]', 0, '[]'),
+    ('air-elemental', 'Air Elemental', 'Large', 'Elemental', '', 'neutral',
+        15, '', 90, '12d10+24', '{"walk": 0, "fly": 90, "swim": 0, "climb": 0, "burrow": 0, "hover": true}',
+        14, 20, 14, 6, 10, 6, '[]', '[]',
+        '', 'lightning, thunder; bludgeoning, piercing, and slashing from nonmagical attacks', 'poison', 'exhaustion, grappled, paralyzed, petrified, poisoned, prone, restrained, unconscious',
+        'darkvision 60 ft., passive Perception 10', 'Auran', 5, '["Desert", "Mountain"]',
+        '[{"name": "Air Form", "description": "The elemental can enter a hostile creature''s space and stop there. It can move through a space as narrow as 1 inch wide without squeezing.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The elemental makes two slam attacks.", "attack_bonus": null, "damage": null}, {"name": "Slam", "description": "Melee Weapon Attack: +8 to hit, reach 5 ft., one target. Hit: 14 (2d8 + 5) bludgeoning damage.", "attack_bonus": 8, "damage": "2d8+5"}, {"name": "Whirlwind (Recharge 4-6)", "description": "Each creature in the elemental''s space must make a DC 13 Strength saving throw. On a failure, a target takes 15 (3d8 + 2) bludgeoning damage and is flung up 20 feet away from the elemental in a random direction and knocked prone. If a thrown target strikes an object, such as a wall or floor, the target takes 3 (1d6) bludgeoning damage for every 10 feet it was thrown. If the target is thrown at another creature, that creature must succeed on a DC 13 Dexterity saving throw or take the same damage and be knocked prone. If the saving throw is successful, the target takes half the bludgeoning damage and isn''t flung away or knocked prone.", "attack_bonus": null, "damage": "3d8+2"}]',
+        '[]', 0, '[]'),
+    ('barbed-devil', 'Barbed Devil', 'Medium', 'Fiend', 'devil', 'lawful evil',
+        15, 'natural armor', 110, '13d8+52', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 17, 18, 12, 14, 14, '[{"name": "Str", "bonus": 6}, {"name": "Con", "bonus": 7}, {"name": "Wis", "bonus": 5}, {"name": "Cha", "bonus": 5}]', '[{"name": "Deception", "bonus": 5}, {"name": "Insight", "bonus": 5}, {"name": "Perception", "bonus": 8}]',
+        '', 'cold; bludgeoning, piercing, and slashing from nonmagical attacks that aren''t silvered', 'fire, poison', 'poisoned',
+        'darkvision 120 ft., passive Perception 18', 'Infernal, telepathy 120 ft.', 5, '[]',
+        '[{"name": "Barbed Hide", "description": "At the start of each of its turns, the barbed devil deals 5 (1d10) piercing damage to any creature grappling it.", "attack_bonus": null, "damage": "1d10"}, {"name": "Devil''s Sight", "description": "Magical darkness doesn''t impede the devil''s darkvision.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The devil has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The devil makes three melee attacks: one with its tail and two with its claws. Alternatively, it can use Hurl Flame twice.", "attack_bonus": null, "damage": null}, {"name": "Claw", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 6 (1d6 + 3) piercing damage.", "attack_bonus": 6, "damage": "1d6+3"}, {"name": "Tail", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 10 (2d6 + 3) piercing damage.", "attack_bonus": 6, "damage": "2d6+3"}, {"name": "Hurl Flame", "description": "Ranged Spell Attack: +5 to hit, range 150 ft., one target. Hit: 10 (3d6) fire damage. If the target is a flammable object that isn''t being worn or carried, it also catches fire.", "attack_bonus": 5, "damage": "3d6"}]',
+        '[]', 0, '[]'),
+    ('bulette', 'Bulette', 'Large', 'Monstrosity', '', 'unaligned',
+        17, 'natural armor', 94, '9d10+45', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 40, "hover": false}',
+        19, 11, 21, 2, 10, 5, '[]', '[{"name": "Perception", "bonus": 6}]',
+        '', '', '', '',
+        'darkvision 60 ft., tremorsense 60 ft., passive Perception 16', '', 5, '["Grassland", "Hill", "Mountain"]',
+        '[{"name": "Standing Leap", "description": "The bulette''s long jump is up to 30 feet and its high jump is up to 15 feet, with or without a running start.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 30 (4d12 + 4) piercing damage.", "attack_bonus": 7, "damage": "4d12+4"}, {"name": "Deadly Leap", "description": "If the bulette jumps at least 15 feet as part of its movement, it can then use this action to land on its feet in a space that contains one or more other creatures. Each of those creatures must succeed on a DC 16 Strength or Dexterity saving throw (target''s choice) or be knocked prone and take 14 (3d6 + 4) bludgeoning damage plus 14 (3d6 + 4) slashing damage. On a successful save, the creature takes only half the damage, isn''t knocked prone, and is pushed 5 feet out of the bulette''s space into an unoccupied space of the creature''s choice. If no unoccupied space is within range, the creature instead falls prone in the bulette''s space.", "attack_bonus": null, "damage": "3d6+4"}]',
+        '[]', 0, '[]'),
+    ('earth-elemental', 'Earth Elemental', 'Large', 'Elemental', '', 'neutral',
+        17, 'natural armor', 126, '12d10+60', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 30, "hover": false}',
+        20, 8, 20, 5, 10, 5, '[]', '[]',
+        'thunder', 'bludgeoning, piercing, and slashing from nonmagical attacks', 'poison', 'exhaustion, paralyzed, petrified, poisoned, unconscious',
+        'darkvision 60 ft., tremorsense 60 ft., passive Perception 10', 'Terran', 5, '["Mountain", "Underdark"]',
+        '[{"name": "Earth Glide", "description": "The elemental can burrow through nonmagical, unworked earth and stone. While doing so, the elemental doesn''t disturb the material it moves through.", "attack_bonus": null, "damage": null}, {"name": "Siege Monster", "description": "The elemental deals double damage to objects and structures.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The elemental makes two slam attacks.", "attack_bonus": null, "damage": null}, {"name": "Slam", "description": "Melee Weapon Attack: +8 to hit, reach 10 ft., one target. Hit: 14 (2d8 + 5) bludgeoning damage.", "attack_bonus": 8, "damage": "2d8+5"}]',
+        '[]', 0, '[]'),
+    ('fire-elemental', 'Fire Elemental', 'Large', 'Elemental', '', 'neutral',
+        13, '', 102, '12d10+36', '{"walk": 50, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        10, 17, 16, 6, 10, 7, '[]', '[]',
+        '', 'bludgeoning, piercing, and slashing from nonmagical attacks', 'fire, poison', 'exhaustion, grappled, paralyzed, petrified, poisoned, prone, restrained, unconscious',
+        'darkvision 60 ft., passive Perception 10', 'Ignan', 5, '["Desert", "Mountain"]',
+        '[{"name": "Fire Form", "description": "The elemental can move through a space as narrow as 1 inch wide without squeezing. A creature that touches the elemental or hits it with a melee attack while within 5 feet of it takes 5 (1d10) fire damage. In addition, the elemental can enter a hostile creature''s space and stop there. The first time it enters a creature''s space on a turn, that creature takes 5 (1d10) fire damage and catches fire; until someone takes an action to douse the fire, the creature takes 5 (1d10) fire damage at the start of each of its turns.", "attack_bonus": null, "damage": "1d10"}, {"name": "Illumination", "description": "The elemental sheds bright light in a 30-foot radius and dim light in an additional 30 feet.", "attack_bonus": null, "damage": null}, {"name": "Water Susceptibility", "description": "For every 5 feet the elemental moves in water, or for every gallon of water splashed on it, it takes 1 cold damage.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The elemental makes two touch attacks.", "attack_bonus": null, "damage": null}, {"name": "Touch", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 10 (2d6 + 3) fire damage. If the target is a creature or a flammable object, it ignites. Until a creature takes an action to douse the fire, the target takes 5 (1d10) fire damage at the start of each of its turns.", "attack_bonus": 6, "damage": "2d6+3"}]',
+        '[]', 0, '[]'),
+    ('flesh-golem', 'Flesh Golem', 'Medium', 'Construct', '', 'neutral',
+        9, '', 93, '11d8+44', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        19, 9, 18, 6, 10, 5, '[]', '[]',
+        '', '', 'lightning, poison; bludgeoning, piercing, and slashing from nonmagical attacks that aren''t adamantine', 'charmed, exhaustion, frightened, paralyzed, petrified, poisoned',
+        'darkvision 60 ft., passive Perception 10', 'understands the languages of its creator but can''t speak', 5, '[]',
+        '[{"name": "Berserk", "description": "Whenever the golem starts its turn with 40 hit points or fewer, roll a d6. On a 6, the golem goes berserk. On each of its turns while berserk, the golem attacks the nearest creature it can see. If no creature is near enough to move to and attack, the golem attacks an object, with preference for an object smaller than itself. Once the golem goes berserk, it continues to do so until it is destroyed or regains all its hit points. The golem''s creator, if within 60 feet of the berserk golem, can try to calm it by speaking firmly and persuasively. The golem must be able to hear its creator, who must take an action to make a DC 15 Charisma (Persuasion) check. If the check succeeds, the golem ceases being berserk. If it takes damage while still at 40 hit points or fewer, the golem might go berserk again.", "attack_bonus": null, "damage": null}, {"name": "Aversion of Fire", "description": "If the golem takes fire damage, it has disadvantage on attack rolls and ability checks until the end of its next turn.", "attack_bonus": null, "damage": null}, {"name": "Immutable Form", "description": "The golem is immune to any spell or effect that would alter its form.", "attack_bonus": null, "damage": null}, {"name": "Lightning Absorption", "description": "Whenever the golem is subjected to lightning damage, it takes no damage and instead regains a number of hit points equal to the lightning damage dealt.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The golem has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}, {"name": "Magic Weapons", "description": "The golem''s weapon attacks are magical.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The golem makes two slam attacks.", "attack_bonus": null, "damage": null}, {"name": "Slam", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 13 (2d8 + 4) bludgeoning damage.", "attack_bonus": 7, "damage": "2d8+4"}]',
+        '[]', 0, '[]'),
+    ('giant-crocodile', 'Giant Crocodile', 'Huge', 'Beast', '', 'unaligned',
+        14, 'natural armor', 85, '9d12+27', '{"walk": 30, "fly": 0, "swim": 50, "climb": 0, "burrow": 0, "hover": false}',
+        21, 9, 17, 2, 10, 7, '[]', '[{"name": "Stealth", "bonus": 5}]',
+        '', '', '', '',
+        'passive Perception 10', '', 5, '["Swamp"]',
+        '[{"name": "Hold Breath", "description": "The crocodile can hold its breath for 30 minutes.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The crocodile makes two attacks: one with its bite and one with its tail.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +8 to hit, reach 5 ft., one target. Hit: 21 (3d10 + 5) piercing damage. If the target is a creature, it is grappled (escape DC 16). Until this grapple ends, the target is restrained, and the crocodile can''t bite another target.", "attack_bonus": 8, "damage": "3d10+5"}, {"name": "Tail", "description": "Melee Weapon Attack: +8 to hit, reach 10 ft., one target not grappled by the crocodile. Hit: 14 (2d8 + 5) bludgeoning damage. If the target is a creature, it must succeed on a DC 16 Strength saving throw or be knocked prone.", "attack_bonus": 8, "damage": "2d8+5"}]',
+        '[]', 0, '[]'),
+    ('giant-shark', 'Giant Shark', 'Huge', 'Beast', '', 'unaligned',
+        13, 'natural armor', 126, '11d12+55', '{"walk": 0, "fly": 0, "swim": 50, "climb": 0, "burrow": 0, "hover": false}',
+        23, 11, 21, 1, 10, 5, '[]', '[{"name": "Perception", "bonus": 3}]',
+        '', '', '', '',
+        'blindsight 60 ft., passive Perception 13', '', 5, '["Underwater"]',
+        '[{"name": "Blood Frenzy", "description": "The shark has advantage on melee attack rolls against any creature that doesn''t have all its hit points.", "attack_bonus": null, "damage": null}, {"name": "Water Breathing", "description": "The shark can breathe only underwater.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +9 to hit, reach 5 ft., one target. Hit: 22 (3d10 + 6) piercing damage.", "attack_bonus": 9, "damage": "3d10+6"}]',
+        '[]', 0, '[]'),
+    ('gladiator', 'Gladiator', 'Medium', 'Humanoid', 'any race', 'any alignment',
+        16, 'studded leather, shield', 112, '15d8+45', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 15, 16, 10, 12, 15, '[{"name": "Str", "bonus": 7}, {"name": "Dex", "bonus": 5}, {"name": "Con", "bonus": 6}]', '[{"name": "Athletics", "bonus": 10}, {"name": "Intimidation", "bonus": 5}]',
+        '', '', '', '',
+        'passive Perception 11', 'any one language (usually Common)', 5, '["Urban"]',
+        '[{"name": "Brave", "description": "The gladiator has advantage on saving throws against being frightened.", "attack_bonus": null, "damage": null}, {"name": "Brute", "description": "A melee weapon deals one extra die of its damage when the gladiator hits with it (included in the attack).", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The gladiator makes three melee attacks or two ranged attacks.", "attack_bonus": null, "damage": null}, {"name": "Spear", "description": "Melee or Ranged Weapon Attack: +7 to hit, reach 5 ft. or range 20/60 ft., one target. Hit: 11 (2d6 + 4) piercing damage, or 13 (2d8 + 4) piercing damage if used with two hands to make a melee attack.", "attack_bonus": 7, "damage": "2d6+4"}, {"name": "Shield Bash", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one creature. Hit: 9 (2d4 + 4) bludgeoning damage. If the target is a Medium or smaller creature, it must succeed on a DC 15 Strength saving throw or be knocked prone.", "attack_bonus": 7, "damage": "2d4+4"}]',
+        '[{"name": "Parry", "description": "The gladiator adds 3 to its AC against one melee attack that would hit it. To do so, the gladiator must see the attacker and be wielding a melee weapon.", "attack_bonus": null, "damage": null}]', 0, '[]'),
+    ('gorgon', 'Gorgon', 'Large', 'Monstrosity', '', 'unaligned',
+        19, 'natural armor', 114, '12d10+48', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        20, 11, 18, 2, 12, 7, '[]', '[{"name": "Perception", "bonus": 4}]',
+        '', '', '', 'petrified',
+        'darkvision 60 ft., passive Perception 14', '', 5, '["Grassland", "Hill"]',
+        '[{"name": "Trampling Charge", "description": "If the gorgon moves at least 20 feet straight toward a creature and then hits it with a gore attack on the same turn, that target must succeed on a DC 16 Strength saving throw or be knocked prone. If the target is prone, the gorgon can make one attack with its hooves against it as a bonus action.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Gore", "description": "Melee Weapon Attack: +8 to hit, reach 5 ft., one target. Hit: 18 (2d12 + 5) piercing damage.", "attack_bonus": 8, "damage": "2d12+5"}, {"name": "Hooves", "description": "Melee Weapon Attack: +8 to hit, reach 5 ft., one target. Hit: 16 (2d10 + 5) bludgeoning damage.", "attack_bonus": 8, "damage": "2d10+5"}, {"name": "Petrifying Breath (Recharge 5-6)", "description": "The gorgon exhales petrifying gas in a 30-foot cone. Each creature in that area must succeed on a DC 13 Constitution saving throw. On a failed save, a target begins to turn to stone and is restrained. The restrained target must repeat the saving throw at the end of its next turn. On a success, the effect ends on the target. On a failure, the target is petrified until freed by the greater restoration spell or other magic.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('half-red-dragon-veteran', 'Half-Red Dragon Veteran', 'Medium', 'Humanoid', 'human', 'any alignment',
+        18, 'plate', 65, '10d8+20', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 13, 14, 10, 11, 10, '[]', '[{"name": "Athletics", "bonus": 5}, {"name": "Perception", "bonus": 2}]',
+        '', 'fire', '', '',
+        'blindsight 10 ft., darkvision 60 ft., passive Perception 12', 'Common, Draconic', 5, '["Mountain"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The veteran makes two longsword attacks. If it has a shortsword drawn, it can also make a shortsword attack.", "attack_bonus": null, "damage": null}, {"name": "Longsword", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 7 (1d8 + 3) slashing damage, or 8 (1d10 + 3) slashing damage if used with two hands to make a melee attack.", "attack_bonus": 5, "damage": "1d8+3"}, {"name": "Shortsword", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 6 (1d6 + 3) piercing damage.", "attack_bonus": 5, "damage": "1d6+3"}, {"name": "Heavy Crossbow", "description": "Ranged Weapon Attack: +3 to hit, range 100/400 ft., one target. Hit: 6 (1d10 + 1) piercing damage.", "attack_bonus": 3, "damage": "1d10+1"}, {"name": "Fire Breath (Recharge 5-6)", "description": "The veteran exhales fire in a 15-foot cone. Each creature in that area must make a DC 15 Dexterity saving throw, taking 24 (7d6) fire damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "7d6"}]',
+        '[]', 0, '[]'),
+    ('night-hag', 'Night Hag', 'Medium', 'Fiend', '', 'neutral evil',
+        17, 'natural armor', 112, '15d8+45', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 15, 16, 16, 14, 16, '[]', '[{"name": "Deception", "bonus": 7}, {"name": "Insight", "bonus": 6}, {"name": "Perception", "bonus": 6}, {"name": "Stealth", "bonus": 6}]',
+        '', 'cold, fire; bludgeoning, piercing, and slashing from nonmagical attacks that aren''t silvered', '', 'charmed',
+        'darkvision 120 ft., passive Perception 16', 'Abyssal, Common, Infernal, Primordial', 5, '[]',
+        '[{"name": "Innate Spellcasting", "description": "The hag''s innate spellcasting ability is Charisma (spell save DC 14, +6 to hit with spell attacks). She can innately cast the following spells, requiring no material components. At will: detect magic, magic missile. 2/day each: plane shift (self only), ray of enfeeblement, sleep.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The hag has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Claws (Hag Form Only)", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 13 (2d8 + 4) slashing damage.", "attack_bonus": 7, "damage": "2d8+4"}, {"name": "Change Shape", "description": "The hag magically polymorphs into a Small or Medium female humanoid, or back into her true form. Her statistics are the same in each form. Any equipment she is wearing or carrying isn''t transformed. She reverts to her true form if she dies.", "attack_bonus": null, "damage": null}, {"name": "Etherealness", "description": "The hag magically enters the Ethereal Plane from the Material Plane, or vice versa. To do so, the hag must have a heartstone in her possession.", "attack_bonus": null, "damage": null}, {"name": "Nightmare Haunting (1/Day)", "description": "While on the Ethereal Plane, the hag magically touches a sleeping humanoid on the Material Plane. A protection from evil and good spell cast on the target prevents this contact, as does a magic circle. As long as the contact persists, the target has dreadful visions. If these visions last for at least 1 hour, the target gains no benefit from its rest, and its hit point maximum is reduced by 5 (1d10). If this effect reduces the target''s hit point maximum to 0, the target dies, and if the target was evil, its soul is trapped in the hag''s soul bag. The reduction to the target''s hit point maximum lasts until removed by the greater restoration spell or similar magic.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('otyugh', 'Otyugh', 'Large', 'Aberration', '', 'neutral',
+        14, 'natural armor', 114, '12d10+48', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 11, 19, 6, 13, 6, '[{"name": "Con", "bonus": 7}]', '[]',
+        '', '', '', '',
+        'darkvision 120 ft., passive Perception 11', 'Otyugh', 5, '["Swamp", "Underdark", "Urban"]',
+        '[{"name": "Limited Telepathy", "description": "The otyugh can magically transmit simple messages and images to any creature within 120 feet of it that can understand a language. This form of telepathy doesn''t allow the receiving creature to telepathically respond.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The otyugh makes three attacks: one with its bite and two with its tentacles.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 12 (2d8 + 3) piercing damage. If the target is a creature, it must succeed on a DC 15 Constitution saving throw against disease or become poisoned until the disease is cured. Every 24 hours that elapse, the target must repeat the saving throw, reducing its hit point maximum by 5 (1d10) on a failure. The disease is cured on a success. The target dies if the disease reduces its hit point maximum to 0. This reduction to the target''s hit point maximum lasts until the disease is cured.", "attack_bonus": 6, "damage": "2d8+3"}, {"name": "Tentacle", "description": "Melee Weapon Attack: +6 to hit, reach 10 ft., one target. Hit: 7 (1d8 + 3) bludgeoning damage plus 4 (1d8) piercing damage. If the target is Medium or smaller, it is grappled (escape DC 13) and restrained until the grapple ends. The otyugh has two tentacles, each of which can grapple one target.", "attack_bonus": 6, "damage": "1d8+3"}, {"name": "Tentacle Slam", "description": "The otyugh slams creatures grappled by it into each other or a solid surface. Each creature must succeed on a DC 14 Constitution saving throw or take 10 (2d6 + 3) bludgeoning damage and be stunned until the end of the otyugh''s next turn. On a successful save, the target takes half the bludgeoning damage and isn''t stunned.", "attack_bonus": null, "damage": "2d6+3"}]',
+        '[]', 0, '[]'),
+    ('roper', 'Roper', 'Large', 'Monstrosity', '', 'neutral evil',
+        20, 'natural armor', 93, '11d10+33', '{"walk": 10, "fly": 0, "swim": 0, "climb": 10, "burrow": 0, "hover": false}',
+        18, 8, 17, 7, 16, 6, '[]', '[{"name": "Perception", "bonus": 6}, {"name": "Stealth", "bonus": 5}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 16', '', 5, '["Underdark"]',
+        '[{"name": "False Appearance", "description": "While the roper remains motionless, it is indistinguishable from a normal cave formation, such as a stalagmite.", "attack_bonus": null, "damage": null}, {"name": "Grasping Tendrils", "description": "The roper can have up to six tendrils at a time. Each tendril can be attacked (AC 20; 10 hit points; immunity to poison and psychic damage). Destroying a tendril deals no damage to the roper, which can extrude a replacement tendril on its next turn. A tendril can also be broken if a creature takes an action and succeeds on a DC 15 Strength check against it.", "attack_bonus": null, "damage": null}, {"name": "Spider Climb", "description": "The roper can climb difficult surfaces, including upside down on ceilings, without needing to make an ability check.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The roper makes four attacks with its tendrils, uses Reel, and makes one attack with its bite.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 22 (4d8 + 4) piercing damage.", "attack_bonus": 7, "damage": "4d8+4"}, {"name": "Tendril", "description": "Melee Weapon Attack: +7 to hit, reach 50 ft., one creature. Hit: The target is grappled (escape DC 15). Until the grapple ends, the target is restrained and has disadvantage on Strength checks and Strength saving throws, and the roper can''t use the same tendril on another target.", "attack_bonus": 7, "damage": null}, {"name": "Reel", "description": "The roper pulls each creature grappled by it up to 25 feet straight toward it.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('salamander', 'Salamander', 'Large', 'Elemental', '', 'neutral evil',
+        15, 'natural armor', 90, '12d10+24', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 14, 15, 11, 10, 12, '[]', '[]',
+        'cold', 'bludgeoning, piercing, and slashing from nonmagical attacks', 'fire', '',
+        'darkvision 60 ft., passive Perception 10', 'Ignan', 5, '["Mountain"]',
+        '[{"name": "Heated Body", "description": "A creature that touches the salamander or hits it with a melee attack while within 5 feet of it takes 7 (2d6) fire damage.", "attack_bonus": null, "damage": "2d6"}, {"name": "Heated Weapons", "description": "Any metal melee weapon the salamander wields deals an extra 3 (1d6) fire damage on a hit (included in the attack).", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The salamander makes two attacks: one with its spear and one with its tail.", "attack_bonus": null, "damage": null}, {"name": "Spear", "description": "Melee or Ranged Weapon Attack: +7 to hit, reach 5 ft. or range 20/60 ft., one target. Hit: 11 (2d6 + 4) piercing damage, or 13 (2d8 + 4) piercing damage if used with two hands to make a melee attack plus 3 (1d6) fire damage.", "attack_bonus": 7, "damage": "2d6+4"}, {"name": "Tail", "description": "Melee Weapon Attack: +7 to hit, reach 10 ft., one target. Hit: 11 (2d6 + 4) bludgeoning damage plus 7 (2d6) fire damage. The target is grappled (escape DC 14). Until this grapple ends, the target is restrained, the salamander can automatically hit the target with its tail, and the salamander can''t make tail attacks against other targets.", "attack_bonus": 7, "damage": "2d6+4"}]',
+        '[]', 0, '[]'),
+    ('shambling-mound', 'Shambling Mound', 'Large', 'Plant', '', 'unaligned',
+        15, 'natural armor', 136, '16d10+48', '{"walk": 20, "fly": 0, "swim": 20, "climb": 0, "burrow": 0, "hover": false}',
+        18, 8, 16, 5, 10, 5, '[]', '[{"name": "Stealth", "bonus": 2}]',
+        '', 'cold, fire', 'lightning', 'blinded, deafened, exhaustion',
+        'blindsight 60 ft. (blind beyond this radius), passive Perception 10', '', 5, '["Forest", "Swamp"]',
+        '[{"name": "Lightning Absorption", "description": "Whenever the shambling mound is subjected to lightning damage, it takes no damage and regains a number of hit points equal to the lightning damage dealt.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The shambling mound makes two slam attacks. If both attacks hit a Medium or smaller target, the target is grappled (escape DC 14), and the shambling mound uses its Engulf on it.", "attack_bonus": null, "damage": null}, {"name": "Slam", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 13 (2d8 + 4) bludgeoning damage.", "attack_bonus": 7, "damage": "2d8+4"}, {"name": "Engulf", "description": "The shambling mound engulfs a Medium or smaller creature grappled by it. The engulfed target is blinded, restrained, and unable to breathe, and it must succeed on a DC 14 Constitution saving throw at the start of each of the mound''s turns or take 13 (2d8 + 4) bludgeoning damage. If the mound moves, the engulfed target moves with it. The mound can have only one creature engulfed at a time.", "attack_bonus": null, "damage": "2d8+4"}]',
+        '[]', 0, '[]'),
+    ('triceratops', 'Triceratops', 'Huge', 'Beast', '', 'unaligned',
+        13, 'natural armor', 95, '10d12+30', '{"walk": 50, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        22, 9, 17, 2, 11, 5, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 10', '', 5, '["Forest", "Grassland"]',
+        '[{"name": "Trampling Charge", "description": "If the triceratops moves at least 20 feet straight toward a creature and then hits it with a gore attack on the same turn, that target must succeed on a DC 13 Strength saving throw or be knocked prone. If the target is prone, the triceratops can make one stomp attack against it as a bonus action.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Gore", "description": "Melee Weapon Attack: +9 to hit, reach 5 ft., one target. Hit: 24 (4d8 + 6) piercing damage.", "attack_bonus": 9, "damage": "4d8+6"}, {"name": "Stomp", "description": "Melee Weapon Attack: +9 to hit, reach 5 ft., one prone creature. Hit: 22 (3d10 + 6) bludgeoning damage.", "attack_bonus": 9, "damage": "3d10+6"}]',
+        '[]', 0, '[]'),
+    ('unicorn', 'Unicorn', 'Large', 'Celestial', '', 'lawful good',
+        12, '', 67, '9d10+18', '{"walk": 50, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 14, 15, 11, 17, 16, '[]', '[]',
+        '', '', 'poison', 'charmed, paralyzed, poisoned',
+        'darkvision 60 ft., passive Perception 13', 'Celestial, Elvish, Sylvan, telepathy 60 ft.', 5, '["Forest"]',
+        '[{"name": "Charge", "description": "If the unicorn moves at least 20 feet straight toward a target and then hits it with a horn attack on the same turn, the target takes an extra 9 (2d8) piercing damage. If the target is a creature, it must succeed on a DC 15 Strength saving throw or be knocked prone.", "attack_bonus": null, "damage": "2d8"}, {"name": "Innate Spellcasting", "description": "The unicorn''s innate spellcasting ability is Charisma (spell save DC 14). The unicorn can innately cast the following spells, requiring no components. At will: detect evil and good, druidcraft, pass without trace. 1/day each: calm emotions, dispel evil and good, entangle.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The unicorn has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}, {"name": "Magic Weapons", "description": "The unicorn''s weapon attacks are magical.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The unicorn makes two attacks: one with its hooves and one with its horn.", "attack_bonus": null, "damage": null}, {"name": "Hooves", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 11 (2d6 + 4) bludgeoning damage.", "attack_bonus": 7, "damage": "2d6+4"}, {"name": "Horn", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 8 (1d8 + 4) piercing damage.", "attack_bonus": 7, "damage": "1d8+4"}, {"name": "Healing Touch (3/Day)", "description": "The unicorn touches another creature with its horn. The target magically regains 11 (2d8 + 2) hit points. In addition, the touch removes all diseases and neutralizes all poisons afflicting the target.", "attack_bonus": null, "damage": null}, {"name": "Teleport (1/Day)", "description": "The unicorn magically teleports itself and up to three willing creatures it can see within 5 feet of it, along with any equipment they are wearing or carrying, to a location the unicorn is familiar with, up to 1 mile away.", "attack_bonus": null, "damage": null}]',
+        '[]', 3, '[{"name": "Hooves", "description": "The unicorn makes one attack with its hooves.", "attack_bonus": null, "damage": null}, {"name": "Shimmering Shield (Costs 2 Actions)", "description": "The unicorn creates a shimmering, magical field around itself or another creature it can see within 60 feet of it. The target gains a +2 bonus to AC until the end of the unicorn''s next turn.", "attack_bonus": null, "damage": null}, {"name": "Heal Self (Costs 3 Actions)", "description": "The unicorn magically regains 11 (2d8 + 2) hit points.", "attack_bonus": null, "damage": null}]'),
+    ('vampire-spawn', 'Vampire Spawn', 'Medium', 'Undead', '', 'neutral evil',
+        15, 'natural armor', 82, '11d8+33', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        16, 16, 16, 11, 10, 12, '[{"name": "Dex", "bonus": 6}, {"name": "Wis", "bonus": 3}]', '[{"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 6}]',
+        '', 'necrotic; bludgeoning, piercing, and slashing from nonmagical attacks', '', '',
+        'darkvision 60 ft., passive Perception 13', 'the languages it knew in life', 5, '["Urban"]',
+        '[{"name": "Regeneration", "description": "The vampire regains 10 hit points at the start of its turn if it has at least 1 hit point and isn''t in sunlight or running water. If the vampire takes radiant damage or damage from holy water, this trait doesn''t function at the start of the vampire''s next turn.", "attack_bonus": null, "damage": null}, {"name": "Spider Climb", "description": "The vampire can climb difficult surfaces, including upside down on ceilings, without needing to make an ability check.", "attack_bonus": null, "damage": null}, {"name": "Vampire Weaknesses", "description": "The vampire has the following flaws: Forbiddance. The vampire can''t enter a residence without an invitation from one of the occupants. Harmed by Running Water. The vampire takes 20 acid damage when it ends its turn in running water. Stake to the Heart. The vampire is destroyed if a piercing weapon made of wood is driven into its heart while it is incapacitated in its resting place. Sunlight Hypersensitivity. The vampire takes 20 radiant damage when it starts its turn in sunlight. While in sunlight, it has disadvantage on attack rolls and ability checks.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The vampire makes two attacks, only one of which can be a bite attack.", "attack_bonus": null, "damage": null}, {"name": "Claws", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one creature. Hit: 8 (2d4 + 3) slashing damage. Instead of dealing damage, the vampire can grapple the target (escape DC 13).", "attack_bonus": 6, "damage": "2d4+3"}, {"name": "Bite", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one willing creature, or a creature that is grappled by the vampire, incapacitated, or restrained. Hit: 6 (1d6 + 3) piercing damage plus 7 (2d6) necrotic damage. The target''s hit point maximum is reduced by an amount equal to the necrotic damage taken, and the vampire regains hit points equal to that amount. The reduction lasts until the target finishes a long rest. The target dies if this effect reduces its hit point maximum to 0.", "attack_bonus": 6, "damage": "1d6+3"}]',
+        '[]', 0, '[]'),
+    ('water-elemental', 'Water Elemental', 'Large', 'Elemental', '', 'neutral',
+        14, 'natural armor', 114, '12d10+48', '{"walk": 30, "fly": 0, "swim": 90, "climb": 0, "burrow": 0, "hover": false}',
+        18, 14, 18, 5, 10, 8, '[]', '[]',
+        '', 'acid; bludgeoning, piercing, and slashing from nonmagical attacks', 'poison', 'exhaustion, grappled, paralyzed, petrified, poisoned, prone, restrained, unconscious',
+        'darkvision 60 ft., passive Perception 10', 'Aquan', 5, '["Coastal", "Underwater"]',
+        '[{"name": "Water Form", "description": "The elemental can enter a hostile creature''s space and stop there. It can move through a space as narrow as 1 inch wide without squeezing.", "attack_bonus": null, "damage": null}, {"name": "Freeze", "description": "If the elemental takes cold damage, it partially freezes; its speed is reduced by 20 feet until the end of its next turn.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The elemental makes two slam attacks.", "attack_bonus": null, "damage": null}, {"name": "Slam", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 13 (2d8 + 4) bludgeoning damage.", "attack_bonus": 7, "damage": "2d8+4"}, {"name": "Whelm (Recharge 4-6)", "description": "Each creature in the elemental''s space must make a DC 15 Strength saving throw. On a failure, a target takes 13 (2d8 + 4) bludgeoning damage. If it is Large or smaller, it is also grappled (escape DC 14). Until this grapple ends, the target is restrained and unable to breathe unless it can breathe water. If the saving throw is successful, the target is pushed out of the elemental''s space. The elemental can grapple one Large creature or up to two Medium or smaller creatures at one time. At the start of each of the elemental''s turns, each target grappled by it takes 13 (2d8 + 4) bludgeoning damage. A creature within 5 feet of the elemental can pull a creature or object out of it by taking an action to make a DC 14 Strength check and succeeding.", "attack_bonus": null, "damage": "2d8+4"}]',
+        '[]', 0, '[]'),
+    ('werebear', 'Werebear', 'Medium', 'Humanoid', 'human, shapechanger', 'neutral good',
+        10, 'in humanoid form, 11 (natural armor) in bear and hybrid form', 135, '18d8+54', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        19, 10, 17, 11, 12, 12, '[]', '[{"name": "Perception", "bonus": 7}]',
+        '', '', 'bludgeoning, piercing, and slashing from nonmagical attacks that aren''t silvered', '',
+        'passive Perception 17', 'Common (can''t speak in bear form)', 5, '["Forest", "Arctic"]',
+        '[{"name": "Shapechanger", "description": "The werebear can use its action to polymorph into a Large bear-humanoid hybrid or into a Large bear, or back into its true form, which is humanoid. Its statistics, other than its size and AC, are the same in each form. Its speed is 40 feet, climb 30 feet, in bear or hybrid form. Any equipment it is wearing or carrying isn''t transformed. It reverts to its true form if it dies.", "attack_bonus": null, "damage": null}, {"name": "Keen Smell", "description": "The werebear has advantage on Wisdom (Perception) checks that rely on smell.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "In bear form, the werebear makes two claw attacks. In humanoid form, it makes two greataxe attacks. In hybrid form, it can attack like a bear or a humanoid.", "attack_bonus": null, "damage": null}, {"name": "Bite (Bear or Hybrid Form Only)", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 15 (2d10 + 4) piercing damage. If the target is a humanoid, it must succeed on a DC 14 Constitution saving throw or be cursed with werebear lycanthropy.", "attack_bonus": 7, "damage": "2d10+4"}, {"name": "Claw (Bear or Hybrid Form Only)", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 13 (2d8 + 4) slashing damage.", "attack_bonus": 7, "damage": "2d8+4"}, {"name": "Greataxe (Humanoid or Hybrid Form Only)", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 10 (1d12 + 4) slashing damage.", "attack_bonus": 7, "damage": "1d12+4"}]',
+        '[]', 0, '[]'),
+    ('wraith', 'Wraith', 'Medium', 'Undead', '', 'neutral evil',
+        13, '', 67, '9d8+27', '{"walk": 0, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": true}',
+        6, 16, 16, 12, 14, 15, '[]', '[]',
+        '', 'acid, cold, fire, lightning, thunder; bludgeoning, piercing, and slashing from nonmagical attacks that aren''t silvered', 'necrotic, poison', 'charmed, exhaustion, grappled, paralyzed, petrified, poisoned, prone, restrained',
+        'darkvision 60 ft., passive Perception 12', 'the languages it knew in life', 5, '["Underdark", "Urban"]',
+        '[{"name": "Incorporeal Movement", "description": "The wraith can move through other creatures and objects as if they were difficult terrain. It takes 5 (1d10) force damage if it ends its turn inside an object.", "attack_bonus": null, "damage": null}, {"name": "Sunlight Sensitivity", "description": "While in sunlight, the wraith has disadvantage on attack rolls, as well as on Wisdom (Perception) checks that rely on sight.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Life Drain", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one creature. Hit: 21 (4d8 + 3) necrotic damage. The target must succeed on a DC 14 Constitution saving throw or its hit point maximum is reduced by an amount equal to the damage taken. This reduction lasts until the target finishes a long rest. The target dies if this effect reduces its hit point maximum to 0.", "attack_bonus": 6, "damage": "4d8+3"}, {"name": "Create Specter", "description": "The wraith targets a humanoid within 10 feet of it that has been dead for no longer than 1 minute and died violently. The target''s spirit rises as a specter in the space of its corpse or in the nearest unoccupied space. The specter is under the wraith''s control. The wraith can have no more than seven specters under its control at one time.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('xorn', 'Xorn', 'Medium', 'Elemental', '', 'neutral',
+        19, 'natural armor', 73, '7d8+42', '{"walk": 20, "fly": 0, "swim": 0, "climb": 0, "burrow": 20, "hover": false}',
+        17, 10, 22, 11, 10, 11, '[]', '[{"name": "Perception", "bonus": 6}, {"name": "Stealth", "bonus": 3}]',
+        '', 'piercing and slashing from nonmagical attacks that aren''t adamantine', '', '',
+        'darkvision 60 ft., tremorsense 60 ft., passive Perception 16', 'Terran', 5, '["Underdark"]',
+        '[{"name": "Earth Glide", "description": "The xorn can burrow through nonmagical, unworked earth and stone. While doing so, the xorn doesn''t disturb the material it moves through.", "attack_bonus": null, "damage": null}, {"name": "Stone Camouflage", "description": "The xorn has advantage on Dexterity (Stealth) checks made to hide in rocky terrain.", "attack_bonus": null, "damage": null}, {"name": "Treasure Sense", "description": "The xorn can pinpoint, by scent, the location of precious metals and stones, such as coins and gems, within 60 feet of it.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The xorn makes three claw attacks and one bite attack.", "attack_bonus": null, "damage": null}, {"name": "Claw", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 6 (1d6 + 3) slashing damage.", "attack_bonus": 6, "damage": "1d6+3"}, {"name": "Bite", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 13 (3d6 + 3) piercing damage.", "attack_bonus": 6, "damage": "3d6+3"}]',
+        '[]', 0, '[]'),
+    ('chimera', 'Chimera', 'Large', 'Monstrosity', '', 'chaotic evil',
+        14, 'natural armor', 114, '12d10+48', '{"walk": 30, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        19, 11, 19, 3, 14, 10, '[]', '[{"name": "Perception", "bonus": 8}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 18', 'understands Draconic but can''t speak', 6, '["Grassland", "Hill", "Mountain"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The chimera makes three attacks: one with its bite, one with its horns, and one with its claws. When its fire breath is available, it can use the breath in place of its bite or horns.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 11 (2d6 + 4) piercing damage.", "attack_bonus": 7, "damage": "2d6+4"}, {"name": "Horns", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 10 (1d12 + 4) bludgeoning damage.", "attack_bonus": 7, "damage": "1d12+4"}, {"name": "Claws", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 11 (2d6 + 4) slashing damage.", "attack_bonus": 7, "damage": "2d6+4"}, {"name": "Fire Breath (Recharge 5-6)", "description": "The dragon head exhales fire in a 15-foot cone. Each creature in that area must make a DC 15 Dexterity saving throw, taking 31 (7d8) fire damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "7d8"}]',
+        '[]', 0, '[]'),
+    ('drider', 'Drider', 'Large', 'Monstrosity', '', 'chaotic evil',
+        19, 'natural armor', 123, '13d10+52', '{"walk": 30, "fly": 0, "swim": 0, "climb": 30, "burrow": 0, "hover": false}',
+        16, 16, 18, 13, 14, 12, '[]', '[{"name": "Perception", "bonus": 5}, {"name": "Stealth", "bonus": 9}]',
+        '', '', '', '',
+        'darkvision 120 ft., passive Perception 15', 'Elvish, Undercommon', 6, '["Underdark"]',
+        '[{"name": "Fey Ancestry", "description": "The drider has advantage on saving throws against being charmed, and magic can''t put the drider to sleep.", "attack_bonus": null, "damage": null}, {"name": "Innate Spellcasting", "description": "The drider''s innate spellcasting ability is Wisdom (spell save DC 13). The drider can innately cast the following spells, requiring no material components. At will: dancing lights. 1/day each: darkness, faerie fire.", "attack_bonus": null, "damage": null}, {"name": "Spider Climb", "description": "The drider can climb difficult surfaces, including upside down on ceilings, without needing to make an ability check.", "attack_bonus": null, "damage": null}, {"name": "Sunlight Sensitivity", "description": "While in sunlight, the drider has disadvantage on attack rolls, as well as on Wisdom (Perception) checks that rely on sight.", "attack_bonus": null, "damage": null}, {"name": "Web Walker", "description": "The drider ignores movement restrictions caused by webbing.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The drider makes three attacks, either with its longsword or its longbow. It can replace one of those attacks with a bite attack.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one creature. Hit: 2 (1d4) piercing damage plus 9 (2d8) poison damage.", "attack_bonus": 6, "damage": "1d4"}, {"name": "Longsword", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 7 (1d8 + 3) slashing damage, or 8 (1d10 + 3) slashing damage if used with two hands to make a melee attack.", "attack_bonus": 6, "damage": "1d8+3"}, {"name": "Longbow", "description": "Ranged Weapon Attack: +6 to hit, range 150/600 ft., one target. Hit: 7 (1d8 + 3) piercing damage plus 4 (1d8) poison damage.", "attack_bonus": 6, "damage": "1d8+3"}]',
+        '[]', 0, '[]'),
+    ('invisible-stalker', 'Invisible Stalker', 'Medium', 'Elemental', '', 'neutral',
+        14, '', 104, '16d8+32', '{"walk": 50, "fly": 50, "swim": 0, "climb": 0, "burrow": 0, "hover": true}',
+        16, 19, 14, 10, 15, 11, '[]', '[{"name": "Perception", "bonus": 8}, {"name": "Stealth", "bonus": 10}]',
+        '', 'bludgeoning, piercing, and slashing from nonmagical attacks', 'poison', 'exhaustion, grappled, paralyzed, petrified, poisoned, prone, restrained, unconscious',
+        'darkvision 60 ft., passive Perception 18', 'Auran, understands Common but doesn''t speak it', 6, '[]',
+        '[{"name": "Invisibility", "description": "The stalker is invisible.", "attack_bonus": null, "damage": null}, {"name": "Faultless Tracker", "description": "The stalker is given a quarry by its summoner. The stalker knows the direction and distance to its quarry as long as the two of them are on the same plane of existence. The stalker also knows the location of its summoner.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The stalker makes two slam attacks.", "attack_bonus": null, "damage": null}, {"name": "Slam", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 10 (2d6 + 3) bludgeoning damage.", "attack_bonus": 6, "damage": "2d6+3"}]',
+        '[]', 0, '[]'),
+    ('mammoth', 'Mammoth', 'Huge', 'Beast', '', 'unaligned',
+        13, 'natural armor', 126, '11d12+55', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        24, 9, 21, 3, 11, 6, '[]', '[]',
+        '', '', '', '',
+        'passive Perception 10', '', 6, '["Arctic"]',
+        '[{"name": "Trampling Charge", "description": "If the mammoth moves at least 20 feet straight toward a creature and then hits it with a gore attack on the same turn, that target must succeed on a DC 18 Strength saving throw or be knocked prone. If the target is prone, the mammoth can make one stomp attack against it as a bonus action.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Gore", "description": "Melee Weapon Attack: +10 to hit, reach 10 ft., one target. Hit: 25 (4d8 + 7) piercing damage.", "attack_bonus": 10, "damage": "4d8+7"}, {"name": "Stomp", "description": "Melee Weapon Attack: +10 to hit, reach 5 ft., one prone creature. Hit: 29 (4d10 + 7) bludgeoning damage.", "attack_bonus": 10, "damage": "4d10+7"}]',
+        '[]', 0, '[]'),
+    ('medusa', 'Medusa', 'Medium', 'Monstrosity', '', 'lawful evil',
+        15, 'natural armor', 127, '17d8+51', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        10, 15, 16, 12, 13, 15, '[]', '[{"name": "Deception", "bonus": 5}, {"name": "Insight", "bonus": 4}, {"name": "Perception", "bonus": 4}, {"name": "Stealth", "bonus": 5}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 14', 'Common', 6, '["Desert", "Mountain"]',
+        '[{"name": "Petrifying Gaze", "description": "When a creature that can see the medusa''s eyes starts its turn within 30 feet of the medusa, the medusa can force it to make a DC 14 Constitution saving throw if the medusa isn''t incapacitated and can see the creature. If the saving throw fails by 5 or more, the creature is instantly petrified. Otherwise, a creature that fails the save begins to turn to stone and is restrained. The restrained creature must repeat the saving throw at the end of its next turn, becoming petrified on a failure or ending the effect on a success. The petrification lasts until the creature is freed by the greater restoration spell or other magic. Unless surprised, a creature can avert its eyes to avoid the saving throw at the start of its turn. If the creature does so, it can''t see the medusa until the start of its next turn, when it can avert its eyes again. If the creature looks at the medusa in the meantime, it must immediately make the save. If the medusa sees itself reflected on a polished surface within 30 feet of it and in an area of bright light, the medusa is, due to its curse, affected by its own gaze.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The medusa makes either three melee attacks, one with its snake hair and two with its shortsword, or two ranged attacks with its longbow.", "attack_bonus": null, "damage": null}, {"name": "Snake Hair", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one creature. Hit: 4 (1d4 + 2) piercing damage plus 14 (4d6) poison damage.", "attack_bonus": 5, "damage": "1d4+2"}, {"name": "Shortsword", "description": "Melee Weapon Attack: +5 to hit, reach 5 ft., one target. Hit: 5 (1d6 + 2) piercing damage.", "attack_bonus": 5, "damage": "1d6+2"}, {"name": "Longbow", "description": "Ranged Weapon Attack: +5 to hit, range 150/600 ft., one target. Hit: 6 (1d8 + 2) piercing damage plus 7 (2d6) poison damage.", "attack_bonus": 5, "damage": "1d8+2"}]',
+        '[]', 0, '[]'),
+    ('vrock', 'Vrock', 'Large', 'Fiend', 'demon', 'chaotic evil',
+        15, 'natural armor', 104, '11d10+44', '{"walk": 40, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        17, 15, 18, 8, 13, 8, '[{"name": "Dex", "bonus": 5}, {"name": "Wis", "bonus": 4}, {"name": "Cha", "bonus": 2}]', '[]',
+        '', 'cold, fire, lightning; bludgeoning, piercing, and slashing from nonmagical attacks', 'poison', 'poisoned',
+        'darkvision 120 ft., passive Perception 11', 'Abyssal, telepathy 120 ft.', 6, '[]',
+        '[{"name": "Magic Resistance", "description": "The vrock has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The vrock makes two attacks: one with its beak and one with its talons.", "attack_bonus": null, "damage": null}, {"name": "Beak", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 10 (2d6 + 3) piercing damage.", "attack_bonus": 6, "damage": "2d6+3"}, {"name": "Talons", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 14 (2d10 + 3) slashing damage.", "attack_bonus": 6, "damage": "2d10+3"}, {"name": "Spores (Recharge 6)", "description": "A 15-foot-radius cloud of toxic spores extends out from the vrock. The spores spread around corners. Each creature in that area must succeed on a DC 14 Constitution saving throw or become poisoned. While poisoned in this way, a target takes 5 (1d10) poison damage at the start of each of its turns. A target can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. Emptying a vial of holy water on the target also ends the effect on it.", "attack_bonus": null, "damage": "1d10"}, {"name": "Stunning Screech (1/Day)", "description": "The vrock emits a horrific screech. Each creature within 20 feet of it that can hear it and that isn''t a demon must succeed on a DC 14 Constitution saving throw or be stunned until the end of the vrock''s next turn.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('wyvern', 'Wyvern', 'Large', 'Dragon', '', 'unaligned',
+        13, 'natural armor', 110, '13d10+39', '{"walk": 20, "fly": 80, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        19, 10, 16, 5, 12, 6, '[]', '[{"name": "Perception", "bonus": 4}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 14', '', 6, '["Hill", "Mountain"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The wyvern makes two attacks: one with its bite and one with its stinger. While flying, it can use its claws in place of one other attack.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +7 to hit, reach 10 ft., one creature. Hit: 11 (2d6 + 4) piercing damage.", "attack_bonus": 7, "damage": "2d6+4"}, {"name": "Claws", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 13 (2d8 + 4) slashing damage.", "attack_bonus": 7, "damage": "2d8+4"}, {"name": "Stinger", "description": "Melee Weapon Attack: +7 to hit, reach 10 ft., one creature. Hit: 11 (2d6 + 4) piercing damage. The target must make a DC 15 Constitution saving throw, taking 24 (7d6) poison damage on a failed save, or half as much damage on a successful one.", "attack_bonus": 7, "damage": "2d6+4"}]',
+        '[]', 0, '[]'),
+    ('young-brass-dragon', 'Young Brass Dragon', 'Large', 'Dragon', '', 'chaotic good',
+        17, 'natural armor', 110, '13d10+39', '{"walk": 40, "fly": 80, "swim": 0, "climb": 0, "burrow": 20, "hover": false}',
+        19, 10, 17, 12, 11, 15, '[{"name": "Dex", "bonus": 3}, {"name": "Con", "bonus": 6}, {"name": "Wis", "bonus": 3}, {"name": "Cha", "bonus": 5}]', '[{"name": "Perception", "bonus": 6}, {"name": "Persuasion", "bonus": 5}, {"name": "Stealth", "bonus": 3}]',
+        '', '', 'fire', '',
+        'blindsight 30 ft., darkvision 120 ft., passive Perception 16', 'Common, Draconic', 6, '["Desert"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The dragon makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +7 to hit, reach 10 ft., one target. Hit: 15 (2d10 + 4) piercing damage.", "attack_bonus": 7, "damage": "2d10+4"}, {"name": "Claw", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 11 (2d6 + 4) slashing damage.", "attack_bonus": 7, "damage": "2d6+4"}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Fire Breath. The dragon exhales fire in a 40-foot line that is 5 feet wide. Each creature in that line must make a DC 14 Dexterity saving throw, taking 42 (12d6) fire damage on a failed save, or half as much damage on a successful one. Sleep Breath. The dragon exhales sleep gas in a 30-foot cone. Each creature in that area must succeed on a DC 14 Constitution saving throw or fall unconscious for 5 minutes. This effect ends for a creature if the creature takes damage or someone uses an action to wake it.", "attack_bonus": null, "damage": "12d6"}]',
+        '[]', 0, '[]'),
+    ('young-white-dragon', 'Young White Dragon', 'Large', 'Dragon', '', 'chaotic evil',
+        17, 'natural armor', 133, '14d10+56', '{"walk": 40, "fly": 80, "swim": 40, "climb": 0, "burrow": 20, "hover": false}',
+        18, 10, 18, 6, 11, 12, '[{"name": "Dex", "bonus": 3}, {"name": "Con", "bonus": 7}, {"name": "Wis", "bonus": 3}, {"name": "Cha", "bonus": 4}]', '[{"name": "Perception", "bonus": 6}, {"name": "Stealth", "bonus": 3}]',
+        '', '', 'cold', '',
+        'blindsight 30 ft., darkvision 120 ft., passive Perception 16', 'Common, Draconic', 6, '["Arctic"]',
+        '[{"name": "Ice Walk", "description": "The dragon can move across and climb icy surfaces without needing to make an ability check. Additionally, difficult terrain composed of ice or snow doesn''t cost it extra movement.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +7 to hit, reach 10 ft., one target. Hit: 15 (2d10 + 4) piercing damage plus 4 (1d8) cold damage.", "attack_bonus": 7, "damage": "2d10+4"}, {"name": "Claw", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 11 (2d6 + 4) slashing damage.", "attack_bonus": 7, "damage": "2d6+4"}, {"name": "Cold Breath (Recharge 5-6)", "description": "The dragon exhales an icy blast in a 30-foot cone. Each creature in that area must make a DC 15 Constitution saving throw, taking 45 (10d8) cold damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "10d8"}]',
+        '[]', 0, '[]'),
+    ('giant-ape', 'Giant Ape', 'Huge', 'Beast', '', 'unaligned',
+        12, '', 157, '15d12+60', '{"walk": 40, "fly": 0, "swim": 0, "climb": 40, "burrow": 0, "hover": false}',
+        23, 14, 18, 7, 12, 7, '[]', '[{"name": "Athletics", "bonus": 9}, {"name": "Perception", "bonus": 4}]',
+        '', '', '', '',
+        'passive Perception 14', '', 7, '["Forest"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The ape makes two fist attacks.", "attack_bonus": null, "damage": null}, {"name": "Fist", "description": "Melee Weapon Attack: +9 to hit, reach 10 ft., one target. Hit: 22 (3d10 + 6) bludgeoning damage.", "attack_bonus": 9, "damage": "3d10+6"}, {"name": "Rock", "description": "Ranged Weapon Attack: +9 to hit, range 50/100 ft., one target. Hit: 30 (7d6 + 6) bludgeoning damage.", "attack_bonus": 9, "damage": "7d6+6"}]',
+        '[]', 0, '[]'),
+    ('oni', 'Oni', 'Large', 'Giant', '', 'lawful evil',
+        16, 'chain mail', 110, '13d10+39', '{"walk": 30, "fly": 30, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        19, 11, 16, 14, 12, 15, '[{"name": "Dex", "bonus": 3}, {"name": "Con", "bonus": 6}, {"name": "Wis", "bonus": 4}, {"name": "Cha", "bonus": 5}]', '[{"name": "Arcana", "bonus": 5}, {"name": "Deception", "bonus": 8}, {"name": "Perception", "bonus": 4}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 14', 'Common, Giant', 7, '["Forest", "Hill", "Urban"]',
+        '[{"name": "Innate Spellcasting", "description": "The oni''s innate spellcasting ability is Charisma (spell save DC 13). The oni can innately cast the following spells, requiring no material components. At will: darkness, invisibility. 1/day each: charm person, cone of cold, gaseous form, sleep.", "attack_bonus": null, "damage": null}, {"name": "Magic Weapons", "description": "The oni''s weapon attacks are magical.", "attack_bonus": null, "damage": null}, {"name": "Regeneration", "description": "The oni regains 10 hit points at the start of its turn if it has at least 1 hit point.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The oni makes two attacks, either with its claws or its glaive.", "attack_bonus": null, "damage": null}, {"name": "Claw (Oni Form Only)", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 8 (1d8 + 4) slashing damage.", "attack_bonus": 7, "damage": "1d8+4"}, {"name": "Glaive", "description": "Melee Weapon Attack: +7 to hit, reach 10 ft., one target. Hit: 15 (2d10 + 4) slashing damage, or 9 (1d10 + 4) slashing damage in Small or Medium form.", "attack_bonus": 7, "damage": "2d10+4"}, {"name": "Change Shape", "description": "The oni magically polymorphs into a Small or Medium humanoid, into a Large giant, or back into its true form. Other than its size, its statistics are the same in each form. The only equipment that is transformed is its glaive, which shrinks so that it can be wielded in humanoid form. If the oni dies, it reverts to its true form, and its glaive reverts to its normal size.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('shield-guardian', 'Shield Guardian', 'Large', 'Construct', '', 'unaligned',
+        17, 'natural armor', 142, '15d10+60', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 8, 18, 7, 10, 3, '[]', '[]',
+        '', '', 'poison', 'charmed, exhaustion, frightened, paralyzed, poisoned',
+        'blindsight 10 ft., darkvision 60 ft., passive Perception 10', 'understands commands given in any language but can''t speak', 7, '[]',
+        '[{"name": "Bound", "description": "The shield guardian is magically bound to an amulet. As long as the guardian and its amulet are on the same plane of existence, the amulet''s wearer can telepathically call the guardian to travel to it, and the guardian knows the distance and direction to the amulet. If the guardian is within 60 feet of the amulet''s wearer, half of any damage the wearer takes (rounded up) is transferred to the guardian.", "attack_bonus": null, "damage": null}, {"name": "Regeneration", "description": "The shield guardian regains 10 hit points at the start of its turn if it has at least 1 hit point.", "attack_bonus": null, "damage": null}, {"name": "Spell Storing", "description": "A spellcaster who wears the shield guardian''s amulet can cause the guardian to store one spell of 4th level or lower. To do so, the wearer must cast the spell on the guardian. The spell has no effect but is stored within the guardian. When commanded to do so by the wearer or when a situation arises that was predefined by the spellcaster, the guardian casts the stored spell with any parameters set by the original caster, requiring no components. When the spell is cast or a new spell is stored, any previously stored spell is lost.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The guardian makes two fist attacks.", "attack_bonus": null, "damage": null}, {"name": "Fist", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 11 (2d6 + 4) bludgeoning damage.", "attack_bonus": 7, "damage": "2d6+4"}]',
+        '[{"name": "Shield", "description": "When a creature makes an attack against the wearer of the guardian''s amulet, the guardian grants a +2 bonus to the wearer''s AC if the guardian is within 5 feet of the wearer.", "attack_bonus": null, "damage": null}]', 0, '[]'),
+    ('stone-giant', 'Stone Giant', 'Huge', 'Giant', '', 'neutral',
+        17, 'natural armor', 126, '11d12+55', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        23, 15, 20, 10, 12, 9, '[{"name": "Dex", "bonus": 5}, {"name": "Con", "bonus": 8}, {"name": "Wis", "bonus": 4}]', '[{"name": "Athletics", "bonus": 12}, {"name": "Perception", "bonus": 4}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 14', 'Giant', 7, '["Hill", "Mountain", "Underdark"]',
+        '[{"name": "Stone Camouflage", "description": "The giant has advantage on Dexterity (Stealth) checks made to hide in rocky terrain.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The giant makes two greatclub attacks.", "attack_bonus": null, "damage": null}, {"name": "Greatclub", "description": "Melee Weapon Attack: +9 to hit, reach 15 ft., one target. Hit: 19 (3d8 + 6) bludgeoning damage.", "attack_bonus": 9, "damage": "3d8+6"}, {"name": "Rock", "description": "Ranged Weapon Attack: +9 to hit, range 60/240 ft., one target. Hit: 28 (4d10 + 6) bludgeoning damage. If the target is a creature, it must succeed on a DC 17 Strength saving throw or be knocked prone.", "attack_bonus": 9, "damage": "4d10+6"}]',
+        '[{"name": "Rock Catching", "description": "If a rock or similar object is hurled at the giant, the giant can, with a successful DC 10 Dexterity saving throw, catch the missile and take no bludgeoning damage from it.", "attack_bonus": null, "damage": null}]', 0, '[]'),
+    ('young-black-dragon', 'Young Black Dragon', 'Large', 'Dragon', '', 'chaotic evil',
+        18, 'natural armor', 127, '15d10+45', '{"walk": 40, "fly": 80, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        19, 14, 17, 12, 11, 15, '[{"name": "Dex", "bonus": 5}, {"name": "Con", "bonus": 6}, {"name": "Wis", "bonus": 3}, {"name": "Cha", "bonus": 5}]', '[{"name": "Perception", "bonus": 6}, {"name": "Stealth", "bonus": 5}]',
+        '', '', 'acid', '',
+        'blindsight 30 ft., darkvision 120 ft., passive Perception 16', 'Common, Draconic', 7, '["Swamp"]',
+        '[{"name": "Amphibious", "description": "The dragon can breathe air and water.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +7 to hit, reach 10 ft., one target. Hit: 15 (2d10 + 4) piercing damage plus 4 (1d8) acid damage.", "attack_bonus": 7, "damage": "2d10+4"}, {"name": "Claw", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 11 (2d6 + 4) slashing damage.", "attack_bonus": 7, "damage": "2d6+4"}, {"name": "Acid Breath (Recharge 5-6)", "description": "The dragon exhales acid in a 30-foot line that is 5 feet wide. Each creature in that line must make a DC 14 Dexterity saving throw, taking 49 (11d8) acid damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "11d8"}]',
+        '[]', 0, '[]'),
+    ('young-copper-dragon', 'Young Copper Dragon', 'Large', 'Dragon', '', 'chaotic good',
+        17, 'natural armor', 119, '14d10+42', '{"walk": 40, "fly": 80, "swim": 0, "climb": 40, "burrow": 0, "hover": false}',
+        19, 12, 17, 16, 13, 15, '[{"name": "Dex", "bonus": 4}, {"name": "Con", "bonus": 6}, {"name": "Wis", "bonus": 4}, {"name": "Cha", "bonus": 5}]', '[{"name": "Deception", "bonus": 5}, {"name": "Perception", "bonus": 7}, {"name": "Stealth", "bonus": 4}]',
+        '', '', 'acid', '',
+        'blindsight 30 ft., darkvision 120 ft., passive Perception 17', 'Common, Draconic', 7, '["Hill", "Mountain"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The dragon makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +7 to hit, reach 10 ft., one target. Hit: 15 (2d10 + 4) piercing damage.", "attack_bonus": 7, "damage": "2d10+4"}, {"name": "Claw", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 11 (2d6 + 4) slashing damage.", "attack_bonus": 7, "damage": "2d6+4"}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Acid Breath. The dragon exhales acid in a 40-foot line that is 5 feet wide. Each creature in that line must make a DC 14 Dexterity saving throw, taking 40 (9d8) acid damage on a failed save, or half as much damage on a successful one. Slowing Breath. The dragon exhales gas in a 30-foot cone. Each creature in that area must succeed on a DC 14 Constitution saving throw. On a failed save, the creature can''t use reactions, its speed is halved, and it can''t make more than one attack on its turn. In addition, the creature can use either an action or a bonus action on its turn, but not both. These effects last for 1 minute. The creature can repeat the saving throw at the end of each of its turns, ending the effect on itself with a successful save.", "attack_bonus": null, "damage": "9d8"}]',
+        '[]', 0, '[]'),
+    ('assassin', 'Assassin', 'Medium', 'Humanoid', 'any race', 'any non-good alignment',
+        15, 'studded leather', 78, '12d8+24', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        11, 16, 14, 13, 11, 10, '[{"name": "Dex", "bonus": 6}, {"name": "Int", "bonus": 4}]', '[{"name": "Acrobatics", "bonus": 6}, {"name": "Deception", "bonus": 3}, {"name": "Perception", "bonus": 3}, {"name": "Stealth", "bonus": 9}]',
+        '', 'poison', '', '',
+        'passive Perception 13', 'Thieves'' cant plus any two languages', 8, '["Urban"]',
+        '[{"name": "Assassinate", "description": "During its first turn, the assassin has advantage on attack rolls against any creature that hasn''t taken a turn. Any hit the assassin scores against a surprised creature is a critical hit.", "attack_bonus": null, "damage": null}, {"name": "Evasion", "description": "If the assassin is subjected to an effect that allows it to make a Dexterity saving throw to take only half damage, the assassin instead takes no damage if it succeeds on the saving throw, and only half damage if it fails.", "attack_bonus": null, "damage": null}, {"name": "Sneak Attack (1/Turn)", "description": "The assassin deals an extra 14 (4d6) damage when it hits a target with a weapon attack and has advantage on the attack roll, or when the target is within 5 feet of an ally of the assassin that isn''t incapacitated and the assassin doesn''t have disadvantage on the attack roll.", "attack_bonus": null, "damage": "4d6"}]',
+        '[{"name": "Multiattack", "description": "The assassin makes two shortsword attacks.", "attack_bonus": null, "damage": null}, {"name": "Shortsword", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one target. Hit: 6 (1d6 + 3) piercing damage. The target must make a DC 15 Constitution saving throw, taking 24 (7d6) poison damage on a failed save, or half as much damage on a successful one.", "attack_bonus": 6, "damage": "1d6+3"}, {"name": "Light Crossbow", "description": "Ranged Weapon Attack: +6 to hit, range 80/320 ft., one target. Hit: 7 (1d8 + 3) piercing damage. The target must make a DC 15 Constitution saving throw, taking 24 (7d6) poison damage on a failed save, or half as much damage on a successful one.", "attack_bonus": 6, "damage": "1d8+3"}]',
+        '[]', 0, '[]'),
+    ('chain-devil', 'Chain Devil', 'Medium', 'Fiend', 'devil', 'lawful evil',
+        16, 'natural armor', 85, '10d8+40', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 15, 18, 11, 12, 14, '[{"name": "Con", "bonus": 7}, {"name": "Wis", "bonus": 4}, {"name": "Cha", "bonus": 5}]', '[]',
+        '', 'cold; bludgeoning, piercing, and slashing from nonmagical attacks that aren''t silvered', 'fire, poison', 'poisoned',
+        'darkvision 120 ft., passive Perception 11', 'Infernal, telepathy 120 ft.', 8, '[]',
+        '[{"name": "Devil''s Sight", "description": "Magical darkness doesn''t impede the devil''s darkvision.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The devil has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The devil makes two attacks with its chains.", "attack_bonus": null, "damage": null}, {"name": "Chain", "description": "Melee Weapon Attack: +8 to hit, reach 10 ft., one target. Hit: 11 (2d6 + 4) slashing damage. The target is grappled (escape DC 14) if the devil isn''t already grappling a creature. Until this grapple ends, the target is restrained and takes 7 (2d6) piercing damage at the start of each of its turns.", "attack_bonus": 8, "damage": "2d6+4"}, {"name": "Animate Chains (Recharges after a Short or Long Rest)", "description": "Up to four chains the devil can see within 60 feet of it magically sprout razor-edged barbs and animate under the devil''s control, provided that the chains aren''t being worn or carried. Each animated chain is an object with AC 20, 20 hit points, resistance to piercing damage, and immunity to psychic and thunder damage. When the devil uses Multiattack on its turn, it can use each animated chain to make one additional chain attack. An animated chain can grapple one creature of its own but can''t make attacks while grappling. An animated chain reverts to its inanimate state if reduced to 0 hit points or if the devil is incapacitated or dies.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Unnerving Mask", "description": "When a creature the devil can see starts its turn within 30 feet of the devil, the devil can create the illusion that it looks like one of the creature''s departed loved ones or bitter enemies. If the creature can see the devil, it must succeed on a DC 14 Wisdom saving throw or be frightened until the end of its turn.", "attack_bonus": null, "damage": null}]', 0, '[]'),
+    ('cloaker', 'Cloaker', 'Large', 'Aberration', '', 'chaotic neutral',
+        14, 'natural armor', 78, '12d10+12', '{"walk": 10, "fly": 40, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        17, 15, 12, 13, 12, 14, '[]', '[{"name": "Stealth", "bonus": 5}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 11', 'Deep Speech, Undercommon', 8, '["Underdark"]',
+        '[{"name": "Damage Transfer", "description": "While attached to a creature, the cloaker takes only half the damage dealt to it (rounded down), and that creature takes the other half.", "attack_bonus": null, "damage": null}, {"name": "False Appearance", "description": "While the cloaker remains motionless without its underside exposed, it is indistinguishable from a dark leather cloak.", "attack_bonus": null, "damage": null}, {"name": "Light Sensitivity", "description": "While in bright light, the cloaker has disadvantage on attack rolls and Wisdom (Perception) checks that rely on sight.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The cloaker makes two attacks: one with its bite and one with its tail.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +6 to hit, reach 5 ft., one creature. Hit: 10 (2d6 + 3) piercing damage. If the target is Large or smaller, the cloaker attaches to it. If the cloaker has advantage against the target, the cloaker attaches to the target''s head, and the target is blinded and unable to breathe while the cloaker is attached. While attached, the cloaker can make this attack only against the target and has advantage on the attack roll. The cloaker can detach itself by spending 5 feet of its movement. A creature, including the target, can take its action to detach the cloaker by succeeding on a DC 16 Strength check.", "attack_bonus": 6, "damage": "2d6+3"}, {"name": "Tail", "description": "Melee Weapon Attack: +6 to hit, reach 10 ft., one creature. Hit: 7 (1d8 + 3) slashing damage.", "attack_bonus": 6, "damage": "1d8+3"}, {"name": "Moan", "description": "Each creature within 60 feet of the cloaker that can hear its moan and that isn''t an aberration must succeed on a DC 13 Wisdom saving throw or become frightened until the end of the cloaker''s next turn. If a creature''s saving throw is successful, the creature is immune to the cloaker''s moan for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Phantasms (Recharges after a Short or Long Rest)", "description": "The cloaker magically creates three illusory duplicates of itself if it isn''t in bright light. The duplicates move with it and mimic its actions, shifting position so as to make it impossible to track which cloaker is the real one. If the cloaker is ever in an area of bright light, the duplicates disappear. Whenever any creature targets the cloaker with an attack or a harmful spell while a duplicate remains, that creature rolls randomly to determine whether it targets the cloaker or one of the duplicates. A duplicate has the cloaker''s AC and uses its saving throws. If an attack hits a duplicate, or if a duplicate fails a saving throw against an effect that deals damage, the duplicate disappears.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('frost-giant', 'Frost Giant', 'Huge', 'Giant', '', 'neutral evil',
+        15, 'patchwork armor', 138, '12d12+60', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        23, 9, 21, 9, 10, 12, '[{"name": "Con", "bonus": 8}, {"name": "Wis", "bonus": 3}, {"name": "Cha", "bonus": 4}]', '[{"name": "Athletics", "bonus": 9}, {"name": "Perception", "bonus": 3}]',
+        '', '', 'cold', '',
+        'passive Perception 13', 'Giant', 8, '["Arctic", "Mountain"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The giant makes two greataxe attacks.", "attack_bonus": null, "damage": null}, {"name": "Greataxe", "description": "Melee Weapon Attack: +9 to hit, reach 10 ft., one target. Hit: 25 (3d12 + 6) slashing damage.", "attack_bonus": 9, "damage": "3d12+6"}, {"name": "Rock", "description": "Ranged Weapon Attack: +9 to hit, range 60/240 ft., one target. Hit: 28 (4d10 + 6) bludgeoning damage.", "attack_bonus": 9, "damage": "4d10+6"}]',
+        '[]', 0, '[]'),
+    ('hezrou', 'Hezrou', 'Large', 'Fiend', 'demon', 'chaotic evil',
+        16, 'natural armor', 136, '13d10+65', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        19, 17, 20, 5, 12, 13, '[{"name": "Str", "bonus": 7}, {"name": "Con", "bonus": 8}, {"name": "Wis", "bonus": 4}]', '[]',
+        '', 'cold, fire, lightning; bludgeoning, piercing, and slashing from nonmagical attacks', 'poison', 'poisoned',
+        'darkvision 120 ft., passive Perception 11', 'Abyssal, telepathy 120 ft.', 8, '[]',
+        '[{"name": "Magic Resistance", "description": "The hezrou has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}, {"name": "Stench", "description": "Any creature that starts its turn within 10 feet of the hezrou must succeed on a DC 14 Constitution saving throw or be poisoned until the start of its next turn. On a successful saving throw, the creature is immune to the hezrou''s stench for 24 hours.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The hezrou makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 15 (2d10 + 4) piercing damage.", "attack_bonus": 7, "damage": "2d10+4"}, {"name": "Claw", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 11 (2d6 + 4) slashing damage.", "attack_bonus": 7, "damage": "2d6+4"}]',
+        '[]', 0, '[]'),
+    ('hydra', 'Hydra', 'Huge', 'Monstrosity', '', 'unaligned',
+        15, 'natural armor', 172, '15d12+75', '{"walk": 30, "fly": 0, "swim": 30, "climb": 0, "burrow": 0, "hover": false}',
+        20, 12, 20, 2, 10, 7, '[]', '[{"name": "Perception", "bonus": 6}]',
+        '', '', '', '',
+        'darkvision 60 ft., passive Perception 16', '', 8, '["Swamp", "Coastal"]',
+        '[{"name": "Hold Breath", "description": "The hydra can hold its breath for 1 hour.", "attack_bonus": null, "damage": null}, {"name": "Multiple Heads", "description": "The hydra has five heads. While it has more than one head, the hydra has advantage on saving throws against being blinded, charmed, deafened, frightened, stunned, and knocked unconscious. Whenever the hydra takes 25 or more damage in a single turn, one of its heads dies. If all its heads die, the hydra dies. At the end of its turn, it grows two heads for each of its heads that died since its last turn, unless it has taken fire damage since its last turn. The hydra regains 10 hit points for each head regrown in this way.", "attack_bonus": null, "damage": null}, {"name": "Reactive Heads", "description": "For each head the hydra has beyond one, it gets an extra reaction that can be used only for opportunity attacks.", "attack_bonus": null, "damage": null}, {"name": "Wakeful", "description": "While the hydra sleeps, at least one of its heads is awake.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The hydra makes as many bite attacks as it has heads.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +8 to hit, reach 10 ft., one target. Hit: 10 (1d10 + 5) piercing damage.", "attack_bonus": 8, "damage": "1d10+5"}]',
+        '[]', 0, '[]'),
+    ('spirit-naga', 'Spirit Naga', 'Large', 'Monstrosity', '', 'chaotic evil',
+        15, 'natural armor', 75, '10d10+20', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 17, 14, 16, 15, 16, '[{"name": "Dex", "bonus": 6}, {"name": "Con", "bonus": 5}, {"name": "Wis", "bonus": 5}, {"name": "Cha", "bonus": 6}]', '[]',
+        '', '', 'poison', 'charmed, poisoned',
+        'darkvision 60 ft., passive Perception 12', 'Abyssal, Common', 8, '["Underdark"]',
+        '[{"name": "Rejuvenation", "description": "If it dies, the naga returns to life in 1d6 days and regains all its hit points. Only a wish spell can prevent this trait from functioning.", "attack_bonus": null, "damage": null}, {"name": "Spellcasting", "description": "The naga is a 10th-level spellcaster. Its spellcasting ability is Intelligence (spell save DC 14, +6 to hit with spell attacks), and it needs only verbal components to cast its spells. It has the following wizard spells prepared. Cantrips: mage hand, minor illusion, ray of frost. 1st level (4 slots): charm person, detect magic, sleep. 2nd level (3 slots): detect thoughts, hold person. 3rd level (3 slots): lightning bolt, water breathing. 4th level (3 slots): blight, dimension door. 5th level (2 slots): dominate person.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +7 to hit, reach 10 ft., one creature. Hit: 7 (1d6 + 4) piercing damage. The target must make a DC 13 Constitution saving throw, taking 31 (7d8) poison damage on a failed save, or half as much damage on a successful one.", "attack_bonus": 7, "damage": "1d6+4"}]',
+        '[]', 0, '[]'),
+    ('tyrannosaurus-rex', 'Tyrannosaurus Rex', 'Huge', 'Beast', '', 'unaligned',
+        13, 'natural armor', 136, '13d12+52', '{"walk": 50, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        25, 10, 19, 2, 12, 9, '[]', '[{"name": "Perception", "bonus": 4}]',
+        '', '', '', '',
+        'passive Perception 14', '', 8, '["Forest", "Grassland"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The tyrannosaurus makes two attacks: one with its bite and one with its tail. It can''t make both attacks against the same target.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +10 to hit, reach 10 ft., one target. Hit: 33 (4d12 + 7) piercing damage. If the target is a Medium or smaller creature, it is grappled (escape DC 17). Until this grapple ends, the target is restrained, and the tyrannosaurus can''t bite another target.", "attack_bonus": 10, "damage": "4d12+7"}, {"name": "Tail", "description": "Melee Weapon Attack: +10 to hit, reach 10 ft., one target. Hit: 20 (3d8 + 7) bludgeoning damage.", "attack_bonus": 10, "damage": "3d8+7"}]',
+        '[]', 0, '[]'),
+    ('young-bronze-dragon', 'Young Bronze Dragon', 'Large', 'Dragon', '', 'lawful good',
+        18, 'natural armor', 142, '15d10+60', '{"walk": 40, "fly": 80, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        21, 10, 19, 14, 13, 17, '[{"name": "Dex", "bonus": 3}, {"name": "Con", "bonus": 7}, {"name": "Wis", "bonus": 4}, {"name": "Cha", "bonus": 6}]', '[{"name": "Insight", "bonus": 4}, {"name": "Perception", "bonus": 7}, {"name": "Stealth", "bonus": 3}]',
+        '', '', 'lightning', '',
+        'blindsight 30 ft., darkvision 120 ft., passive Perception 17', 'Common, Draconic', 8, '["Coastal", "Underwater"]',
+        '[{"name": "Amphibious", "description": "The dragon can breathe air and water.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +8 to hit, reach 10 ft., one target. Hit: 16 (2d10 + 5) piercing damage.", "attack_bonus": 8, "damage": "2d10+5"}, {"name": "Claw", "description": "Melee Weapon Attack: +8 to hit, reach 5 ft., one target. Hit: 12 (2d6 + 5) slashing damage.", "attack_bonus": 8, "damage": "2d6+5"}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Lightning Breath. The dragon exhales lightning in a 60-foot line that is 5 feet wide. Each creature in that line must make a DC 15 Dexterity saving throw, taking 55 (10d10) lightning damage on a failed save, or half as much damage on a successful one. Repulsion Breath. The dragon exhales repulsion energy in a 30-foot cone. Each creature in that area must succeed on a DC 15 Strength saving throw. On a failed save, the creature is pushed 40 feet away from the dragon.", "attack_bonus": null, "damage": "10d10"}]',
+        '[]', 0, '[]'),
+    ('bone-devil', 'Bone Devil', 'Large', 'Fiend', 'devil', 'lawful evil',
+        19, 'natural armor', 142, '15d10+60', '{"walk": 40, "fly": 40, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 16, 18, 13, 14, 16, '[{"name": "Int", "bonus": 5}, {"name": "Wis", "bonus": 6}, {"name": "Cha", "bonus": 7}]', '[{"name": "Deception", "bonus": 7}, {"name": "Insight", "bonus": 6}]',
+        '', 'cold; bludgeoning, piercing, and slashing from nonmagical attacks that aren''t silvered', 'fire, poison', 'poisoned',
+        'darkvision 120 ft., passive Perception 12', 'Infernal, telepathy 120 ft.', 9, '[]',
+        '[{"name": "Devil''s Sight", "description": "Magical darkness doesn''t impede the devil''s darkvision.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The devil has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The devil makes three attacks: two with its claws and one with its sting.", "attack_bonus": null, "damage": null}, {"name": "Claw", "description": "Melee Weapon Attack: +8 to hit, reach 10 ft., one target. Hit: 8 (1d8 + 4) slashing damage.", "attack_bonus": 8, "damage": "1d8+4"}, {"name": "Sting", "description": "Melee Weapon Attack: +8 to hit, reach 10 ft., one target. Hit: 13 (2d8 + 4) piercing damage plus 17 (5d6) poison damage. The target must succeed on a DC 14 Constitution saving throw or become poisoned for 1 minute. The target can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success.", "attack_bonus": 8, "damage": "2d8+4"}]',
+        '[]', 0, '[]'),
+    ('clay-golem', 'Clay Golem', 'Large', 'Construct', '', 'unaligned',
+        14, 'natural armor', 133, '14d10+56', '{"walk": 20, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        20, 9, 18, 3, 8, 1, '[]', '[]',
+        '', '', 'acid, poison, psychic; bludgeoning, piercing, and slashing from nonmagical attacks that aren''t adamantine', 'charmed, exhaustion, frightened, paralyzed, petrified, poisoned',
+        'darkvision 60 ft., passive Perception 9', 'understands the languages of its creator but can''t speak', 9, '[]',
+        '[{"name": "Acid Absorption", "description": "Whenever the golem is subjected to acid damage, it takes no damage and instead regains a number of hit points equal to the acid damage dealt.", "attack_bonus": null, "damage": null}, {"name": "Berserk", "description": "Whenever the golem starts its turn with 60 hit points or fewer, roll a d6. On a 6, the golem goes berserk. On each of its turns while berserk, the golem attacks the nearest creature it can see. If no creature is near enough to move to and attack, the golem attacks an object, with preference for an object smaller than itself. Once the golem goes berserk, it continues to do so until it is destroyed or regains all its hit points.", "attack_bonus": null, "damage": null}, {"name": "Immutable Form", "description": "The golem is immune to any spell or effect that would alter its form.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The golem has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}, {"name": "Magic Weapons", "description": "The golem''s weapon attacks are magical.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The golem makes two slam attacks.", "attack_bonus": null, "damage": null}, {"name": "Slam", "description": "Melee Weapon Attack: +8 to hit, reach 5 ft., one target. Hit: 16 (2d10 + 5) bludgeoning damage. If the target is a creature, it must succeed on a DC 15 Constitution saving throw or have its hit point maximum reduced by an amount equal to the damage taken. The target dies if this attack reduces its hit point maximum to 0. The reduction lasts until removed by the greater restoration spell or other magic.", "attack_bonus": 8, "damage": "2d10+5"}, {"name": "Haste (Recharge 5-6)", "description": "Until the end of its next turn, the golem magically gains a +2 bonus to its AC, has advantage on Dexterity saving throws, and can use its slam attack as a bonus action.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('cloud-giant', 'Cloud Giant', 'Huge', 'Giant', '', 'neutral good (50%) or neutral evil (50%)',
+        14, 'natural armor', 200, '16d12+96', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        27, 10, 22, 12, 16, 16, '[{"name": "Con", "bonus": 10}, {"name": "Wis", "bonus": 7}, {"name": "Cha", "bonus": 7}]', '[{"name": "Insight", "bonus": 7}, {"name": "Perception", "bonus": 7}]',
+        '', '', '', '',
+        'passive Perception 17', 'Common, Giant', 9, '["Mountain"]',
+        '[{"name": "Keen Smell", "description": "The giant has advantage on Wisdom (Perception) checks that rely on smell.", "attack_bonus": null, "damage": null}, {"name": "Innate Spellcasting", "description": "The giant''s innate spellcasting ability is Charisma. It can innately cast the following spells, requiring no material components. At will: detect magic, fog cloud, light. 3/day each: feather fall, fly, misty step, telekinesis. 1/day each: control weather, gaseous form.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The giant makes two morningstar attacks.", "attack_bonus": null, "damage": null}, {"name": "Morningstar", "description": "Melee Weapon Attack: +12 to hit, reach 10 ft., one target. Hit: 21 (3d8 + 8) piercing damage.", "attack_bonus": 12, "damage": "3d8+8"}, {"name": "Rock", "description": "Ranged Weapon Attack: +12 to hit, range 60/240 ft., one target. Hit: 30 (4d10 + 8) bludgeoning damage.", "attack_bonus": 12, "damage": "4d10+8"}]',
+        '[]', 0, '[]'),
+    ('fire-giant', 'Fire Giant', 'Huge', 'Giant', '', 'lawful evil',
+        18, 'plate', 162, '13d12+78', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        25, 9, 23, 10, 14, 13, '[{"name": "Dex", "bonus": 3}, {"name": "Con", "bonus": 10}, {"name": "Cha", "bonus": 5}]', '[{"name": "Athletics", "bonus": 11}, {"name": "Perception", "bonus": 6}]',
+        '', '', 'fire', '',
+        'passive Perception 16', 'Giant', 9, '["Mountain", "Underdark"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The giant makes two greatsword attacks.", "attack_bonus": null, "damage": null}, {"name": "Greatsword", "description": "Melee Weapon Attack: +11 to hit, reach 10 ft., one target. Hit: 28 (6d6 + 7) slashing damage.", "attack_bonus": 11, "damage": "6d6+7"}, {"name": "Rock", "description": "Ranged Weapon Attack: +11 to hit, range 60/240 ft., one target. Hit: 29 (4d10 + 7) bludgeoning damage.", "attack_bonus": 11, "damage": "4d10+7"}]',
+        '[]', 0, '[]'),
+    ('glabrezu', 'Glabrezu', 'Large', 'Fiend', 'demon', 'chaotic evil',
+        17, 'natural armor', 157, '15d10+75', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        20, 15, 21, 19, 17, 16, '[{"name": "Str", "bonus": 9}, {"name": "Con", "bonus": 9}, {"name": "Wis", "bonus": 7}, {"name": "Cha", "bonus": 7}]', '[]',
+        '', 'cold, fire, lightning; bludgeoning, piercing, and slashing from nonmagical attacks', 'poison', 'poisoned',
+        'truesight 120 ft., passive Perception 13', 'Abyssal, telepathy 120 ft.', 9, '[]',
+        '[{"name": "Innate Spellcasting", "description": "The glabrezu''s spellcasting ability is Intelligence (spell save DC 16). The glabrezu can innately cast the following spells, requiring no material components. At will: darkness, detect magic, dispel magic. 1/day each: confusion, fly, power word stun.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The glabrezu has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The glabrezu makes four attacks: two with its pincers and two with its fists. Alternatively, it makes two attacks with its pincers and casts one spell.", "attack_bonus": null, "damage": null}, {"name": "Pincer", "description": "Melee Weapon Attack: +9 to hit, reach 10 ft., one target. Hit: 16 (2d10 + 5) bludgeoning damage. If the target is a Medium or smaller creature, it is grappled (escape DC 15). The glabrezu has two pincers, each of which can grapple only one target.", "attack_bonus": 9, "damage": "2d10+5"}, {"name": "Fist", "description": "Melee Weapon Attack: +9 to hit, reach 5 ft., one target. Hit: 7 (2d4 + 2) bludgeoning damage.", "attack_bonus": 9, "damage": "2d4+2"}]',
+        '[]', 0, '[]'),
+    ('treant', 'Treant', 'Huge', 'Plant', '', 'chaotic good',
+        16, 'natural armor', 138, '12d12+60', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        23, 8, 21, 12, 16, 12, '[]', '[]',
+        'fire', 'bludgeoning, piercing', '', '',
+        'passive Perception 13', 'Common, Druidic, Elvish, Sylvan', 9, '["Forest"]',
+        '[{"name": "False Appearance", "description": "While the treant remains motionless, it is indistinguishable from a normal tree.", "attack_bonus": null, "damage": null}, {"name": "Siege Monster", "description": "The treant deals double damage to objects and structures.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The treant makes two slam attacks.", "attack_bonus": null, "damage": null}, {"name": "Slam", "description": "Melee Weapon Attack: +10 to hit, reach 5 ft., one target. Hit: 16 (3d6 + 6) bludgeoning damage.", "attack_bonus": 10, "damage": "3d6+6"}, {"name": "Rock", "description": "Ranged Weapon Attack: +10 to hit, range 60/180 ft., one target. Hit: 28 (4d10 + 6) bludgeoning damage.", "attack_bonus": 10, "damage": "4d10+6"}, {"name": "Animate Trees (1/Day)", "description": "The treant magically animates one or two trees it can see within 60 feet of it. These trees have the same statistics as a treant, except they have Intelligence and Charisma scores of 1, they can''t speak, and they have only the Slam action option. An animated tree acts as an ally of the treant. The tree remains animate for 1 day or until it dies; until the treant dies or is more than 120 feet from the tree; or until the treant takes a bonus action to turn it back into an inanimate tree. The tree then takes root if possible.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('young-blue-dragon', 'Young Blue Dragon', 'Large', 'Dragon', '', 'lawful evil',
+        18, 'natural armor', 152, '16d10+64', '{"walk": 40, "fly": 80, "swim": 0, "climb": 0, "burrow": 20, "hover": false}',
+        21, 10, 19, 14, 13, 17, '[{"name": "Dex", "bonus": 4}, {"name": "Con", "bonus": 8}, {"name": "Wis", "bonus": 5}, {"name": "Cha", "bonus": 7}]', '[{"name": "Perception", "bonus": 9}, {"name": "Stealth", "bonus": 4}]',
+        '', '', 'lightning', '',
+        'blindsight 30 ft., darkvision 120 ft., passive Perception 19', 'Common, Draconic', 9, '["Desert", "Coastal"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The dragon makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +9 to hit, reach 10 ft., one target. Hit: 16 (2d10 + 5) piercing damage plus 5 (1d10) lightning damage.", "attack_bonus": 9, "damage": "2d10+5"}, {"name": "Claw", "description": "Melee Weapon Attack: +9 to hit, reach 5 ft., one target. Hit: 12 (2d6 + 5) slashing damage.", "attack_bonus": 9, "damage": "2d6+5"}, {"name": "Lightning Breath (Recharge 5-6)", "description": "The dragon exhales lightning in a 60-foot line that is 5 feet wide. Each creature in that line must make a DC 16 Dexterity saving throw, taking 55 (10d10) lightning damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "10d10"}]',
+        '[]', 0, '[]'),
+    ('young-silver-dragon', 'Young Silver Dragon', 'Large', 'Dragon', '', 'lawful good',
+        18, 'natural armor', 168, '16d10+80', '{"walk": 40, "fly": 80, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        23, 10, 21, 14, 11, 19, '[{"name": "Dex", "bonus": 4}, {"name": "Con", "bonus": 9}, {"name": "Wis", "bonus": 4}, {"name": "Cha", "bonus": 8}]', '[{"name": "Arcana", "bonus": 6}, {"name": "History", "bonus": 6}, {"name": "Perception", "bonus": 8}, {"name": "Stealth", "bonus": 4}]',
+        '', '', 'cold', '',
+        'blindsight 30 ft., darkvision 120 ft., passive Perception 18', 'Common, Draconic', 9, '["Mountain", "Urban"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The dragon makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +10 to hit, reach 10 ft., one target. Hit: 17 (2d10 + 6) piercing damage.", "attack_bonus": 10, "damage": "2d10+6"}, {"name": "Claw", "description": "Melee Weapon Attack: +10 to hit, reach 5 ft., one target. Hit: 13 (2d6 + 6) slashing damage.", "attack_bonus": 10, "damage": "2d6+6"}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Cold Breath. The dragon exhales an icy blast in a 30-foot cone. Each creature in that area must make a DC 17 Constitution saving throw, taking 54 (12d8) cold damage on a failed save, or half as much damage on a successful one. Paralyzing Breath. The dragon exhales paralyzing gas in a 30-foot cone. Each creature in that area must succeed on a DC 17 Constitution saving throw or be paralyzed for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success.", "attack_bonus": null, "damage": "12d8"}]',
+        '[]', 0, '[]'),
+    ('aboleth', 'Aboleth', 'Large', 'Aberration', '', 'lawful evil',
+        17, 'natural armor', 135, '18d10+36', '{"walk": 10, "fly": 0, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        21, 9, 15, 18, 15, 18, '[{"name": "Con", "bonus": 6}, {"name": "Int", "bonus": 8}, {"name": "Wis", "bonus": 6}]', '[{"name": "History", "bonus": 12}, {"name": "Perception", "bonus": 10}]',
+        '', '', '', '',
+        'darkvision 120 ft., passive Perception 20', 'Deep Speech, telepathy 120 ft.', 10, '["Underdark", "Underwater"]',
+        '[{"name": "Amphibious", "description": "The aboleth can breathe air and water.", "attack_bonus": null, "damage": null}, {"name": "Mucous Cloud", "description": "While underwater, the aboleth is surrounded by transformative mucus. A creature that touches the aboleth or that hits it with a melee attack while within 5 feet of it must make a DC 14 Constitution saving throw. On a failure, the creature is diseased for 1d4 hours. The diseased creature can breathe only underwater.", "attack_bonus": null, "damage": null}, {"name": "Probing Telepathy", "description": "If a creature communicates telepathically with the aboleth, the aboleth learns the creature''s greatest desires if the aboleth can see the creature.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The aboleth makes three tentacle attacks.", "attack_bonus": null, "damage": null}, {"name": "Tentacle", "description": "Melee Weapon Attack: +9 to hit, reach 10 ft., one target. Hit: 12 (2d6 + 5) bludgeoning damage. If the target is a creature, it must succeed on a DC 14 Constitution saving throw or become diseased. The disease has no effect for 1 minute and can be removed by any magic that cures disease. After 1 minute, the diseased creature''s skin becomes translucent and slimy, the creature can''t regain hit points unless it is underwater, and the disease can be removed only by heal or another disease-curing spell of 6th level or higher. When the creature is outside a body of water, it takes 6 (1d12) acid damage every 10 minutes unless moisture is applied to the skin before 10 minutes have passed.", "attack_bonus": 9, "damage": "2d6+5"}, {"name": "Tail", "description": "Melee Weapon Attack: +9 to hit, reach 10 ft., one target. Hit: 15 (3d6 + 5) bludgeoning damage.", "attack_bonus": 9, "damage": "3d6+5"}, {"name": "Enslave (3/Day)", "description": "The aboleth targets one creature it can see within 30 feet of it. The target must succeed on a DC 14 Wisdom saving throw or be magically charmed by the aboleth until the aboleth dies or until it is on a different plane of existence from the target. The charmed target is under the aboleth''s control and can''t take reactions, and the aboleth and the target can communicate telepathically with each other over any distance. Whenever the charmed target takes damage, the target can repeat the saving throw. On a success, the effect ends. No more than once every 24 hours, the target can also repeat the saving throw when it is at least 1 mile away from the aboleth.", "attack_bonus": null, "damage": null}]',
+        '[]', 3, '[{"name": "Detect", "description": "The aboleth makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Swipe", "description": "The aboleth makes one tail attack.", "attack_bonus": null, "damage": null}, {"name": "Psychic Drain (Costs 2 Actions)", "description": "One creature charmed by the aboleth takes 10 (3d6) psychic damage, and the aboleth regains hit points equal to the damage the creature takes.", "attack_bonus": null, "damage": "3d6"}]'),
+    ('deva', 'Deva', 'Medium', 'Celestial', '', 'lawful good',
+        17, 'natural armor', 136, '16d8+64', '{"walk": 30, "fly": 90, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 18, 18, 17, 20, 20, '[{"name": "Wis", "bonus": 9}, {"name": "Cha", "bonus": 9}]', '[{"name": "Insight", "bonus": 9}, {"name": "Perception", "bonus": 9}]',
+        '', 'radiant; bludgeoning, piercing, and slashing from nonmagical attacks', '', 'charmed, exhaustion, frightened',
+        'darkvision 120 ft., passive Perception 19', 'all, telepathy 120 ft.', 10, '[]',
+        '[{"name": "Angelic Weapons", "description": "The deva''s weapon attacks are magical. When the deva hits with any weapon, the weapon deals an extra 4d8 radiant damage (included in the attack).", "attack_bonus": null, "damage": null}, {"name": "Innate Spellcasting", "description": "The deva''s spellcasting ability is Charisma (spell save DC 17). The deva can innately cast the following spells, requiring only verbal components. At will: detect evil and good. 1/day each: commune, raise dead.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The deva has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The deva makes two melee attacks.", "attack_bonus": null, "damage": null}, {"name": "Mace", "description": "Melee Weapon Attack: +8 to hit, reach 5 ft., one target. Hit: 7 (1d6 + 4) bludgeoning damage plus 18 (4d8) radiant damage.", "attack_bonus": 8, "damage": "1d6+4"}, {"name": "Healing Touch (3/Day)", "description": "The deva touches another creature. The target magically regains 20 (4d8 + 2) hit points and is freed from any curse, disease, poison, blindness, or deafness.", "attack_bonus": null, "damage": null}, {"name": "Change Shape", "description": "The deva magically polymorphs into a humanoid or beast that has a challenge rating equal to or less than its own, or back into its true form. It reverts to its true form if it dies. Any equipment it is wearing or carrying is absorbed or borne by the new form (the deva''s choice). In a new form, the deva retains its game statistics and ability to speak, but its AC, movement modes, Strength, Dexterity, and special senses are replaced by those of the new form, and it gains any statistics and capabilities (except class features, legendary actions, and lair actions) that the new form has but that it lacks.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('guardian-naga', 'Guardian Naga', 'Large', 'Monstrosity', '', 'lawful good',
+        18, 'natural armor', 127, '15d10+45', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        19, 18, 16, 16, 19, 18, '[{"name": "Dex", "bonus": 8}, {"name": "Con", "bonus": 7}, {"name": "Int", "bonus": 7}, {"name": "Wis", "bonus": 8}, {"name": "Cha", "bonus": 8}]', '[]',
+        '', '', 'poison', 'charmed, poisoned',
+        'darkvision 60 ft., passive Perception 14', 'Celestial, Common', 10, '["Desert", "Forest"]',
+        '[{"name": "Rejuvenation", "description": "If it dies, the naga returns to life in 1d6 days and regains all its hit points. Only a wish spell can prevent this trait from functioning.", "attack_bonus": null, "damage": null}, {"name": "Spellcasting", "description": "The naga is an 11th-level spellcaster. Its spellcasting ability is Wisdom (spell save DC 16, +8 to hit with spell attacks), and it needs only verbal components to cast its spells. It has the following cleric spells prepared. Cantrips: mending, sacred flame, thaumaturgy. 1st level (4 slots): command, cure wounds, shield of faith. 2nd level (3 slots): calm emotions, hold person. 3rd level (3 slots): bestow curse, clairvoyance. 4th level (3 slots): banishment, freedom of movement. 5th level (2 slots): flame strike, geas. 6th level (1 slot): true seeing.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +8 to hit, reach 10 ft., one creature. Hit: 8 (1d8 + 4) piercing damage. The target must make a DC 15 Constitution saving throw, taking 45 (10d8) poison damage on a failed save, or half as much damage on a successful one.", "attack_bonus": 8, "damage": "1d8+4"}, {"name": "Spit Poison", "description": "Ranged Weapon Attack: +8 to hit, range 15/30 ft., one creature. Hit: The target must make a DC 15 Constitution saving throw, taking 45 (10d8) poison damage on a failed save, or half as much damage on a successful one.", "attack_bonus": 8, "damage": null}]',
+        '[]', 0, '[]'),
+    ('stone-golem', 'Stone Golem', 'Large', 'Construct', '', 'unaligned',
+        17, 'natural armor', 178, '17d10+85', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        22, 9, 20, 3, 11, 1, '[]', '[]',
+        '', '', 'poison, psychic; bludgeoning, piercing, and slashing from nonmagical attacks that aren''t adamantine', 'charmed, exhaustion, frightened, paralyzed, petrified, poisoned',
+        'darkvision 120 ft., passive Perception 10', 'understands the languages of its creator but can''t speak', 10, '[]',
+        '[{"name": "Immutable Form", "description": "The golem is immune to any spell or effect that would alter its form.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The golem has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}, {"name": "Magic Weapons", "description": "The golem''s weapon attacks are magical.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The golem makes two slam attacks.", "attack_bonus": null, "damage": null}, {"name": "Slam", "description": "Melee Weapon Attack: +10 to hit, reach 5 ft., one target. Hit: 19 (3d8 + 6) bludgeoning damage.", "attack_bonus": 10, "damage": "3d8+6"}, {"name": "Slow (Recharge 5-6)", "description": "The golem targets one or more creatures it can see within 10 feet of it. Each target must make a DC 17 Wisdom saving throw against this magic. On a failed save, a target can''t use reactions, its speed is halved, and it can''t make more than one attack on its turn. In addition, the target can take either an action or a bonus action on its turn, not both. These effects last for 1 minute. A target can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('young-gold-dragon', 'Young Gold Dragon', 'Large', 'Dragon', '', 'lawful good',
+        18, 'natural armor', 178, '17d10+85', '{"walk": 40, "fly": 80, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        23, 14, 21, 16, 13, 20, '[{"name": "Dex", "bonus": 6}, {"name": "Con", "bonus": 9}, {"name": "Wis", "bonus": 5}, {"name": "Cha", "bonus": 9}]', '[{"name": "Insight", "bonus": 5}, {"name": "Perception", "bonus": 9}, {"name": "Persuasion", "bonus": 9}, {"name": "Stealth", "bonus": 6}]',
+        '', '', 'fire', '',
+        'blindsight 30 ft., darkvision 120 ft., passive Perception 19', 'Common, Draconic', 10, '["Forest", "Grassland"]',
+        '[{"name": "Amphibious", "description": "The dragon can breathe air and water.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +10 to hit, reach 10 ft., one target. Hit: 17 (2d10 + 6) piercing damage.", "attack_bonus": 10, "damage": "2d10+6"}, {"name": "Claw", "description": "Melee Weapon Attack: +10 to hit, reach 5 ft., one target. Hit: 13 (2d6 + 6) slashing damage.", "attack_bonus": 10, "damage": "2d6+6"}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Fire Breath. The dragon exhales fire in a 30-foot cone. Each creature in that area must make a DC 17 Dexterity saving throw, taking 55 (10d10) fire damage on a failed save, or half as much damage on a successful one. Weakening Breath. The dragon exhales gas in a 30-foot cone. Each creature in that area must succeed on a DC 17 Strength saving throw or have disadvantage on Strength-based attack rolls, Strength checks, and Strength saving throws for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success.", "attack_bonus": null, "damage": "10d10"}]',
+        '[]', 0, '[]'),
+    ('behir', 'Behir', 'Huge', 'Monstrosity', '', 'neutral evil',
+        17, 'natural armor', 168, '16d12+64', '{"walk": 50, "fly": 0, "swim": 0, "climb": 40, "burrow": 0, "hover": false}',
+        23, 16, 18, 7, 14, 12, '[]', '[{"name": "Perception", "bonus": 6}, {"name": "Stealth", "bonus": 7}]',
+        '', '', 'lightning', '',
+        'darkvision 90 ft., passive Perception 16', 'Draconic', 11, '["Underdark", "Mountain"]',
+        '[]',
+        '[{"name": "Multiattack", "description": "The behir makes two attacks: one with its bite and one to constrict.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +10 to hit, reach 10 ft., one target. Hit: 22 (3d10 + 6) piercing damage.", "attack_bonus": 10, "damage": "3d10+6"}, {"name": "Constrict", "description": "Melee Weapon Attack: +10 to hit, reach 5 ft., one Large or smaller creature. Hit: 17 (2d10 + 6) bludgeoning damage plus 17 (2d10 + 6) slashing damage. The target is grappled (escape DC 16) if the behir isn''t already constricting a creature, and the target is restrained until this grapple ends.", "attack_bonus": 10, "damage": "2d10+6"}, {"name": "Lightning Breath (Recharge 5-6)", "description": "The behir exhales a line of lightning that is 20 feet long and 5 feet wide. Each creature in that line must make a DC 16 Dexterity saving throw, taking 66 (12d10) lightning damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "12d10"}, {"name": "Swallow", "description": "The behir makes one bite attack against a Medium or smaller target it is grappling. If the attack hits, the target is also swallowed, and the grapple ends. While swallowed, the target is blinded and restrained, it has total cover against attacks and other effects outside the behir, and it takes 21 (6d6) acid damage at the start of each of the behir''s turns. A behir can have only one creature swallowed at a time. If the behir takes 30 damage or more on a single turn from the swallowed creature, the behir must succeed on a DC 14 Constitution saving throw at the end of that turn or regurgitate the creature, which falls prone in a space within 10 feet of the behir. If the behir dies, a swallowed creature is no longer restrained by it and can escape from the corpse by using 15 feet of movement, exiting prone.", "attack_bonus": null, "damage": "6d6"}]',
+        '[]', 0, '[]'),
+    ('djinni', 'Djinni', 'Large', 'Elemental', '', 'chaotic good',
+        17, 'natural armor', 161, '14d10+84', '{"walk": 30, "fly": 90, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        21, 15, 22, 15, 16, 20, '[{"name": "Dex", "bonus": 6}, {"name": "Wis", "bonus": 7}, {"name": "Cha", "bonus": 9}]', '[]',
+        '', '', 'lightning, thunder', '',
+        'darkvision 120 ft., passive Perception 13', 'Auran', 11, '["Desert"]',
+        '[{"name": "Elemental Demise", "description": "If the djinni dies, its body disintegrates into a warm breeze, leaving behind equipment the djinni was wearing or carrying.", "attack_bonus": null, "damage": null}, {"name": "Innate Spellcasting", "description": "The djinni''s innate spellcasting ability is Charisma (spell save DC 17, +9 to hit with spell attacks). It can innately cast the following spells, requiring no material components. At will: detect evil and good, detect magic, thunderwave. 3/day each: create food and water (can create wine instead of water), tongues, wind walk. 1/day each: conjure elemental (air elemental only), creation, gaseous form, invisibility, major image, plane shift.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The djinni makes three scimitar attacks.", "attack_bonus": null, "damage": null}, {"name": "Scimitar", "description": "Melee Weapon Attack: +9 to hit, reach 5 ft., one target. Hit: 12 (2d6 + 5) slashing damage plus 3 (1d6) lightning or thunder damage (djinni''s choice).", "attack_bonus": 9, "damage": "2d6+5"}, {"name": "Create Whirlwind", "description": "A 5-foot-radius, 30-foot-tall cylinder of swirling air magically forms on a point the djinni can see within 120 feet of it. The whirlwind lasts as long as the djinni maintains concentration (as if concentrating on a spell). Any creature but the djinni that enters the whirlwind must succeed on a DC 18 Strength saving throw or be restrained by it. The djinni can move the whirlwind up to 60 feet as an action, and creatures restrained by the whirlwind move with it. The whirlwind ends if the djinni loses sight of it. A creature can use its action to free a creature restrained by the whirlwind, including itself, by succeeding on a DC 18 Strength check. If the check succeeds, the creature is no longer restrained and moves to the nearest space outside the whirlwind.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('efreeti', 'Efreeti', 'Large', 'Elemental', '', 'lawful evil',
+        17, 'natural armor', 200, '16d10+112', '{"walk": 40, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        22, 12, 24, 16, 15, 16, '[{"name": "Int", "bonus": 7}, {"name": "Wis", "bonus": 6}, {"name": "Cha", "bonus": 7}]', '[]',
+        '', '', 'fire', '',
+        'darkvision 120 ft., passive Perception 12', 'Ignan', 11, '["Desert"]',
+        '[{"name": "Elemental Demise", "description": "If the efreeti dies, its body disintegrates into a flash of fire and puff of smoke, leaving behind equipment the efreeti was wearing or carrying.", "attack_bonus": null, "damage": null}, {"name": "Innate Spellcasting", "description": "The efreeti''s innate spellcasting ability is Charisma (spell save DC 15, +7 to hit with spell attacks). It can innately cast the following spells, requiring no material components. At will: detect magic. 3/day each: enlarge/reduce, tongues. 1/day each: conjure elemental (fire elemental only), gaseous form, invisibility, major image, plane shift, wall of fire.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The efreeti makes two scimitar attacks or uses its Hurl Flame twice.", "attack_bonus": null, "damage": null}, {"name": "Scimitar", "description": "Melee Weapon Attack: +10 to hit, reach 5 ft., one target. Hit: 13 (2d6 + 6) slashing damage plus 7 (2d6) fire damage.", "attack_bonus": 10, "damage": "2d6+6"}, {"name": "Hurl Flame", "description": "Ranged Spell Attack: +7 to hit, range 120 ft., one target. Hit: 17 (5d6) fire damage.", "attack_bonus": 7, "damage": "5d6"}]',
+        '[]', 0, '[]'),
+    ('gynosphinx', 'Gynosphinx', 'Large', 'Monstrosity', '', 'lawful neutral',
+        17, 'natural armor', 136, '16d10+48', '{"walk": 40, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 15, 16, 18, 18, 18, '[]', '[{"name": "Arcana", "bonus": 12}, {"name": "History", "bonus": 12}, {"name": "Perception", "bonus": 8}, {"name": "Religion", "bonus": 8}]',
+        '', 'bludgeoning, piercing, and slashing from nonmagical attacks', 'psychic', 'charmed, frightened',
+        'truesight 120 ft., passive Perception 18', 'Common, Sphinx', 11, '["Desert"]',
+        '[{"name": "Inscrutable", "description": "The sphinx is immune to any effect that would sense its emotions or read its thoughts, as well as any divination spell that it refuses. Wisdom (Insight) checks made to ascertain the sphinx''s intentions or sincerity have disadvantage.", "attack_bonus": null, "damage": null}, {"name": "Magic Weapons", "description": "The sphinx''s weapon attacks are magical.", "attack_bonus": null, "damage": null}, {"name": "Spellcasting", "description": "The sphinx is a 9th-level spellcaster. Its spellcasting ability is Intelligence (spell save DC 16, +8 to hit with spell attacks). It requires no material components to cast its spells. The sphinx has the following wizard spells prepared. Cantrips: mage hand, minor illusion, prestidigitation. 1st level (4 slots): detect magic, identify, shield. 2nd level (3 slots): darkness, locate object, suggestion. 3rd level (3 slots): dispel magic, remove curse, tongues. 4th level (3 slots): banishment, greater invisibility. 5th level (1 slot): legend lore.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The sphinx makes two claw attacks.", "attack_bonus": null, "damage": null}, {"name": "Claw", "description": "Melee Weapon Attack: +9 to hit, reach 5 ft., one target. Hit: 13 (2d8 + 4) slashing damage.", "attack_bonus": 9, "damage": "2d8+4"}]',
+        '[]', 3, '[{"name": "Claw Attack", "description": "The sphinx makes one claw attack.", "attack_bonus": null, "damage": null}, {"name": "Teleport (Costs 2 Actions)", "description": "The sphinx magically teleports, along with any equipment it is wearing or carrying, up to 120 feet to an unoccupied space it can see.", "attack_bonus": null, "damage": null}, {"name": "Cast a Spell (Costs 3 Actions)", "description": "The sphinx casts a spell from its list of prepared spells, using a spell slot as normal.", "attack_bonus": null, "damage": null}]'),
+    ('horned-devil', 'Horned Devil', 'Large', 'Fiend', 'devil', 'lawful evil',
+        18, 'natural armor', 178, '17d10+85', '{"walk": 20, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        22, 17, 21, 12, 16, 17, '[{"name": "Str", "bonus": 10}, {"name": "Dex", "bonus": 7}, {"name": "Wis", "bonus": 7}, {"name": "Cha", "bonus": 7}]', '[]',
+        '', 'cold; bludgeoning, piercing, and slashing from nonmagical attacks that aren''t silvered', 'fire, poison', 'poisoned',
+        'darkvision 120 ft., passive Perception 13', 'Infernal, telepathy 120 ft.', 11, '[]',
+        '[{"name": "Devil''s Sight", "description": "Magical darkness doesn''t impede the devil''s darkvision.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The devil has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The devil makes three melee attacks: two with its fork and one with its tail. It can use Hurl Flame in place of any melee attack.", "attack_bonus": null, "damage": null}, {"name": "Fork", "description": "Melee Weapon Attack: +10 to hit, reach 10 ft., one target. Hit: 15 (2d8 + 6) piercing damage.", "attack_bonus": 10, "damage": "2d8+6"}, {"name": "Tail", "description": "Melee Weapon Attack: +10 to hit, reach 10 ft., one target. Hit: 10 (1d8 + 6) piercing damage. If the target is a creature other than an undead or a construct, it must succeed on a DC 17 Constitution saving throw or lose 10 (3d6) hit points at the start of each of its turns due to an infernal wound. Each time the devil hits the wounded target with this attack, the damage dealt by the wound increases by 10 (3d6). Any creature can take an action to stanch the wound with a successful DC 12 Wisdom (Medicine) check. The wound also closes if the target receives magical healing.", "attack_bonus": 10, "damage": "1d8+6"}, {"name": "Hurl Flame", "description": "Ranged Spell Attack: +7 to hit, range 150 ft., one target. Hit: 14 (4d6) fire damage. If the target is a flammable object that isn''t being worn or carried, it also catches fire.", "attack_bonus": 7, "damage": "4d6"}]',
+        '[]', 0, '[]'),
+    ('remorhaz', 'Remorhaz', 'Huge', 'Monstrosity', '', 'unaligned',
+        17, 'natural armor', 195, '17d12+85', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 20, "hover": false}',
+        24, 13, 21, 4, 10, 5, '[]', '[]',
+        '', '', 'cold, fire', '',
+        'darkvision 60 ft., tremorsense 60 ft., passive Perception 10', '', 11, '["Arctic"]',
+        '[{"name": "Heated Body", "description": "A creature that touches the remorhaz or hits it with a melee attack while within 5 feet of it takes 10 (3d6) fire damage.", "attack_bonus": null, "damage": "3d6"}]',
+        '[{"name": "Bite", "description": "Melee Weapon Attack: +11 to hit, reach 10 ft., one target. Hit: 40 (6d10 + 7) piercing damage plus 10 (3d6) fire damage. If the target is a creature, it is grappled (escape DC 17). Until this grapple ends, the target is restrained, and the remorhaz can''t bite another target.", "attack_bonus": 11, "damage": "6d10+7"}, {"name": "Swallow", "description": "The remorhaz makes one bite attack against a Medium or smaller creature it is grappling. If the attack hits, that creature takes the bite''s damage and is swallowed, and the grapple ends. While swallowed, the creature is blinded and restrained, it has total cover against attacks and other effects outside the remorhaz, and it takes 21 (6d6) acid damage at the start of each of the remorhaz''s turns. If the remorhaz takes 30 damage or more on a single turn from a creature inside it, the remorhaz must succeed on a DC 15 Constitution saving throw at the end of that turn or regurgitate all swallowed creatures, which fall prone in a space within 10 feet of the remorhaz. If the remorhaz dies, a swallowed creature is no longer restrained by it and can escape from the corpse using 15 feet of movement, exiting prone.", "attack_bonus": null, "damage": "6d6"}]',
+        '[]', 0, '[]'),
+    ('roc', 'Roc', 'Gargantuan', 'Monstrosity', '', 'unaligned',
+        15, 'natural armor', 248, '16d20+80', '{"walk": 20, "fly": 120, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        28, 10, 20, 3, 10, 9, '[{"name": "Dex", "bonus": 4}, {"name": "Con", "bonus": 9}, {"name": "Wis", "bonus": 4}, {"name": "Cha", "bonus": 3}]', '[{"name": "Perception", "bonus": 4}]',
+        '', '', '', '',
+        'passive Perception 14', '', 11, '["Coastal", "Mountain"]',
+        '[{"name": "Keen Sight", "description": "The roc has advantage on Wisdom (Perception) checks that rely on sight.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The roc makes two attacks: one with its beak and one with its talons.", "attack_bonus": null, "damage": null}, {"name": "Beak", "description": "Melee Weapon Attack: +13 to hit, reach 10 ft., one target. Hit: 27 (4d8 + 9) piercing damage.", "attack_bonus": 13, "damage": "4d8+9"}, {"name": "Talons", "description": "Melee Weapon Attack: +13 to hit, reach 5 ft., one target. Hit: 23 (4d6 + 9) slashing damage. The target is grappled (escape DC 19). Until this grapple ends, the target is restrained, and the roc can''t use its talons on another target.", "attack_bonus": 13, "damage": "4d6+9"}]',
+        '[]', 0, '[]'),
+    ('archmage', 'Archmage', 'Medium', 'Humanoid', 'any race', 'any alignment',
+        12, '15 with mage armor', 99, '18d8+18', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        10, 14, 12, 20, 15, 16, '[{"name": "Int", "bonus": 9}, {"name": "Wis", "bonus": 6}]', '[{"name": "Arcana", "bonus": 13}, {"name": "History", "bonus": 13}]',
+        '', 'damage from spells; nonmagical bludgeoning, piercing, and slashing (from stoneskin)', '', '',
+        'passive Perception 12', 'any six languages', 12, '["Urban"]',
+        '[{"name": "Magic Resistance", "description": "The archmage has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}, {"name": "Spellcasting", "description": "The archmage is an 18th-level spellcaster. Its spellcasting ability is Intelligence (spell save DC 17, +9 to hit with spell attacks). The archmage can cast disguise self and invisibility at will and has the following wizard spells prepared. Cantrips (at will): fire bolt, light, mage hand, prestidigitation, shocking grasp. 1st level (4 slots): detect magic, identify, mage armor*, magic missile. 2nd level (3 slots): detect thoughts, mirror image, misty step. 3rd level (3 slots): counterspell, fly, lightning bolt. 4th level (3 slots): banishment, fire shield, stoneskin*. 5th level (3 slots): cone of cold, scrying, wall of force. 6th level (1 slot): globe of invulnerability. 7th level (1 slot): teleport. 8th level (1 slot): mind blank*. 9th level (1 slot): time stop. *The archmage casts these spells on itself before combat.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Dagger", "description": "Melee or Ranged Weapon Attack: +6 to hit, reach 5 ft. or range 20/60 ft., one target. Hit: 4 (1d4 + 2) piercing damage.", "attack_bonus": 6, "damage": "1d4+2"}]',
+        '[]', 0, '[]'),
+    ('erinyes', 'Erinyes', 'Medium', 'Fiend', 'devil', 'lawful evil',
+        18, 'plate', 153, '18d8+72', '{"walk": 30, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 16, 18, 14, 14, 18, '[{"name": "Dex", "bonus": 7}, {"name": "Con", "bonus": 8}, {"name": "Wis", "bonus": 6}, {"name": "Cha", "bonus": 8}]', '[]',
+        '', 'cold; bludgeoning, piercing, and slashing from nonmagical attacks that aren''t silvered', 'fire, poison', 'poisoned',
+        'truesight 120 ft., passive Perception 12', 'Infernal, telepathy 120 ft.', 12, '[]',
+        '[{"name": "Hellish Weapons", "description": "The erinyes''s weapon attacks are magical and deal an extra 13 (3d8) poison damage on a hit (included in the attacks).", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The erinyes has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The erinyes makes three attacks.", "attack_bonus": null, "damage": null}, {"name": "Longsword", "description": "Melee Weapon Attack: +8 to hit, reach 5 ft., one target. Hit: 8 (1d8 + 4) slashing damage, or 9 (1d10 + 4) slashing damage if used with two hands to make a melee attack plus 13 (3d8) poison damage.", "attack_bonus": 8, "damage": "1d8+4"}, {"name": "Longbow", "description": "Ranged Weapon Attack: +7 to hit, range 150/600 ft., one target. Hit: 7 (1d8 + 3) piercing damage plus 13 (3d8) poison damage. The target must succeed on a DC 14 Constitution saving throw or be poisoned. The poison lasts until it is removed by the lesser restoration spell or similar magic.", "attack_bonus": 7, "damage": "1d8+3"}]',
+        '[{"name": "Parry", "description": "The erinyes adds 4 to its AC against one melee attack that would hit it. To do so, the erinyes must see the attacker and be wielding a melee weapon.", "attack_bonus": null, "damage": null}]', 0, '[]'),
+    ('adult-brass-dragon', 'Adult Brass Dragon', 'Huge', 'Dragon', '', 'chaotic good',
+        18, 'natural armor', 172, '15d12+75', '{"walk": 40, "fly": 80, "swim": 0, "climb": 0, "burrow": 30, "hover": false}',
+        23, 10, 21, 14, 13, 17, '[{"name": "Dex", "bonus": 5}, {"name": "Con", "bonus": 10}, {"name": "Wis", "bonus": 6}, {"name": "Cha", "bonus": 8}]', '[{"name": "History", "bonus": 7}, {"name": "Perception", "bonus": 11}, {"name": "Persuasion", "bonus": 8}, {"name": "Stealth", "bonus": 5}]',
+        '', '', 'fire', '',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 21', 'Common, Draconic', 13, '["Desert"]',
+        '[{"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +11 to hit, reach 10 ft., one target. Hit: 17 (2d10 + 6) piercing damage.", "attack_bonus": 11, "damage": "2d10+6"}, {"name": "Claw", "description": "Melee Weapon Attack: +11 to hit, reach 5 ft., one target. Hit: 13 (2d6 + 6) slashing damage.", "attack_bonus": 11, "damage": "2d6+6"}, {"name": "Tail", "description": "Melee Weapon Attack: +11 to hit, reach 15 ft., one target. Hit: 15 (2d8 + 6) bludgeoning damage.", "attack_bonus": 11, "damage": "2d8+6"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice that is within 120 feet of the dragon and aware of it must succeed on a DC 16 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the dragon''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Fire Breath. The dragon exhales fire in a 60-foot line that is 5 feet wide. Each creature in that line must make a DC 18 Dexterity saving throw, taking 45 (13d6) fire damage on a failed save, or half as much damage on a successful one. Sleep Breath. The dragon exhales sleep gas in a 60-foot cone. Each creature in that area must succeed on a DC 18 Constitution saving throw or fall unconscious for 10 minutes. This effect ends for a creature if the creature takes damage or someone uses an action to wake it.", "attack_bonus": null, "damage": "13d6"}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "The dragon beats its wings. Each creature within 10 feet of the dragon must succeed on a DC 19 Dexterity saving throw or take 13 (2d6 + 6) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+6"}]'),
+    ('adult-white-dragon', 'Adult White Dragon', 'Huge', 'Dragon', '', 'chaotic evil',
+        18, 'natural armor', 200, '16d12+96', '{"walk": 40, "fly": 80, "swim": 40, "climb": 0, "burrow": 30, "hover": false}',
+        22, 10, 22, 8, 12, 12, '[{"name": "Dex", "bonus": 5}, {"name": "Con", "bonus": 11}, {"name": "Wis", "bonus": 6}, {"name": "Cha", "bonus": 6}]', '[{"name": "Perception", "bonus": 11}, {"name": "Stealth", "bonus": 5}]',
+        '', '', 'cold', '',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 21', 'Common, Draconic', 13, '["Arctic"]',
+        '[{"name": "Ice Walk", "description": "The dragon can move across and climb icy surfaces without needing to make an ability check. Additionally, difficult terrain composed of ice or snow doesn''t cost it extra movement.", "attack_bonus": null, "damage": null}, {"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +11 to hit, reach 10 ft., one target. Hit: 17 (2d10 + 6) piercing damage plus 4 (1d8) cold damage.", "attack_bonus": 11, "damage": "2d10+6"}, {"name": "Claw", "description": "Melee Weapon Attack: +11 to hit, reach 5 ft., one target. Hit: 13 (2d6 + 6) slashing damage.", "attack_bonus": 11, "damage": "2d6+6"}, {"name": "Tail", "description": "Melee Weapon Attack: +11 to hit, reach 15 ft., one target. Hit: 15 (2d8 + 6) bludgeoning damage.", "attack_bonus": 11, "damage": "2d8+6"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice that is within 120 feet of the dragon and aware of it must succeed on a DC 14 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the dragon''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Cold Breath (Recharge 5-6)", "description": "The dragon exhales an icy blast in a 60-foot cone. Each creature in that area must make a DC 19 Constitution saving throw, taking 54 (12d8) cold damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "12d8"}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "The dragon beats its wings. Each creature within 10 feet of the dragon must succeed on a DC 19 Dexterity saving throw or take 13 (2d6 + 6) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+6"}]'),
+    ('nalfeshnee', 'Nalfeshnee', 'Large', 'Fiend', 'demon', 'chaotic evil',
+        18, 'natural armor', 184, '16d10+96', '{"walk": 20, "fly": 30, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        21, 10, 22, 19, 12, 15, '[{"name": "Con", "bonus": 11}, {"name": "Int", "bonus": 9}, {"name": "Wis", "bonus": 6}, {"name": "Cha", "bonus": 7}]', '[]',
+        '', 'cold, fire, lightning; bludgeoning, piercing, and slashing from nonmagical attacks', 'poison', 'poisoned',
+        'truesight 120 ft., passive Perception 11', 'Abyssal, telepathy 120 ft.', 13, '[]',
+        '[{"name": "Magic Resistance", "description": "The nalfeshnee has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The nalfeshnee uses Horror Nimbus if it can. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +10 to hit, reach 5 ft., one target. Hit: 32 (5d10 + 5) piercing damage.", "attack_bonus": 10, "damage": "5d10+5"}, {"name": "Claw", "description": "Melee Weapon Attack: +10 to hit, reach 10 ft., one target. Hit: 15 (3d6 + 5) slashing damage.", "attack_bonus": 10, "damage": "3d6+5"}, {"name": "Horror Nimbus (Recharge 5-6)", "description": "The nalfeshnee magically emits scintillating, multicolored light. Each creature within 15 feet of the nalfeshnee that can see the light must succeed on a DC 15 Wisdom saving throw or be frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the nalfeshnee''s Horror Nimbus for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Teleport", "description": "The nalfeshnee magically teleports, along with any equipment it is wearing or carrying, up to 120 feet to an unoccupied space it can see.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('rakshasa', 'Rakshasa', 'Medium', 'Fiend', '', 'lawful evil',
+        16, 'natural armor', 110, '13d8+52', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        14, 17, 18, 13, 16, 20, '[]', '[{"name": "Deception", "bonus": 10}, {"name": "Insight", "bonus": 8}]',
+        'piercing from magic weapons wielded by good creatures', '', 'bludgeoning, piercing, and slashing from nonmagical attacks', '',
+        'darkvision 60 ft., passive Perception 13', 'Common, Infernal', 13, '["Urban"]',
+        '[{"name": "Limited Magic Immunity", "description": "The rakshasa can''t be affected or detected by spells of 6th level or lower unless it wishes to be. It has advantage on saving throws against all other spells and magical effects.", "attack_bonus": null, "damage": null}, {"name": "Innate Spellcasting", "description": "The rakshasa''s innate spellcasting ability is Charisma (spell save DC 18, +10 to hit with spell attacks). The rakshasa can innately cast the following spells, requiring no material components. At will: detect thoughts, disguise self, mage hand, minor illusion. 3/day each: charm person, detect magic, invisibility, major image, suggestion. 1/day each: dominate person, fly, plane shift, true seeing.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The rakshasa makes two claw attacks.", "attack_bonus": null, "damage": null}, {"name": "Claw", "description": "Melee Weapon Attack: +7 to hit, reach 5 ft., one target. Hit: 9 (2d6 + 2) slashing damage. If the target is a creature, it is cursed. The magical curse takes effect whenever the target takes a short or long rest, filling the target''s thoughts with horrible images and dreams. The cursed target gains no benefit from finishing a short or long rest. The curse lasts until it is lifted by a remove curse spell or similar magic.", "attack_bonus": 7, "damage": "2d6+2"}]',
+        '[]', 0, '[]'),
+    ('storm-giant', 'Storm Giant', 'Huge', 'Giant', '', 'chaotic good',
+        16, 'scale mail', 230, '20d12+100', '{"walk": 50, "fly": 0, "swim": 50, "climb": 0, "burrow": 0, "hover": false}',
+        29, 14, 20, 16, 18, 18, '[{"name": "Str", "bonus": 14}, {"name": "Con", "bonus": 10}, {"name": "Wis", "bonus": 9}, {"name": "Cha", "bonus": 9}]', '[{"name": "Arcana", "bonus": 8}, {"name": "Athletics", "bonus": 14}, {"name": "History", "bonus": 8}, {"name": "Perception", "bonus": 9}]',
+        '', 'cold', 'lightning, thunder', '',
+        'passive Perception 19', 'Common, Giant', 13, '["Coastal", "Mountain", "Underwater"]',
+        '[{"name": "Amphibious", "description": "The giant can breathe air and water.", "attack_bonus": null, "damage": null}, {"name": "Innate Spellcasting", "description": "The giant''s innate spellcasting ability is Charisma (spell save DC 17). It can innately cast the following spells, requiring no material components. At will: detect magic, feather fall, levitate, light. 3/day each: control weather, water breathing.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The giant makes two greatsword attacks.", "attack_bonus": null, "damage": null}, {"name": "Greatsword", "description": "Melee Weapon Attack: +14 to hit, reach 10 ft., one target. Hit: 30 (6d6 + 9) slashing damage.", "attack_bonus": 14, "damage": "6d6+9"}, {"name": "Rock", "description": "Ranged Weapon Attack: +14 to hit, range 60/240 ft., one target. Hit: 35 (4d12 + 9) bludgeoning damage.", "attack_bonus": 14, "damage": "4d12+9"}, {"name": "Lightning Strike (Recharge 5-6)", "description": "The giant hurls a magical lightning bolt at a point it can see within 500 feet of it. Each creature within 10 feet of that point must make a DC 17 Dexterity saving throw, taking 54 (12d8) lightning damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "12d8"}]',
+        '[]', 0, '[]'),
+    ('adult-black-dragon', 'Adult Black Dragon', 'Huge', 'Dragon', '', 'chaotic evil',
+        19, 'natural armor', 195, '17d12+85', '{"walk": 40, "fly": 80, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        23, 14, 21, 14, 13, 17, '[{"name": "Dex", "bonus": 7}, {"name": "Con", "bonus": 10}, {"name": "Wis", "bonus": 6}, {"name": "Cha", "bonus": 8}]', '[{"name": "Perception", "bonus": 11}, {"name": "Stealth", "bonus": 7}]',
+        '', '', 'acid', '',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 21', 'Common, Draconic', 14, '["Swamp"]',
+        '[{"name": "Amphibious", "description": "The dragon can breathe air and water.", "attack_bonus": null, "damage": null}, {"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +11 to hit, reach 10 ft., one target. Hit: 17 (2d10 + 6) piercing damage plus 4 (1d8) acid damage.", "attack_bonus": 11, "damage": "2d10+6"}, {"name": "Claw", "description": "Melee Weapon Attack: +11 to hit, reach 5 ft., one target. Hit: 13 (2d6 + 6) slashing damage.", "attack_bonus": 11, "damage": "2d6+6"}, {"name": "Tail", "description": "Melee Weapon Attack: +11 to hit, reach 15 ft., one target. Hit: 15 (2d8 + 6) bludgeoning damage.", "attack_bonus": 11, "damage": "2d8+6"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice that is within 120 feet of the dragon and aware of it must succeed on a DC 16 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the dragon''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Acid Breath (Recharge 5-6)", "description": "The dragon exhales acid in a 60-foot line that is 5 feet wide. Each creature in that line must make a DC 18 Dexterity saving throw, taking 54 (12d8) acid damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "12d8"}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "The dragon beats its wings. Each creature within 10 feet of the dragon must succeed on a DC 19 Dexterity saving throw or take 13 (2d6 + 6) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+6"}]'),
+    ('adult-copper-dragon', 'Adult Copper Dragon', 'Huge', 'Dragon', '', 'chaotic good',
+        18, 'natural armor', 184, '16d12+80', '{"walk": 40, "fly": 80, "swim": 0, "climb": 40, "burrow": 0, "hover": false}',
+        23, 12, 21, 18, 15, 17, '[{"name": "Dex", "bonus": 6}, {"name": "Con", "bonus": 10}, {"name": "Wis", "bonus": 7}, {"name": "Cha", "bonus": 8}]', '[{"name": "Deception", "bonus": 8}, {"name": "Perception", "bonus": 12}, {"name": "Stealth", "bonus": 6}]',
+        '', '', 'acid', '',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 22', 'Common, Draconic', 14, '["Hill", "Mountain"]',
+        '[{"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +11 to hit, reach 10 ft., one target. Hit: 17 (2d10 + 6) piercing damage.", "attack_bonus": 11, "damage": "2d10+6"}, {"name": "Claw", "description": "Melee Weapon Attack: +11 to hit, reach 5 ft., one target. Hit: 13 (2d6 + 6) slashing damage.", "attack_bonus": 11, "damage": "2d6+6"}, {"name": "Tail", "description": "Melee Weapon Attack: +11 to hit, reach 15 ft., one target. Hit: 15 (2d8 + 6) bludgeoning damage.", "attack_bonus": 11, "damage": "2d8+6"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice that is within 120 feet of the dragon and aware of it must succeed on a DC 16 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the dragon''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Acid Breath. The dragon exhales acid in a 60-foot line that is 5 feet wide. Each creature in that line must make a DC 18 Dexterity saving throw, taking 54 (12d8) acid damage on a failed save, or half as much damage on a successful one. Slowing Breath. The dragon exhales gas in a 60-foot cone. Each creature in that area must succeed on a DC 18 Constitution saving throw. On a failed save, the creature can''t use reactions, its speed is halved, and it can''t make more than one attack on its turn. In addition, the creature can use either an action or a bonus action on its turn, but not both. These effects last for 1 minute. The creature can repeat the saving throw at the end of each of its turns, ending the effect on itself with a successful save.", "attack_bonus": null, "damage": "12d8"}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "The dragon beats its wings. Each creature within 10 feet of the dragon must succeed on a DC 19 Dexterity saving throw or take 13 (2d6 + 6) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+6"}]'),
+    ('ice-devil', 'Ice Devil', 'Large', 'Fiend', 'devil', 'lawful evil',
+        18, 'natural armor', 180, '19d10+76', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        21, 14, 18, 18, 15, 18, '[{"name": "Dex", "bonus": 7}, {"name": "Con", "bonus": 9}, {"name": "Wis", "bonus": 7}, {"name": "Cha", "bonus": 9}]', '[]',
+        '', 'bludgeoning, piercing, and slashing from nonmagical attacks that aren''t silvered', 'cold, fire, poison', 'poisoned',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 12', 'Infernal, telepathy 120 ft.', 14, '[]',
+        '[{"name": "Devil''s Sight", "description": "Magical darkness doesn''t impede the devil''s darkvision.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The devil has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The devil makes three attacks: one with its bite, one with its claws, and one with its tail.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +10 to hit, reach 5 ft., one target. Hit: 12 (2d6 + 5) piercing damage plus 10 (3d6) cold damage.", "attack_bonus": 10, "damage": "2d6+5"}, {"name": "Claws", "description": "Melee Weapon Attack: +10 to hit, reach 5 ft., one target. Hit: 10 (2d4 + 5) slashing damage plus 10 (3d6) cold damage.", "attack_bonus": 10, "damage": "2d4+5"}, {"name": "Tail", "description": "Melee Weapon Attack: +10 to hit, reach 10 ft., one target. Hit: 12 (2d6 + 5) bludgeoning damage plus 10 (3d6) cold damage.", "attack_bonus": 10, "damage": "2d6+5"}, {"name": "Wall of Ice (Recharge 6)", "description": "The devil magically forms an opaque wall of ice on a solid surface it can see within 60 feet of it. The wall is 1 foot thick and up to 30 feet long and 10 feet high, or it''s a hemispherical dome up to 20 feet in diameter. When the wall appears, each creature in its space is pushed out of it by the shortest route. The creature chooses which side of the wall to end up on, unless the creature is incapacitated. The creature then makes a DC 17 Dexterity saving throw, taking 35 (10d6) cold damage on a failed save, or half as much damage on a successful one. The wall lasts for 1 minute or until the devil is incapacitated or dies. The wall can be damaged and breached; each 10-foot section has AC 5, 30 hit points, vulnerability to fire damage, and immunity to acid, cold, necrotic, poison, and psychic damage. If a section is destroyed, it leaves behind a sheet of frigid air in the space the wall occupied. Whenever a creature finishes moving through the frigid air on a turn, willingly or otherwise, the creature must make a DC 17 Constitution saving throw, taking 17 (5d6) cold damage on a failed save, or half as much damage on a successful one. The frigid air dissipates when the rest of the wall vanishes.", "attack_bonus": null, "damage": "10d6"}]',
+        '[]', 0, '[]'),
+    ('adult-bronze-dragon', 'Adult Bronze Dragon', 'Huge', 'Dragon', '', 'lawful good',
+        19, 'natural armor', 212, '17d12+102', '{"walk": 40, "fly": 80, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        25, 10, 23, 16, 15, 19, '[{"name": "Dex", "bonus": 5}, {"name": "Con", "bonus": 11}, {"name": "Wis", "bonus": 7}, {"name": "Cha", "bonus": 9}]', '[{"name": "Insight", "bonus": 7}, {"name": "Perception", "bonus": 12}, {"name": "Stealth", "bonus": 5}]',
+        '', '', 'lightning', '',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 22', 'Common, Draconic', 15, '["Coastal", "Underwater"]',
+        '[{"name": "Amphibious", "description": "The dragon can breathe air and water.", "attack_bonus": null, "damage": null}, {"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +12 to hit, reach 10 ft., one target. Hit: 18 (2d10 + 7) piercing damage.", "attack_bonus": 12, "damage": "2d10+7"}, {"name": "Claw", "description": "Melee Weapon Attack: +12 to hit, reach 5 ft., one target. Hit: 14 (2d6 + 7) slashing damage.", "attack_bonus": 12, "damage": "2d6+7"}, {"name": "Tail", "description": "Melee Weapon Attack: +12 to hit, reach 15 ft., one target. Hit: 16 (2d8 + 7) bludgeoning damage.", "attack_bonus": 12, "damage": "2d8+7"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice that is within 120 feet of the dragon and aware of it must succeed on a DC 17 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the dragon''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Lightning Breath. The dragon exhales lightning in a 90-foot line that is 5 feet wide. Each creature in that line must make a DC 19 Dexterity saving throw, taking 66 (12d10) lightning damage on a failed save, or half as much damage on a successful one. Repulsion Breath. The dragon exhales repulsion energy in a 30-foot cone. Each creature in that area must succeed on a DC 19 Strength saving throw. On a failed save, the creature is pushed 60 feet away from the dragon.", "attack_bonus": null, "damage": "12d10"}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "The dragon beats its wings. Each creature within 10 feet of the dragon must succeed on a DC 20 Dexterity saving throw or take 14 (2d6 + 7) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+7"}]'),
+    ('adult-green-dragon', 'Adult Green Dragon', 'Huge', 'Dragon', '', 'lawful evil',
+        19, 'natural armor', 207, '18d12+90', '{"walk": 40, "fly": 80, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        23, 12, 21, 18, 15, 17, '[{"name": "Dex", "bonus": 6}, {"name": "Con", "bonus": 10}, {"name": "Wis", "bonus": 7}, {"name": "Cha", "bonus": 8}]', '[{"name": "Deception", "bonus": 8}, {"name": "Insight", "bonus": 7}, {"name": "Perception", "bonus": 12}, {"name": "Persuasion", "bonus": 8}, {"name": "Stealth", "bonus": 6}]',
+        '', '', 'poison', 'poisoned',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 22', 'Common, Draconic', 15, '["Forest"]',
+        '[{"name": "Amphibious", "description": "The dragon can breathe air and water.", "attack_bonus": null, "damage": null}, {"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +11 to hit, reach 10 ft., one target. Hit: 17 (2d10 + 6) piercing damage plus 7 (2d6) poison damage.", "attack_bonus": 11, "damage": "2d10+6"}, {"name": "Claw", "description": "Melee Weapon Attack: +11 to hit, reach 5 ft., one target. Hit: 13 (2d6 + 6) slashing damage.", "attack_bonus": 11, "damage": "2d6+6"}, {"name": "Tail", "description": "Melee Weapon Attack: +11 to hit, reach 15 ft., one target. Hit: 15 (2d8 + 6) bludgeoning damage.", "attack_bonus": 11, "damage": "2d8+6"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice that is within 120 feet of the dragon and aware of it must succeed on a DC 16 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the dragon''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Poison Breath (Recharge 5-6)", "description": "The dragon exhales poisonous gas in a 60-foot cone. Each creature in that area must make a DC 18 Constitution saving throw, taking 56 (16d6) poison damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "16d6"}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "The dragon beats its wings. Each creature within 10 feet of the dragon must succeed on a DC 19 Dexterity saving throw or take 13 (2d6 + 6) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+6"}]'),
+    ('mummy-lord', 'Mummy Lord', 'Medium', 'Undead', '', 'lawful evil',
+        17, 'natural armor', 97, '13d8+39', '{"walk": 20, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 10, 17, 11, 18, 16, '[{"name": "Con", "bonus": 8}, {"name": "Int", "bonus": 5}, {"name": "Wis", "bonus": 9}, {"name": "Cha", "bonus": 8}]', '[{"name": "History", "bonus": 5}, {"name": "Religion", "bonus": 5}]',
+        'fire', '', 'necrotic, poison; bludgeoning, piercing, and slashing from nonmagical attacks', 'charmed, exhaustion, frightened, paralyzed, poisoned',
+        'darkvision 60 ft., passive Perception 14', 'the languages it knew in life', 15, '["Desert"]',
+        '[{"name": "Magic Resistance", "description": "The mummy lord has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}, {"name": "Rejuvenation", "description": "A destroyed mummy lord gains a new body in 24 hours if its heart is intact, regaining all its hit points and becoming active again. The new body appears within 5 feet of the mummy lord''s heart.", "attack_bonus": null, "damage": null}, {"name": "Spellcasting", "description": "The mummy lord is a 10th-level spellcaster. Its spellcasting ability is Wisdom (spell save DC 17, +9 to hit with spell attacks). The mummy lord has the following cleric spells prepared. Cantrips: sacred flame, thaumaturgy. 1st level (4 slots): command, guiding bolt, shield of faith. 2nd level (3 slots): hold person, silence, spiritual weapon. 3rd level (3 slots): animate dead, dispel magic. 4th level (3 slots): divination, guardian of faith. 5th level (2 slots): contagion, insect plague. 6th level (1 slot): harm.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The mummy can use its Dreadful Glare and makes one attack with its rotting fist.", "attack_bonus": null, "damage": null}, {"name": "Rotting Fist", "description": "Melee Weapon Attack: +9 to hit, reach 5 ft., one target. Hit: 14 (3d6 + 4) bludgeoning damage plus 21 (6d6) necrotic damage. If the target is a creature, it must succeed on a DC 16 Constitution saving throw or be cursed with mummy rot. The cursed target can''t regain hit points, and its hit point maximum decreases by 10 (3d6) for every 24 hours that elapse. If the curse reduces the target''s hit point maximum to 0, the target dies, and its body turns to dust. The curse lasts until removed by the remove curse spell or other magic.", "attack_bonus": 9, "damage": "3d6+4"}, {"name": "Dreadful Glare", "description": "The mummy lord targets one creature it can see within 60 feet of it. If the target can see the mummy lord, it must succeed on a DC 16 Wisdom saving throw against this magic or become frightened until the end of the mummy''s next turn. If the target fails the saving throw by 5 or more, it is also paralyzed for the same duration. A target that succeeds on the saving throw is immune to the Dreadful Glare of all mummies and mummy lords for the next 24 hours.", "attack_bonus": null, "damage": null}]',
+        '[]', 3, '[{"name": "Attack", "description": "The mummy lord makes one attack with its rotting fist or uses its Dreadful Glare.", "attack_bonus": null, "damage": null}, {"name": "Blinding Dust", "description": "Blinding dust and sand swirls magically around the mummy lord. Each creature within 5 feet of the mummy lord must succeed on a DC 16 Constitution saving throw or be blinded until the end of the creature''s next turn.", "attack_bonus": null, "damage": null}, {"name": "Blasphemous Word (Costs 2 Actions)", "description": "The mummy lord utters a blasphemous word. Each non-undead creature within 10 feet of the mummy lord that can hear the magical utterance must succeed on a DC 16 Constitution saving throw or be stunned until the end of the mummy lord''s next turn.", "attack_bonus": null, "damage": null}, {"name": "Channel Negative Energy (Costs 2 Actions)", "description": "The mummy lord magically unleashes negative energy. Creatures within 60 feet of the mummy lord, including ones behind barriers and around corners, can''t regain hit points until the end of the mummy lord''s next turn.", "attack_bonus": null, "damage": null}, {"name": "Whirlwind of Sand (Costs 2 Actions)", "description": "The mummy lord magically transforms into a whirlwind of sand, moves up to 60 feet, and reverts to its normal form. While in whirlwind form, the mummy lord is immune to all damage, and it can''t be grappled, petrified, knocked prone, restrained, or stunned. Equipment worn or carried by the mummy lord remain in its possession.", "attack_bonus": null, "damage": null}]'),
+    ('purple-worm', 'Purple Worm', 'Gargantuan', 'Monstrosity', '', 'unaligned',
+        18, 'natural armor', 247, '15d20+90', '{"walk": 50, "fly": 0, "swim": 0, "climb": 0, "burrow": 30, "hover": false}',
+        28, 7, 22, 1, 8, 4, '[{"name": "Con", "bonus": 11}, {"name": "Wis", "bonus": 4}]', '[]',
+        '', '', '', '',
+        'blindsight 30 ft., tremorsense 60 ft., passive Perception 9', '', 15, '["Desert", "Underdark"]',
+        '[{"name": "Tunneler", "description": "The worm can burrow through solid rock at half its burrow speed and leaves a 10-foot-diameter tunnel in its wake.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The worm makes two attacks: one with its bite and one with its stinger.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +14 to hit, reach 10 ft., one target. Hit: 22 (3d8 + 9) piercing damage. If the target is a Large or smaller creature, it must succeed on a DC 19 Dexterity saving throw or be swallowed by the worm. A swallowed creature is blinded and restrained, it has total cover against attacks and other effects outside the worm, and it takes 21 (6d6) acid damage at the start of each of the worm''s turns. If the worm takes 30 damage or more on a single turn from a creature inside it, the worm must succeed on a DC 21 Constitution saving throw at the end of that turn or regurgitate all swallowed creatures, which fall prone in a space within 10 feet of the worm. If the worm dies, a swallowed creature is no longer restrained by it and can escape from the corpse by using 20 feet of movement, exiting prone.", "attack_bonus": 14, "damage": "3d8+9"}, {"name": "Tail Stinger", "description": "Melee Weapon Attack: +14 to hit, reach 10 ft., one creature. Hit: 19 (3d6 + 9) piercing damage. The target must make a DC 19 Constitution saving throw, taking 42 (12d6) poison damage on a failed save, or half as much damage on a successful one.", "attack_bonus": 14, "damage": "3d6+9"}]',
+        '[]', 0, '[]'),
+    ('adult-blue-dragon', 'Adult Blue Dragon', 'Huge', 'Dragon', '', 'lawful evil',
+        19, 'natural armor', 225, '18d12+108', '{"walk": 40, "fly": 80, "swim": 0, "climb": 0, "burrow": 30, "hover": false}',
+        25, 10, 23, 16, 15, 19, '[{"name": "Dex", "bonus": 5}, {"name": "Con", "bonus": 11}, {"name": "Wis", "bonus": 7}, {"name": "Cha", "bonus": 9}]', '[{"name": "Perception", "bonus": 12}, {"name": "Stealth", "bonus": 5}]',
+        '', '', 'lightning', '',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 22', 'Common, Draconic', 16, '["Desert", "Coastal"]',
+        '[{"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +12 to hit, reach 10 ft., one target. Hit: 18 (2d10 + 7) piercing damage plus 5 (1d10) lightning damage.", "attack_bonus": 12, "damage": "2d10+7"}, {"name": "Claw", "description": "Melee Weapon Attack: +12 to hit, reach 5 ft., one target. Hit: 14 (2d6 + 7) slashing damage.", "attack_bonus": 12, "damage": "2d6+7"}, {"name": "Tail", "description": "Melee Weapon Attack: +12 to hit, reach 15 ft., one target. Hit: 16 (2d8 + 7) bludgeoning damage.", "attack_bonus": 12, "damage": "2d8+7"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice that is within 120 feet of the dragon and aware of it must succeed on a DC 17 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the dragon''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Lightning Breath (Recharge 5-6)", "description": "The dragon exhales lightning in a 90-foot line that is 5 feet wide. Each creature in that line must make a DC 19 Dexterity saving throw, taking 66 (12d10) lightning damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "12d10"}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "The dragon beats its wings. Each creature within 10 feet of the dragon must succeed on a DC 20 Dexterity saving throw or take 14 (2d6 + 7) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+7"}]'),
+    ('adult-silver-dragon', 'Adult Silver Dragon', 'Huge', 'Dragon', '', 'lawful good',
+        19, 'natural armor', 243, '18d12+126', '{"walk": 40, "fly": 80, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        27, 10, 25, 16, 13, 21, '[{"name": "Dex", "bonus": 5}, {"name": "Con", "bonus": 12}, {"name": "Wis", "bonus": 6}, {"name": "Cha", "bonus": 10}]', '[{"name": "Arcana", "bonus": 8}, {"name": "History", "bonus": 8}, {"name": "Perception", "bonus": 11}, {"name": "Stealth", "bonus": 5}]',
+        '', '', 'cold', '',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 21', 'Common, Draconic', 16, '["Mountain", "Urban"]',
+        '[{"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +13 to hit, reach 10 ft., one target. Hit: 19 (2d10 + 8) piercing damage.", "attack_bonus": 13, "damage": "2d10+8"}, {"name": "Claw", "description": "Melee Weapon Attack: +13 to hit, reach 5 ft., one target. Hit: 15 (2d6 + 8) slashing damage.", "attack_bonus": 13, "damage": "2d6+8"}, {"name": "Tail", "description": "Melee Weapon Attack: +13 to hit, reach 15 ft., one target. Hit: 17 (2d8 + 8) bludgeoning damage.", "attack_bonus": 13, "damage": "2d8+8"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice that is within 120 feet of the dragon and aware of it must succeed on a DC 18 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the dragon''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Cold Breath. The dragon exhales an icy blast in a 60-foot cone. Each creature in that area must make a DC 20 Constitution saving throw, taking 58 (13d8) cold damage on a failed save, or half as much damage on a successful one. Paralyzing Breath. The dragon exhales paralyzing gas in a 60-foot cone. Each creature in that area must succeed on a DC 20 Constitution saving throw or be paralyzed for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success.", "attack_bonus": null, "damage": "13d8"}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "The dragon beats its wings. Each creature within 10 feet of the dragon must succeed on a DC 21 Dexterity saving throw or take 15 (2d6 + 8) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+8"}]'),
+    ('iron-golem', 'Iron Golem', 'Large', 'Construct', '', 'unaligned',
+        20, 'natural armor', 210, '20d10+100', '{"walk": 30, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        24, 9, 20, 3, 11, 1, '[]', '[]',
+        '', '', 'fire, poison, psychic; bludgeoning, piercing, and slashing from nonmagical attacks that aren''t adamantine', 'charmed, exhaustion, frightened, paralyzed, petrified, poisoned',
+        'darkvision 120 ft., passive Perception 10', 'understands the languages of its creator but can''t speak', 16, '[]',
+        '[{"name": "Fire Absorption", "description": "Whenever the golem is subjected to fire damage, it takes no damage and instead regains a number of hit points equal to the fire damage dealt.", "attack_bonus": null, "damage": null}, {"name": "Immutable Form", "description": "The golem is immune to any spell or effect that would alter its form.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The golem has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}, {"name": "Magic Weapons", "description": "The golem''s weapon attacks are magical.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The golem makes two melee attacks.", "attack_bonus": null, "damage": null}, {"name": "Slam", "description": "Melee Weapon Attack: +13 to hit, reach 5 ft., one target. Hit: 20 (3d8 + 7) bludgeoning damage.", "attack_bonus": 13, "damage": "3d8+7"}, {"name": "Sword", "description": "Melee Weapon Attack: +13 to hit, reach 10 ft., one target. Hit: 23 (3d10 + 7) slashing damage.", "attack_bonus": 13, "damage": "3d10+7"}, {"name": "Poison Breath (Recharge 6)", "description": "The golem exhales poisonous gas in a 15-foot cone. Each creature in that area must make a DC 19 Constitution saving throw, taking 45 (10d8) poison damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "10d8"}]',
+        '[]', 0, '[]'),
+    ('marilith', 'Marilith', 'Large', 'Fiend', 'demon', 'chaotic evil',
+        18, 'natural armor', 189, '18d10+90', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        18, 20, 20, 18, 16, 20, '[{"name": "Str", "bonus": 9}, {"name": "Con", "bonus": 10}, {"name": "Wis", "bonus": 8}, {"name": "Cha", "bonus": 10}]', '[]',
+        '', 'cold, fire, lightning; bludgeoning, piercing, and slashing from nonmagical attacks', 'poison', 'poisoned',
+        'truesight 120 ft., passive Perception 13', 'Abyssal, telepathy 120 ft.', 16, '[]',
+        '[{"name": "Magic Resistance", "description": "The marilith has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}, {"name": "Magic Weapons", "description": "The marilith''s weapon attacks are magical.", "attack_bonus": null, "damage": null}, {"name": "Reactive", "description": "The marilith can take one reaction on every turn in a combat.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The marilith makes seven attacks: six with its longswords and one with its tail.", "attack_bonus": null, "damage": null}, {"name": "Longsword", "description": "Melee Weapon Attack: +9 to hit, reach 5 ft., one target. Hit: 13 (2d8 + 4) slashing damage.", "attack_bonus": 9, "damage": "2d8+4"}, {"name": "Tail", "description": "Melee Weapon Attack: +9 to hit, reach 10 ft., one creature. Hit: 15 (2d10 + 4) bludgeoning damage. If the target is Medium or smaller, it is grappled (escape DC 19). Until this grapple ends, the target is restrained, the marilith can automatically hit the target with its tail, and the marilith can''t make tail attacks against other targets.", "attack_bonus": 9, "damage": "2d10+4"}, {"name": "Teleport", "description": "The marilith magically teleports, along with any equipment it is wearing or carrying, up to 120 feet to an unoccupied space it can see.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Parry", "description": "The marilith adds 5 to its AC against one melee attack that would hit it. To do so, the marilith must see the attacker and be wielding a melee weapon.", "attack_bonus": null, "damage": null}]', 0, '[]'),
+    ('planetar', 'Planetar', 'Large', 'Celestial', '', 'lawful good',
+        19, 'natural armor', 200, '16d10+112', '{"walk": 40, "fly": 120, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        24, 20, 24, 19, 22, 25, '[{"name": "Con", "bonus": 12}, {"name": "Wis", "bonus": 11}, {"name": "Cha", "bonus": 12}]', '[{"name": "Perception", "bonus": 11}]',
+        '', 'radiant; bludgeoning, piercing, and slashing from nonmagical attacks', '', 'charmed, exhaustion, frightened',
+        'truesight 120 ft., passive Perception 21', 'all, telepathy 120 ft.', 16, '[]',
+        '[{"name": "Angelic Weapons", "description": "The planetar''s weapon attacks are magical. When the planetar hits with any weapon, the weapon deals an extra 5d8 radiant damage (included in the attack).", "attack_bonus": null, "damage": null}, {"name": "Divine Awareness", "description": "The planetar knows if it hears a lie.", "attack_bonus": null, "damage": null}, {"name": "Innate Spellcasting", "description": "The planetar''s spellcasting ability is Charisma (spell save DC 20). The planetar can innately cast the following spells, requiring no material components. At will: detect evil and good, invisibility (self only). 3/day each: blade barrier, dispel evil and good, flame strike, raise dead. 1/day each: commune, control weather, insect plague.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The planetar has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The planetar makes two melee attacks.", "attack_bonus": null, "damage": null}, {"name": "Greatsword", "description": "Melee Weapon Attack: +12 to hit, reach 5 ft., one target. Hit: 21 (4d6 + 7) slashing damage plus 22 (5d8) radiant damage.", "attack_bonus": 12, "damage": "4d6+7"}, {"name": "Healing Touch (4/Day)", "description": "The planetar touches another creature. The target magically regains 30 (6d8 + 3) hit points and is freed from any curse, disease, poison, blindness, or deafness.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('adult-gold-dragon', 'Adult Gold Dragon', 'Huge', 'Dragon', '', 'lawful good',
+        19, 'natural armor', 256, '19d12+133', '{"walk": 40, "fly": 80, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        27, 14, 25, 16, 15, 24, '[{"name": "Dex", "bonus": 8}, {"name": "Con", "bonus": 13}, {"name": "Wis", "bonus": 8}, {"name": "Cha", "bonus": 13}]', '[{"name": "Insight", "bonus": 8}, {"name": "Perception", "bonus": 14}, {"name": "Persuasion", "bonus": 13}, {"name": "Stealth", "bonus": 8}]',
+        '', '', 'fire', '',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 24', 'Common, Draconic', 17, '["Forest", "Grassland"]',
+        '[{"name": "Amphibious", "description": "The dragon can breathe air and water.", "attack_bonus": null, "damage": null}, {"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +14 to hit, reach 10 ft., one target. Hit: 19 (2d10 + 8) piercing damage.", "attack_bonus": 14, "damage": "2d10+8"}, {"name": "Claw", "description": "Melee Weapon Attack: +14 to hit, reach 5 ft., one target. Hit: 15 (2d6 + 8) slashing damage.", "attack_bonus": 14, "damage": "2d6+8"}, {"name": "Tail", "description": "Melee Weapon Attack: +14 to hit, reach 15 ft., one target. Hit: 17 (2d8 + 8) bludgeoning damage.", "attack_bonus": 14, "damage": "2d8+8"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice that is within 120 feet of the dragon and aware of it must succeed on a DC 21 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the dragon''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Fire Breath. The dragon exhales fire in a 60-foot cone. Each creature in that area must make a DC 21 Dexterity saving throw, taking 66 (12d10) fire damage on a failed save, or half as much damage on a successful one. Weakening Breath. The dragon exhales gas in a 60-foot cone. Each creature in that area must succeed on a DC 21 Strength saving throw or have disadvantage on Strength-based attack rolls, Strength checks, and Strength saving throws for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success.", "attack_bonus": null, "damage": "12d10"}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "The dragon beats its wings. Each creature within 10 feet of the dragon must succeed on a DC 22 Dexterity saving throw or take 15 (2d6 + 8) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+8"}]'),
+    ('androsphinx', 'Androsphinx', 'Large', 'Monstrosity', '', 'lawful neutral',
+        17, 'natural armor', 199, '19d10+95', '{"walk": 40, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        22, 10, 20, 16, 18, 23, '[{"name": "Dex", "bonus": 6}, {"name": "Con", "bonus": 11}, {"name": "Int", "bonus": 9}, {"name": "Wis", "bonus": 10}]', '[{"name": "Arcana", "bonus": 9}, {"name": "Perception", "bonus": 10}, {"name": "Religion", "bonus": 15}]',
+        '', '', 'psychic; bludgeoning, piercing, and slashing from nonmagical attacks', 'charmed, frightened',
+        'truesight 120 ft., passive Perception 20', 'Common, Sphinx', 17, '["Desert"]',
+        '[{"name": "Inscrutable", "description": "The sphinx is immune to any effect that would sense its emotions or read its thoughts, as well as any divination spell that it refuses. Wisdom (Insight) checks made to ascertain the sphinx''s intentions or sincerity have disadvantage.", "attack_bonus": null, "damage": null}, {"name": "Magic Weapons", "description": "The sphinx''s weapon attacks are magical.", "attack_bonus": null, "damage": null}, {"name": "Spellcasting", "description": "The sphinx is a 12th-level spellcaster. Its spellcasting ability is Wisdom (spell save DC 18, +10 to hit with spell attacks). It requires no material components to cast its spells. The sphinx has the following cleric spells prepared. Cantrips: sacred flame, spare the dying, thaumaturgy. 1st level (4 slots): command, detect evil and good, detect magic. 2nd level (3 slots): lesser restoration, zone of truth. 3rd level (3 slots): dispel magic, tongues. 4th level (3 slots): banishment, freedom of movement. 5th level (2 slots): flame strike, greater restoration. 6th level (1 slot): heroes'' feast.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The sphinx makes two claw attacks.", "attack_bonus": null, "damage": null}, {"name": "Claw", "description": "Melee Weapon Attack: +12 to hit, reach 5 ft., one target. Hit: 17 (2d10 + 6) slashing damage.", "attack_bonus": 12, "damage": "2d10+6"}, {"name": "Roar (3/Day)", "description": "The sphinx emits a magical roar. Each time it roars before finishing a long rest, the roar is louder and the effect is different, as detailed below. Each creature within 500 feet of the sphinx and able to hear the roar must make a saving throw. First Roar. Each creature that fails a DC 18 Wisdom saving throw is frightened for 1 minute. A frightened creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. Second Roar. Each creature that fails a DC 18 Wisdom saving throw is deafened and frightened for 1 minute. A frightened creature is paralyzed and can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. Third Roar. Each creature makes a DC 18 Constitution saving throw. On a failed save, a creature takes 44 (8d10) thunder damage and is knocked prone. On a successful save, the creature takes half as much damage and isn''t knocked prone.", "attack_bonus": null, "damage": "8d10"}]',
+        '[]', 3, '[{"name": "Claw Attack", "description": "The sphinx makes one claw attack.", "attack_bonus": null, "damage": null}, {"name": "Teleport (Costs 2 Actions)", "description": "The sphinx magically teleports, along with any equipment it is wearing or carrying, up to 120 feet to an unoccupied space it can see.", "attack_bonus": null, "damage": null}, {"name": "Cast a Spell (Costs 3 Actions)", "description": "The sphinx casts a spell from its list of prepared spells, using a spell slot as normal.", "attack_bonus": null, "damage": null}]'),
+    ('dragon-turtle', 'Dragon Turtle', 'Gargantuan', 'Dragon', '', 'neutral',
+        20, 'natural armor', 341, '22d20+110', '{"walk": 20, "fly": 0, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        25, 10, 20, 10, 12, 12, '[{"name": "Dex", "bonus": 6}, {"name": "Con", "bonus": 11}, {"name": "Wis", "bonus": 7}]', '[]',
+        '', 'fire', '', '',
+        'darkvision 120 ft., passive Perception 11', 'Aquan, Draconic', 17, '["Coastal", "Underwater"]',
+        '[{"name": "Amphibious", "description": "The dragon turtle can breathe air and water.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon turtle makes three attacks: one with its bite and two with its claws. It can make one tail attack in place of its two claw attacks.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +13 to hit, reach 15 ft., one target. Hit: 26 (3d12 + 7) piercing damage.", "attack_bonus": 13, "damage": "3d12+7"}, {"name": "Claw", "description": "Melee Weapon Attack: +13 to hit, reach 10 ft., one target. Hit: 16 (2d8 + 7) slashing damage.", "attack_bonus": 13, "damage": "2d8+7"}, {"name": "Tail", "description": "Melee Weapon Attack: +13 to hit, reach 15 ft., one target. Hit: 26 (3d12 + 7) bludgeoning damage. If the target is a creature, it must succeed on a DC 20 Strength saving throw or be pushed up to 10 feet away from the dragon turtle and knocked prone.", "attack_bonus": 13, "damage": "3d12+7"}, {"name": "Steam Breath (Recharge 5-6)", "description": "The dragon turtle exhales scalding steam in a 60-foot cone. Each creature in that area must make a DC 18 Constitution saving throw, taking 52 (15d6) fire damage on a failed save, or half as much damage on a successful one. Being underwater doesn''t grant resistance against this damage.", "attack_bonus": null, "damage": "15d6"}]',
+        '[]', 0, '[]'),
+    ('balor', 'Balor', 'Huge', 'Fiend', 'demon', 'chaotic evil',
+        19, 'natural armor', 262, '21d12+126', '{"walk": 40, "fly": 80, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        26, 15, 22, 20, 16, 22, '[{"name": "Str", "bonus": 14}, {"name": "Con", "bonus": 12}, {"name": "Wis", "bonus": 9}, {"name": "Cha", "bonus": 12}]', '[]',
+        '', 'cold, lightning; bludgeoning, piercing, and slashing from nonmagical attacks', 'fire, poison', 'poisoned',
+        'truesight 120 ft., passive Perception 13', 'Abyssal, telepathy 120 ft.', 19, '[]',
+        '[{"name": "Death Throes", "description": "When the balor dies, it explodes, and each creature within 30 feet of it must make a DC 20 Dexterity saving throw, taking 70 (20d6) fire damage on a failed save, or half as much damage on a successful one. The explosion ignites flammable objects in that area that aren''t being worn or carried, and it destroys the balor''s weapons.", "attack_bonus": null, "damage": "20d6"}, {"name": "Fire Aura", "description": "At the start of each of the balor''s turns, each creature within 5 feet of it takes 10 (3d6) fire damage, and flammable objects in the aura that aren''t being worn or carried ignite. A creature that touches the balor or hits it with a melee attack while within 5 feet of it takes 10 (3d6) fire damage.", "attack_bonus": null, "damage": "3d6"}, {"name": "Magic Resistance", "description": "The balor has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}, {"name": "Magic Weapons", "description": "The balor''s weapon attacks are magical.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The balor makes two attacks: one with its longsword and one with its whip.", "attack_bonus": null, "damage": null}, {"name": "Longsword", "description": "Melee Weapon Attack: +14 to hit, reach 10 ft., one target. Hit: 21 (3d8 + 8) slashing damage plus 13 (3d8) lightning damage. If the balor scores a critical hit, it rolls damage dice three times, instead of twice.", "attack_bonus": 14, "damage": "3d8+8"}, {"name": "Whip", "description": "Melee Weapon Attack: +14 to hit, reach 30 ft., one target. Hit: 15 (2d6 + 8) slashing damage plus 10 (3d6) fire damage. The target must succeed on a DC 20 Strength saving throw or be pulled up to 25 feet toward the balor.", "attack_bonus": 14, "damage": "2d6+8"}, {"name": "Teleport", "description": "The balor magically teleports, along with any equipment it is wearing or carrying, up to 120 feet to an unoccupied space it can see.", "attack_bonus": null, "damage": null}]',
+        '[]', 0, '[]'),
+    ('ancient-brass-dragon', 'Ancient Brass Dragon', 'Gargantuan', 'Dragon', '', 'chaotic good',
+        20, 'natural armor', 297, '17d20+119', '{"walk": 40, "fly": 80, "swim": 0, "climb": 0, "burrow": 40, "hover": false}',
+        27, 10, 25, 16, 15, 19, '[{"name": "Dex", "bonus": 6}, {"name": "Con", "bonus": 13}, {"name": "Wis", "bonus": 8}, {"name": "Cha", "bonus": 10}]', '[{"name": "History", "bonus": 9}, {"name": "Perception", "bonus": 14}, {"name": "Persuasion", "bonus": 10}, {"name": "Stealth", "bonus": 6}]',
+        '', '', 'fire', '',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 24', 'Common, Draconic', 20, '["Desert"]',
+        '[{"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +14 to hit, reach 15 ft., one target. Hit: 19 (2d10 + 8) piercing damage.", "attack_bonus": 14, "damage": "2d10+8"}, {"name": "Claw", "description": "Melee Weapon Attack: +14 to hit, reach 10 ft., one target. Hit: 15 (2d6 + 8) slashing damage.", "attack_bonus": 14, "damage": "2d6+8"}, {"name": "Tail", "description": "Melee Weapon Attack: +14 to hit, reach 20 ft., one target. Hit: 17 (2d8 + 8) bludgeoning damage.", "attack_bonus": 14, "damage": "2d8+8"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice that is within 120 feet of the dragon and aware of it must succeed on a DC 18 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the dragon''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Fire Breath. The dragon exhales fire in a 90-foot line that is 10 feet wide. Each creature in that line must make a DC 21 Dexterity saving throw, taking 56 (16d6) fire damage on a failed save, or half as much damage on a successful one. Sleep Breath. The dragon exhales sleep gas in a 90-foot cone. Each creature in that area must succeed on a DC 21 Constitution saving throw or fall unconscious for 10 minutes. This effect ends for a creature if the creature takes damage or someone uses an action to wake it.", "attack_bonus": null, "damage": "16d6"}, {"name": "Change Shape", "description": "The dragon magically polymorphs into a humanoid or beast that has a challenge rating no higher than its own, or back into its true form. It reverts to its true form if it dies. Any equipment it is wearing or carrying is absorbed or borne by the new form (the dragon''s choice). In a new form, the dragon retains its alignment, hit points, Hit Dice, ability to speak, proficiencies, Legendary Resistance, lair actions, and Intelligence, Wisdom, and Charisma scores, as well as this action. Its statistics and capabilities are otherwise replaced by those of the new form, except any class features or legendary actions of that form.", "attack_bonus": null, "damage": null}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "The dragon beats its wings. Each creature within 15 feet of the dragon must succeed on a DC 22 Dexterity saving throw or take 15 (2d6 + 8) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+8"}]'),
+    ('ancient-white-dragon', 'Ancient White Dragon', 'Gargantuan', 'Dragon', '', 'chaotic evil',
+        20, 'natural armor', 333, '18d20+144', '{"walk": 40, "fly": 80, "swim": 40, "climb": 0, "burrow": 40, "hover": false}',
+        26, 10, 26, 10, 13, 14, '[{"name": "Dex", "bonus": 6}, {"name": "Con", "bonus": 14}, {"name": "Wis", "bonus": 7}, {"name": "Cha", "bonus": 8}]', '[{"name": "Perception", "bonus": 13}, {"name": "Stealth", "bonus": 6}]',
+        '', '', 'cold', '',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 23', 'Common, Draconic', 20, '["Arctic"]',
+        '[{"name": "Ice Walk", "description": "The dragon can move across and climb icy surfaces without needing to make an ability check. Additionally, difficult terrain composed of ice or snow doesn''t cost it extra movement.", "attack_bonus": null, "damage": null}, {"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +14 to hit, reach 15 ft., one target. Hit: 19 (2d10 + 8) piercing damage plus 9 (2d8) cold damage.", "attack_bonus": 14, "damage": "2d10+8"}, {"name": "Claw", "description": "Melee Weapon Attack: +14 to hit, reach 10 ft., one target. Hit: 15 (2d6 + 8) slashing damage.", "attack_bonus": 14, "damage": "2d6+8"}, {"name": "Tail", "description": "Melee Weapon Attack: +14 to hit, reach 20 ft., one target. Hit: 17 (2d8 + 8) bludgeoning damage.", "attack_bonus": 14, "damage": "2d8+8"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice that is within 120 feet of the dragon and aware of it must succeed on a DC 16 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the dragon''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Cold Breath (Recharge 5-6)", "description": "The dragon exhales an icy blast in a 90-foot cone. Each creature in that area must make a DC 22 Constitution saving throw, taking 72 (16d8) cold damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "16d8"}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "The dragon beats its wings. Each creature within 15 feet of the dragon must succeed on a DC 22 Dexterity saving throw or take 15 (2d6 + 8) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+8"}]'),
+    ('pit-fiend', 'Pit Fiend', 'Large', 'Fiend', 'devil', 'lawful evil',
+        19, 'natural armor', 300, '24d10+168', '{"walk": 30, "fly": 60, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        26, 14, 24, 22, 18, 24, '[{"name": "Dex", "bonus": 8}, {"name": "Con", "bonus": 13}, {"name": "Wis", "bonus": 10}]', '[]',
+        '', 'cold; bludgeoning, piercing, and slashing from nonmagical attacks that aren''t silvered', 'fire, poison', 'poisoned',
+        'truesight 120 ft., passive Perception 14', 'Infernal, telepathy 120 ft.', 20, '[]',
+        '[{"name": "Fear Aura", "description": "Any creature hostile to the pit fiend that starts its turn within 20 feet of the pit fiend must make a DC 21 Wisdom saving throw, unless the pit fiend is incapacitated. On a failed save, the creature is frightened until the start of its next turn. If a creature''s saving throw is successful, the creature is immune to the pit fiend''s Fear Aura for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The pit fiend has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}, {"name": "Magic Weapons", "description": "The pit fiend''s weapon attacks are magical.", "attack_bonus": null, "damage": null}, {"name": "Innate Spellcasting", "description": "The pit fiend''s spellcasting ability is Charisma (spell save DC 21). The pit fiend can innately cast the following spells, requiring no material components. At will: detect magic, fireball. 3/day each: hold monster, wall of fire.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The pit fiend makes four attacks: one with its bite, one with its claw, one with its mace, and one with its tail.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +14 to hit, reach 5 ft., one target. Hit: 22 (4d6 + 8) piercing damage. The target must succeed on a DC 21 Constitution saving throw or become poisoned. While poisoned in this way, the target can''t regain hit points, and it takes 21 (6d6) poison damage at the start of each of its turns. The poisoned target can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success.", "attack_bonus": 14, "damage": "4d6+8"}, {"name": "Claw", "description": "Melee Weapon Attack: +14 to hit, reach 10 ft., one target. Hit: 17 (2d8 + 8) slashing damage.", "attack_bonus": 14, "damage": "2d8+8"}, {"name": "Mace", "description": "Melee Weapon Attack: +14 to hit, reach 10 ft., one target. Hit: 15 (2d6 + 8) bludgeoning damage plus 21 (6d6) fire damage.", "attack_bonus": 14, "damage": "2d6+8"}, {"name": "Tail", "description": "Melee Weapon Attack: +14 to hit, reach 10 ft., one target. Hit: 24 (3d10 + 8) bludgeoning damage.", "attack_bonus": 14, "damage": "3d10+8"}]',
+        '[]', 0, '[]'),
+    ('ancient-black-dragon', 'Ancient Black Dragon', 'Gargantuan', 'Dragon', '', 'chaotic evil',
+        22, 'natural armor', 367, '21d20+147', '{"walk": 40, "fly": 80, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        27, 14, 25, 16, 15, 19, '[{"name": "Dex", "bonus": 9}, {"name": "Con", "bonus": 14}, {"name": "Wis", "bonus": 9}, {"name": "Cha", "bonus": 11}]', '[{"name": "Perception", "bonus": 16}, {"name": "Stealth", "bonus": 9}]',
+        '', '', 'acid', '',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 26', 'Common, Draconic', 21, '["Swamp"]',
+        '[{"name": "Amphibious", "description": "The dragon can breathe air and water.", "attack_bonus": null, "damage": null}, {"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +15 to hit, reach 15 ft., one target. Hit: 19 (2d10 + 8) piercing damage plus 9 (2d8) acid damage.", "attack_bonus": 15, "damage": "2d10+8"}, {"name": "Claw", "description": "Melee Weapon Attack: +15 to hit, reach 10 ft., one target. Hit: 15 (2d6 + 8) slashing damage.", "attack_bonus": 15, "damage": "2d6+8"}, {"name": "Tail", "description": "Melee Weapon Attack: +15 to hit, reach 20 ft., one target. Hit: 17 (2d8 + 8) bludgeoning damage.", "attack_bonus": 15, "damage": "2d8+8"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice that is within 120 feet of the dragon and aware of it must succeed on a DC 19 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the dragon''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Acid Breath (Recharge 5-6)", "description": "The dragon exhales acid in a 90-foot line that is 10 feet wide. Each creature in that line must make a DC 22 Dexterity saving throw, taking 67 (15d8) acid damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "15d8"}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "The dragon beats its wings. Each creature within 15 feet of the dragon must succeed on a DC 23 Dexterity saving throw or take 15 (2d6 + 8) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+8"}]'),
+    ('ancient-copper-dragon', 'Ancient Copper Dragon', 'Gargantuan', 'Dragon', '', 'chaotic good',
+        21, 'natural armor', 350, '20d20+140', '{"walk": 40, "fly": 80, "swim": 0, "climb": 40, "burrow": 0, "hover": false}',
+        27, 12, 25, 20, 17, 19, '[{"name": "Dex", "bonus": 8}, {"name": "Con", "bonus": 14}, {"name": "Wis", "bonus": 10}, {"name": "Cha", "bonus": 11}]', '[{"name": "Deception", "bonus": 11}, {"name": "Perception", "bonus": 17}, {"name": "Stealth", "bonus": 8}]',
+        '', '', 'acid', '',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 27', 'Common, Draconic', 21, '["Hill", "Mountain"]',
+        '[{"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +15 to hit, reach 15 ft., one target. Hit: 19 (2d10 + 8) piercing damage.", "attack_bonus": 15, "damage": "2d10+8"}, {"name": "Claw", "description": "Melee Weapon Attack: +15 to hit, reach 10 ft., one target. Hit: 15 (2d6 + 8) slashing damage.", "attack_bonus": 15, "damage": "2d6+8"}, {"name": "Tail", "description": "Melee Weapon Attack: +15 to hit, reach 20 ft., one target. Hit: 17 (2d8 + 8) bludgeoning damage.", "attack_bonus": 15, "damage": "2d8+8"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice that is within 120 feet of the dragon and aware of it must succeed on a DC 19 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the dragon''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Acid Breath. The dragon exhales acid in a 90-foot line that is 10 feet wide. Each creature in that line must make a DC 22 Dexterity saving throw, taking 63 (14d8) acid damage on a failed save, or half as much damage on a successful one. Slowing Breath. The dragon exhales gas in a 90-foot cone. Each creature in that area must succeed on a DC 22 Constitution saving throw. On a failed save, the creature can''t use reactions, its speed is halved, and it can''t make more than one attack on its turn. In addition, the creature can use either an action or a bonus action on its turn, but not both. These effects last for 1 minute. The creature can repeat the saving throw at the end of each of its turns, ending the effect on itself with a successful save.", "attack_bonus": null, "damage": "14d8"}, {"name": "Change Shape", "description": "The dragon magically polymorphs into a humanoid or beast that has a challenge rating no higher than its own, or back into its true form. It reverts to its true form if it dies. Any equipment it is wearing or carrying is absorbed or borne by the new form (the dragon''s choice). In a new form, the dragon retains its alignment, hit points, Hit Dice, ability to speak, proficiencies, Legendary Resistance, lair actions, and Intelligence, Wisdom, and Charisma scores, as well as this action. Its statistics and capabilities are otherwise replaced by those of the new form, except any class features or legendary actions of that form.", "attack_bonus": null, "damage": null}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "The dragon beats its wings. Each creature within 15 feet of the dragon must succeed on a DC 23 Dexterity saving throw or take 15 (2d6 + 8) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+8"}]'),
+    ('solar', 'Solar', 'Large', 'Celestial', '', 'lawful good',
+        21, 'natural armor', 243, '18d10+144', '{"walk": 50, "fly": 150, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        26, 22, 26, 25, 25, 30, '[{"name": "Int", "bonus": 14}, {"name": "Wis", "bonus": 14}, {"name": "Cha", "bonus": 17}]', '[{"name": "Perception", "bonus": 14}]',
+        '', 'radiant; bludgeoning, piercing, and slashing from nonmagical attacks', 'necrotic, poison', 'charmed, exhaustion, frightened, poisoned',
+        'truesight 120 ft., passive Perception 24', 'all, telepathy 120 ft.', 21, '[]',
+        '[{"name": "Angelic Weapons", "description": "The solar''s weapon attacks are magical. When the solar hits with any weapon, the weapon deals an extra 6d8 radiant damage (included in the attack).", "attack_bonus": null, "damage": null}, {"name": "Divine Awareness", "description": "The solar knows if it hears a lie.", "attack_bonus": null, "damage": null}, {"name": "Innate Spellcasting", "description": "The solar''s spellcasting ability is Charisma (spell save DC 25). It can innately cast the following spells, requiring no material components. At will: detect evil and good, invisibility (self only). 3/day each: blade barrier, dispel evil and good, resurrection. 1/day each: commune, control weather.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The solar has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The solar makes two greatsword attacks.", "attack_bonus": null, "damage": null}, {"name": "Greatsword", "description": "Melee Weapon Attack: +15 to hit, reach 5 ft., one target. Hit: 22 (4d6 + 8) slashing damage plus 27 (6d8) radiant damage.", "attack_bonus": 15, "damage": "4d6+8"}, {"name": "Slaying Longbow", "description": "Ranged Weapon Attack: +13 to hit, range 150/600 ft., one target. Hit: 15 (2d8 + 6) piercing damage plus 27 (6d8) radiant damage. If the target is a creature that has 100 hit points or fewer, it must succeed on a DC 15 Constitution saving throw or die.", "attack_bonus": 13, "damage": "2d8+6"}, {"name": "Flying Sword", "description": "The solar releases its greatsword to hover magically in an unoccupied space within 5 feet of it. If the solar can see the sword, the solar can mentally command it as a bonus action to fly up to 50 feet and either make one attack against a target or return to the solar''s hands. If the hovering sword is targeted by any effect, the solar is considered to be holding it. The hovering sword falls if the solar dies.", "attack_bonus": null, "damage": null}, {"name": "Healing Touch (4/Day)", "description": "The solar touches another creature. The target magically regains 40 (8d8 + 4) hit points and is freed from any curse, disease, poison, blindness, or deafness.", "attack_bonus": null, "damage": null}]',
+        '[]', 3, '[{"name": "Teleport", "description": "The solar magically teleports, along with any equipment it is wearing or carrying, up to 120 feet to an unoccupied space it can see.", "attack_bonus": null, "damage": null}, {"name": "Searing Burst (Costs 2 Actions)", "description": "The solar emits magical, divine energy. Each creature of its choice in a 10-foot radius must make a DC 23 Dexterity saving throw, taking 14 (4d6) fire damage plus 14 (4d6) radiant damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "4d6"}, {"name": "Blinding Gaze (Costs 3 Actions)", "description": "The solar targets one creature it can see within 30 feet of it. If the target can see it, the target must succeed on a DC 15 Constitution saving throw or be blinded until magic such as the lesser restoration spell removes the blindness.", "attack_bonus": null, "damage": null}]'),
+    ('ancient-bronze-dragon', 'Ancient Bronze Dragon', 'Gargantuan', 'Dragon', '', 'lawful good',
+        22, 'natural armor', 444, '24d20+192', '{"walk": 40, "fly": 80, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        29, 10, 27, 18, 17, 21, '[{"name": "Dex", "bonus": 7}, {"name": "Con", "bonus": 15}, {"name": "Wis", "bonus": 10}, {"name": "Cha", "bonus": 12}]', '[{"name": "Insight", "bonus": 10}, {"name": "Perception", "bonus": 17}, {"name": "Stealth", "bonus": 7}]',
+        '', '', 'lightning', '',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 27', 'Common, Draconic', 22, '["Coastal", "Underwater"]',
+        '[{"name": "Amphibious", "description": "The dragon can breathe air and water.", "attack_bonus": null, "damage": null}, {"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +16 to hit, reach 15 ft., one target. Hit: 20 (2d10 + 9) piercing damage.", "attack_bonus": 16, "damage": "2d10+9"}, {"name": "Claw", "description": "Melee Weapon Attack: +16 to hit, reach 10 ft., one target. Hit: 16 (2d6 + 9) slashing damage.", "attack_bonus": 16, "damage": "2d6+9"}, {"name": "Tail", "description": "Melee Weapon Attack: +16 to hit, reach 20 ft., one target. Hit: 18 (2d8 + 9) bludgeoning damage.", "attack_bonus": 16, "damage": "2d8+9"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice that is within 120 feet of the dragon and aware of it must succeed on a DC 20 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the dragon''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Lightning Breath. The dragon exhales lightning in a 120-foot line that is 10 feet wide. Each creature in that line must make a DC 23 Dexterity saving throw, taking 88 (16d10) lightning damage on a failed save, or half as much damage on a successful one. Repulsion Breath. The dragon exhales repulsion energy in a 30-foot cone. Each creature in that area must succeed on a DC 23 Strength saving throw. On a failed save, the creature is pushed 60 feet away from the dragon.", "attack_bonus": null, "damage": "16d10"}, {"name": "Change Shape", "description": "The dragon magically polymorphs into a humanoid or beast that has a challenge rating no higher than its own, or back into its true form. It reverts to its true form if it dies. Any equipment it is wearing or carrying is absorbed or borne by the new form (the dragon''s choice). In a new form, the dragon retains its alignment, hit points, Hit Dice, ability to speak, proficiencies, Legendary Resistance, lair actions, and Intelligence, Wisdom, and Charisma scores, as well as this action. Its statistics and capabilities are otherwise replaced by those of the new form, except any class features or legendary actions of that form.", "attack_bonus": null, "damage": null}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "The dragon beats its wings. Each creature within 15 feet of the dragon must succeed on a DC 24 Dexterity saving throw or take 16 (2d6 + 9) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+9"}]'),
+    ('ancient-green-dragon', 'Ancient Green Dragon', 'Gargantuan', 'Dragon', '', 'lawful evil',
+        21, 'natural armor', 385, '22d20+154', '{"walk": 40, "fly": 80, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        27, 12, 25, 20, 17, 19, '[{"name": "Dex", "bonus": 8}, {"name": "Con", "bonus": 14}, {"name": "Wis", "bonus": 10}, {"name": "Cha", "bonus": 11}]', '[{"name": "Deception", "bonus": 11}, {"name": "Insight", "bonus": 10}, {"name": "Perception", "bonus": 17}, {"name": "Persuasion", "bonus": 11}, {"name": "Stealth", "bonus": 8}]',
+        '', '', 'poison', 'poisoned',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 27', 'Common, Draconic', 22, '["Forest"]',
+        '[{"name": "Amphibious", "description": "The dragon can breathe air and water.", "attack_bonus": null, "damage": null}, {"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +15 to hit, reach 15 ft., one target. Hit: 19 (2d10 + 8) piercing damage plus 10 (3d6) poison damage.", "attack_bonus": 15, "damage": "2d10+8"}, {"name": "Claw", "description": "Melee Weapon Attack: +15 to hit, reach 10 ft., one target. Hit: 22 (4d6 + 8) slashing damage.", "attack_bonus": 15, "damage": "4d6+8"}, {"name": "Tail", "description": "Melee Weapon Attack: +15 to hit, reach 20 ft., one target. Hit: 17 (2d8 + 8) bludgeoning damage.", "attack_bonus": 15, "damage": "2d8+8"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice that is within 120 feet of the dragon and aware of it must succeed on a DC 19 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the dragon''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Poison Breath (Recharge 5-6)", "description": "The dragon exhales poisonous gas in a 90-foot cone. Each creature in that area must make a DC 22 Constitution saving throw, taking 77 (22d6) poison damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "22d6"}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "The dragon beats its wings. Each creature within 15 feet of the dragon must succeed on a DC 23 Dexterity saving throw or take 15 (2d6 + 8) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+8"}]'),
+    ('ancient-blue-dragon', 'Ancient Blue Dragon', 'Gargantuan', 'Dragon', '', 'lawful evil',
+        22, 'natural armor', 481, '26d20+208', '{"walk": 40, "fly": 80, "swim": 0, "climb": 0, "burrow": 40, "hover": false}',
+        29, 10, 27, 18, 17, 21, '[{"name": "Dex", "bonus": 7}, {"name": "Con", "bonus": 15}, {"name": "Wis", "bonus": 10}, {"name": "Cha", "bonus": 12}]', '[{"name": "Perception", "bonus": 17}, {"name": "Stealth", "bonus": 7}]',
+        '', '', 'lightning', '',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 27', 'Common, Draconic', 23, '["Desert", "Coastal"]',
+        '[{"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +16 to hit, reach 15 ft., one target. Hit: 20 (2d10 + 9) piercing damage plus 11 (2d10) lightning damage.", "attack_bonus": 16, "damage": "2d10+9"}, {"name": "Claw", "description": "Melee Weapon Attack: +16 to hit, reach 10 ft., one target. Hit: 16 (2d6 + 9) slashing damage.", "attack_bonus": 16, "damage": "2d6+9"}, {"name": "Tail", "description": "Melee Weapon Attack: +16 to hit, reach 20 ft., one target. Hit: 18 (2d8 + 9) bludgeoning damage.", "attack_bonus": 16, "damage": "2d8+9"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice that is within 120 feet of the dragon and aware of it must succeed on a DC 20 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the dragon''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Lightning Breath (Recharge 5-6)", "description": "The dragon exhales lightning in a 120-foot line that is 10 feet wide. Each creature in that line must make a DC 23 Dexterity saving throw, taking 88 (16d10) lightning damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "16d10"}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "The dragon beats its wings. Each creature within 15 feet of the dragon must succeed on a DC 24 Dexterity saving throw or take 16 (2d6 + 9) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+9"}]'),
+    ('ancient-silver-dragon', 'Ancient Silver Dragon', 'Gargantuan', 'Dragon', '', 'lawful good',
+        22, 'natural armor', 487, '25d20+225', '{"walk": 40, "fly": 80, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        30, 10, 29, 18, 15, 23, '[{"name": "Dex", "bonus": 7}, {"name": "Con", "bonus": 16}, {"name": "Wis", "bonus": 9}, {"name": "Cha", "bonus": 13}]', '[{"name": "Arcana", "bonus": 11}, {"name": "History", "bonus": 11}, {"name": "Perception", "bonus": 16}, {"name": "Stealth", "bonus": 7}]',
+        '', '', 'cold', '',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 26', 'Common, Draconic', 23, '["Mountain", "Urban"]',
+        '[{"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +17 to hit, reach 15 ft., one target. Hit: 21 (2d10 + 10) piercing damage.", "attack_bonus": 17, "damage": "2d10+10"}, {"name": "Claw", "description": "Melee Weapon Attack: +17 to hit, reach 10 ft., one target. Hit: 17 (2d6 + 10) slashing damage.", "attack_bonus": 17, "damage": "2d6+10"}, {"name": "Tail", "description": "Melee Weapon Attack: +17 to hit, reach 20 ft., one target. Hit: 19 (2d8 + 10) bludgeoning damage.", "attack_bonus": 17, "damage": "2d8+10"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice that is within 120 feet of the dragon and aware of it must succeed on a DC 21 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the dragon''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Cold Breath. The dragon exhales an icy blast in a 90-foot cone. Each creature in that area must make a DC 24 Constitution saving throw, taking 67 (15d8) cold damage on a failed save, or half as much damage on a successful one. Paralyzing Breath. The dragon exhales paralyzing gas in a 90-foot cone. Each creature in that area must succeed on a DC 24 Constitution saving throw or be paralyzed for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success.", "attack_bonus": null, "damage": "15d8"}, {"name": "Change Shape", "description": "The dragon magically polymorphs into a humanoid or beast that has a challenge rating no higher than its own, or back into its true form. It reverts to its true form if it dies. Any equipment it is wearing or carrying is absorbed or borne by the new form (the dragon''s choice). In a new form, the dragon retains its alignment, hit points, Hit Dice, ability to speak, proficiencies, Legendary Resistance, lair actions, and Intelligence, Wisdom, and Charisma scores, as well as this action. Its statistics and capabilities are otherwise replaced by those of the new form, except any class features or legendary actions of that form.", "attack_bonus": null, "damage": null}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "The dragon beats its wings. Each creature within 15 feet of the dragon must succeed on a DC 25 Dexterity saving throw or take 17 (2d6 + 10) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+10"}]'),
+    ('kraken', 'Kraken', 'Gargantuan', 'Monstrosity', 'titan', 'chaotic evil',
+        18, 'natural armor', 472, '27d20+189', '{"walk": 20, "fly": 0, "swim": 60, "climb": 0, "burrow": 0, "hover": false}',
+        30, 11, 25, 22, 18, 20, '[{"name": "Str", "bonus": 17}, {"name": "Dex", "bonus": 7}, {"name": "Con", "bonus": 14}, {"name": "Int", "bonus": 13}, {"name": "Wis", "bonus": 11}]', '[]',
+        '', '', 'lightning; bludgeoning, piercing, and slashing from nonmagical attacks', 'frightened, paralyzed',
+        'truesight 120 ft., passive Perception 14', 'understands Abyssal, Celestial, Infernal, and Primordial but can''t speak, telepathy 120 ft.', 23, '["Underwater"]',
+        '[{"name": "Amphibious", "description": "The kraken can breathe air and water.", "attack_bonus": null, "damage": null}, {"name": "Freedom of Movement", "description": "The kraken ignores difficult terrain, and magical effects can''t reduce its speed or cause it to be restrained. It can spend 5 feet of movement to escape from nonmagical restraints or being grappled.", "attack_bonus": null, "damage": null}, {"name": "Siege Monster", "description": "The kraken deals double damage to objects and structures.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The kraken makes three tentacle attacks, each of which it can replace with one use of Fling.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +17 to hit, reach 5 ft., one target. Hit: 23 (3d8 + 10) piercing damage. If the target is a Large or smaller creature grappled by the kraken, that creature is swallowed, and the grapple ends. While swallowed, the creature is blinded and restrained, it has total cover against attacks and other effects outside the kraken, and it takes 42 (12d6) acid damage at the start of each of the kraken''s turns. If the kraken takes 50 damage or more on a single turn from a creature inside it, the kraken must succeed on a DC 25 Constitution saving throw at the end of that turn or regurgitate all swallowed creatures, which fall prone in a space within 10 feet of the kraken. If the kraken dies, a swallowed creature is no longer restrained by it and can escape from the corpse using 15 feet of movement, exiting prone.", "attack_bonus": 17, "damage": "3d8+10"}, {"name": "Tentacle", "description": "Melee Weapon Attack: +17 to hit, reach 30 ft., one target. Hit: 20 (3d6 + 10) bludgeoning damage. The target is grappled (escape DC 18). Until this grapple ends, the target is restrained. The kraken has ten tentacles, each of which can grapple one target.", "attack_bonus": 17, "damage": "3d6+10"}, {"name": "Fling", "description": "One Large or smaller object held or creature grappled by the kraken is thrown up to 60 feet in a random direction and knocked prone. If a thrown target strikes a solid surface, the target takes 3 (1d6) bludgeoning damage for every 10 feet it was thrown. If the target is thrown at another creature, that creature must succeed on a DC 18 Dexterity saving throw or take the same damage and be knocked prone.", "attack_bonus": null, "damage": null}, {"name": "Lightning Storm", "description": "The kraken magically creates three bolts of lightning, each of which can strike a target the kraken can see within 120 feet of it. A target must make a DC 23 Dexterity saving throw, taking 22 (4d10) lightning damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "4d10"}]',
+        '[]', 3, '[{"name": "Tentacle Attack or Fling", "description": "The kraken makes one tentacle attack or uses its Fling.", "attack_bonus": null, "damage": null}, {"name": "Lightning Storm (Costs 2 Actions)", "description": "The kraken uses Lightning Storm.", "attack_bonus": null, "damage": null}, {"name": "Ink Cloud (Costs 3 Actions)", "description": "While underwater, the kraken expels an ink cloud in a 60-foot radius. The cloud spreads around corners, and that area is heavily obscured to creatures other than the kraken. Each creature other than the kraken that ends its turn there must succeed on a DC 23 Constitution saving throw, taking 16 (3d10) poison damage on a failed save, or half as much damage on a successful one. A strong current disperses the cloud, which otherwise disappears at the end of the kraken''s next turn.", "attack_bonus": null, "damage": "3d10"}]'),
+    ('ancient-gold-dragon', 'Ancient Gold Dragon', 'Gargantuan', 'Dragon', '', 'lawful good',
+        22, 'natural armor', 546, '28d20+252', '{"walk": 40, "fly": 80, "swim": 40, "climb": 0, "burrow": 0, "hover": false}',
+        30, 14, 29, 18, 17, 28, '[{"name": "Dex", "bonus": 9}, {"name": "Con", "bonus": 16}, {"name": "Wis", "bonus": 10}, {"name": "Cha", "bonus": 16}]', '[{"name": "Insight", "bonus": 10}, {"name": "Perception", "bonus": 17}, {"name": "Persuasion", "bonus": 16}, {"name": "Stealth", "bonus": 9}]',
+        '', '', 'fire', '',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 27', 'Common, Draconic', 24, '["Forest", "Grassland"]',
+        '[{"name": "Amphibious", "description": "The dragon can breathe air and water.", "attack_bonus": null, "damage": null}, {"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +17 to hit, reach 15 ft., one target. Hit: 21 (2d10 + 10) piercing damage.", "attack_bonus": 17, "damage": "2d10+10"}, {"name": "Claw", "description": "Melee Weapon Attack: +17 to hit, reach 10 ft., one target. Hit: 17 (2d6 + 10) slashing damage.", "attack_bonus": 17, "damage": "2d6+10"}, {"name": "Tail", "description": "Melee Weapon Attack: +17 to hit, reach 20 ft., one target. Hit: 19 (2d8 + 10) bludgeoning damage.", "attack_bonus": 17, "damage": "2d8+10"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice that is within 120 feet of the dragon and aware of it must succeed on a DC 24 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the dragon''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Breath Weapons (Recharge 5-6)", "description": "The dragon uses one of the following breath weapons. Fire Breath. The dragon exhales fire in a 90-foot cone. Each creature in that area must make a DC 24 Dexterity saving throw, taking 71 (13d10) fire damage on a failed save, or half as much damage on a successful one. Weakening Breath. The dragon exhales gas in a 90-foot cone. Each creature in that area must succeed on a DC 24 Strength saving throw or have disadvantage on Strength-based attack rolls, Strength checks, and Strength saving throws for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success.", "attack_bonus": null, "damage": "13d10"}, {"name": "Change Shape", "description": "The dragon magically polymorphs into a humanoid or beast that has a challenge rating no higher than its own, or back into its true form. It reverts to its true form if it dies. Any equipment it is wearing or carrying is absorbed or borne by the new form (the dragon''s choice). In a new form, the dragon retains its alignment, hit points, Hit Dice, ability to speak, proficiencies, Legendary Resistance, lair actions, and Intelligence, Wisdom, and Charisma scores, as well as this action. Its statistics and capabilities are otherwise replaced by those of the new form, except any class features or legendary actions of that form.", "attack_bonus": null, "damage": null}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "The dragon beats its wings. Each creature within 15 feet of the dragon must succeed on a DC 25 Dexterity saving throw or take 17 (2d6 + 10) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+10"}]'),
+    ('ancient-red-dragon', 'Ancient Red Dragon', 'Gargantuan', 'Dragon', '', 'chaotic evil',
+        22, 'natural armor', 546, '28d20+252', '{"walk": 40, "fly": 80, "swim": 0, "climb": 40, "burrow": 0, "hover": false}',
+        30, 10, 29, 18, 15, 23, '[{"name": "Dex", "bonus": 7}, {"name": "Con", "bonus": 16}, {"name": "Wis", "bonus": 9}, {"name": "Cha", "bonus": 13}]', '[{"name": "Perception", "bonus": 16}, {"name": "Stealth", "bonus": 7}]',
+        '', '', 'fire', '',
+        'blindsight 60 ft., darkvision 120 ft., passive Perception 26', 'Common, Draconic', 24, '["Mountain", "Hill"]',
+        '[{"name": "Legendary Resistance (3/Day)", "description": "If the dragon fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The dragon can use its Frightful Presence. It then makes three attacks: one with its bite and two with its claws.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +17 to hit, reach 15 ft., one target. Hit: 21 (2d10 + 10) piercing damage plus 14 (4d6) fire damage.", "attack_bonus": 17, "damage": "2d10+10"}, {"name": "Claw", "description": "Melee Weapon Attack: +17 to hit, reach 10 ft., one target. Hit: 17 (2d6 + 10) slashing damage.", "attack_bonus": 17, "damage": "2d6+10"}, {"name": "Tail", "description": "Melee Weapon Attack: +17 to hit, reach 20 ft., one target. Hit: 19 (2d8 + 10) bludgeoning damage.", "attack_bonus": 17, "damage": "2d8+10"}, {"name": "Frightful Presence", "description": "Each creature of the dragon''s choice that is within 120 feet of the dragon and aware of it must succeed on a DC 21 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the dragon''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Fire Breath (Recharge 5-6)", "description": "The dragon exhales fire in a 90-foot cone. Each creature in that area must make a DC 24 Dexterity saving throw, taking 91 (26d6) fire damage on a failed save, or half as much damage on a successful one.", "attack_bonus": null, "damage": "26d6"}]',
+        '[]', 3, '[{"name": "Detect", "description": "The dragon makes a Wisdom (Perception) check.", "attack_bonus": null, "damage": null}, {"name": "Tail Attack", "description": "The dragon makes a tail attack.", "attack_bonus": null, "damage": null}, {"name": "Wing Attack (Costs 2 Actions)", "description": "The dragon beats its wings. Each creature within 15 feet of the dragon must succeed on a DC 25 Dexterity saving throw or take 17 (2d6 + 10) bludgeoning damage and be knocked prone. The dragon can then fly up to half its flying speed.", "attack_bonus": null, "damage": "2d6+10"}]'),
+    ('tarrasque', 'Tarrasque', 'Gargantuan', 'Monstrosity', 'titan', 'unaligned',
+        25, 'natural armor', 676, '33d20+330', '{"walk": 40, "fly": 0, "swim": 0, "climb": 0, "burrow": 0, "hover": false}',
+        30, 11, 30, 3, 11, 11, '[{"name": "Int", "bonus": 5}, {"name": "Wis", "bonus": 9}, {"name": "Cha", "bonus": 9}]', '[]',
+        '', '', 'fire, poison; bludgeoning, piercing, and slashing from nonmagical attacks', 'charmed, frightened, paralyzed, poisoned',
+        'blindsight 120 ft., passive Perception 10', '', 30, '[]',
+        '[{"name": "Legendary Resistance (3/Day)", "description": "If the tarrasque fails a saving throw, it can choose to succeed instead.", "attack_bonus": null, "damage": null}, {"name": "Magic Resistance", "description": "The tarrasque has advantage on saving throws against spells and other magical effects.", "attack_bonus": null, "damage": null}, {"name": "Reflective Carapace", "description": "Any time the tarrasque is targeted by a magic missile spell, a line spell, or a spell that requires a ranged attack roll, roll a d6. On a 1 to 5, the tarrasque is unaffected. On a 6, the tarrasque is unaffected, and the effect is reflected back at the caster as though it originated from the tarrasque, turning the caster into the target.", "attack_bonus": null, "damage": null}, {"name": "Siege Monster", "description": "The tarrasque deals double damage to objects and structures.", "attack_bonus": null, "damage": null}]',
+        '[{"name": "Multiattack", "description": "The tarrasque can use its Frightful Presence. It then makes five attacks: one with its bite, two with its claws, one with its horns, and one with its tail. It can use its Swallow instead of its bite.", "attack_bonus": null, "damage": null}, {"name": "Bite", "description": "Melee Weapon Attack: +19 to hit, reach 10 ft., one target. Hit: 36 (4d12 + 10) piercing damage. If the target is a creature, it is grappled (escape DC 20). Until this grapple ends, the target is restrained, and the tarrasque can''t bite another target.", "attack_bonus": 19, "damage": "4d12+10"}, {"name": "Claw", "description": "Melee Weapon Attack: +19 to hit, reach 15 ft., one target. Hit: 28 (4d8 + 10) slashing damage.", "attack_bonus": 19, "damage": "4d8+10"}, {"name": "Horns", "description": "Melee Weapon Attack: +19 to hit, reach 10 ft., one target. Hit: 32 (4d10 + 10) piercing damage.", "attack_bonus": 19, "damage": "4d10+10"}, {"name": "Tail", "description": "Melee Weapon Attack: +19 to hit, reach 20 ft., one target. Hit: 24 (4d6 + 10) bludgeoning damage. If the target is a creature, it must succeed on a DC 20 Strength saving throw or be knocked prone.", "attack_bonus": 19, "damage": "4d6+10"}, {"name": "Frightful Presence", "description": "Each creature of the tarrasque''s choice within 120 feet of it and aware of it must succeed on a DC 17 Wisdom saving throw or become frightened for 1 minute. A creature can repeat the saving throw at the end of each of its turns, with disadvantage if the tarrasque is within line of sight, ending the effect on itself on a success. If a creature''s saving throw is successful or the effect ends for it, the creature is immune to the tarrasque''s Frightful Presence for the next 24 hours.", "attack_bonus": null, "damage": null}, {"name": "Swallow", "description": "The tarrasque makes one bite attack against a Large or smaller creature it is grappling. If the attack hits, the target takes the bite''s damage, the target is swallowed, and the grapple ends. While swallowed, the creature is blinded and restrained, it has total cover against attacks and other effects outside the tarrasque, and it takes 56 (16d6) acid damage at the start of each of the tarrasque''s turns. If the tarrasque takes 60 damage or more on a single turn from a creature inside it, the tarrasque must succeed on a DC 20 Constitution saving throw at the end of that turn or regurgitate all swallowed creatures, which fall prone in a space within 10 feet of the tarrasque. If the tarrasque dies, a swallowed creature is no longer restrained by it and can escape from the corpse by using 30 feet of movement, exiting prone.", "attack_bonus": null, "damage": "16d6"}]',
+        '[]', 3, '[{"name": "Attack", "description": "The tarrasque makes one claw attack or tail attack.", "attack_bonus": null, "damage": null}, {"name": "Move", "description": "The tarrasque moves up to half its speed.", "attack_bonus": null, "damage": null}, {"name": "Chomp (Costs 2 Actions)", "description": "The tarrasque makes one bite attack or uses its Swallow.", "attack_bonus": null, "damage": null}]')
+on conflict (slug) do nothing;
@@ -0,0 +1,7 @@
+-- Only the server writes the campaign roll log. record_roll in src/api/campaign.rs throws the
+-- dice and inserts the row with the service role, which bypasses row-level security; a player
+-- inserting through PostgREST could otherwise log any result or total they liked.
+
+drop policy if exists "Members log their own rolls" on campaign_rolls;
+
+revoke insert, update, delete, truncate on campaign_rolls from anon, authenticated;
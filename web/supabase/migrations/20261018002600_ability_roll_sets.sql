@@ -0,0 +1,21 @@
+-- Ability scores rolled for the creation wizard. roll_ability_scores in src/api/character.rs
+-- throws each 4d6-drop-lowest set on the server and keeps it here, and create_character only
+-- accepts ability_rolls that match sets rolled for the caller, so nobody can claim 18s they
+-- didn't roll. Players read their own sets; only the server, with the service role, writes them.
+
+create table if not exists ability_roll_sets (
+    id serial primary key,
+    owner text not null,
+    dice jsonb not null check (jsonb_typeof(dice) = 'array'),
+    created_at timestamptz not null default now()
+);
+
+create index if not exists ability_roll_sets_owner_idx on ability_roll_sets (owner);
+
+alter table ability_roll_sets enable row level security;
+
+create policy "Players read the sets rolled for them"
+    on ability_roll_sets for select
+    using (owner = auth.uid()::text);
+
+revoke insert, update, delete, truncate on ability_roll_sets from anon, authenticated;